@@ -0,0 +1,3 @@
+console.log("linted");
+console.info("allowed by the config");
+console[method]("not known statically");
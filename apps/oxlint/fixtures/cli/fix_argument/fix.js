@@ -1 +1 @@
-var x = new String('Hello world');
+var x = 1.0;
@@ -0,0 +1,2 @@
+import fs from "fs";
+var x = 1.0;
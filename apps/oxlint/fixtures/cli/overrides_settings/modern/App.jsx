@@ -0,0 +1,3 @@
+class App extends React.Component {
+  UNSAFE_componentWillMount() {}
+}
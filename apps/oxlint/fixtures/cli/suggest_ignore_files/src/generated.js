@@ -0,0 +1,4 @@
+/* eslint-disable no-unused-vars */
+// oxlint-disable-next-line no-debugger
+debugger;
+var a = 1; // oxlint-disable-line no-var
@@ -0,0 +1,2 @@
+// eslint-disable-next-line no-debugger, some-plugin/some-rule
+debugger;
@@ -0,0 +1,89 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use rustc_hash::FxHashSet;
+
+use crate::cli::ChangedFilesOptions;
+
+/// Set of files to restrict linting to, from `--changed-since` and `--changed-files-from`.
+///
+/// Paths are canonicalized, so that they can be compared with the paths found by the walker
+/// regardless of symlinks or how the working directory was spelled.
+#[derive(Debug, Default)]
+pub struct ChangedFiles(FxHashSet<PathBuf>);
+
+impl ChangedFiles {
+    /// Collect the changed files requested by `options`.
+    ///
+    /// Returns `Ok(None)` if neither option was passed, in which case all files are linted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if git could not be run, the revision is invalid, or the list of
+    /// changed files could not be read.
+    pub fn from_options(options: &ChangedFilesOptions, cwd: &Path) -> Result<Option<Self>, String> {
+        if options.changed_since.is_none() && options.changed_files_from.is_none() {
+            return Ok(None);
+        }
+
+        let mut changed_files = Self::default();
+
+        if let Some(rev) = &options.changed_since {
+            let (root, names) = git_diff_names(rev, cwd)?;
+            changed_files.extend(&root, names.lines());
+        }
+
+        if let Some(path) = &options.changed_files_from {
+            let names = fs::read_to_string(cwd.join(path)).map_err(|err| {
+                format!("Failed to read changed files from `{}`: {err}", path.display())
+            })?;
+            changed_files.extend(cwd, names.lines());
+        }
+
+        Ok(Some(changed_files))
+    }
+
+    /// Add `names`, relative to `root`. Files which no longer exist are skipped.
+    fn extend<'a>(&mut self, root: &Path, names: impl Iterator<Item = &'a str>) {
+        self.0.extend(
+            names
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .filter_map(|name| fs::canonicalize(root.join(name)).ok()),
+        );
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        fs::canonicalize(path).is_ok_and(|path| self.0.contains(&path))
+    }
+}
+
+/// Run `git diff --name-only --diff-filter=ACMR <rev>` in `cwd`.
+///
+/// Added, copied, modified and renamed (new path) files are listed; deleted files are not.
+/// Returns the repository root, which the listed names are relative to, and the names.
+fn git_diff_names(rev: &str, cwd: &Path) -> Result<(PathBuf, String), String> {
+    let root = run_git(&["rev-parse", "--show-toplevel"], cwd)
+        .map_err(|err| format!("Failed to find the git repository: {err}"))?;
+    let names = run_git(&["diff", "--name-only", "--diff-filter=ACMR", rev, "--"], cwd)
+        .map_err(|err| format!("Failed to get files changed since `{rev}`: {err}"))?;
+    Ok((PathBuf::from(root.trim_end()), names))
+}
+
+fn run_git(args: &[&str], cwd: &Path) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .map_err(|err| format!("Failed to run `git`: {err}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.lines().next().unwrap_or_default().to_string());
+    }
+
+    String::from_utf8(output.stdout).map_err(|err| format!("Invalid `git` output: {err}"))
+}
@@ -29,6 +29,9 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub ignore_options: IgnoreOptions,
 
+    #[bpaf(external)]
+    pub changed_files_options: ChangedFilesOptions,
+
     #[bpaf(external)]
     pub warning_options: WarningOptions,
 
@@ -73,6 +76,22 @@ pub struct LintCommand {
     pub paths: Vec<PathBuf>,
 }
 
+/// Changed Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct ChangedFilesOptions {
+    /// Only lint files added, copied, modified or renamed since the given git revision,
+    /// as listed by `git diff --name-only --diff-filter=ACMR <REV>`.
+    /// Files which would not be linted otherwise (e.g. ignored files) are still excluded.
+    #[bpaf(argument("REV"), hide_usage)]
+    pub changed_since: Option<String>,
+
+    /// Only lint the files listed in the given file, one path per line,
+    /// relative to the current working directory.
+    /// Files which would not be linted otherwise (e.g. ignored files) are still excluded.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub changed_files_from: Option<PathBuf>,
+}
+
 #[derive(Debug, Clone, Bpaf)]
 pub struct SuppressionOptions {
     /// Generate suppressions for all current violations
@@ -166,6 +185,10 @@ pub struct BasicOptions {
     /// Initialize oxlint configuration with default values
     #[bpaf(switch, hide_usage)]
     pub init: bool,
+
+    /// Print the JSON schema of the configuration file, for editor autocompletion
+    #[bpaf(switch, hide_usage)]
+    pub print_schema: bool,
 }
 
 // This is formatted according to
@@ -230,13 +253,33 @@ pub struct FixOptions {
     /// Apply dangerous fixes and suggestions
     #[bpaf(switch, hide_usage)]
     pub fix_dangerously: bool,
+
+    /// Compute fixes like `--fix`, but report what would change instead of writing files.
+    /// Prints a diff per file, or adds the fixed source to `--format json` output.
+    /// Can be combined with `--fix-suggestions` and `--fix-dangerously`, but not `--fix`.
+    #[bpaf(switch, hide_usage)]
+    pub fix_dry_run: bool,
+
+    /// Only apply the fixes of rule or category NAME, e.g. `unicorn/prefer-node-protocol`,
+    /// `no-else-return` or `style`. Can be repeated. All diagnostics are still reported.
+    #[bpaf(argument("NAME"), many, hide_usage)]
+    pub fix_only: Vec<String>,
+
+    /// Don't apply the fixes of rule or category NAME. Can be repeated.
+    #[bpaf(argument("NAME"), many, hide_usage)]
+    pub fix_except: Vec<String>,
+
+    /// Apply fixes without re-parsing the fixed files.
+    /// By default, all fixes of a file are discarded if together they introduce syntax errors.
+    #[bpaf(switch, hide_usage)]
+    pub no_verify_fixes: bool,
 }
 
 impl FixOptions {
     pub fn fix_kind(&self) -> FixKind {
         let mut kind = FixKind::None;
 
-        if self.fix {
+        if self.fix || self.fix_dry_run {
             kind.set(FixKind::SafeFix, true);
         }
 
@@ -251,8 +294,14 @@ impl FixOptions {
         kind
     }
 
+    /// Whether fixes are computed, either to be written or, with `--fix-dry-run`, reported.
     pub fn is_enabled(&self) -> bool {
-        self.fix || self.fix_suggestions || self.fix_dangerously
+        self.fix || self.fix_suggestions || self.fix_dangerously || self.fix_dry_run
+    }
+
+    /// Whether fixed files are written to disk.
+    pub fn writes_files(&self) -> bool {
+        self.is_enabled() && !self.fix_dry_run
     }
 }
 
@@ -289,6 +338,17 @@ pub struct OutputOptions {
         hide_usage
     )]
     pub debug: DebugOptions,
+
+    /// Run only this rule on the file given with `--debug-file`, and print the nodes it visits,
+    /// the messages it traces and the diagnostics it reports, then exit
+    #[cfg(feature = "debug_rule")]
+    #[bpaf(argument("NAME"), hide_usage)]
+    pub debug_rule: Option<String>,
+
+    /// File to run the rule given with `--debug-rule` on
+    #[cfg(feature = "debug_rule")]
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub debug_file: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -557,6 +617,11 @@ pub enum ReportUnusedDirectives {
 pub struct InlineConfigOptions {
     #[bpaf(external)]
     pub report_unused_directives: ReportUnusedDirectives,
+
+    /// List the files with at least INT disable directive comments in `.oxlintignore` format.
+    /// Files which need this many directives are often generated, and better ignored as a whole.
+    #[bpaf(argument("INT"), hide_usage)]
+    pub suggest_ignore_files: Option<usize>,
 }
 
 #[cfg(test)]
@@ -634,7 +699,7 @@ mod warning_options {
 mod lint_options {
     use std::{fs::File, path::PathBuf};
 
-    use oxc_linter::AllowWarnDeny;
+    use oxc_linter::{AllowWarnDeny, FixKind};
 
     use super::{DebugOption, DebugOptions, LintCommand, OutputFormat, lint_command};
 
@@ -692,6 +757,42 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert!(options.fix_options.fix_dry_run);
+        assert!(options.fix_options.is_enabled());
+        assert!(!options.fix_options.writes_files());
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix);
+
+        let options = get_lint_options("--fix-dry-run --fix-suggestions test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix | FixKind::Suggestion);
+        assert!(!options.fix_options.writes_files());
+    }
+
+    #[test]
+    fn fix_only_and_except() {
+        let options = get_lint_options("--fix test.js");
+        assert!(options.fix_options.fix_only.is_empty());
+        assert!(options.fix_options.fix_except.is_empty());
+
+        let options = get_lint_options(
+            "--fix --fix-only unicorn/prefer-node-protocol --fix-only style --fix-except no-else-return test.js",
+        );
+        assert_eq!(options.fix_options.fix_only, ["unicorn/prefer-node-protocol", "style"]);
+        assert_eq!(options.fix_options.fix_except, ["no-else-return"]);
+    }
+
+    #[test]
+    fn no_verify_fixes() {
+        let options = get_lint_options("--fix test.js");
+        assert!(!options.fix_options.no_verify_fixes);
+
+        let options = get_lint_options("--fix --no-verify-fixes test.js");
+        assert!(options.fix_options.no_verify_fixes);
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix);
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -750,6 +851,25 @@ mod lint_options {
         );
     }
 
+    #[cfg(feature = "debug_rule")]
+    #[test]
+    fn debug_rule() {
+        let options = get_lint_options("--debug-rule no-console --debug-file console.js");
+        assert_eq!(options.output_options.debug_rule.as_deref(), Some("no-console"));
+        assert_eq!(options.output_options.debug_file, Some(PathBuf::from("console.js")));
+    }
+
+    #[cfg(not(feature = "debug_rule"))]
+    #[test]
+    fn debug_rule_requires_feature() {
+        let args = "--debug-rule no-console --debug-file console.js"
+            .split(' ')
+            .map(std::string::ToString::to_string)
+            .collect::<Vec<_>>();
+        let result = lint_command().run_inner(args.as_slice());
+        assert!(result.is_err_and(|err| err.unwrap_stderr().contains("--debug-rule")));
+    }
+
     #[test]
     fn format_error() {
         let args = "-f asdf".split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -765,6 +885,13 @@ mod lint_options {
         assert!(options.list_rules);
     }
 
+    #[test]
+    fn print_schema() {
+        let options = get_lint_options("--print-schema");
+        assert!(options.basic_options.print_schema);
+        assert!(!get_lint_options(".").basic_options.print_schema);
+    }
+
     #[test]
     fn disable_nested_config() {
         let options = get_lint_options("--disable-nested-config");
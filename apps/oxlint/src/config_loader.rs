@@ -244,7 +244,7 @@ impl ConfigLoadError {
 #[derive(Debug)]
 pub enum CliConfigLoadError {
     /// An error that occurred while loading or parsing the root configuration.
-    RootConfig(OxcDiagnostic),
+    RootConfig(ConfigLoadError),
     /// One or more errors that occurred while loading nested configuration files.
     NestedConfigs(Vec<ConfigLoadError>),
 }
@@ -514,23 +514,27 @@ impl<'a> ConfigLoader<'a> {
         &self,
         discovery: &ConfigDiscovery,
         dir: &Path,
-    ) -> Result<Option<Oxlintrc>, OxcDiagnostic> {
-        let config_file =
-            discovery.find_unique_config_by_readdir(dir, true).map_err(OxcDiagnostic::from)?;
+    ) -> Result<Option<Oxlintrc>, ConfigLoadError> {
+        let config_file = discovery
+            .find_unique_config_by_readdir(dir, true)
+            .map_err(|err| ConfigLoadError::Diagnostic(err.into()))?;
 
         match config_file {
             Some(DiscoveredConfigFile::Json(path) | DiscoveredConfigFile::Jsonc(path)) => {
-                Oxlintrc::from_file(&path).map(Some)
+                Self::load(&path).map(Some)
             }
             Some(DiscoveredConfigFile::Js(path)) => {
-                let config = self.load_root_js_config(&path)?;
+                let config =
+                    self.load_root_js_config(&path).map_err(ConfigLoadError::Diagnostic)?;
                 debug_assert!(
                     config.is_some(),
                     "oxlint JS/TS config should always return a config"
                 );
                 Ok(config)
             }
-            Some(DiscoveredConfigFile::Vite(path)) => self.load_root_js_config(&path),
+            Some(DiscoveredConfigFile::Vite(path)) => {
+                self.load_root_js_config(&path).map_err(ConfigLoadError::Diagnostic)
+            }
             None => Ok(None),
         }
     }
@@ -549,7 +553,7 @@ impl<'a> ConfigLoader<'a> {
         &self,
         cwd: &Path,
         config_path: Option<&PathBuf>,
-    ) -> Result<Oxlintrc, OxcDiagnostic> {
+    ) -> Result<Oxlintrc, ConfigLoadError> {
         // If an explicit config path is provided, use it directly
         if let Some(config_path) = config_path {
             return self.load_explicit_config(cwd, config_path);
@@ -575,21 +579,24 @@ impl<'a> ConfigLoader<'a> {
         &self,
         cwd: &Path,
         config_path: &Path,
-    ) -> Result<Oxlintrc, OxcDiagnostic> {
+    ) -> Result<Oxlintrc, ConfigLoadError> {
         // Normalize away `.`/`..` components:
         // this path (config's parent directory) becomes the root for `ignorePatterns` matching,
         // which is compared against the (normalized) lint target paths as a literal prefix.
         // If a root containing `..`, it never matches.
         let full_path = normalize_path(cwd.join(config_path));
         if is_js_config_path(&full_path) {
-            return self.load_root_js_config(&full_path)?.ok_or_else(|| {
-                OxcDiagnostic::error(format!(
-                    "Expected a `lint` field in the default export of {}",
-                    full_path.display()
-                ))
-            });
+            return self
+                .load_root_js_config(&full_path)
+                .map_err(ConfigLoadError::Diagnostic)?
+                .ok_or_else(|| {
+                    ConfigLoadError::Diagnostic(OxcDiagnostic::error(format!(
+                        "Expected a `lint` field in the default export of {}",
+                        full_path.display()
+                    )))
+                });
         }
-        Oxlintrc::from_file(&full_path)
+        Self::load(&full_path)
     }
 
     /// Load a single JS/TS config file. Returns `Ok(None)` when JS side signals "skip"
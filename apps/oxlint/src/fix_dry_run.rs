@@ -0,0 +1,76 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use cow_utils::CowUtils;
+
+use oxc_allocator::Allocator;
+use oxc_linter::{AppliedFix, OsFileSystem, RuntimeFileSystem};
+
+/// A file which `--fix-dry-run` would have fixed.
+#[derive(Debug)]
+pub struct FixedFile {
+    pub path: PathBuf,
+    /// Source text before fixing.
+    pub source_text: String,
+    /// Source text after applying `applied_fixes`.
+    pub fixed_code: String,
+    pub applied_fixes: Vec<AppliedFix>,
+}
+
+impl FixedFile {
+    /// Path relative to `cwd` if possible, with `/` separators.
+    pub fn relative_path(&self, cwd: &Path) -> String {
+        let path = self.path.strip_prefix(cwd).unwrap_or(&self.path);
+        path.to_string_lossy().cow_replace('\\', "/").into_owned()
+    }
+}
+
+/// File system for `--fix-dry-run`: files are read from disk, but fixed files are collected
+/// instead of being written.
+#[derive(Debug, Default)]
+pub struct FixDryRunFileSystem {
+    fixed_files: Mutex<Vec<FixedFile>>,
+}
+
+impl FixDryRunFileSystem {
+    /// The collected files, sorted by path.
+    pub fn into_fixed_files(self) -> Vec<FixedFile> {
+        let mut fixed_files = self.fixed_files.into_inner().expect("fixed_files mutex poisoned");
+        fixed_files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+        fixed_files
+    }
+}
+
+impl RuntimeFileSystem for FixDryRunFileSystem {
+    fn read_to_arena_str<'a>(
+        &self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, std::io::Error> {
+        OsFileSystem.read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error> {
+        self.write_fixed_file(path, content, &[])
+    }
+
+    fn write_fixed_file(
+        &self,
+        path: &Path,
+        content: &str,
+        applied_fixes: &[AppliedFix],
+    ) -> Result<(), std::io::Error> {
+        // The file is never written, so it still contains the original source text.
+        let source_text = fs::read_to_string(path)?;
+        self.fixed_files.lock().expect("fixed_files mutex poisoned").push(FixedFile {
+            path: path.to_path_buf(),
+            source_text,
+            fixed_code: content.to_string(),
+            applied_fixes: applied_fixes.to_vec(),
+        });
+        Ok(())
+    }
+}
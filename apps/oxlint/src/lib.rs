@@ -2,8 +2,10 @@
 #![cfg_attr(not(feature = "napi"), allow(dead_code))]
 
 mod agent_detection;
+mod changed_files;
 mod command;
 mod config_loader;
+mod fix_dry_run;
 mod init;
 mod lint;
 pub mod lsp;
@@ -2,6 +2,7 @@ use std::{
     env,
     ffi::OsStr,
     fmt::Debug,
+    fs,
     io::{ErrorKind, Write},
     path::{Path, PathBuf, absolute},
     sync::Arc,
@@ -14,19 +15,22 @@ use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
     AllowWarnDeny, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ExternalLinter,
-    ExternalPluginStore, InvalidFilterKind, LintFilter, LintOptions, LintRunner,
-    LintServiceOptions, Linter, OxlintSuppressionFileAction, RuleTimingStore, SuppressionManager,
+    ExternalPluginStore, FixFilter, InvalidFilterKind, LintFilter, LintOptions, LintRunner,
+    LintServiceOptions, Linter, OsFileSystem, OxlintSuppressionFileAction, RuleTimingStore,
+    SuppressionManager,
 };
 
 #[cfg(feature = "napi")]
 use crate::js_config::JsConfigLoaderCb;
 use crate::{
+    changed_files::ChangedFiles,
     cli::{
         CliRunResult, DebugOption, LintCommand, MiscOptions, ReportUnusedDirectives, WarningOptions,
     },
     config_loader::{
         CliConfigLoadError, ConfigLoadError, ConfigLoader, materialize_default_plugins,
     },
+    fix_dry_run::FixDryRunFileSystem,
     output_formatter::{LintCommandInfo, OutputFormatter},
     walk::Walk,
 };
@@ -83,12 +87,15 @@ impl CliRunner {
             basic_options,
             warning_options,
             ignore_options,
+            changed_files_options,
             fix_options,
             enable_plugins,
             misc_options,
             disable_nested_config,
             inline_config_options,
             suppression_options,
+            #[cfg(feature = "debug_rule")]
+            output_options,
             ..
         } = self.options;
 
@@ -96,6 +103,42 @@ impl CliRunner {
             return crate::mode::run_init(&self.cwd, stdout);
         }
 
+        if basic_options.print_schema {
+            return crate::mode::run_print_schema(stdout);
+        }
+
+        if fix_options.fix && fix_options.fix_dry_run {
+            print_and_flush_stdout(
+                stdout,
+                "The `--fix-dry-run` option cannot be used with `--fix`.\n",
+            );
+            return CliRunResult::InvalidOptionFixDryRunWithFix;
+        }
+
+        let fix_filter = match FixFilter::new(&fix_options.fix_only, &fix_options.fix_except) {
+            Ok(fix_filter) => fix_filter,
+            Err(err) => {
+                print_and_flush_stdout(
+                    stdout,
+                    &format!("Invalid `--fix-only` or `--fix-except` option: {err}\n"),
+                );
+                return CliRunResult::InvalidOptionFixFilter;
+            }
+        };
+
+        #[cfg(feature = "debug_rule")]
+        let debug_rule = match (output_options.debug_rule, output_options.debug_file) {
+            (Some(rule), Some(file)) => Some((rule, file)),
+            (None, None) => None,
+            _ => {
+                print_and_flush_stdout(
+                    stdout,
+                    "The `--debug-rule` and `--debug-file` options must be used together.\n",
+                );
+                return CliRunResult::InvalidOptionDebugRule;
+            }
+        };
+
         let external_linter = self.external_linter.as_ref();
 
         let mut paths = paths;
@@ -110,6 +153,14 @@ impl CliRunner {
             }
         };
 
+        let changed_files = match ChangedFiles::from_options(&changed_files_options, &self.cwd) {
+            Ok(changed_files) => changed_files,
+            Err(message) => {
+                print_and_flush_stdout(stdout, &format!("{message}\n"));
+                return CliRunResult::InvalidOptionChangedFiles;
+            }
+        };
+
         let handler = if cfg!(any(test, feature = "testing")) {
             GraphicalReportHandler::new_themed(miette::GraphicalTheme::none())
         } else {
@@ -235,55 +286,25 @@ impl CliRunner {
         let (mut root_config, nested_configs, nested_ignore_patterns) = match config_result {
             Ok(loaded) => (loaded.root, loaded.nested, loaded.nested_ignore_patterns),
             Err(error) => {
-                match error {
+                let message = match error {
                     CliConfigLoadError::RootConfig(error) => {
-                        print_and_flush_stdout(
-                            stdout,
-                            &format!(
-                                "Failed to parse oxlint configuration file.\n{}\n",
-                                render_report(&handler, &error)
-                            ),
-                        );
-                    }
-                    CliConfigLoadError::NestedConfigs(errors) => {
-                        if let Some(error) = errors.into_iter().next() {
-                            let message = match &error {
-                                ConfigLoadError::Parse { path, error } => {
-                                    format!(
-                                        "Failed to parse oxlint configuration file at {}.\n{}\n",
-                                        path.to_string_lossy().cow_replace('\\', "/"),
-                                        render_report(&handler, error)
-                                    )
-                                }
-                                ConfigLoadError::Build { path, error } => {
-                                    format!(
-                                        "Failed to build configuration from {}.\n{}\n",
-                                        path.to_string_lossy().cow_replace('\\', "/"),
-                                        render_report(
-                                            &handler,
-                                            &OxcDiagnostic::error(error.clone())
-                                        )
-                                    )
-                                }
-                                ConfigLoadError::JsConfigFileFoundButJsRuntimeNotAvailable => {
-                                    "Error: JavaScript/TypeScript config files found but JS runtime not available.\n\
-                                     This is an experimental feature that requires running oxlint via Node.js.\n\
-                                     Please use JSON config files (.oxlintrc.json or .oxlintrc.jsonc) instead, or run oxlint via the npm package.\n".to_string()
-                                }
-                                ConfigLoadError::Diagnostic(error) => {
-                                    let report = render_report(&handler, error);
-                                    format!("Failed to parse oxlint configuration file.\n{report}\n")
-                                }
-                            };
-                            print_and_flush_stdout(stdout, &message);
-                        }
+                        Some(render_config_load_error(&handler, &self.cwd, &error, false))
                     }
+                    CliConfigLoadError::NestedConfigs(errors) => errors
+                        .first()
+                        .map(|error| render_config_load_error(&handler, &self.cwd, error, true)),
+                };
+                if let Some(message) = message {
+                    print_and_flush_stdout(stdout, &message);
                 }
 
                 return CliRunResult::InvalidOptionConfig;
             }
         };
 
+        let config_warnings = std::mem::take(&mut root_config.warnings);
+        let config_path = root_config.path.clone();
+
         materialize_default_plugins(&mut root_config);
         let mut plugins = root_config.plugins.unwrap_or_default();
         enable_plugins.apply_overrides(&mut plugins);
@@ -343,6 +364,9 @@ impl CliRunner {
         let files_to_lint = paths
             .into_iter()
             .filter(|path| !ignore_matcher.should_ignore(Path::new(path)))
+            .filter(|path| {
+                changed_files.as_ref().is_none_or(|changed| changed.contains(Path::new(path)))
+            })
             .collect::<Vec<Arc<OsStr>>>();
 
         if debug_files {
@@ -370,7 +394,7 @@ impl CliRunner {
             options.cwd(),
             "oxlint-suppressions.json",
             suppression_options.suppress_all,
-            suppression_options.prune_suppressions || fix_options.is_enabled(),
+            suppression_options.prune_suppressions || fix_options.writes_files(),
         );
 
         let config_store = ConfigStore::new(lint_config, nested_configs, external_plugin_store);
@@ -446,8 +470,15 @@ impl CliRunner {
 
         let linter = Linter::new(LintOptions::default(), config_store, external_linter)
             .with_fix(fix_options.fix_kind())
+            .with_fix_filter(fix_filter)
+            .with_verify_fixes(!fix_options.no_verify_fixes)
             .with_report_unused_directives(report_unused_directives);
 
+        #[cfg(feature = "debug_rule")]
+        if let Some((rule_name, file)) = debug_rule {
+            return crate::mode::run_debug_rule(&linter, &rule_name, &file, &self.cwd, stdout);
+        }
+
         let number_of_files = files_to_lint.len();
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
@@ -501,29 +532,61 @@ impl CliRunner {
             }
         };
 
+        if !config_warnings.is_empty()
+            && let Ok(source_text) = fs::read_to_string(&config_path)
+        {
+            tx_error
+                .send(DiagnosticService::wrap_diagnostics(
+                    &self.cwd,
+                    &config_path,
+                    &source_text,
+                    config_warnings,
+                ))
+                .unwrap();
+        }
+
         let diff_manager = suppression_manager.build_diff();
 
+        let fix_dry_run_fs = fix_options.fix_dry_run.then(FixDryRunFileSystem::default);
+        let fs: &(dyn oxc_linter::RuntimeFileSystem + Sync + Send) =
+            fix_dry_run_fs.as_ref().map_or(&OsFileSystem, |fs| fs);
+
         let rule_timing_store = debug_timings.then(RuleTimingStore::new);
         let lint_result = if let Some(rule_timing_store) = &rule_timing_store {
             lint_runner.lint_files::<true>(
                 &files_to_lint,
+                fs,
                 tx_error.clone(),
                 &diff_manager,
                 Some(rule_timing_store),
             )
         } else {
-            lint_runner.lint_files::<false>(&files_to_lint, tx_error.clone(), &diff_manager, None)
+            lint_runner.lint_files::<false>(
+                &files_to_lint,
+                fs,
+                tx_error.clone(),
+                &diff_manager,
+                None,
+            )
         };
 
-        match lint_result {
+        let ignore_file_suggestion = match lint_result {
             Ok(lint_runner) => {
                 lint_runner.report_unused_directives(report_unused_directives, &tx_error);
+                inline_config_options.suggest_ignore_files.filter(|_| !type_check_only).and_then(
+                    |threshold| {
+                        let files = lint_runner
+                            .directives_coordinator()
+                            .files_with_disable_directives(threshold);
+                        output_formatter.ignore_file_suggestion(&files, threshold, &cwd)
+                    },
+                )
             }
             Err(err) => {
                 print_and_flush_stdout(stdout, &format!("{err}\n"));
                 return CliRunResult::TsGoLintError;
             }
-        }
+        };
 
         // A suppression file can contain regular lint rules that were not run in type-check-only
         // mode, so its runtime diff is incomplete and cannot be used to validate the baseline.
@@ -538,6 +601,17 @@ impl CliRunner {
 
         let diagnostic_result = diagnostic_service.run(stdout);
 
+        if let Some(fix_dry_run_fs) = fix_dry_run_fs
+            && let Some(fixed_files) =
+                output_formatter.fixed_files(&fix_dry_run_fs.into_fixed_files(), &cwd)
+        {
+            print_and_flush_stdout(stdout, &fixed_files);
+        }
+
+        if let Some(ignore_file_suggestion) = ignore_file_suggestion {
+            print_and_flush_stdout(stdout, &ignore_file_suggestion);
+        }
+
         let oxlint_suppression_file_action = if let Err(report_suppression_error) = result {
             OxlintSuppressionFileAction::UnableToPerformFsOperation(report_suppression_error)
         } else {
@@ -708,6 +782,56 @@ fn render_report(handler: &GraphicalReportHandler, diagnostic: &OxcDiagnostic) -
     err
 }
 
+/// Renders an error which occurred while loading a configuration file, with the parts of the
+/// file it refers to. `show_path` includes the path of the file in the message.
+fn render_config_load_error(
+    handler: &GraphicalReportHandler,
+    cwd: &Path,
+    error: &ConfigLoadError,
+    show_path: bool,
+) -> String {
+    match error {
+        ConfigLoadError::Parse { path, error } => {
+            let report = match fs::read_to_string(path) {
+                Ok(source_text) if !error.labels.is_empty() => {
+                    let mut report = String::new();
+                    for error in
+                        DiagnosticService::wrap_diagnostics(cwd, path, &source_text, vec![error.clone()])
+                    {
+                        handler.render_report(&mut report, error.as_ref()).unwrap();
+                    }
+                    report
+                }
+                _ => render_report(handler, error),
+            };
+            if show_path {
+                format!(
+                    "Failed to parse oxlint configuration file at {}.\n{report}\n",
+                    path.to_string_lossy().cow_replace('\\', "/"),
+                )
+            } else {
+                format!("Failed to parse oxlint configuration file.\n{report}\n")
+            }
+        }
+        ConfigLoadError::Build { path, error } => {
+            format!(
+                "Failed to build configuration from {}.\n{}\n",
+                path.to_string_lossy().cow_replace('\\', "/"),
+                render_report(handler, &OxcDiagnostic::error(error.clone()))
+            )
+        }
+        ConfigLoadError::JsConfigFileFoundButJsRuntimeNotAvailable => {
+            "Error: JavaScript/TypeScript config files found but JS runtime not available.\n\
+             This is an experimental feature that requires running oxlint via Node.js.\n\
+             Please use JSON config files (.oxlintrc.json or .oxlintrc.jsonc) instead, or run oxlint via the npm package.\n".to_string()
+        }
+        ConfigLoadError::Diagnostic(error) => {
+            let report = render_report(handler, error);
+            format!("Failed to parse oxlint configuration file.\n{report}\n")
+        }
+    }
+}
+
 fn render_config_builder_error(
     handler: &GraphicalReportHandler,
     error: ConfigBuilderError,
@@ -723,9 +847,9 @@ fn render_config_builder_error(
 
 #[cfg(test)]
 mod test {
-    use std::fs;
+    use std::{fs, path::Path, process::Command};
 
-    use crate::{DEFAULT_OXLINTRC_NAME, tester::Tester};
+    use crate::{DEFAULT_OXLINTRC_NAME, cli::CliRunResult, tester::Tester};
     use oxc_linter::rules::RULES;
 
     // lints the full directory of fixtures,
@@ -800,6 +924,63 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn changed_files_from() {
+        // `deleted.js` doesn't exist, `ignored.js` is ignored and `notes.md` isn't a JS file,
+        // so only `changed.js` is linted.
+        let args = &["--ignore-pattern", "ignored.js", "--changed-files-from", "changed-files.txt"];
+        Tester::new().with_cwd("fixtures/cli/changed_files".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn changed_since() {
+        let git = |dir: &Path, args: &[&str]| {
+            let status = Command::new("git").args(args).current_dir(dir).status().unwrap();
+            assert!(status.success(), "`git {}` failed", args.join(" "));
+        };
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("changed.js"), "debugger;\n").unwrap();
+        fs::write(dir.join("unchanged.js"), "debugger;\n").unwrap();
+        git(dir, &["init", "--quiet"]);
+        git(dir, &["add", "."]);
+        git(
+            dir,
+            &[
+                "-c",
+                "user.name=oxlint",
+                "-c",
+                "user.email=oxlint@example.com",
+                "-c",
+                "commit.gpgsign=false",
+                "commit",
+                "--quiet",
+                "-m",
+                "initial",
+            ],
+        );
+        fs::write(dir.join("changed.js"), "debugger;\ndebugger;\n").unwrap();
+
+        let args = &["--debug", "files", "--changed-since", "HEAD"];
+        let (output, result) = Tester::new().with_cwd(dir.into()).test_output(args);
+        assert_eq!(output, "changed.js\n");
+        assert!(matches!(result, CliRunResult::LintSucceeded));
+    }
+
+    #[test]
+    fn changed_since_invalid_revision() {
+        let args = &["--changed-since", "this-revision-does-not-exist"];
+        let (output, result) =
+            Tester::new().with_cwd("fixtures/cli/changed_files".into()).test_output(args);
+        // The rest of the message is git's error, which depends on the git version and locale.
+        assert!(
+            output
+                .starts_with("Failed to get files changed since `this-revision-does-not-exist`: "),
+            "{output}"
+        );
+        assert!(matches!(result, CliRunResult::InvalidOptionChangedFiles));
+    }
+
     #[test]
     fn ignore_file_no_ignore() {
         let args = &[
@@ -1178,18 +1359,66 @@ mod test {
     #[test]
     fn test_fix() {
         let tester = Tester::new().with_cwd("fixtures/cli/fix_argument".into());
-        tester.test_fix(
-            "fix.js",
-            "var x = new String('Hello world');\n",
-            "var x = 'Hello world';\n",
-        );
+        tester.test_fix("fix.js", "var x = 1.0;\n", "var x = 1;\n");
         tester.test_fix(
             "fix.vue",
-            "<script>var x = new String('Hello world');</script>\n<script>var y = new String('Hello world');</script>\n",
-            "<script>var x = 'Hello world';</script>\n<script>var y = 'Hello world';</script>\n",
+            "<script>var x = 1.0;</script>\n<script>var y = 1.0;</script>\n",
+            "<script>var x = 1;</script>\n<script>var y = 1;</script>\n",
         );
     }
 
+    #[test]
+    fn test_fix_only_and_except() {
+        let tester = Tester::new().with_cwd("fixtures/cli/fix_filter".into());
+        let before = "import fs from \"fs\";\nvar x = 1.0;\n";
+        let node_protocol_fixed = "import fs from \"node:fs\";\nvar x = 1.0;\n";
+        let zero_fraction_fixed = "import fs from \"fs\";\nvar x = 1;\n";
+        let both_fixed = "import fs from \"node:fs\";\nvar x = 1;\n";
+
+        for (args, after) in [
+            (&["--fix-only", "unicorn/prefer-node-protocol"][..], node_protocol_fixed),
+            (&["--fix-only", "no-zero-fractions"], zero_fraction_fixed),
+            (&["--fix-only", "style"], zero_fraction_fixed),
+            (
+                &["--fix-only", "prefer-node-protocol", "--fix-only", "no-zero-fractions"],
+                both_fixed,
+            ),
+            (&["--fix-except", "prefer-node-protocol"], zero_fraction_fixed),
+            (&["--fix-except", "unicorn/no-zero-fractions"], node_protocol_fixed),
+            (&["--fix-only", "unicorn/no-zero-fractions", "--fix-except", "style"], before),
+            (&["--fix-except", "all"], before),
+        ] {
+            tester.test_fix_with_args("fix.js", before, after, args);
+        }
+    }
+
+    #[test]
+    fn test_fix_only_and_except_report_all_diagnostics() {
+        let tester = Tester::new().with_cwd("fixtures/cli/fix_filter".into());
+        tester.test_and_snapshot_multiple(&[
+            &["--fix-dry-run", "--fix-only", "unicorn/prefer-node-protocol", "dry_run.js"],
+            &["--fix", "--fix-only", "unicorn/prefer-node-protocl", "dry_run.js"],
+            &["--fix", "--fix-except", "no-zero-fraction", "dry_run.js"],
+        ]);
+    }
+
+    #[test]
+    fn test_fix_dry_run() {
+        let tester = Tester::new().with_cwd("fixtures/cli/fix_dry_run".into());
+        tester.test_and_snapshot_multiple(&[
+            &["--fix-dry-run"],
+            &["--fix-dry-run", "--format", "json"],
+            &["--fix-dry-run", "--fix"],
+        ]);
+
+        // Files are left untouched.
+        #[expect(clippy::disallowed_methods)]
+        let content = std::fs::read_to_string("fixtures/cli/fix_dry_run/fix.js")
+            .unwrap()
+            .replace("\r\n", "\n");
+        assert_eq!(content, "var x = 1.0;\ndebugger;\n");
+    }
+
     #[test]
     fn test_fix_skip_suggestion() {
         let tester = Tester::new().with_cwd("fixtures/cli/fix_argument".into());
@@ -1263,6 +1492,21 @@ mod test {
         Tester::new().with_cwd("fixtures/cli/overrides_env_globals".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_overrides_settings() {
+        // `react/no-unsafe` only reports `UNSAFE_` methods from React 16.3, which `legacy/` overrides.
+        let args = &["-c", ".oxlintrc.json", "."];
+        Tester::new().with_cwd("fixtures/cli/overrides_settings".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_overrides_settings_invalid() {
+        let args = &["-c", ".oxlintrc.json", "."];
+        Tester::new()
+            .with_cwd("fixtures/cli/overrides_settings_invalid".into())
+            .test_and_snapshot(args);
+    }
+
     #[test]
     fn test_ignore_patterns() {
         let args = &["-c", "./test/eslintrc.json", "--ignore-pattern", "*.ts", "."];
@@ -1360,6 +1604,41 @@ mod test {
             .test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_suggest_ignore_files() {
+        // `src/generated.js` has 3 disable directives, `handwritten.js` has 1.
+        let args = &["--suggest-ignore-files", "2"];
+
+        Tester::new().with_cwd("fixtures/cli/suggest_ignore_files".into()).test_and_snapshot(args);
+    }
+
+    #[cfg(feature = "debug_rule")]
+    #[test]
+    fn test_debug_rule() {
+        // `no-console` is configured to allow `console.info` in `.oxlintrc.json`.
+        let args = &["--debug-rule", "eslint/no-console", "--debug-file", "console.js"];
+
+        Tester::new().with_cwd("fixtures/cli/debug_rule".into()).test_and_snapshot(args);
+    }
+
+    #[cfg(feature = "debug_rule")]
+    #[test]
+    fn test_debug_rule_without_file() {
+        let args = &["--debug-rule", "no-console"];
+
+        Tester::new().with_cwd("fixtures/cli/debug_rule".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_unsupported_directive_rules_gate() {
+        // Only `unsupported.js` references a rule oxlint doesn't support, and fails the run.
+        let args1 = &["supported.js"];
+        let args2 = &["unsupported.js"];
+        Tester::new()
+            .with_cwd("fixtures/cli/unsupported_directive_rules".into())
+            .test_and_snapshot_multiple(&[args1, args2]);
+    }
+
     #[test]
     fn test_report_unused_directives_from_config() {
         // Verify that `reportUnusedDisableDirectives` in the config file enables reporting
@@ -1824,6 +2103,20 @@ export { redundant };
         Tester::new().with_cwd("fixtures/cli/invalid_config_enum".into()).test_and_snapshot(&[]);
     }
 
+    #[test]
+    fn test_invalid_config_unknown_rule() {
+        Tester::new()
+            .with_cwd("fixtures/cli/invalid_config_unknown_rule".into())
+            .test_and_snapshot(&[]);
+    }
+
+    #[test]
+    fn test_config_unknown_top_level_key() {
+        Tester::new()
+            .with_cwd("fixtures/cli/config_unknown_top_level_key".into())
+            .test_and_snapshot(&[]);
+    }
+
     #[test]
     fn test_invalid_config_invalid_config_extra_options() {
         Tester::new()
@@ -110,7 +110,7 @@ impl ServerLinterBuilder {
         let mut oxlintrc = match loader.load_root_config(&root_path, config_path.as_ref()) {
             Ok(config) => config,
             Err(e) => {
-                warn!("Failed to load config: {e}");
+                warn!("Failed to load config: {e:?}");
                 Oxlintrc::default()
             }
         };
@@ -0,0 +1,36 @@
+use std::{io::Write, path::Path};
+
+use oxc_linter::Linter;
+
+use crate::{cli::CliRunResult, lint::print_and_flush_stdout};
+
+pub fn run_debug_rule(
+    linter: &Linter,
+    rule_name: &str,
+    file: &Path,
+    cwd: &Path,
+    stdout: &mut dyn Write,
+) -> CliRunResult {
+    let path = cwd.join(file);
+    let source_text = match std::fs::read_to_string(&path) {
+        Ok(source_text) => source_text,
+        Err(err) => {
+            print_and_flush_stdout(
+                stdout,
+                &format!("Failed to read {}: {err}\n", file.to_string_lossy()),
+            );
+            return CliRunResult::InvalidOptionDebugRule;
+        }
+    };
+
+    match linter.trace_rule(rule_name, &path, &source_text) {
+        Ok(trace) => {
+            print_and_flush_stdout(stdout, &trace.render(&source_text));
+            CliRunResult::LintSucceeded
+        }
+        Err(err) => {
+            print_and_flush_stdout(stdout, &format!("{err}\n"));
+            CliRunResult::InvalidOptionDebugRule
+        }
+    }
+}
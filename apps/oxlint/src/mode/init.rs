@@ -13,6 +13,18 @@ pub fn run_init(cwd: &Path, stdout: &mut dyn std::io::Write) -> CliRunResult {
     config.insert("categories".to_string(), json!({ "correctness": "error" }));
     config.insert("rules".to_string(), json!({}));
     config.insert("env".to_string(), json!({ "builtin": true }));
+    // Declaration files reference each other with triple-slash directives, and use `export {}`
+    // to turn global declarations into module augmentations.
+    config.insert(
+        "overrides".to_string(),
+        json!([{
+            "files": ["**/*.d.ts"],
+            "rules": {
+                "typescript/triple-slash-reference": "off",
+                "unicorn/require-module-specifiers": "off"
+            }
+        }]),
+    );
 
     let configuration = serde_json::to_string_pretty(&serde_json::Value::Object(config)).unwrap();
 
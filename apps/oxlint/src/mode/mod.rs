@@ -1,9 +1,15 @@
 mod debug_files;
+#[cfg(feature = "debug_rule")]
+mod debug_rule;
 mod init;
 mod print_config;
+mod print_schema;
 mod rules;
 
 pub use debug_files::run_debug_files;
+#[cfg(feature = "debug_rule")]
+pub use debug_rule::run_debug_rule;
 pub use init::run_init;
 pub use print_config::run_print_config;
+pub use print_schema::run_print_schema;
 pub use rules::run_rules;
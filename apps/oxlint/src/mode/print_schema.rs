@@ -0,0 +1,27 @@
+use oxc_linter::Oxlintrc;
+use schemars::r#gen::SchemaSettings;
+use serde_json::Value;
+
+use crate::{cli::CliRunResult, lint::print_and_flush_stdout};
+
+/// Prints the JSON schema of the configuration file, for editors to validate and autocomplete
+/// `.oxlintrc.json` files with.
+pub fn run_print_schema(stdout: &mut dyn std::io::Write) -> CliRunResult {
+    let generator = SchemaSettings::draft07()
+        .with(|s| {
+            // `Option<T>` fields are already optional (not in `required`),
+            // so adding `null` to the type is unnecessary.
+            s.option_add_null_type = false;
+        })
+        .into_generator();
+    let mut schema = generator.into_root_schema_for::<Oxlintrc>();
+
+    // Configuration files are JSONC.
+    schema.schema.extensions.insert("allowComments".to_string(), Value::Bool(true));
+    schema.schema.extensions.insert("allowTrailingCommas".to_string(), Value::Bool(true));
+
+    print_and_flush_stdout(stdout, &serde_json::to_string_pretty(&schema).unwrap());
+    print_and_flush_stdout(stdout, "\n");
+
+    CliRunResult::PrintConfigResult
+}
@@ -1,6 +1,14 @@
-use std::fmt::Write;
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use cow_utils::CowUtils;
 
-use crate::output_formatter::InternalFormatter;
+use crate::{
+    fix_dry_run::FixedFile,
+    output_formatter::{InternalFormatter, diff::unified_diff},
+};
 use oxc_diagnostics::{
     Error, GraphicalReportHandler,
     reporter::{DiagnosticReporter, DiagnosticResult},
@@ -37,6 +45,35 @@ impl InternalFormatter for DefaultOutputFormatter {
         Some(output)
     }
 
+    fn fixed_files(&self, fixed_files: &[FixedFile], cwd: &Path) -> Option<String> {
+        let output = fixed_files
+            .iter()
+            .map(|file| unified_diff(&file.relative_path(cwd), &file.source_text, &file.fixed_code))
+            .collect::<String>();
+        (!output.is_empty()).then_some(output)
+    }
+
+    fn ignore_file_suggestion(
+        &self,
+        files: &[(PathBuf, usize)],
+        threshold: usize,
+        cwd: &Path,
+    ) -> Option<String> {
+        if files.is_empty() {
+            return None;
+        }
+
+        let mut output = format!(
+            "# Files with {threshold} or more disable directives. Consider adding them to `.oxlintignore`,\n# or to `ignorePatterns` in the oxlint config.\n"
+        );
+        for (path, count) in files {
+            let path = path.strip_prefix(cwd).unwrap_or(path);
+            writeln!(output, "# {count} disable directives").unwrap();
+            writeln!(output, "{}", path.to_string_lossy().cow_replace('\\', "/")).unwrap();
+        }
+        Some(output)
+    }
+
     #[cfg(not(any(test, feature = "testing")))]
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
         Box::new(GraphicalReporter::default())
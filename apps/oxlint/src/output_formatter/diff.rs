@@ -0,0 +1,86 @@
+use similar::TextDiff;
+
+/// Number of unchanged lines shown around each change, as in `git diff`.
+const CONTEXT_LINES: usize = 3;
+
+/// Renders a unified diff between `old` and `new`, the contents of the file at `path`.
+///
+/// Returns an empty string if the contents are equal.
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    if old == new {
+        return String::new();
+    }
+
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(CONTEXT_LINES)
+        .header(&format!("a/{path}"), &format!("b/{path}"))
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use cow_utils::CowUtils;
+
+    use super::unified_diff;
+
+    #[test]
+    fn unchanged() {
+        assert_eq!(unified_diff("test.js", "debugger;\n", "debugger;\n"), "");
+    }
+
+    #[test]
+    fn single_change() {
+        assert_eq!(
+            unified_diff("src/test.js", "var a = 1;\nfoo(a);\n", "let a = 1;\nfoo(a);\n"),
+            "--- a/src/test.js
++++ b/src/test.js
+@@ -1,2 +1,2 @@
+-var a = 1;
++let a = 1;
+ foo(a);
+"
+        );
+    }
+
+    #[test]
+    fn separate_hunks() {
+        let old = (1..=20).map(|i| format!("line{i};")).collect::<Vec<_>>().join("\n") + "\n";
+        let new =
+            old.cow_replace("line2;", "fixed2;").cow_replace("line19;", "fixed19;").into_owned();
+        assert_eq!(
+            unified_diff("test.js", &old, &new),
+            "--- a/test.js
++++ b/test.js
+@@ -1,5 +1,5 @@
+ line1;
+-line2;
++fixed2;
+ line3;
+ line4;
+ line5;
+@@ -16,5 +16,5 @@
+ line16;
+ line17;
+ line18;
+-line19;
++fixed19;
+ line20;
+"
+        );
+    }
+
+    #[test]
+    fn removed_line_and_missing_newline() {
+        assert_eq!(
+            unified_diff("test.js", "debugger;\nfoo();", "foo();"),
+            "--- a/test.js
++++ b/test.js
+@@ -1,2 +1 @@
+-debugger;
+ foo();
+\\ No newline at end of file
+"
+        );
+    }
+}
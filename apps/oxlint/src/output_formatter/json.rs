@@ -1,4 +1,4 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, path::Path, rc::Rc};
 
 use oxc_str::CompactStr;
 
@@ -12,11 +12,13 @@ use oxc_diagnostics::{
 };
 use oxc_linter::{RuleCategory, rules::RULES};
 
-use crate::output_formatter::InternalFormatter;
+use crate::{fix_dry_run::FixedFile, output_formatter::InternalFormatter};
 
 #[derive(Debug, Default)]
 pub struct JsonOutputFormatter {
     reporter: JsonReporterWrapper,
+    /// Serialized `files` field, only set with `--fix-dry-run`.
+    fixed_files: RefCell<Option<String>>,
 }
 
 impl InternalFormatter for JsonOutputFormatter {
@@ -70,14 +72,64 @@ impl InternalFormatter for JsonOutputFormatter {
         Some(serde_json::to_string_pretty(&rules_info).expect("Failed to serialize"))
     }
 
+    fn fixed_files(&self, fixed_files: &[FixedFile], cwd: &Path) -> Option<String> {
+        #[derive(Debug, Serialize)]
+        struct FixedFileJson<'a> {
+            filename: String,
+            /// The fixed source text, like ESLint's `output`.
+            output: &'a str,
+            fixes: Vec<AppliedFixJson>,
+        }
+
+        #[derive(Debug, Serialize)]
+        struct AppliedFixJson {
+            rule: Option<String>,
+            span: SpanJson,
+        }
+
+        #[derive(Debug, Serialize)]
+        struct SpanJson {
+            offset: u32,
+            length: u32,
+        }
+
+        let files = fixed_files
+            .iter()
+            .filter(|file| !file.applied_fixes.is_empty())
+            .map(|file| FixedFileJson {
+                filename: file.relative_path(cwd),
+                output: &file.fixed_code,
+                fixes: file
+                    .applied_fixes
+                    .iter()
+                    .map(|fix| AppliedFixJson {
+                        rule: fix.rule.as_ref().map(oxc_linter::MessageRule::short_canonical_name),
+                        span: SpanJson { offset: fix.span.start, length: fix.span.size() },
+                    })
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+
+        // Written together with the diagnostics by `lint_command_info`.
+        *self.fixed_files.borrow_mut() =
+            Some(serde_json::to_string(&files).expect("Failed to serialize"));
+        None
+    }
+
     fn lint_command_info(&self, lint_command_info: &super::LintCommandInfo) -> Option<String> {
         let diagnostics = self.reporter.0.borrow_mut().render();
         let number_of_rules =
             lint_command_info.number_of_rules.map_or("null".to_string(), |x| x.to_string());
         let start_time = lint_command_info.start_time.as_secs_f64();
+        let fixed_files = self
+            .fixed_files
+            .borrow()
+            .as_ref()
+            .map(|files| format!("\n              \"files\": {files},"))
+            .unwrap_or_default();
 
         Some(format!(
-            r#"{{ "diagnostics": {},
+            r#"{{ "diagnostics": {},{}
               "number_of_files": {},
               "number_of_rules": {},
               "threads_count": {},
@@ -85,6 +137,7 @@ impl InternalFormatter for JsonOutputFormatter {
             }}
             "#,
             diagnostics,
+            fixed_files,
             lint_command_info.number_of_files,
             number_of_rules,
             lint_command_info.threads_count,
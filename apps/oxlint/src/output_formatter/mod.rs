@@ -1,6 +1,7 @@
 mod agent;
 mod checkstyle;
 mod default;
+mod diff;
 mod github;
 mod gitlab;
 mod json;
@@ -10,6 +11,7 @@ mod stylish;
 mod unix;
 mod xml_utils;
 
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -26,7 +28,10 @@ use unix::UnixOutputFormatter;
 
 use oxc_diagnostics::reporter::DiagnosticReporter;
 
-use crate::output_formatter::{default::DefaultOutputFormatter, json::JsonOutputFormatter};
+use crate::{
+    fix_dry_run::FixedFile,
+    output_formatter::{default::DefaultOutputFormatter, json::JsonOutputFormatter},
+};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum OutputFormat {
@@ -139,6 +144,23 @@ trait InternalFormatter {
         None
     }
 
+    /// With `--fix-dry-run`, report the files which would have been fixed, before
+    /// [`InternalFormatter::lint_command_info`] is called.
+    fn fixed_files(&self, _fixed_files: &[FixedFile], _cwd: &Path) -> Option<String> {
+        None
+    }
+
+    /// With `--suggest-ignore-files`, list the files with at least `threshold` disable
+    /// directive comments, in `.oxlintignore` format.
+    fn ignore_file_suggestion(
+        &self,
+        _files: &[(PathBuf, usize)],
+        _threshold: usize,
+        _cwd: &Path,
+    ) -> Option<String> {
+        None
+    }
+
     /// oxlint words with [`DiagnosticService`](oxc_diagnostics::DiagnosticService),
     /// which uses a own reporter to output to stdout.
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter>;
@@ -179,6 +201,23 @@ impl OutputFormatter {
         self.internal.lint_command_info(lint_command_info)
     }
 
+    /// Report the files which `--fix-dry-run` would have fixed.
+    /// See [`InternalFormatter::fixed_files`] for more details.
+    pub fn fixed_files(&self, fixed_files: &[FixedFile], cwd: &Path) -> Option<String> {
+        self.internal.fixed_files(fixed_files, cwd)
+    }
+
+    /// List the files which rely on many disable directives.
+    /// See [`InternalFormatter::ignore_file_suggestion`] for more details.
+    pub fn ignore_file_suggestion(
+        &self,
+        files: &[(PathBuf, usize)],
+        threshold: usize,
+        cwd: &Path,
+    ) -> Option<String> {
+        self.internal.ignore_file_suggestion(files, threshold, cwd)
+    }
+
     /// Returns the [`DiagnosticReporter`] which then will be used by [`DiagnosticService`](oxc_diagnostics::DiagnosticService)
     /// See [`InternalFormatter::get_diagnostic_reporter`] for more details.
     pub fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
@@ -12,6 +12,10 @@ pub enum CliRunResult {
     InvalidOptionSeverityWithoutFilter,
     InvalidOptionSeverityWithoutPluginName,
     InvalidOptionSeverityWithoutRuleName,
+    InvalidOptionChangedFiles,
+    InvalidOptionFixDryRunWithFix,
+    InvalidOptionFixFilter,
+    InvalidOptionDebugRule,
     LintSucceeded,
     LintFoundErrors,
     LintUnprunedSuppressions,
@@ -45,6 +49,10 @@ impl Termination for CliRunResult {
             | Self::InvalidOptionSeverityWithoutFilter
             | Self::InvalidOptionSeverityWithoutPluginName
             | Self::InvalidOptionSeverityWithoutRuleName
+            | Self::InvalidOptionChangedFiles
+            | Self::InvalidOptionFixDryRunWithFix
+            | Self::InvalidOptionFixFilter
+            | Self::InvalidOptionDebugRule
             | Self::LintUnprunedSuppressions
             | Self::TsGoLintError => ExitCode::FAILURE,
         }
@@ -400,6 +400,19 @@ pub fn get_new_expr_ident_name<'a>(new_expr: &'a NewExpression<'a>) -> Option<&'
     Some(ident.name.as_str())
 }
 
+/// Whether `new_expr` constructs the global `name`, e.g. `new URL(...)` when `URL` is not
+/// shadowed by a local binding.
+pub fn is_global_new_expression(
+    new_expr: &NewExpression,
+    name: &str,
+    semantic: &Semantic<'_>,
+) -> bool {
+    let Expression::Identifier(ident) = new_expr.callee.without_parentheses() else {
+        return false;
+    };
+    ident.name == name && ident.is_global_reference(semantic.scoping())
+}
+
 pub fn is_global_require_call(call_expr: &CallExpression, ctx: &Semantic) -> bool {
     if call_expr.arguments.len() != 1 {
         return false;
@@ -507,12 +520,19 @@ pub fn get_preceding_indent_str(source_text: &str, span: Span) -> Option<&str> {
     preceding_source_text.lines().last().filter(|&line| line.trim().is_empty())
 }
 
-pub fn could_be_error(ctx: &LintContext, expr: &Expression) -> bool {
-    could_be_error_impl(ctx, expr, &mut FxHashSet::default())
+/// Whether `expr` could evaluate to an `Error` object, i.e. whether it is not definitely a
+/// non-Error value such as a literal, an object or array expression, or a template literal.
+///
+/// Without type information, values which can't be determined statically, like calls, member
+/// expressions, `new` expressions, awaited values and unknown identifiers, could be errors.
+/// Identifiers declared in the file are followed to the values assigned to them. Both branches of
+/// conditional expressions are checked.
+pub fn could_be_error(semantic: &Semantic, expr: &Expression) -> bool {
+    could_be_error_impl(semantic, expr, &mut FxHashSet::default())
 }
 
 fn could_be_error_impl(
-    ctx: &LintContext,
+    semantic: &Semantic,
     expr: &Expression,
     visited: &mut FxHashSet<SymbolId>,
 ) -> bool {
@@ -529,7 +549,7 @@ fn could_be_error_impl(
         Expression::AssignmentExpression(expr) => {
             if matches!(expr.operator, AssignmentOperator::Assign | AssignmentOperator::LogicalAnd)
             {
-                return could_be_error_impl(ctx, &expr.right, visited);
+                return could_be_error_impl(semantic, &expr.right, visited);
             }
 
             if matches!(
@@ -539,29 +559,29 @@ fn could_be_error_impl(
                 return expr
                     .left
                     .get_expression()
-                    .is_none_or(|expr| could_be_error_impl(ctx, expr, visited))
-                    || could_be_error_impl(ctx, &expr.right, visited);
+                    .is_none_or(|expr| could_be_error_impl(semantic, expr, visited))
+                    || could_be_error_impl(semantic, &expr.right, visited);
             }
 
             false
         }
         Expression::SequenceExpression(expr) => {
-            expr.expressions.last().is_some_and(|expr| could_be_error_impl(ctx, expr, visited))
+            expr.expressions.last().is_some_and(|expr| could_be_error_impl(semantic, expr, visited))
         }
         Expression::LogicalExpression(expr) => {
             if matches!(expr.operator, LogicalOperator::And) {
-                return could_be_error_impl(ctx, &expr.right, visited);
+                return could_be_error_impl(semantic, &expr.right, visited);
             }
 
-            could_be_error_impl(ctx, &expr.left, visited)
-                || could_be_error_impl(ctx, &expr.right, visited)
+            could_be_error_impl(semantic, &expr.left, visited)
+                || could_be_error_impl(semantic, &expr.right, visited)
         }
         Expression::ConditionalExpression(expr) => {
-            could_be_error_impl(ctx, &expr.consequent, visited)
-                || could_be_error_impl(ctx, &expr.alternate, visited)
+            could_be_error_impl(semantic, &expr.consequent, visited)
+                || could_be_error_impl(semantic, &expr.alternate, visited)
         }
         Expression::Identifier(ident) => {
-            let reference = ctx.scoping().get_reference(ident.reference_id());
+            let reference = semantic.scoping().get_reference(ident.reference_id());
             let Some(symbol_id) = reference.symbol_id() else {
                 return true;
             };
@@ -572,29 +592,29 @@ fn could_be_error_impl(
                 return true;
             }
 
-            let decl = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+            let decl = semantic.nodes().get_node(semantic.scoping().symbol_declaration(symbol_id));
             match decl.kind() {
                 AstKind::VariableDeclarator(decl) => {
                     if decl
                         .init
                         .as_ref()
-                        .is_some_and(|init| could_be_error_impl(ctx, init, visited))
+                        .is_some_and(|init| could_be_error_impl(semantic, init, visited))
                     {
                         return true;
                     }
 
-                    ctx.scoping().get_resolved_references(symbol_id).any(|reference| {
+                    semantic.scoping().get_resolved_references(symbol_id).any(|reference| {
                         if !reference.is_write() {
                             return false;
                         }
 
-                        let reference_node = ctx.nodes().get_node(reference.node_id());
+                        let reference_node = semantic.nodes().get_node(reference.node_id());
                         if reference_node.span().end > ident.span.start {
                             return false;
                         }
 
                         let AstKind::AssignmentExpression(assignment) =
-                            ctx.nodes().parent_kind(reference.node_id())
+                            semantic.nodes().parent_kind(reference.node_id())
                         else {
                             return false;
                         };
@@ -605,7 +625,7 @@ fn could_be_error_impl(
                                 AssignmentTarget::AssignmentTargetIdentifier(target)
                                     if target.span == reference_node.span()
                             )
-                            && could_be_error_impl(ctx, &assignment.right, visited)
+                            && could_be_error_impl(semantic, &assignment.right, visited)
                     })
                 }
                 AstKind::Function(_)
@@ -1098,9 +1118,10 @@ pub fn could_be_asi_hazard(node: &AstNode, ctx: &LintContext) -> bool {
     }
 
     let source_text = ctx.source_text();
-    let last_char = find_last_meaningful_char(source_text, expr_stmt_span.start, ctx);
+    let last_char =
+        find_last_meaningful_char(source_text, expr_stmt_span.start, ctx.semantic().comments());
 
-    let Some(last_char) = last_char else {
+    let Some((_, last_char)) = last_char else {
         return false;
     };
 
@@ -1111,16 +1132,169 @@ pub fn could_be_asi_hazard(node: &AstNode, ctx: &LintContext) -> bool {
         || last_char == '$'
 }
 
+/// Checks whether `node` is the leftmost part of an `ExpressionStatement`, e.g. `foo` in
+/// `foo.bar();`, but not in `(foo).bar();`.
+///
+/// Arrow function expression bodies are represented as an `ExpressionStatement`, so this is
+/// also `true` for `foo` in `() => foo.bar`.
+pub fn is_start_of_expression_statement(node: &AstNode, nodes: &AstNodes) -> bool {
+    let start = node.span().start;
+    for ancestor in nodes.ancestors(node.id()) {
+        if ancestor.span().start != start {
+            return false;
+        }
+        if matches!(ancestor.kind(), AstKind::ExpressionStatement(_)) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Determines whether a semicolon must be inserted before `node` when it is replaced by code
+/// starting with `(` or `[`, so that the replacement isn't parsed as a continuation of the
+/// previous statement.
+///
+/// This is a port of ESLint's `needsPrecedingSemicolon`, which looks at the token before
+/// `node`, and should only be used when `node` is at the start of an `ExpressionStatement`
+/// (see [`is_start_of_expression_statement`]). Unlike [`could_be_asi_hazard`], it knows about
+/// tokens which end a statement, such as the `}` of a block or the `)` of an `if` header.
+pub fn needs_preceding_semicolon(semantic: &Semantic, node: &AstNode) -> bool {
+    let source_text = semantic.source_text();
+    let Some((prev_pos, prev_char)) =
+        find_last_meaningful_char(source_text, node.span().start, semantic.comments())
+    else {
+        return false;
+    };
+    let prev_pos_usize = prev_pos as usize;
+    let before_prev = source_text[..prev_pos_usize].chars().next_back();
+
+    match prev_char {
+        ':' | ';' | '{' => return false,
+        // `=>`, `++`, `--`
+        '>' if before_prev == Some('=') => return false,
+        '+' | '-' if before_prev == Some(prev_char) => return false,
+        _ => {}
+    }
+
+    let Some(prev_node) = node_containing_offset(semantic.nodes(), node, prev_pos) else {
+        return true;
+    };
+
+    match prev_char {
+        // A `)` that isn't part of an expression closes a statement header, e.g. `if (a)`.
+        ')' => !matches!(
+            prev_node.kind(),
+            AstKind::IfStatement(_)
+                | AstKind::ForStatement(_)
+                | AstKind::ForInStatement(_)
+                | AstKind::ForOfStatement(_)
+                | AstKind::WhileStatement(_)
+                | AstKind::DoWhileStatement(_)
+                | AstKind::WithStatement(_)
+        ),
+        // A `}` only continues an expression if it ends a function or class expression, or an
+        // object literal.
+        '}' => match prev_node.kind() {
+            AstKind::FunctionBody(_) => {
+                let parent = semantic.nodes().parent_node(prev_node.id());
+                matches!(parent.kind(), AstKind::Function(func) if func.is_expression())
+                    && !matches!(
+                        semantic.nodes().parent_kind(parent.id()),
+                        AstKind::MethodDefinition(_)
+                    )
+            }
+            AstKind::ClassBody(_) => matches!(
+                semantic.nodes().parent_kind(prev_node.id()),
+                AstKind::Class(class) if class.is_expression()
+            ),
+            AstKind::ObjectExpression(_) => true,
+            _ => false,
+        },
+        // The module specifier at the end of an import or export declaration.
+        '\'' | '"' => !matches!(
+            semantic.nodes().parent_kind(prev_node.id()),
+            AstKind::ImportDeclaration(_)
+                | AstKind::ExportAllDeclaration(_)
+                | AstKind::ExportNamedDeclaration(_)
+        ),
+        c if is_identifier_part_char(c) => {
+            // The label of `break foo` or `continue foo`.
+            if matches!(
+                semantic.nodes().parent_kind(prev_node.id()),
+                AstKind::BreakStatement(_) | AstKind::ContinueStatement(_)
+            ) {
+                return false;
+            }
+            // The type in `foo as Bar` or `foo satisfies Bar` can't be continued by `(` or `[`.
+            let prev_end = prev_pos_usize + prev_char.len_utf8();
+            if semantic
+                .nodes()
+                .ancestors(prev_node.id())
+                .take_while(|ancestor| ancestor.span().end as usize == prev_end)
+                .any(|ancestor| {
+                    matches!(
+                        ancestor.kind(),
+                        AstKind::TSAsExpression(_) | AstKind::TSSatisfiesExpression(_)
+                    )
+                })
+            {
+                return false;
+            }
+            let word_start = source_text[..prev_pos_usize]
+                .char_indices()
+                .rev()
+                .take_while(|&(_, c)| is_identifier_part_char(c))
+                .last()
+                .map_or(prev_pos_usize, |(i, _)| i);
+            let word = &source_text[word_start..prev_end];
+            !matches!(
+                (word, prev_node.kind()),
+                ("break", AstKind::BreakStatement(_))
+                    | ("continue", AstKind::ContinueStatement(_))
+                    | ("debugger", AstKind::DebuggerStatement(_))
+                    | ("do", AstKind::DoWhileStatement(_))
+                    | ("else", AstKind::IfStatement(_))
+                    | ("return", AstKind::ReturnStatement(_))
+                    | ("yield", AstKind::YieldExpression(_))
+            )
+        }
+        _ => true,
+    }
+}
+
+fn is_identifier_part_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Find the innermost node containing `offset`, which must be before the start of `node`.
+///
+/// Nodes are stored in source order with parents before their children, so the innermost node
+/// containing `offset` is the last one before `node` whose span contains it.
+fn node_containing_offset<'a, 'n>(
+    nodes: &'n AstNodes<'a>,
+    node: &AstNode<'a>,
+    offset: u32,
+) -> Option<&'n AstNode<'a>> {
+    (0..node.id().index())
+        .rev()
+        .map(|id| nodes.get_node(NodeId::from_usize(id)))
+        .find(|node| node.span().start <= offset && offset < node.span().end)
+}
+
 #[inline]
 #[expect(clippy::cast_possible_wrap)]
 fn is_utf8_char_boundary(b: u8) -> bool {
     (b as i8) >= -0x40
 }
 
-/// Find the last meaningful (non-whitespace, non-comment) character before `end_pos`.
-fn find_last_meaningful_char(source_text: &str, end_pos: u32, ctx: &LintContext) -> Option<char> {
+/// Find the last meaningful (non-whitespace, non-comment) character before `end_pos`, and the
+/// offset it starts at.
+fn find_last_meaningful_char(
+    source_text: &str,
+    end_pos: u32,
+    comments: &[Comment],
+) -> Option<(u32, char)> {
     let bytes = source_text.as_bytes();
-    let comments = ctx.semantic().comments();
 
     let mut comment_idx = comments.partition_point(|c| c.span.start < end_pos);
     let mut current_comment_end: u32 = 0;
@@ -1168,7 +1342,7 @@ fn find_last_meaningful_char(source_text: &str, end_pos: u32, ctx: &LintContext)
         }
 
         if byte.is_ascii() {
-            return Some(byte as char);
+            return Some((i, byte as char));
         }
 
         // Multi-byte UTF-8: find the start byte (max 4 bytes per char)
@@ -1183,16 +1357,16 @@ fn find_last_meaningful_char(source_text: &str, end_pos: u32, ctx: &LintContext)
 
         let c = source_text[char_start..].chars().next().unwrap();
 
+        #[expect(clippy::cast_possible_truncation)]
+        let char_start = char_start as u32;
+
         // Skip irregular whitespace (NBSP, ZWNBSP, etc.)
         if is_irregular_whitespace(c) {
-            #[expect(clippy::cast_possible_truncation)]
-            {
-                i = char_start as u32;
-            }
+            i = char_start;
             continue;
         }
 
-        return Some(c);
+        return Some((char_start, c));
     }
 
     None
@@ -1202,3 +1376,150 @@ fn find_last_meaningful_char(source_text: &str, end_pos: u32, ctx: &LintContext)
 fn test_this_use_alphabetization() {
     assert!(METHOD_WHICH_HAS_THIS_ARG.is_sorted());
 }
+
+#[test]
+fn test_needs_preceding_semicolon() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    let cases = [
+        // The previous token continues an expression.
+        ("foo()\nX()", true),
+        ("foo\nX()", true),
+        ("foo.yield\nX()", true),
+        ("var foo = { bar: baz }\nX()", true),
+        ("var foo = function () {}\nX()", true),
+        ("var foo = class {}\nX()", true),
+        ("foo[0]\nX()", true),
+        ("'use strict'\nX()", true),
+        ("foo = 'bar'\nX()", true),
+        ("1\nX()", true),
+        ("<foo />\nX()", true),
+        ("foo() /* comment */\nX()", true),
+        ("foo as Bar<Baz>\nX()", true),
+        ("(function () { Fn\nX() }) as Fn", true),
+        // The previous token ends a statement.
+        ("X()", false),
+        ("/* comment */ X()", false),
+        ("foo();\nX()", false),
+        ("{}\nX()", false),
+        ("{ X() }", false),
+        ("function foo() {}\nX()", false),
+        ("class Foo {}\nX()", false),
+        ("class Foo { foo() {} }\nX()", false),
+        ("foo: X()", false),
+        ("if (a) X()", false),
+        ("if (a); else X()", false),
+        ("for (;;) X()", false),
+        ("for (a in b) X()", false),
+        ("for (a of b) X()", false),
+        ("while (a) X()", false),
+        ("do X(); while (a)", false),
+        ("do {} while (a) X()", false),
+        ("with (a) X()", false),
+        ("foo: while (a) { break foo\nX() }", false),
+        ("foo: while (a) { continue foo\nX() }", false),
+        ("while (a) { break\nX() }", false),
+        ("debugger\nX()", false),
+        ("foo as Bar\nX()", false),
+        ("foo satisfies Bar\nX()", false),
+        ("import foo from 'foo'\nX()", false),
+        ("export * from \"foo\"\nX()", false),
+        ("export { foo } from 'foo'\nX()", false),
+        ("export { foo }\nX()", false),
+        ("a++\nX()", false),
+        ("a--\nX()", false),
+        ("var foo = () => {}\nX()", false),
+        ("() => X()", false),
+        ("function foo() { return\nX() }", false),
+        ("function* foo() { yield\nX() }", false),
+    ];
+
+    for (source, expected) in cases {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::tsx()).parse();
+        assert!(ret.diagnostics.is_empty(), "{source:?} failed to parse");
+        let semantic = SemanticBuilder::new_linter().build(&ret.program).semantic;
+        let node = semantic
+            .nodes()
+            .iter()
+            .find(|node| {
+                matches!(node.kind(), AstKind::CallExpression(call)
+                    if call.callee.is_specific_id("X"))
+            })
+            .unwrap();
+
+        assert!(is_start_of_expression_statement(node, semantic.nodes()), "{source:?}");
+        assert_eq!(needs_preceding_semicolon(&semantic, node), expected, "{source:?}");
+    }
+}
+
+#[test]
+fn test_could_be_error() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    let cases = [
+        // Errors, and values which can't be determined statically.
+        ("throw new Error('foo')", true),
+        ("throw new TypeError", true),
+        ("class MyError extends Error {} throw new MyError()", true),
+        ("throw new Foo()", true),
+        ("throw foo", true),
+        ("throw foo()", true),
+        ("throw foo.bar", true),
+        ("throw foo?.bar", true),
+        ("async function f() { throw await foo }", true),
+        ("async function f() { throw await 'foo' }", true),
+        ("function* f() { throw yield }", true),
+        ("throw foo`bar`", true),
+        ("throw (foo, new Error())", true),
+        ("throw foo ? new Error() : bar", true),
+        ("throw foo || 'bar'", true),
+        ("throw foo = new Error()", true),
+        ("const e = new Error(); throw e", true),
+        ("let e; e = new Error(); throw e", true),
+        ("function f(e) { throw e }", true),
+        ("try {} catch (e) { throw e }", true),
+        // Definitely not errors.
+        ("throw 'foo'", false),
+        ("throw 0", false),
+        ("throw null", false),
+        ("throw `foo ${bar}`", false),
+        ("throw { message: 'foo' }", false),
+        ("throw [new Error()]", false),
+        ("throw 'an ' + new Error()", false),
+        ("throw !foo", false),
+        ("throw (new Error(), 'foo')", false),
+        ("throw foo ? new Error() : 'bar'", true),
+        ("throw foo ? 'bar' : 0", false),
+        ("throw foo && 'bar'", false),
+        ("throw foo += 'bar'", false),
+        ("const e = 'foo'; throw e", false),
+        ("function e() {} throw e", false),
+        ("class E {} throw E", false),
+        ("function f(e: string | number) { throw e }", false),
+        ("throw () => {}", false),
+    ];
+
+    for (source, expected) in cases {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::ts()).parse();
+        assert!(ret.diagnostics.is_empty(), "{source:?} failed to parse");
+        let semantic = SemanticBuilder::new_linter().build(&ret.program).semantic;
+        let argument = semantic
+            .nodes()
+            .iter()
+            .find_map(|node| match node.kind() {
+                AstKind::ThrowStatement(throw) => Some(&throw.argument),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(could_be_error(&semantic, argument), expected, "{source:?}");
+    }
+}
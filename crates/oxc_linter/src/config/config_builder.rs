@@ -380,8 +380,11 @@ impl ConfigStoreBuilder {
         self.config.options.report_unused_disable_directives
     }
 
-    #[cfg(test)]
-    pub(crate) fn with_rule(mut self, rule: RuleEnum, severity: AllowWarnDeny) -> Self {
+    /// Enable `rule`, as configured, with the given severity.
+    ///
+    /// This is for rules configured programmatically, e.g.
+    /// [`OxcNoEslintDisableComments::with_changed_lines`](crate::rules::OxcNoEslintDisableComments::with_changed_lines).
+    pub fn with_rule(mut self, rule: RuleEnum, severity: AllowWarnDeny) -> Self {
         self.rules.insert(rule, severity);
         self
     }
@@ -551,6 +554,7 @@ impl ConfigStoreBuilder {
                     globals: override_config.globals,
                     plugins: override_config.plugins,
                     rules: ResolvedOxlintOverrideRules { builtin_rules, external_rules },
+                    settings: override_config.settings,
                 })
             })
             .collect::<Result<Vec<_>, _>>()?;
@@ -12,7 +12,8 @@ use crate::{
 };
 
 use super::{
-    GlobSet, LintConfig, LintPlugins, OxlintEnv, OxlintGlobals, categories::OxlintCategories,
+    GlobSet, LintConfig, LintPlugins, OxlintEnv, OxlintGlobals, OxlintSettings,
+    categories::OxlintCategories,
 };
 
 // TODO: support `categories` et. al. in overrides.
@@ -50,6 +51,7 @@ pub struct ResolvedOxlintOverride {
     pub globals: Option<OxlintGlobals>,
     pub plugins: Option<LintPlugins>,
     pub rules: ResolvedOxlintOverrideRules,
+    pub settings: Option<OxlintSettings>,
 }
 
 #[derive(Debug, Clone)]
@@ -154,7 +156,7 @@ impl Config {
         let mut env = self.base.config.env.clone();
         let mut globals = self.base.config.globals.clone();
         let mut plugins = self.base.config.plugins;
-        let settings = self.base.config.settings.clone();
+        let mut settings = self.base.config.settings.clone();
 
         for override_config in overrides_to_apply.clone() {
             if let Some(override_plugins) = override_config.plugins {
@@ -247,6 +249,10 @@ impl Config {
             if let Some(override_globals) = &override_config.globals {
                 override_globals.override_globals(&mut globals);
             }
+
+            if let Some(override_settings) = &override_config.settings {
+                override_settings.override_settings(&mut settings);
+            }
         }
 
         let config: Arc<LintConfig> = if plugins == self.base.config.plugins
@@ -406,7 +412,21 @@ impl ConfigStore {
     // NOTE: This function is not crate visible because it is used in `oxlint` as well to resolve configs
     // for the `tsgolint` linter.
     pub fn resolve(&self, path: &Path) -> ResolvedLinterState {
-        Config::apply_overrides(self.get_related_config(path), path)
+        let resolved = Config::apply_overrides(self.get_related_config(path), path);
+        // Later configs and overrides replace the configuration of a rule, so each rule must be
+        // run at most once per file.
+        #[cfg(debug_assertions)]
+        {
+            let mut seen = FxHashSet::default();
+            let duplicate = resolved.rules.iter().find(|(rule, _)| !seen.insert(rule.id()));
+            debug_assert!(
+                duplicate.is_none(),
+                "{:?} was resolved more than once for {}",
+                duplicate.map(|(rule, _)| rule.name()),
+                path.display()
+            );
+        }
+        resolved
     }
 
     fn get_nearest_config(&self, path: &Path) -> Option<&Config> {
@@ -482,6 +502,7 @@ mod test {
             exclude_files: GlobSet::default(),
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
         let store = ConfigStore::new(
@@ -520,6 +541,7 @@ mod test {
                     | LintPlugins::JSX_A11Y,
             ),
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
         let store = ConfigStore::new(
@@ -551,6 +573,7 @@ mod test {
             exclude_files: GlobSet::default(),
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules {
                 builtin_rules: vec![(
                     RuleEnum::TypescriptNoExplicitAny(TypescriptNoExplicitAny::default()),
@@ -589,6 +612,7 @@ mod test {
             exclude_files: GlobSet::default(),
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules {
                 builtin_rules: vec![(
                     RuleEnum::EslintNoUnusedVars(EslintNoUnusedVars::default()),
@@ -625,6 +649,7 @@ mod test {
             files: GlobSet::new(vec!["src/**/*.{ts,tsx}"]),
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules {
                 builtin_rules: vec![
                     (
@@ -668,6 +693,7 @@ mod test {
                 files: GlobSet::new(vec!["*.ts"]),
                 plugins: None,
                 globals: None,
+                settings: None,
                 rules: ResolvedOxlintOverrideRules {
                     builtin_rules: vec![
                         (RuleEnum::EslintCurly(EslintCurly::default()), AllowWarnDeny::Warn),
@@ -685,6 +711,7 @@ mod test {
                 files: GlobSet::new(vec!["*.tsx"]),
                 plugins: None,
                 globals: None,
+                settings: None,
                 rules: ResolvedOxlintOverrideRules {
                     builtin_rules: vec![(
                         RuleEnum::EslintNoUnusedVars(EslintNoUnusedVars::default()),
@@ -718,6 +745,7 @@ mod test {
             files: GlobSet::new(vec!["*.ts"]),
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules {
                 builtin_rules: vec![(
                     RuleEnum::TypescriptNoExplicitAny(TypescriptNoExplicitAny::default()),
@@ -753,6 +781,7 @@ mod test {
             exclude_files: GlobSet::default(),
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules {
                 builtin_rules: vec![(
                     RuleEnum::TypescriptNoExplicitAny(TypescriptNoExplicitAny::default()),
@@ -794,6 +823,7 @@ mod test {
                 exclude_files: GlobSet::default(),
                 plugins: Some(LintPlugins::REACT),
                 globals: None,
+                settings: None,
                 rules: ResolvedOxlintOverrideRules {
                     builtin_rules: vec![],
                     external_rules: vec![],
@@ -805,6 +835,7 @@ mod test {
                 exclude_files: GlobSet::default(),
                 plugins: Some(LintPlugins::TYPESCRIPT),
                 globals: None,
+                settings: None,
                 rules: ResolvedOxlintOverrideRules {
                     builtin_rules: vec![],
                     external_rules: vec![],
@@ -842,6 +873,7 @@ mod test {
             exclude_files: GlobSet::default(),
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
 
@@ -866,6 +898,7 @@ mod test {
             env: Some(from_json!({ "es2024": false })),
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
 
@@ -890,6 +923,7 @@ mod test {
             env: None,
             plugins: None,
             globals: Some(from_json!({ "React": "readonly", "Secret": "writable" })),
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
 
@@ -929,6 +963,7 @@ mod test {
             env: None,
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
 
@@ -967,6 +1002,7 @@ mod test {
             env: None,
             plugins: None,
             globals: Some(from_json!({ "React": "off", "Secret": "off" })),
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
 
@@ -983,6 +1019,69 @@ mod test {
         assert!(!app.globals.is_enabled("Secret"));
     }
 
+    #[test]
+    fn test_override_settings() {
+        let base_config = LintConfig {
+            plugins: LintPlugins::REACT,
+            settings: from_json!({
+                "react": {
+                    "version": "18.2.0",
+                    "linkComponents": ["Hyperlink"]
+                }
+            }),
+            ..Default::default()
+        };
+
+        let override_for = |dir: &str, settings: OxlintSettings| ResolvedOxlintOverride {
+            files: GlobSet::new(vec![format!("{dir}/**")]),
+            exclude_files: GlobSet::default(),
+            env: None,
+            plugins: None,
+            globals: None,
+            settings: Some(settings),
+            rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+        };
+        let overrides = ResolvedOxlintOverrides::new(vec![
+            override_for("legacy", from_json!({ "react": { "version": "16.2.0" } })),
+            override_for(
+                "legacy/hooks",
+                from_json!({ "react-hooks": { "additionalEffectHooks": "useMyEffect" } }),
+            ),
+        ]);
+
+        let store = ConfigStore::new(
+            Config::new(vec![], vec![], OxlintCategories::default(), base_config, overrides),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        let app = store.resolve("app/App.jsx".as_ref()).config;
+        assert_eq!(app.settings.react.version.unwrap().to_string(), "18.2.0");
+        assert!(std::sync::Arc::ptr_eq(&app, &store.base.base.config));
+
+        let legacy = store.resolve("legacy/App.jsx".as_ref()).config;
+        assert_eq!(legacy.settings.react.version.unwrap().to_string(), "16.2.0");
+        // Settings which the override doesn't change are kept.
+        assert!(legacy.settings.react.get_link_component_attrs("Hyperlink").is_some());
+        assert!(legacy.settings.react_hooks.additional_effect_hooks.is_none());
+
+        let hooks = store.resolve("legacy/hooks/App.jsx".as_ref()).config;
+        assert_eq!(hooks.settings.react.version.unwrap().to_string(), "16.2.0");
+        assert_eq!(
+            hooks
+                .settings
+                .react_hooks
+                .additional_effect_hooks
+                .as_ref()
+                .map(lazy_regex::Regex::as_str),
+            Some("useMyEffect")
+        );
+        assert_eq!(
+            hooks.settings.json.as_ref().unwrap()["react"]["linkComponents"],
+            serde_json::json!(["Hyperlink"])
+        );
+    }
+
     #[test]
     fn test_override_rule_not_reset_by_later_override_with_different_plugins() {
         // This test reproduces the issue from https://github.com/oxc-project/oxc/issues/12859
@@ -1012,6 +1111,7 @@ mod test {
                 exclude_files: GlobSet::default(),
                 plugins: Some(LintPlugins::TYPESCRIPT),
                 globals: None,
+                settings: None,
                 rules: ResolvedOxlintOverrideRules {
                     builtin_rules: vec![],
                     external_rules: vec![],
@@ -1024,6 +1124,7 @@ mod test {
                 exclude_files: GlobSet::default(),
                 plugins: Some(LintPlugins::REACT),
                 globals: None,
+                settings: None,
                 rules: ResolvedOxlintOverrideRules {
                     builtin_rules: vec![(
                         RuleEnum::ReactJsxFilenameExtension(ReactJsxFilenameExtension::default()),
@@ -1039,6 +1140,7 @@ mod test {
                 exclude_files: GlobSet::default(),
                 plugins: Some(LintPlugins::UNICORN),
                 globals: None,
+                settings: None,
                 rules: ResolvedOxlintOverrideRules {
                     builtin_rules: vec![],
                     external_rules: vec![],
@@ -1101,6 +1203,7 @@ mod test {
             exclude_files: GlobSet::default(),
             plugins: Some(LintPlugins::REACT),
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
 
@@ -1135,6 +1238,7 @@ mod test {
             exclude_files: GlobSet::default(),
             plugins: None,
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules {
                 builtin_rules: vec![(
                     RuleEnum::EslintNoUnusedVars(override_rule),
@@ -1215,6 +1319,7 @@ mod test {
             exclude_files: GlobSet::default(),
             plugins: Some(LintPlugins::TYPESCRIPT),
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
 
@@ -1331,6 +1436,7 @@ mod test {
                 exclude_files: GlobSet::default(),
                 env: None,
                 globals: None,
+                settings: None,
                 plugins: None,
                 // Override redefines the same rule with options B and severity error
                 rules: ResolvedOxlintOverrideRules {
@@ -1583,6 +1689,7 @@ mod test {
             exclude_files: GlobSet::default(),
             plugins: Some(LintPlugins::IMPORT),
             globals: None,
+            settings: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
         }]);
 
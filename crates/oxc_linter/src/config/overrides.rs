@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 
 use oxc_config::GlobSet;
 
-use crate::{LintPlugins, OxlintEnv, OxlintGlobals, config::OxlintRules};
+use crate::{LintPlugins, OxlintEnv, OxlintGlobals, OxlintSettings, config::OxlintRules};
 
 use super::external_plugins::{ExternalPluginEntry, external_plugins_schema};
 
@@ -120,6 +120,13 @@ pub struct OxlintOverride {
 
     #[serde(default)]
     pub rules: OxlintRules,
+
+    /// Plugin settings for this override, merged into the base config's settings.
+    ///
+    /// ## Example
+    /// `{ "react": { "version": "16.2.0" } }`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<OxlintSettings>,
 }
 
 #[cfg(test)]
@@ -7,7 +7,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
 
 use crate::{AllowWarnDeny, LintPlugins, utils::read_to_string};
 
@@ -19,6 +19,7 @@ use super::{
     overrides::OxlintOverrides,
     rules::OxlintRules,
     settings::OxlintSettings,
+    validation::{combine_errors, is_top_level_key, validate_oxlintrc},
 };
 
 /// Options for the linter.
@@ -296,6 +297,11 @@ pub struct Oxlintrc {
     #[serde(skip)]
     #[schemars(skip)]
     pub extends_configs: Vec<Oxlintrc>,
+    /// Warnings found while loading the configuration file, e.g. about unknown top-level keys,
+    /// which are ignored.
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub warnings: Vec<OxcDiagnostic>,
 }
 
 impl Oxlintrc {
@@ -315,7 +321,7 @@ impl Oxlintrc {
             OxcDiagnostic::error(format!("Failed to parse jsonc file {}: {err:?}", path.display()))
         })?;
 
-        let json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
+        let mut json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
             let ext = path.extension().and_then(OsStr::to_str);
             let err = match ext {
                 // syntax error
@@ -340,11 +346,22 @@ impl Oxlintrc {
             )));
         }
 
+        let (warnings, errors): (Vec<_>, Vec<_>) = validate_oxlintrc(&json, &string)
+            .into_iter()
+            .partition(|diagnostic| diagnostic.severity == Severity::Warning);
+        if let Some(error) = combine_errors(errors) {
+            return Err(error);
+        }
+        if let Some(object) = json.as_object_mut() {
+            object.retain(|key, _| is_top_level_key(key));
+        }
+
         let mut config = Self::deserialize(&json).map_err(|err| {
             OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
         })?;
 
         config.path = path.to_path_buf();
+        config.warnings = warnings;
 
         #[expect(clippy::missing_panics_doc)]
         let config_dir =
@@ -451,6 +468,7 @@ impl Oxlintrc {
             ignore_patterns: self.ignore_patterns.clone(),
             extends: self.extends.clone(),
             extends_configs: self.extends_configs.clone(),
+            warnings: self.warnings.clone(),
         }
     }
 
@@ -240,7 +240,7 @@ impl OxlintRules {
     }
 }
 
-fn transform_rule_and_plugin_name<'a>(
+pub(super) fn transform_rule_and_plugin_name<'a>(
     rule_name: &'a str,
     plugin_name: &'a str,
 ) -> (&'a str, &'a str) {
@@ -638,7 +638,10 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+/// Splits a rule name as written in configs and directives, e.g. `no-console`,
+/// `@typescript-eslint/no-explicit-any` or `react-hooks/rules-of-hooks`, into the oxlint plugin
+/// name and rule name.
+pub fn parse_rule_key(name: &str) -> (String, String) {
     // For scoped packages (starting with `@`), split at the last `/` to handle
     // packages like `@eslint-react/naming-convention` with rule `rule-name`.
     // For non-scoped packages, split at the first `/`.
@@ -3,6 +3,7 @@ pub mod jsdoc;
 mod jsx_a11y;
 mod next;
 mod react;
+mod react_hooks;
 pub mod vitest;
 
 use schemars::JsonSchema;
@@ -10,7 +11,8 @@ use serde::{Deserialize, Serialize};
 
 use self::{
     jest::JestPluginSettings, jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings,
-    next::NextPluginSettings, react::ReactPluginSettings, vitest::VitestPluginSettings,
+    next::NextPluginSettings, react::ReactPluginSettings, react_hooks::ReactHooksPluginSettings,
+    vitest::VitestPluginSettings,
 };
 
 pub use self::react::ReactVersion;
@@ -56,6 +58,10 @@ pub struct OxlintSettings {
     #[serde(default)]
     pub react: ReactPluginSettings,
 
+    #[serde(default)]
+    #[serde(rename = "react-hooks")]
+    pub react_hooks: ReactHooksPluginSettings,
+
     #[serde(default)]
     pub jsdoc: JSDocPluginSettings,
 
@@ -79,6 +85,10 @@ struct WellKnownOxlintSettings {
     #[serde(default)]
     pub react: ReactPluginSettings,
 
+    #[serde(default)]
+    #[serde(rename = "react-hooks")]
+    pub react_hooks: ReactHooksPluginSettings,
+
     #[serde(default)]
     pub jsdoc: JSDocPluginSettings,
 
@@ -109,6 +119,7 @@ impl<'de> Deserialize<'de> for OxlintSettings {
             jsx_a11y: well_known_settings.jsx_a11y,
             next: well_known_settings.next,
             react: well_known_settings.react,
+            react_hooks: well_known_settings.react_hooks,
             jsdoc: well_known_settings.jsdoc,
             vitest: well_known_settings.vitest,
             jest: well_known_settings.jest,
@@ -117,11 +128,10 @@ impl<'de> Deserialize<'de> for OxlintSettings {
 }
 
 impl OxlintSettings {
-    // Note: We don't merge settings in overrides at present.
-    // So this is dead code, but keeping it for now, as we may want to enable merging settings in the future.
-    #[expect(dead_code)]
-    /// Mutates `settings_to_override` by reading from `self`.
-    fn override_settings(&self, settings_to_override: &mut OxlintSettings) {
+    /// Mutates `settings_to_override` by reading from `self`, the settings of an override.
+    ///
+    /// Settings are deep merged, so an override only needs to specify the settings it changes.
+    pub(crate) fn override_settings(&self, settings_to_override: &mut OxlintSettings) {
         // If `None`, `self` has nothing configured, so we don't need to mutate `settings_to_override` at all.
         if let Some(self_json) = &self.json {
             if let Some(override_json) = &settings_to_override.json {
@@ -134,19 +144,21 @@ impl OxlintSettings {
                         settings_to_override.jsx_a11y = well_known_settings.jsx_a11y;
                         settings_to_override.next = well_known_settings.next;
                         settings_to_override.react = well_known_settings.react;
+                        settings_to_override.react_hooks = well_known_settings.react_hooks;
                         settings_to_override.jsdoc = well_known_settings.jsdoc;
                         settings_to_override.vitest = well_known_settings.vitest;
                         settings_to_override.jest = well_known_settings.jest;
                     }
-                    Err(e) => {
-                        panic!("Failed to parse override settings: {e:?}");
-                    }
+                    // Both settings were valid on their own, so this is unlikely. Prefer the
+                    // settings of the override rather than failing mid-lint.
+                    Err(_) => settings_to_override.clone_from(self),
                 }
             } else {
                 settings_to_override.json = Some(self_json.clone());
                 settings_to_override.jsx_a11y = self.jsx_a11y.clone();
                 settings_to_override.next = self.next.clone();
                 settings_to_override.react = self.react.clone();
+                settings_to_override.react_hooks = self.react_hooks.clone();
                 settings_to_override.jsdoc = self.jsdoc.clone();
                 settings_to_override.vitest = self.vitest.clone();
                 settings_to_override.jest = self.jest.clone();
@@ -380,4 +392,48 @@ mod test {
 
         OxlintSettings::deserialize(&json_value).expect_err("Jest Version cannot be negative");
     }
+
+    #[test]
+    fn test_parse_react_hooks_settings() {
+        let settings = OxlintSettings::deserialize(&serde_json::json!({
+            "react-hooks": {
+                "additionalEffectHooks": "(useMyEffect|useCustomEffect)"
+            }
+        }))
+        .unwrap();
+        let additional_effect_hooks = settings.react_hooks.additional_effect_hooks.unwrap();
+        assert!(additional_effect_hooks.is_match("useMyEffect"));
+        assert!(!additional_effect_hooks.is_match("useEffect"));
+
+        OxlintSettings::deserialize(&serde_json::json!({
+            "react-hooks": {
+                "additionalEffectHooks": "(useMyEffect"
+            }
+        }))
+        .expect_err("additionalEffectHooks must be a valid regex");
+    }
+
+    #[test]
+    fn test_override_settings() {
+        let mut settings = OxlintSettings::deserialize(&serde_json::json!({
+            "react": { "version": "18.2.0", "linkComponents": ["Hyperlink"] },
+            "jsx-a11y": { "polymorphicPropName": "as" }
+        }))
+        .unwrap();
+        let override_settings = OxlintSettings::deserialize(&serde_json::json!({
+            "react": { "version": "16.2.0" }
+        }))
+        .unwrap();
+
+        override_settings.override_settings(&mut settings);
+
+        assert_eq!(settings.react.version.unwrap().to_string(), "16.2.0");
+        assert!(settings.react.get_link_component_attrs("Hyperlink").is_some());
+        assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("as".into()));
+
+        // Overrides without settings change nothing.
+        let before = settings.clone();
+        OxlintSettings::default().override_settings(&mut settings);
+        assert_eq!(settings, before);
+    }
 }
@@ -0,0 +1,42 @@
+use lazy_regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::utils::deserialize_regex_option;
+
+/// Configure React Hooks rules.
+///
+/// Derived from [eslint-plugin-react-hooks](https://github.com/facebook/react/tree/main/packages/eslint-plugin-react-hooks#advanced-configuration)
+#[derive(Debug, Clone, Deserialize, Default, Serialize, JsonSchema)]
+pub struct ReactHooksPluginSettings {
+    /// A regex matching the names of custom Hooks that behave like `useEffect`, in which
+    /// functions created with `useEffectEvent` may be called.
+    ///
+    /// Example:
+    ///
+    /// ```jsonc
+    /// {
+    ///   "settings": {
+    ///     "react-hooks": {
+    ///       "additionalEffectHooks": "(useMyEffect|useCustomEffect)"
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, rename = "additionalEffectHooks")]
+    #[serde(deserialize_with = "deserialize_regex_option", serialize_with = "serialize_regex")]
+    #[schemars(with = "Option<String>")]
+    pub additional_effect_hooks: Option<Regex>,
+}
+
+impl PartialEq for ReactHooksPluginSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.additional_effect_hooks.as_ref().map(Regex::as_str)
+            == other.additional_effect_hooks.as_ref().map(Regex::as_str)
+    }
+}
+
+#[expect(clippy::ref_option, reason = "signature required by `serialize_with`")]
+fn serialize_regex<S: Serializer>(regex: &Option<Regex>, serializer: S) -> Result<S::Ok, S::Error> {
+    regex.as_ref().map(Regex::as_str).serialize(serializer)
+}
@@ -0,0 +1,636 @@
+use std::{fmt, sync::LazyLock};
+
+use cow_utils::CowUtils;
+use schemars::schema_for;
+use serde_json::Value;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Span, min_edit_distance};
+
+use crate::{AllowWarnDeny, LintPlugins, rules::RULES};
+
+use super::{
+    Oxlintrc,
+    rules::{parse_rule_key, transform_rule_and_plugin_name},
+};
+
+/// Maximum number of close matches listed for an unknown key or rule name.
+const MAX_CLOSE_MATCHES: usize = 3;
+
+/// Keys allowed at the top level of a configuration file, taken from the schema of [`Oxlintrc`].
+static TOP_LEVEL_KEYS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    schema_for!(Oxlintrc)
+        .schema
+        .object
+        .map(|object| object.properties.into_keys().collect())
+        .unwrap_or_default()
+});
+
+/// Whether `key` is a known top-level key of a configuration file.
+pub(super) fn is_top_level_key(key: &str) -> bool {
+    TOP_LEVEL_KEYS.iter().any(|known| known == key)
+}
+
+/// A segment of a [`JsonPath`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// The location of a value inside a configuration file, e.g.
+/// `rules.no-unused-vars[1].varsIgnorePattern`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct JsonPath(Vec<PathSegment>);
+
+impl JsonPath {
+    fn key(&self, key: &str) -> Self {
+        let mut path = self.clone();
+        path.0.push(PathSegment::Key(key.to_string()));
+        path
+    }
+
+    fn index(&self, index: usize) -> Self {
+        let mut path = self.clone();
+        path.0.push(PathSegment::Index(index));
+        path
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Key(key) if i == 0 => f.write_str(key)?,
+                PathSegment::Key(key) => write!(f, ".{key}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A problem found in a configuration file, reported at the key or value at `path`.
+struct Problem {
+    path: JsonPath,
+    /// Whether the problem is with the key at `path` rather than its value.
+    at_key: bool,
+    message: String,
+    help: Option<String>,
+    is_warning: bool,
+}
+
+impl Problem {
+    fn error(path: JsonPath, message: String) -> Self {
+        Self { path, at_key: false, message, help: None, is_warning: false }
+    }
+
+    fn warning(path: JsonPath, message: String) -> Self {
+        Self { is_warning: true, ..Self::error(path, message) }
+    }
+
+    fn at_key(mut self) -> Self {
+        self.at_key = true;
+        self
+    }
+
+    fn with_help(mut self, help: Option<String>) -> Self {
+        self.help = help;
+        self
+    }
+
+    fn into_diagnostic(self, source_text: &str) -> OxcDiagnostic {
+        let diagnostic = if self.is_warning {
+            OxcDiagnostic::warn(self.message)
+        } else {
+            OxcDiagnostic::error(self.message)
+        };
+        let diagnostic = match self.help {
+            Some(help) => diagnostic.with_help(help),
+            None => diagnostic,
+        };
+        match locate(source_text, &self.path.0) {
+            Some((key_span, value_span)) => {
+                let span = if self.at_key { key_span.unwrap_or(value_span) } else { value_span };
+                diagnostic.with_label(span.label(self.path.to_string()))
+            }
+            None => diagnostic,
+        }
+    }
+}
+
+/// Checks a configuration file for unknown top-level keys, unknown rules, invalid severities and
+/// rule options the rules don't accept.
+///
+/// `json` is the parsed configuration file and `source_text` the text it was parsed from, with
+/// comments stripped. Each diagnostic is labeled with the JSON path of the problem, at its span
+/// in `source_text`. Unknown top-level keys are warnings, everything else is an error.
+pub(super) fn validate_oxlintrc(json: &Value, source_text: &str) -> Vec<OxcDiagnostic> {
+    let Some(object) = json.as_object() else {
+        return vec![];
+    };
+    let mut problems = vec![];
+    let root = JsonPath::default();
+
+    for key in object.keys().filter(|key| !is_top_level_key(key)) {
+        let help = did_you_mean(key, key, TOP_LEVEL_KEYS.iter());
+        problems.push(
+            Problem::warning(
+                root.key(key),
+                format!("Unknown top-level key `{key}`, it is ignored."),
+            )
+            .at_key()
+            .with_help(help),
+        );
+    }
+
+    if let Some(rules) = object.get("rules") {
+        validate_rules(rules, &root.key("rules"), &mut problems);
+    }
+    if let Some(Value::Array(overrides)) = object.get("overrides") {
+        for (i, r#override) in overrides.iter().enumerate() {
+            if let Some(rules) = r#override.get("rules") {
+                validate_rules(rules, &root.key("overrides").index(i).key("rules"), &mut problems);
+            }
+        }
+    }
+
+    problems.into_iter().map(|problem| problem.into_diagnostic(source_text)).collect()
+}
+
+/// Combines the errors found by [`validate_oxlintrc`] into a single diagnostic, labeled at every
+/// problem.
+pub(super) fn combine_errors(mut errors: Vec<OxcDiagnostic>) -> Option<OxcDiagnostic> {
+    if errors.len() <= 1 {
+        return errors.pop();
+    }
+    let message = errors.iter().map(|error| error.message.as_ref()).collect::<Vec<_>>().join("\n");
+    let help = errors.iter().filter_map(|error| error.help.as_deref()).collect::<Vec<_>>();
+    let labels = errors.iter().flat_map(|error| error.labels.iter().cloned()).collect::<Vec<_>>();
+    let error = OxcDiagnostic::error(message).with_labels(labels);
+    Some(if help.is_empty() { error } else { error.with_help(help.join("\n")) })
+}
+
+fn validate_rules(rules: &Value, path: &JsonPath, problems: &mut Vec<Problem>) {
+    let Some(rules) = rules.as_object() else {
+        return;
+    };
+    for (key, value) in rules {
+        let path = path.key(key);
+
+        let (severity, options) = match value {
+            Value::Array(values) => {
+                let Some((severity, options)) = values.split_first() else {
+                    problems.push(Problem::error(
+                        path,
+                        "Expected a severity, or an array of a severity followed by options."
+                            .to_string(),
+                    ));
+                    continue;
+                };
+                ((severity, path.index(0)), options)
+            }
+            _ => ((value, path.clone()), [].as_slice()),
+        };
+        if let Err(err) = AllowWarnDeny::try_from(severity.0) {
+            problems.push(Problem::error(severity.1, err.to_string()));
+        }
+
+        let (plugin_name, rule_name) = parse_rule_key(key);
+        let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+        // Rules of other plugins, like JS plugins, are checked once the plugins are loaded.
+        if LintPlugins::try_from(plugin_name).is_err() {
+            continue;
+        }
+        let Some(rule) =
+            RULES.iter().find(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+        else {
+            let candidates = RULES.iter().map(|rule| {
+                if rule.plugin_name() == "eslint" {
+                    rule.name().to_string()
+                } else {
+                    format!("{}/{}", rule.plugin_name().cow_replace('_', "-"), rule.name())
+                }
+            });
+            let help = did_you_mean(key, rule_name, candidates);
+            problems.push(
+                Problem::error(path, format!("Unknown rule `{key}`.")).at_key().with_help(help),
+            );
+            continue;
+        };
+
+        if options.is_empty() {
+            continue;
+        }
+        if !rule.has_config() {
+            problems.push(Problem::error(
+                path.index(1),
+                format!("Rule `{key}` does not accept configuration options."),
+            ));
+            continue;
+        }
+        if let Err(err) = rule.from_configuration(Value::Array(options.to_vec())) {
+            let message = err.to_string();
+            let (options_path, at_key) = find_offending_value(options, &message, &path)
+                .unwrap_or_else(|| {
+                    (if options.len() == 1 { path.index(1) } else { path.clone() }, false)
+                });
+            let problem = Problem::error(
+                options_path,
+                format!("Invalid configuration for rule `{key}`: {message}"),
+            );
+            problems.push(if at_key { problem.at_key() } else { problem });
+        }
+    }
+}
+
+/// Finds the value in `options` which a deserialization error `message` refers to, by the
+/// unknown field, unknown variant or unexpected value it names.
+///
+/// `path` is the path of the rule, `options` its values after the severity. Returns the path of
+/// the value, and whether the error is about its key.
+fn find_offending_value(
+    options: &[Value],
+    message: &str,
+    path: &JsonPath,
+) -> Option<(JsonPath, bool)> {
+    let quoted = |prefix: &str| {
+        let rest = message.strip_prefix(prefix)?;
+        let end = rest.find('`')?;
+        Some(&rest[..end])
+    };
+
+    if let Some(field) = quoted("unknown field `") {
+        return find_option(options, path, &mut |_, key| key == Some(field))
+            .map(|path| (path, true));
+    }
+    let expected: Value = if let Some(variant) = quoted("unknown variant `") {
+        Value::String(variant.to_string())
+    } else if let Some(rest) = message.strip_prefix("invalid type: string \"") {
+        let end = rest.find("\", expected")?;
+        Value::String(rest[..end].to_string())
+    } else if let Some(literal) = quoted("invalid type: integer `")
+        .or_else(|| quoted("invalid type: floating point `"))
+        .or_else(|| quoted("invalid type: boolean `"))
+        .or_else(|| quoted("invalid value: integer `"))
+    {
+        serde_json::from_str(literal).ok()?
+    } else if message.starts_with("invalid type: null") {
+        Value::Null
+    } else {
+        return None;
+    };
+    find_option(options, path, &mut |value, _| value == &expected).map(|path| (path, false))
+}
+
+/// [`find_value`] for the `options` of the rule at `path`.
+fn find_option(
+    options: &[Value],
+    path: &JsonPath,
+    predicate: &mut impl FnMut(&Value, Option<&str>) -> bool,
+) -> Option<JsonPath> {
+    options.iter().enumerate().find_map(|(i, option)| {
+        let path = path.index(i + 1);
+        if predicate(option, None) {
+            return Some(path);
+        }
+        find_value(option, &path, predicate)
+    })
+}
+
+/// Depth-first search for the first value matching `predicate`, which is passed each value and
+/// the key it is stored under, if it is in an object.
+fn find_value(
+    value: &Value,
+    path: &JsonPath,
+    predicate: &mut impl FnMut(&Value, Option<&str>) -> bool,
+) -> Option<JsonPath> {
+    match value {
+        Value::Object(object) => object.iter().find_map(|(key, value)| {
+            let path = path.key(key);
+            if predicate(value, Some(key)) {
+                return Some(path);
+            }
+            find_value(value, &path, predicate)
+        }),
+        Value::Array(values) => values.iter().enumerate().find_map(|(i, value)| {
+            let path = path.index(i);
+            if predicate(value, None) {
+                return Some(path);
+            }
+            find_value(value, &path, predicate)
+        }),
+        _ => None,
+    }
+}
+
+/// A help message listing the `candidates` closest to the unknown `name`. Typos are measured
+/// relative to the length of `base_name`, the part of `name` without a plugin prefix.
+fn did_you_mean<S: AsRef<str>>(
+    name: &str,
+    base_name: &str,
+    candidates: impl Iterator<Item = S>,
+) -> Option<String> {
+    let threshold = (base_name.len() / 4).max(2);
+    let mut close_matches = candidates
+        .filter_map(|candidate| {
+            let distance = min_edit_distance(candidate.as_ref(), name);
+            (distance <= threshold).then(|| (distance, candidate.as_ref().to_string()))
+        })
+        .collect::<Vec<_>>();
+    if close_matches.is_empty() {
+        return None;
+    }
+    close_matches.sort();
+    close_matches.dedup();
+    let close_matches = close_matches
+        .into_iter()
+        .take(MAX_CLOSE_MATCHES)
+        .map(|(_, candidate)| format!("`{candidate}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(format!("Did you mean {close_matches}?"))
+}
+
+/// Finds the spans of the key and value at `path` in the JSON `source_text`. The key span is
+/// `None` for array elements and the root value.
+fn locate(source_text: &str, path: &[PathSegment]) -> Option<(Option<Span>, Span)> {
+    let bytes = source_text.as_bytes();
+    let mut start = skip_whitespace(bytes, 0);
+    let mut key_span = None;
+
+    for segment in path {
+        let mut pos = start + 1;
+        let mut index = 0;
+        let (close, is_object) = match bytes.get(start)? {
+            b'{' => (b'}', true),
+            b'[' => (b']', false),
+            _ => return None,
+        };
+        loop {
+            pos = skip_whitespace(bytes, pos);
+            if *bytes.get(pos)? == close {
+                return None;
+            }
+            let (entry_key_span, matches) = if is_object {
+                let key_end = skip_string(bytes, pos)?;
+                let key = serde_json::from_str::<String>(&source_text[pos..key_end]).ok()?;
+                let key_span = to_span(pos, key_end);
+                pos = skip_whitespace(bytes, key_end);
+                if *bytes.get(pos)? != b':' {
+                    return None;
+                }
+                pos = skip_whitespace(bytes, pos + 1);
+                (
+                    Some(key_span),
+                    matches!(segment, PathSegment::Key(segment_key) if *segment_key == key),
+                )
+            } else {
+                index += 1;
+                (
+                    None,
+                    matches!(segment, PathSegment::Index(segment_index) if *segment_index + 1 == index),
+                )
+            };
+            if matches {
+                start = pos;
+                key_span = entry_key_span;
+                break;
+            }
+            pos = skip_whitespace(bytes, skip_value(bytes, pos)?);
+            if *bytes.get(pos)? == b',' {
+                pos += 1;
+            }
+        }
+    }
+
+    Some((key_span, to_span(start, skip_value(bytes, start)?)))
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn to_span(start: usize, end: usize) -> Span {
+    Span::new(start as u32, end as u32)
+}
+
+fn skip_whitespace(bytes: &[u8], mut pos: usize) -> usize {
+    while bytes.get(pos).is_some_and(u8::is_ascii_whitespace) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Returns the end of the string starting at `pos`.
+fn skip_string(bytes: &[u8], mut pos: usize) -> Option<usize> {
+    if *bytes.get(pos)? != b'"' {
+        return None;
+    }
+    pos += 1;
+    loop {
+        match *bytes.get(pos)? {
+            b'\\' => pos += 2,
+            b'"' => return Some(pos + 1),
+            _ => pos += 1,
+        }
+    }
+}
+
+/// Returns the end of the value starting at `pos`.
+fn skip_value(bytes: &[u8], mut pos: usize) -> Option<usize> {
+    match *bytes.get(pos)? {
+        b'"' => skip_string(bytes, pos),
+        b'{' | b'[' => {
+            let mut depth = 0usize;
+            loop {
+                match *bytes.get(pos)? {
+                    b'"' => {
+                        pos = skip_string(bytes, pos)?;
+                        continue;
+                    }
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(pos + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                pos += 1;
+            }
+        }
+        _ => {
+            while bytes
+                .get(pos)
+                .is_some_and(|b| !matches!(b, b',' | b'}' | b']') && !b.is_ascii_whitespace())
+            {
+                pos += 1;
+            }
+            Some(pos)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn validate(source_text: &str) -> Vec<OxcDiagnostic> {
+        let json = serde_json::from_str(source_text).unwrap();
+        validate_oxlintrc(&json, source_text)
+    }
+
+    /// The message, help and labeled source text of a diagnostic.
+    type ProblemText<'s> = (String, Option<String>, Vec<(String, &'s str)>);
+
+    fn problems(source_text: &str) -> Vec<ProblemText<'_>> {
+        validate(source_text)
+            .into_iter()
+            .map(|diagnostic| {
+                let labels = diagnostic
+                    .labels
+                    .iter()
+                    .map(|label| {
+                        let span = Span::sized(label.offset(), label.len());
+                        (label.label().unwrap().to_string(), span.source_text(source_text))
+                    })
+                    .collect();
+                (
+                    diagnostic.message.to_string(),
+                    diagnostic.help.as_ref().map(ToString::to_string),
+                    labels,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_config() {
+        let source_text = r#"{
+            "$schema": "./node_modules/oxlint/configuration_schema.json",
+            "plugins": ["import"],
+            "rules": {
+                "no-console": ["warn", { "allow": ["info"] }],
+                "eqeqeq": "error",
+                "typescript/no-unused-vars": [2, { "varsIgnorePattern": "^_" }],
+                "@typescript-eslint/no-explicit-any": "off",
+                "custom/unknown-rule": "error"
+            },
+            "overrides": [{ "files": ["*.ts"], "rules": { "no-debugger": 0 } }]
+        }"#;
+        assert!(validate(source_text).is_empty());
+    }
+
+    #[test]
+    fn unknown_top_level_key() {
+        let source_text = r#"{ "rule": {}, "ignorePatterns": [] }"#;
+        let diagnostics = validate(source_text);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, oxc_diagnostics::Severity::Warning);
+        assert_eq!(
+            problems(source_text),
+            vec![(
+                "Unknown top-level key `rule`, it is ignored.".to_string(),
+                Some("Did you mean `rules`?".to_string()),
+                vec![("rule".to_string(), r#""rule""#)]
+            )]
+        );
+    }
+
+    #[test]
+    fn unknown_rule() {
+        let source_text = r#"{
+            "rules": { "no-unsued-vars": "error", "unicorn/no-nul": "warn", "foo": "off" },
+            "overrides": [{ "files": ["*.ts"], "rules": { "no-debuger": "error" } }]
+        }"#;
+        assert_eq!(
+            problems(source_text),
+            vec![
+                (
+                    "Unknown rule `no-unsued-vars`.".to_string(),
+                    Some("Did you mean `no-unused-vars`?".to_string()),
+                    vec![("rules.no-unsued-vars".to_string(), r#""no-unsued-vars""#)]
+                ),
+                (
+                    "Unknown rule `unicorn/no-nul`.".to_string(),
+                    Some("Did you mean `unicorn/no-null`?".to_string()),
+                    vec![("rules.unicorn/no-nul".to_string(), r#""unicorn/no-nul""#)]
+                ),
+                (
+                    "Unknown rule `foo`.".to_string(),
+                    None,
+                    vec![("rules.foo".to_string(), r#""foo""#)]
+                ),
+                (
+                    "Unknown rule `no-debuger`.".to_string(),
+                    Some("Did you mean `no-debugger`?".to_string()),
+                    vec![("overrides[0].rules.no-debuger".to_string(), r#""no-debuger""#)]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_severity() {
+        let source_text =
+            r#"{ "rules": { "no-console": 3, "no-debugger": ["err"], "eqeqeq": [] } }"#;
+        let problems = problems(source_text);
+        assert_eq!(problems.len(), 3);
+        assert_eq!(problems[0].2, vec![("rules.no-console".to_string(), "3")]);
+        assert_eq!(problems[1].2, vec![("rules.no-debugger[0]".to_string(), r#""err""#)]);
+        assert_eq!(problems[2].2, vec![("rules.eqeqeq".to_string(), "[]")]);
+    }
+
+    #[test]
+    fn invalid_options() {
+        let source_text = r#"{
+            "rules": {
+                "no-unused-vars": ["error", { "args": "none", "vars": "some" }],
+                "no-console": ["warn", { "allow": ["info"], "extra": true }],
+                "no-return-assign": ["error", "foobar"],
+                "no-debugger": ["error", { "foo": true }],
+                "yoda": ["error", "never", { "exceptRange": 123 }]
+            }
+        }"#;
+        let labels: Vec<_> =
+            problems(source_text).into_iter().flat_map(|(_, _, labels)| labels).collect();
+        assert_eq!(
+            labels,
+            vec![
+                // The error doesn't name the offending value.
+                ("rules.no-unused-vars[1]".to_string(), r#"{ "args": "none", "vars": "some" }"#),
+                ("rules.no-console[1].extra".to_string(), r#""extra""#),
+                ("rules.no-return-assign[1]".to_string(), r#""foobar""#),
+                ("rules.no-debugger[1]".to_string(), r#"{ "foo": true }"#),
+                ("rules.yoda[2].exceptRange".to_string(), "123"),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_path() {
+        let path = JsonPath::default().key("rules").key("no-unused-vars").index(1).key("vars");
+        assert_eq!(path.to_string(), "rules.no-unused-vars[1].vars");
+    }
+
+    #[test]
+    fn locate_path() {
+        let source_text = r#"{ "a": [1, { "b\"": "}", "c": [true, null] }], "d": 2 }"#;
+        let text = |path: &[PathSegment]| {
+            let (key, value) = locate(source_text, path).unwrap();
+            (key.map(|key| key.source_text(source_text)), value.source_text(source_text))
+        };
+        let key = |key: &str| PathSegment::Key(key.to_string());
+        assert_eq!(text(&[key("d")]), (Some(r#""d""#), "2"));
+        assert_eq!(text(&[key("a"), PathSegment::Index(0)]), (None, "1"));
+        assert_eq!(
+            text(&[key("a"), PathSegment::Index(1), key("c"), PathSegment::Index(1)]),
+            (None, "null")
+        );
+        assert_eq!(
+            text(&[key("a"), PathSegment::Index(1), key("b\"")]),
+            (Some(r#""b\"""#), r#""}""#)
+        );
+        assert_eq!(locate(source_text, &[key("e")]), None);
+        assert_eq!(locate(source_text, &[key("a"), PathSegment::Index(2)]), None);
+    }
+}
@@ -22,6 +22,7 @@ use crate::{
     module_record::ModuleRecord,
     options::LintOptions,
     rules::RuleEnum,
+    trace::TraceEvent,
 };
 
 #[cfg(not(test))]
@@ -169,6 +170,8 @@ pub struct ContextHost<'a> {
     pub(super) config: Arc<LintConfig>,
     /// Front-end frameworks that might be in use in the target file.
     pub(super) frameworks: FrameworkFlags,
+    /// Events of the rule being traced with `oxlint --debug-rule`. `None` unless tracing.
+    pub(super) trace: Option<RefCell<Vec<TraceEvent>>>,
 }
 
 impl std::fmt::Debug for ContextHost<'_> {
@@ -207,6 +210,7 @@ impl<'a> ContextHost<'a> {
             file_extension,
             config,
             frameworks: options.framework_hints,
+            trace: None,
         }
         .sniff_for_frameworks()
     }
@@ -287,6 +291,13 @@ impl<'a> ContextHost<'a> {
         self.semantic().source_type()
     }
 
+    /// Whether the file being linted is a TypeScript declaration file (`.d.ts`, `.d.mts`,
+    /// `.d.cts`).
+    #[inline]
+    pub fn is_declaration_file(&self) -> bool {
+        self.source_type().is_typescript_definition()
+    }
+
     #[inline]
     pub fn plugins(&self) -> LintPlugins {
         self.config.plugins
@@ -314,9 +325,40 @@ impl<'a> ContextHost<'a> {
         if self.current_sub_host().source_text_offset != 0 {
             diagnostic.move_offset(self.current_sub_host().source_text_offset);
         }
+        #[cfg(feature = "trace")]
+        if let Some(trace) = &self.trace {
+            trace.borrow_mut().push(TraceEvent::Diagnostic {
+                message: diagnostic.error.message.to_string(),
+                span: diagnostic.span,
+            });
+        }
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
+    /// Start recording [`TraceEvent`]s, see [`LintContext::trace`].
+    pub(crate) fn with_trace(mut self) -> Self {
+        self.trace = Some(RefCell::default());
+        self
+    }
+
+    /// Records the event made by `event` if tracing. Does nothing without the `trace` feature.
+    #[cfg(feature = "trace")]
+    #[inline]
+    pub(crate) fn push_trace_event(&self, event: impl FnOnce() -> TraceEvent) {
+        if let Some(trace) = &self.trace {
+            trace.borrow_mut().push(event());
+        }
+    }
+
+    #[cfg(not(feature = "trace"))]
+    #[expect(clippy::unused_self)]
+    #[inline(always)]
+    pub(crate) fn push_trace_event(&self, _event: impl FnOnce() -> TraceEvent) {}
+
+    pub(crate) fn take_trace_events(&self) -> Vec<TraceEvent> {
+        self.trace.as_ref().map(RefCell::take).unwrap_or_default()
+    }
+
     // Append a list of diagnostics. Only used in report_unused_directives.
     fn append_diagnostics(&self, mut diagnostics: Vec<Message>) {
         if self.current_sub_host().source_text_offset != 0 {
@@ -437,12 +479,10 @@ impl<'a> ContextHost<'a> {
         self.sub_hosts.into_iter().next().map(|sub_host| sub_host.disable_directives)
     }
 
-    #[cfg(debug_assertions)]
     pub fn get_diagnostics(&self, cb: impl FnOnce(&mut Vec<Message>)) {
         cb(self.diagnostics.borrow_mut().as_mut());
     }
 
-    #[cfg(debug_assertions)]
     pub fn diagnostic_count(&self) -> usize {
         self.diagnostics.borrow().len()
     }
@@ -8,15 +8,17 @@ use oxc_allocator::Allocator;
 use oxc_ast::ast::IdentifierReference;
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
-use oxc_semantic::Semantic;
-use oxc_span::Span;
+use oxc_semantic::{AstNode, Semantic};
+use oxc_span::{GetSpan, Span};
 
 #[cfg(debug_assertions)]
 use crate::rule::RuleFixMeta;
+#[cfg(feature = "trace")]
+use crate::trace::TraceEvent;
 use crate::{
     FrameworkFlags, ModuleRecord, OxlintEnv, OxlintGlobals, OxlintSettings, WEBSITE_BASE_RULES_URL,
     config::GlobalValue,
-    disable_directives::DisableDirectives,
+    disable_directives::{DisableDirectives, DisabledRule},
     fixer::{Fix, FixKind, Message, MessageRule, PossibleFixes, RuleFix, RuleFixer},
     frameworks::FrameworkOptions,
 };
@@ -105,6 +107,12 @@ impl<'a> LintContext<'a> {
         self.parent.disable_directives()
     }
 
+    /// Directives which suppress diagnostics reported on `node`, combining file-level,
+    /// block, and next-line (or same-line) directives.
+    pub fn active_directives_for(&self, node: &AstNode) -> Vec<&DisabledRule> {
+        self.disable_directives().active_directives_for(node.span())
+    }
+
     /// Get a snippet of source text covered by the given [`Span`]. For details,
     /// see [`Span::source_text`].
     pub fn source_range(&self, span: Span) -> &'a str {
@@ -178,6 +186,13 @@ impl<'a> LintContext<'a> {
         self.parent.file_extension()
     }
 
+    /// Whether the file currently being linted is a TypeScript declaration file (`.d.ts`,
+    /// `.d.mts`, `.d.cts`).
+    #[inline]
+    pub fn is_declaration_file(&self) -> bool {
+        self.parent.is_declaration_file()
+    }
+
     /// Plugin settings
     #[inline]
     pub fn settings(&self) -> &OxlintSettings {
@@ -300,6 +315,22 @@ impl<'a> LintContext<'a> {
         self.parent.push_diagnostic(message);
     }
 
+    /// Record why the rule did or didn't report a node, e.g. `ctx.trace(|| "skipped: not a call
+    /// expression")`, for debugging a rule with `oxlint --debug-rule`.
+    ///
+    /// Does nothing unless the rule is being traced, and `message` is only called then, so traces
+    /// can be left in rules. Without the `trace` feature, this compiles to nothing.
+    #[cfg(feature = "trace")]
+    #[inline]
+    pub fn trace<S: Into<String>>(&self, message: impl FnOnce() -> S) {
+        self.parent.push_trace_event(|| TraceEvent::Note(message().into()));
+    }
+
+    #[cfg(not(feature = "trace"))]
+    #[expect(clippy::unused_self)]
+    #[inline(always)]
+    pub fn trace<S: Into<String>>(&self, _message: impl FnOnce() -> S) {}
+
     /// Report a lint rule violation.
     ///
     /// Use [`LintContext::diagnostic_with_fix`] to provide an automatic fix.
@@ -568,6 +599,11 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// Whether fixes of `kind` are applied in this run, e.g. with `--fix`.
+    pub fn can_apply_fix(&self, kind: FixKind) -> bool {
+        self.parent.fix.can_apply(kind)
+    }
+
     /// Framework flags, indicating front-end frameworks that might be in use.
     pub fn frameworks(&self) -> FrameworkFlags {
         self.parent.frameworks
@@ -88,8 +88,9 @@ enum DirectiveKind {
     Enable,
 }
 
+/// A parsed disable directive, together with the rule(s) it disables.
 #[derive(Debug, Clone, Eq, PartialEq)]
-enum DisabledRule {
+pub enum DisabledRule {
     /// Disables all linting rules for a span of code.
     /// Used by directives like `eslint-disable`, `eslint-disable-next-line`, or `eslint-disable-line` without specific rule names.
     ///
@@ -144,6 +145,14 @@ enum DisabledRule {
 }
 
 impl DisabledRule {
+    /// Name of the disabled rule, or `None` if the directive disables all rules.
+    pub fn rule_name(&self) -> Option<&str> {
+        match self {
+            DisabledRule::All { .. } => None,
+            DisabledRule::Single { rule_name, .. } => Some(rule_name),
+        }
+    }
+
     pub fn comment_span(&self) -> &Span {
         match self {
             DisabledRule::All { comment_span, .. } | DisabledRule::Single { comment_span, .. } => {
@@ -342,21 +351,7 @@ impl DisableDirectives {
                 continue;
             }
 
-            // Check if the diagnostic span is covered by this interval
-            let span_covered = if interval.val.is_next_line() {
-                // For next-line directives, only check if the diagnostic starts within the interval
-                // We intentionally only check span.start (not span.end) to avoid suppressing
-                // diagnostics for large constructs that merely contain the disabled line
-                #[expect(clippy::suspicious_operation_groupings)]
-                {
-                    span.start >= interval.start && span.start < interval.stop
-                }
-            } else {
-                // For regular disable directives, check if there's any overlap
-                span.start < interval.stop && span.end > interval.start
-            };
-
-            if span_covered {
+            if Self::covers(interval, span) {
                 self.mark_disable_directive_used(interval.val.clone());
                 has_match = true;
             }
@@ -364,6 +359,35 @@ impl DisableDirectives {
         has_match
     }
 
+    /// All directives which affect `span`, whether they come from a file-level `eslint-disable`,
+    /// a block delimited by `eslint-disable` / `eslint-enable`, or an `eslint-disable-next-line`
+    /// or `eslint-disable-line` comment.
+    ///
+    /// Unlike [`DisableDirectives::contains`], this does not mark the directives as used.
+    pub fn active_directives_for(&self, span: Span) -> Vec<&DisabledRule> {
+        self.intervals
+            .find(span.start, span.end)
+            .filter(|interval| Self::covers(interval, span))
+            .map(|interval| &interval.val)
+            .collect()
+    }
+
+    /// Check if the diagnostic span is covered by this interval
+    fn covers(interval: &Interval<u32, DisabledRule>, span: Span) -> bool {
+        if interval.val.is_next_line() {
+            // For next-line directives, only check if the diagnostic starts within the interval
+            // We intentionally only check span.start (not span.end) to avoid suppressing
+            // diagnostics for large constructs that merely contain the disabled line
+            #[expect(clippy::suspicious_operation_groupings)]
+            {
+                span.start >= interval.start && span.start < interval.stop
+            }
+        } else {
+            // For regular disable directives, check if there's any overlap
+            span.start < interval.stop && span.end > interval.start
+        }
+    }
+
     pub fn disable_rule_comments(&self) -> &[DisableRuleComment] {
         &self.disable_rule_comments
     }
@@ -669,24 +693,39 @@ impl DisableDirectivesBuilder {
                     }
                 }
                 DirectiveKind::DisableLine => {
-                    // Get the span between the preceding newline to this comment
+                    // As in ESLint, a block comment spanning multiple lines can't disable a line.
+                    if text_source.contains(['\n', '\r']) {
+                        continue;
+                    }
+                    // Get the span of the line containing this comment. Block comments may be
+                    // followed by code on the same line, e.g. `/* eslint-disable-line */ foo();`.
                     let start = source_text[..comment_span.start as usize]
-                        .lines()
-                        .next_back()
-                        .map_or(0, |line| comment_span.start - line.len() as u32);
-                    let stop = comment_span.start;
+                        .rfind(['\n', '\r'])
+                        .map_or(0, |offset| offset as u32 + 1);
+                    let stop = source_text[comment_span.end as usize..]
+                        .find(['\n', '\r'])
+                        .map_or(source_text.len() as u32, |offset| {
+                            comment_span.end + offset as u32
+                        });
+                    // The comment itself is excluded, so diagnostics about the directive are
+                    // not suppressed by it.
+                    let line_ranges = [(start, outer_span.start), (outer_span.end, stop)]
+                        .into_iter()
+                        .filter(|(start, stop)| start < stop);
 
                     if rule_names.is_empty() {
-                        self.add_interval(
-                            start,
-                            stop,
-                            DisabledRule::All {
-                                directive_prefix,
-                                comment_span: outer_span,
-                                fix_span: comment_fix_span,
-                                is_next_line: true,
-                            },
-                        );
+                        for (start, stop) in line_ranges {
+                            self.add_interval(
+                                start,
+                                stop,
+                                DisabledRule::All {
+                                    directive_prefix,
+                                    comment_span: outer_span,
+                                    fix_span: comment_fix_span,
+                                    is_next_line: true,
+                                },
+                            );
+                        }
                         self.disable_rule_comments.push(DisableRuleComment {
                             directive_prefix,
                             span: comment_span,
@@ -698,18 +737,20 @@ impl DisableDirectivesBuilder {
                         let mut rules = vec![];
                         for (rule_name, name_span) in rule_names {
                             let name_span = name_span.move_right(rule_list_start);
-                            self.add_interval(
-                                start,
-                                stop,
-                                DisabledRule::Single {
-                                    directive_prefix,
-                                    rule_name: rule_name.to_string(),
-                                    name_span,
-                                    comment_span: outer_span,
-                                    fix_span: comment_fix_span,
-                                    is_next_line: true,
-                                },
-                            );
+                            for (start, stop) in line_ranges.clone() {
+                                self.add_interval(
+                                    start,
+                                    stop,
+                                    DisabledRule::Single {
+                                        directive_prefix,
+                                        rule_name: rule_name.to_string(),
+                                        name_span,
+                                        comment_span: outer_span,
+                                        fix_span: comment_fix_span,
+                                        is_next_line: true,
+                                    },
+                                );
+                            }
                             rules.push(RuleCommentRule {
                                 directive_prefix,
                                 rule_name: rule_name.to_string(),
@@ -967,6 +1008,14 @@ fn test() {
             debugger;
 
             debugger; /* {prefix}-disable-line */
+        "
+            ),
+            // Block comments disable the whole line, including code after the comment.
+            format!(
+                "
+/* {prefix}-disable-line */ debugger;
+            /* {prefix}-disable-line no-debugger */ debugger;
+            foo(/* {prefix}-disable-line no-debugger */); debugger;
         "
             ),
             // To disable a specific rule on a specific line:
@@ -1158,6 +1207,23 @@ no-debugger
 
         let fail = vec![
             "debugger".to_string(),
+            // `disable-line` only disables its own line.
+            format!("debugger;\n/* {prefix}-disable-line */"),
+            format!("/* {prefix}-disable-line */\ndebugger;"),
+            // Block comments spanning multiple lines can't disable a line.
+            format!("debugger; /* {prefix}-disable-line\n */"),
+            format!("/* {prefix}-disable-line\n */ debugger;"),
+            // Directive text in template literals, e.g. GraphQL queries, is not a comment
+            format!(
+                "
+            const query = gql`
+                /* {prefix}-disable */
+                # {prefix}-disable-next-line no-debugger
+                query {{ user {{ id }} }}
+            `;
+            debugger;
+        "
+            ),
             format!(
                 "
             debugger; // {prefix}-disable-line no-alert
@@ -1206,6 +1272,15 @@ no-debugger
             // {prefix}-disable-next-line no-debugger
             debugger;
             debugger;
+        "
+            ),
+            // A next-line directive only applies to the line after it, which is the next directive
+            // when they are stacked, see `oxc/no-stacked-next-line-directives`
+            format!(
+                "
+            // {prefix}-disable-next-line no-debugger
+            // {prefix}-disable-next-line no-alert
+            debugger;
         "
             ),
             // Should not match invalid directives
@@ -2124,4 +2199,79 @@ function test() {
             "`import/export` directive must suppress the `export` rule"
         );
     }
+
+    #[test]
+    #[expect(clippy::cast_possible_truncation)] // for `as u32`
+    fn next_line_directives_before_declare_statements() {
+        for prefix in ["eslint", "oxlint"] {
+            let source_text = format!(
+                r"
+                // {prefix}-disable-next-line no-var
+                declare var foo: number;
+                // {prefix}-disable-next-line no-unused-vars
+                export declare function bar(): void;
+                declare const baz: string;
+                "
+            );
+            let allocator = Allocator::default();
+            let parser_ret = Parser::new(&allocator, &source_text, SourceType::ts()).parse();
+            assert!(parser_ret.diagnostics.is_empty());
+            let directives =
+                DisableDirectivesBuilder::new().build(&source_text, &parser_ret.program.comments);
+            let span_of = |needle: &str| {
+                Span::sized(source_text.find(needle).unwrap() as u32, needle.len() as u32)
+            };
+
+            assert!(directives.contains("no-var", span_of("declare var foo: number;")));
+            assert!(
+                directives
+                    .contains("no-unused-vars", span_of("export declare function bar(): void;"))
+            );
+            assert!(!directives.contains("no-unused-vars", span_of("declare const baz: string;")));
+        }
+    }
+
+    #[test]
+    #[expect(clippy::cast_possible_truncation)] // for `as u32`
+    fn active_directives_for_file_level_and_next_line() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    /* {prefix}-disable no-console */
+                    console.log();
+                    // {prefix}-disable-next-line no-debugger, no-alert
+                    debugger;
+                    alert();
+                    "
+                )
+            },
+            |source_text, _comments, directives| {
+                let span_of = |needle: &str| {
+                    Span::sized(source_text.find(needle).unwrap() as u32, needle.len() as u32)
+                };
+                let rule_names = |span: Span| {
+                    directives
+                        .active_directives_for(span)
+                        .into_iter()
+                        .map(|directive| directive.rule_name().unwrap())
+                        .collect::<Vec<_>>()
+                };
+
+                // Only the file-level directive applies before the next-line directive.
+                assert_eq!(rule_names(span_of("console.log()")), ["no-console"]);
+
+                // Both the file-level and next-line directives apply to the following line.
+                let mut debugger_rules = rule_names(span_of("debugger;"));
+                debugger_rules.sort_unstable();
+                assert_eq!(debugger_rules, ["no-alert", "no-console", "no-debugger"]);
+
+                // The next-line directive no longer applies on the line after.
+                assert_eq!(rule_names(span_of("alert()")), ["no-console"]);
+
+                // Looking up active directives does not mark them as used.
+                assert_eq!(directives.collect_unused_disable_comments().len(), 2);
+            },
+        );
+    }
 }
@@ -1,8 +1,11 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, path::PathBuf};
 
+use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::{ParseOptions, Parser};
 use oxc_span::{GetSpan, SourceType, Span};
+use serde::Serialize;
 
 /// Identifies the lint rule that produced a [`Message`].
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -25,7 +28,7 @@ impl MessageRule {
     }
 }
 
-use crate::LintContext;
+use crate::{FixFilter, LintContext};
 
 mod fix;
 pub use fix::{CompositeFix, Fix, FixKind, MergeFixesError, PossibleFixes, RuleFix};
@@ -259,6 +262,34 @@ pub struct FixResult<'a> {
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message>,
+    /// The fixes which were applied to produce `fixed_code`, in source order.
+    pub applied_fixes: Vec<AppliedFix>,
+    /// The fixes which were discarded because together they introduced syntax errors, in source
+    /// order. Only set when verification is enabled with [`Fixer::with_verify`].
+    pub discarded_fixes: Vec<AppliedFix>,
+}
+
+/// A fix applied by the [`Fixer`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AppliedFix {
+    /// Span of the original source text replaced by the fix.
+    pub span: Span,
+    /// The lint rule that produced the fix, if any.
+    pub rule: Option<MessageRule>,
+}
+
+/// A fix of [`Linter::apply_fixes`](crate::Linter::apply_fixes), as an entry of a migration
+/// changelog.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixChangelogEntry {
+    pub path: PathBuf,
+    /// Span of the original source text replaced by the fix.
+    pub span: Span,
+    pub original: String,
+    pub replacement: String,
+    /// Whether the fix was applied. Fixes overlapping an earlier fix are not.
+    pub applied: bool,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -347,25 +378,38 @@ pub struct Fixer<'a> {
     // The behavior is oriented by `oxlint` where only one PossibleFixes is applied.
     fix_index: u8,
 
-    #[cfg(debug_assertions)]
     source_type: Option<SourceType>,
+    verify: bool,
+    filter: Option<&'a FixFilter>,
 }
 
 impl<'a> Fixer<'a> {
     pub fn new(
         source_text: &'a str,
         messages: Vec<Message>,
-        #[cfg_attr(not(debug_assertions), expect(unused_variables))] source_type: Option<
-            SourceType,
-        >,
+        source_type: Option<SourceType>,
     ) -> Self {
-        Self {
-            source_text,
-            messages,
-            fix_index: 0,
-            #[cfg(debug_assertions)]
-            source_type,
-        }
+        Self { source_text, messages, fix_index: 0, source_type, verify: false, filter: None }
+    }
+
+    /// Only apply the fixes of messages whose rule is allowed by `filter`. Other messages are
+    /// kept as unfixed.
+    #[must_use]
+    pub fn with_filter(mut self, filter: &'a FixFilter) -> Self {
+        self.filter = (!filter.is_empty()).then_some(filter);
+        self
+    }
+
+    /// Re-parse the fixed code, and discard all fixes if they introduce syntax errors which
+    /// the original source text doesn't have. The discarded fixes are reported in
+    /// [`FixResult::discarded_fixes`], and their messages are kept as unfixed.
+    ///
+    /// Requires a source type, so that whole-file fixes of files with multiple sections (e.g.
+    /// `.vue` files) are not verified.
+    #[must_use]
+    pub fn with_verify(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
     }
 
     #[cfg(test)]
@@ -383,6 +427,8 @@ impl<'a> Fixer<'a> {
                 fixed: false,
                 fixed_code: Cow::Borrowed(source_text),
                 messages: self.messages,
+                applied_fixes: vec![],
+                discarded_fixes: vec![],
             };
         }
 
@@ -393,6 +439,12 @@ impl<'a> Fixer<'a> {
 
         // only keep messages that were not fixed
         let mut filtered_messages = Vec::with_capacity(self.messages.len());
+        // kept to restore them if the fixes are discarded
+        let mut fixed_messages = vec![];
+        let mut applied_fixes = vec![];
+        // The span and replacement length of each applied fix, to map positions in the output
+        // back to the source text.
+        let mut replacements = vec![];
 
         for mut m in self.messages {
             let fix = match &m.fixes {
@@ -406,6 +458,10 @@ impl<'a> Fixer<'a> {
                 filtered_messages.push(m);
                 continue;
             };
+            if self.filter.is_some_and(|filter| !filter.allows(m.rule.as_ref())) {
+                filtered_messages.push(m);
+                continue;
+            }
             let start = span.start;
             let end = span.end;
             debug_assert!(start <= end, "Negative range is invalid: {span:?}");
@@ -427,38 +483,120 @@ impl<'a> Fixer<'a> {
 
             m.fixed = true;
             fixed = true;
+            applied_fixes.push(AppliedFix { span: *span, rule: m.rule.clone() });
+            #[expect(clippy::cast_possible_truncation)]
+            replacements.push((*span, content.len() as u32));
             let offset = last_pos as usize;
             output.push_str(&source_text[offset..start as usize]);
             output.push_str(content);
             last_pos = end;
+            fixed_messages.push(m);
         }
 
         output.push_str(&source_text[last_pos as usize..]);
 
+        if fixed
+            && self.verify
+            && let Some(source_type) = self.source_type
+            && introduces_syntax_errors(source_text, &output, source_type, &replacements)
+        {
+            filtered_messages.extend(fixed_messages.into_iter().map(|mut m| {
+                m.fixed = false;
+                m
+            }));
+            filtered_messages.sort_unstable_by_key(GetSpan::span);
+            return FixResult {
+                fixed: false,
+                fixed_code: Cow::Borrowed(source_text),
+                messages: filtered_messages,
+                applied_fixes: vec![],
+                discarded_fixes: applied_fixes,
+            };
+        }
+
         filtered_messages.sort_unstable_by_key(GetSpan::span);
 
         #[cfg(debug_assertions)]
-        if fixed && let Some(source_type) = self.source_type {
-            use oxc_allocator::Allocator;
-            use oxc_parser::{ParseOptions, Parser};
-
-            let allocator = Allocator::default();
-            let parse_result = Parser::new(&allocator, &output, source_type)
-                .with_options(ParseOptions {
-                    parse_regular_expression: true,
-                    allow_return_outside_function: true,
-                    ..ParseOptions::default()
-                })
-                .parse();
+        if fixed
+            && !self.verify
+            && let Some(source_type) = self.source_type
+        {
+            let errors = syntax_errors(&output, source_type);
             debug_assert!(
-                parse_result.diagnostics.is_empty() && !parse_result.panicked,
-                "Linter fixer produced invalid syntax.\n\nInput code: \n```\n{source_text}\n```\n\nFixed code: \n```\n{output}\n```\n\nParse errors: {:?}",
-                parse_result.diagnostics
+                errors.is_empty(),
+                "Linter fixer produced invalid syntax.\n\nInput code: \n```\n{source_text}\n```\n\nFixed code: \n```\n{output}\n```\n\nParse errors: {errors:?}",
             );
         }
 
-        FixResult { fixed, fixed_code: Cow::Owned(output), messages: filtered_messages }
+        FixResult {
+            fixed,
+            fixed_code: Cow::Owned(output),
+            messages: filtered_messages,
+            applied_fixes,
+            discarded_fixes: vec![],
+        }
+    }
+}
+
+/// Parses `source_text` like the linter does, returning the syntax errors.
+fn syntax_errors(source_text: &str, source_type: SourceType) -> Vec<Message> {
+    let allocator = Allocator::default();
+    Parser::new(&allocator, source_text, source_type)
+        .with_options(ParseOptions {
+            parse_regular_expression: true,
+            allow_return_outside_function: true,
+            ..ParseOptions::default()
+        })
+        .parse()
+        .diagnostics
+        .into_iter()
+        .map(|error| Message::new(error, PossibleFixes::None))
+        .collect()
+}
+
+/// Whether `fixed_code` has syntax errors which `source_text` doesn't.
+///
+/// Errors are compared by message and span, with spans in `fixed_code` mapped back to
+/// `source_text` through `replacements`, the span and replacement length of each applied fix in
+/// source order. An error inside a replacement is always new.
+fn introduces_syntax_errors(
+    source_text: &str,
+    fixed_code: &str,
+    source_type: SourceType,
+    replacements: &[(Span, u32)],
+) -> bool {
+    let fixed_errors = syntax_errors(fixed_code, source_type);
+    if fixed_errors.is_empty() {
+        return false;
+    }
+    let original_errors = syntax_errors(source_text, source_type);
+    fixed_errors.iter().any(|error| {
+        let Some(span) = original_span(error.span, replacements) else {
+            return true;
+        };
+        !original_errors
+            .iter()
+            .any(|original| original.span == span && original.error.message == error.error.message)
+    })
+}
+
+/// Maps `span` of the fixed code back to the source text, or `None` if it overlaps a replacement.
+fn original_span(span: Span, replacements: &[(Span, u32)]) -> Option<Span> {
+    let mut shift: i64 = 0;
+    for (replaced, len) in replacements {
+        let start = i64::from(replaced.start) + shift;
+        let end = start + i64::from(*len);
+        if i64::from(span.end) <= start {
+            break;
+        }
+        if i64::from(span.start) < end {
+            return None;
+        }
+        shift += i64::from(*len) - i64::from(replaced.size());
     }
+    let start = u32::try_from(i64::from(span.start) - shift).ok()?;
+    let end = u32::try_from(i64::from(span.end) - shift).ok()?;
+    Some(Span::new(start, end))
 }
 
 #[cfg(test)]
@@ -469,10 +607,11 @@ mod test {
     use oxc_diagnostics::OxcDiagnostic;
     use oxc_span::{SourceType, Span};
 
-    use crate::FixKind;
+    use crate::{FixFilter, FixKind};
 
     use super::{
-        CompositeFix, Fix, FixResult, Fixer, Message, PossibleFixes, format_replace_message,
+        AppliedFix, CompositeFix, Fix, FixResult, Fixer, Message, MessageRule, PossibleFixes,
+        format_replace_message,
     };
 
     fn insert_at_end() -> OxcDiagnostic {
@@ -740,6 +879,59 @@ mod test {
         assert!(result.fixed);
     }
 
+    #[test]
+    fn applied_fixes() {
+        let rule = MessageRule {
+            plugin_name: Cow::Borrowed("eslint"),
+            rule_name: Cow::Borrowed("no-var"),
+        };
+        let result = get_fix_result(vec![
+            create_message(remove_middle(Span::default()), PossibleFixes::Single(REMOVE_MIDDLE)),
+            create_message(replace_id(), PossibleFixes::Single(REPLACE_ID)).with_rule(rule.clone()),
+            create_message(replace_num(), PossibleFixes::Single(REPLACE_NUM)),
+            create_message(no_fix(Span::default()), PossibleFixes::None),
+        ]);
+        assert_eq!(
+            result.applied_fixes,
+            vec![
+                AppliedFix { span: REPLACE_ID.span, rule: Some(rule) },
+                AppliedFix { span: REPLACE_NUM.span, rule: None },
+            ]
+        );
+
+        let result =
+            get_fix_result(vec![create_message(no_fix(Span::default()), PossibleFixes::None)]);
+        assert!(result.applied_fixes.is_empty());
+    }
+
+    #[test]
+    fn filter() {
+        let rule = MessageRule {
+            plugin_name: Cow::Borrowed("eslint"),
+            rule_name: Cow::Borrowed("no-var"),
+        };
+        let messages = || {
+            vec![
+                create_message(replace_id(), PossibleFixes::Single(REPLACE_ID))
+                    .with_rule(rule.clone()),
+                create_message(replace_num(), PossibleFixes::Single(REPLACE_NUM)),
+            ]
+        };
+
+        let only = FixFilter::new(&["no-var"], &[]).unwrap();
+        let result = Fixer::new(TEST_CODE, messages(), None).with_filter(&only).fix();
+        assert_eq!(
+            result.applied_fixes,
+            vec![AppliedFix { span: REPLACE_ID.span, rule: Some(rule.clone()) }]
+        );
+        assert_eq!(result.messages.len(), 1);
+
+        let except = FixFilter::new(&[], &["eslint/no-var"]).unwrap();
+        let result = Fixer::new(TEST_CODE, messages(), None).with_filter(&except).fix();
+        assert_eq!(result.applied_fixes, vec![AppliedFix { span: REPLACE_NUM.span, rule: None }]);
+        assert_eq!(result.messages.len(), 1);
+    }
+
     #[test]
     fn apply_one_fix_when_the_start_the_same_as_the_previous_end() {
         let result = get_fix_result(vec![
@@ -954,6 +1146,101 @@ mod test {
         assert_eq!(result.fixed_code, "let answer = 42;");
     }
 
+    #[test]
+    fn verify_discards_fixes_introducing_syntax_errors() {
+        let source_text = "var answer = 42; let x = 1;";
+        let rule = MessageRule {
+            plugin_name: Cow::Borrowed("eslint"),
+            rule_name: Cow::Borrowed("no-var"),
+        };
+        let broken = create_message(
+            OxcDiagnostic::warn("Invalid fix test"),
+            PossibleFixes::Single(Fix::new(Cow::Borrowed("!!!INVALID"), Span::new(0, 3))),
+        )
+        .with_rule(rule.clone());
+        let valid = create_message(
+            OxcDiagnostic::warn("Valid fix test"),
+            PossibleFixes::Single(Fix::new(Cow::Borrowed("const"), Span::new(17, 20))),
+        );
+
+        let result = Fixer::new(source_text, vec![valid, broken], Some(SourceType::default()))
+            .with_verify(true)
+            .fix();
+        assert!(!result.fixed);
+        assert_eq!(result.fixed_code, source_text);
+        assert!(result.applied_fixes.is_empty());
+        assert_eq!(
+            result.discarded_fixes,
+            vec![
+                AppliedFix { span: Span::new(0, 3), rule: Some(rule) },
+                AppliedFix { span: Span::new(17, 20), rule: None },
+            ]
+        );
+        // The messages are reported as unfixed, in source order.
+        assert_eq!(result.messages.len(), 2);
+        assert!(result.messages.iter().all(|m| !m.fixed));
+        assert_eq!(result.messages[0].error.message, "Invalid fix test");
+    }
+
+    #[test]
+    fn verify_keeps_valid_fixes() {
+        let source_text = "var answer = 42;";
+        let fix = Fix::new(Cow::Borrowed("let"), Span::new(0, 3));
+        let message =
+            create_message(OxcDiagnostic::warn("Valid fix test"), PossibleFixes::Single(fix));
+
+        let result = Fixer::new(source_text, vec![message], Some(SourceType::default()))
+            .with_verify(true)
+            .fix();
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, "let answer = 42;");
+        assert!(result.discarded_fixes.is_empty());
+    }
+
+    #[test]
+    fn verify_ignores_syntax_errors_already_in_source() {
+        let source_text = "var answer = 42; b = 2 +;";
+        let fix = Fix::new(Cow::Borrowed("let"), Span::new(0, 3));
+        let message =
+            create_message(OxcDiagnostic::warn("Valid fix test"), PossibleFixes::Single(fix));
+
+        let result = Fixer::new(source_text, vec![message], Some(SourceType::default()))
+            .with_verify(true)
+            .fix();
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, "let answer = 42; b = 2 +;");
+    }
+
+    #[test]
+    fn verify_maps_syntax_errors_through_fixes_changing_length() {
+        let source_text = "var answer = 42; b = 2 +;";
+        let fix = Fix::new(Cow::Borrowed("const"), Span::new(0, 3));
+        let message =
+            create_message(OxcDiagnostic::warn("Valid fix test"), PossibleFixes::Single(fix));
+
+        let result = Fixer::new(source_text, vec![message], Some(SourceType::default()))
+            .with_verify(true)
+            .fix();
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, "const answer = 42; b = 2 +;");
+    }
+
+    #[test]
+    fn verify_discards_fixes_repeating_an_existing_syntax_error() {
+        // The source already has this syntax error, but the fix adds it at another position.
+        let source_text = "a = 1; b = 2 +;";
+        let fix = Fix::new(Cow::Borrowed("1 +"), Span::new(4, 5));
+        let message =
+            create_message(OxcDiagnostic::warn("Invalid fix test"), PossibleFixes::Single(fix));
+
+        let result = Fixer::new(source_text, vec![message], Some(SourceType::default()))
+            .with_verify(true)
+            .fix();
+        assert!(!result.fixed);
+        assert_eq!(result.fixed_code, source_text);
+        assert_eq!(result.discarded_fixes.len(), 1);
+    }
+
     #[test]
     fn format_replace_message_for_non_empty_replacement() {
         assert_eq!(format_replace_message("{foo}", "bar"), "Replace `{foo}` with `bar`.");
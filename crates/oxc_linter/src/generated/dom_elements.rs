@@ -0,0 +1,156 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To regenerate: `cargo lintgen`
+
+use crate::globals::DomElement;
+
+pub static DOM_ELEMENTS: phf::Map<&'static str, DomElement> = phf::phf_map! {
+    "a" => DomElement { reserved: false, void: false },
+    "abbr" => DomElement { reserved: false, void: false },
+    "acronym" => DomElement { reserved: false, void: false },
+    "address" => DomElement { reserved: false, void: false },
+    "applet" => DomElement { reserved: false, void: false },
+    "area" => DomElement { reserved: false, void: true },
+    "article" => DomElement { reserved: false, void: false },
+    "aside" => DomElement { reserved: false, void: false },
+    "audio" => DomElement { reserved: false, void: false },
+    "b" => DomElement { reserved: false, void: false },
+    "base" => DomElement { reserved: true, void: true },
+    "basefont" => DomElement { reserved: false, void: false },
+    "bdi" => DomElement { reserved: false, void: false },
+    "bdo" => DomElement { reserved: false, void: false },
+    "bgsound" => DomElement { reserved: false, void: false },
+    "big" => DomElement { reserved: false, void: false },
+    "blink" => DomElement { reserved: false, void: false },
+    "blockquote" => DomElement { reserved: false, void: false },
+    "body" => DomElement { reserved: false, void: false },
+    "br" => DomElement { reserved: false, void: true },
+    "button" => DomElement { reserved: false, void: false },
+    "canvas" => DomElement { reserved: false, void: false },
+    "caption" => DomElement { reserved: false, void: false },
+    "center" => DomElement { reserved: false, void: false },
+    "cite" => DomElement { reserved: false, void: false },
+    "code" => DomElement { reserved: false, void: false },
+    "col" => DomElement { reserved: true, void: true },
+    "colgroup" => DomElement { reserved: true, void: false },
+    "command" => DomElement { reserved: false, void: false },
+    "content" => DomElement { reserved: false, void: false },
+    "data" => DomElement { reserved: false, void: false },
+    "datalist" => DomElement { reserved: false, void: false },
+    "dd" => DomElement { reserved: false, void: false },
+    "del" => DomElement { reserved: false, void: false },
+    "details" => DomElement { reserved: false, void: false },
+    "dfn" => DomElement { reserved: false, void: false },
+    "dialog" => DomElement { reserved: false, void: false },
+    "dir" => DomElement { reserved: false, void: false },
+    "div" => DomElement { reserved: false, void: false },
+    "dl" => DomElement { reserved: false, void: false },
+    "dt" => DomElement { reserved: false, void: false },
+    "element" => DomElement { reserved: false, void: false },
+    "em" => DomElement { reserved: false, void: false },
+    "embed" => DomElement { reserved: false, void: true },
+    "fieldset" => DomElement { reserved: false, void: false },
+    "figcaption" => DomElement { reserved: false, void: false },
+    "figure" => DomElement { reserved: false, void: false },
+    "font" => DomElement { reserved: false, void: false },
+    "footer" => DomElement { reserved: false, void: false },
+    "form" => DomElement { reserved: false, void: false },
+    "frame" => DomElement { reserved: false, void: false },
+    "frameset" => DomElement { reserved: false, void: false },
+    "h1" => DomElement { reserved: false, void: false },
+    "h2" => DomElement { reserved: false, void: false },
+    "h3" => DomElement { reserved: false, void: false },
+    "h4" => DomElement { reserved: false, void: false },
+    "h5" => DomElement { reserved: false, void: false },
+    "h6" => DomElement { reserved: false, void: false },
+    "head" => DomElement { reserved: true, void: false },
+    "header" => DomElement { reserved: false, void: false },
+    "hgroup" => DomElement { reserved: false, void: false },
+    "hr" => DomElement { reserved: false, void: true },
+    "html" => DomElement { reserved: true, void: false },
+    "i" => DomElement { reserved: false, void: false },
+    "iframe" => DomElement { reserved: false, void: false },
+    "image" => DomElement { reserved: false, void: false },
+    "img" => DomElement { reserved: false, void: true },
+    "input" => DomElement { reserved: false, void: true },
+    "ins" => DomElement { reserved: false, void: false },
+    "isindex" => DomElement { reserved: false, void: false },
+    "kbd" => DomElement { reserved: false, void: false },
+    "keygen" => DomElement { reserved: false, void: true },
+    "label" => DomElement { reserved: false, void: false },
+    "legend" => DomElement { reserved: false, void: false },
+    "li" => DomElement { reserved: false, void: false },
+    "link" => DomElement { reserved: true, void: true },
+    "listing" => DomElement { reserved: false, void: false },
+    "main" => DomElement { reserved: false, void: false },
+    "map" => DomElement { reserved: false, void: false },
+    "mark" => DomElement { reserved: false, void: false },
+    "marquee" => DomElement { reserved: false, void: false },
+    "math" => DomElement { reserved: false, void: false },
+    "menu" => DomElement { reserved: false, void: false },
+    "menuitem" => DomElement { reserved: false, void: true },
+    "meta" => DomElement { reserved: true, void: true },
+    "meter" => DomElement { reserved: false, void: false },
+    "multicol" => DomElement { reserved: false, void: false },
+    "nav" => DomElement { reserved: false, void: false },
+    "nextid" => DomElement { reserved: false, void: false },
+    "nobr" => DomElement { reserved: false, void: false },
+    "noembed" => DomElement { reserved: true, void: false },
+    "noframes" => DomElement { reserved: false, void: false },
+    "noscript" => DomElement { reserved: true, void: false },
+    "object" => DomElement { reserved: false, void: false },
+    "ol" => DomElement { reserved: false, void: false },
+    "optgroup" => DomElement { reserved: false, void: false },
+    "option" => DomElement { reserved: false, void: false },
+    "output" => DomElement { reserved: false, void: false },
+    "p" => DomElement { reserved: false, void: false },
+    "param" => DomElement { reserved: true, void: true },
+    "picture" => DomElement { reserved: true, void: false },
+    "plaintext" => DomElement { reserved: false, void: false },
+    "pre" => DomElement { reserved: false, void: false },
+    "progress" => DomElement { reserved: false, void: false },
+    "q" => DomElement { reserved: false, void: false },
+    "rb" => DomElement { reserved: false, void: false },
+    "rbc" => DomElement { reserved: false, void: false },
+    "rp" => DomElement { reserved: false, void: false },
+    "rt" => DomElement { reserved: false, void: false },
+    "rtc" => DomElement { reserved: false, void: false },
+    "ruby" => DomElement { reserved: false, void: false },
+    "s" => DomElement { reserved: false, void: false },
+    "samp" => DomElement { reserved: false, void: false },
+    "script" => DomElement { reserved: true, void: false },
+    "search" => DomElement { reserved: false, void: false },
+    "section" => DomElement { reserved: false, void: false },
+    "select" => DomElement { reserved: false, void: false },
+    "shadow" => DomElement { reserved: false, void: false },
+    "slot" => DomElement { reserved: false, void: false },
+    "small" => DomElement { reserved: false, void: false },
+    "source" => DomElement { reserved: true, void: true },
+    "spacer" => DomElement { reserved: false, void: false },
+    "span" => DomElement { reserved: false, void: false },
+    "strike" => DomElement { reserved: false, void: false },
+    "strong" => DomElement { reserved: false, void: false },
+    "style" => DomElement { reserved: true, void: false },
+    "sub" => DomElement { reserved: false, void: false },
+    "summary" => DomElement { reserved: false, void: false },
+    "sup" => DomElement { reserved: false, void: false },
+    "svg" => DomElement { reserved: false, void: false },
+    "table" => DomElement { reserved: false, void: false },
+    "tbody" => DomElement { reserved: false, void: false },
+    "td" => DomElement { reserved: false, void: false },
+    "template" => DomElement { reserved: false, void: false },
+    "textarea" => DomElement { reserved: false, void: false },
+    "tfoot" => DomElement { reserved: false, void: false },
+    "th" => DomElement { reserved: false, void: false },
+    "thead" => DomElement { reserved: false, void: false },
+    "time" => DomElement { reserved: false, void: false },
+    "title" => DomElement { reserved: true, void: false },
+    "tr" => DomElement { reserved: false, void: false },
+    "track" => DomElement { reserved: true, void: true },
+    "tt" => DomElement { reserved: false, void: false },
+    "u" => DomElement { reserved: false, void: false },
+    "ul" => DomElement { reserved: false, void: false },
+    "var" => DomElement { reserved: false, void: false },
+    "video" => DomElement { reserved: false, void: false },
+    "wbr" => DomElement { reserved: false, void: true },
+    "xmp" => DomElement { reserved: false, void: false },
+};
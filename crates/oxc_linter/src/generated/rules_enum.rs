@@ -168,6 +168,7 @@ pub use crate::rules::eslint::no_void::NoVoid as EslintNoVoid;
 pub use crate::rules::eslint::no_warning_comments::NoWarningComments as EslintNoWarningComments;
 pub use crate::rules::eslint::no_with::NoWith as EslintNoWith;
 pub use crate::rules::eslint::object_shorthand::ObjectShorthand as EslintObjectShorthand;
+pub use crate::rules::eslint::one_var::OneVar as EslintOneVar;
 pub use crate::rules::eslint::operator_assignment::OperatorAssignment as EslintOperatorAssignment;
 pub use crate::rules::eslint::prefer_arrow_callback::PreferArrowCallback as EslintPreferArrowCallback;
 pub use crate::rules::eslint::prefer_const::PreferConst as EslintPreferConst;
@@ -226,6 +227,7 @@ pub use crate::rules::import::no_nodejs_modules::NoNodejsModules as ImportNoNode
 pub use crate::rules::import::no_relative_parent_imports::NoRelativeParentImports as ImportNoRelativeParentImports;
 pub use crate::rules::import::no_self_import::NoSelfImport as ImportNoSelfImport;
 pub use crate::rules::import::no_unassigned_import::NoUnassignedImport as ImportNoUnassignedImport;
+pub use crate::rules::import::no_useless_path_segments::NoUselessPathSegments as ImportNoUselessPathSegments;
 pub use crate::rules::import::no_webpack_loader_syntax::NoWebpackLoaderSyntax as ImportNoWebpackLoaderSyntax;
 pub use crate::rules::import::prefer_default_export::PreferDefaultExport as ImportPreferDefaultExport;
 pub use crate::rules::import::unambiguous::Unambiguous as ImportUnambiguous;
@@ -306,6 +308,7 @@ pub use crate::rules::jsdoc::require_property_type::RequirePropertyType as Jsdoc
 pub use crate::rules::jsdoc::require_returns::RequireReturns as JsdocRequireReturns;
 pub use crate::rules::jsdoc::require_returns_description::RequireReturnsDescription as JsdocRequireReturnsDescription;
 pub use crate::rules::jsdoc::require_returns_type::RequireReturnsType as JsdocRequireReturnsType;
+pub use crate::rules::jsdoc::require_throws::RequireThrows as JsdocRequireThrows;
 pub use crate::rules::jsdoc::require_throws_description::RequireThrowsDescription as JsdocRequireThrowsDescription;
 pub use crate::rules::jsdoc::require_throws_type::RequireThrowsType as JsdocRequireThrowsType;
 pub use crate::rules::jsdoc::require_yields::RequireYields as JsdocRequireYields;
@@ -381,6 +384,7 @@ pub use crate::rules::node::no_sync::NoSync as NodeNoSync;
 pub use crate::rules::node::no_top_level_await::NoTopLevelAwait as NodeNoTopLevelAwait;
 pub use crate::rules::oxc::approx_constant::ApproxConstant as OxcApproxConstant;
 pub use crate::rules::oxc::bad_array_method_on_arguments::BadArrayMethodOnArguments as OxcBadArrayMethodOnArguments;
+pub use crate::rules::oxc::bad_bigint_arg::BadBigintArg as OxcBadBigintArg;
 pub use crate::rules::oxc::bad_bitwise_operator::BadBitwiseOperator as OxcBadBitwiseOperator;
 pub use crate::rules::oxc::bad_char_at_comparison::BadCharAtComparison as OxcBadCharAtComparison;
 pub use crate::rules::oxc::bad_comparison_sequence::BadComparisonSequence as OxcBadComparisonSequence;
@@ -391,19 +395,36 @@ pub use crate::rules::oxc::branches_sharing_code::BranchesSharingCode as OxcBran
 pub use crate::rules::oxc::const_comparisons::ConstComparisons as OxcConstComparisons;
 pub use crate::rules::oxc::double_comparisons::DoubleComparisons as OxcDoubleComparisons;
 pub use crate::rules::oxc::erasing_op::ErasingOp as OxcErasingOp;
+pub use crate::rules::oxc::max_rules_per_directive::MaxRulesPerDirective as OxcMaxRulesPerDirective;
 pub use crate::rules::oxc::misrefactored_assign_op::MisrefactoredAssignOp as OxcMisrefactoredAssignOp;
 pub use crate::rules::oxc::missing_throw::MissingThrow as OxcMissingThrow;
 pub use crate::rules::oxc::no_accumulating_spread::NoAccumulatingSpread as OxcNoAccumulatingSpread;
 pub use crate::rules::oxc::no_async_await::NoAsyncAwait as OxcNoAsyncAwait;
 pub use crate::rules::oxc::no_async_endpoint_handlers::NoAsyncEndpointHandlers as OxcNoAsyncEndpointHandlers;
 pub use crate::rules::oxc::no_barrel_file::NoBarrelFile as OxcNoBarrelFile;
+pub use crate::rules::oxc::no_class_level_directives::NoClassLevelDirectives as OxcNoClassLevelDirectives;
 pub use crate::rules::oxc::no_const_enum::NoConstEnum as OxcNoConstEnum;
+pub use crate::rules::oxc::no_deprecated_directive_rules::NoDeprecatedDirectiveRules as OxcNoDeprecatedDirectiveRules;
+pub use crate::rules::oxc::no_disabled_correctness_rules::NoDisabledCorrectnessRules as OxcNoDisabledCorrectnessRules;
+pub use crate::rules::oxc::no_disabled_explicit_any::NoDisabledExplicitAny as OxcNoDisabledExplicitAny;
+pub use crate::rules::oxc::no_duplicate_type_constituents::NoDuplicateTypeConstituents as OxcNoDuplicateTypeConstituents;
+pub use crate::rules::oxc::no_eslint_disable_comments::NoEslintDisableComments as OxcNoEslintDisableComments;
+pub use crate::rules::oxc::no_full_width_directives::NoFullWidthDirectives as OxcNoFullWidthDirectives;
+pub use crate::rules::oxc::no_irrelevant_directive_rules::NoIrrelevantDirectiveRules as OxcNoIrrelevantDirectiveRules;
 pub use crate::rules::oxc::no_map_spread::NoMapSpread as OxcNoMapSpread;
 pub use crate::rules::oxc::no_optional_chaining::NoOptionalChaining as OxcNoOptionalChaining;
+pub use crate::rules::oxc::no_redundant_enable_comments::NoRedundantEnableComments as OxcNoRedundantEnableComments;
+pub use crate::rules::oxc::no_redundant_line_directives::NoRedundantLineDirectives as OxcNoRedundantLineDirectives;
 pub use crate::rules::oxc::no_rest_spread_properties::NoRestSpreadProperties as OxcNoRestSpreadProperties;
+pub use crate::rules::oxc::no_stacked_next_line_directives::NoStackedNextLineDirectives as OxcNoStackedNextLineDirectives;
+pub use crate::rules::oxc::no_stale_directives::NoStaleDirectives as OxcNoStaleDirectives;
+pub use crate::rules::oxc::no_stripped_directives::NoStrippedDirectives as OxcNoStrippedDirectives;
 pub use crate::rules::oxc::no_this_in_exported_function::NoThisInExportedFunction as OxcNoThisInExportedFunction;
+pub use crate::rules::oxc::no_unsupported_directive_rules::NoUnsupportedDirectiveRules as OxcNoUnsupportedDirectiveRules;
+pub use crate::rules::oxc::no_unterminated_disable_comments::NoUnterminatedDisableComments as OxcNoUnterminatedDisableComments;
 pub use crate::rules::oxc::number_arg_out_of_range::NumberArgOutOfRange as OxcNumberArgOutOfRange;
 pub use crate::rules::oxc::only_used_in_recursion::OnlyUsedInRecursion as OxcOnlyUsedInRecursion;
+pub use crate::rules::oxc::require_directive_ticket::RequireDirectiveTicket as OxcRequireDirectiveTicket;
 pub use crate::rules::oxc::uninvoked_array_callback::UninvokedArrayCallback as OxcUninvokedArrayCallback;
 pub use crate::rules::promise::always_return::AlwaysReturn as PromiseAlwaysReturn;
 pub use crate::rules::promise::avoid_new::AvoidNew as PromiseAvoidNew;
@@ -859,7 +880,10 @@ pub use crate::rules::vue::valid_next_tick::ValidNextTick as VueValidNextTick;
 use crate::{
     AstNode,
     context::{ContextHost, LintContext},
-    rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
+    rule::{
+        Rule, RuleCategory, RuleFixMeta, RuleLanguage, RuleMeta, RuleRunFunctionsImplemented,
+        RuleRunner,
+    },
     timing::RuleTimingStat,
     utils::PossibleJestNode,
 };
@@ -896,6 +920,7 @@ pub enum RuleEnum {
     ImportNoRelativeParentImports(ImportNoRelativeParentImports),
     ImportNoSelfImport(ImportNoSelfImport),
     ImportNoUnassignedImport(ImportNoUnassignedImport),
+    ImportNoUselessPathSegments(ImportNoUselessPathSegments),
     ImportNoWebpackLoaderSyntax(ImportNoWebpackLoaderSyntax),
     ImportPreferDefaultExport(ImportPreferDefaultExport),
     ImportUnambiguous(ImportUnambiguous),
@@ -1057,6 +1082,7 @@ pub enum RuleEnum {
     EslintNoWarningComments(EslintNoWarningComments),
     EslintNoWith(EslintNoWith),
     EslintObjectShorthand(EslintObjectShorthand),
+    EslintOneVar(EslintOneVar),
     EslintOperatorAssignment(EslintOperatorAssignment),
     EslintPreferArrowCallback(EslintPreferArrowCallback),
     EslintPreferConst(EslintPreferConst),
@@ -1505,6 +1531,7 @@ pub enum RuleEnum {
     JsxA11YTabindexNoPositive(JsxA11YTabindexNoPositive),
     OxcApproxConstant(OxcApproxConstant),
     OxcBadArrayMethodOnArguments(OxcBadArrayMethodOnArguments),
+    OxcBadBigintArg(OxcBadBigintArg),
     OxcBadBitwiseOperator(OxcBadBitwiseOperator),
     OxcBadCharAtComparison(OxcBadCharAtComparison),
     OxcBadComparisonSequence(OxcBadComparisonSequence),
@@ -1515,19 +1542,36 @@ pub enum RuleEnum {
     OxcConstComparisons(OxcConstComparisons),
     OxcDoubleComparisons(OxcDoubleComparisons),
     OxcErasingOp(OxcErasingOp),
+    OxcMaxRulesPerDirective(OxcMaxRulesPerDirective),
     OxcMisrefactoredAssignOp(OxcMisrefactoredAssignOp),
     OxcMissingThrow(OxcMissingThrow),
     OxcNoAccumulatingSpread(OxcNoAccumulatingSpread),
     OxcNoAsyncAwait(OxcNoAsyncAwait),
     OxcNoAsyncEndpointHandlers(OxcNoAsyncEndpointHandlers),
     OxcNoBarrelFile(OxcNoBarrelFile),
+    OxcNoClassLevelDirectives(OxcNoClassLevelDirectives),
     OxcNoConstEnum(OxcNoConstEnum),
+    OxcNoDeprecatedDirectiveRules(OxcNoDeprecatedDirectiveRules),
+    OxcNoDisabledCorrectnessRules(OxcNoDisabledCorrectnessRules),
+    OxcNoDisabledExplicitAny(OxcNoDisabledExplicitAny),
+    OxcNoDuplicateTypeConstituents(OxcNoDuplicateTypeConstituents),
+    OxcNoEslintDisableComments(OxcNoEslintDisableComments),
+    OxcNoFullWidthDirectives(OxcNoFullWidthDirectives),
+    OxcNoIrrelevantDirectiveRules(OxcNoIrrelevantDirectiveRules),
     OxcNoMapSpread(OxcNoMapSpread),
     OxcNoOptionalChaining(OxcNoOptionalChaining),
+    OxcNoRedundantEnableComments(OxcNoRedundantEnableComments),
+    OxcNoRedundantLineDirectives(OxcNoRedundantLineDirectives),
     OxcNoRestSpreadProperties(OxcNoRestSpreadProperties),
+    OxcNoStackedNextLineDirectives(OxcNoStackedNextLineDirectives),
+    OxcNoStaleDirectives(OxcNoStaleDirectives),
+    OxcNoStrippedDirectives(OxcNoStrippedDirectives),
     OxcNoThisInExportedFunction(OxcNoThisInExportedFunction),
+    OxcNoUnsupportedDirectiveRules(OxcNoUnsupportedDirectiveRules),
+    OxcNoUnterminatedDisableComments(OxcNoUnterminatedDisableComments),
     OxcNumberArgOutOfRange(OxcNumberArgOutOfRange),
     OxcOnlyUsedInRecursion(OxcOnlyUsedInRecursion),
+    OxcRequireDirectiveTicket(OxcRequireDirectiveTicket),
     OxcUninvokedArrayCallback(OxcUninvokedArrayCallback),
     NextjsGoogleFontDisplay(NextjsGoogleFontDisplay),
     NextjsGoogleFontPreconnect(NextjsGoogleFontPreconnect),
@@ -1567,6 +1611,7 @@ pub enum RuleEnum {
     JsdocRequireReturns(JsdocRequireReturns),
     JsdocRequireReturnsDescription(JsdocRequireReturnsDescription),
     JsdocRequireReturnsType(JsdocRequireReturnsType),
+    JsdocRequireThrows(JsdocRequireThrows),
     JsdocRequireThrowsDescription(JsdocRequireThrowsDescription),
     JsdocRequireThrowsType(JsdocRequireThrowsType),
     JsdocRequireYields(JsdocRequireYields),
@@ -1751,7 +1796,8 @@ const IMPORT_NO_NODEJS_MODULES_ID: usize = IMPORT_NO_NAMESPACE_ID + 1usize;
 const IMPORT_NO_RELATIVE_PARENT_IMPORTS_ID: usize = IMPORT_NO_NODEJS_MODULES_ID + 1usize;
 const IMPORT_NO_SELF_IMPORT_ID: usize = IMPORT_NO_RELATIVE_PARENT_IMPORTS_ID + 1usize;
 const IMPORT_NO_UNASSIGNED_IMPORT_ID: usize = IMPORT_NO_SELF_IMPORT_ID + 1usize;
-const IMPORT_NO_WEBPACK_LOADER_SYNTAX_ID: usize = IMPORT_NO_UNASSIGNED_IMPORT_ID + 1usize;
+const IMPORT_NO_USELESS_PATH_SEGMENTS_ID: usize = IMPORT_NO_UNASSIGNED_IMPORT_ID + 1usize;
+const IMPORT_NO_WEBPACK_LOADER_SYNTAX_ID: usize = IMPORT_NO_USELESS_PATH_SEGMENTS_ID + 1usize;
 const IMPORT_PREFER_DEFAULT_EXPORT_ID: usize = IMPORT_NO_WEBPACK_LOADER_SYNTAX_ID + 1usize;
 const IMPORT_UNAMBIGUOUS_ID: usize = IMPORT_PREFER_DEFAULT_EXPORT_ID + 1usize;
 const ESLINT_ACCESSOR_PAIRS_ID: usize = IMPORT_UNAMBIGUOUS_ID + 1usize;
@@ -1912,7 +1958,8 @@ const ESLINT_NO_VOID_ID: usize = ESLINT_NO_VAR_ID + 1usize;
 const ESLINT_NO_WARNING_COMMENTS_ID: usize = ESLINT_NO_VOID_ID + 1usize;
 const ESLINT_NO_WITH_ID: usize = ESLINT_NO_WARNING_COMMENTS_ID + 1usize;
 const ESLINT_OBJECT_SHORTHAND_ID: usize = ESLINT_NO_WITH_ID + 1usize;
-const ESLINT_OPERATOR_ASSIGNMENT_ID: usize = ESLINT_OBJECT_SHORTHAND_ID + 1usize;
+const ESLINT_ONE_VAR_ID: usize = ESLINT_OBJECT_SHORTHAND_ID + 1usize;
+const ESLINT_OPERATOR_ASSIGNMENT_ID: usize = ESLINT_ONE_VAR_ID + 1usize;
 const ESLINT_PREFER_ARROW_CALLBACK_ID: usize = ESLINT_OPERATOR_ASSIGNMENT_ID + 1usize;
 const ESLINT_PREFER_CONST_ID: usize = ESLINT_PREFER_ARROW_CALLBACK_ID + 1usize;
 const ESLINT_PREFER_DESTRUCTURING_ID: usize = ESLINT_PREFER_CONST_ID + 1usize;
@@ -2445,7 +2492,8 @@ const JSX_A_11_Y_SCOPE_ID: usize = JSX_A_11_Y_ROLE_SUPPORTS_ARIA_PROPS_ID + 1usi
 const JSX_A_11_Y_TABINDEX_NO_POSITIVE_ID: usize = JSX_A_11_Y_SCOPE_ID + 1usize;
 const OXC_APPROX_CONSTANT_ID: usize = JSX_A_11_Y_TABINDEX_NO_POSITIVE_ID + 1usize;
 const OXC_BAD_ARRAY_METHOD_ON_ARGUMENTS_ID: usize = OXC_APPROX_CONSTANT_ID + 1usize;
-const OXC_BAD_BITWISE_OPERATOR_ID: usize = OXC_BAD_ARRAY_METHOD_ON_ARGUMENTS_ID + 1usize;
+const OXC_BAD_BIGINT_ARG_ID: usize = OXC_BAD_ARRAY_METHOD_ON_ARGUMENTS_ID + 1usize;
+const OXC_BAD_BITWISE_OPERATOR_ID: usize = OXC_BAD_BIGINT_ARG_ID + 1usize;
 const OXC_BAD_CHAR_AT_COMPARISON_ID: usize = OXC_BAD_BITWISE_OPERATOR_ID + 1usize;
 const OXC_BAD_COMPARISON_SEQUENCE_ID: usize = OXC_BAD_CHAR_AT_COMPARISON_ID + 1usize;
 const OXC_BAD_MIN_MAX_FUNC_ID: usize = OXC_BAD_COMPARISON_SEQUENCE_ID + 1usize;
@@ -2455,20 +2503,38 @@ const OXC_BRANCHES_SHARING_CODE_ID: usize = OXC_BAD_REPLACE_ALL_ARG_ID + 1usize;
 const OXC_CONST_COMPARISONS_ID: usize = OXC_BRANCHES_SHARING_CODE_ID + 1usize;
 const OXC_DOUBLE_COMPARISONS_ID: usize = OXC_CONST_COMPARISONS_ID + 1usize;
 const OXC_ERASING_OP_ID: usize = OXC_DOUBLE_COMPARISONS_ID + 1usize;
-const OXC_MISREFACTORED_ASSIGN_OP_ID: usize = OXC_ERASING_OP_ID + 1usize;
+const OXC_MAX_RULES_PER_DIRECTIVE_ID: usize = OXC_ERASING_OP_ID + 1usize;
+const OXC_MISREFACTORED_ASSIGN_OP_ID: usize = OXC_MAX_RULES_PER_DIRECTIVE_ID + 1usize;
 const OXC_MISSING_THROW_ID: usize = OXC_MISREFACTORED_ASSIGN_OP_ID + 1usize;
 const OXC_NO_ACCUMULATING_SPREAD_ID: usize = OXC_MISSING_THROW_ID + 1usize;
 const OXC_NO_ASYNC_AWAIT_ID: usize = OXC_NO_ACCUMULATING_SPREAD_ID + 1usize;
 const OXC_NO_ASYNC_ENDPOINT_HANDLERS_ID: usize = OXC_NO_ASYNC_AWAIT_ID + 1usize;
 const OXC_NO_BARREL_FILE_ID: usize = OXC_NO_ASYNC_ENDPOINT_HANDLERS_ID + 1usize;
-const OXC_NO_CONST_ENUM_ID: usize = OXC_NO_BARREL_FILE_ID + 1usize;
-const OXC_NO_MAP_SPREAD_ID: usize = OXC_NO_CONST_ENUM_ID + 1usize;
+const OXC_NO_CLASS_LEVEL_DIRECTIVES_ID: usize = OXC_NO_BARREL_FILE_ID + 1usize;
+const OXC_NO_CONST_ENUM_ID: usize = OXC_NO_CLASS_LEVEL_DIRECTIVES_ID + 1usize;
+const OXC_NO_DEPRECATED_DIRECTIVE_RULES_ID: usize = OXC_NO_CONST_ENUM_ID + 1usize;
+const OXC_NO_DISABLED_CORRECTNESS_RULES_ID: usize = OXC_NO_DEPRECATED_DIRECTIVE_RULES_ID + 1usize;
+const OXC_NO_DISABLED_EXPLICIT_ANY_ID: usize = OXC_NO_DISABLED_CORRECTNESS_RULES_ID + 1usize;
+const OXC_NO_DUPLICATE_TYPE_CONSTITUENTS_ID: usize = OXC_NO_DISABLED_EXPLICIT_ANY_ID + 1usize;
+const OXC_NO_ESLINT_DISABLE_COMMENTS_ID: usize = OXC_NO_DUPLICATE_TYPE_CONSTITUENTS_ID + 1usize;
+const OXC_NO_FULL_WIDTH_DIRECTIVES_ID: usize = OXC_NO_ESLINT_DISABLE_COMMENTS_ID + 1usize;
+const OXC_NO_IRRELEVANT_DIRECTIVE_RULES_ID: usize = OXC_NO_FULL_WIDTH_DIRECTIVES_ID + 1usize;
+const OXC_NO_MAP_SPREAD_ID: usize = OXC_NO_IRRELEVANT_DIRECTIVE_RULES_ID + 1usize;
 const OXC_NO_OPTIONAL_CHAINING_ID: usize = OXC_NO_MAP_SPREAD_ID + 1usize;
-const OXC_NO_REST_SPREAD_PROPERTIES_ID: usize = OXC_NO_OPTIONAL_CHAINING_ID + 1usize;
-const OXC_NO_THIS_IN_EXPORTED_FUNCTION_ID: usize = OXC_NO_REST_SPREAD_PROPERTIES_ID + 1usize;
-const OXC_NUMBER_ARG_OUT_OF_RANGE_ID: usize = OXC_NO_THIS_IN_EXPORTED_FUNCTION_ID + 1usize;
+const OXC_NO_REDUNDANT_ENABLE_COMMENTS_ID: usize = OXC_NO_OPTIONAL_CHAINING_ID + 1usize;
+const OXC_NO_REDUNDANT_LINE_DIRECTIVES_ID: usize = OXC_NO_REDUNDANT_ENABLE_COMMENTS_ID + 1usize;
+const OXC_NO_REST_SPREAD_PROPERTIES_ID: usize = OXC_NO_REDUNDANT_LINE_DIRECTIVES_ID + 1usize;
+const OXC_NO_STACKED_NEXT_LINE_DIRECTIVES_ID: usize = OXC_NO_REST_SPREAD_PROPERTIES_ID + 1usize;
+const OXC_NO_STALE_DIRECTIVES_ID: usize = OXC_NO_STACKED_NEXT_LINE_DIRECTIVES_ID + 1usize;
+const OXC_NO_STRIPPED_DIRECTIVES_ID: usize = OXC_NO_STALE_DIRECTIVES_ID + 1usize;
+const OXC_NO_THIS_IN_EXPORTED_FUNCTION_ID: usize = OXC_NO_STRIPPED_DIRECTIVES_ID + 1usize;
+const OXC_NO_UNSUPPORTED_DIRECTIVE_RULES_ID: usize = OXC_NO_THIS_IN_EXPORTED_FUNCTION_ID + 1usize;
+const OXC_NO_UNTERMINATED_DISABLE_COMMENTS_ID: usize =
+    OXC_NO_UNSUPPORTED_DIRECTIVE_RULES_ID + 1usize;
+const OXC_NUMBER_ARG_OUT_OF_RANGE_ID: usize = OXC_NO_UNTERMINATED_DISABLE_COMMENTS_ID + 1usize;
 const OXC_ONLY_USED_IN_RECURSION_ID: usize = OXC_NUMBER_ARG_OUT_OF_RANGE_ID + 1usize;
-const OXC_UNINVOKED_ARRAY_CALLBACK_ID: usize = OXC_ONLY_USED_IN_RECURSION_ID + 1usize;
+const OXC_REQUIRE_DIRECTIVE_TICKET_ID: usize = OXC_ONLY_USED_IN_RECURSION_ID + 1usize;
+const OXC_UNINVOKED_ARRAY_CALLBACK_ID: usize = OXC_REQUIRE_DIRECTIVE_TICKET_ID + 1usize;
 const NEXTJS_GOOGLE_FONT_DISPLAY_ID: usize = OXC_UNINVOKED_ARRAY_CALLBACK_ID + 1usize;
 const NEXTJS_GOOGLE_FONT_PRECONNECT_ID: usize = NEXTJS_GOOGLE_FONT_DISPLAY_ID + 1usize;
 const NEXTJS_INLINE_SCRIPT_ID_ID: usize = NEXTJS_GOOGLE_FONT_PRECONNECT_ID + 1usize;
@@ -2509,7 +2575,8 @@ const JSDOC_REQUIRE_PROPERTY_TYPE_ID: usize = JSDOC_REQUIRE_PROPERTY_NAME_ID + 1
 const JSDOC_REQUIRE_RETURNS_ID: usize = JSDOC_REQUIRE_PROPERTY_TYPE_ID + 1usize;
 const JSDOC_REQUIRE_RETURNS_DESCRIPTION_ID: usize = JSDOC_REQUIRE_RETURNS_ID + 1usize;
 const JSDOC_REQUIRE_RETURNS_TYPE_ID: usize = JSDOC_REQUIRE_RETURNS_DESCRIPTION_ID + 1usize;
-const JSDOC_REQUIRE_THROWS_DESCRIPTION_ID: usize = JSDOC_REQUIRE_RETURNS_TYPE_ID + 1usize;
+const JSDOC_REQUIRE_THROWS_ID: usize = JSDOC_REQUIRE_RETURNS_TYPE_ID + 1usize;
+const JSDOC_REQUIRE_THROWS_DESCRIPTION_ID: usize = JSDOC_REQUIRE_THROWS_ID + 1usize;
 const JSDOC_REQUIRE_THROWS_TYPE_ID: usize = JSDOC_REQUIRE_THROWS_DESCRIPTION_ID + 1usize;
 const JSDOC_REQUIRE_YIELDS_ID: usize = JSDOC_REQUIRE_THROWS_TYPE_ID + 1usize;
 const JSDOC_REQUIRE_YIELDS_DESCRIPTION_ID: usize = JSDOC_REQUIRE_YIELDS_ID + 1usize;
@@ -2711,6 +2778,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => IMPORT_NO_RELATIVE_PARENT_IMPORTS_ID,
             Self::ImportNoSelfImport(_) => IMPORT_NO_SELF_IMPORT_ID,
             Self::ImportNoUnassignedImport(_) => IMPORT_NO_UNASSIGNED_IMPORT_ID,
+            Self::ImportNoUselessPathSegments(_) => IMPORT_NO_USELESS_PATH_SEGMENTS_ID,
             Self::ImportNoWebpackLoaderSyntax(_) => IMPORT_NO_WEBPACK_LOADER_SYNTAX_ID,
             Self::ImportPreferDefaultExport(_) => IMPORT_PREFER_DEFAULT_EXPORT_ID,
             Self::ImportUnambiguous(_) => IMPORT_UNAMBIGUOUS_ID,
@@ -2872,6 +2940,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => ESLINT_NO_WARNING_COMMENTS_ID,
             Self::EslintNoWith(_) => ESLINT_NO_WITH_ID,
             Self::EslintObjectShorthand(_) => ESLINT_OBJECT_SHORTHAND_ID,
+            Self::EslintOneVar(_) => ESLINT_ONE_VAR_ID,
             Self::EslintOperatorAssignment(_) => ESLINT_OPERATOR_ASSIGNMENT_ID,
             Self::EslintPreferArrowCallback(_) => ESLINT_PREFER_ARROW_CALLBACK_ID,
             Self::EslintPreferConst(_) => ESLINT_PREFER_CONST_ID,
@@ -3428,6 +3497,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => JSX_A_11_Y_TABINDEX_NO_POSITIVE_ID,
             Self::OxcApproxConstant(_) => OXC_APPROX_CONSTANT_ID,
             Self::OxcBadArrayMethodOnArguments(_) => OXC_BAD_ARRAY_METHOD_ON_ARGUMENTS_ID,
+            Self::OxcBadBigintArg(_) => OXC_BAD_BIGINT_ARG_ID,
             Self::OxcBadBitwiseOperator(_) => OXC_BAD_BITWISE_OPERATOR_ID,
             Self::OxcBadCharAtComparison(_) => OXC_BAD_CHAR_AT_COMPARISON_ID,
             Self::OxcBadComparisonSequence(_) => OXC_BAD_COMPARISON_SEQUENCE_ID,
@@ -3438,19 +3508,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => OXC_CONST_COMPARISONS_ID,
             Self::OxcDoubleComparisons(_) => OXC_DOUBLE_COMPARISONS_ID,
             Self::OxcErasingOp(_) => OXC_ERASING_OP_ID,
+            Self::OxcMaxRulesPerDirective(_) => OXC_MAX_RULES_PER_DIRECTIVE_ID,
             Self::OxcMisrefactoredAssignOp(_) => OXC_MISREFACTORED_ASSIGN_OP_ID,
             Self::OxcMissingThrow(_) => OXC_MISSING_THROW_ID,
             Self::OxcNoAccumulatingSpread(_) => OXC_NO_ACCUMULATING_SPREAD_ID,
             Self::OxcNoAsyncAwait(_) => OXC_NO_ASYNC_AWAIT_ID,
             Self::OxcNoAsyncEndpointHandlers(_) => OXC_NO_ASYNC_ENDPOINT_HANDLERS_ID,
             Self::OxcNoBarrelFile(_) => OXC_NO_BARREL_FILE_ID,
+            Self::OxcNoClassLevelDirectives(_) => OXC_NO_CLASS_LEVEL_DIRECTIVES_ID,
             Self::OxcNoConstEnum(_) => OXC_NO_CONST_ENUM_ID,
+            Self::OxcNoDeprecatedDirectiveRules(_) => OXC_NO_DEPRECATED_DIRECTIVE_RULES_ID,
+            Self::OxcNoDisabledCorrectnessRules(_) => OXC_NO_DISABLED_CORRECTNESS_RULES_ID,
+            Self::OxcNoDisabledExplicitAny(_) => OXC_NO_DISABLED_EXPLICIT_ANY_ID,
+            Self::OxcNoDuplicateTypeConstituents(_) => OXC_NO_DUPLICATE_TYPE_CONSTITUENTS_ID,
+            Self::OxcNoEslintDisableComments(_) => OXC_NO_ESLINT_DISABLE_COMMENTS_ID,
+            Self::OxcNoFullWidthDirectives(_) => OXC_NO_FULL_WIDTH_DIRECTIVES_ID,
+            Self::OxcNoIrrelevantDirectiveRules(_) => OXC_NO_IRRELEVANT_DIRECTIVE_RULES_ID,
             Self::OxcNoMapSpread(_) => OXC_NO_MAP_SPREAD_ID,
             Self::OxcNoOptionalChaining(_) => OXC_NO_OPTIONAL_CHAINING_ID,
+            Self::OxcNoRedundantEnableComments(_) => OXC_NO_REDUNDANT_ENABLE_COMMENTS_ID,
+            Self::OxcNoRedundantLineDirectives(_) => OXC_NO_REDUNDANT_LINE_DIRECTIVES_ID,
             Self::OxcNoRestSpreadProperties(_) => OXC_NO_REST_SPREAD_PROPERTIES_ID,
+            Self::OxcNoStackedNextLineDirectives(_) => OXC_NO_STACKED_NEXT_LINE_DIRECTIVES_ID,
+            Self::OxcNoStaleDirectives(_) => OXC_NO_STALE_DIRECTIVES_ID,
+            Self::OxcNoStrippedDirectives(_) => OXC_NO_STRIPPED_DIRECTIVES_ID,
             Self::OxcNoThisInExportedFunction(_) => OXC_NO_THIS_IN_EXPORTED_FUNCTION_ID,
+            Self::OxcNoUnsupportedDirectiveRules(_) => OXC_NO_UNSUPPORTED_DIRECTIVE_RULES_ID,
+            Self::OxcNoUnterminatedDisableComments(_) => OXC_NO_UNTERMINATED_DISABLE_COMMENTS_ID,
             Self::OxcNumberArgOutOfRange(_) => OXC_NUMBER_ARG_OUT_OF_RANGE_ID,
             Self::OxcOnlyUsedInRecursion(_) => OXC_ONLY_USED_IN_RECURSION_ID,
+            Self::OxcRequireDirectiveTicket(_) => OXC_REQUIRE_DIRECTIVE_TICKET_ID,
             Self::OxcUninvokedArrayCallback(_) => OXC_UNINVOKED_ARRAY_CALLBACK_ID,
             Self::NextjsGoogleFontDisplay(_) => NEXTJS_GOOGLE_FONT_DISPLAY_ID,
             Self::NextjsGoogleFontPreconnect(_) => NEXTJS_GOOGLE_FONT_PRECONNECT_ID,
@@ -3492,6 +3579,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(_) => JSDOC_REQUIRE_RETURNS_ID,
             Self::JsdocRequireReturnsDescription(_) => JSDOC_REQUIRE_RETURNS_DESCRIPTION_ID,
             Self::JsdocRequireReturnsType(_) => JSDOC_REQUIRE_RETURNS_TYPE_ID,
+            Self::JsdocRequireThrows(_) => JSDOC_REQUIRE_THROWS_ID,
             Self::JsdocRequireThrowsDescription(_) => JSDOC_REQUIRE_THROWS_DESCRIPTION_ID,
             Self::JsdocRequireThrowsType(_) => JSDOC_REQUIRE_THROWS_TYPE_ID,
             Self::JsdocRequireYields(_) => JSDOC_REQUIRE_YIELDS_ID,
@@ -3689,6 +3777,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::NAME,
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::NAME,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::NAME,
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::NAME,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::NAME,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::NAME,
             Self::ImportUnambiguous(_) => ImportUnambiguous::NAME,
@@ -3850,6 +3939,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => EslintNoWarningComments::NAME,
             Self::EslintNoWith(_) => EslintNoWith::NAME,
             Self::EslintObjectShorthand(_) => EslintObjectShorthand::NAME,
+            Self::EslintOneVar(_) => EslintOneVar::NAME,
             Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::NAME,
             Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::NAME,
             Self::EslintPreferConst(_) => EslintPreferConst::NAME,
@@ -4396,6 +4486,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::NAME,
             Self::OxcApproxConstant(_) => OxcApproxConstant::NAME,
             Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::NAME,
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::NAME,
             Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::NAME,
             Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::NAME,
             Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::NAME,
@@ -4406,19 +4497,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => OxcConstComparisons::NAME,
             Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::NAME,
             Self::OxcErasingOp(_) => OxcErasingOp::NAME,
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::NAME,
             Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::NAME,
             Self::OxcMissingThrow(_) => OxcMissingThrow::NAME,
             Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::NAME,
             Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::NAME,
             Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::NAME,
             Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::NAME,
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::NAME,
             Self::OxcNoConstEnum(_) => OxcNoConstEnum::NAME,
+            Self::OxcNoDeprecatedDirectiveRules(_) => OxcNoDeprecatedDirectiveRules::NAME,
+            Self::OxcNoDisabledCorrectnessRules(_) => OxcNoDisabledCorrectnessRules::NAME,
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::NAME,
+            Self::OxcNoDuplicateTypeConstituents(_) => OxcNoDuplicateTypeConstituents::NAME,
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::NAME,
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::NAME,
+            Self::OxcNoIrrelevantDirectiveRules(_) => OxcNoIrrelevantDirectiveRules::NAME,
             Self::OxcNoMapSpread(_) => OxcNoMapSpread::NAME,
             Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::NAME,
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::NAME,
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::NAME,
             Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::NAME,
+            Self::OxcNoStackedNextLineDirectives(_) => OxcNoStackedNextLineDirectives::NAME,
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::NAME,
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::NAME,
             Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::NAME,
+            Self::OxcNoUnsupportedDirectiveRules(_) => OxcNoUnsupportedDirectiveRules::NAME,
+            Self::OxcNoUnterminatedDisableComments(_) => OxcNoUnterminatedDisableComments::NAME,
             Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::NAME,
             Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::NAME,
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::NAME,
             Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::NAME,
             Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::NAME,
             Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::NAME,
@@ -4460,6 +4568,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(_) => JsdocRequireReturns::NAME,
             Self::JsdocRequireReturnsDescription(_) => JsdocRequireReturnsDescription::NAME,
             Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::NAME,
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::NAME,
             Self::JsdocRequireThrowsDescription(_) => JsdocRequireThrowsDescription::NAME,
             Self::JsdocRequireThrowsType(_) => JsdocRequireThrowsType::NAME,
             Self::JsdocRequireYields(_) => JsdocRequireYields::NAME,
@@ -4655,6 +4764,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::CATEGORY,
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::CATEGORY,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::CATEGORY,
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::CATEGORY,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::CATEGORY,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::CATEGORY,
             Self::ImportUnambiguous(_) => ImportUnambiguous::CATEGORY,
@@ -4818,6 +4928,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => EslintNoWarningComments::CATEGORY,
             Self::EslintNoWith(_) => EslintNoWith::CATEGORY,
             Self::EslintObjectShorthand(_) => EslintObjectShorthand::CATEGORY,
+            Self::EslintOneVar(_) => EslintOneVar::CATEGORY,
             Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::CATEGORY,
             Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::CATEGORY,
             Self::EslintPreferConst(_) => EslintPreferConst::CATEGORY,
@@ -5404,6 +5515,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::CATEGORY,
             Self::OxcApproxConstant(_) => OxcApproxConstant::CATEGORY,
             Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::CATEGORY,
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::CATEGORY,
             Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::CATEGORY,
             Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::CATEGORY,
             Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::CATEGORY,
@@ -5414,19 +5526,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => OxcConstComparisons::CATEGORY,
             Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::CATEGORY,
             Self::OxcErasingOp(_) => OxcErasingOp::CATEGORY,
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::CATEGORY,
             Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::CATEGORY,
             Self::OxcMissingThrow(_) => OxcMissingThrow::CATEGORY,
             Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::CATEGORY,
             Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::CATEGORY,
             Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::CATEGORY,
             Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::CATEGORY,
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::CATEGORY,
             Self::OxcNoConstEnum(_) => OxcNoConstEnum::CATEGORY,
+            Self::OxcNoDeprecatedDirectiveRules(_) => OxcNoDeprecatedDirectiveRules::CATEGORY,
+            Self::OxcNoDisabledCorrectnessRules(_) => OxcNoDisabledCorrectnessRules::CATEGORY,
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::CATEGORY,
+            Self::OxcNoDuplicateTypeConstituents(_) => OxcNoDuplicateTypeConstituents::CATEGORY,
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::CATEGORY,
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::CATEGORY,
+            Self::OxcNoIrrelevantDirectiveRules(_) => OxcNoIrrelevantDirectiveRules::CATEGORY,
             Self::OxcNoMapSpread(_) => OxcNoMapSpread::CATEGORY,
             Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::CATEGORY,
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::CATEGORY,
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::CATEGORY,
             Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::CATEGORY,
+            Self::OxcNoStackedNextLineDirectives(_) => OxcNoStackedNextLineDirectives::CATEGORY,
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::CATEGORY,
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::CATEGORY,
             Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::CATEGORY,
+            Self::OxcNoUnsupportedDirectiveRules(_) => OxcNoUnsupportedDirectiveRules::CATEGORY,
+            Self::OxcNoUnterminatedDisableComments(_) => OxcNoUnterminatedDisableComments::CATEGORY,
             Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::CATEGORY,
             Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::CATEGORY,
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::CATEGORY,
             Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::CATEGORY,
             Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::CATEGORY,
             Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::CATEGORY,
@@ -5468,6 +5597,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(_) => JsdocRequireReturns::CATEGORY,
             Self::JsdocRequireReturnsDescription(_) => JsdocRequireReturnsDescription::CATEGORY,
             Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::CATEGORY,
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::CATEGORY,
             Self::JsdocRequireThrowsDescription(_) => JsdocRequireThrowsDescription::CATEGORY,
             Self::JsdocRequireThrowsType(_) => JsdocRequireThrowsType::CATEGORY,
             Self::JsdocRequireYields(_) => JsdocRequireYields::CATEGORY,
@@ -5676,6 +5806,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::FIX,
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::FIX,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::FIX,
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::FIX,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::FIX,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::FIX,
             Self::ImportUnambiguous(_) => ImportUnambiguous::FIX,
@@ -5837,6 +5968,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => EslintNoWarningComments::FIX,
             Self::EslintNoWith(_) => EslintNoWith::FIX,
             Self::EslintObjectShorthand(_) => EslintObjectShorthand::FIX,
+            Self::EslintOneVar(_) => EslintOneVar::FIX,
             Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::FIX,
             Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::FIX,
             Self::EslintPreferConst(_) => EslintPreferConst::FIX,
@@ -6383,6 +6515,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::FIX,
             Self::OxcApproxConstant(_) => OxcApproxConstant::FIX,
             Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::FIX,
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::FIX,
             Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::FIX,
             Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::FIX,
             Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::FIX,
@@ -6393,19 +6526,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => OxcConstComparisons::FIX,
             Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::FIX,
             Self::OxcErasingOp(_) => OxcErasingOp::FIX,
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::FIX,
             Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::FIX,
             Self::OxcMissingThrow(_) => OxcMissingThrow::FIX,
             Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::FIX,
             Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::FIX,
             Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::FIX,
             Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::FIX,
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::FIX,
             Self::OxcNoConstEnum(_) => OxcNoConstEnum::FIX,
+            Self::OxcNoDeprecatedDirectiveRules(_) => OxcNoDeprecatedDirectiveRules::FIX,
+            Self::OxcNoDisabledCorrectnessRules(_) => OxcNoDisabledCorrectnessRules::FIX,
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::FIX,
+            Self::OxcNoDuplicateTypeConstituents(_) => OxcNoDuplicateTypeConstituents::FIX,
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::FIX,
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::FIX,
+            Self::OxcNoIrrelevantDirectiveRules(_) => OxcNoIrrelevantDirectiveRules::FIX,
             Self::OxcNoMapSpread(_) => OxcNoMapSpread::FIX,
             Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::FIX,
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::FIX,
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::FIX,
             Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::FIX,
+            Self::OxcNoStackedNextLineDirectives(_) => OxcNoStackedNextLineDirectives::FIX,
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::FIX,
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::FIX,
             Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::FIX,
+            Self::OxcNoUnsupportedDirectiveRules(_) => OxcNoUnsupportedDirectiveRules::FIX,
+            Self::OxcNoUnterminatedDisableComments(_) => OxcNoUnterminatedDisableComments::FIX,
             Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::FIX,
             Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::FIX,
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::FIX,
             Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::FIX,
             Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::FIX,
             Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::FIX,
@@ -6447,6 +6597,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(_) => JsdocRequireReturns::FIX,
             Self::JsdocRequireReturnsDescription(_) => JsdocRequireReturnsDescription::FIX,
             Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::FIX,
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::FIX,
             Self::JsdocRequireThrowsDescription(_) => JsdocRequireThrowsDescription::FIX,
             Self::JsdocRequireThrowsType(_) => JsdocRequireThrowsType::FIX,
             Self::JsdocRequireYields(_) => JsdocRequireYields::FIX,
@@ -6647,6 +6798,7 @@ impl RuleEnum {
             }
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::documentation(),
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::documentation(),
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::documentation(),
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::documentation(),
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::documentation(),
             Self::ImportUnambiguous(_) => ImportUnambiguous::documentation(),
@@ -6830,6 +6982,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => EslintNoWarningComments::documentation(),
             Self::EslintNoWith(_) => EslintNoWith::documentation(),
             Self::EslintObjectShorthand(_) => EslintObjectShorthand::documentation(),
+            Self::EslintOneVar(_) => EslintOneVar::documentation(),
             Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::documentation(),
             Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::documentation(),
             Self::EslintPreferConst(_) => EslintPreferConst::documentation(),
@@ -7556,6 +7709,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::documentation(),
             Self::OxcApproxConstant(_) => OxcApproxConstant::documentation(),
             Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::documentation(),
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::documentation(),
             Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::documentation(),
             Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::documentation(),
             Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::documentation(),
@@ -7568,19 +7722,50 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => OxcConstComparisons::documentation(),
             Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::documentation(),
             Self::OxcErasingOp(_) => OxcErasingOp::documentation(),
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::documentation(),
             Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::documentation(),
             Self::OxcMissingThrow(_) => OxcMissingThrow::documentation(),
             Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::documentation(),
             Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::documentation(),
             Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::documentation(),
             Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::documentation(),
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::documentation(),
             Self::OxcNoConstEnum(_) => OxcNoConstEnum::documentation(),
+            Self::OxcNoDeprecatedDirectiveRules(_) => {
+                OxcNoDeprecatedDirectiveRules::documentation()
+            }
+            Self::OxcNoDisabledCorrectnessRules(_) => {
+                OxcNoDisabledCorrectnessRules::documentation()
+            }
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::documentation(),
+            Self::OxcNoDuplicateTypeConstituents(_) => {
+                OxcNoDuplicateTypeConstituents::documentation()
+            }
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::documentation(),
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::documentation(),
+            Self::OxcNoIrrelevantDirectiveRules(_) => {
+                OxcNoIrrelevantDirectiveRules::documentation()
+            }
             Self::OxcNoMapSpread(_) => OxcNoMapSpread::documentation(),
             Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::documentation(),
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::documentation(),
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::documentation(),
             Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::documentation(),
+            Self::OxcNoStackedNextLineDirectives(_) => {
+                OxcNoStackedNextLineDirectives::documentation()
+            }
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::documentation(),
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::documentation(),
             Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::documentation(),
+            Self::OxcNoUnsupportedDirectiveRules(_) => {
+                OxcNoUnsupportedDirectiveRules::documentation()
+            }
+            Self::OxcNoUnterminatedDisableComments(_) => {
+                OxcNoUnterminatedDisableComments::documentation()
+            }
             Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::documentation(),
             Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::documentation(),
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::documentation(),
             Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::documentation(),
             Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::documentation(),
             Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::documentation(),
@@ -7628,6 +7813,7 @@ impl RuleEnum {
                 JsdocRequireReturnsDescription::documentation()
             }
             Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::documentation(),
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::documentation(),
             Self::JsdocRequireThrowsDescription(_) => {
                 JsdocRequireThrowsDescription::documentation()
             }
@@ -7921,6 +8107,10 @@ impl RuleEnum {
                 .or_else(|| ImportNoSelfImport::schema(generator)),
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::config_schema(generator)
                 .or_else(|| ImportNoUnassignedImport::schema(generator)),
+            Self::ImportNoUselessPathSegments(_) => {
+                ImportNoUselessPathSegments::config_schema(generator)
+                    .or_else(|| ImportNoUselessPathSegments::schema(generator))
+            }
             Self::ImportNoWebpackLoaderSyntax(_) => {
                 ImportNoWebpackLoaderSyntax::config_schema(generator)
                     .or_else(|| ImportNoWebpackLoaderSyntax::schema(generator))
@@ -8328,6 +8518,9 @@ impl RuleEnum {
             }
             Self::EslintObjectShorthand(_) => EslintObjectShorthand::config_schema(generator)
                 .or_else(|| EslintObjectShorthand::schema(generator)),
+            Self::EslintOneVar(_) => {
+                EslintOneVar::config_schema(generator).or_else(|| EslintOneVar::schema(generator))
+            }
             Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::config_schema(generator)
                 .or_else(|| EslintOperatorAssignment::schema(generator)),
             Self::EslintPreferArrowCallback(_) => {
@@ -9690,6 +9883,8 @@ impl RuleEnum {
                 OxcBadArrayMethodOnArguments::config_schema(generator)
                     .or_else(|| OxcBadArrayMethodOnArguments::schema(generator))
             }
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::config_schema(generator)
+                .or_else(|| OxcBadBigintArg::schema(generator)),
             Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::config_schema(generator)
                 .or_else(|| OxcBadBitwiseOperator::schema(generator)),
             Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::config_schema(generator)
@@ -9713,6 +9908,8 @@ impl RuleEnum {
             Self::OxcErasingOp(_) => {
                 OxcErasingOp::config_schema(generator).or_else(|| OxcErasingOp::schema(generator))
             }
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::config_schema(generator)
+                .or_else(|| OxcMaxRulesPerDirective::schema(generator)),
             Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::config_schema(generator)
                 .or_else(|| OxcMisrefactoredAssignOp::schema(generator)),
             Self::OxcMissingThrow(_) => OxcMissingThrow::config_schema(generator)
@@ -9727,24 +9924,80 @@ impl RuleEnum {
             }
             Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::config_schema(generator)
                 .or_else(|| OxcNoBarrelFile::schema(generator)),
+            Self::OxcNoClassLevelDirectives(_) => {
+                OxcNoClassLevelDirectives::config_schema(generator)
+                    .or_else(|| OxcNoClassLevelDirectives::schema(generator))
+            }
             Self::OxcNoConstEnum(_) => OxcNoConstEnum::config_schema(generator)
                 .or_else(|| OxcNoConstEnum::schema(generator)),
+            Self::OxcNoDeprecatedDirectiveRules(_) => {
+                OxcNoDeprecatedDirectiveRules::config_schema(generator)
+                    .or_else(|| OxcNoDeprecatedDirectiveRules::schema(generator))
+            }
+            Self::OxcNoDisabledCorrectnessRules(_) => {
+                OxcNoDisabledCorrectnessRules::config_schema(generator)
+                    .or_else(|| OxcNoDisabledCorrectnessRules::schema(generator))
+            }
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::config_schema(generator)
+                .or_else(|| OxcNoDisabledExplicitAny::schema(generator)),
+            Self::OxcNoDuplicateTypeConstituents(_) => {
+                OxcNoDuplicateTypeConstituents::config_schema(generator)
+                    .or_else(|| OxcNoDuplicateTypeConstituents::schema(generator))
+            }
+            Self::OxcNoEslintDisableComments(_) => {
+                OxcNoEslintDisableComments::config_schema(generator)
+                    .or_else(|| OxcNoEslintDisableComments::schema(generator))
+            }
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::config_schema(generator)
+                .or_else(|| OxcNoFullWidthDirectives::schema(generator)),
+            Self::OxcNoIrrelevantDirectiveRules(_) => {
+                OxcNoIrrelevantDirectiveRules::config_schema(generator)
+                    .or_else(|| OxcNoIrrelevantDirectiveRules::schema(generator))
+            }
             Self::OxcNoMapSpread(_) => OxcNoMapSpread::config_schema(generator)
                 .or_else(|| OxcNoMapSpread::schema(generator)),
             Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::config_schema(generator)
                 .or_else(|| OxcNoOptionalChaining::schema(generator)),
+            Self::OxcNoRedundantEnableComments(_) => {
+                OxcNoRedundantEnableComments::config_schema(generator)
+                    .or_else(|| OxcNoRedundantEnableComments::schema(generator))
+            }
+            Self::OxcNoRedundantLineDirectives(_) => {
+                OxcNoRedundantLineDirectives::config_schema(generator)
+                    .or_else(|| OxcNoRedundantLineDirectives::schema(generator))
+            }
             Self::OxcNoRestSpreadProperties(_) => {
                 OxcNoRestSpreadProperties::config_schema(generator)
                     .or_else(|| OxcNoRestSpreadProperties::schema(generator))
             }
+            Self::OxcNoStackedNextLineDirectives(_) => {
+                OxcNoStackedNextLineDirectives::config_schema(generator)
+                    .or_else(|| OxcNoStackedNextLineDirectives::schema(generator))
+            }
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::config_schema(generator)
+                .or_else(|| OxcNoStaleDirectives::schema(generator)),
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::config_schema(generator)
+                .or_else(|| OxcNoStrippedDirectives::schema(generator)),
             Self::OxcNoThisInExportedFunction(_) => {
                 OxcNoThisInExportedFunction::config_schema(generator)
                     .or_else(|| OxcNoThisInExportedFunction::schema(generator))
             }
+            Self::OxcNoUnsupportedDirectiveRules(_) => {
+                OxcNoUnsupportedDirectiveRules::config_schema(generator)
+                    .or_else(|| OxcNoUnsupportedDirectiveRules::schema(generator))
+            }
+            Self::OxcNoUnterminatedDisableComments(_) => {
+                OxcNoUnterminatedDisableComments::config_schema(generator)
+                    .or_else(|| OxcNoUnterminatedDisableComments::schema(generator))
+            }
             Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::config_schema(generator)
                 .or_else(|| OxcNumberArgOutOfRange::schema(generator)),
             Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::config_schema(generator)
                 .or_else(|| OxcOnlyUsedInRecursion::schema(generator)),
+            Self::OxcRequireDirectiveTicket(_) => {
+                OxcRequireDirectiveTicket::config_schema(generator)
+                    .or_else(|| OxcRequireDirectiveTicket::schema(generator))
+            }
             Self::OxcUninvokedArrayCallback(_) => {
                 OxcUninvokedArrayCallback::config_schema(generator)
                     .or_else(|| OxcUninvokedArrayCallback::schema(generator))
@@ -9852,6 +10105,8 @@ impl RuleEnum {
             }
             Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::config_schema(generator)
                 .or_else(|| JsdocRequireReturnsType::schema(generator)),
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::config_schema(generator)
+                .or_else(|| JsdocRequireThrows::schema(generator)),
             Self::JsdocRequireThrowsDescription(_) => {
                 JsdocRequireThrowsDescription::config_schema(generator)
                     .or_else(|| JsdocRequireThrowsDescription::schema(generator))
@@ -10310,6 +10565,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => "import",
             Self::ImportNoSelfImport(_) => "import",
             Self::ImportNoUnassignedImport(_) => "import",
+            Self::ImportNoUselessPathSegments(_) => "import",
             Self::ImportNoWebpackLoaderSyntax(_) => "import",
             Self::ImportPreferDefaultExport(_) => "import",
             Self::ImportUnambiguous(_) => "import",
@@ -10471,6 +10727,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => "eslint",
             Self::EslintNoWith(_) => "eslint",
             Self::EslintObjectShorthand(_) => "eslint",
+            Self::EslintOneVar(_) => "eslint",
             Self::EslintOperatorAssignment(_) => "eslint",
             Self::EslintPreferArrowCallback(_) => "eslint",
             Self::EslintPreferConst(_) => "eslint",
@@ -10913,6 +11170,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => "jsx_a11y",
             Self::OxcApproxConstant(_) => "oxc",
             Self::OxcBadArrayMethodOnArguments(_) => "oxc",
+            Self::OxcBadBigintArg(_) => "oxc",
             Self::OxcBadBitwiseOperator(_) => "oxc",
             Self::OxcBadCharAtComparison(_) => "oxc",
             Self::OxcBadComparisonSequence(_) => "oxc",
@@ -10923,19 +11181,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => "oxc",
             Self::OxcDoubleComparisons(_) => "oxc",
             Self::OxcErasingOp(_) => "oxc",
+            Self::OxcMaxRulesPerDirective(_) => "oxc",
             Self::OxcMisrefactoredAssignOp(_) => "oxc",
             Self::OxcMissingThrow(_) => "oxc",
             Self::OxcNoAccumulatingSpread(_) => "oxc",
             Self::OxcNoAsyncAwait(_) => "oxc",
             Self::OxcNoAsyncEndpointHandlers(_) => "oxc",
             Self::OxcNoBarrelFile(_) => "oxc",
+            Self::OxcNoClassLevelDirectives(_) => "oxc",
             Self::OxcNoConstEnum(_) => "oxc",
+            Self::OxcNoDeprecatedDirectiveRules(_) => "oxc",
+            Self::OxcNoDisabledCorrectnessRules(_) => "oxc",
+            Self::OxcNoDisabledExplicitAny(_) => "oxc",
+            Self::OxcNoDuplicateTypeConstituents(_) => "oxc",
+            Self::OxcNoEslintDisableComments(_) => "oxc",
+            Self::OxcNoFullWidthDirectives(_) => "oxc",
+            Self::OxcNoIrrelevantDirectiveRules(_) => "oxc",
             Self::OxcNoMapSpread(_) => "oxc",
             Self::OxcNoOptionalChaining(_) => "oxc",
+            Self::OxcNoRedundantEnableComments(_) => "oxc",
+            Self::OxcNoRedundantLineDirectives(_) => "oxc",
             Self::OxcNoRestSpreadProperties(_) => "oxc",
+            Self::OxcNoStackedNextLineDirectives(_) => "oxc",
+            Self::OxcNoStaleDirectives(_) => "oxc",
+            Self::OxcNoStrippedDirectives(_) => "oxc",
             Self::OxcNoThisInExportedFunction(_) => "oxc",
+            Self::OxcNoUnsupportedDirectiveRules(_) => "oxc",
+            Self::OxcNoUnterminatedDisableComments(_) => "oxc",
             Self::OxcNumberArgOutOfRange(_) => "oxc",
             Self::OxcOnlyUsedInRecursion(_) => "oxc",
+            Self::OxcRequireDirectiveTicket(_) => "oxc",
             Self::OxcUninvokedArrayCallback(_) => "oxc",
             Self::NextjsGoogleFontDisplay(_) => "nextjs",
             Self::NextjsGoogleFontPreconnect(_) => "nextjs",
@@ -10975,6 +11250,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(_) => "jsdoc",
             Self::JsdocRequireReturnsDescription(_) => "jsdoc",
             Self::JsdocRequireReturnsType(_) => "jsdoc",
+            Self::JsdocRequireThrows(_) => "jsdoc",
             Self::JsdocRequireThrowsDescription(_) => "jsdoc",
             Self::JsdocRequireThrowsType(_) => "jsdoc",
             Self::JsdocRequireYields(_) => "jsdoc",
@@ -11219,6 +11495,9 @@ impl RuleEnum {
             Self::ImportNoUnassignedImport(_) => Ok(Self::ImportNoUnassignedImport(
                 ImportNoUnassignedImport::from_configuration(value)?,
             )),
+            Self::ImportNoUselessPathSegments(_) => Ok(Self::ImportNoUselessPathSegments(
+                ImportNoUselessPathSegments::from_configuration(value)?,
+            )),
             Self::ImportNoWebpackLoaderSyntax(_) => Ok(Self::ImportNoWebpackLoaderSyntax(
                 ImportNoWebpackLoaderSyntax::from_configuration(value)?,
             )),
@@ -11704,6 +11983,9 @@ impl RuleEnum {
             Self::EslintObjectShorthand(_) => {
                 Ok(Self::EslintObjectShorthand(EslintObjectShorthand::from_configuration(value)?))
             }
+            Self::EslintOneVar(_) => {
+                Ok(Self::EslintOneVar(EslintOneVar::from_configuration(value)?))
+            }
             Self::EslintOperatorAssignment(_) => Ok(Self::EslintOperatorAssignment(
                 EslintOperatorAssignment::from_configuration(value)?,
             )),
@@ -13190,6 +13472,9 @@ impl RuleEnum {
             Self::OxcBadArrayMethodOnArguments(_) => Ok(Self::OxcBadArrayMethodOnArguments(
                 OxcBadArrayMethodOnArguments::from_configuration(value)?,
             )),
+            Self::OxcBadBigintArg(_) => {
+                Ok(Self::OxcBadBigintArg(OxcBadBigintArg::from_configuration(value)?))
+            }
             Self::OxcBadBitwiseOperator(_) => {
                 Ok(Self::OxcBadBitwiseOperator(OxcBadBitwiseOperator::from_configuration(value)?))
             }
@@ -13220,6 +13505,9 @@ impl RuleEnum {
             Self::OxcErasingOp(_) => {
                 Ok(Self::OxcErasingOp(OxcErasingOp::from_configuration(value)?))
             }
+            Self::OxcMaxRulesPerDirective(_) => Ok(Self::OxcMaxRulesPerDirective(
+                OxcMaxRulesPerDirective::from_configuration(value)?,
+            )),
             Self::OxcMisrefactoredAssignOp(_) => Ok(Self::OxcMisrefactoredAssignOp(
                 OxcMisrefactoredAssignOp::from_configuration(value)?,
             )),
@@ -13238,27 +13526,77 @@ impl RuleEnum {
             Self::OxcNoBarrelFile(_) => {
                 Ok(Self::OxcNoBarrelFile(OxcNoBarrelFile::from_configuration(value)?))
             }
+            Self::OxcNoClassLevelDirectives(_) => Ok(Self::OxcNoClassLevelDirectives(
+                OxcNoClassLevelDirectives::from_configuration(value)?,
+            )),
             Self::OxcNoConstEnum(_) => {
                 Ok(Self::OxcNoConstEnum(OxcNoConstEnum::from_configuration(value)?))
             }
+            Self::OxcNoDeprecatedDirectiveRules(_) => Ok(Self::OxcNoDeprecatedDirectiveRules(
+                OxcNoDeprecatedDirectiveRules::from_configuration(value)?,
+            )),
+            Self::OxcNoDisabledCorrectnessRules(_) => Ok(Self::OxcNoDisabledCorrectnessRules(
+                OxcNoDisabledCorrectnessRules::from_configuration(value)?,
+            )),
+            Self::OxcNoDisabledExplicitAny(_) => Ok(Self::OxcNoDisabledExplicitAny(
+                OxcNoDisabledExplicitAny::from_configuration(value)?,
+            )),
+            Self::OxcNoDuplicateTypeConstituents(_) => Ok(Self::OxcNoDuplicateTypeConstituents(
+                OxcNoDuplicateTypeConstituents::from_configuration(value)?,
+            )),
+            Self::OxcNoEslintDisableComments(_) => Ok(Self::OxcNoEslintDisableComments(
+                OxcNoEslintDisableComments::from_configuration(value)?,
+            )),
+            Self::OxcNoFullWidthDirectives(_) => Ok(Self::OxcNoFullWidthDirectives(
+                OxcNoFullWidthDirectives::from_configuration(value)?,
+            )),
+            Self::OxcNoIrrelevantDirectiveRules(_) => Ok(Self::OxcNoIrrelevantDirectiveRules(
+                OxcNoIrrelevantDirectiveRules::from_configuration(value)?,
+            )),
             Self::OxcNoMapSpread(_) => {
                 Ok(Self::OxcNoMapSpread(OxcNoMapSpread::from_configuration(value)?))
             }
             Self::OxcNoOptionalChaining(_) => {
                 Ok(Self::OxcNoOptionalChaining(OxcNoOptionalChaining::from_configuration(value)?))
             }
+            Self::OxcNoRedundantEnableComments(_) => Ok(Self::OxcNoRedundantEnableComments(
+                OxcNoRedundantEnableComments::from_configuration(value)?,
+            )),
+            Self::OxcNoRedundantLineDirectives(_) => Ok(Self::OxcNoRedundantLineDirectives(
+                OxcNoRedundantLineDirectives::from_configuration(value)?,
+            )),
             Self::OxcNoRestSpreadProperties(_) => Ok(Self::OxcNoRestSpreadProperties(
                 OxcNoRestSpreadProperties::from_configuration(value)?,
             )),
+            Self::OxcNoStackedNextLineDirectives(_) => Ok(Self::OxcNoStackedNextLineDirectives(
+                OxcNoStackedNextLineDirectives::from_configuration(value)?,
+            )),
+            Self::OxcNoStaleDirectives(_) => {
+                Ok(Self::OxcNoStaleDirectives(OxcNoStaleDirectives::from_configuration(value)?))
+            }
+            Self::OxcNoStrippedDirectives(_) => Ok(Self::OxcNoStrippedDirectives(
+                OxcNoStrippedDirectives::from_configuration(value)?,
+            )),
             Self::OxcNoThisInExportedFunction(_) => Ok(Self::OxcNoThisInExportedFunction(
                 OxcNoThisInExportedFunction::from_configuration(value)?,
             )),
+            Self::OxcNoUnsupportedDirectiveRules(_) => Ok(Self::OxcNoUnsupportedDirectiveRules(
+                OxcNoUnsupportedDirectiveRules::from_configuration(value)?,
+            )),
+            Self::OxcNoUnterminatedDisableComments(_) => {
+                Ok(Self::OxcNoUnterminatedDisableComments(
+                    OxcNoUnterminatedDisableComments::from_configuration(value)?,
+                ))
+            }
             Self::OxcNumberArgOutOfRange(_) => {
                 Ok(Self::OxcNumberArgOutOfRange(OxcNumberArgOutOfRange::from_configuration(value)?))
             }
             Self::OxcOnlyUsedInRecursion(_) => {
                 Ok(Self::OxcOnlyUsedInRecursion(OxcOnlyUsedInRecursion::from_configuration(value)?))
             }
+            Self::OxcRequireDirectiveTicket(_) => Ok(Self::OxcRequireDirectiveTicket(
+                OxcRequireDirectiveTicket::from_configuration(value)?,
+            )),
             Self::OxcUninvokedArrayCallback(_) => Ok(Self::OxcUninvokedArrayCallback(
                 OxcUninvokedArrayCallback::from_configuration(value)?,
             )),
@@ -13378,6 +13716,9 @@ impl RuleEnum {
             Self::JsdocRequireReturnsType(_) => Ok(Self::JsdocRequireReturnsType(
                 JsdocRequireReturnsType::from_configuration(value)?,
             )),
+            Self::JsdocRequireThrows(_) => {
+                Ok(Self::JsdocRequireThrows(JsdocRequireThrows::from_configuration(value)?))
+            }
             Self::JsdocRequireThrowsDescription(_) => Ok(Self::JsdocRequireThrowsDescription(
                 JsdocRequireThrowsDescription::from_configuration(value)?,
             )),
@@ -13891,6 +14232,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.to_configuration(),
             Self::ImportNoSelfImport(rule) => rule.to_configuration(),
             Self::ImportNoUnassignedImport(rule) => rule.to_configuration(),
+            Self::ImportNoUselessPathSegments(rule) => rule.to_configuration(),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.to_configuration(),
             Self::ImportPreferDefaultExport(rule) => rule.to_configuration(),
             Self::ImportUnambiguous(rule) => rule.to_configuration(),
@@ -14052,6 +14394,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(rule) => rule.to_configuration(),
             Self::EslintNoWith(rule) => rule.to_configuration(),
             Self::EslintObjectShorthand(rule) => rule.to_configuration(),
+            Self::EslintOneVar(rule) => rule.to_configuration(),
             Self::EslintOperatorAssignment(rule) => rule.to_configuration(),
             Self::EslintPreferArrowCallback(rule) => rule.to_configuration(),
             Self::EslintPreferConst(rule) => rule.to_configuration(),
@@ -14496,6 +14839,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(rule) => rule.to_configuration(),
             Self::OxcApproxConstant(rule) => rule.to_configuration(),
             Self::OxcBadArrayMethodOnArguments(rule) => rule.to_configuration(),
+            Self::OxcBadBigintArg(rule) => rule.to_configuration(),
             Self::OxcBadBitwiseOperator(rule) => rule.to_configuration(),
             Self::OxcBadCharAtComparison(rule) => rule.to_configuration(),
             Self::OxcBadComparisonSequence(rule) => rule.to_configuration(),
@@ -14506,19 +14850,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(rule) => rule.to_configuration(),
             Self::OxcDoubleComparisons(rule) => rule.to_configuration(),
             Self::OxcErasingOp(rule) => rule.to_configuration(),
+            Self::OxcMaxRulesPerDirective(rule) => rule.to_configuration(),
             Self::OxcMisrefactoredAssignOp(rule) => rule.to_configuration(),
             Self::OxcMissingThrow(rule) => rule.to_configuration(),
             Self::OxcNoAccumulatingSpread(rule) => rule.to_configuration(),
             Self::OxcNoAsyncAwait(rule) => rule.to_configuration(),
             Self::OxcNoAsyncEndpointHandlers(rule) => rule.to_configuration(),
             Self::OxcNoBarrelFile(rule) => rule.to_configuration(),
+            Self::OxcNoClassLevelDirectives(rule) => rule.to_configuration(),
             Self::OxcNoConstEnum(rule) => rule.to_configuration(),
+            Self::OxcNoDeprecatedDirectiveRules(rule) => rule.to_configuration(),
+            Self::OxcNoDisabledCorrectnessRules(rule) => rule.to_configuration(),
+            Self::OxcNoDisabledExplicitAny(rule) => rule.to_configuration(),
+            Self::OxcNoDuplicateTypeConstituents(rule) => rule.to_configuration(),
+            Self::OxcNoEslintDisableComments(rule) => rule.to_configuration(),
+            Self::OxcNoFullWidthDirectives(rule) => rule.to_configuration(),
+            Self::OxcNoIrrelevantDirectiveRules(rule) => rule.to_configuration(),
             Self::OxcNoMapSpread(rule) => rule.to_configuration(),
             Self::OxcNoOptionalChaining(rule) => rule.to_configuration(),
+            Self::OxcNoRedundantEnableComments(rule) => rule.to_configuration(),
+            Self::OxcNoRedundantLineDirectives(rule) => rule.to_configuration(),
             Self::OxcNoRestSpreadProperties(rule) => rule.to_configuration(),
+            Self::OxcNoStackedNextLineDirectives(rule) => rule.to_configuration(),
+            Self::OxcNoStaleDirectives(rule) => rule.to_configuration(),
+            Self::OxcNoStrippedDirectives(rule) => rule.to_configuration(),
             Self::OxcNoThisInExportedFunction(rule) => rule.to_configuration(),
+            Self::OxcNoUnsupportedDirectiveRules(rule) => rule.to_configuration(),
+            Self::OxcNoUnterminatedDisableComments(rule) => rule.to_configuration(),
             Self::OxcNumberArgOutOfRange(rule) => rule.to_configuration(),
             Self::OxcOnlyUsedInRecursion(rule) => rule.to_configuration(),
+            Self::OxcRequireDirectiveTicket(rule) => rule.to_configuration(),
             Self::OxcUninvokedArrayCallback(rule) => rule.to_configuration(),
             Self::NextjsGoogleFontDisplay(rule) => rule.to_configuration(),
             Self::NextjsGoogleFontPreconnect(rule) => rule.to_configuration(),
@@ -14558,6 +14919,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(rule) => rule.to_configuration(),
             Self::JsdocRequireReturnsDescription(rule) => rule.to_configuration(),
             Self::JsdocRequireReturnsType(rule) => rule.to_configuration(),
+            Self::JsdocRequireThrows(rule) => rule.to_configuration(),
             Self::JsdocRequireThrowsDescription(rule) => rule.to_configuration(),
             Self::JsdocRequireThrowsType(rule) => rule.to_configuration(),
             Self::JsdocRequireYields(rule) => rule.to_configuration(),
@@ -14746,6 +15108,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.run(node, ctx),
             Self::ImportNoSelfImport(rule) => rule.run(node, ctx),
             Self::ImportNoUnassignedImport(rule) => rule.run(node, ctx),
+            Self::ImportNoUselessPathSegments(rule) => rule.run(node, ctx),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.run(node, ctx),
             Self::ImportPreferDefaultExport(rule) => rule.run(node, ctx),
             Self::ImportUnambiguous(rule) => rule.run(node, ctx),
@@ -14907,6 +15270,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(rule) => rule.run(node, ctx),
             Self::EslintNoWith(rule) => rule.run(node, ctx),
             Self::EslintObjectShorthand(rule) => rule.run(node, ctx),
+            Self::EslintOneVar(rule) => rule.run(node, ctx),
             Self::EslintOperatorAssignment(rule) => rule.run(node, ctx),
             Self::EslintPreferArrowCallback(rule) => rule.run(node, ctx),
             Self::EslintPreferConst(rule) => rule.run(node, ctx),
@@ -15349,6 +15713,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(rule) => rule.run(node, ctx),
             Self::OxcApproxConstant(rule) => rule.run(node, ctx),
             Self::OxcBadArrayMethodOnArguments(rule) => rule.run(node, ctx),
+            Self::OxcBadBigintArg(rule) => rule.run(node, ctx),
             Self::OxcBadBitwiseOperator(rule) => rule.run(node, ctx),
             Self::OxcBadCharAtComparison(rule) => rule.run(node, ctx),
             Self::OxcBadComparisonSequence(rule) => rule.run(node, ctx),
@@ -15359,19 +15724,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(rule) => rule.run(node, ctx),
             Self::OxcDoubleComparisons(rule) => rule.run(node, ctx),
             Self::OxcErasingOp(rule) => rule.run(node, ctx),
+            Self::OxcMaxRulesPerDirective(rule) => rule.run(node, ctx),
             Self::OxcMisrefactoredAssignOp(rule) => rule.run(node, ctx),
             Self::OxcMissingThrow(rule) => rule.run(node, ctx),
             Self::OxcNoAccumulatingSpread(rule) => rule.run(node, ctx),
             Self::OxcNoAsyncAwait(rule) => rule.run(node, ctx),
             Self::OxcNoAsyncEndpointHandlers(rule) => rule.run(node, ctx),
             Self::OxcNoBarrelFile(rule) => rule.run(node, ctx),
+            Self::OxcNoClassLevelDirectives(rule) => rule.run(node, ctx),
             Self::OxcNoConstEnum(rule) => rule.run(node, ctx),
+            Self::OxcNoDeprecatedDirectiveRules(rule) => rule.run(node, ctx),
+            Self::OxcNoDisabledCorrectnessRules(rule) => rule.run(node, ctx),
+            Self::OxcNoDisabledExplicitAny(rule) => rule.run(node, ctx),
+            Self::OxcNoDuplicateTypeConstituents(rule) => rule.run(node, ctx),
+            Self::OxcNoEslintDisableComments(rule) => rule.run(node, ctx),
+            Self::OxcNoFullWidthDirectives(rule) => rule.run(node, ctx),
+            Self::OxcNoIrrelevantDirectiveRules(rule) => rule.run(node, ctx),
             Self::OxcNoMapSpread(rule) => rule.run(node, ctx),
             Self::OxcNoOptionalChaining(rule) => rule.run(node, ctx),
+            Self::OxcNoRedundantEnableComments(rule) => rule.run(node, ctx),
+            Self::OxcNoRedundantLineDirectives(rule) => rule.run(node, ctx),
             Self::OxcNoRestSpreadProperties(rule) => rule.run(node, ctx),
+            Self::OxcNoStackedNextLineDirectives(rule) => rule.run(node, ctx),
+            Self::OxcNoStaleDirectives(rule) => rule.run(node, ctx),
+            Self::OxcNoStrippedDirectives(rule) => rule.run(node, ctx),
             Self::OxcNoThisInExportedFunction(rule) => rule.run(node, ctx),
+            Self::OxcNoUnsupportedDirectiveRules(rule) => rule.run(node, ctx),
+            Self::OxcNoUnterminatedDisableComments(rule) => rule.run(node, ctx),
             Self::OxcNumberArgOutOfRange(rule) => rule.run(node, ctx),
             Self::OxcOnlyUsedInRecursion(rule) => rule.run(node, ctx),
+            Self::OxcRequireDirectiveTicket(rule) => rule.run(node, ctx),
             Self::OxcUninvokedArrayCallback(rule) => rule.run(node, ctx),
             Self::NextjsGoogleFontDisplay(rule) => rule.run(node, ctx),
             Self::NextjsGoogleFontPreconnect(rule) => rule.run(node, ctx),
@@ -15411,6 +15793,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(rule) => rule.run(node, ctx),
             Self::JsdocRequireReturnsDescription(rule) => rule.run(node, ctx),
             Self::JsdocRequireReturnsType(rule) => rule.run(node, ctx),
+            Self::JsdocRequireThrows(rule) => rule.run(node, ctx),
             Self::JsdocRequireThrowsDescription(rule) => rule.run(node, ctx),
             Self::JsdocRequireThrowsType(rule) => rule.run(node, ctx),
             Self::JsdocRequireYields(rule) => rule.run(node, ctx),
@@ -15609,6 +15992,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.run_once(ctx),
             Self::ImportNoSelfImport(rule) => rule.run_once(ctx),
             Self::ImportNoUnassignedImport(rule) => rule.run_once(ctx),
+            Self::ImportNoUselessPathSegments(rule) => rule.run_once(ctx),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.run_once(ctx),
             Self::ImportPreferDefaultExport(rule) => rule.run_once(ctx),
             Self::ImportUnambiguous(rule) => rule.run_once(ctx),
@@ -15770,6 +16154,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(rule) => rule.run_once(ctx),
             Self::EslintNoWith(rule) => rule.run_once(ctx),
             Self::EslintObjectShorthand(rule) => rule.run_once(ctx),
+            Self::EslintOneVar(rule) => rule.run_once(ctx),
             Self::EslintOperatorAssignment(rule) => rule.run_once(ctx),
             Self::EslintPreferArrowCallback(rule) => rule.run_once(ctx),
             Self::EslintPreferConst(rule) => rule.run_once(ctx),
@@ -16212,6 +16597,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(rule) => rule.run_once(ctx),
             Self::OxcApproxConstant(rule) => rule.run_once(ctx),
             Self::OxcBadArrayMethodOnArguments(rule) => rule.run_once(ctx),
+            Self::OxcBadBigintArg(rule) => rule.run_once(ctx),
             Self::OxcBadBitwiseOperator(rule) => rule.run_once(ctx),
             Self::OxcBadCharAtComparison(rule) => rule.run_once(ctx),
             Self::OxcBadComparisonSequence(rule) => rule.run_once(ctx),
@@ -16222,19 +16608,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(rule) => rule.run_once(ctx),
             Self::OxcDoubleComparisons(rule) => rule.run_once(ctx),
             Self::OxcErasingOp(rule) => rule.run_once(ctx),
+            Self::OxcMaxRulesPerDirective(rule) => rule.run_once(ctx),
             Self::OxcMisrefactoredAssignOp(rule) => rule.run_once(ctx),
             Self::OxcMissingThrow(rule) => rule.run_once(ctx),
             Self::OxcNoAccumulatingSpread(rule) => rule.run_once(ctx),
             Self::OxcNoAsyncAwait(rule) => rule.run_once(ctx),
             Self::OxcNoAsyncEndpointHandlers(rule) => rule.run_once(ctx),
             Self::OxcNoBarrelFile(rule) => rule.run_once(ctx),
+            Self::OxcNoClassLevelDirectives(rule) => rule.run_once(ctx),
             Self::OxcNoConstEnum(rule) => rule.run_once(ctx),
+            Self::OxcNoDeprecatedDirectiveRules(rule) => rule.run_once(ctx),
+            Self::OxcNoDisabledCorrectnessRules(rule) => rule.run_once(ctx),
+            Self::OxcNoDisabledExplicitAny(rule) => rule.run_once(ctx),
+            Self::OxcNoDuplicateTypeConstituents(rule) => rule.run_once(ctx),
+            Self::OxcNoEslintDisableComments(rule) => rule.run_once(ctx),
+            Self::OxcNoFullWidthDirectives(rule) => rule.run_once(ctx),
+            Self::OxcNoIrrelevantDirectiveRules(rule) => rule.run_once(ctx),
             Self::OxcNoMapSpread(rule) => rule.run_once(ctx),
             Self::OxcNoOptionalChaining(rule) => rule.run_once(ctx),
+            Self::OxcNoRedundantEnableComments(rule) => rule.run_once(ctx),
+            Self::OxcNoRedundantLineDirectives(rule) => rule.run_once(ctx),
             Self::OxcNoRestSpreadProperties(rule) => rule.run_once(ctx),
+            Self::OxcNoStackedNextLineDirectives(rule) => rule.run_once(ctx),
+            Self::OxcNoStaleDirectives(rule) => rule.run_once(ctx),
+            Self::OxcNoStrippedDirectives(rule) => rule.run_once(ctx),
             Self::OxcNoThisInExportedFunction(rule) => rule.run_once(ctx),
+            Self::OxcNoUnsupportedDirectiveRules(rule) => rule.run_once(ctx),
+            Self::OxcNoUnterminatedDisableComments(rule) => rule.run_once(ctx),
             Self::OxcNumberArgOutOfRange(rule) => rule.run_once(ctx),
             Self::OxcOnlyUsedInRecursion(rule) => rule.run_once(ctx),
+            Self::OxcRequireDirectiveTicket(rule) => rule.run_once(ctx),
             Self::OxcUninvokedArrayCallback(rule) => rule.run_once(ctx),
             Self::NextjsGoogleFontDisplay(rule) => rule.run_once(ctx),
             Self::NextjsGoogleFontPreconnect(rule) => rule.run_once(ctx),
@@ -16274,6 +16677,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(rule) => rule.run_once(ctx),
             Self::JsdocRequireReturnsDescription(rule) => rule.run_once(ctx),
             Self::JsdocRequireReturnsType(rule) => rule.run_once(ctx),
+            Self::JsdocRequireThrows(rule) => rule.run_once(ctx),
             Self::JsdocRequireThrowsDescription(rule) => rule.run_once(ctx),
             Self::JsdocRequireThrowsType(rule) => rule.run_once(ctx),
             Self::JsdocRequireYields(rule) => rule.run_once(ctx),
@@ -16475,6 +16879,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoSelfImport(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoUnassignedImport(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::ImportNoUselessPathSegments(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportPreferDefaultExport(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportUnambiguous(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -16636,6 +17041,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoWith(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintObjectShorthand(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::EslintOneVar(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintOperatorAssignment(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintPreferArrowCallback(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintPreferConst(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -17182,6 +17588,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcApproxConstant(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcBadArrayMethodOnArguments(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcBadBigintArg(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcBadBitwiseOperator(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcBadCharAtComparison(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcBadComparisonSequence(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -17192,19 +17599,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcDoubleComparisons(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcErasingOp(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcMaxRulesPerDirective(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcMisrefactoredAssignOp(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcMissingThrow(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNoAccumulatingSpread(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNoAsyncAwait(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNoAsyncEndpointHandlers(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNoBarrelFile(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoClassLevelDirectives(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNoConstEnum(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoDeprecatedDirectiveRules(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoDisabledCorrectnessRules(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoDisabledExplicitAny(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoDuplicateTypeConstituents(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoEslintDisableComments(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoFullWidthDirectives(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoIrrelevantDirectiveRules(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNoMapSpread(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNoOptionalChaining(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoRedundantEnableComments(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoRedundantLineDirectives(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNoRestSpreadProperties(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoStackedNextLineDirectives(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoStaleDirectives(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoStrippedDirectives(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNoThisInExportedFunction(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoUnsupportedDirectiveRules(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcNoUnterminatedDisableComments(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcNumberArgOutOfRange(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcOnlyUsedInRecursion(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::OxcRequireDirectiveTicket(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::OxcUninvokedArrayCallback(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NextjsGoogleFontDisplay(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NextjsGoogleFontPreconnect(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -17246,6 +17670,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::JsdocRequireReturnsDescription(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::JsdocRequireReturnsType(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::JsdocRequireThrows(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::JsdocRequireThrowsDescription(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::JsdocRequireThrowsType(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::JsdocRequireYields(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -17453,6 +17878,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.should_run(ctx),
             Self::ImportNoSelfImport(rule) => rule.should_run(ctx),
             Self::ImportNoUnassignedImport(rule) => rule.should_run(ctx),
+            Self::ImportNoUselessPathSegments(rule) => rule.should_run(ctx),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.should_run(ctx),
             Self::ImportPreferDefaultExport(rule) => rule.should_run(ctx),
             Self::ImportUnambiguous(rule) => rule.should_run(ctx),
@@ -17614,6 +18040,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(rule) => rule.should_run(ctx),
             Self::EslintNoWith(rule) => rule.should_run(ctx),
             Self::EslintObjectShorthand(rule) => rule.should_run(ctx),
+            Self::EslintOneVar(rule) => rule.should_run(ctx),
             Self::EslintOperatorAssignment(rule) => rule.should_run(ctx),
             Self::EslintPreferArrowCallback(rule) => rule.should_run(ctx),
             Self::EslintPreferConst(rule) => rule.should_run(ctx),
@@ -18056,6 +18483,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(rule) => rule.should_run(ctx),
             Self::OxcApproxConstant(rule) => rule.should_run(ctx),
             Self::OxcBadArrayMethodOnArguments(rule) => rule.should_run(ctx),
+            Self::OxcBadBigintArg(rule) => rule.should_run(ctx),
             Self::OxcBadBitwiseOperator(rule) => rule.should_run(ctx),
             Self::OxcBadCharAtComparison(rule) => rule.should_run(ctx),
             Self::OxcBadComparisonSequence(rule) => rule.should_run(ctx),
@@ -18066,19 +18494,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(rule) => rule.should_run(ctx),
             Self::OxcDoubleComparisons(rule) => rule.should_run(ctx),
             Self::OxcErasingOp(rule) => rule.should_run(ctx),
+            Self::OxcMaxRulesPerDirective(rule) => rule.should_run(ctx),
             Self::OxcMisrefactoredAssignOp(rule) => rule.should_run(ctx),
             Self::OxcMissingThrow(rule) => rule.should_run(ctx),
             Self::OxcNoAccumulatingSpread(rule) => rule.should_run(ctx),
             Self::OxcNoAsyncAwait(rule) => rule.should_run(ctx),
             Self::OxcNoAsyncEndpointHandlers(rule) => rule.should_run(ctx),
             Self::OxcNoBarrelFile(rule) => rule.should_run(ctx),
+            Self::OxcNoClassLevelDirectives(rule) => rule.should_run(ctx),
             Self::OxcNoConstEnum(rule) => rule.should_run(ctx),
+            Self::OxcNoDeprecatedDirectiveRules(rule) => rule.should_run(ctx),
+            Self::OxcNoDisabledCorrectnessRules(rule) => rule.should_run(ctx),
+            Self::OxcNoDisabledExplicitAny(rule) => rule.should_run(ctx),
+            Self::OxcNoDuplicateTypeConstituents(rule) => rule.should_run(ctx),
+            Self::OxcNoEslintDisableComments(rule) => rule.should_run(ctx),
+            Self::OxcNoFullWidthDirectives(rule) => rule.should_run(ctx),
+            Self::OxcNoIrrelevantDirectiveRules(rule) => rule.should_run(ctx),
             Self::OxcNoMapSpread(rule) => rule.should_run(ctx),
             Self::OxcNoOptionalChaining(rule) => rule.should_run(ctx),
+            Self::OxcNoRedundantEnableComments(rule) => rule.should_run(ctx),
+            Self::OxcNoRedundantLineDirectives(rule) => rule.should_run(ctx),
             Self::OxcNoRestSpreadProperties(rule) => rule.should_run(ctx),
+            Self::OxcNoStackedNextLineDirectives(rule) => rule.should_run(ctx),
+            Self::OxcNoStaleDirectives(rule) => rule.should_run(ctx),
+            Self::OxcNoStrippedDirectives(rule) => rule.should_run(ctx),
             Self::OxcNoThisInExportedFunction(rule) => rule.should_run(ctx),
+            Self::OxcNoUnsupportedDirectiveRules(rule) => rule.should_run(ctx),
+            Self::OxcNoUnterminatedDisableComments(rule) => rule.should_run(ctx),
             Self::OxcNumberArgOutOfRange(rule) => rule.should_run(ctx),
             Self::OxcOnlyUsedInRecursion(rule) => rule.should_run(ctx),
+            Self::OxcRequireDirectiveTicket(rule) => rule.should_run(ctx),
             Self::OxcUninvokedArrayCallback(rule) => rule.should_run(ctx),
             Self::NextjsGoogleFontDisplay(rule) => rule.should_run(ctx),
             Self::NextjsGoogleFontPreconnect(rule) => rule.should_run(ctx),
@@ -18118,6 +18563,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(rule) => rule.should_run(ctx),
             Self::JsdocRequireReturnsDescription(rule) => rule.should_run(ctx),
             Self::JsdocRequireReturnsType(rule) => rule.should_run(ctx),
+            Self::JsdocRequireThrows(rule) => rule.should_run(ctx),
             Self::JsdocRequireThrowsDescription(rule) => rule.should_run(ctx),
             Self::JsdocRequireThrowsType(rule) => rule.should_run(ctx),
             Self::JsdocRequireYields(rule) => rule.should_run(ctx),
@@ -18309,6 +18755,7 @@ impl RuleEnum {
             }
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::IS_TSGOLINT_RULE,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::IS_TSGOLINT_RULE,
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::IS_TSGOLINT_RULE,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::IS_TSGOLINT_RULE,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::IS_TSGOLINT_RULE,
             Self::ImportUnambiguous(_) => ImportUnambiguous::IS_TSGOLINT_RULE,
@@ -18492,6 +18939,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => EslintNoWarningComments::IS_TSGOLINT_RULE,
             Self::EslintNoWith(_) => EslintNoWith::IS_TSGOLINT_RULE,
             Self::EslintObjectShorthand(_) => EslintObjectShorthand::IS_TSGOLINT_RULE,
+            Self::EslintOneVar(_) => EslintOneVar::IS_TSGOLINT_RULE,
             Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::IS_TSGOLINT_RULE,
             Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::IS_TSGOLINT_RULE,
             Self::EslintPreferConst(_) => EslintPreferConst::IS_TSGOLINT_RULE,
@@ -19218,6 +19666,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::IS_TSGOLINT_RULE,
             Self::OxcApproxConstant(_) => OxcApproxConstant::IS_TSGOLINT_RULE,
             Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::IS_TSGOLINT_RULE,
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::IS_TSGOLINT_RULE,
             Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::IS_TSGOLINT_RULE,
             Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::IS_TSGOLINT_RULE,
             Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::IS_TSGOLINT_RULE,
@@ -19230,19 +19679,50 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => OxcConstComparisons::IS_TSGOLINT_RULE,
             Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::IS_TSGOLINT_RULE,
             Self::OxcErasingOp(_) => OxcErasingOp::IS_TSGOLINT_RULE,
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::IS_TSGOLINT_RULE,
             Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::IS_TSGOLINT_RULE,
             Self::OxcMissingThrow(_) => OxcMissingThrow::IS_TSGOLINT_RULE,
             Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::IS_TSGOLINT_RULE,
             Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::IS_TSGOLINT_RULE,
             Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::IS_TSGOLINT_RULE,
             Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::IS_TSGOLINT_RULE,
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::IS_TSGOLINT_RULE,
             Self::OxcNoConstEnum(_) => OxcNoConstEnum::IS_TSGOLINT_RULE,
+            Self::OxcNoDeprecatedDirectiveRules(_) => {
+                OxcNoDeprecatedDirectiveRules::IS_TSGOLINT_RULE
+            }
+            Self::OxcNoDisabledCorrectnessRules(_) => {
+                OxcNoDisabledCorrectnessRules::IS_TSGOLINT_RULE
+            }
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::IS_TSGOLINT_RULE,
+            Self::OxcNoDuplicateTypeConstituents(_) => {
+                OxcNoDuplicateTypeConstituents::IS_TSGOLINT_RULE
+            }
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::IS_TSGOLINT_RULE,
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::IS_TSGOLINT_RULE,
+            Self::OxcNoIrrelevantDirectiveRules(_) => {
+                OxcNoIrrelevantDirectiveRules::IS_TSGOLINT_RULE
+            }
             Self::OxcNoMapSpread(_) => OxcNoMapSpread::IS_TSGOLINT_RULE,
             Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::IS_TSGOLINT_RULE,
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::IS_TSGOLINT_RULE,
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::IS_TSGOLINT_RULE,
             Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::IS_TSGOLINT_RULE,
+            Self::OxcNoStackedNextLineDirectives(_) => {
+                OxcNoStackedNextLineDirectives::IS_TSGOLINT_RULE
+            }
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::IS_TSGOLINT_RULE,
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::IS_TSGOLINT_RULE,
             Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::IS_TSGOLINT_RULE,
+            Self::OxcNoUnsupportedDirectiveRules(_) => {
+                OxcNoUnsupportedDirectiveRules::IS_TSGOLINT_RULE
+            }
+            Self::OxcNoUnterminatedDisableComments(_) => {
+                OxcNoUnterminatedDisableComments::IS_TSGOLINT_RULE
+            }
             Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::IS_TSGOLINT_RULE,
             Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::IS_TSGOLINT_RULE,
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::IS_TSGOLINT_RULE,
             Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::IS_TSGOLINT_RULE,
             Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::IS_TSGOLINT_RULE,
             Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::IS_TSGOLINT_RULE,
@@ -19290,6 +19770,7 @@ impl RuleEnum {
                 JsdocRequireReturnsDescription::IS_TSGOLINT_RULE
             }
             Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::IS_TSGOLINT_RULE,
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::IS_TSGOLINT_RULE,
             Self::JsdocRequireThrowsDescription(_) => {
                 JsdocRequireThrowsDescription::IS_TSGOLINT_RULE
             }
@@ -19539,6 +20020,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::VERSION,
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::VERSION,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::VERSION,
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::VERSION,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::VERSION,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::VERSION,
             Self::ImportUnambiguous(_) => ImportUnambiguous::VERSION,
@@ -19702,6 +20184,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => EslintNoWarningComments::VERSION,
             Self::EslintNoWith(_) => EslintNoWith::VERSION,
             Self::EslintObjectShorthand(_) => EslintObjectShorthand::VERSION,
+            Self::EslintOneVar(_) => EslintOneVar::VERSION,
             Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::VERSION,
             Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::VERSION,
             Self::EslintPreferConst(_) => EslintPreferConst::VERSION,
@@ -20288,6 +20771,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::VERSION,
             Self::OxcApproxConstant(_) => OxcApproxConstant::VERSION,
             Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::VERSION,
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::VERSION,
             Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::VERSION,
             Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::VERSION,
             Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::VERSION,
@@ -20298,19 +20782,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => OxcConstComparisons::VERSION,
             Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::VERSION,
             Self::OxcErasingOp(_) => OxcErasingOp::VERSION,
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::VERSION,
             Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::VERSION,
             Self::OxcMissingThrow(_) => OxcMissingThrow::VERSION,
             Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::VERSION,
             Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::VERSION,
             Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::VERSION,
             Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::VERSION,
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::VERSION,
             Self::OxcNoConstEnum(_) => OxcNoConstEnum::VERSION,
+            Self::OxcNoDeprecatedDirectiveRules(_) => OxcNoDeprecatedDirectiveRules::VERSION,
+            Self::OxcNoDisabledCorrectnessRules(_) => OxcNoDisabledCorrectnessRules::VERSION,
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::VERSION,
+            Self::OxcNoDuplicateTypeConstituents(_) => OxcNoDuplicateTypeConstituents::VERSION,
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::VERSION,
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::VERSION,
+            Self::OxcNoIrrelevantDirectiveRules(_) => OxcNoIrrelevantDirectiveRules::VERSION,
             Self::OxcNoMapSpread(_) => OxcNoMapSpread::VERSION,
             Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::VERSION,
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::VERSION,
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::VERSION,
             Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::VERSION,
+            Self::OxcNoStackedNextLineDirectives(_) => OxcNoStackedNextLineDirectives::VERSION,
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::VERSION,
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::VERSION,
             Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::VERSION,
+            Self::OxcNoUnsupportedDirectiveRules(_) => OxcNoUnsupportedDirectiveRules::VERSION,
+            Self::OxcNoUnterminatedDisableComments(_) => OxcNoUnterminatedDisableComments::VERSION,
             Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::VERSION,
             Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::VERSION,
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::VERSION,
             Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::VERSION,
             Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::VERSION,
             Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::VERSION,
@@ -20352,6 +20853,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(_) => JsdocRequireReturns::VERSION,
             Self::JsdocRequireReturnsDescription(_) => JsdocRequireReturnsDescription::VERSION,
             Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::VERSION,
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::VERSION,
             Self::JsdocRequireThrowsDescription(_) => JsdocRequireThrowsDescription::VERSION,
             Self::JsdocRequireThrowsType(_) => JsdocRequireThrowsType::VERSION,
             Self::JsdocRequireYields(_) => JsdocRequireYields::VERSION,
@@ -20562,6 +21064,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::HAS_CONFIG,
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::HAS_CONFIG,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::HAS_CONFIG,
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::HAS_CONFIG,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::HAS_CONFIG,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::HAS_CONFIG,
             Self::ImportUnambiguous(_) => ImportUnambiguous::HAS_CONFIG,
@@ -20731,6 +21234,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => EslintNoWarningComments::HAS_CONFIG,
             Self::EslintNoWith(_) => EslintNoWith::HAS_CONFIG,
             Self::EslintObjectShorthand(_) => EslintObjectShorthand::HAS_CONFIG,
+            Self::EslintOneVar(_) => EslintOneVar::HAS_CONFIG,
             Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::HAS_CONFIG,
             Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::HAS_CONFIG,
             Self::EslintPreferConst(_) => EslintPreferConst::HAS_CONFIG,
@@ -21343,6 +21847,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::HAS_CONFIG,
             Self::OxcApproxConstant(_) => OxcApproxConstant::HAS_CONFIG,
             Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::HAS_CONFIG,
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::HAS_CONFIG,
             Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::HAS_CONFIG,
             Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::HAS_CONFIG,
             Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::HAS_CONFIG,
@@ -21353,19 +21858,38 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => OxcConstComparisons::HAS_CONFIG,
             Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::HAS_CONFIG,
             Self::OxcErasingOp(_) => OxcErasingOp::HAS_CONFIG,
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::HAS_CONFIG,
             Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::HAS_CONFIG,
             Self::OxcMissingThrow(_) => OxcMissingThrow::HAS_CONFIG,
             Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::HAS_CONFIG,
             Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::HAS_CONFIG,
             Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::HAS_CONFIG,
             Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::HAS_CONFIG,
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::HAS_CONFIG,
             Self::OxcNoConstEnum(_) => OxcNoConstEnum::HAS_CONFIG,
+            Self::OxcNoDeprecatedDirectiveRules(_) => OxcNoDeprecatedDirectiveRules::HAS_CONFIG,
+            Self::OxcNoDisabledCorrectnessRules(_) => OxcNoDisabledCorrectnessRules::HAS_CONFIG,
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::HAS_CONFIG,
+            Self::OxcNoDuplicateTypeConstituents(_) => OxcNoDuplicateTypeConstituents::HAS_CONFIG,
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::HAS_CONFIG,
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::HAS_CONFIG,
+            Self::OxcNoIrrelevantDirectiveRules(_) => OxcNoIrrelevantDirectiveRules::HAS_CONFIG,
             Self::OxcNoMapSpread(_) => OxcNoMapSpread::HAS_CONFIG,
             Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::HAS_CONFIG,
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::HAS_CONFIG,
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::HAS_CONFIG,
             Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::HAS_CONFIG,
+            Self::OxcNoStackedNextLineDirectives(_) => OxcNoStackedNextLineDirectives::HAS_CONFIG,
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::HAS_CONFIG,
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::HAS_CONFIG,
             Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::HAS_CONFIG,
+            Self::OxcNoUnsupportedDirectiveRules(_) => OxcNoUnsupportedDirectiveRules::HAS_CONFIG,
+            Self::OxcNoUnterminatedDisableComments(_) => {
+                OxcNoUnterminatedDisableComments::HAS_CONFIG
+            }
             Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::HAS_CONFIG,
             Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::HAS_CONFIG,
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::HAS_CONFIG,
             Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::HAS_CONFIG,
             Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::HAS_CONFIG,
             Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::HAS_CONFIG,
@@ -21407,6 +21931,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(_) => JsdocRequireReturns::HAS_CONFIG,
             Self::JsdocRequireReturnsDescription(_) => JsdocRequireReturnsDescription::HAS_CONFIG,
             Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::HAS_CONFIG,
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::HAS_CONFIG,
             Self::JsdocRequireThrowsDescription(_) => JsdocRequireThrowsDescription::HAS_CONFIG,
             Self::JsdocRequireThrowsType(_) => JsdocRequireThrowsType::HAS_CONFIG,
             Self::JsdocRequireYields(_) => JsdocRequireYields::HAS_CONFIG,
@@ -21590,6 +22115,2256 @@ impl RuleEnum {
             Self::VueValidNextTick(_) => VueValidNextTick::HAS_CONFIG,
         }
     }
+    #[doc = r" Whether this rule runs on TypeScript declaration files."]
+    pub fn works_on_d_ts(&self) -> bool {
+        match self {
+            Self::ImportConsistentTypeSpecifierStyle(_) => {
+                ImportConsistentTypeSpecifierStyle::WORKS_ON_D_TS
+            }
+            Self::ImportDefault(_) => ImportDefault::WORKS_ON_D_TS,
+            Self::ImportExport(_) => ImportExport::WORKS_ON_D_TS,
+            Self::ImportExportsLast(_) => ImportExportsLast::WORKS_ON_D_TS,
+            Self::ImportExtensions(_) => ImportExtensions::WORKS_ON_D_TS,
+            Self::ImportFirst(_) => ImportFirst::WORKS_ON_D_TS,
+            Self::ImportGroupExports(_) => ImportGroupExports::WORKS_ON_D_TS,
+            Self::ImportMaxDependencies(_) => ImportMaxDependencies::WORKS_ON_D_TS,
+            Self::ImportNamed(_) => ImportNamed::WORKS_ON_D_TS,
+            Self::ImportNamespace(_) => ImportNamespace::WORKS_ON_D_TS,
+            Self::ImportNewlineAfterImport(_) => ImportNewlineAfterImport::WORKS_ON_D_TS,
+            Self::ImportNoAbsolutePath(_) => ImportNoAbsolutePath::WORKS_ON_D_TS,
+            Self::ImportNoAmd(_) => ImportNoAmd::WORKS_ON_D_TS,
+            Self::ImportNoAnonymousDefaultExport(_) => {
+                ImportNoAnonymousDefaultExport::WORKS_ON_D_TS
+            }
+            Self::ImportNoCommonjs(_) => ImportNoCommonjs::WORKS_ON_D_TS,
+            Self::ImportNoCycle(_) => ImportNoCycle::WORKS_ON_D_TS,
+            Self::ImportNoDefaultExport(_) => ImportNoDefaultExport::WORKS_ON_D_TS,
+            Self::ImportNoDuplicates(_) => ImportNoDuplicates::WORKS_ON_D_TS,
+            Self::ImportNoDynamicRequire(_) => ImportNoDynamicRequire::WORKS_ON_D_TS,
+            Self::ImportNoEmptyNamedBlocks(_) => ImportNoEmptyNamedBlocks::WORKS_ON_D_TS,
+            Self::ImportNoMutableExports(_) => ImportNoMutableExports::WORKS_ON_D_TS,
+            Self::ImportNoNamedAsDefault(_) => ImportNoNamedAsDefault::WORKS_ON_D_TS,
+            Self::ImportNoNamedAsDefaultMember(_) => ImportNoNamedAsDefaultMember::WORKS_ON_D_TS,
+            Self::ImportNoNamedDefault(_) => ImportNoNamedDefault::WORKS_ON_D_TS,
+            Self::ImportNoNamedExport(_) => ImportNoNamedExport::WORKS_ON_D_TS,
+            Self::ImportNoNamespace(_) => ImportNoNamespace::WORKS_ON_D_TS,
+            Self::ImportNoNodejsModules(_) => ImportNoNodejsModules::WORKS_ON_D_TS,
+            Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::WORKS_ON_D_TS,
+            Self::ImportNoSelfImport(_) => ImportNoSelfImport::WORKS_ON_D_TS,
+            Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::WORKS_ON_D_TS,
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::WORKS_ON_D_TS,
+            Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::WORKS_ON_D_TS,
+            Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::WORKS_ON_D_TS,
+            Self::ImportUnambiguous(_) => ImportUnambiguous::WORKS_ON_D_TS,
+            Self::EslintAccessorPairs(_) => EslintAccessorPairs::WORKS_ON_D_TS,
+            Self::EslintArrayCallbackReturn(_) => EslintArrayCallbackReturn::WORKS_ON_D_TS,
+            Self::EslintArrowBodyStyle(_) => EslintArrowBodyStyle::WORKS_ON_D_TS,
+            Self::EslintBlockScopedVar(_) => EslintBlockScopedVar::WORKS_ON_D_TS,
+            Self::EslintCapitalizedComments(_) => EslintCapitalizedComments::WORKS_ON_D_TS,
+            Self::EslintClassMethodsUseThis(_) => EslintClassMethodsUseThis::WORKS_ON_D_TS,
+            Self::EslintComplexity(_) => EslintComplexity::WORKS_ON_D_TS,
+            Self::EslintConstructorSuper(_) => EslintConstructorSuper::WORKS_ON_D_TS,
+            Self::EslintCurly(_) => EslintCurly::WORKS_ON_D_TS,
+            Self::EslintDefaultCase(_) => EslintDefaultCase::WORKS_ON_D_TS,
+            Self::EslintDefaultCaseLast(_) => EslintDefaultCaseLast::WORKS_ON_D_TS,
+            Self::EslintDefaultParamLast(_) => EslintDefaultParamLast::WORKS_ON_D_TS,
+            Self::EslintEqeqeq(_) => EslintEqeqeq::WORKS_ON_D_TS,
+            Self::EslintForDirection(_) => EslintForDirection::WORKS_ON_D_TS,
+            Self::EslintFuncNameMatching(_) => EslintFuncNameMatching::WORKS_ON_D_TS,
+            Self::EslintFuncNames(_) => EslintFuncNames::WORKS_ON_D_TS,
+            Self::EslintFuncStyle(_) => EslintFuncStyle::WORKS_ON_D_TS,
+            Self::EslintGetterReturn(_) => EslintGetterReturn::WORKS_ON_D_TS,
+            Self::EslintGroupedAccessorPairs(_) => EslintGroupedAccessorPairs::WORKS_ON_D_TS,
+            Self::EslintGuardForIn(_) => EslintGuardForIn::WORKS_ON_D_TS,
+            Self::EslintIdDenylist(_) => EslintIdDenylist::WORKS_ON_D_TS,
+            Self::EslintIdLength(_) => EslintIdLength::WORKS_ON_D_TS,
+            Self::EslintIdMatch(_) => EslintIdMatch::WORKS_ON_D_TS,
+            Self::EslintInitDeclarations(_) => EslintInitDeclarations::WORKS_ON_D_TS,
+            Self::EslintLogicalAssignmentOperators(_) => {
+                EslintLogicalAssignmentOperators::WORKS_ON_D_TS
+            }
+            Self::EslintMaxClassesPerFile(_) => EslintMaxClassesPerFile::WORKS_ON_D_TS,
+            Self::EslintMaxDepth(_) => EslintMaxDepth::WORKS_ON_D_TS,
+            Self::EslintMaxLines(_) => EslintMaxLines::WORKS_ON_D_TS,
+            Self::EslintMaxLinesPerFunction(_) => EslintMaxLinesPerFunction::WORKS_ON_D_TS,
+            Self::EslintMaxNestedCallbacks(_) => EslintMaxNestedCallbacks::WORKS_ON_D_TS,
+            Self::EslintMaxParams(_) => EslintMaxParams::WORKS_ON_D_TS,
+            Self::EslintMaxStatements(_) => EslintMaxStatements::WORKS_ON_D_TS,
+            Self::EslintNewCap(_) => EslintNewCap::WORKS_ON_D_TS,
+            Self::EslintNoAlert(_) => EslintNoAlert::WORKS_ON_D_TS,
+            Self::EslintNoArrayConstructor(_) => EslintNoArrayConstructor::WORKS_ON_D_TS,
+            Self::EslintNoAsyncPromiseExecutor(_) => EslintNoAsyncPromiseExecutor::WORKS_ON_D_TS,
+            Self::EslintNoAwaitInLoop(_) => EslintNoAwaitInLoop::WORKS_ON_D_TS,
+            Self::EslintNoBitwise(_) => EslintNoBitwise::WORKS_ON_D_TS,
+            Self::EslintNoCaller(_) => EslintNoCaller::WORKS_ON_D_TS,
+            Self::EslintNoCaseDeclarations(_) => EslintNoCaseDeclarations::WORKS_ON_D_TS,
+            Self::EslintNoClassAssign(_) => EslintNoClassAssign::WORKS_ON_D_TS,
+            Self::EslintNoCompareNegZero(_) => EslintNoCompareNegZero::WORKS_ON_D_TS,
+            Self::EslintNoCondAssign(_) => EslintNoCondAssign::WORKS_ON_D_TS,
+            Self::EslintNoConsole(_) => EslintNoConsole::WORKS_ON_D_TS,
+            Self::EslintNoConstAssign(_) => EslintNoConstAssign::WORKS_ON_D_TS,
+            Self::EslintNoConstantBinaryExpression(_) => {
+                EslintNoConstantBinaryExpression::WORKS_ON_D_TS
+            }
+            Self::EslintNoConstantCondition(_) => EslintNoConstantCondition::WORKS_ON_D_TS,
+            Self::EslintNoConstructorReturn(_) => EslintNoConstructorReturn::WORKS_ON_D_TS,
+            Self::EslintNoContinue(_) => EslintNoContinue::WORKS_ON_D_TS,
+            Self::EslintNoControlRegex(_) => EslintNoControlRegex::WORKS_ON_D_TS,
+            Self::EslintNoDebugger(_) => EslintNoDebugger::WORKS_ON_D_TS,
+            Self::EslintNoDeleteVar(_) => EslintNoDeleteVar::WORKS_ON_D_TS,
+            Self::EslintNoDivRegex(_) => EslintNoDivRegex::WORKS_ON_D_TS,
+            Self::EslintNoDupeClassMembers(_) => EslintNoDupeClassMembers::WORKS_ON_D_TS,
+            Self::EslintNoDupeElseIf(_) => EslintNoDupeElseIf::WORKS_ON_D_TS,
+            Self::EslintNoDupeKeys(_) => EslintNoDupeKeys::WORKS_ON_D_TS,
+            Self::EslintNoDuplicateCase(_) => EslintNoDuplicateCase::WORKS_ON_D_TS,
+            Self::EslintNoDuplicateImports(_) => EslintNoDuplicateImports::WORKS_ON_D_TS,
+            Self::EslintNoElseReturn(_) => EslintNoElseReturn::WORKS_ON_D_TS,
+            Self::EslintNoEmpty(_) => EslintNoEmpty::WORKS_ON_D_TS,
+            Self::EslintNoEmptyCharacterClass(_) => EslintNoEmptyCharacterClass::WORKS_ON_D_TS,
+            Self::EslintNoEmptyFunction(_) => EslintNoEmptyFunction::WORKS_ON_D_TS,
+            Self::EslintNoEmptyPattern(_) => EslintNoEmptyPattern::WORKS_ON_D_TS,
+            Self::EslintNoEmptyStaticBlock(_) => EslintNoEmptyStaticBlock::WORKS_ON_D_TS,
+            Self::EslintNoEqNull(_) => EslintNoEqNull::WORKS_ON_D_TS,
+            Self::EslintNoEval(_) => EslintNoEval::WORKS_ON_D_TS,
+            Self::EslintNoExAssign(_) => EslintNoExAssign::WORKS_ON_D_TS,
+            Self::EslintNoExtendNative(_) => EslintNoExtendNative::WORKS_ON_D_TS,
+            Self::EslintNoExtraBind(_) => EslintNoExtraBind::WORKS_ON_D_TS,
+            Self::EslintNoExtraBooleanCast(_) => EslintNoExtraBooleanCast::WORKS_ON_D_TS,
+            Self::EslintNoExtraLabel(_) => EslintNoExtraLabel::WORKS_ON_D_TS,
+            Self::EslintNoFallthrough(_) => EslintNoFallthrough::WORKS_ON_D_TS,
+            Self::EslintNoFuncAssign(_) => EslintNoFuncAssign::WORKS_ON_D_TS,
+            Self::EslintNoGlobalAssign(_) => EslintNoGlobalAssign::WORKS_ON_D_TS,
+            Self::EslintNoImplicitCoercion(_) => EslintNoImplicitCoercion::WORKS_ON_D_TS,
+            Self::EslintNoImplicitGlobals(_) => EslintNoImplicitGlobals::WORKS_ON_D_TS,
+            Self::EslintNoImpliedEval(_) => EslintNoImpliedEval::WORKS_ON_D_TS,
+            Self::EslintNoImportAssign(_) => EslintNoImportAssign::WORKS_ON_D_TS,
+            Self::EslintNoInlineComments(_) => EslintNoInlineComments::WORKS_ON_D_TS,
+            Self::EslintNoInnerDeclarations(_) => EslintNoInnerDeclarations::WORKS_ON_D_TS,
+            Self::EslintNoInvalidRegexp(_) => EslintNoInvalidRegexp::WORKS_ON_D_TS,
+            Self::EslintNoIrregularWhitespace(_) => EslintNoIrregularWhitespace::WORKS_ON_D_TS,
+            Self::EslintNoIterator(_) => EslintNoIterator::WORKS_ON_D_TS,
+            Self::EslintNoLabelVar(_) => EslintNoLabelVar::WORKS_ON_D_TS,
+            Self::EslintNoLabels(_) => EslintNoLabels::WORKS_ON_D_TS,
+            Self::EslintNoLoneBlocks(_) => EslintNoLoneBlocks::WORKS_ON_D_TS,
+            Self::EslintNoLonelyIf(_) => EslintNoLonelyIf::WORKS_ON_D_TS,
+            Self::EslintNoLoopFunc(_) => EslintNoLoopFunc::WORKS_ON_D_TS,
+            Self::EslintNoLossOfPrecision(_) => EslintNoLossOfPrecision::WORKS_ON_D_TS,
+            Self::EslintNoMagicNumbers(_) => EslintNoMagicNumbers::WORKS_ON_D_TS,
+            Self::EslintNoMisleadingCharacterClass(_) => {
+                EslintNoMisleadingCharacterClass::WORKS_ON_D_TS
+            }
+            Self::EslintNoMultiAssign(_) => EslintNoMultiAssign::WORKS_ON_D_TS,
+            Self::EslintNoMultiStr(_) => EslintNoMultiStr::WORKS_ON_D_TS,
+            Self::EslintNoNegatedCondition(_) => EslintNoNegatedCondition::WORKS_ON_D_TS,
+            Self::EslintNoNestedTernary(_) => EslintNoNestedTernary::WORKS_ON_D_TS,
+            Self::EslintNoNew(_) => EslintNoNew::WORKS_ON_D_TS,
+            Self::EslintNoNewFunc(_) => EslintNoNewFunc::WORKS_ON_D_TS,
+            Self::EslintNoNewNativeNonconstructor(_) => {
+                EslintNoNewNativeNonconstructor::WORKS_ON_D_TS
+            }
+            Self::EslintNoNewWrappers(_) => EslintNoNewWrappers::WORKS_ON_D_TS,
+            Self::EslintNoNonoctalDecimalEscape(_) => EslintNoNonoctalDecimalEscape::WORKS_ON_D_TS,
+            Self::EslintNoObjCalls(_) => EslintNoObjCalls::WORKS_ON_D_TS,
+            Self::EslintNoObjectConstructor(_) => EslintNoObjectConstructor::WORKS_ON_D_TS,
+            Self::EslintNoParamReassign(_) => EslintNoParamReassign::WORKS_ON_D_TS,
+            Self::EslintNoPlusplus(_) => EslintNoPlusplus::WORKS_ON_D_TS,
+            Self::EslintNoPromiseExecutorReturn(_) => EslintNoPromiseExecutorReturn::WORKS_ON_D_TS,
+            Self::EslintNoProto(_) => EslintNoProto::WORKS_ON_D_TS,
+            Self::EslintNoPrototypeBuiltins(_) => EslintNoPrototypeBuiltins::WORKS_ON_D_TS,
+            Self::EslintNoRedeclare(_) => EslintNoRedeclare::WORKS_ON_D_TS,
+            Self::EslintNoRegexSpaces(_) => EslintNoRegexSpaces::WORKS_ON_D_TS,
+            Self::EslintNoRestrictedExports(_) => EslintNoRestrictedExports::WORKS_ON_D_TS,
+            Self::EslintNoRestrictedGlobals(_) => EslintNoRestrictedGlobals::WORKS_ON_D_TS,
+            Self::EslintNoRestrictedImports(_) => EslintNoRestrictedImports::WORKS_ON_D_TS,
+            Self::EslintNoRestrictedProperties(_) => EslintNoRestrictedProperties::WORKS_ON_D_TS,
+            Self::EslintNoReturnAssign(_) => EslintNoReturnAssign::WORKS_ON_D_TS,
+            Self::EslintNoScriptUrl(_) => EslintNoScriptUrl::WORKS_ON_D_TS,
+            Self::EslintNoSelfAssign(_) => EslintNoSelfAssign::WORKS_ON_D_TS,
+            Self::EslintNoSelfCompare(_) => EslintNoSelfCompare::WORKS_ON_D_TS,
+            Self::EslintNoSequences(_) => EslintNoSequences::WORKS_ON_D_TS,
+            Self::EslintNoSetterReturn(_) => EslintNoSetterReturn::WORKS_ON_D_TS,
+            Self::EslintNoShadow(_) => EslintNoShadow::WORKS_ON_D_TS,
+            Self::EslintNoShadowRestrictedNames(_) => EslintNoShadowRestrictedNames::WORKS_ON_D_TS,
+            Self::EslintNoSparseArrays(_) => EslintNoSparseArrays::WORKS_ON_D_TS,
+            Self::EslintNoTemplateCurlyInString(_) => EslintNoTemplateCurlyInString::WORKS_ON_D_TS,
+            Self::EslintNoTernary(_) => EslintNoTernary::WORKS_ON_D_TS,
+            Self::EslintNoThisBeforeSuper(_) => EslintNoThisBeforeSuper::WORKS_ON_D_TS,
+            Self::EslintNoThrowLiteral(_) => EslintNoThrowLiteral::WORKS_ON_D_TS,
+            Self::EslintNoUnassignedVars(_) => EslintNoUnassignedVars::WORKS_ON_D_TS,
+            Self::EslintNoUndef(_) => EslintNoUndef::WORKS_ON_D_TS,
+            Self::EslintNoUndefined(_) => EslintNoUndefined::WORKS_ON_D_TS,
+            Self::EslintNoUnderscoreDangle(_) => EslintNoUnderscoreDangle::WORKS_ON_D_TS,
+            Self::EslintNoUnexpectedMultiline(_) => EslintNoUnexpectedMultiline::WORKS_ON_D_TS,
+            Self::EslintNoUnmodifiedLoopCondition(_) => {
+                EslintNoUnmodifiedLoopCondition::WORKS_ON_D_TS
+            }
+            Self::EslintNoUnneededTernary(_) => EslintNoUnneededTernary::WORKS_ON_D_TS,
+            Self::EslintNoUnreachable(_) => EslintNoUnreachable::WORKS_ON_D_TS,
+            Self::EslintNoUnreachableLoop(_) => EslintNoUnreachableLoop::WORKS_ON_D_TS,
+            Self::EslintNoUnsafeFinally(_) => EslintNoUnsafeFinally::WORKS_ON_D_TS,
+            Self::EslintNoUnsafeNegation(_) => EslintNoUnsafeNegation::WORKS_ON_D_TS,
+            Self::EslintNoUnsafeOptionalChaining(_) => {
+                EslintNoUnsafeOptionalChaining::WORKS_ON_D_TS
+            }
+            Self::EslintNoUnusedExpressions(_) => EslintNoUnusedExpressions::WORKS_ON_D_TS,
+            Self::EslintNoUnusedLabels(_) => EslintNoUnusedLabels::WORKS_ON_D_TS,
+            Self::EslintNoUnusedPrivateClassMembers(_) => {
+                EslintNoUnusedPrivateClassMembers::WORKS_ON_D_TS
+            }
+            Self::EslintNoUnusedVars(_) => EslintNoUnusedVars::WORKS_ON_D_TS,
+            Self::EslintNoUseBeforeDefine(_) => EslintNoUseBeforeDefine::WORKS_ON_D_TS,
+            Self::EslintNoUselessAssignment(_) => EslintNoUselessAssignment::WORKS_ON_D_TS,
+            Self::EslintNoUselessBackreference(_) => EslintNoUselessBackreference::WORKS_ON_D_TS,
+            Self::EslintNoUselessCall(_) => EslintNoUselessCall::WORKS_ON_D_TS,
+            Self::EslintNoUselessCatch(_) => EslintNoUselessCatch::WORKS_ON_D_TS,
+            Self::EslintNoUselessComputedKey(_) => EslintNoUselessComputedKey::WORKS_ON_D_TS,
+            Self::EslintNoUselessConcat(_) => EslintNoUselessConcat::WORKS_ON_D_TS,
+            Self::EslintNoUselessConstructor(_) => EslintNoUselessConstructor::WORKS_ON_D_TS,
+            Self::EslintNoUselessEscape(_) => EslintNoUselessEscape::WORKS_ON_D_TS,
+            Self::EslintNoUselessRename(_) => EslintNoUselessRename::WORKS_ON_D_TS,
+            Self::EslintNoUselessReturn(_) => EslintNoUselessReturn::WORKS_ON_D_TS,
+            Self::EslintNoVar(_) => EslintNoVar::WORKS_ON_D_TS,
+            Self::EslintNoVoid(_) => EslintNoVoid::WORKS_ON_D_TS,
+            Self::EslintNoWarningComments(_) => EslintNoWarningComments::WORKS_ON_D_TS,
+            Self::EslintNoWith(_) => EslintNoWith::WORKS_ON_D_TS,
+            Self::EslintObjectShorthand(_) => EslintObjectShorthand::WORKS_ON_D_TS,
+            Self::EslintOneVar(_) => EslintOneVar::WORKS_ON_D_TS,
+            Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::WORKS_ON_D_TS,
+            Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::WORKS_ON_D_TS,
+            Self::EslintPreferConst(_) => EslintPreferConst::WORKS_ON_D_TS,
+            Self::EslintPreferDestructuring(_) => EslintPreferDestructuring::WORKS_ON_D_TS,
+            Self::EslintPreferExponentiationOperator(_) => {
+                EslintPreferExponentiationOperator::WORKS_ON_D_TS
+            }
+            Self::EslintPreferNamedCaptureGroup(_) => EslintPreferNamedCaptureGroup::WORKS_ON_D_TS,
+            Self::EslintPreferNumericLiterals(_) => EslintPreferNumericLiterals::WORKS_ON_D_TS,
+            Self::EslintPreferObjectHasOwn(_) => EslintPreferObjectHasOwn::WORKS_ON_D_TS,
+            Self::EslintPreferObjectSpread(_) => EslintPreferObjectSpread::WORKS_ON_D_TS,
+            Self::EslintPreferPromiseRejectErrors(_) => {
+                EslintPreferPromiseRejectErrors::WORKS_ON_D_TS
+            }
+            Self::EslintPreferRegexLiterals(_) => EslintPreferRegexLiterals::WORKS_ON_D_TS,
+            Self::EslintPreferRestParams(_) => EslintPreferRestParams::WORKS_ON_D_TS,
+            Self::EslintPreferSpread(_) => EslintPreferSpread::WORKS_ON_D_TS,
+            Self::EslintPreferTemplate(_) => EslintPreferTemplate::WORKS_ON_D_TS,
+            Self::EslintPreserveCaughtError(_) => EslintPreserveCaughtError::WORKS_ON_D_TS,
+            Self::EslintRadix(_) => EslintRadix::WORKS_ON_D_TS,
+            Self::EslintRequireAwait(_) => EslintRequireAwait::WORKS_ON_D_TS,
+            Self::EslintRequireUnicodeRegexp(_) => EslintRequireUnicodeRegexp::WORKS_ON_D_TS,
+            Self::EslintRequireYield(_) => EslintRequireYield::WORKS_ON_D_TS,
+            Self::EslintSortImports(_) => EslintSortImports::WORKS_ON_D_TS,
+            Self::EslintSortKeys(_) => EslintSortKeys::WORKS_ON_D_TS,
+            Self::EslintSortVars(_) => EslintSortVars::WORKS_ON_D_TS,
+            Self::EslintSymbolDescription(_) => EslintSymbolDescription::WORKS_ON_D_TS,
+            Self::EslintUnicodeBom(_) => EslintUnicodeBom::WORKS_ON_D_TS,
+            Self::EslintUseIsnan(_) => EslintUseIsnan::WORKS_ON_D_TS,
+            Self::EslintValidTypeof(_) => EslintValidTypeof::WORKS_ON_D_TS,
+            Self::EslintVarsOnTop(_) => EslintVarsOnTop::WORKS_ON_D_TS,
+            Self::EslintYoda(_) => EslintYoda::WORKS_ON_D_TS,
+            Self::TypescriptAdjacentOverloadSignatures(_) => {
+                TypescriptAdjacentOverloadSignatures::WORKS_ON_D_TS
+            }
+            Self::TypescriptArrayType(_) => TypescriptArrayType::WORKS_ON_D_TS,
+            Self::TypescriptAwaitThenable(_) => TypescriptAwaitThenable::WORKS_ON_D_TS,
+            Self::TypescriptBanTsComment(_) => TypescriptBanTsComment::WORKS_ON_D_TS,
+            Self::TypescriptBanTslintComment(_) => TypescriptBanTslintComment::WORKS_ON_D_TS,
+            Self::TypescriptBanTypes(_) => TypescriptBanTypes::WORKS_ON_D_TS,
+            Self::TypescriptClassLiteralPropertyStyle(_) => {
+                TypescriptClassLiteralPropertyStyle::WORKS_ON_D_TS
+            }
+            Self::TypescriptConsistentGenericConstructors(_) => {
+                TypescriptConsistentGenericConstructors::WORKS_ON_D_TS
+            }
+            Self::TypescriptConsistentIndexedObjectStyle(_) => {
+                TypescriptConsistentIndexedObjectStyle::WORKS_ON_D_TS
+            }
+            Self::TypescriptConsistentReturn(_) => TypescriptConsistentReturn::WORKS_ON_D_TS,
+            Self::TypescriptConsistentTypeAssertions(_) => {
+                TypescriptConsistentTypeAssertions::WORKS_ON_D_TS
+            }
+            Self::TypescriptConsistentTypeDefinitions(_) => {
+                TypescriptConsistentTypeDefinitions::WORKS_ON_D_TS
+            }
+            Self::TypescriptConsistentTypeExports(_) => {
+                TypescriptConsistentTypeExports::WORKS_ON_D_TS
+            }
+            Self::TypescriptConsistentTypeImports(_) => {
+                TypescriptConsistentTypeImports::WORKS_ON_D_TS
+            }
+            Self::TypescriptDotNotation(_) => TypescriptDotNotation::WORKS_ON_D_TS,
+            Self::TypescriptExplicitFunctionReturnType(_) => {
+                TypescriptExplicitFunctionReturnType::WORKS_ON_D_TS
+            }
+            Self::TypescriptExplicitMemberAccessibility(_) => {
+                TypescriptExplicitMemberAccessibility::WORKS_ON_D_TS
+            }
+            Self::TypescriptExplicitModuleBoundaryTypes(_) => {
+                TypescriptExplicitModuleBoundaryTypes::WORKS_ON_D_TS
+            }
+            Self::TypescriptMethodSignatureStyle(_) => {
+                TypescriptMethodSignatureStyle::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoArrayDelete(_) => TypescriptNoArrayDelete::WORKS_ON_D_TS,
+            Self::TypescriptNoBaseToString(_) => TypescriptNoBaseToString::WORKS_ON_D_TS,
+            Self::TypescriptNoConfusingNonNullAssertion(_) => {
+                TypescriptNoConfusingNonNullAssertion::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoConfusingVoidExpression(_) => {
+                TypescriptNoConfusingVoidExpression::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoDeprecated(_) => TypescriptNoDeprecated::WORKS_ON_D_TS,
+            Self::TypescriptNoDuplicateEnumValues(_) => {
+                TypescriptNoDuplicateEnumValues::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoDuplicateTypeConstituents(_) => {
+                TypescriptNoDuplicateTypeConstituents::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoDynamicDelete(_) => TypescriptNoDynamicDelete::WORKS_ON_D_TS,
+            Self::TypescriptNoEmptyInterface(_) => TypescriptNoEmptyInterface::WORKS_ON_D_TS,
+            Self::TypescriptNoEmptyObjectType(_) => TypescriptNoEmptyObjectType::WORKS_ON_D_TS,
+            Self::TypescriptNoExplicitAny(_) => TypescriptNoExplicitAny::WORKS_ON_D_TS,
+            Self::TypescriptNoExtraNonNullAssertion(_) => {
+                TypescriptNoExtraNonNullAssertion::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoExtraneousClass(_) => TypescriptNoExtraneousClass::WORKS_ON_D_TS,
+            Self::TypescriptNoFloatingPromises(_) => TypescriptNoFloatingPromises::WORKS_ON_D_TS,
+            Self::TypescriptNoForInArray(_) => TypescriptNoForInArray::WORKS_ON_D_TS,
+            Self::TypescriptNoImpliedEval(_) => TypescriptNoImpliedEval::WORKS_ON_D_TS,
+            Self::TypescriptNoImportTypeSideEffects(_) => {
+                TypescriptNoImportTypeSideEffects::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoInferrableTypes(_) => TypescriptNoInferrableTypes::WORKS_ON_D_TS,
+            Self::TypescriptNoInvalidVoidType(_) => TypescriptNoInvalidVoidType::WORKS_ON_D_TS,
+            Self::TypescriptNoMeaninglessVoidOperator(_) => {
+                TypescriptNoMeaninglessVoidOperator::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoMisusedNew(_) => TypescriptNoMisusedNew::WORKS_ON_D_TS,
+            Self::TypescriptNoMisusedPromises(_) => TypescriptNoMisusedPromises::WORKS_ON_D_TS,
+            Self::TypescriptNoMisusedSpread(_) => TypescriptNoMisusedSpread::WORKS_ON_D_TS,
+            Self::TypescriptNoMixedEnums(_) => TypescriptNoMixedEnums::WORKS_ON_D_TS,
+            Self::TypescriptNoNamespace(_) => TypescriptNoNamespace::WORKS_ON_D_TS,
+            Self::TypescriptNoNonNullAssertedNullishCoalescing(_) => {
+                TypescriptNoNonNullAssertedNullishCoalescing::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoNonNullAssertedOptionalChain(_) => {
+                TypescriptNoNonNullAssertedOptionalChain::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoNonNullAssertion(_) => TypescriptNoNonNullAssertion::WORKS_ON_D_TS,
+            Self::TypescriptNoRedundantTypeConstituents(_) => {
+                TypescriptNoRedundantTypeConstituents::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoRequireImports(_) => TypescriptNoRequireImports::WORKS_ON_D_TS,
+            Self::TypescriptNoRestrictedTypes(_) => TypescriptNoRestrictedTypes::WORKS_ON_D_TS,
+            Self::TypescriptNoThisAlias(_) => TypescriptNoThisAlias::WORKS_ON_D_TS,
+            Self::TypescriptNoUnnecessaryBooleanLiteralCompare(_) => {
+                TypescriptNoUnnecessaryBooleanLiteralCompare::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnnecessaryCondition(_) => {
+                TypescriptNoUnnecessaryCondition::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnnecessaryParameterPropertyAssignment(_) => {
+                TypescriptNoUnnecessaryParameterPropertyAssignment::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnnecessaryQualifier(_) => {
+                TypescriptNoUnnecessaryQualifier::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnnecessaryTemplateExpression(_) => {
+                TypescriptNoUnnecessaryTemplateExpression::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnnecessaryTypeArguments(_) => {
+                TypescriptNoUnnecessaryTypeArguments::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnnecessaryTypeAssertion(_) => {
+                TypescriptNoUnnecessaryTypeAssertion::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnnecessaryTypeConstraint(_) => {
+                TypescriptNoUnnecessaryTypeConstraint::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnnecessaryTypeConversion(_) => {
+                TypescriptNoUnnecessaryTypeConversion::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnnecessaryTypeParameters(_) => {
+                TypescriptNoUnnecessaryTypeParameters::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnsafeArgument(_) => TypescriptNoUnsafeArgument::WORKS_ON_D_TS,
+            Self::TypescriptNoUnsafeAssignment(_) => TypescriptNoUnsafeAssignment::WORKS_ON_D_TS,
+            Self::TypescriptNoUnsafeCall(_) => TypescriptNoUnsafeCall::WORKS_ON_D_TS,
+            Self::TypescriptNoUnsafeDeclarationMerging(_) => {
+                TypescriptNoUnsafeDeclarationMerging::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnsafeEnumComparison(_) => {
+                TypescriptNoUnsafeEnumComparison::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnsafeFunctionType(_) => {
+                TypescriptNoUnsafeFunctionType::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnsafeMemberAccess(_) => {
+                TypescriptNoUnsafeMemberAccess::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnsafeReturn(_) => TypescriptNoUnsafeReturn::WORKS_ON_D_TS,
+            Self::TypescriptNoUnsafeTypeAssertion(_) => {
+                TypescriptNoUnsafeTypeAssertion::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUnsafeUnaryMinus(_) => TypescriptNoUnsafeUnaryMinus::WORKS_ON_D_TS,
+            Self::TypescriptNoUselessDefaultAssignment(_) => {
+                TypescriptNoUselessDefaultAssignment::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoUselessEmptyExport(_) => {
+                TypescriptNoUselessEmptyExport::WORKS_ON_D_TS
+            }
+            Self::TypescriptNoVarRequires(_) => TypescriptNoVarRequires::WORKS_ON_D_TS,
+            Self::TypescriptNoWrapperObjectTypes(_) => {
+                TypescriptNoWrapperObjectTypes::WORKS_ON_D_TS
+            }
+            Self::TypescriptNonNullableTypeAssertionStyle(_) => {
+                TypescriptNonNullableTypeAssertionStyle::WORKS_ON_D_TS
+            }
+            Self::TypescriptOnlyThrowError(_) => TypescriptOnlyThrowError::WORKS_ON_D_TS,
+            Self::TypescriptParameterProperties(_) => TypescriptParameterProperties::WORKS_ON_D_TS,
+            Self::TypescriptPreferAsConst(_) => TypescriptPreferAsConst::WORKS_ON_D_TS,
+            Self::TypescriptPreferEnumInitializers(_) => {
+                TypescriptPreferEnumInitializers::WORKS_ON_D_TS
+            }
+            Self::TypescriptPreferFind(_) => TypescriptPreferFind::WORKS_ON_D_TS,
+            Self::TypescriptPreferForOf(_) => TypescriptPreferForOf::WORKS_ON_D_TS,
+            Self::TypescriptPreferFunctionType(_) => TypescriptPreferFunctionType::WORKS_ON_D_TS,
+            Self::TypescriptPreferIncludes(_) => TypescriptPreferIncludes::WORKS_ON_D_TS,
+            Self::TypescriptPreferLiteralEnumMember(_) => {
+                TypescriptPreferLiteralEnumMember::WORKS_ON_D_TS
+            }
+            Self::TypescriptPreferNamespaceKeyword(_) => {
+                TypescriptPreferNamespaceKeyword::WORKS_ON_D_TS
+            }
+            Self::TypescriptPreferNullishCoalescing(_) => {
+                TypescriptPreferNullishCoalescing::WORKS_ON_D_TS
+            }
+            Self::TypescriptPreferOptionalChain(_) => TypescriptPreferOptionalChain::WORKS_ON_D_TS,
+            Self::TypescriptPreferPromiseRejectErrors(_) => {
+                TypescriptPreferPromiseRejectErrors::WORKS_ON_D_TS
+            }
+            Self::TypescriptPreferReadonly(_) => TypescriptPreferReadonly::WORKS_ON_D_TS,
+            Self::TypescriptPreferReadonlyParameterTypes(_) => {
+                TypescriptPreferReadonlyParameterTypes::WORKS_ON_D_TS
+            }
+            Self::TypescriptPreferReduceTypeParameter(_) => {
+                TypescriptPreferReduceTypeParameter::WORKS_ON_D_TS
+            }
+            Self::TypescriptPreferRegexpExec(_) => TypescriptPreferRegexpExec::WORKS_ON_D_TS,
+            Self::TypescriptPreferReturnThisType(_) => {
+                TypescriptPreferReturnThisType::WORKS_ON_D_TS
+            }
+            Self::TypescriptPreferStringStartsEndsWith(_) => {
+                TypescriptPreferStringStartsEndsWith::WORKS_ON_D_TS
+            }
+            Self::TypescriptPreferTsExpectError(_) => TypescriptPreferTsExpectError::WORKS_ON_D_TS,
+            Self::TypescriptPromiseFunctionAsync(_) => {
+                TypescriptPromiseFunctionAsync::WORKS_ON_D_TS
+            }
+            Self::TypescriptRelatedGetterSetterPairs(_) => {
+                TypescriptRelatedGetterSetterPairs::WORKS_ON_D_TS
+            }
+            Self::TypescriptRequireArraySortCompare(_) => {
+                TypescriptRequireArraySortCompare::WORKS_ON_D_TS
+            }
+            Self::TypescriptRequireAwait(_) => TypescriptRequireAwait::WORKS_ON_D_TS,
+            Self::TypescriptRestrictPlusOperands(_) => {
+                TypescriptRestrictPlusOperands::WORKS_ON_D_TS
+            }
+            Self::TypescriptRestrictTemplateExpressions(_) => {
+                TypescriptRestrictTemplateExpressions::WORKS_ON_D_TS
+            }
+            Self::TypescriptReturnAwait(_) => TypescriptReturnAwait::WORKS_ON_D_TS,
+            Self::TypescriptStrictBooleanExpressions(_) => {
+                TypescriptStrictBooleanExpressions::WORKS_ON_D_TS
+            }
+            Self::TypescriptStrictVoidReturn(_) => TypescriptStrictVoidReturn::WORKS_ON_D_TS,
+            Self::TypescriptSwitchExhaustivenessCheck(_) => {
+                TypescriptSwitchExhaustivenessCheck::WORKS_ON_D_TS
+            }
+            Self::TypescriptTripleSlashReference(_) => {
+                TypescriptTripleSlashReference::WORKS_ON_D_TS
+            }
+            Self::TypescriptUnboundMethod(_) => TypescriptUnboundMethod::WORKS_ON_D_TS,
+            Self::TypescriptUnifiedSignatures(_) => TypescriptUnifiedSignatures::WORKS_ON_D_TS,
+            Self::TypescriptUseUnknownInCatchCallbackVariable(_) => {
+                TypescriptUseUnknownInCatchCallbackVariable::WORKS_ON_D_TS
+            }
+            Self::JestConsistentTestIt(_) => JestConsistentTestIt::WORKS_ON_D_TS,
+            Self::JestExpectExpect(_) => JestExpectExpect::WORKS_ON_D_TS,
+            Self::JestMaxExpects(_) => JestMaxExpects::WORKS_ON_D_TS,
+            Self::JestMaxNestedDescribe(_) => JestMaxNestedDescribe::WORKS_ON_D_TS,
+            Self::JestNoAliasMethods(_) => JestNoAliasMethods::WORKS_ON_D_TS,
+            Self::JestNoCommentedOutTests(_) => JestNoCommentedOutTests::WORKS_ON_D_TS,
+            Self::JestNoConditionalExpect(_) => JestNoConditionalExpect::WORKS_ON_D_TS,
+            Self::JestNoConditionalInTest(_) => JestNoConditionalInTest::WORKS_ON_D_TS,
+            Self::JestNoConfusingSetTimeout(_) => JestNoConfusingSetTimeout::WORKS_ON_D_TS,
+            Self::JestNoDeprecatedFunctions(_) => JestNoDeprecatedFunctions::WORKS_ON_D_TS,
+            Self::JestNoDisabledTests(_) => JestNoDisabledTests::WORKS_ON_D_TS,
+            Self::JestNoDoneCallback(_) => JestNoDoneCallback::WORKS_ON_D_TS,
+            Self::JestNoDuplicateHooks(_) => JestNoDuplicateHooks::WORKS_ON_D_TS,
+            Self::JestNoExport(_) => JestNoExport::WORKS_ON_D_TS,
+            Self::JestNoFocusedTests(_) => JestNoFocusedTests::WORKS_ON_D_TS,
+            Self::JestNoHooks(_) => JestNoHooks::WORKS_ON_D_TS,
+            Self::JestNoIdenticalTitle(_) => JestNoIdenticalTitle::WORKS_ON_D_TS,
+            Self::JestNoInterpolationInSnapshots(_) => {
+                JestNoInterpolationInSnapshots::WORKS_ON_D_TS
+            }
+            Self::JestNoJasmineGlobals(_) => JestNoJasmineGlobals::WORKS_ON_D_TS,
+            Self::JestNoLargeSnapshots(_) => JestNoLargeSnapshots::WORKS_ON_D_TS,
+            Self::JestNoMocksImport(_) => JestNoMocksImport::WORKS_ON_D_TS,
+            Self::JestNoRestrictedJestMethods(_) => JestNoRestrictedJestMethods::WORKS_ON_D_TS,
+            Self::JestNoRestrictedMatchers(_) => JestNoRestrictedMatchers::WORKS_ON_D_TS,
+            Self::JestNoStandaloneExpect(_) => JestNoStandaloneExpect::WORKS_ON_D_TS,
+            Self::JestNoTestPrefixes(_) => JestNoTestPrefixes::WORKS_ON_D_TS,
+            Self::JestNoTestReturnStatement(_) => JestNoTestReturnStatement::WORKS_ON_D_TS,
+            Self::JestNoUnneededAsyncExpectFunction(_) => {
+                JestNoUnneededAsyncExpectFunction::WORKS_ON_D_TS
+            }
+            Self::JestNoUntypedMockFactory(_) => JestNoUntypedMockFactory::WORKS_ON_D_TS,
+            Self::JestPaddingAroundAfterAllBlocks(_) => {
+                JestPaddingAroundAfterAllBlocks::WORKS_ON_D_TS
+            }
+            Self::JestPaddingAroundTestBlocks(_) => JestPaddingAroundTestBlocks::WORKS_ON_D_TS,
+            Self::JestPreferCalledWith(_) => JestPreferCalledWith::WORKS_ON_D_TS,
+            Self::JestPreferComparisonMatcher(_) => JestPreferComparisonMatcher::WORKS_ON_D_TS,
+            Self::JestPreferEach(_) => JestPreferEach::WORKS_ON_D_TS,
+            Self::JestPreferEndingWithAnExpect(_) => JestPreferEndingWithAnExpect::WORKS_ON_D_TS,
+            Self::JestPreferEqualityMatcher(_) => JestPreferEqualityMatcher::WORKS_ON_D_TS,
+            Self::JestPreferExpectAssertions(_) => JestPreferExpectAssertions::WORKS_ON_D_TS,
+            Self::JestPreferExpectResolves(_) => JestPreferExpectResolves::WORKS_ON_D_TS,
+            Self::JestPreferHooksInOrder(_) => JestPreferHooksInOrder::WORKS_ON_D_TS,
+            Self::JestPreferHooksOnTop(_) => JestPreferHooksOnTop::WORKS_ON_D_TS,
+            Self::JestPreferImportingJestGlobals(_) => {
+                JestPreferImportingJestGlobals::WORKS_ON_D_TS
+            }
+            Self::JestPreferJestMocked(_) => JestPreferJestMocked::WORKS_ON_D_TS,
+            Self::JestPreferLowercaseTitle(_) => JestPreferLowercaseTitle::WORKS_ON_D_TS,
+            Self::JestPreferMockPromiseShorthand(_) => {
+                JestPreferMockPromiseShorthand::WORKS_ON_D_TS
+            }
+            Self::JestPreferMockReturnShorthand(_) => JestPreferMockReturnShorthand::WORKS_ON_D_TS,
+            Self::JestPreferSnapshotHint(_) => JestPreferSnapshotHint::WORKS_ON_D_TS,
+            Self::JestPreferSpyOn(_) => JestPreferSpyOn::WORKS_ON_D_TS,
+            Self::JestPreferStrictEqual(_) => JestPreferStrictEqual::WORKS_ON_D_TS,
+            Self::JestPreferToBe(_) => JestPreferToBe::WORKS_ON_D_TS,
+            Self::JestPreferToContain(_) => JestPreferToContain::WORKS_ON_D_TS,
+            Self::JestPreferToHaveBeenCalled(_) => JestPreferToHaveBeenCalled::WORKS_ON_D_TS,
+            Self::JestPreferToHaveBeenCalledTimes(_) => {
+                JestPreferToHaveBeenCalledTimes::WORKS_ON_D_TS
+            }
+            Self::JestPreferToHaveLength(_) => JestPreferToHaveLength::WORKS_ON_D_TS,
+            Self::JestPreferTodo(_) => JestPreferTodo::WORKS_ON_D_TS,
+            Self::JestRequireHook(_) => JestRequireHook::WORKS_ON_D_TS,
+            Self::JestRequireToThrowMessage(_) => JestRequireToThrowMessage::WORKS_ON_D_TS,
+            Self::JestRequireTopLevelDescribe(_) => JestRequireTopLevelDescribe::WORKS_ON_D_TS,
+            Self::JestValidDescribeCallback(_) => JestValidDescribeCallback::WORKS_ON_D_TS,
+            Self::JestValidExpect(_) => JestValidExpect::WORKS_ON_D_TS,
+            Self::JestValidExpectInPromise(_) => JestValidExpectInPromise::WORKS_ON_D_TS,
+            Self::JestValidTitle(_) => JestValidTitle::WORKS_ON_D_TS,
+            Self::ReactButtonHasType(_) => ReactButtonHasType::WORKS_ON_D_TS,
+            Self::ReactCheckedRequiresOnchangeOrReadonly(_) => {
+                ReactCheckedRequiresOnchangeOrReadonly::WORKS_ON_D_TS
+            }
+            Self::ReactDisplayName(_) => ReactDisplayName::WORKS_ON_D_TS,
+            Self::ReactExhaustiveDeps(_) => ReactExhaustiveDeps::WORKS_ON_D_TS,
+            Self::ReactForbidComponentProps(_) => ReactForbidComponentProps::WORKS_ON_D_TS,
+            Self::ReactForbidDomProps(_) => ReactForbidDomProps::WORKS_ON_D_TS,
+            Self::ReactForbidElements(_) => ReactForbidElements::WORKS_ON_D_TS,
+            Self::ReactForwardRefUsesRef(_) => ReactForwardRefUsesRef::WORKS_ON_D_TS,
+            Self::ReactFunctionComponentDefinition(_) => {
+                ReactFunctionComponentDefinition::WORKS_ON_D_TS
+            }
+            Self::ReactHookUseState(_) => ReactHookUseState::WORKS_ON_D_TS,
+            Self::ReactIframeMissingSandbox(_) => ReactIframeMissingSandbox::WORKS_ON_D_TS,
+            Self::ReactJsxBooleanValue(_) => ReactJsxBooleanValue::WORKS_ON_D_TS,
+            Self::ReactJsxCurlyBracePresence(_) => ReactJsxCurlyBracePresence::WORKS_ON_D_TS,
+            Self::ReactJsxFilenameExtension(_) => ReactJsxFilenameExtension::WORKS_ON_D_TS,
+            Self::ReactJsxFragments(_) => ReactJsxFragments::WORKS_ON_D_TS,
+            Self::ReactJsxHandlerNames(_) => ReactJsxHandlerNames::WORKS_ON_D_TS,
+            Self::ReactJsxKey(_) => ReactJsxKey::WORKS_ON_D_TS,
+            Self::ReactJsxMaxDepth(_) => ReactJsxMaxDepth::WORKS_ON_D_TS,
+            Self::ReactJsxNoCommentTextnodes(_) => ReactJsxNoCommentTextnodes::WORKS_ON_D_TS,
+            Self::ReactJsxNoConstructedContextValues(_) => {
+                ReactJsxNoConstructedContextValues::WORKS_ON_D_TS
+            }
+            Self::ReactJsxNoDuplicateProps(_) => ReactJsxNoDuplicateProps::WORKS_ON_D_TS,
+            Self::ReactJsxNoLiterals(_) => ReactJsxNoLiterals::WORKS_ON_D_TS,
+            Self::ReactJsxNoScriptUrl(_) => ReactJsxNoScriptUrl::WORKS_ON_D_TS,
+            Self::ReactJsxNoTargetBlank(_) => ReactJsxNoTargetBlank::WORKS_ON_D_TS,
+            Self::ReactJsxNoUndef(_) => ReactJsxNoUndef::WORKS_ON_D_TS,
+            Self::ReactJsxNoUselessFragment(_) => ReactJsxNoUselessFragment::WORKS_ON_D_TS,
+            Self::ReactJsxPascalCase(_) => ReactJsxPascalCase::WORKS_ON_D_TS,
+            Self::ReactJsxPropsNoSpreadMulti(_) => ReactJsxPropsNoSpreadMulti::WORKS_ON_D_TS,
+            Self::ReactJsxPropsNoSpreading(_) => ReactJsxPropsNoSpreading::WORKS_ON_D_TS,
+            Self::ReactNoArrayIndexKey(_) => ReactNoArrayIndexKey::WORKS_ON_D_TS,
+            Self::ReactNoChildrenProp(_) => ReactNoChildrenProp::WORKS_ON_D_TS,
+            Self::ReactNoCloneElement(_) => ReactNoCloneElement::WORKS_ON_D_TS,
+            Self::ReactNoDanger(_) => ReactNoDanger::WORKS_ON_D_TS,
+            Self::ReactNoDangerWithChildren(_) => ReactNoDangerWithChildren::WORKS_ON_D_TS,
+            Self::ReactNoDidMountSetState(_) => ReactNoDidMountSetState::WORKS_ON_D_TS,
+            Self::ReactNoDidUpdateSetState(_) => ReactNoDidUpdateSetState::WORKS_ON_D_TS,
+            Self::ReactNoDirectMutationState(_) => ReactNoDirectMutationState::WORKS_ON_D_TS,
+            Self::ReactNoFindDomNode(_) => ReactNoFindDomNode::WORKS_ON_D_TS,
+            Self::ReactNoIsMounted(_) => ReactNoIsMounted::WORKS_ON_D_TS,
+            Self::ReactNoMultiComp(_) => ReactNoMultiComp::WORKS_ON_D_TS,
+            Self::ReactNoNamespace(_) => ReactNoNamespace::WORKS_ON_D_TS,
+            Self::ReactNoObjectTypeAsDefaultProp(_) => {
+                ReactNoObjectTypeAsDefaultProp::WORKS_ON_D_TS
+            }
+            Self::ReactNoReactChildren(_) => ReactNoReactChildren::WORKS_ON_D_TS,
+            Self::ReactNoRedundantShouldComponentUpdate(_) => {
+                ReactNoRedundantShouldComponentUpdate::WORKS_ON_D_TS
+            }
+            Self::ReactNoRenderReturnValue(_) => ReactNoRenderReturnValue::WORKS_ON_D_TS,
+            Self::ReactNoSetState(_) => ReactNoSetState::WORKS_ON_D_TS,
+            Self::ReactNoStringRefs(_) => ReactNoStringRefs::WORKS_ON_D_TS,
+            Self::ReactNoThisInSfc(_) => ReactNoThisInSfc::WORKS_ON_D_TS,
+            Self::ReactNoUnescapedEntities(_) => ReactNoUnescapedEntities::WORKS_ON_D_TS,
+            Self::ReactNoUnknownProperty(_) => ReactNoUnknownProperty::WORKS_ON_D_TS,
+            Self::ReactNoUnsafe(_) => ReactNoUnsafe::WORKS_ON_D_TS,
+            Self::ReactNoUnstableNestedComponents(_) => {
+                ReactNoUnstableNestedComponents::WORKS_ON_D_TS
+            }
+            Self::ReactNoWillUpdateSetState(_) => ReactNoWillUpdateSetState::WORKS_ON_D_TS,
+            Self::ReactOnlyExportComponents(_) => ReactOnlyExportComponents::WORKS_ON_D_TS,
+            Self::ReactPreferEs6Class(_) => ReactPreferEs6Class::WORKS_ON_D_TS,
+            Self::ReactPreferFunctionComponent(_) => ReactPreferFunctionComponent::WORKS_ON_D_TS,
+            Self::ReactReactCompiler(_) => ReactReactCompiler::WORKS_ON_D_TS,
+            Self::ReactReactInJsxScope(_) => ReactReactInJsxScope::WORKS_ON_D_TS,
+            Self::ReactRequireRenderReturn(_) => ReactRequireRenderReturn::WORKS_ON_D_TS,
+            Self::ReactRulesOfHooks(_) => ReactRulesOfHooks::WORKS_ON_D_TS,
+            Self::ReactSelfClosingComp(_) => ReactSelfClosingComp::WORKS_ON_D_TS,
+            Self::ReactStateInConstructor(_) => ReactStateInConstructor::WORKS_ON_D_TS,
+            Self::ReactStylePropObject(_) => ReactStylePropObject::WORKS_ON_D_TS,
+            Self::ReactVoidDomElementsNoChildren(_) => {
+                ReactVoidDomElementsNoChildren::WORKS_ON_D_TS
+            }
+            Self::ReactPerfJsxNoJsxAsProp(_) => ReactPerfJsxNoJsxAsProp::WORKS_ON_D_TS,
+            Self::ReactPerfJsxNoNewArrayAsProp(_) => ReactPerfJsxNoNewArrayAsProp::WORKS_ON_D_TS,
+            Self::ReactPerfJsxNoNewFunctionAsProp(_) => {
+                ReactPerfJsxNoNewFunctionAsProp::WORKS_ON_D_TS
+            }
+            Self::ReactPerfJsxNoNewObjectAsProp(_) => ReactPerfJsxNoNewObjectAsProp::WORKS_ON_D_TS,
+            Self::UnicornCatchErrorName(_) => UnicornCatchErrorName::WORKS_ON_D_TS,
+            Self::UnicornConsistentAssert(_) => UnicornConsistentAssert::WORKS_ON_D_TS,
+            Self::UnicornConsistentDateClone(_) => UnicornConsistentDateClone::WORKS_ON_D_TS,
+            Self::UnicornConsistentEmptyArraySpread(_) => {
+                UnicornConsistentEmptyArraySpread::WORKS_ON_D_TS
+            }
+            Self::UnicornConsistentExistenceIndexCheck(_) => {
+                UnicornConsistentExistenceIndexCheck::WORKS_ON_D_TS
+            }
+            Self::UnicornConsistentFunctionScoping(_) => {
+                UnicornConsistentFunctionScoping::WORKS_ON_D_TS
+            }
+            Self::UnicornConsistentTemplateLiteralEscape(_) => {
+                UnicornConsistentTemplateLiteralEscape::WORKS_ON_D_TS
+            }
+            Self::UnicornCustomErrorDefinition(_) => UnicornCustomErrorDefinition::WORKS_ON_D_TS,
+            Self::UnicornEmptyBraceSpaces(_) => UnicornEmptyBraceSpaces::WORKS_ON_D_TS,
+            Self::UnicornErrorMessage(_) => UnicornErrorMessage::WORKS_ON_D_TS,
+            Self::UnicornEscapeCase(_) => UnicornEscapeCase::WORKS_ON_D_TS,
+            Self::UnicornExplicitLengthCheck(_) => UnicornExplicitLengthCheck::WORKS_ON_D_TS,
+            Self::UnicornExplicitTimerDelay(_) => UnicornExplicitTimerDelay::WORKS_ON_D_TS,
+            Self::UnicornFilenameCase(_) => UnicornFilenameCase::WORKS_ON_D_TS,
+            Self::UnicornImportStyle(_) => UnicornImportStyle::WORKS_ON_D_TS,
+            Self::UnicornMaxNestedCalls(_) => UnicornMaxNestedCalls::WORKS_ON_D_TS,
+            Self::UnicornNewForBuiltins(_) => UnicornNewForBuiltins::WORKS_ON_D_TS,
+            Self::UnicornNoAbusiveEslintDisable(_) => UnicornNoAbusiveEslintDisable::WORKS_ON_D_TS,
+            Self::UnicornNoAccessorRecursion(_) => UnicornNoAccessorRecursion::WORKS_ON_D_TS,
+            Self::UnicornNoAnonymousDefaultExport(_) => {
+                UnicornNoAnonymousDefaultExport::WORKS_ON_D_TS
+            }
+            Self::UnicornNoArrayCallbackReference(_) => {
+                UnicornNoArrayCallbackReference::WORKS_ON_D_TS
+            }
+            Self::UnicornNoArrayFillWithReferenceType(_) => {
+                UnicornNoArrayFillWithReferenceType::WORKS_ON_D_TS
+            }
+            Self::UnicornNoArrayForEach(_) => UnicornNoArrayForEach::WORKS_ON_D_TS,
+            Self::UnicornNoArrayMethodThisArgument(_) => {
+                UnicornNoArrayMethodThisArgument::WORKS_ON_D_TS
+            }
+            Self::UnicornNoArrayReduce(_) => UnicornNoArrayReduce::WORKS_ON_D_TS,
+            Self::UnicornNoArrayReverse(_) => UnicornNoArrayReverse::WORKS_ON_D_TS,
+            Self::UnicornNoArraySort(_) => UnicornNoArraySort::WORKS_ON_D_TS,
+            Self::UnicornNoAwaitExpressionMember(_) => {
+                UnicornNoAwaitExpressionMember::WORKS_ON_D_TS
+            }
+            Self::UnicornNoAwaitInPromiseMethods(_) => {
+                UnicornNoAwaitInPromiseMethods::WORKS_ON_D_TS
+            }
+            Self::UnicornNoConfusingArrayWith(_) => UnicornNoConfusingArrayWith::WORKS_ON_D_TS,
+            Self::UnicornNoConsoleSpaces(_) => UnicornNoConsoleSpaces::WORKS_ON_D_TS,
+            Self::UnicornNoDocumentCookie(_) => UnicornNoDocumentCookie::WORKS_ON_D_TS,
+            Self::UnicornNoEmptyFile(_) => UnicornNoEmptyFile::WORKS_ON_D_TS,
+            Self::UnicornNoHexEscape(_) => UnicornNoHexEscape::WORKS_ON_D_TS,
+            Self::UnicornNoImmediateMutation(_) => UnicornNoImmediateMutation::WORKS_ON_D_TS,
+            Self::UnicornNoInstanceofArray(_) => UnicornNoInstanceofArray::WORKS_ON_D_TS,
+            Self::UnicornNoInstanceofBuiltins(_) => UnicornNoInstanceofBuiltins::WORKS_ON_D_TS,
+            Self::UnicornNoInvalidFetchOptions(_) => UnicornNoInvalidFetchOptions::WORKS_ON_D_TS,
+            Self::UnicornNoInvalidRemoveEventListener(_) => {
+                UnicornNoInvalidRemoveEventListener::WORKS_ON_D_TS
+            }
+            Self::UnicornNoLengthAsSliceEnd(_) => UnicornNoLengthAsSliceEnd::WORKS_ON_D_TS,
+            Self::UnicornNoLonelyIf(_) => UnicornNoLonelyIf::WORKS_ON_D_TS,
+            Self::UnicornNoMagicArrayFlatDepth(_) => UnicornNoMagicArrayFlatDepth::WORKS_ON_D_TS,
+            Self::UnicornNoNegatedCondition(_) => UnicornNoNegatedCondition::WORKS_ON_D_TS,
+            Self::UnicornNoNegationInEqualityCheck(_) => {
+                UnicornNoNegationInEqualityCheck::WORKS_ON_D_TS
+            }
+            Self::UnicornNoNestedTernary(_) => UnicornNoNestedTernary::WORKS_ON_D_TS,
+            Self::UnicornNoNewArray(_) => UnicornNoNewArray::WORKS_ON_D_TS,
+            Self::UnicornNoNewBuffer(_) => UnicornNoNewBuffer::WORKS_ON_D_TS,
+            Self::UnicornNoNull(_) => UnicornNoNull::WORKS_ON_D_TS,
+            Self::UnicornNoObjectAsDefaultParameter(_) => {
+                UnicornNoObjectAsDefaultParameter::WORKS_ON_D_TS
+            }
+            Self::UnicornNoProcessExit(_) => UnicornNoProcessExit::WORKS_ON_D_TS,
+            Self::UnicornNoSinglePromiseInPromiseMethods(_) => {
+                UnicornNoSinglePromiseInPromiseMethods::WORKS_ON_D_TS
+            }
+            Self::UnicornNoStaticOnlyClass(_) => UnicornNoStaticOnlyClass::WORKS_ON_D_TS,
+            Self::UnicornNoThenable(_) => UnicornNoThenable::WORKS_ON_D_TS,
+            Self::UnicornNoThisAssignment(_) => UnicornNoThisAssignment::WORKS_ON_D_TS,
+            Self::UnicornNoTypeofUndefined(_) => UnicornNoTypeofUndefined::WORKS_ON_D_TS,
+            Self::UnicornNoUnnecessaryArrayFlatDepth(_) => {
+                UnicornNoUnnecessaryArrayFlatDepth::WORKS_ON_D_TS
+            }
+            Self::UnicornNoUnnecessaryArraySpliceCount(_) => {
+                UnicornNoUnnecessaryArraySpliceCount::WORKS_ON_D_TS
+            }
+            Self::UnicornNoUnnecessaryAwait(_) => UnicornNoUnnecessaryAwait::WORKS_ON_D_TS,
+            Self::UnicornNoUnnecessarySliceEnd(_) => UnicornNoUnnecessarySliceEnd::WORKS_ON_D_TS,
+            Self::UnicornNoUnreadableArrayDestructuring(_) => {
+                UnicornNoUnreadableArrayDestructuring::WORKS_ON_D_TS
+            }
+            Self::UnicornNoUnreadableIife(_) => UnicornNoUnreadableIife::WORKS_ON_D_TS,
+            Self::UnicornNoUselessCollectionArgument(_) => {
+                UnicornNoUselessCollectionArgument::WORKS_ON_D_TS
+            }
+            Self::UnicornNoUselessErrorCaptureStackTrace(_) => {
+                UnicornNoUselessErrorCaptureStackTrace::WORKS_ON_D_TS
+            }
+            Self::UnicornNoUselessFallbackInSpread(_) => {
+                UnicornNoUselessFallbackInSpread::WORKS_ON_D_TS
+            }
+            Self::UnicornNoUselessIteratorToArray(_) => {
+                UnicornNoUselessIteratorToArray::WORKS_ON_D_TS
+            }
+            Self::UnicornNoUselessLengthCheck(_) => UnicornNoUselessLengthCheck::WORKS_ON_D_TS,
+            Self::UnicornNoUselessPromiseResolveReject(_) => {
+                UnicornNoUselessPromiseResolveReject::WORKS_ON_D_TS
+            }
+            Self::UnicornNoUselessSpread(_) => UnicornNoUselessSpread::WORKS_ON_D_TS,
+            Self::UnicornNoUselessSwitchCase(_) => UnicornNoUselessSwitchCase::WORKS_ON_D_TS,
+            Self::UnicornNoUselessUndefined(_) => UnicornNoUselessUndefined::WORKS_ON_D_TS,
+            Self::UnicornNoZeroFractions(_) => UnicornNoZeroFractions::WORKS_ON_D_TS,
+            Self::UnicornNumberLiteralCase(_) => UnicornNumberLiteralCase::WORKS_ON_D_TS,
+            Self::UnicornNumericSeparatorsStyle(_) => UnicornNumericSeparatorsStyle::WORKS_ON_D_TS,
+            Self::UnicornPreferAddEventListener(_) => UnicornPreferAddEventListener::WORKS_ON_D_TS,
+            Self::UnicornPreferArrayFind(_) => UnicornPreferArrayFind::WORKS_ON_D_TS,
+            Self::UnicornPreferArrayFlat(_) => UnicornPreferArrayFlat::WORKS_ON_D_TS,
+            Self::UnicornPreferArrayFlatMap(_) => UnicornPreferArrayFlatMap::WORKS_ON_D_TS,
+            Self::UnicornPreferArrayIndexOf(_) => UnicornPreferArrayIndexOf::WORKS_ON_D_TS,
+            Self::UnicornPreferArraySome(_) => UnicornPreferArraySome::WORKS_ON_D_TS,
+            Self::UnicornPreferAt(_) => UnicornPreferAt::WORKS_ON_D_TS,
+            Self::UnicornPreferBigintLiterals(_) => UnicornPreferBigintLiterals::WORKS_ON_D_TS,
+            Self::UnicornPreferBlobReadingMethods(_) => {
+                UnicornPreferBlobReadingMethods::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferClassFields(_) => UnicornPreferClassFields::WORKS_ON_D_TS,
+            Self::UnicornPreferClasslistToggle(_) => UnicornPreferClasslistToggle::WORKS_ON_D_TS,
+            Self::UnicornPreferCodePoint(_) => UnicornPreferCodePoint::WORKS_ON_D_TS,
+            Self::UnicornPreferDateNow(_) => UnicornPreferDateNow::WORKS_ON_D_TS,
+            Self::UnicornPreferDefaultParameters(_) => {
+                UnicornPreferDefaultParameters::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferDomNodeAppend(_) => UnicornPreferDomNodeAppend::WORKS_ON_D_TS,
+            Self::UnicornPreferDomNodeDataset(_) => UnicornPreferDomNodeDataset::WORKS_ON_D_TS,
+            Self::UnicornPreferDomNodeRemove(_) => UnicornPreferDomNodeRemove::WORKS_ON_D_TS,
+            Self::UnicornPreferDomNodeTextContent(_) => {
+                UnicornPreferDomNodeTextContent::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferEventTarget(_) => UnicornPreferEventTarget::WORKS_ON_D_TS,
+            Self::UnicornPreferExportFrom(_) => UnicornPreferExportFrom::WORKS_ON_D_TS,
+            Self::UnicornPreferGlobalThis(_) => UnicornPreferGlobalThis::WORKS_ON_D_TS,
+            Self::UnicornPreferImportMetaProperties(_) => {
+                UnicornPreferImportMetaProperties::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferIncludes(_) => UnicornPreferIncludes::WORKS_ON_D_TS,
+            Self::UnicornPreferKeyboardEventKey(_) => UnicornPreferKeyboardEventKey::WORKS_ON_D_TS,
+            Self::UnicornPreferLogicalOperatorOverTernary(_) => {
+                UnicornPreferLogicalOperatorOverTernary::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferMathMinMax(_) => UnicornPreferMathMinMax::WORKS_ON_D_TS,
+            Self::UnicornPreferMathTrunc(_) => UnicornPreferMathTrunc::WORKS_ON_D_TS,
+            Self::UnicornPreferModernDomApis(_) => UnicornPreferModernDomApis::WORKS_ON_D_TS,
+            Self::UnicornPreferModernMathApis(_) => UnicornPreferModernMathApis::WORKS_ON_D_TS,
+            Self::UnicornPreferModule(_) => UnicornPreferModule::WORKS_ON_D_TS,
+            Self::UnicornPreferNativeCoercionFunctions(_) => {
+                UnicornPreferNativeCoercionFunctions::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferNegativeIndex(_) => UnicornPreferNegativeIndex::WORKS_ON_D_TS,
+            Self::UnicornPreferNodeProtocol(_) => UnicornPreferNodeProtocol::WORKS_ON_D_TS,
+            Self::UnicornPreferNumberCoercion(_) => UnicornPreferNumberCoercion::WORKS_ON_D_TS,
+            Self::UnicornPreferNumberProperties(_) => UnicornPreferNumberProperties::WORKS_ON_D_TS,
+            Self::UnicornPreferObjectFromEntries(_) => {
+                UnicornPreferObjectFromEntries::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferOptionalCatchBinding(_) => {
+                UnicornPreferOptionalCatchBinding::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferPrototypeMethods(_) => UnicornPreferPrototypeMethods::WORKS_ON_D_TS,
+            Self::UnicornPreferQuerySelector(_) => UnicornPreferQuerySelector::WORKS_ON_D_TS,
+            Self::UnicornPreferReflectApply(_) => UnicornPreferReflectApply::WORKS_ON_D_TS,
+            Self::UnicornPreferRegexpTest(_) => UnicornPreferRegexpTest::WORKS_ON_D_TS,
+            Self::UnicornPreferResponseStaticJson(_) => {
+                UnicornPreferResponseStaticJson::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferSetHas(_) => UnicornPreferSetHas::WORKS_ON_D_TS,
+            Self::UnicornPreferSetSize(_) => UnicornPreferSetSize::WORKS_ON_D_TS,
+            Self::UnicornPreferSingleCall(_) => UnicornPreferSingleCall::WORKS_ON_D_TS,
+            Self::UnicornPreferSpread(_) => UnicornPreferSpread::WORKS_ON_D_TS,
+            Self::UnicornPreferStringRaw(_) => UnicornPreferStringRaw::WORKS_ON_D_TS,
+            Self::UnicornPreferStringReplaceAll(_) => UnicornPreferStringReplaceAll::WORKS_ON_D_TS,
+            Self::UnicornPreferStringSlice(_) => UnicornPreferStringSlice::WORKS_ON_D_TS,
+            Self::UnicornPreferStringStartsEndsWith(_) => {
+                UnicornPreferStringStartsEndsWith::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferStringTrimStartEnd(_) => {
+                UnicornPreferStringTrimStartEnd::WORKS_ON_D_TS
+            }
+            Self::UnicornPreferStructuredClone(_) => UnicornPreferStructuredClone::WORKS_ON_D_TS,
+            Self::UnicornPreferTernary(_) => UnicornPreferTernary::WORKS_ON_D_TS,
+            Self::UnicornPreferTopLevelAwait(_) => UnicornPreferTopLevelAwait::WORKS_ON_D_TS,
+            Self::UnicornPreferTypeError(_) => UnicornPreferTypeError::WORKS_ON_D_TS,
+            Self::UnicornRelativeUrlStyle(_) => UnicornRelativeUrlStyle::WORKS_ON_D_TS,
+            Self::UnicornRequireArrayJoinSeparator(_) => {
+                UnicornRequireArrayJoinSeparator::WORKS_ON_D_TS
+            }
+            Self::UnicornRequireModuleAttributes(_) => {
+                UnicornRequireModuleAttributes::WORKS_ON_D_TS
+            }
+            Self::UnicornRequireModuleSpecifiers(_) => {
+                UnicornRequireModuleSpecifiers::WORKS_ON_D_TS
+            }
+            Self::UnicornRequireNumberToFixedDigitsArgument(_) => {
+                UnicornRequireNumberToFixedDigitsArgument::WORKS_ON_D_TS
+            }
+            Self::UnicornRequirePostMessageTargetOrigin(_) => {
+                UnicornRequirePostMessageTargetOrigin::WORKS_ON_D_TS
+            }
+            Self::UnicornSwitchCaseBraces(_) => UnicornSwitchCaseBraces::WORKS_ON_D_TS,
+            Self::UnicornSwitchCaseBreakPosition(_) => {
+                UnicornSwitchCaseBreakPosition::WORKS_ON_D_TS
+            }
+            Self::UnicornTextEncodingIdentifierCase(_) => {
+                UnicornTextEncodingIdentifierCase::WORKS_ON_D_TS
+            }
+            Self::UnicornThrowNewError(_) => UnicornThrowNewError::WORKS_ON_D_TS,
+            Self::JsxA11YAltText(_) => JsxA11YAltText::WORKS_ON_D_TS,
+            Self::JsxA11YAnchorAmbiguousText(_) => JsxA11YAnchorAmbiguousText::WORKS_ON_D_TS,
+            Self::JsxA11YAnchorHasContent(_) => JsxA11YAnchorHasContent::WORKS_ON_D_TS,
+            Self::JsxA11YAnchorIsValid(_) => JsxA11YAnchorIsValid::WORKS_ON_D_TS,
+            Self::JsxA11YAriaActivedescendantHasTabindex(_) => {
+                JsxA11YAriaActivedescendantHasTabindex::WORKS_ON_D_TS
+            }
+            Self::JsxA11YAriaProps(_) => JsxA11YAriaProps::WORKS_ON_D_TS,
+            Self::JsxA11YAriaProptypes(_) => JsxA11YAriaProptypes::WORKS_ON_D_TS,
+            Self::JsxA11YAriaRole(_) => JsxA11YAriaRole::WORKS_ON_D_TS,
+            Self::JsxA11YAriaUnsupportedElements(_) => {
+                JsxA11YAriaUnsupportedElements::WORKS_ON_D_TS
+            }
+            Self::JsxA11YAutocompleteValid(_) => JsxA11YAutocompleteValid::WORKS_ON_D_TS,
+            Self::JsxA11YClickEventsHaveKeyEvents(_) => {
+                JsxA11YClickEventsHaveKeyEvents::WORKS_ON_D_TS
+            }
+            Self::JsxA11YControlHasAssociatedLabel(_) => {
+                JsxA11YControlHasAssociatedLabel::WORKS_ON_D_TS
+            }
+            Self::JsxA11YHeadingHasContent(_) => JsxA11YHeadingHasContent::WORKS_ON_D_TS,
+            Self::JsxA11YHtmlHasLang(_) => JsxA11YHtmlHasLang::WORKS_ON_D_TS,
+            Self::JsxA11YIframeHasTitle(_) => JsxA11YIframeHasTitle::WORKS_ON_D_TS,
+            Self::JsxA11YImgRedundantAlt(_) => JsxA11YImgRedundantAlt::WORKS_ON_D_TS,
+            Self::JsxA11YInteractiveSupportsFocus(_) => {
+                JsxA11YInteractiveSupportsFocus::WORKS_ON_D_TS
+            }
+            Self::JsxA11YLabelHasAssociatedControl(_) => {
+                JsxA11YLabelHasAssociatedControl::WORKS_ON_D_TS
+            }
+            Self::JsxA11YLang(_) => JsxA11YLang::WORKS_ON_D_TS,
+            Self::JsxA11YMediaHasCaption(_) => JsxA11YMediaHasCaption::WORKS_ON_D_TS,
+            Self::JsxA11YMouseEventsHaveKeyEvents(_) => {
+                JsxA11YMouseEventsHaveKeyEvents::WORKS_ON_D_TS
+            }
+            Self::JsxA11YNoAccessKey(_) => JsxA11YNoAccessKey::WORKS_ON_D_TS,
+            Self::JsxA11YNoAriaHiddenOnFocusable(_) => {
+                JsxA11YNoAriaHiddenOnFocusable::WORKS_ON_D_TS
+            }
+            Self::JsxA11YNoAutofocus(_) => JsxA11YNoAutofocus::WORKS_ON_D_TS,
+            Self::JsxA11YNoDistractingElements(_) => JsxA11YNoDistractingElements::WORKS_ON_D_TS,
+            Self::JsxA11YNoInteractiveElementToNoninteractiveRole(_) => {
+                JsxA11YNoInteractiveElementToNoninteractiveRole::WORKS_ON_D_TS
+            }
+            Self::JsxA11YNoNoninteractiveElementInteractions(_) => {
+                JsxA11YNoNoninteractiveElementInteractions::WORKS_ON_D_TS
+            }
+            Self::JsxA11YNoNoninteractiveElementToInteractiveRole(_) => {
+                JsxA11YNoNoninteractiveElementToInteractiveRole::WORKS_ON_D_TS
+            }
+            Self::JsxA11YNoNoninteractiveTabindex(_) => {
+                JsxA11YNoNoninteractiveTabindex::WORKS_ON_D_TS
+            }
+            Self::JsxA11YNoRedundantRoles(_) => JsxA11YNoRedundantRoles::WORKS_ON_D_TS,
+            Self::JsxA11YNoStaticElementInteractions(_) => {
+                JsxA11YNoStaticElementInteractions::WORKS_ON_D_TS
+            }
+            Self::JsxA11YPreferTagOverRole(_) => JsxA11YPreferTagOverRole::WORKS_ON_D_TS,
+            Self::JsxA11YRoleHasRequiredAriaProps(_) => {
+                JsxA11YRoleHasRequiredAriaProps::WORKS_ON_D_TS
+            }
+            Self::JsxA11YRoleSupportsAriaProps(_) => JsxA11YRoleSupportsAriaProps::WORKS_ON_D_TS,
+            Self::JsxA11YScope(_) => JsxA11YScope::WORKS_ON_D_TS,
+            Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::WORKS_ON_D_TS,
+            Self::OxcApproxConstant(_) => OxcApproxConstant::WORKS_ON_D_TS,
+            Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::WORKS_ON_D_TS,
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::WORKS_ON_D_TS,
+            Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::WORKS_ON_D_TS,
+            Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::WORKS_ON_D_TS,
+            Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::WORKS_ON_D_TS,
+            Self::OxcBadMinMaxFunc(_) => OxcBadMinMaxFunc::WORKS_ON_D_TS,
+            Self::OxcBadObjectLiteralComparison(_) => OxcBadObjectLiteralComparison::WORKS_ON_D_TS,
+            Self::OxcBadReplaceAllArg(_) => OxcBadReplaceAllArg::WORKS_ON_D_TS,
+            Self::OxcBranchesSharingCode(_) => OxcBranchesSharingCode::WORKS_ON_D_TS,
+            Self::OxcConstComparisons(_) => OxcConstComparisons::WORKS_ON_D_TS,
+            Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::WORKS_ON_D_TS,
+            Self::OxcErasingOp(_) => OxcErasingOp::WORKS_ON_D_TS,
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::WORKS_ON_D_TS,
+            Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::WORKS_ON_D_TS,
+            Self::OxcMissingThrow(_) => OxcMissingThrow::WORKS_ON_D_TS,
+            Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::WORKS_ON_D_TS,
+            Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::WORKS_ON_D_TS,
+            Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::WORKS_ON_D_TS,
+            Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::WORKS_ON_D_TS,
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::WORKS_ON_D_TS,
+            Self::OxcNoConstEnum(_) => OxcNoConstEnum::WORKS_ON_D_TS,
+            Self::OxcNoDeprecatedDirectiveRules(_) => OxcNoDeprecatedDirectiveRules::WORKS_ON_D_TS,
+            Self::OxcNoDisabledCorrectnessRules(_) => OxcNoDisabledCorrectnessRules::WORKS_ON_D_TS,
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::WORKS_ON_D_TS,
+            Self::OxcNoDuplicateTypeConstituents(_) => {
+                OxcNoDuplicateTypeConstituents::WORKS_ON_D_TS
+            }
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::WORKS_ON_D_TS,
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::WORKS_ON_D_TS,
+            Self::OxcNoIrrelevantDirectiveRules(_) => OxcNoIrrelevantDirectiveRules::WORKS_ON_D_TS,
+            Self::OxcNoMapSpread(_) => OxcNoMapSpread::WORKS_ON_D_TS,
+            Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::WORKS_ON_D_TS,
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::WORKS_ON_D_TS,
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::WORKS_ON_D_TS,
+            Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::WORKS_ON_D_TS,
+            Self::OxcNoStackedNextLineDirectives(_) => {
+                OxcNoStackedNextLineDirectives::WORKS_ON_D_TS
+            }
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::WORKS_ON_D_TS,
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::WORKS_ON_D_TS,
+            Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::WORKS_ON_D_TS,
+            Self::OxcNoUnsupportedDirectiveRules(_) => {
+                OxcNoUnsupportedDirectiveRules::WORKS_ON_D_TS
+            }
+            Self::OxcNoUnterminatedDisableComments(_) => {
+                OxcNoUnterminatedDisableComments::WORKS_ON_D_TS
+            }
+            Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::WORKS_ON_D_TS,
+            Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::WORKS_ON_D_TS,
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::WORKS_ON_D_TS,
+            Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::WORKS_ON_D_TS,
+            Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::WORKS_ON_D_TS,
+            Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::WORKS_ON_D_TS,
+            Self::NextjsInlineScriptId(_) => NextjsInlineScriptId::WORKS_ON_D_TS,
+            Self::NextjsNextScriptForGa(_) => NextjsNextScriptForGa::WORKS_ON_D_TS,
+            Self::NextjsNoAssignModuleVariable(_) => NextjsNoAssignModuleVariable::WORKS_ON_D_TS,
+            Self::NextjsNoAsyncClientComponent(_) => NextjsNoAsyncClientComponent::WORKS_ON_D_TS,
+            Self::NextjsNoBeforeInteractiveScriptOutsideDocument(_) => {
+                NextjsNoBeforeInteractiveScriptOutsideDocument::WORKS_ON_D_TS
+            }
+            Self::NextjsNoCssTags(_) => NextjsNoCssTags::WORKS_ON_D_TS,
+            Self::NextjsNoDocumentImportInPage(_) => NextjsNoDocumentImportInPage::WORKS_ON_D_TS,
+            Self::NextjsNoDuplicateHead(_) => NextjsNoDuplicateHead::WORKS_ON_D_TS,
+            Self::NextjsNoHeadElement(_) => NextjsNoHeadElement::WORKS_ON_D_TS,
+            Self::NextjsNoHeadImportInDocument(_) => NextjsNoHeadImportInDocument::WORKS_ON_D_TS,
+            Self::NextjsNoHtmlLinkForPages(_) => NextjsNoHtmlLinkForPages::WORKS_ON_D_TS,
+            Self::NextjsNoImgElement(_) => NextjsNoImgElement::WORKS_ON_D_TS,
+            Self::NextjsNoPageCustomFont(_) => NextjsNoPageCustomFont::WORKS_ON_D_TS,
+            Self::NextjsNoScriptComponentInHead(_) => NextjsNoScriptComponentInHead::WORKS_ON_D_TS,
+            Self::NextjsNoStyledJsxInDocument(_) => NextjsNoStyledJsxInDocument::WORKS_ON_D_TS,
+            Self::NextjsNoSyncScripts(_) => NextjsNoSyncScripts::WORKS_ON_D_TS,
+            Self::NextjsNoTitleInDocumentHead(_) => NextjsNoTitleInDocumentHead::WORKS_ON_D_TS,
+            Self::NextjsNoTypos(_) => NextjsNoTypos::WORKS_ON_D_TS,
+            Self::NextjsNoUnwantedPolyfillio(_) => NextjsNoUnwantedPolyfillio::WORKS_ON_D_TS,
+            Self::JsdocCheckAccess(_) => JsdocCheckAccess::WORKS_ON_D_TS,
+            Self::JsdocCheckPropertyNames(_) => JsdocCheckPropertyNames::WORKS_ON_D_TS,
+            Self::JsdocCheckTagNames(_) => JsdocCheckTagNames::WORKS_ON_D_TS,
+            Self::JsdocEmptyTags(_) => JsdocEmptyTags::WORKS_ON_D_TS,
+            Self::JsdocImplementsOnClasses(_) => JsdocImplementsOnClasses::WORKS_ON_D_TS,
+            Self::JsdocNoDefaults(_) => JsdocNoDefaults::WORKS_ON_D_TS,
+            Self::JsdocRequireParam(_) => JsdocRequireParam::WORKS_ON_D_TS,
+            Self::JsdocRequireParamDescription(_) => JsdocRequireParamDescription::WORKS_ON_D_TS,
+            Self::JsdocRequireParamName(_) => JsdocRequireParamName::WORKS_ON_D_TS,
+            Self::JsdocRequireParamType(_) => JsdocRequireParamType::WORKS_ON_D_TS,
+            Self::JsdocRequireProperty(_) => JsdocRequireProperty::WORKS_ON_D_TS,
+            Self::JsdocRequirePropertyDescription(_) => {
+                JsdocRequirePropertyDescription::WORKS_ON_D_TS
+            }
+            Self::JsdocRequirePropertyName(_) => JsdocRequirePropertyName::WORKS_ON_D_TS,
+            Self::JsdocRequirePropertyType(_) => JsdocRequirePropertyType::WORKS_ON_D_TS,
+            Self::JsdocRequireReturns(_) => JsdocRequireReturns::WORKS_ON_D_TS,
+            Self::JsdocRequireReturnsDescription(_) => {
+                JsdocRequireReturnsDescription::WORKS_ON_D_TS
+            }
+            Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::WORKS_ON_D_TS,
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::WORKS_ON_D_TS,
+            Self::JsdocRequireThrowsDescription(_) => JsdocRequireThrowsDescription::WORKS_ON_D_TS,
+            Self::JsdocRequireThrowsType(_) => JsdocRequireThrowsType::WORKS_ON_D_TS,
+            Self::JsdocRequireYields(_) => JsdocRequireYields::WORKS_ON_D_TS,
+            Self::JsdocRequireYieldsDescription(_) => JsdocRequireYieldsDescription::WORKS_ON_D_TS,
+            Self::JsdocRequireYieldsType(_) => JsdocRequireYieldsType::WORKS_ON_D_TS,
+            Self::PromiseAlwaysReturn(_) => PromiseAlwaysReturn::WORKS_ON_D_TS,
+            Self::PromiseAvoidNew(_) => PromiseAvoidNew::WORKS_ON_D_TS,
+            Self::PromiseCatchOrReturn(_) => PromiseCatchOrReturn::WORKS_ON_D_TS,
+            Self::PromiseNoCallbackInPromise(_) => PromiseNoCallbackInPromise::WORKS_ON_D_TS,
+            Self::PromiseNoMultipleResolved(_) => PromiseNoMultipleResolved::WORKS_ON_D_TS,
+            Self::PromiseNoNesting(_) => PromiseNoNesting::WORKS_ON_D_TS,
+            Self::PromiseNoNewStatics(_) => PromiseNoNewStatics::WORKS_ON_D_TS,
+            Self::PromiseNoPromiseInCallback(_) => PromiseNoPromiseInCallback::WORKS_ON_D_TS,
+            Self::PromiseNoReturnInFinally(_) => PromiseNoReturnInFinally::WORKS_ON_D_TS,
+            Self::PromiseNoReturnWrap(_) => PromiseNoReturnWrap::WORKS_ON_D_TS,
+            Self::PromiseParamNames(_) => PromiseParamNames::WORKS_ON_D_TS,
+            Self::PromisePreferAwaitToCallbacks(_) => PromisePreferAwaitToCallbacks::WORKS_ON_D_TS,
+            Self::PromisePreferAwaitToThen(_) => PromisePreferAwaitToThen::WORKS_ON_D_TS,
+            Self::PromisePreferCatch(_) => PromisePreferCatch::WORKS_ON_D_TS,
+            Self::PromiseSpecOnly(_) => PromiseSpecOnly::WORKS_ON_D_TS,
+            Self::PromiseValidParams(_) => PromiseValidParams::WORKS_ON_D_TS,
+            Self::VitestConsistentEachFor(_) => VitestConsistentEachFor::WORKS_ON_D_TS,
+            Self::VitestConsistentTestFilename(_) => VitestConsistentTestFilename::WORKS_ON_D_TS,
+            Self::VitestConsistentTestIt(_) => VitestConsistentTestIt::WORKS_ON_D_TS,
+            Self::VitestConsistentVitestVi(_) => VitestConsistentVitestVi::WORKS_ON_D_TS,
+            Self::VitestExpectExpect(_) => VitestExpectExpect::WORKS_ON_D_TS,
+            Self::VitestHoistedApisOnTop(_) => VitestHoistedApisOnTop::WORKS_ON_D_TS,
+            Self::VitestMaxExpects(_) => VitestMaxExpects::WORKS_ON_D_TS,
+            Self::VitestMaxNestedDescribe(_) => VitestMaxNestedDescribe::WORKS_ON_D_TS,
+            Self::VitestNoAliasMethods(_) => VitestNoAliasMethods::WORKS_ON_D_TS,
+            Self::VitestNoCommentedOutTests(_) => VitestNoCommentedOutTests::WORKS_ON_D_TS,
+            Self::VitestNoConditionalExpect(_) => VitestNoConditionalExpect::WORKS_ON_D_TS,
+            Self::VitestNoConditionalInTest(_) => VitestNoConditionalInTest::WORKS_ON_D_TS,
+            Self::VitestNoConditionalTests(_) => VitestNoConditionalTests::WORKS_ON_D_TS,
+            Self::VitestNoDisabledTests(_) => VitestNoDisabledTests::WORKS_ON_D_TS,
+            Self::VitestNoDuplicateHooks(_) => VitestNoDuplicateHooks::WORKS_ON_D_TS,
+            Self::VitestNoFocusedTests(_) => VitestNoFocusedTests::WORKS_ON_D_TS,
+            Self::VitestNoHooks(_) => VitestNoHooks::WORKS_ON_D_TS,
+            Self::VitestNoIdenticalTitle(_) => VitestNoIdenticalTitle::WORKS_ON_D_TS,
+            Self::VitestNoImportNodeTest(_) => VitestNoImportNodeTest::WORKS_ON_D_TS,
+            Self::VitestNoImportingVitestGlobals(_) => {
+                VitestNoImportingVitestGlobals::WORKS_ON_D_TS
+            }
+            Self::VitestNoInterpolationInSnapshots(_) => {
+                VitestNoInterpolationInSnapshots::WORKS_ON_D_TS
+            }
+            Self::VitestNoLargeSnapshots(_) => VitestNoLargeSnapshots::WORKS_ON_D_TS,
+            Self::VitestNoMocksImport(_) => VitestNoMocksImport::WORKS_ON_D_TS,
+            Self::VitestNoRestrictedMatchers(_) => VitestNoRestrictedMatchers::WORKS_ON_D_TS,
+            Self::VitestNoRestrictedViMethods(_) => VitestNoRestrictedViMethods::WORKS_ON_D_TS,
+            Self::VitestNoStandaloneExpect(_) => VitestNoStandaloneExpect::WORKS_ON_D_TS,
+            Self::VitestNoTestPrefixes(_) => VitestNoTestPrefixes::WORKS_ON_D_TS,
+            Self::VitestNoTestReturnStatement(_) => VitestNoTestReturnStatement::WORKS_ON_D_TS,
+            Self::VitestNoUnneededAsyncExpectFunction(_) => {
+                VitestNoUnneededAsyncExpectFunction::WORKS_ON_D_TS
+            }
+            Self::VitestPaddingAroundAfterAllBlocks(_) => {
+                VitestPaddingAroundAfterAllBlocks::WORKS_ON_D_TS
+            }
+            Self::VitestPaddingAroundTestBlocks(_) => VitestPaddingAroundTestBlocks::WORKS_ON_D_TS,
+            Self::VitestPreferCalledExactlyOnceWith(_) => {
+                VitestPreferCalledExactlyOnceWith::WORKS_ON_D_TS
+            }
+            Self::VitestPreferCalledOnce(_) => VitestPreferCalledOnce::WORKS_ON_D_TS,
+            Self::VitestPreferCalledTimes(_) => VitestPreferCalledTimes::WORKS_ON_D_TS,
+            Self::VitestPreferCalledWith(_) => VitestPreferCalledWith::WORKS_ON_D_TS,
+            Self::VitestPreferComparisonMatcher(_) => VitestPreferComparisonMatcher::WORKS_ON_D_TS,
+            Self::VitestPreferDescribeFunctionTitle(_) => {
+                VitestPreferDescribeFunctionTitle::WORKS_ON_D_TS
+            }
+            Self::VitestPreferEach(_) => VitestPreferEach::WORKS_ON_D_TS,
+            Self::VitestPreferEqualityMatcher(_) => VitestPreferEqualityMatcher::WORKS_ON_D_TS,
+            Self::VitestPreferExpectAssertions(_) => VitestPreferExpectAssertions::WORKS_ON_D_TS,
+            Self::VitestPreferExpectResolves(_) => VitestPreferExpectResolves::WORKS_ON_D_TS,
+            Self::VitestPreferExpectTypeOf(_) => VitestPreferExpectTypeOf::WORKS_ON_D_TS,
+            Self::VitestPreferHooksInOrder(_) => VitestPreferHooksInOrder::WORKS_ON_D_TS,
+            Self::VitestPreferHooksOnTop(_) => VitestPreferHooksOnTop::WORKS_ON_D_TS,
+            Self::VitestPreferImportInMock(_) => VitestPreferImportInMock::WORKS_ON_D_TS,
+            Self::VitestPreferImportingVitestGlobals(_) => {
+                VitestPreferImportingVitestGlobals::WORKS_ON_D_TS
+            }
+            Self::VitestPreferLowercaseTitle(_) => VitestPreferLowercaseTitle::WORKS_ON_D_TS,
+            Self::VitestPreferMockPromiseShorthand(_) => {
+                VitestPreferMockPromiseShorthand::WORKS_ON_D_TS
+            }
+            Self::VitestPreferMockReturnShorthand(_) => {
+                VitestPreferMockReturnShorthand::WORKS_ON_D_TS
+            }
+            Self::VitestPreferSnapshotHint(_) => VitestPreferSnapshotHint::WORKS_ON_D_TS,
+            Self::VitestPreferSpyOn(_) => VitestPreferSpyOn::WORKS_ON_D_TS,
+            Self::VitestPreferStrictBooleanMatchers(_) => {
+                VitestPreferStrictBooleanMatchers::WORKS_ON_D_TS
+            }
+            Self::VitestPreferStrictEqual(_) => VitestPreferStrictEqual::WORKS_ON_D_TS,
+            Self::VitestPreferToBe(_) => VitestPreferToBe::WORKS_ON_D_TS,
+            Self::VitestPreferToBeFalsy(_) => VitestPreferToBeFalsy::WORKS_ON_D_TS,
+            Self::VitestPreferToBeObject(_) => VitestPreferToBeObject::WORKS_ON_D_TS,
+            Self::VitestPreferToBeTruthy(_) => VitestPreferToBeTruthy::WORKS_ON_D_TS,
+            Self::VitestPreferToContain(_) => VitestPreferToContain::WORKS_ON_D_TS,
+            Self::VitestPreferToHaveBeenCalledTimes(_) => {
+                VitestPreferToHaveBeenCalledTimes::WORKS_ON_D_TS
+            }
+            Self::VitestPreferToHaveLength(_) => VitestPreferToHaveLength::WORKS_ON_D_TS,
+            Self::VitestPreferTodo(_) => VitestPreferTodo::WORKS_ON_D_TS,
+            Self::VitestRequireAwaitedExpectPoll(_) => {
+                VitestRequireAwaitedExpectPoll::WORKS_ON_D_TS
+            }
+            Self::VitestRequireHook(_) => VitestRequireHook::WORKS_ON_D_TS,
+            Self::VitestRequireLocalTestContextForConcurrentSnapshots(_) => {
+                VitestRequireLocalTestContextForConcurrentSnapshots::WORKS_ON_D_TS
+            }
+            Self::VitestRequireMockTypeParameters(_) => {
+                VitestRequireMockTypeParameters::WORKS_ON_D_TS
+            }
+            Self::VitestRequireTestTimeout(_) => VitestRequireTestTimeout::WORKS_ON_D_TS,
+            Self::VitestRequireToThrowMessage(_) => VitestRequireToThrowMessage::WORKS_ON_D_TS,
+            Self::VitestRequireTopLevelDescribe(_) => VitestRequireTopLevelDescribe::WORKS_ON_D_TS,
+            Self::VitestValidDescribeCallback(_) => VitestValidDescribeCallback::WORKS_ON_D_TS,
+            Self::VitestValidExpect(_) => VitestValidExpect::WORKS_ON_D_TS,
+            Self::VitestValidExpectInPromise(_) => VitestValidExpectInPromise::WORKS_ON_D_TS,
+            Self::VitestValidTitle(_) => VitestValidTitle::WORKS_ON_D_TS,
+            Self::VitestWarnTodo(_) => VitestWarnTodo::WORKS_ON_D_TS,
+            Self::NodeCallbackReturn(_) => NodeCallbackReturn::WORKS_ON_D_TS,
+            Self::NodeExportsStyle(_) => NodeExportsStyle::WORKS_ON_D_TS,
+            Self::NodeGlobalRequire(_) => NodeGlobalRequire::WORKS_ON_D_TS,
+            Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::WORKS_ON_D_TS,
+            Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::WORKS_ON_D_TS,
+            Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::WORKS_ON_D_TS,
+            Self::NodeNoNewRequire(_) => NodeNoNewRequire::WORKS_ON_D_TS,
+            Self::NodeNoPathConcat(_) => NodeNoPathConcat::WORKS_ON_D_TS,
+            Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::WORKS_ON_D_TS,
+            Self::NodeNoSync(_) => NodeNoSync::WORKS_ON_D_TS,
+            Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::WORKS_ON_D_TS,
+            Self::VueComponentDefinitionNameCasing(_) => {
+                VueComponentDefinitionNameCasing::WORKS_ON_D_TS
+            }
+            Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::WORKS_ON_D_TS,
+            Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::WORKS_ON_D_TS,
+            Self::VueDefinePropsDestructuring(_) => VueDefinePropsDestructuring::WORKS_ON_D_TS,
+            Self::VueMaxProps(_) => VueMaxProps::WORKS_ON_D_TS,
+            Self::VueNextTickStyle(_) => VueNextTickStyle::WORKS_ON_D_TS,
+            Self::VueNoArrowFunctionsInWatch(_) => VueNoArrowFunctionsInWatch::WORKS_ON_D_TS,
+            Self::VueNoAsyncInComputedProperties(_) => {
+                VueNoAsyncInComputedProperties::WORKS_ON_D_TS
+            }
+            Self::VueNoComputedPropertiesInData(_) => VueNoComputedPropertiesInData::WORKS_ON_D_TS,
+            Self::VueNoDeprecatedDataObjectDeclaration(_) => {
+                VueNoDeprecatedDataObjectDeclaration::WORKS_ON_D_TS
+            }
+            Self::VueNoDeprecatedDeleteSet(_) => VueNoDeprecatedDeleteSet::WORKS_ON_D_TS,
+            Self::VueNoDeprecatedDestroyedLifecycle(_) => {
+                VueNoDeprecatedDestroyedLifecycle::WORKS_ON_D_TS
+            }
+            Self::VueNoDeprecatedEventsApi(_) => VueNoDeprecatedEventsApi::WORKS_ON_D_TS,
+            Self::VueNoDeprecatedModelDefinition(_) => {
+                VueNoDeprecatedModelDefinition::WORKS_ON_D_TS
+            }
+            Self::VueNoDeprecatedPropsDefaultThis(_) => {
+                VueNoDeprecatedPropsDefaultThis::WORKS_ON_D_TS
+            }
+            Self::VueNoDeprecatedVueConfigKeycodes(_) => {
+                VueNoDeprecatedVueConfigKeycodes::WORKS_ON_D_TS
+            }
+            Self::VueNoDupeKeys(_) => VueNoDupeKeys::WORKS_ON_D_TS,
+            Self::VueNoExportInScriptSetup(_) => VueNoExportInScriptSetup::WORKS_ON_D_TS,
+            Self::VueNoExposeAfterAwait(_) => VueNoExposeAfterAwait::WORKS_ON_D_TS,
+            Self::VueNoImportCompilerMacros(_) => VueNoImportCompilerMacros::WORKS_ON_D_TS,
+            Self::VueNoLifecycleAfterAwait(_) => VueNoLifecycleAfterAwait::WORKS_ON_D_TS,
+            Self::VueNoMultipleSlotArgs(_) => VueNoMultipleSlotArgs::WORKS_ON_D_TS,
+            Self::VueNoRequiredPropWithDefault(_) => VueNoRequiredPropWithDefault::WORKS_ON_D_TS,
+            Self::VueNoReservedComponentNames(_) => VueNoReservedComponentNames::WORKS_ON_D_TS,
+            Self::VueNoReservedKeys(_) => VueNoReservedKeys::WORKS_ON_D_TS,
+            Self::VueNoReservedProps(_) => VueNoReservedProps::WORKS_ON_D_TS,
+            Self::VueNoSharedComponentData(_) => VueNoSharedComponentData::WORKS_ON_D_TS,
+            Self::VueNoSideEffectsInComputedProperties(_) => {
+                VueNoSideEffectsInComputedProperties::WORKS_ON_D_TS
+            }
+            Self::VueNoThisInBeforeRouteEnter(_) => VueNoThisInBeforeRouteEnter::WORKS_ON_D_TS,
+            Self::VueNoWatchAfterAwait(_) => VueNoWatchAfterAwait::WORKS_ON_D_TS,
+            Self::VuePreferImportFromVue(_) => VuePreferImportFromVue::WORKS_ON_D_TS,
+            Self::VuePropNameCasing(_) => VuePropNameCasing::WORKS_ON_D_TS,
+            Self::VueRequireDefaultExport(_) => VueRequireDefaultExport::WORKS_ON_D_TS,
+            Self::VueRequireDefaultProp(_) => VueRequireDefaultProp::WORKS_ON_D_TS,
+            Self::VueRequireDirectExport(_) => VueRequireDirectExport::WORKS_ON_D_TS,
+            Self::VueRequirePropTypeConstructor(_) => VueRequirePropTypeConstructor::WORKS_ON_D_TS,
+            Self::VueRequirePropTypes(_) => VueRequirePropTypes::WORKS_ON_D_TS,
+            Self::VueRequireRenderReturn(_) => VueRequireRenderReturn::WORKS_ON_D_TS,
+            Self::VueRequireSlotsAsFunctions(_) => VueRequireSlotsAsFunctions::WORKS_ON_D_TS,
+            Self::VueRequireTypedRef(_) => VueRequireTypedRef::WORKS_ON_D_TS,
+            Self::VueReturnInComputedProperty(_) => VueReturnInComputedProperty::WORKS_ON_D_TS,
+            Self::VueReturnInEmitsValidator(_) => VueReturnInEmitsValidator::WORKS_ON_D_TS,
+            Self::VueValidDefineEmits(_) => VueValidDefineEmits::WORKS_ON_D_TS,
+            Self::VueValidDefineOptions(_) => VueValidDefineOptions::WORKS_ON_D_TS,
+            Self::VueValidDefineProps(_) => VueValidDefineProps::WORKS_ON_D_TS,
+            Self::VueValidNextTick(_) => VueValidNextTick::WORKS_ON_D_TS,
+        }
+    }
+    #[doc = r" The language of the files this rule applies to."]
+    pub fn language(&self) -> RuleLanguage {
+        match self {
+            Self::ImportConsistentTypeSpecifierStyle(_) => {
+                ImportConsistentTypeSpecifierStyle::LANGUAGE
+            }
+            Self::ImportDefault(_) => ImportDefault::LANGUAGE,
+            Self::ImportExport(_) => ImportExport::LANGUAGE,
+            Self::ImportExportsLast(_) => ImportExportsLast::LANGUAGE,
+            Self::ImportExtensions(_) => ImportExtensions::LANGUAGE,
+            Self::ImportFirst(_) => ImportFirst::LANGUAGE,
+            Self::ImportGroupExports(_) => ImportGroupExports::LANGUAGE,
+            Self::ImportMaxDependencies(_) => ImportMaxDependencies::LANGUAGE,
+            Self::ImportNamed(_) => ImportNamed::LANGUAGE,
+            Self::ImportNamespace(_) => ImportNamespace::LANGUAGE,
+            Self::ImportNewlineAfterImport(_) => ImportNewlineAfterImport::LANGUAGE,
+            Self::ImportNoAbsolutePath(_) => ImportNoAbsolutePath::LANGUAGE,
+            Self::ImportNoAmd(_) => ImportNoAmd::LANGUAGE,
+            Self::ImportNoAnonymousDefaultExport(_) => ImportNoAnonymousDefaultExport::LANGUAGE,
+            Self::ImportNoCommonjs(_) => ImportNoCommonjs::LANGUAGE,
+            Self::ImportNoCycle(_) => ImportNoCycle::LANGUAGE,
+            Self::ImportNoDefaultExport(_) => ImportNoDefaultExport::LANGUAGE,
+            Self::ImportNoDuplicates(_) => ImportNoDuplicates::LANGUAGE,
+            Self::ImportNoDynamicRequire(_) => ImportNoDynamicRequire::LANGUAGE,
+            Self::ImportNoEmptyNamedBlocks(_) => ImportNoEmptyNamedBlocks::LANGUAGE,
+            Self::ImportNoMutableExports(_) => ImportNoMutableExports::LANGUAGE,
+            Self::ImportNoNamedAsDefault(_) => ImportNoNamedAsDefault::LANGUAGE,
+            Self::ImportNoNamedAsDefaultMember(_) => ImportNoNamedAsDefaultMember::LANGUAGE,
+            Self::ImportNoNamedDefault(_) => ImportNoNamedDefault::LANGUAGE,
+            Self::ImportNoNamedExport(_) => ImportNoNamedExport::LANGUAGE,
+            Self::ImportNoNamespace(_) => ImportNoNamespace::LANGUAGE,
+            Self::ImportNoNodejsModules(_) => ImportNoNodejsModules::LANGUAGE,
+            Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::LANGUAGE,
+            Self::ImportNoSelfImport(_) => ImportNoSelfImport::LANGUAGE,
+            Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::LANGUAGE,
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::LANGUAGE,
+            Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::LANGUAGE,
+            Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::LANGUAGE,
+            Self::ImportUnambiguous(_) => ImportUnambiguous::LANGUAGE,
+            Self::EslintAccessorPairs(_) => EslintAccessorPairs::LANGUAGE,
+            Self::EslintArrayCallbackReturn(_) => EslintArrayCallbackReturn::LANGUAGE,
+            Self::EslintArrowBodyStyle(_) => EslintArrowBodyStyle::LANGUAGE,
+            Self::EslintBlockScopedVar(_) => EslintBlockScopedVar::LANGUAGE,
+            Self::EslintCapitalizedComments(_) => EslintCapitalizedComments::LANGUAGE,
+            Self::EslintClassMethodsUseThis(_) => EslintClassMethodsUseThis::LANGUAGE,
+            Self::EslintComplexity(_) => EslintComplexity::LANGUAGE,
+            Self::EslintConstructorSuper(_) => EslintConstructorSuper::LANGUAGE,
+            Self::EslintCurly(_) => EslintCurly::LANGUAGE,
+            Self::EslintDefaultCase(_) => EslintDefaultCase::LANGUAGE,
+            Self::EslintDefaultCaseLast(_) => EslintDefaultCaseLast::LANGUAGE,
+            Self::EslintDefaultParamLast(_) => EslintDefaultParamLast::LANGUAGE,
+            Self::EslintEqeqeq(_) => EslintEqeqeq::LANGUAGE,
+            Self::EslintForDirection(_) => EslintForDirection::LANGUAGE,
+            Self::EslintFuncNameMatching(_) => EslintFuncNameMatching::LANGUAGE,
+            Self::EslintFuncNames(_) => EslintFuncNames::LANGUAGE,
+            Self::EslintFuncStyle(_) => EslintFuncStyle::LANGUAGE,
+            Self::EslintGetterReturn(_) => EslintGetterReturn::LANGUAGE,
+            Self::EslintGroupedAccessorPairs(_) => EslintGroupedAccessorPairs::LANGUAGE,
+            Self::EslintGuardForIn(_) => EslintGuardForIn::LANGUAGE,
+            Self::EslintIdDenylist(_) => EslintIdDenylist::LANGUAGE,
+            Self::EslintIdLength(_) => EslintIdLength::LANGUAGE,
+            Self::EslintIdMatch(_) => EslintIdMatch::LANGUAGE,
+            Self::EslintInitDeclarations(_) => EslintInitDeclarations::LANGUAGE,
+            Self::EslintLogicalAssignmentOperators(_) => EslintLogicalAssignmentOperators::LANGUAGE,
+            Self::EslintMaxClassesPerFile(_) => EslintMaxClassesPerFile::LANGUAGE,
+            Self::EslintMaxDepth(_) => EslintMaxDepth::LANGUAGE,
+            Self::EslintMaxLines(_) => EslintMaxLines::LANGUAGE,
+            Self::EslintMaxLinesPerFunction(_) => EslintMaxLinesPerFunction::LANGUAGE,
+            Self::EslintMaxNestedCallbacks(_) => EslintMaxNestedCallbacks::LANGUAGE,
+            Self::EslintMaxParams(_) => EslintMaxParams::LANGUAGE,
+            Self::EslintMaxStatements(_) => EslintMaxStatements::LANGUAGE,
+            Self::EslintNewCap(_) => EslintNewCap::LANGUAGE,
+            Self::EslintNoAlert(_) => EslintNoAlert::LANGUAGE,
+            Self::EslintNoArrayConstructor(_) => EslintNoArrayConstructor::LANGUAGE,
+            Self::EslintNoAsyncPromiseExecutor(_) => EslintNoAsyncPromiseExecutor::LANGUAGE,
+            Self::EslintNoAwaitInLoop(_) => EslintNoAwaitInLoop::LANGUAGE,
+            Self::EslintNoBitwise(_) => EslintNoBitwise::LANGUAGE,
+            Self::EslintNoCaller(_) => EslintNoCaller::LANGUAGE,
+            Self::EslintNoCaseDeclarations(_) => EslintNoCaseDeclarations::LANGUAGE,
+            Self::EslintNoClassAssign(_) => EslintNoClassAssign::LANGUAGE,
+            Self::EslintNoCompareNegZero(_) => EslintNoCompareNegZero::LANGUAGE,
+            Self::EslintNoCondAssign(_) => EslintNoCondAssign::LANGUAGE,
+            Self::EslintNoConsole(_) => EslintNoConsole::LANGUAGE,
+            Self::EslintNoConstAssign(_) => EslintNoConstAssign::LANGUAGE,
+            Self::EslintNoConstantBinaryExpression(_) => EslintNoConstantBinaryExpression::LANGUAGE,
+            Self::EslintNoConstantCondition(_) => EslintNoConstantCondition::LANGUAGE,
+            Self::EslintNoConstructorReturn(_) => EslintNoConstructorReturn::LANGUAGE,
+            Self::EslintNoContinue(_) => EslintNoContinue::LANGUAGE,
+            Self::EslintNoControlRegex(_) => EslintNoControlRegex::LANGUAGE,
+            Self::EslintNoDebugger(_) => EslintNoDebugger::LANGUAGE,
+            Self::EslintNoDeleteVar(_) => EslintNoDeleteVar::LANGUAGE,
+            Self::EslintNoDivRegex(_) => EslintNoDivRegex::LANGUAGE,
+            Self::EslintNoDupeClassMembers(_) => EslintNoDupeClassMembers::LANGUAGE,
+            Self::EslintNoDupeElseIf(_) => EslintNoDupeElseIf::LANGUAGE,
+            Self::EslintNoDupeKeys(_) => EslintNoDupeKeys::LANGUAGE,
+            Self::EslintNoDuplicateCase(_) => EslintNoDuplicateCase::LANGUAGE,
+            Self::EslintNoDuplicateImports(_) => EslintNoDuplicateImports::LANGUAGE,
+            Self::EslintNoElseReturn(_) => EslintNoElseReturn::LANGUAGE,
+            Self::EslintNoEmpty(_) => EslintNoEmpty::LANGUAGE,
+            Self::EslintNoEmptyCharacterClass(_) => EslintNoEmptyCharacterClass::LANGUAGE,
+            Self::EslintNoEmptyFunction(_) => EslintNoEmptyFunction::LANGUAGE,
+            Self::EslintNoEmptyPattern(_) => EslintNoEmptyPattern::LANGUAGE,
+            Self::EslintNoEmptyStaticBlock(_) => EslintNoEmptyStaticBlock::LANGUAGE,
+            Self::EslintNoEqNull(_) => EslintNoEqNull::LANGUAGE,
+            Self::EslintNoEval(_) => EslintNoEval::LANGUAGE,
+            Self::EslintNoExAssign(_) => EslintNoExAssign::LANGUAGE,
+            Self::EslintNoExtendNative(_) => EslintNoExtendNative::LANGUAGE,
+            Self::EslintNoExtraBind(_) => EslintNoExtraBind::LANGUAGE,
+            Self::EslintNoExtraBooleanCast(_) => EslintNoExtraBooleanCast::LANGUAGE,
+            Self::EslintNoExtraLabel(_) => EslintNoExtraLabel::LANGUAGE,
+            Self::EslintNoFallthrough(_) => EslintNoFallthrough::LANGUAGE,
+            Self::EslintNoFuncAssign(_) => EslintNoFuncAssign::LANGUAGE,
+            Self::EslintNoGlobalAssign(_) => EslintNoGlobalAssign::LANGUAGE,
+            Self::EslintNoImplicitCoercion(_) => EslintNoImplicitCoercion::LANGUAGE,
+            Self::EslintNoImplicitGlobals(_) => EslintNoImplicitGlobals::LANGUAGE,
+            Self::EslintNoImpliedEval(_) => EslintNoImpliedEval::LANGUAGE,
+            Self::EslintNoImportAssign(_) => EslintNoImportAssign::LANGUAGE,
+            Self::EslintNoInlineComments(_) => EslintNoInlineComments::LANGUAGE,
+            Self::EslintNoInnerDeclarations(_) => EslintNoInnerDeclarations::LANGUAGE,
+            Self::EslintNoInvalidRegexp(_) => EslintNoInvalidRegexp::LANGUAGE,
+            Self::EslintNoIrregularWhitespace(_) => EslintNoIrregularWhitespace::LANGUAGE,
+            Self::EslintNoIterator(_) => EslintNoIterator::LANGUAGE,
+            Self::EslintNoLabelVar(_) => EslintNoLabelVar::LANGUAGE,
+            Self::EslintNoLabels(_) => EslintNoLabels::LANGUAGE,
+            Self::EslintNoLoneBlocks(_) => EslintNoLoneBlocks::LANGUAGE,
+            Self::EslintNoLonelyIf(_) => EslintNoLonelyIf::LANGUAGE,
+            Self::EslintNoLoopFunc(_) => EslintNoLoopFunc::LANGUAGE,
+            Self::EslintNoLossOfPrecision(_) => EslintNoLossOfPrecision::LANGUAGE,
+            Self::EslintNoMagicNumbers(_) => EslintNoMagicNumbers::LANGUAGE,
+            Self::EslintNoMisleadingCharacterClass(_) => EslintNoMisleadingCharacterClass::LANGUAGE,
+            Self::EslintNoMultiAssign(_) => EslintNoMultiAssign::LANGUAGE,
+            Self::EslintNoMultiStr(_) => EslintNoMultiStr::LANGUAGE,
+            Self::EslintNoNegatedCondition(_) => EslintNoNegatedCondition::LANGUAGE,
+            Self::EslintNoNestedTernary(_) => EslintNoNestedTernary::LANGUAGE,
+            Self::EslintNoNew(_) => EslintNoNew::LANGUAGE,
+            Self::EslintNoNewFunc(_) => EslintNoNewFunc::LANGUAGE,
+            Self::EslintNoNewNativeNonconstructor(_) => EslintNoNewNativeNonconstructor::LANGUAGE,
+            Self::EslintNoNewWrappers(_) => EslintNoNewWrappers::LANGUAGE,
+            Self::EslintNoNonoctalDecimalEscape(_) => EslintNoNonoctalDecimalEscape::LANGUAGE,
+            Self::EslintNoObjCalls(_) => EslintNoObjCalls::LANGUAGE,
+            Self::EslintNoObjectConstructor(_) => EslintNoObjectConstructor::LANGUAGE,
+            Self::EslintNoParamReassign(_) => EslintNoParamReassign::LANGUAGE,
+            Self::EslintNoPlusplus(_) => EslintNoPlusplus::LANGUAGE,
+            Self::EslintNoPromiseExecutorReturn(_) => EslintNoPromiseExecutorReturn::LANGUAGE,
+            Self::EslintNoProto(_) => EslintNoProto::LANGUAGE,
+            Self::EslintNoPrototypeBuiltins(_) => EslintNoPrototypeBuiltins::LANGUAGE,
+            Self::EslintNoRedeclare(_) => EslintNoRedeclare::LANGUAGE,
+            Self::EslintNoRegexSpaces(_) => EslintNoRegexSpaces::LANGUAGE,
+            Self::EslintNoRestrictedExports(_) => EslintNoRestrictedExports::LANGUAGE,
+            Self::EslintNoRestrictedGlobals(_) => EslintNoRestrictedGlobals::LANGUAGE,
+            Self::EslintNoRestrictedImports(_) => EslintNoRestrictedImports::LANGUAGE,
+            Self::EslintNoRestrictedProperties(_) => EslintNoRestrictedProperties::LANGUAGE,
+            Self::EslintNoReturnAssign(_) => EslintNoReturnAssign::LANGUAGE,
+            Self::EslintNoScriptUrl(_) => EslintNoScriptUrl::LANGUAGE,
+            Self::EslintNoSelfAssign(_) => EslintNoSelfAssign::LANGUAGE,
+            Self::EslintNoSelfCompare(_) => EslintNoSelfCompare::LANGUAGE,
+            Self::EslintNoSequences(_) => EslintNoSequences::LANGUAGE,
+            Self::EslintNoSetterReturn(_) => EslintNoSetterReturn::LANGUAGE,
+            Self::EslintNoShadow(_) => EslintNoShadow::LANGUAGE,
+            Self::EslintNoShadowRestrictedNames(_) => EslintNoShadowRestrictedNames::LANGUAGE,
+            Self::EslintNoSparseArrays(_) => EslintNoSparseArrays::LANGUAGE,
+            Self::EslintNoTemplateCurlyInString(_) => EslintNoTemplateCurlyInString::LANGUAGE,
+            Self::EslintNoTernary(_) => EslintNoTernary::LANGUAGE,
+            Self::EslintNoThisBeforeSuper(_) => EslintNoThisBeforeSuper::LANGUAGE,
+            Self::EslintNoThrowLiteral(_) => EslintNoThrowLiteral::LANGUAGE,
+            Self::EslintNoUnassignedVars(_) => EslintNoUnassignedVars::LANGUAGE,
+            Self::EslintNoUndef(_) => EslintNoUndef::LANGUAGE,
+            Self::EslintNoUndefined(_) => EslintNoUndefined::LANGUAGE,
+            Self::EslintNoUnderscoreDangle(_) => EslintNoUnderscoreDangle::LANGUAGE,
+            Self::EslintNoUnexpectedMultiline(_) => EslintNoUnexpectedMultiline::LANGUAGE,
+            Self::EslintNoUnmodifiedLoopCondition(_) => EslintNoUnmodifiedLoopCondition::LANGUAGE,
+            Self::EslintNoUnneededTernary(_) => EslintNoUnneededTernary::LANGUAGE,
+            Self::EslintNoUnreachable(_) => EslintNoUnreachable::LANGUAGE,
+            Self::EslintNoUnreachableLoop(_) => EslintNoUnreachableLoop::LANGUAGE,
+            Self::EslintNoUnsafeFinally(_) => EslintNoUnsafeFinally::LANGUAGE,
+            Self::EslintNoUnsafeNegation(_) => EslintNoUnsafeNegation::LANGUAGE,
+            Self::EslintNoUnsafeOptionalChaining(_) => EslintNoUnsafeOptionalChaining::LANGUAGE,
+            Self::EslintNoUnusedExpressions(_) => EslintNoUnusedExpressions::LANGUAGE,
+            Self::EslintNoUnusedLabels(_) => EslintNoUnusedLabels::LANGUAGE,
+            Self::EslintNoUnusedPrivateClassMembers(_) => {
+                EslintNoUnusedPrivateClassMembers::LANGUAGE
+            }
+            Self::EslintNoUnusedVars(_) => EslintNoUnusedVars::LANGUAGE,
+            Self::EslintNoUseBeforeDefine(_) => EslintNoUseBeforeDefine::LANGUAGE,
+            Self::EslintNoUselessAssignment(_) => EslintNoUselessAssignment::LANGUAGE,
+            Self::EslintNoUselessBackreference(_) => EslintNoUselessBackreference::LANGUAGE,
+            Self::EslintNoUselessCall(_) => EslintNoUselessCall::LANGUAGE,
+            Self::EslintNoUselessCatch(_) => EslintNoUselessCatch::LANGUAGE,
+            Self::EslintNoUselessComputedKey(_) => EslintNoUselessComputedKey::LANGUAGE,
+            Self::EslintNoUselessConcat(_) => EslintNoUselessConcat::LANGUAGE,
+            Self::EslintNoUselessConstructor(_) => EslintNoUselessConstructor::LANGUAGE,
+            Self::EslintNoUselessEscape(_) => EslintNoUselessEscape::LANGUAGE,
+            Self::EslintNoUselessRename(_) => EslintNoUselessRename::LANGUAGE,
+            Self::EslintNoUselessReturn(_) => EslintNoUselessReturn::LANGUAGE,
+            Self::EslintNoVar(_) => EslintNoVar::LANGUAGE,
+            Self::EslintNoVoid(_) => EslintNoVoid::LANGUAGE,
+            Self::EslintNoWarningComments(_) => EslintNoWarningComments::LANGUAGE,
+            Self::EslintNoWith(_) => EslintNoWith::LANGUAGE,
+            Self::EslintObjectShorthand(_) => EslintObjectShorthand::LANGUAGE,
+            Self::EslintOneVar(_) => EslintOneVar::LANGUAGE,
+            Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::LANGUAGE,
+            Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::LANGUAGE,
+            Self::EslintPreferConst(_) => EslintPreferConst::LANGUAGE,
+            Self::EslintPreferDestructuring(_) => EslintPreferDestructuring::LANGUAGE,
+            Self::EslintPreferExponentiationOperator(_) => {
+                EslintPreferExponentiationOperator::LANGUAGE
+            }
+            Self::EslintPreferNamedCaptureGroup(_) => EslintPreferNamedCaptureGroup::LANGUAGE,
+            Self::EslintPreferNumericLiterals(_) => EslintPreferNumericLiterals::LANGUAGE,
+            Self::EslintPreferObjectHasOwn(_) => EslintPreferObjectHasOwn::LANGUAGE,
+            Self::EslintPreferObjectSpread(_) => EslintPreferObjectSpread::LANGUAGE,
+            Self::EslintPreferPromiseRejectErrors(_) => EslintPreferPromiseRejectErrors::LANGUAGE,
+            Self::EslintPreferRegexLiterals(_) => EslintPreferRegexLiterals::LANGUAGE,
+            Self::EslintPreferRestParams(_) => EslintPreferRestParams::LANGUAGE,
+            Self::EslintPreferSpread(_) => EslintPreferSpread::LANGUAGE,
+            Self::EslintPreferTemplate(_) => EslintPreferTemplate::LANGUAGE,
+            Self::EslintPreserveCaughtError(_) => EslintPreserveCaughtError::LANGUAGE,
+            Self::EslintRadix(_) => EslintRadix::LANGUAGE,
+            Self::EslintRequireAwait(_) => EslintRequireAwait::LANGUAGE,
+            Self::EslintRequireUnicodeRegexp(_) => EslintRequireUnicodeRegexp::LANGUAGE,
+            Self::EslintRequireYield(_) => EslintRequireYield::LANGUAGE,
+            Self::EslintSortImports(_) => EslintSortImports::LANGUAGE,
+            Self::EslintSortKeys(_) => EslintSortKeys::LANGUAGE,
+            Self::EslintSortVars(_) => EslintSortVars::LANGUAGE,
+            Self::EslintSymbolDescription(_) => EslintSymbolDescription::LANGUAGE,
+            Self::EslintUnicodeBom(_) => EslintUnicodeBom::LANGUAGE,
+            Self::EslintUseIsnan(_) => EslintUseIsnan::LANGUAGE,
+            Self::EslintValidTypeof(_) => EslintValidTypeof::LANGUAGE,
+            Self::EslintVarsOnTop(_) => EslintVarsOnTop::LANGUAGE,
+            Self::EslintYoda(_) => EslintYoda::LANGUAGE,
+            Self::TypescriptAdjacentOverloadSignatures(_) => {
+                TypescriptAdjacentOverloadSignatures::LANGUAGE
+            }
+            Self::TypescriptArrayType(_) => TypescriptArrayType::LANGUAGE,
+            Self::TypescriptAwaitThenable(_) => TypescriptAwaitThenable::LANGUAGE,
+            Self::TypescriptBanTsComment(_) => TypescriptBanTsComment::LANGUAGE,
+            Self::TypescriptBanTslintComment(_) => TypescriptBanTslintComment::LANGUAGE,
+            Self::TypescriptBanTypes(_) => TypescriptBanTypes::LANGUAGE,
+            Self::TypescriptClassLiteralPropertyStyle(_) => {
+                TypescriptClassLiteralPropertyStyle::LANGUAGE
+            }
+            Self::TypescriptConsistentGenericConstructors(_) => {
+                TypescriptConsistentGenericConstructors::LANGUAGE
+            }
+            Self::TypescriptConsistentIndexedObjectStyle(_) => {
+                TypescriptConsistentIndexedObjectStyle::LANGUAGE
+            }
+            Self::TypescriptConsistentReturn(_) => TypescriptConsistentReturn::LANGUAGE,
+            Self::TypescriptConsistentTypeAssertions(_) => {
+                TypescriptConsistentTypeAssertions::LANGUAGE
+            }
+            Self::TypescriptConsistentTypeDefinitions(_) => {
+                TypescriptConsistentTypeDefinitions::LANGUAGE
+            }
+            Self::TypescriptConsistentTypeExports(_) => TypescriptConsistentTypeExports::LANGUAGE,
+            Self::TypescriptConsistentTypeImports(_) => TypescriptConsistentTypeImports::LANGUAGE,
+            Self::TypescriptDotNotation(_) => TypescriptDotNotation::LANGUAGE,
+            Self::TypescriptExplicitFunctionReturnType(_) => {
+                TypescriptExplicitFunctionReturnType::LANGUAGE
+            }
+            Self::TypescriptExplicitMemberAccessibility(_) => {
+                TypescriptExplicitMemberAccessibility::LANGUAGE
+            }
+            Self::TypescriptExplicitModuleBoundaryTypes(_) => {
+                TypescriptExplicitModuleBoundaryTypes::LANGUAGE
+            }
+            Self::TypescriptMethodSignatureStyle(_) => TypescriptMethodSignatureStyle::LANGUAGE,
+            Self::TypescriptNoArrayDelete(_) => TypescriptNoArrayDelete::LANGUAGE,
+            Self::TypescriptNoBaseToString(_) => TypescriptNoBaseToString::LANGUAGE,
+            Self::TypescriptNoConfusingNonNullAssertion(_) => {
+                TypescriptNoConfusingNonNullAssertion::LANGUAGE
+            }
+            Self::TypescriptNoConfusingVoidExpression(_) => {
+                TypescriptNoConfusingVoidExpression::LANGUAGE
+            }
+            Self::TypescriptNoDeprecated(_) => TypescriptNoDeprecated::LANGUAGE,
+            Self::TypescriptNoDuplicateEnumValues(_) => TypescriptNoDuplicateEnumValues::LANGUAGE,
+            Self::TypescriptNoDuplicateTypeConstituents(_) => {
+                TypescriptNoDuplicateTypeConstituents::LANGUAGE
+            }
+            Self::TypescriptNoDynamicDelete(_) => TypescriptNoDynamicDelete::LANGUAGE,
+            Self::TypescriptNoEmptyInterface(_) => TypescriptNoEmptyInterface::LANGUAGE,
+            Self::TypescriptNoEmptyObjectType(_) => TypescriptNoEmptyObjectType::LANGUAGE,
+            Self::TypescriptNoExplicitAny(_) => TypescriptNoExplicitAny::LANGUAGE,
+            Self::TypescriptNoExtraNonNullAssertion(_) => {
+                TypescriptNoExtraNonNullAssertion::LANGUAGE
+            }
+            Self::TypescriptNoExtraneousClass(_) => TypescriptNoExtraneousClass::LANGUAGE,
+            Self::TypescriptNoFloatingPromises(_) => TypescriptNoFloatingPromises::LANGUAGE,
+            Self::TypescriptNoForInArray(_) => TypescriptNoForInArray::LANGUAGE,
+            Self::TypescriptNoImpliedEval(_) => TypescriptNoImpliedEval::LANGUAGE,
+            Self::TypescriptNoImportTypeSideEffects(_) => {
+                TypescriptNoImportTypeSideEffects::LANGUAGE
+            }
+            Self::TypescriptNoInferrableTypes(_) => TypescriptNoInferrableTypes::LANGUAGE,
+            Self::TypescriptNoInvalidVoidType(_) => TypescriptNoInvalidVoidType::LANGUAGE,
+            Self::TypescriptNoMeaninglessVoidOperator(_) => {
+                TypescriptNoMeaninglessVoidOperator::LANGUAGE
+            }
+            Self::TypescriptNoMisusedNew(_) => TypescriptNoMisusedNew::LANGUAGE,
+            Self::TypescriptNoMisusedPromises(_) => TypescriptNoMisusedPromises::LANGUAGE,
+            Self::TypescriptNoMisusedSpread(_) => TypescriptNoMisusedSpread::LANGUAGE,
+            Self::TypescriptNoMixedEnums(_) => TypescriptNoMixedEnums::LANGUAGE,
+            Self::TypescriptNoNamespace(_) => TypescriptNoNamespace::LANGUAGE,
+            Self::TypescriptNoNonNullAssertedNullishCoalescing(_) => {
+                TypescriptNoNonNullAssertedNullishCoalescing::LANGUAGE
+            }
+            Self::TypescriptNoNonNullAssertedOptionalChain(_) => {
+                TypescriptNoNonNullAssertedOptionalChain::LANGUAGE
+            }
+            Self::TypescriptNoNonNullAssertion(_) => TypescriptNoNonNullAssertion::LANGUAGE,
+            Self::TypescriptNoRedundantTypeConstituents(_) => {
+                TypescriptNoRedundantTypeConstituents::LANGUAGE
+            }
+            Self::TypescriptNoRequireImports(_) => TypescriptNoRequireImports::LANGUAGE,
+            Self::TypescriptNoRestrictedTypes(_) => TypescriptNoRestrictedTypes::LANGUAGE,
+            Self::TypescriptNoThisAlias(_) => TypescriptNoThisAlias::LANGUAGE,
+            Self::TypescriptNoUnnecessaryBooleanLiteralCompare(_) => {
+                TypescriptNoUnnecessaryBooleanLiteralCompare::LANGUAGE
+            }
+            Self::TypescriptNoUnnecessaryCondition(_) => TypescriptNoUnnecessaryCondition::LANGUAGE,
+            Self::TypescriptNoUnnecessaryParameterPropertyAssignment(_) => {
+                TypescriptNoUnnecessaryParameterPropertyAssignment::LANGUAGE
+            }
+            Self::TypescriptNoUnnecessaryQualifier(_) => TypescriptNoUnnecessaryQualifier::LANGUAGE,
+            Self::TypescriptNoUnnecessaryTemplateExpression(_) => {
+                TypescriptNoUnnecessaryTemplateExpression::LANGUAGE
+            }
+            Self::TypescriptNoUnnecessaryTypeArguments(_) => {
+                TypescriptNoUnnecessaryTypeArguments::LANGUAGE
+            }
+            Self::TypescriptNoUnnecessaryTypeAssertion(_) => {
+                TypescriptNoUnnecessaryTypeAssertion::LANGUAGE
+            }
+            Self::TypescriptNoUnnecessaryTypeConstraint(_) => {
+                TypescriptNoUnnecessaryTypeConstraint::LANGUAGE
+            }
+            Self::TypescriptNoUnnecessaryTypeConversion(_) => {
+                TypescriptNoUnnecessaryTypeConversion::LANGUAGE
+            }
+            Self::TypescriptNoUnnecessaryTypeParameters(_) => {
+                TypescriptNoUnnecessaryTypeParameters::LANGUAGE
+            }
+            Self::TypescriptNoUnsafeArgument(_) => TypescriptNoUnsafeArgument::LANGUAGE,
+            Self::TypescriptNoUnsafeAssignment(_) => TypescriptNoUnsafeAssignment::LANGUAGE,
+            Self::TypescriptNoUnsafeCall(_) => TypescriptNoUnsafeCall::LANGUAGE,
+            Self::TypescriptNoUnsafeDeclarationMerging(_) => {
+                TypescriptNoUnsafeDeclarationMerging::LANGUAGE
+            }
+            Self::TypescriptNoUnsafeEnumComparison(_) => TypescriptNoUnsafeEnumComparison::LANGUAGE,
+            Self::TypescriptNoUnsafeFunctionType(_) => TypescriptNoUnsafeFunctionType::LANGUAGE,
+            Self::TypescriptNoUnsafeMemberAccess(_) => TypescriptNoUnsafeMemberAccess::LANGUAGE,
+            Self::TypescriptNoUnsafeReturn(_) => TypescriptNoUnsafeReturn::LANGUAGE,
+            Self::TypescriptNoUnsafeTypeAssertion(_) => TypescriptNoUnsafeTypeAssertion::LANGUAGE,
+            Self::TypescriptNoUnsafeUnaryMinus(_) => TypescriptNoUnsafeUnaryMinus::LANGUAGE,
+            Self::TypescriptNoUselessDefaultAssignment(_) => {
+                TypescriptNoUselessDefaultAssignment::LANGUAGE
+            }
+            Self::TypescriptNoUselessEmptyExport(_) => TypescriptNoUselessEmptyExport::LANGUAGE,
+            Self::TypescriptNoVarRequires(_) => TypescriptNoVarRequires::LANGUAGE,
+            Self::TypescriptNoWrapperObjectTypes(_) => TypescriptNoWrapperObjectTypes::LANGUAGE,
+            Self::TypescriptNonNullableTypeAssertionStyle(_) => {
+                TypescriptNonNullableTypeAssertionStyle::LANGUAGE
+            }
+            Self::TypescriptOnlyThrowError(_) => TypescriptOnlyThrowError::LANGUAGE,
+            Self::TypescriptParameterProperties(_) => TypescriptParameterProperties::LANGUAGE,
+            Self::TypescriptPreferAsConst(_) => TypescriptPreferAsConst::LANGUAGE,
+            Self::TypescriptPreferEnumInitializers(_) => TypescriptPreferEnumInitializers::LANGUAGE,
+            Self::TypescriptPreferFind(_) => TypescriptPreferFind::LANGUAGE,
+            Self::TypescriptPreferForOf(_) => TypescriptPreferForOf::LANGUAGE,
+            Self::TypescriptPreferFunctionType(_) => TypescriptPreferFunctionType::LANGUAGE,
+            Self::TypescriptPreferIncludes(_) => TypescriptPreferIncludes::LANGUAGE,
+            Self::TypescriptPreferLiteralEnumMember(_) => {
+                TypescriptPreferLiteralEnumMember::LANGUAGE
+            }
+            Self::TypescriptPreferNamespaceKeyword(_) => TypescriptPreferNamespaceKeyword::LANGUAGE,
+            Self::TypescriptPreferNullishCoalescing(_) => {
+                TypescriptPreferNullishCoalescing::LANGUAGE
+            }
+            Self::TypescriptPreferOptionalChain(_) => TypescriptPreferOptionalChain::LANGUAGE,
+            Self::TypescriptPreferPromiseRejectErrors(_) => {
+                TypescriptPreferPromiseRejectErrors::LANGUAGE
+            }
+            Self::TypescriptPreferReadonly(_) => TypescriptPreferReadonly::LANGUAGE,
+            Self::TypescriptPreferReadonlyParameterTypes(_) => {
+                TypescriptPreferReadonlyParameterTypes::LANGUAGE
+            }
+            Self::TypescriptPreferReduceTypeParameter(_) => {
+                TypescriptPreferReduceTypeParameter::LANGUAGE
+            }
+            Self::TypescriptPreferRegexpExec(_) => TypescriptPreferRegexpExec::LANGUAGE,
+            Self::TypescriptPreferReturnThisType(_) => TypescriptPreferReturnThisType::LANGUAGE,
+            Self::TypescriptPreferStringStartsEndsWith(_) => {
+                TypescriptPreferStringStartsEndsWith::LANGUAGE
+            }
+            Self::TypescriptPreferTsExpectError(_) => TypescriptPreferTsExpectError::LANGUAGE,
+            Self::TypescriptPromiseFunctionAsync(_) => TypescriptPromiseFunctionAsync::LANGUAGE,
+            Self::TypescriptRelatedGetterSetterPairs(_) => {
+                TypescriptRelatedGetterSetterPairs::LANGUAGE
+            }
+            Self::TypescriptRequireArraySortCompare(_) => {
+                TypescriptRequireArraySortCompare::LANGUAGE
+            }
+            Self::TypescriptRequireAwait(_) => TypescriptRequireAwait::LANGUAGE,
+            Self::TypescriptRestrictPlusOperands(_) => TypescriptRestrictPlusOperands::LANGUAGE,
+            Self::TypescriptRestrictTemplateExpressions(_) => {
+                TypescriptRestrictTemplateExpressions::LANGUAGE
+            }
+            Self::TypescriptReturnAwait(_) => TypescriptReturnAwait::LANGUAGE,
+            Self::TypescriptStrictBooleanExpressions(_) => {
+                TypescriptStrictBooleanExpressions::LANGUAGE
+            }
+            Self::TypescriptStrictVoidReturn(_) => TypescriptStrictVoidReturn::LANGUAGE,
+            Self::TypescriptSwitchExhaustivenessCheck(_) => {
+                TypescriptSwitchExhaustivenessCheck::LANGUAGE
+            }
+            Self::TypescriptTripleSlashReference(_) => TypescriptTripleSlashReference::LANGUAGE,
+            Self::TypescriptUnboundMethod(_) => TypescriptUnboundMethod::LANGUAGE,
+            Self::TypescriptUnifiedSignatures(_) => TypescriptUnifiedSignatures::LANGUAGE,
+            Self::TypescriptUseUnknownInCatchCallbackVariable(_) => {
+                TypescriptUseUnknownInCatchCallbackVariable::LANGUAGE
+            }
+            Self::JestConsistentTestIt(_) => JestConsistentTestIt::LANGUAGE,
+            Self::JestExpectExpect(_) => JestExpectExpect::LANGUAGE,
+            Self::JestMaxExpects(_) => JestMaxExpects::LANGUAGE,
+            Self::JestMaxNestedDescribe(_) => JestMaxNestedDescribe::LANGUAGE,
+            Self::JestNoAliasMethods(_) => JestNoAliasMethods::LANGUAGE,
+            Self::JestNoCommentedOutTests(_) => JestNoCommentedOutTests::LANGUAGE,
+            Self::JestNoConditionalExpect(_) => JestNoConditionalExpect::LANGUAGE,
+            Self::JestNoConditionalInTest(_) => JestNoConditionalInTest::LANGUAGE,
+            Self::JestNoConfusingSetTimeout(_) => JestNoConfusingSetTimeout::LANGUAGE,
+            Self::JestNoDeprecatedFunctions(_) => JestNoDeprecatedFunctions::LANGUAGE,
+            Self::JestNoDisabledTests(_) => JestNoDisabledTests::LANGUAGE,
+            Self::JestNoDoneCallback(_) => JestNoDoneCallback::LANGUAGE,
+            Self::JestNoDuplicateHooks(_) => JestNoDuplicateHooks::LANGUAGE,
+            Self::JestNoExport(_) => JestNoExport::LANGUAGE,
+            Self::JestNoFocusedTests(_) => JestNoFocusedTests::LANGUAGE,
+            Self::JestNoHooks(_) => JestNoHooks::LANGUAGE,
+            Self::JestNoIdenticalTitle(_) => JestNoIdenticalTitle::LANGUAGE,
+            Self::JestNoInterpolationInSnapshots(_) => JestNoInterpolationInSnapshots::LANGUAGE,
+            Self::JestNoJasmineGlobals(_) => JestNoJasmineGlobals::LANGUAGE,
+            Self::JestNoLargeSnapshots(_) => JestNoLargeSnapshots::LANGUAGE,
+            Self::JestNoMocksImport(_) => JestNoMocksImport::LANGUAGE,
+            Self::JestNoRestrictedJestMethods(_) => JestNoRestrictedJestMethods::LANGUAGE,
+            Self::JestNoRestrictedMatchers(_) => JestNoRestrictedMatchers::LANGUAGE,
+            Self::JestNoStandaloneExpect(_) => JestNoStandaloneExpect::LANGUAGE,
+            Self::JestNoTestPrefixes(_) => JestNoTestPrefixes::LANGUAGE,
+            Self::JestNoTestReturnStatement(_) => JestNoTestReturnStatement::LANGUAGE,
+            Self::JestNoUnneededAsyncExpectFunction(_) => {
+                JestNoUnneededAsyncExpectFunction::LANGUAGE
+            }
+            Self::JestNoUntypedMockFactory(_) => JestNoUntypedMockFactory::LANGUAGE,
+            Self::JestPaddingAroundAfterAllBlocks(_) => JestPaddingAroundAfterAllBlocks::LANGUAGE,
+            Self::JestPaddingAroundTestBlocks(_) => JestPaddingAroundTestBlocks::LANGUAGE,
+            Self::JestPreferCalledWith(_) => JestPreferCalledWith::LANGUAGE,
+            Self::JestPreferComparisonMatcher(_) => JestPreferComparisonMatcher::LANGUAGE,
+            Self::JestPreferEach(_) => JestPreferEach::LANGUAGE,
+            Self::JestPreferEndingWithAnExpect(_) => JestPreferEndingWithAnExpect::LANGUAGE,
+            Self::JestPreferEqualityMatcher(_) => JestPreferEqualityMatcher::LANGUAGE,
+            Self::JestPreferExpectAssertions(_) => JestPreferExpectAssertions::LANGUAGE,
+            Self::JestPreferExpectResolves(_) => JestPreferExpectResolves::LANGUAGE,
+            Self::JestPreferHooksInOrder(_) => JestPreferHooksInOrder::LANGUAGE,
+            Self::JestPreferHooksOnTop(_) => JestPreferHooksOnTop::LANGUAGE,
+            Self::JestPreferImportingJestGlobals(_) => JestPreferImportingJestGlobals::LANGUAGE,
+            Self::JestPreferJestMocked(_) => JestPreferJestMocked::LANGUAGE,
+            Self::JestPreferLowercaseTitle(_) => JestPreferLowercaseTitle::LANGUAGE,
+            Self::JestPreferMockPromiseShorthand(_) => JestPreferMockPromiseShorthand::LANGUAGE,
+            Self::JestPreferMockReturnShorthand(_) => JestPreferMockReturnShorthand::LANGUAGE,
+            Self::JestPreferSnapshotHint(_) => JestPreferSnapshotHint::LANGUAGE,
+            Self::JestPreferSpyOn(_) => JestPreferSpyOn::LANGUAGE,
+            Self::JestPreferStrictEqual(_) => JestPreferStrictEqual::LANGUAGE,
+            Self::JestPreferToBe(_) => JestPreferToBe::LANGUAGE,
+            Self::JestPreferToContain(_) => JestPreferToContain::LANGUAGE,
+            Self::JestPreferToHaveBeenCalled(_) => JestPreferToHaveBeenCalled::LANGUAGE,
+            Self::JestPreferToHaveBeenCalledTimes(_) => JestPreferToHaveBeenCalledTimes::LANGUAGE,
+            Self::JestPreferToHaveLength(_) => JestPreferToHaveLength::LANGUAGE,
+            Self::JestPreferTodo(_) => JestPreferTodo::LANGUAGE,
+            Self::JestRequireHook(_) => JestRequireHook::LANGUAGE,
+            Self::JestRequireToThrowMessage(_) => JestRequireToThrowMessage::LANGUAGE,
+            Self::JestRequireTopLevelDescribe(_) => JestRequireTopLevelDescribe::LANGUAGE,
+            Self::JestValidDescribeCallback(_) => JestValidDescribeCallback::LANGUAGE,
+            Self::JestValidExpect(_) => JestValidExpect::LANGUAGE,
+            Self::JestValidExpectInPromise(_) => JestValidExpectInPromise::LANGUAGE,
+            Self::JestValidTitle(_) => JestValidTitle::LANGUAGE,
+            Self::ReactButtonHasType(_) => ReactButtonHasType::LANGUAGE,
+            Self::ReactCheckedRequiresOnchangeOrReadonly(_) => {
+                ReactCheckedRequiresOnchangeOrReadonly::LANGUAGE
+            }
+            Self::ReactDisplayName(_) => ReactDisplayName::LANGUAGE,
+            Self::ReactExhaustiveDeps(_) => ReactExhaustiveDeps::LANGUAGE,
+            Self::ReactForbidComponentProps(_) => ReactForbidComponentProps::LANGUAGE,
+            Self::ReactForbidDomProps(_) => ReactForbidDomProps::LANGUAGE,
+            Self::ReactForbidElements(_) => ReactForbidElements::LANGUAGE,
+            Self::ReactForwardRefUsesRef(_) => ReactForwardRefUsesRef::LANGUAGE,
+            Self::ReactFunctionComponentDefinition(_) => ReactFunctionComponentDefinition::LANGUAGE,
+            Self::ReactHookUseState(_) => ReactHookUseState::LANGUAGE,
+            Self::ReactIframeMissingSandbox(_) => ReactIframeMissingSandbox::LANGUAGE,
+            Self::ReactJsxBooleanValue(_) => ReactJsxBooleanValue::LANGUAGE,
+            Self::ReactJsxCurlyBracePresence(_) => ReactJsxCurlyBracePresence::LANGUAGE,
+            Self::ReactJsxFilenameExtension(_) => ReactJsxFilenameExtension::LANGUAGE,
+            Self::ReactJsxFragments(_) => ReactJsxFragments::LANGUAGE,
+            Self::ReactJsxHandlerNames(_) => ReactJsxHandlerNames::LANGUAGE,
+            Self::ReactJsxKey(_) => ReactJsxKey::LANGUAGE,
+            Self::ReactJsxMaxDepth(_) => ReactJsxMaxDepth::LANGUAGE,
+            Self::ReactJsxNoCommentTextnodes(_) => ReactJsxNoCommentTextnodes::LANGUAGE,
+            Self::ReactJsxNoConstructedContextValues(_) => {
+                ReactJsxNoConstructedContextValues::LANGUAGE
+            }
+            Self::ReactJsxNoDuplicateProps(_) => ReactJsxNoDuplicateProps::LANGUAGE,
+            Self::ReactJsxNoLiterals(_) => ReactJsxNoLiterals::LANGUAGE,
+            Self::ReactJsxNoScriptUrl(_) => ReactJsxNoScriptUrl::LANGUAGE,
+            Self::ReactJsxNoTargetBlank(_) => ReactJsxNoTargetBlank::LANGUAGE,
+            Self::ReactJsxNoUndef(_) => ReactJsxNoUndef::LANGUAGE,
+            Self::ReactJsxNoUselessFragment(_) => ReactJsxNoUselessFragment::LANGUAGE,
+            Self::ReactJsxPascalCase(_) => ReactJsxPascalCase::LANGUAGE,
+            Self::ReactJsxPropsNoSpreadMulti(_) => ReactJsxPropsNoSpreadMulti::LANGUAGE,
+            Self::ReactJsxPropsNoSpreading(_) => ReactJsxPropsNoSpreading::LANGUAGE,
+            Self::ReactNoArrayIndexKey(_) => ReactNoArrayIndexKey::LANGUAGE,
+            Self::ReactNoChildrenProp(_) => ReactNoChildrenProp::LANGUAGE,
+            Self::ReactNoCloneElement(_) => ReactNoCloneElement::LANGUAGE,
+            Self::ReactNoDanger(_) => ReactNoDanger::LANGUAGE,
+            Self::ReactNoDangerWithChildren(_) => ReactNoDangerWithChildren::LANGUAGE,
+            Self::ReactNoDidMountSetState(_) => ReactNoDidMountSetState::LANGUAGE,
+            Self::ReactNoDidUpdateSetState(_) => ReactNoDidUpdateSetState::LANGUAGE,
+            Self::ReactNoDirectMutationState(_) => ReactNoDirectMutationState::LANGUAGE,
+            Self::ReactNoFindDomNode(_) => ReactNoFindDomNode::LANGUAGE,
+            Self::ReactNoIsMounted(_) => ReactNoIsMounted::LANGUAGE,
+            Self::ReactNoMultiComp(_) => ReactNoMultiComp::LANGUAGE,
+            Self::ReactNoNamespace(_) => ReactNoNamespace::LANGUAGE,
+            Self::ReactNoObjectTypeAsDefaultProp(_) => ReactNoObjectTypeAsDefaultProp::LANGUAGE,
+            Self::ReactNoReactChildren(_) => ReactNoReactChildren::LANGUAGE,
+            Self::ReactNoRedundantShouldComponentUpdate(_) => {
+                ReactNoRedundantShouldComponentUpdate::LANGUAGE
+            }
+            Self::ReactNoRenderReturnValue(_) => ReactNoRenderReturnValue::LANGUAGE,
+            Self::ReactNoSetState(_) => ReactNoSetState::LANGUAGE,
+            Self::ReactNoStringRefs(_) => ReactNoStringRefs::LANGUAGE,
+            Self::ReactNoThisInSfc(_) => ReactNoThisInSfc::LANGUAGE,
+            Self::ReactNoUnescapedEntities(_) => ReactNoUnescapedEntities::LANGUAGE,
+            Self::ReactNoUnknownProperty(_) => ReactNoUnknownProperty::LANGUAGE,
+            Self::ReactNoUnsafe(_) => ReactNoUnsafe::LANGUAGE,
+            Self::ReactNoUnstableNestedComponents(_) => ReactNoUnstableNestedComponents::LANGUAGE,
+            Self::ReactNoWillUpdateSetState(_) => ReactNoWillUpdateSetState::LANGUAGE,
+            Self::ReactOnlyExportComponents(_) => ReactOnlyExportComponents::LANGUAGE,
+            Self::ReactPreferEs6Class(_) => ReactPreferEs6Class::LANGUAGE,
+            Self::ReactPreferFunctionComponent(_) => ReactPreferFunctionComponent::LANGUAGE,
+            Self::ReactReactCompiler(_) => ReactReactCompiler::LANGUAGE,
+            Self::ReactReactInJsxScope(_) => ReactReactInJsxScope::LANGUAGE,
+            Self::ReactRequireRenderReturn(_) => ReactRequireRenderReturn::LANGUAGE,
+            Self::ReactRulesOfHooks(_) => ReactRulesOfHooks::LANGUAGE,
+            Self::ReactSelfClosingComp(_) => ReactSelfClosingComp::LANGUAGE,
+            Self::ReactStateInConstructor(_) => ReactStateInConstructor::LANGUAGE,
+            Self::ReactStylePropObject(_) => ReactStylePropObject::LANGUAGE,
+            Self::ReactVoidDomElementsNoChildren(_) => ReactVoidDomElementsNoChildren::LANGUAGE,
+            Self::ReactPerfJsxNoJsxAsProp(_) => ReactPerfJsxNoJsxAsProp::LANGUAGE,
+            Self::ReactPerfJsxNoNewArrayAsProp(_) => ReactPerfJsxNoNewArrayAsProp::LANGUAGE,
+            Self::ReactPerfJsxNoNewFunctionAsProp(_) => ReactPerfJsxNoNewFunctionAsProp::LANGUAGE,
+            Self::ReactPerfJsxNoNewObjectAsProp(_) => ReactPerfJsxNoNewObjectAsProp::LANGUAGE,
+            Self::UnicornCatchErrorName(_) => UnicornCatchErrorName::LANGUAGE,
+            Self::UnicornConsistentAssert(_) => UnicornConsistentAssert::LANGUAGE,
+            Self::UnicornConsistentDateClone(_) => UnicornConsistentDateClone::LANGUAGE,
+            Self::UnicornConsistentEmptyArraySpread(_) => {
+                UnicornConsistentEmptyArraySpread::LANGUAGE
+            }
+            Self::UnicornConsistentExistenceIndexCheck(_) => {
+                UnicornConsistentExistenceIndexCheck::LANGUAGE
+            }
+            Self::UnicornConsistentFunctionScoping(_) => UnicornConsistentFunctionScoping::LANGUAGE,
+            Self::UnicornConsistentTemplateLiteralEscape(_) => {
+                UnicornConsistentTemplateLiteralEscape::LANGUAGE
+            }
+            Self::UnicornCustomErrorDefinition(_) => UnicornCustomErrorDefinition::LANGUAGE,
+            Self::UnicornEmptyBraceSpaces(_) => UnicornEmptyBraceSpaces::LANGUAGE,
+            Self::UnicornErrorMessage(_) => UnicornErrorMessage::LANGUAGE,
+            Self::UnicornEscapeCase(_) => UnicornEscapeCase::LANGUAGE,
+            Self::UnicornExplicitLengthCheck(_) => UnicornExplicitLengthCheck::LANGUAGE,
+            Self::UnicornExplicitTimerDelay(_) => UnicornExplicitTimerDelay::LANGUAGE,
+            Self::UnicornFilenameCase(_) => UnicornFilenameCase::LANGUAGE,
+            Self::UnicornImportStyle(_) => UnicornImportStyle::LANGUAGE,
+            Self::UnicornMaxNestedCalls(_) => UnicornMaxNestedCalls::LANGUAGE,
+            Self::UnicornNewForBuiltins(_) => UnicornNewForBuiltins::LANGUAGE,
+            Self::UnicornNoAbusiveEslintDisable(_) => UnicornNoAbusiveEslintDisable::LANGUAGE,
+            Self::UnicornNoAccessorRecursion(_) => UnicornNoAccessorRecursion::LANGUAGE,
+            Self::UnicornNoAnonymousDefaultExport(_) => UnicornNoAnonymousDefaultExport::LANGUAGE,
+            Self::UnicornNoArrayCallbackReference(_) => UnicornNoArrayCallbackReference::LANGUAGE,
+            Self::UnicornNoArrayFillWithReferenceType(_) => {
+                UnicornNoArrayFillWithReferenceType::LANGUAGE
+            }
+            Self::UnicornNoArrayForEach(_) => UnicornNoArrayForEach::LANGUAGE,
+            Self::UnicornNoArrayMethodThisArgument(_) => UnicornNoArrayMethodThisArgument::LANGUAGE,
+            Self::UnicornNoArrayReduce(_) => UnicornNoArrayReduce::LANGUAGE,
+            Self::UnicornNoArrayReverse(_) => UnicornNoArrayReverse::LANGUAGE,
+            Self::UnicornNoArraySort(_) => UnicornNoArraySort::LANGUAGE,
+            Self::UnicornNoAwaitExpressionMember(_) => UnicornNoAwaitExpressionMember::LANGUAGE,
+            Self::UnicornNoAwaitInPromiseMethods(_) => UnicornNoAwaitInPromiseMethods::LANGUAGE,
+            Self::UnicornNoConfusingArrayWith(_) => UnicornNoConfusingArrayWith::LANGUAGE,
+            Self::UnicornNoConsoleSpaces(_) => UnicornNoConsoleSpaces::LANGUAGE,
+            Self::UnicornNoDocumentCookie(_) => UnicornNoDocumentCookie::LANGUAGE,
+            Self::UnicornNoEmptyFile(_) => UnicornNoEmptyFile::LANGUAGE,
+            Self::UnicornNoHexEscape(_) => UnicornNoHexEscape::LANGUAGE,
+            Self::UnicornNoImmediateMutation(_) => UnicornNoImmediateMutation::LANGUAGE,
+            Self::UnicornNoInstanceofArray(_) => UnicornNoInstanceofArray::LANGUAGE,
+            Self::UnicornNoInstanceofBuiltins(_) => UnicornNoInstanceofBuiltins::LANGUAGE,
+            Self::UnicornNoInvalidFetchOptions(_) => UnicornNoInvalidFetchOptions::LANGUAGE,
+            Self::UnicornNoInvalidRemoveEventListener(_) => {
+                UnicornNoInvalidRemoveEventListener::LANGUAGE
+            }
+            Self::UnicornNoLengthAsSliceEnd(_) => UnicornNoLengthAsSliceEnd::LANGUAGE,
+            Self::UnicornNoLonelyIf(_) => UnicornNoLonelyIf::LANGUAGE,
+            Self::UnicornNoMagicArrayFlatDepth(_) => UnicornNoMagicArrayFlatDepth::LANGUAGE,
+            Self::UnicornNoNegatedCondition(_) => UnicornNoNegatedCondition::LANGUAGE,
+            Self::UnicornNoNegationInEqualityCheck(_) => UnicornNoNegationInEqualityCheck::LANGUAGE,
+            Self::UnicornNoNestedTernary(_) => UnicornNoNestedTernary::LANGUAGE,
+            Self::UnicornNoNewArray(_) => UnicornNoNewArray::LANGUAGE,
+            Self::UnicornNoNewBuffer(_) => UnicornNoNewBuffer::LANGUAGE,
+            Self::UnicornNoNull(_) => UnicornNoNull::LANGUAGE,
+            Self::UnicornNoObjectAsDefaultParameter(_) => {
+                UnicornNoObjectAsDefaultParameter::LANGUAGE
+            }
+            Self::UnicornNoProcessExit(_) => UnicornNoProcessExit::LANGUAGE,
+            Self::UnicornNoSinglePromiseInPromiseMethods(_) => {
+                UnicornNoSinglePromiseInPromiseMethods::LANGUAGE
+            }
+            Self::UnicornNoStaticOnlyClass(_) => UnicornNoStaticOnlyClass::LANGUAGE,
+            Self::UnicornNoThenable(_) => UnicornNoThenable::LANGUAGE,
+            Self::UnicornNoThisAssignment(_) => UnicornNoThisAssignment::LANGUAGE,
+            Self::UnicornNoTypeofUndefined(_) => UnicornNoTypeofUndefined::LANGUAGE,
+            Self::UnicornNoUnnecessaryArrayFlatDepth(_) => {
+                UnicornNoUnnecessaryArrayFlatDepth::LANGUAGE
+            }
+            Self::UnicornNoUnnecessaryArraySpliceCount(_) => {
+                UnicornNoUnnecessaryArraySpliceCount::LANGUAGE
+            }
+            Self::UnicornNoUnnecessaryAwait(_) => UnicornNoUnnecessaryAwait::LANGUAGE,
+            Self::UnicornNoUnnecessarySliceEnd(_) => UnicornNoUnnecessarySliceEnd::LANGUAGE,
+            Self::UnicornNoUnreadableArrayDestructuring(_) => {
+                UnicornNoUnreadableArrayDestructuring::LANGUAGE
+            }
+            Self::UnicornNoUnreadableIife(_) => UnicornNoUnreadableIife::LANGUAGE,
+            Self::UnicornNoUselessCollectionArgument(_) => {
+                UnicornNoUselessCollectionArgument::LANGUAGE
+            }
+            Self::UnicornNoUselessErrorCaptureStackTrace(_) => {
+                UnicornNoUselessErrorCaptureStackTrace::LANGUAGE
+            }
+            Self::UnicornNoUselessFallbackInSpread(_) => UnicornNoUselessFallbackInSpread::LANGUAGE,
+            Self::UnicornNoUselessIteratorToArray(_) => UnicornNoUselessIteratorToArray::LANGUAGE,
+            Self::UnicornNoUselessLengthCheck(_) => UnicornNoUselessLengthCheck::LANGUAGE,
+            Self::UnicornNoUselessPromiseResolveReject(_) => {
+                UnicornNoUselessPromiseResolveReject::LANGUAGE
+            }
+            Self::UnicornNoUselessSpread(_) => UnicornNoUselessSpread::LANGUAGE,
+            Self::UnicornNoUselessSwitchCase(_) => UnicornNoUselessSwitchCase::LANGUAGE,
+            Self::UnicornNoUselessUndefined(_) => UnicornNoUselessUndefined::LANGUAGE,
+            Self::UnicornNoZeroFractions(_) => UnicornNoZeroFractions::LANGUAGE,
+            Self::UnicornNumberLiteralCase(_) => UnicornNumberLiteralCase::LANGUAGE,
+            Self::UnicornNumericSeparatorsStyle(_) => UnicornNumericSeparatorsStyle::LANGUAGE,
+            Self::UnicornPreferAddEventListener(_) => UnicornPreferAddEventListener::LANGUAGE,
+            Self::UnicornPreferArrayFind(_) => UnicornPreferArrayFind::LANGUAGE,
+            Self::UnicornPreferArrayFlat(_) => UnicornPreferArrayFlat::LANGUAGE,
+            Self::UnicornPreferArrayFlatMap(_) => UnicornPreferArrayFlatMap::LANGUAGE,
+            Self::UnicornPreferArrayIndexOf(_) => UnicornPreferArrayIndexOf::LANGUAGE,
+            Self::UnicornPreferArraySome(_) => UnicornPreferArraySome::LANGUAGE,
+            Self::UnicornPreferAt(_) => UnicornPreferAt::LANGUAGE,
+            Self::UnicornPreferBigintLiterals(_) => UnicornPreferBigintLiterals::LANGUAGE,
+            Self::UnicornPreferBlobReadingMethods(_) => UnicornPreferBlobReadingMethods::LANGUAGE,
+            Self::UnicornPreferClassFields(_) => UnicornPreferClassFields::LANGUAGE,
+            Self::UnicornPreferClasslistToggle(_) => UnicornPreferClasslistToggle::LANGUAGE,
+            Self::UnicornPreferCodePoint(_) => UnicornPreferCodePoint::LANGUAGE,
+            Self::UnicornPreferDateNow(_) => UnicornPreferDateNow::LANGUAGE,
+            Self::UnicornPreferDefaultParameters(_) => UnicornPreferDefaultParameters::LANGUAGE,
+            Self::UnicornPreferDomNodeAppend(_) => UnicornPreferDomNodeAppend::LANGUAGE,
+            Self::UnicornPreferDomNodeDataset(_) => UnicornPreferDomNodeDataset::LANGUAGE,
+            Self::UnicornPreferDomNodeRemove(_) => UnicornPreferDomNodeRemove::LANGUAGE,
+            Self::UnicornPreferDomNodeTextContent(_) => UnicornPreferDomNodeTextContent::LANGUAGE,
+            Self::UnicornPreferEventTarget(_) => UnicornPreferEventTarget::LANGUAGE,
+            Self::UnicornPreferExportFrom(_) => UnicornPreferExportFrom::LANGUAGE,
+            Self::UnicornPreferGlobalThis(_) => UnicornPreferGlobalThis::LANGUAGE,
+            Self::UnicornPreferImportMetaProperties(_) => {
+                UnicornPreferImportMetaProperties::LANGUAGE
+            }
+            Self::UnicornPreferIncludes(_) => UnicornPreferIncludes::LANGUAGE,
+            Self::UnicornPreferKeyboardEventKey(_) => UnicornPreferKeyboardEventKey::LANGUAGE,
+            Self::UnicornPreferLogicalOperatorOverTernary(_) => {
+                UnicornPreferLogicalOperatorOverTernary::LANGUAGE
+            }
+            Self::UnicornPreferMathMinMax(_) => UnicornPreferMathMinMax::LANGUAGE,
+            Self::UnicornPreferMathTrunc(_) => UnicornPreferMathTrunc::LANGUAGE,
+            Self::UnicornPreferModernDomApis(_) => UnicornPreferModernDomApis::LANGUAGE,
+            Self::UnicornPreferModernMathApis(_) => UnicornPreferModernMathApis::LANGUAGE,
+            Self::UnicornPreferModule(_) => UnicornPreferModule::LANGUAGE,
+            Self::UnicornPreferNativeCoercionFunctions(_) => {
+                UnicornPreferNativeCoercionFunctions::LANGUAGE
+            }
+            Self::UnicornPreferNegativeIndex(_) => UnicornPreferNegativeIndex::LANGUAGE,
+            Self::UnicornPreferNodeProtocol(_) => UnicornPreferNodeProtocol::LANGUAGE,
+            Self::UnicornPreferNumberCoercion(_) => UnicornPreferNumberCoercion::LANGUAGE,
+            Self::UnicornPreferNumberProperties(_) => UnicornPreferNumberProperties::LANGUAGE,
+            Self::UnicornPreferObjectFromEntries(_) => UnicornPreferObjectFromEntries::LANGUAGE,
+            Self::UnicornPreferOptionalCatchBinding(_) => {
+                UnicornPreferOptionalCatchBinding::LANGUAGE
+            }
+            Self::UnicornPreferPrototypeMethods(_) => UnicornPreferPrototypeMethods::LANGUAGE,
+            Self::UnicornPreferQuerySelector(_) => UnicornPreferQuerySelector::LANGUAGE,
+            Self::UnicornPreferReflectApply(_) => UnicornPreferReflectApply::LANGUAGE,
+            Self::UnicornPreferRegexpTest(_) => UnicornPreferRegexpTest::LANGUAGE,
+            Self::UnicornPreferResponseStaticJson(_) => UnicornPreferResponseStaticJson::LANGUAGE,
+            Self::UnicornPreferSetHas(_) => UnicornPreferSetHas::LANGUAGE,
+            Self::UnicornPreferSetSize(_) => UnicornPreferSetSize::LANGUAGE,
+            Self::UnicornPreferSingleCall(_) => UnicornPreferSingleCall::LANGUAGE,
+            Self::UnicornPreferSpread(_) => UnicornPreferSpread::LANGUAGE,
+            Self::UnicornPreferStringRaw(_) => UnicornPreferStringRaw::LANGUAGE,
+            Self::UnicornPreferStringReplaceAll(_) => UnicornPreferStringReplaceAll::LANGUAGE,
+            Self::UnicornPreferStringSlice(_) => UnicornPreferStringSlice::LANGUAGE,
+            Self::UnicornPreferStringStartsEndsWith(_) => {
+                UnicornPreferStringStartsEndsWith::LANGUAGE
+            }
+            Self::UnicornPreferStringTrimStartEnd(_) => UnicornPreferStringTrimStartEnd::LANGUAGE,
+            Self::UnicornPreferStructuredClone(_) => UnicornPreferStructuredClone::LANGUAGE,
+            Self::UnicornPreferTernary(_) => UnicornPreferTernary::LANGUAGE,
+            Self::UnicornPreferTopLevelAwait(_) => UnicornPreferTopLevelAwait::LANGUAGE,
+            Self::UnicornPreferTypeError(_) => UnicornPreferTypeError::LANGUAGE,
+            Self::UnicornRelativeUrlStyle(_) => UnicornRelativeUrlStyle::LANGUAGE,
+            Self::UnicornRequireArrayJoinSeparator(_) => UnicornRequireArrayJoinSeparator::LANGUAGE,
+            Self::UnicornRequireModuleAttributes(_) => UnicornRequireModuleAttributes::LANGUAGE,
+            Self::UnicornRequireModuleSpecifiers(_) => UnicornRequireModuleSpecifiers::LANGUAGE,
+            Self::UnicornRequireNumberToFixedDigitsArgument(_) => {
+                UnicornRequireNumberToFixedDigitsArgument::LANGUAGE
+            }
+            Self::UnicornRequirePostMessageTargetOrigin(_) => {
+                UnicornRequirePostMessageTargetOrigin::LANGUAGE
+            }
+            Self::UnicornSwitchCaseBraces(_) => UnicornSwitchCaseBraces::LANGUAGE,
+            Self::UnicornSwitchCaseBreakPosition(_) => UnicornSwitchCaseBreakPosition::LANGUAGE,
+            Self::UnicornTextEncodingIdentifierCase(_) => {
+                UnicornTextEncodingIdentifierCase::LANGUAGE
+            }
+            Self::UnicornThrowNewError(_) => UnicornThrowNewError::LANGUAGE,
+            Self::JsxA11YAltText(_) => JsxA11YAltText::LANGUAGE,
+            Self::JsxA11YAnchorAmbiguousText(_) => JsxA11YAnchorAmbiguousText::LANGUAGE,
+            Self::JsxA11YAnchorHasContent(_) => JsxA11YAnchorHasContent::LANGUAGE,
+            Self::JsxA11YAnchorIsValid(_) => JsxA11YAnchorIsValid::LANGUAGE,
+            Self::JsxA11YAriaActivedescendantHasTabindex(_) => {
+                JsxA11YAriaActivedescendantHasTabindex::LANGUAGE
+            }
+            Self::JsxA11YAriaProps(_) => JsxA11YAriaProps::LANGUAGE,
+            Self::JsxA11YAriaProptypes(_) => JsxA11YAriaProptypes::LANGUAGE,
+            Self::JsxA11YAriaRole(_) => JsxA11YAriaRole::LANGUAGE,
+            Self::JsxA11YAriaUnsupportedElements(_) => JsxA11YAriaUnsupportedElements::LANGUAGE,
+            Self::JsxA11YAutocompleteValid(_) => JsxA11YAutocompleteValid::LANGUAGE,
+            Self::JsxA11YClickEventsHaveKeyEvents(_) => JsxA11YClickEventsHaveKeyEvents::LANGUAGE,
+            Self::JsxA11YControlHasAssociatedLabel(_) => JsxA11YControlHasAssociatedLabel::LANGUAGE,
+            Self::JsxA11YHeadingHasContent(_) => JsxA11YHeadingHasContent::LANGUAGE,
+            Self::JsxA11YHtmlHasLang(_) => JsxA11YHtmlHasLang::LANGUAGE,
+            Self::JsxA11YIframeHasTitle(_) => JsxA11YIframeHasTitle::LANGUAGE,
+            Self::JsxA11YImgRedundantAlt(_) => JsxA11YImgRedundantAlt::LANGUAGE,
+            Self::JsxA11YInteractiveSupportsFocus(_) => JsxA11YInteractiveSupportsFocus::LANGUAGE,
+            Self::JsxA11YLabelHasAssociatedControl(_) => JsxA11YLabelHasAssociatedControl::LANGUAGE,
+            Self::JsxA11YLang(_) => JsxA11YLang::LANGUAGE,
+            Self::JsxA11YMediaHasCaption(_) => JsxA11YMediaHasCaption::LANGUAGE,
+            Self::JsxA11YMouseEventsHaveKeyEvents(_) => JsxA11YMouseEventsHaveKeyEvents::LANGUAGE,
+            Self::JsxA11YNoAccessKey(_) => JsxA11YNoAccessKey::LANGUAGE,
+            Self::JsxA11YNoAriaHiddenOnFocusable(_) => JsxA11YNoAriaHiddenOnFocusable::LANGUAGE,
+            Self::JsxA11YNoAutofocus(_) => JsxA11YNoAutofocus::LANGUAGE,
+            Self::JsxA11YNoDistractingElements(_) => JsxA11YNoDistractingElements::LANGUAGE,
+            Self::JsxA11YNoInteractiveElementToNoninteractiveRole(_) => {
+                JsxA11YNoInteractiveElementToNoninteractiveRole::LANGUAGE
+            }
+            Self::JsxA11YNoNoninteractiveElementInteractions(_) => {
+                JsxA11YNoNoninteractiveElementInteractions::LANGUAGE
+            }
+            Self::JsxA11YNoNoninteractiveElementToInteractiveRole(_) => {
+                JsxA11YNoNoninteractiveElementToInteractiveRole::LANGUAGE
+            }
+            Self::JsxA11YNoNoninteractiveTabindex(_) => JsxA11YNoNoninteractiveTabindex::LANGUAGE,
+            Self::JsxA11YNoRedundantRoles(_) => JsxA11YNoRedundantRoles::LANGUAGE,
+            Self::JsxA11YNoStaticElementInteractions(_) => {
+                JsxA11YNoStaticElementInteractions::LANGUAGE
+            }
+            Self::JsxA11YPreferTagOverRole(_) => JsxA11YPreferTagOverRole::LANGUAGE,
+            Self::JsxA11YRoleHasRequiredAriaProps(_) => JsxA11YRoleHasRequiredAriaProps::LANGUAGE,
+            Self::JsxA11YRoleSupportsAriaProps(_) => JsxA11YRoleSupportsAriaProps::LANGUAGE,
+            Self::JsxA11YScope(_) => JsxA11YScope::LANGUAGE,
+            Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::LANGUAGE,
+            Self::OxcApproxConstant(_) => OxcApproxConstant::LANGUAGE,
+            Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::LANGUAGE,
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::LANGUAGE,
+            Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::LANGUAGE,
+            Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::LANGUAGE,
+            Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::LANGUAGE,
+            Self::OxcBadMinMaxFunc(_) => OxcBadMinMaxFunc::LANGUAGE,
+            Self::OxcBadObjectLiteralComparison(_) => OxcBadObjectLiteralComparison::LANGUAGE,
+            Self::OxcBadReplaceAllArg(_) => OxcBadReplaceAllArg::LANGUAGE,
+            Self::OxcBranchesSharingCode(_) => OxcBranchesSharingCode::LANGUAGE,
+            Self::OxcConstComparisons(_) => OxcConstComparisons::LANGUAGE,
+            Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::LANGUAGE,
+            Self::OxcErasingOp(_) => OxcErasingOp::LANGUAGE,
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::LANGUAGE,
+            Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::LANGUAGE,
+            Self::OxcMissingThrow(_) => OxcMissingThrow::LANGUAGE,
+            Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::LANGUAGE,
+            Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::LANGUAGE,
+            Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::LANGUAGE,
+            Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::LANGUAGE,
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::LANGUAGE,
+            Self::OxcNoConstEnum(_) => OxcNoConstEnum::LANGUAGE,
+            Self::OxcNoDeprecatedDirectiveRules(_) => OxcNoDeprecatedDirectiveRules::LANGUAGE,
+            Self::OxcNoDisabledCorrectnessRules(_) => OxcNoDisabledCorrectnessRules::LANGUAGE,
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::LANGUAGE,
+            Self::OxcNoDuplicateTypeConstituents(_) => OxcNoDuplicateTypeConstituents::LANGUAGE,
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::LANGUAGE,
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::LANGUAGE,
+            Self::OxcNoIrrelevantDirectiveRules(_) => OxcNoIrrelevantDirectiveRules::LANGUAGE,
+            Self::OxcNoMapSpread(_) => OxcNoMapSpread::LANGUAGE,
+            Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::LANGUAGE,
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::LANGUAGE,
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::LANGUAGE,
+            Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::LANGUAGE,
+            Self::OxcNoStackedNextLineDirectives(_) => OxcNoStackedNextLineDirectives::LANGUAGE,
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::LANGUAGE,
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::LANGUAGE,
+            Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::LANGUAGE,
+            Self::OxcNoUnsupportedDirectiveRules(_) => OxcNoUnsupportedDirectiveRules::LANGUAGE,
+            Self::OxcNoUnterminatedDisableComments(_) => OxcNoUnterminatedDisableComments::LANGUAGE,
+            Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::LANGUAGE,
+            Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::LANGUAGE,
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::LANGUAGE,
+            Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::LANGUAGE,
+            Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::LANGUAGE,
+            Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::LANGUAGE,
+            Self::NextjsInlineScriptId(_) => NextjsInlineScriptId::LANGUAGE,
+            Self::NextjsNextScriptForGa(_) => NextjsNextScriptForGa::LANGUAGE,
+            Self::NextjsNoAssignModuleVariable(_) => NextjsNoAssignModuleVariable::LANGUAGE,
+            Self::NextjsNoAsyncClientComponent(_) => NextjsNoAsyncClientComponent::LANGUAGE,
+            Self::NextjsNoBeforeInteractiveScriptOutsideDocument(_) => {
+                NextjsNoBeforeInteractiveScriptOutsideDocument::LANGUAGE
+            }
+            Self::NextjsNoCssTags(_) => NextjsNoCssTags::LANGUAGE,
+            Self::NextjsNoDocumentImportInPage(_) => NextjsNoDocumentImportInPage::LANGUAGE,
+            Self::NextjsNoDuplicateHead(_) => NextjsNoDuplicateHead::LANGUAGE,
+            Self::NextjsNoHeadElement(_) => NextjsNoHeadElement::LANGUAGE,
+            Self::NextjsNoHeadImportInDocument(_) => NextjsNoHeadImportInDocument::LANGUAGE,
+            Self::NextjsNoHtmlLinkForPages(_) => NextjsNoHtmlLinkForPages::LANGUAGE,
+            Self::NextjsNoImgElement(_) => NextjsNoImgElement::LANGUAGE,
+            Self::NextjsNoPageCustomFont(_) => NextjsNoPageCustomFont::LANGUAGE,
+            Self::NextjsNoScriptComponentInHead(_) => NextjsNoScriptComponentInHead::LANGUAGE,
+            Self::NextjsNoStyledJsxInDocument(_) => NextjsNoStyledJsxInDocument::LANGUAGE,
+            Self::NextjsNoSyncScripts(_) => NextjsNoSyncScripts::LANGUAGE,
+            Self::NextjsNoTitleInDocumentHead(_) => NextjsNoTitleInDocumentHead::LANGUAGE,
+            Self::NextjsNoTypos(_) => NextjsNoTypos::LANGUAGE,
+            Self::NextjsNoUnwantedPolyfillio(_) => NextjsNoUnwantedPolyfillio::LANGUAGE,
+            Self::JsdocCheckAccess(_) => JsdocCheckAccess::LANGUAGE,
+            Self::JsdocCheckPropertyNames(_) => JsdocCheckPropertyNames::LANGUAGE,
+            Self::JsdocCheckTagNames(_) => JsdocCheckTagNames::LANGUAGE,
+            Self::JsdocEmptyTags(_) => JsdocEmptyTags::LANGUAGE,
+            Self::JsdocImplementsOnClasses(_) => JsdocImplementsOnClasses::LANGUAGE,
+            Self::JsdocNoDefaults(_) => JsdocNoDefaults::LANGUAGE,
+            Self::JsdocRequireParam(_) => JsdocRequireParam::LANGUAGE,
+            Self::JsdocRequireParamDescription(_) => JsdocRequireParamDescription::LANGUAGE,
+            Self::JsdocRequireParamName(_) => JsdocRequireParamName::LANGUAGE,
+            Self::JsdocRequireParamType(_) => JsdocRequireParamType::LANGUAGE,
+            Self::JsdocRequireProperty(_) => JsdocRequireProperty::LANGUAGE,
+            Self::JsdocRequirePropertyDescription(_) => JsdocRequirePropertyDescription::LANGUAGE,
+            Self::JsdocRequirePropertyName(_) => JsdocRequirePropertyName::LANGUAGE,
+            Self::JsdocRequirePropertyType(_) => JsdocRequirePropertyType::LANGUAGE,
+            Self::JsdocRequireReturns(_) => JsdocRequireReturns::LANGUAGE,
+            Self::JsdocRequireReturnsDescription(_) => JsdocRequireReturnsDescription::LANGUAGE,
+            Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::LANGUAGE,
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::LANGUAGE,
+            Self::JsdocRequireThrowsDescription(_) => JsdocRequireThrowsDescription::LANGUAGE,
+            Self::JsdocRequireThrowsType(_) => JsdocRequireThrowsType::LANGUAGE,
+            Self::JsdocRequireYields(_) => JsdocRequireYields::LANGUAGE,
+            Self::JsdocRequireYieldsDescription(_) => JsdocRequireYieldsDescription::LANGUAGE,
+            Self::JsdocRequireYieldsType(_) => JsdocRequireYieldsType::LANGUAGE,
+            Self::PromiseAlwaysReturn(_) => PromiseAlwaysReturn::LANGUAGE,
+            Self::PromiseAvoidNew(_) => PromiseAvoidNew::LANGUAGE,
+            Self::PromiseCatchOrReturn(_) => PromiseCatchOrReturn::LANGUAGE,
+            Self::PromiseNoCallbackInPromise(_) => PromiseNoCallbackInPromise::LANGUAGE,
+            Self::PromiseNoMultipleResolved(_) => PromiseNoMultipleResolved::LANGUAGE,
+            Self::PromiseNoNesting(_) => PromiseNoNesting::LANGUAGE,
+            Self::PromiseNoNewStatics(_) => PromiseNoNewStatics::LANGUAGE,
+            Self::PromiseNoPromiseInCallback(_) => PromiseNoPromiseInCallback::LANGUAGE,
+            Self::PromiseNoReturnInFinally(_) => PromiseNoReturnInFinally::LANGUAGE,
+            Self::PromiseNoReturnWrap(_) => PromiseNoReturnWrap::LANGUAGE,
+            Self::PromiseParamNames(_) => PromiseParamNames::LANGUAGE,
+            Self::PromisePreferAwaitToCallbacks(_) => PromisePreferAwaitToCallbacks::LANGUAGE,
+            Self::PromisePreferAwaitToThen(_) => PromisePreferAwaitToThen::LANGUAGE,
+            Self::PromisePreferCatch(_) => PromisePreferCatch::LANGUAGE,
+            Self::PromiseSpecOnly(_) => PromiseSpecOnly::LANGUAGE,
+            Self::PromiseValidParams(_) => PromiseValidParams::LANGUAGE,
+            Self::VitestConsistentEachFor(_) => VitestConsistentEachFor::LANGUAGE,
+            Self::VitestConsistentTestFilename(_) => VitestConsistentTestFilename::LANGUAGE,
+            Self::VitestConsistentTestIt(_) => VitestConsistentTestIt::LANGUAGE,
+            Self::VitestConsistentVitestVi(_) => VitestConsistentVitestVi::LANGUAGE,
+            Self::VitestExpectExpect(_) => VitestExpectExpect::LANGUAGE,
+            Self::VitestHoistedApisOnTop(_) => VitestHoistedApisOnTop::LANGUAGE,
+            Self::VitestMaxExpects(_) => VitestMaxExpects::LANGUAGE,
+            Self::VitestMaxNestedDescribe(_) => VitestMaxNestedDescribe::LANGUAGE,
+            Self::VitestNoAliasMethods(_) => VitestNoAliasMethods::LANGUAGE,
+            Self::VitestNoCommentedOutTests(_) => VitestNoCommentedOutTests::LANGUAGE,
+            Self::VitestNoConditionalExpect(_) => VitestNoConditionalExpect::LANGUAGE,
+            Self::VitestNoConditionalInTest(_) => VitestNoConditionalInTest::LANGUAGE,
+            Self::VitestNoConditionalTests(_) => VitestNoConditionalTests::LANGUAGE,
+            Self::VitestNoDisabledTests(_) => VitestNoDisabledTests::LANGUAGE,
+            Self::VitestNoDuplicateHooks(_) => VitestNoDuplicateHooks::LANGUAGE,
+            Self::VitestNoFocusedTests(_) => VitestNoFocusedTests::LANGUAGE,
+            Self::VitestNoHooks(_) => VitestNoHooks::LANGUAGE,
+            Self::VitestNoIdenticalTitle(_) => VitestNoIdenticalTitle::LANGUAGE,
+            Self::VitestNoImportNodeTest(_) => VitestNoImportNodeTest::LANGUAGE,
+            Self::VitestNoImportingVitestGlobals(_) => VitestNoImportingVitestGlobals::LANGUAGE,
+            Self::VitestNoInterpolationInSnapshots(_) => VitestNoInterpolationInSnapshots::LANGUAGE,
+            Self::VitestNoLargeSnapshots(_) => VitestNoLargeSnapshots::LANGUAGE,
+            Self::VitestNoMocksImport(_) => VitestNoMocksImport::LANGUAGE,
+            Self::VitestNoRestrictedMatchers(_) => VitestNoRestrictedMatchers::LANGUAGE,
+            Self::VitestNoRestrictedViMethods(_) => VitestNoRestrictedViMethods::LANGUAGE,
+            Self::VitestNoStandaloneExpect(_) => VitestNoStandaloneExpect::LANGUAGE,
+            Self::VitestNoTestPrefixes(_) => VitestNoTestPrefixes::LANGUAGE,
+            Self::VitestNoTestReturnStatement(_) => VitestNoTestReturnStatement::LANGUAGE,
+            Self::VitestNoUnneededAsyncExpectFunction(_) => {
+                VitestNoUnneededAsyncExpectFunction::LANGUAGE
+            }
+            Self::VitestPaddingAroundAfterAllBlocks(_) => {
+                VitestPaddingAroundAfterAllBlocks::LANGUAGE
+            }
+            Self::VitestPaddingAroundTestBlocks(_) => VitestPaddingAroundTestBlocks::LANGUAGE,
+            Self::VitestPreferCalledExactlyOnceWith(_) => {
+                VitestPreferCalledExactlyOnceWith::LANGUAGE
+            }
+            Self::VitestPreferCalledOnce(_) => VitestPreferCalledOnce::LANGUAGE,
+            Self::VitestPreferCalledTimes(_) => VitestPreferCalledTimes::LANGUAGE,
+            Self::VitestPreferCalledWith(_) => VitestPreferCalledWith::LANGUAGE,
+            Self::VitestPreferComparisonMatcher(_) => VitestPreferComparisonMatcher::LANGUAGE,
+            Self::VitestPreferDescribeFunctionTitle(_) => {
+                VitestPreferDescribeFunctionTitle::LANGUAGE
+            }
+            Self::VitestPreferEach(_) => VitestPreferEach::LANGUAGE,
+            Self::VitestPreferEqualityMatcher(_) => VitestPreferEqualityMatcher::LANGUAGE,
+            Self::VitestPreferExpectAssertions(_) => VitestPreferExpectAssertions::LANGUAGE,
+            Self::VitestPreferExpectResolves(_) => VitestPreferExpectResolves::LANGUAGE,
+            Self::VitestPreferExpectTypeOf(_) => VitestPreferExpectTypeOf::LANGUAGE,
+            Self::VitestPreferHooksInOrder(_) => VitestPreferHooksInOrder::LANGUAGE,
+            Self::VitestPreferHooksOnTop(_) => VitestPreferHooksOnTop::LANGUAGE,
+            Self::VitestPreferImportInMock(_) => VitestPreferImportInMock::LANGUAGE,
+            Self::VitestPreferImportingVitestGlobals(_) => {
+                VitestPreferImportingVitestGlobals::LANGUAGE
+            }
+            Self::VitestPreferLowercaseTitle(_) => VitestPreferLowercaseTitle::LANGUAGE,
+            Self::VitestPreferMockPromiseShorthand(_) => VitestPreferMockPromiseShorthand::LANGUAGE,
+            Self::VitestPreferMockReturnShorthand(_) => VitestPreferMockReturnShorthand::LANGUAGE,
+            Self::VitestPreferSnapshotHint(_) => VitestPreferSnapshotHint::LANGUAGE,
+            Self::VitestPreferSpyOn(_) => VitestPreferSpyOn::LANGUAGE,
+            Self::VitestPreferStrictBooleanMatchers(_) => {
+                VitestPreferStrictBooleanMatchers::LANGUAGE
+            }
+            Self::VitestPreferStrictEqual(_) => VitestPreferStrictEqual::LANGUAGE,
+            Self::VitestPreferToBe(_) => VitestPreferToBe::LANGUAGE,
+            Self::VitestPreferToBeFalsy(_) => VitestPreferToBeFalsy::LANGUAGE,
+            Self::VitestPreferToBeObject(_) => VitestPreferToBeObject::LANGUAGE,
+            Self::VitestPreferToBeTruthy(_) => VitestPreferToBeTruthy::LANGUAGE,
+            Self::VitestPreferToContain(_) => VitestPreferToContain::LANGUAGE,
+            Self::VitestPreferToHaveBeenCalledTimes(_) => {
+                VitestPreferToHaveBeenCalledTimes::LANGUAGE
+            }
+            Self::VitestPreferToHaveLength(_) => VitestPreferToHaveLength::LANGUAGE,
+            Self::VitestPreferTodo(_) => VitestPreferTodo::LANGUAGE,
+            Self::VitestRequireAwaitedExpectPoll(_) => VitestRequireAwaitedExpectPoll::LANGUAGE,
+            Self::VitestRequireHook(_) => VitestRequireHook::LANGUAGE,
+            Self::VitestRequireLocalTestContextForConcurrentSnapshots(_) => {
+                VitestRequireLocalTestContextForConcurrentSnapshots::LANGUAGE
+            }
+            Self::VitestRequireMockTypeParameters(_) => VitestRequireMockTypeParameters::LANGUAGE,
+            Self::VitestRequireTestTimeout(_) => VitestRequireTestTimeout::LANGUAGE,
+            Self::VitestRequireToThrowMessage(_) => VitestRequireToThrowMessage::LANGUAGE,
+            Self::VitestRequireTopLevelDescribe(_) => VitestRequireTopLevelDescribe::LANGUAGE,
+            Self::VitestValidDescribeCallback(_) => VitestValidDescribeCallback::LANGUAGE,
+            Self::VitestValidExpect(_) => VitestValidExpect::LANGUAGE,
+            Self::VitestValidExpectInPromise(_) => VitestValidExpectInPromise::LANGUAGE,
+            Self::VitestValidTitle(_) => VitestValidTitle::LANGUAGE,
+            Self::VitestWarnTodo(_) => VitestWarnTodo::LANGUAGE,
+            Self::NodeCallbackReturn(_) => NodeCallbackReturn::LANGUAGE,
+            Self::NodeExportsStyle(_) => NodeExportsStyle::LANGUAGE,
+            Self::NodeGlobalRequire(_) => NodeGlobalRequire::LANGUAGE,
+            Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::LANGUAGE,
+            Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::LANGUAGE,
+            Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::LANGUAGE,
+            Self::NodeNoNewRequire(_) => NodeNoNewRequire::LANGUAGE,
+            Self::NodeNoPathConcat(_) => NodeNoPathConcat::LANGUAGE,
+            Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::LANGUAGE,
+            Self::NodeNoSync(_) => NodeNoSync::LANGUAGE,
+            Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::LANGUAGE,
+            Self::VueComponentDefinitionNameCasing(_) => VueComponentDefinitionNameCasing::LANGUAGE,
+            Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::LANGUAGE,
+            Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::LANGUAGE,
+            Self::VueDefinePropsDestructuring(_) => VueDefinePropsDestructuring::LANGUAGE,
+            Self::VueMaxProps(_) => VueMaxProps::LANGUAGE,
+            Self::VueNextTickStyle(_) => VueNextTickStyle::LANGUAGE,
+            Self::VueNoArrowFunctionsInWatch(_) => VueNoArrowFunctionsInWatch::LANGUAGE,
+            Self::VueNoAsyncInComputedProperties(_) => VueNoAsyncInComputedProperties::LANGUAGE,
+            Self::VueNoComputedPropertiesInData(_) => VueNoComputedPropertiesInData::LANGUAGE,
+            Self::VueNoDeprecatedDataObjectDeclaration(_) => {
+                VueNoDeprecatedDataObjectDeclaration::LANGUAGE
+            }
+            Self::VueNoDeprecatedDeleteSet(_) => VueNoDeprecatedDeleteSet::LANGUAGE,
+            Self::VueNoDeprecatedDestroyedLifecycle(_) => {
+                VueNoDeprecatedDestroyedLifecycle::LANGUAGE
+            }
+            Self::VueNoDeprecatedEventsApi(_) => VueNoDeprecatedEventsApi::LANGUAGE,
+            Self::VueNoDeprecatedModelDefinition(_) => VueNoDeprecatedModelDefinition::LANGUAGE,
+            Self::VueNoDeprecatedPropsDefaultThis(_) => VueNoDeprecatedPropsDefaultThis::LANGUAGE,
+            Self::VueNoDeprecatedVueConfigKeycodes(_) => VueNoDeprecatedVueConfigKeycodes::LANGUAGE,
+            Self::VueNoDupeKeys(_) => VueNoDupeKeys::LANGUAGE,
+            Self::VueNoExportInScriptSetup(_) => VueNoExportInScriptSetup::LANGUAGE,
+            Self::VueNoExposeAfterAwait(_) => VueNoExposeAfterAwait::LANGUAGE,
+            Self::VueNoImportCompilerMacros(_) => VueNoImportCompilerMacros::LANGUAGE,
+            Self::VueNoLifecycleAfterAwait(_) => VueNoLifecycleAfterAwait::LANGUAGE,
+            Self::VueNoMultipleSlotArgs(_) => VueNoMultipleSlotArgs::LANGUAGE,
+            Self::VueNoRequiredPropWithDefault(_) => VueNoRequiredPropWithDefault::LANGUAGE,
+            Self::VueNoReservedComponentNames(_) => VueNoReservedComponentNames::LANGUAGE,
+            Self::VueNoReservedKeys(_) => VueNoReservedKeys::LANGUAGE,
+            Self::VueNoReservedProps(_) => VueNoReservedProps::LANGUAGE,
+            Self::VueNoSharedComponentData(_) => VueNoSharedComponentData::LANGUAGE,
+            Self::VueNoSideEffectsInComputedProperties(_) => {
+                VueNoSideEffectsInComputedProperties::LANGUAGE
+            }
+            Self::VueNoThisInBeforeRouteEnter(_) => VueNoThisInBeforeRouteEnter::LANGUAGE,
+            Self::VueNoWatchAfterAwait(_) => VueNoWatchAfterAwait::LANGUAGE,
+            Self::VuePreferImportFromVue(_) => VuePreferImportFromVue::LANGUAGE,
+            Self::VuePropNameCasing(_) => VuePropNameCasing::LANGUAGE,
+            Self::VueRequireDefaultExport(_) => VueRequireDefaultExport::LANGUAGE,
+            Self::VueRequireDefaultProp(_) => VueRequireDefaultProp::LANGUAGE,
+            Self::VueRequireDirectExport(_) => VueRequireDirectExport::LANGUAGE,
+            Self::VueRequirePropTypeConstructor(_) => VueRequirePropTypeConstructor::LANGUAGE,
+            Self::VueRequirePropTypes(_) => VueRequirePropTypes::LANGUAGE,
+            Self::VueRequireRenderReturn(_) => VueRequireRenderReturn::LANGUAGE,
+            Self::VueRequireSlotsAsFunctions(_) => VueRequireSlotsAsFunctions::LANGUAGE,
+            Self::VueRequireTypedRef(_) => VueRequireTypedRef::LANGUAGE,
+            Self::VueReturnInComputedProperty(_) => VueReturnInComputedProperty::LANGUAGE,
+            Self::VueReturnInEmitsValidator(_) => VueReturnInEmitsValidator::LANGUAGE,
+            Self::VueValidDefineEmits(_) => VueValidDefineEmits::LANGUAGE,
+            Self::VueValidDefineOptions(_) => VueValidDefineOptions::LANGUAGE,
+            Self::VueValidDefineProps(_) => VueValidDefineProps::LANGUAGE,
+            Self::VueValidNextTick(_) => VueValidNextTick::LANGUAGE,
+        }
+    }
     #[doc = r" Additional information about this rule."]
     #[cfg(feature = "ruledocs")]
     pub fn info(&self) -> RuleInfo {
@@ -21624,6 +24399,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::INFO,
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::INFO,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::INFO,
+            Self::ImportNoUselessPathSegments(_) => ImportNoUselessPathSegments::INFO,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::INFO,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::INFO,
             Self::ImportUnambiguous(_) => ImportUnambiguous::INFO,
@@ -21785,6 +24561,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(_) => EslintNoWarningComments::INFO,
             Self::EslintNoWith(_) => EslintNoWith::INFO,
             Self::EslintObjectShorthand(_) => EslintObjectShorthand::INFO,
+            Self::EslintOneVar(_) => EslintOneVar::INFO,
             Self::EslintOperatorAssignment(_) => EslintOperatorAssignment::INFO,
             Self::EslintPreferArrowCallback(_) => EslintPreferArrowCallback::INFO,
             Self::EslintPreferConst(_) => EslintPreferConst::INFO,
@@ -22331,6 +25108,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(_) => JsxA11YTabindexNoPositive::INFO,
             Self::OxcApproxConstant(_) => OxcApproxConstant::INFO,
             Self::OxcBadArrayMethodOnArguments(_) => OxcBadArrayMethodOnArguments::INFO,
+            Self::OxcBadBigintArg(_) => OxcBadBigintArg::INFO,
             Self::OxcBadBitwiseOperator(_) => OxcBadBitwiseOperator::INFO,
             Self::OxcBadCharAtComparison(_) => OxcBadCharAtComparison::INFO,
             Self::OxcBadComparisonSequence(_) => OxcBadComparisonSequence::INFO,
@@ -22341,19 +25119,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(_) => OxcConstComparisons::INFO,
             Self::OxcDoubleComparisons(_) => OxcDoubleComparisons::INFO,
             Self::OxcErasingOp(_) => OxcErasingOp::INFO,
+            Self::OxcMaxRulesPerDirective(_) => OxcMaxRulesPerDirective::INFO,
             Self::OxcMisrefactoredAssignOp(_) => OxcMisrefactoredAssignOp::INFO,
             Self::OxcMissingThrow(_) => OxcMissingThrow::INFO,
             Self::OxcNoAccumulatingSpread(_) => OxcNoAccumulatingSpread::INFO,
             Self::OxcNoAsyncAwait(_) => OxcNoAsyncAwait::INFO,
             Self::OxcNoAsyncEndpointHandlers(_) => OxcNoAsyncEndpointHandlers::INFO,
             Self::OxcNoBarrelFile(_) => OxcNoBarrelFile::INFO,
+            Self::OxcNoClassLevelDirectives(_) => OxcNoClassLevelDirectives::INFO,
             Self::OxcNoConstEnum(_) => OxcNoConstEnum::INFO,
+            Self::OxcNoDeprecatedDirectiveRules(_) => OxcNoDeprecatedDirectiveRules::INFO,
+            Self::OxcNoDisabledCorrectnessRules(_) => OxcNoDisabledCorrectnessRules::INFO,
+            Self::OxcNoDisabledExplicitAny(_) => OxcNoDisabledExplicitAny::INFO,
+            Self::OxcNoDuplicateTypeConstituents(_) => OxcNoDuplicateTypeConstituents::INFO,
+            Self::OxcNoEslintDisableComments(_) => OxcNoEslintDisableComments::INFO,
+            Self::OxcNoFullWidthDirectives(_) => OxcNoFullWidthDirectives::INFO,
+            Self::OxcNoIrrelevantDirectiveRules(_) => OxcNoIrrelevantDirectiveRules::INFO,
             Self::OxcNoMapSpread(_) => OxcNoMapSpread::INFO,
             Self::OxcNoOptionalChaining(_) => OxcNoOptionalChaining::INFO,
+            Self::OxcNoRedundantEnableComments(_) => OxcNoRedundantEnableComments::INFO,
+            Self::OxcNoRedundantLineDirectives(_) => OxcNoRedundantLineDirectives::INFO,
             Self::OxcNoRestSpreadProperties(_) => OxcNoRestSpreadProperties::INFO,
+            Self::OxcNoStackedNextLineDirectives(_) => OxcNoStackedNextLineDirectives::INFO,
+            Self::OxcNoStaleDirectives(_) => OxcNoStaleDirectives::INFO,
+            Self::OxcNoStrippedDirectives(_) => OxcNoStrippedDirectives::INFO,
             Self::OxcNoThisInExportedFunction(_) => OxcNoThisInExportedFunction::INFO,
+            Self::OxcNoUnsupportedDirectiveRules(_) => OxcNoUnsupportedDirectiveRules::INFO,
+            Self::OxcNoUnterminatedDisableComments(_) => OxcNoUnterminatedDisableComments::INFO,
             Self::OxcNumberArgOutOfRange(_) => OxcNumberArgOutOfRange::INFO,
             Self::OxcOnlyUsedInRecursion(_) => OxcOnlyUsedInRecursion::INFO,
+            Self::OxcRequireDirectiveTicket(_) => OxcRequireDirectiveTicket::INFO,
             Self::OxcUninvokedArrayCallback(_) => OxcUninvokedArrayCallback::INFO,
             Self::NextjsGoogleFontDisplay(_) => NextjsGoogleFontDisplay::INFO,
             Self::NextjsGoogleFontPreconnect(_) => NextjsGoogleFontPreconnect::INFO,
@@ -22395,6 +25190,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(_) => JsdocRequireReturns::INFO,
             Self::JsdocRequireReturnsDescription(_) => JsdocRequireReturnsDescription::INFO,
             Self::JsdocRequireReturnsType(_) => JsdocRequireReturnsType::INFO,
+            Self::JsdocRequireThrows(_) => JsdocRequireThrows::INFO,
             Self::JsdocRequireThrowsDescription(_) => JsdocRequireThrowsDescription::INFO,
             Self::JsdocRequireThrowsType(_) => JsdocRequireThrowsType::INFO,
             Self::JsdocRequireYields(_) => JsdocRequireYields::INFO,
@@ -22593,6 +25389,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.types_info(),
             Self::ImportNoSelfImport(rule) => rule.types_info(),
             Self::ImportNoUnassignedImport(rule) => rule.types_info(),
+            Self::ImportNoUselessPathSegments(rule) => rule.types_info(),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.types_info(),
             Self::ImportPreferDefaultExport(rule) => rule.types_info(),
             Self::ImportUnambiguous(rule) => rule.types_info(),
@@ -22754,6 +25551,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(rule) => rule.types_info(),
             Self::EslintNoWith(rule) => rule.types_info(),
             Self::EslintObjectShorthand(rule) => rule.types_info(),
+            Self::EslintOneVar(rule) => rule.types_info(),
             Self::EslintOperatorAssignment(rule) => rule.types_info(),
             Self::EslintPreferArrowCallback(rule) => rule.types_info(),
             Self::EslintPreferConst(rule) => rule.types_info(),
@@ -23196,6 +25994,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(rule) => rule.types_info(),
             Self::OxcApproxConstant(rule) => rule.types_info(),
             Self::OxcBadArrayMethodOnArguments(rule) => rule.types_info(),
+            Self::OxcBadBigintArg(rule) => rule.types_info(),
             Self::OxcBadBitwiseOperator(rule) => rule.types_info(),
             Self::OxcBadCharAtComparison(rule) => rule.types_info(),
             Self::OxcBadComparisonSequence(rule) => rule.types_info(),
@@ -23206,19 +26005,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(rule) => rule.types_info(),
             Self::OxcDoubleComparisons(rule) => rule.types_info(),
             Self::OxcErasingOp(rule) => rule.types_info(),
+            Self::OxcMaxRulesPerDirective(rule) => rule.types_info(),
             Self::OxcMisrefactoredAssignOp(rule) => rule.types_info(),
             Self::OxcMissingThrow(rule) => rule.types_info(),
             Self::OxcNoAccumulatingSpread(rule) => rule.types_info(),
             Self::OxcNoAsyncAwait(rule) => rule.types_info(),
             Self::OxcNoAsyncEndpointHandlers(rule) => rule.types_info(),
             Self::OxcNoBarrelFile(rule) => rule.types_info(),
+            Self::OxcNoClassLevelDirectives(rule) => rule.types_info(),
             Self::OxcNoConstEnum(rule) => rule.types_info(),
+            Self::OxcNoDeprecatedDirectiveRules(rule) => rule.types_info(),
+            Self::OxcNoDisabledCorrectnessRules(rule) => rule.types_info(),
+            Self::OxcNoDisabledExplicitAny(rule) => rule.types_info(),
+            Self::OxcNoDuplicateTypeConstituents(rule) => rule.types_info(),
+            Self::OxcNoEslintDisableComments(rule) => rule.types_info(),
+            Self::OxcNoFullWidthDirectives(rule) => rule.types_info(),
+            Self::OxcNoIrrelevantDirectiveRules(rule) => rule.types_info(),
             Self::OxcNoMapSpread(rule) => rule.types_info(),
             Self::OxcNoOptionalChaining(rule) => rule.types_info(),
+            Self::OxcNoRedundantEnableComments(rule) => rule.types_info(),
+            Self::OxcNoRedundantLineDirectives(rule) => rule.types_info(),
             Self::OxcNoRestSpreadProperties(rule) => rule.types_info(),
+            Self::OxcNoStackedNextLineDirectives(rule) => rule.types_info(),
+            Self::OxcNoStaleDirectives(rule) => rule.types_info(),
+            Self::OxcNoStrippedDirectives(rule) => rule.types_info(),
             Self::OxcNoThisInExportedFunction(rule) => rule.types_info(),
+            Self::OxcNoUnsupportedDirectiveRules(rule) => rule.types_info(),
+            Self::OxcNoUnterminatedDisableComments(rule) => rule.types_info(),
             Self::OxcNumberArgOutOfRange(rule) => rule.types_info(),
             Self::OxcOnlyUsedInRecursion(rule) => rule.types_info(),
+            Self::OxcRequireDirectiveTicket(rule) => rule.types_info(),
             Self::OxcUninvokedArrayCallback(rule) => rule.types_info(),
             Self::NextjsGoogleFontDisplay(rule) => rule.types_info(),
             Self::NextjsGoogleFontPreconnect(rule) => rule.types_info(),
@@ -23258,6 +26074,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(rule) => rule.types_info(),
             Self::JsdocRequireReturnsDescription(rule) => rule.types_info(),
             Self::JsdocRequireReturnsType(rule) => rule.types_info(),
+            Self::JsdocRequireThrows(rule) => rule.types_info(),
             Self::JsdocRequireThrowsDescription(rule) => rule.types_info(),
             Self::JsdocRequireThrowsType(rule) => rule.types_info(),
             Self::JsdocRequireYields(rule) => rule.types_info(),
@@ -23443,6 +26260,7 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.run_info(),
             Self::ImportNoSelfImport(rule) => rule.run_info(),
             Self::ImportNoUnassignedImport(rule) => rule.run_info(),
+            Self::ImportNoUselessPathSegments(rule) => rule.run_info(),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.run_info(),
             Self::ImportPreferDefaultExport(rule) => rule.run_info(),
             Self::ImportUnambiguous(rule) => rule.run_info(),
@@ -23604,6 +26422,7 @@ impl RuleEnum {
             Self::EslintNoWarningComments(rule) => rule.run_info(),
             Self::EslintNoWith(rule) => rule.run_info(),
             Self::EslintObjectShorthand(rule) => rule.run_info(),
+            Self::EslintOneVar(rule) => rule.run_info(),
             Self::EslintOperatorAssignment(rule) => rule.run_info(),
             Self::EslintPreferArrowCallback(rule) => rule.run_info(),
             Self::EslintPreferConst(rule) => rule.run_info(),
@@ -24046,6 +26865,7 @@ impl RuleEnum {
             Self::JsxA11YTabindexNoPositive(rule) => rule.run_info(),
             Self::OxcApproxConstant(rule) => rule.run_info(),
             Self::OxcBadArrayMethodOnArguments(rule) => rule.run_info(),
+            Self::OxcBadBigintArg(rule) => rule.run_info(),
             Self::OxcBadBitwiseOperator(rule) => rule.run_info(),
             Self::OxcBadCharAtComparison(rule) => rule.run_info(),
             Self::OxcBadComparisonSequence(rule) => rule.run_info(),
@@ -24056,19 +26876,36 @@ impl RuleEnum {
             Self::OxcConstComparisons(rule) => rule.run_info(),
             Self::OxcDoubleComparisons(rule) => rule.run_info(),
             Self::OxcErasingOp(rule) => rule.run_info(),
+            Self::OxcMaxRulesPerDirective(rule) => rule.run_info(),
             Self::OxcMisrefactoredAssignOp(rule) => rule.run_info(),
             Self::OxcMissingThrow(rule) => rule.run_info(),
             Self::OxcNoAccumulatingSpread(rule) => rule.run_info(),
             Self::OxcNoAsyncAwait(rule) => rule.run_info(),
             Self::OxcNoAsyncEndpointHandlers(rule) => rule.run_info(),
             Self::OxcNoBarrelFile(rule) => rule.run_info(),
+            Self::OxcNoClassLevelDirectives(rule) => rule.run_info(),
             Self::OxcNoConstEnum(rule) => rule.run_info(),
+            Self::OxcNoDeprecatedDirectiveRules(rule) => rule.run_info(),
+            Self::OxcNoDisabledCorrectnessRules(rule) => rule.run_info(),
+            Self::OxcNoDisabledExplicitAny(rule) => rule.run_info(),
+            Self::OxcNoDuplicateTypeConstituents(rule) => rule.run_info(),
+            Self::OxcNoEslintDisableComments(rule) => rule.run_info(),
+            Self::OxcNoFullWidthDirectives(rule) => rule.run_info(),
+            Self::OxcNoIrrelevantDirectiveRules(rule) => rule.run_info(),
             Self::OxcNoMapSpread(rule) => rule.run_info(),
             Self::OxcNoOptionalChaining(rule) => rule.run_info(),
+            Self::OxcNoRedundantEnableComments(rule) => rule.run_info(),
+            Self::OxcNoRedundantLineDirectives(rule) => rule.run_info(),
             Self::OxcNoRestSpreadProperties(rule) => rule.run_info(),
+            Self::OxcNoStackedNextLineDirectives(rule) => rule.run_info(),
+            Self::OxcNoStaleDirectives(rule) => rule.run_info(),
+            Self::OxcNoStrippedDirectives(rule) => rule.run_info(),
             Self::OxcNoThisInExportedFunction(rule) => rule.run_info(),
+            Self::OxcNoUnsupportedDirectiveRules(rule) => rule.run_info(),
+            Self::OxcNoUnterminatedDisableComments(rule) => rule.run_info(),
             Self::OxcNumberArgOutOfRange(rule) => rule.run_info(),
             Self::OxcOnlyUsedInRecursion(rule) => rule.run_info(),
+            Self::OxcRequireDirectiveTicket(rule) => rule.run_info(),
             Self::OxcUninvokedArrayCallback(rule) => rule.run_info(),
             Self::NextjsGoogleFontDisplay(rule) => rule.run_info(),
             Self::NextjsGoogleFontPreconnect(rule) => rule.run_info(),
@@ -24108,6 +26945,7 @@ impl RuleEnum {
             Self::JsdocRequireReturns(rule) => rule.run_info(),
             Self::JsdocRequireReturnsDescription(rule) => rule.run_info(),
             Self::JsdocRequireReturnsType(rule) => rule.run_info(),
+            Self::JsdocRequireThrows(rule) => rule.run_info(),
             Self::JsdocRequireThrowsDescription(rule) => rule.run_info(),
             Self::JsdocRequireThrowsType(rule) => rule.run_info(),
             Self::JsdocRequireYields(rule) => rule.run_info(),
@@ -24315,6 +27153,7 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::ImportNoRelativeParentImports(ImportNoRelativeParentImports::default()),
         RuleEnum::ImportNoSelfImport(ImportNoSelfImport::default()),
         RuleEnum::ImportNoUnassignedImport(ImportNoUnassignedImport::default()),
+        RuleEnum::ImportNoUselessPathSegments(ImportNoUselessPathSegments::default()),
         RuleEnum::ImportNoWebpackLoaderSyntax(ImportNoWebpackLoaderSyntax::default()),
         RuleEnum::ImportPreferDefaultExport(ImportPreferDefaultExport::default()),
         RuleEnum::ImportUnambiguous(ImportUnambiguous::default()),
@@ -24476,6 +27315,7 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::EslintNoWarningComments(EslintNoWarningComments::default()),
         RuleEnum::EslintNoWith(EslintNoWith::default()),
         RuleEnum::EslintObjectShorthand(EslintObjectShorthand::default()),
+        RuleEnum::EslintOneVar(EslintOneVar::default()),
         RuleEnum::EslintOperatorAssignment(EslintOperatorAssignment::default()),
         RuleEnum::EslintPreferArrowCallback(EslintPreferArrowCallback::default()),
         RuleEnum::EslintPreferConst(EslintPreferConst::default()),
@@ -25022,6 +27862,7 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::JsxA11YTabindexNoPositive(JsxA11YTabindexNoPositive::default()),
         RuleEnum::OxcApproxConstant(OxcApproxConstant::default()),
         RuleEnum::OxcBadArrayMethodOnArguments(OxcBadArrayMethodOnArguments::default()),
+        RuleEnum::OxcBadBigintArg(OxcBadBigintArg::default()),
         RuleEnum::OxcBadBitwiseOperator(OxcBadBitwiseOperator::default()),
         RuleEnum::OxcBadCharAtComparison(OxcBadCharAtComparison::default()),
         RuleEnum::OxcBadComparisonSequence(OxcBadComparisonSequence::default()),
@@ -25032,19 +27873,36 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::OxcConstComparisons(OxcConstComparisons::default()),
         RuleEnum::OxcDoubleComparisons(OxcDoubleComparisons::default()),
         RuleEnum::OxcErasingOp(OxcErasingOp::default()),
+        RuleEnum::OxcMaxRulesPerDirective(OxcMaxRulesPerDirective::default()),
         RuleEnum::OxcMisrefactoredAssignOp(OxcMisrefactoredAssignOp::default()),
         RuleEnum::OxcMissingThrow(OxcMissingThrow::default()),
         RuleEnum::OxcNoAccumulatingSpread(OxcNoAccumulatingSpread::default()),
         RuleEnum::OxcNoAsyncAwait(OxcNoAsyncAwait::default()),
         RuleEnum::OxcNoAsyncEndpointHandlers(OxcNoAsyncEndpointHandlers::default()),
         RuleEnum::OxcNoBarrelFile(OxcNoBarrelFile::default()),
+        RuleEnum::OxcNoClassLevelDirectives(OxcNoClassLevelDirectives::default()),
         RuleEnum::OxcNoConstEnum(OxcNoConstEnum::default()),
+        RuleEnum::OxcNoDeprecatedDirectiveRules(OxcNoDeprecatedDirectiveRules::default()),
+        RuleEnum::OxcNoDisabledCorrectnessRules(OxcNoDisabledCorrectnessRules::default()),
+        RuleEnum::OxcNoDisabledExplicitAny(OxcNoDisabledExplicitAny::default()),
+        RuleEnum::OxcNoDuplicateTypeConstituents(OxcNoDuplicateTypeConstituents::default()),
+        RuleEnum::OxcNoEslintDisableComments(OxcNoEslintDisableComments::default()),
+        RuleEnum::OxcNoFullWidthDirectives(OxcNoFullWidthDirectives::default()),
+        RuleEnum::OxcNoIrrelevantDirectiveRules(OxcNoIrrelevantDirectiveRules::default()),
         RuleEnum::OxcNoMapSpread(OxcNoMapSpread::default()),
         RuleEnum::OxcNoOptionalChaining(OxcNoOptionalChaining::default()),
+        RuleEnum::OxcNoRedundantEnableComments(OxcNoRedundantEnableComments::default()),
+        RuleEnum::OxcNoRedundantLineDirectives(OxcNoRedundantLineDirectives::default()),
         RuleEnum::OxcNoRestSpreadProperties(OxcNoRestSpreadProperties::default()),
+        RuleEnum::OxcNoStackedNextLineDirectives(OxcNoStackedNextLineDirectives::default()),
+        RuleEnum::OxcNoStaleDirectives(OxcNoStaleDirectives::default()),
+        RuleEnum::OxcNoStrippedDirectives(OxcNoStrippedDirectives::default()),
         RuleEnum::OxcNoThisInExportedFunction(OxcNoThisInExportedFunction::default()),
+        RuleEnum::OxcNoUnsupportedDirectiveRules(OxcNoUnsupportedDirectiveRules::default()),
+        RuleEnum::OxcNoUnterminatedDisableComments(OxcNoUnterminatedDisableComments::default()),
         RuleEnum::OxcNumberArgOutOfRange(OxcNumberArgOutOfRange::default()),
         RuleEnum::OxcOnlyUsedInRecursion(OxcOnlyUsedInRecursion::default()),
+        RuleEnum::OxcRequireDirectiveTicket(OxcRequireDirectiveTicket::default()),
         RuleEnum::OxcUninvokedArrayCallback(OxcUninvokedArrayCallback::default()),
         RuleEnum::NextjsGoogleFontDisplay(NextjsGoogleFontDisplay::default()),
         RuleEnum::NextjsGoogleFontPreconnect(NextjsGoogleFontPreconnect::default()),
@@ -25086,6 +27944,7 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::JsdocRequireReturns(JsdocRequireReturns::default()),
         RuleEnum::JsdocRequireReturnsDescription(JsdocRequireReturnsDescription::default()),
         RuleEnum::JsdocRequireReturnsType(JsdocRequireReturnsType::default()),
+        RuleEnum::JsdocRequireThrows(JsdocRequireThrows::default()),
         RuleEnum::JsdocRequireThrowsDescription(JsdocRequireThrowsDescription::default()),
         RuleEnum::JsdocRequireThrowsType(JsdocRequireThrowsType::default()),
         RuleEnum::JsdocRequireYields(JsdocRequireYields::default()),
@@ -1,15 +1,29 @@
+use crate::generated::dom_elements::DOM_ELEMENTS;
+
 pub const GLOBAL_OBJECT_NAMES: [&str; 4] = ["global", "globalThis", "self", "window"];
 
-/// set of reserved HTML tag names definition
-/// if it's not reserved, then it can have aria-* roles, states, and properties
-/// Reference: <https://github.com/GoogleChrome/accessibility-developer-tools/wiki/Audit-Rules#ax_aria_12>
-/// Reference: <https://www.w3.org/TR/html-aria/#rules-wd>
-/// Reference: <https://github.com/A11yance/aria-query/blob/v5.3.2/src/domMap.js>
-pub const RESERVED_HTML_TAG: [&str; 16] = [
-    "base", "col", "colgroup", "head", "html", "link", "meta", "noembed", "noscript", "param",
-    "picture", "script", "source", "style", "title", "track",
+/// How a global function behaves when it is called with `new`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NativeConstructorKind {
+    /// `new` creates a wrapper object instead of a primitive, e.g. `new String("a")`.
+    PrimitiveWrapper,
+    /// `new` throws a `TypeError`, e.g. `new Symbol()`.
+    NonConstructor,
+}
+
+/// Global functions which should not be called with `new`, with how they behave when they are.
+pub const NATIVE_CONSTRUCTORS: [(&str, NativeConstructorKind); 5] = [
+    ("Boolean", NativeConstructorKind::PrimitiveWrapper),
+    ("Number", NativeConstructorKind::PrimitiveWrapper),
+    ("String", NativeConstructorKind::PrimitiveWrapper),
+    ("BigInt", NativeConstructorKind::NonConstructor),
+    ("Symbol", NativeConstructorKind::NonConstructor),
 ];
 
+pub fn native_constructor_kind(name: &str) -> Option<NativeConstructorKind> {
+    NATIVE_CONSTRUCTORS.iter().find(|(global, _)| *global == name).map(|(_, kind)| *kind)
+}
+
 const ARIA_ACTIVEDESCENDANT: &str = "activedescendant";
 const ARIA_ATOMIC: &str = "atomic";
 const ARIA_AUTOCOMPLETE: &str = "autocomplete";
@@ -321,154 +335,34 @@ pub const VALID_ARIA_ROLES: phf::Set<&'static str> = phf::phf_set![
     "treeitem",
 ];
 
-pub const HTML_TAG: phf::Set<&'static str> = phf::phf_set![
-    "a",
-    "abbr",
-    "acronym",
-    "address",
-    "applet",
-    "area",
-    "article",
-    "aside",
-    "audio",
-    "b",
-    "base",
-    "basefont",
-    "bdi",
-    "bdo",
-    "bgsound",
-    "big",
-    "blink",
-    "blockquote",
-    "body",
-    "br",
-    "button",
-    "canvas",
-    "caption",
-    "center",
-    "cite",
-    "code",
-    "col",
-    "colgroup",
-    "command",
-    "content",
-    "data",
-    "datalist",
-    "dd",
-    "del",
-    "details",
-    "dfn",
-    "dialog",
-    "dir",
-    "div",
-    "dl",
-    "dt",
-    "element",
-    "em",
-    "embed",
-    "fieldset",
-    "figcaption",
-    "figure",
-    "font",
-    "footer",
-    "form",
-    "frame",
-    "frameset",
-    "h1",
-    "h2",
-    "h3",
-    "h4",
-    "h5",
-    "h6",
-    "head",
-    "header",
-    "hgroup",
-    "hr",
-    "html",
-    "i",
-    "iframe",
-    "image",
-    "img",
-    "input",
-    "ins",
-    "isindex",
-    "kbd",
-    "keygen",
-    "label",
-    "legend",
-    "li",
-    "link",
-    "listing",
-    "main",
-    "map",
-    "mark",
-    "marquee",
-    "math",
-    "menu",
-    "menuitem",
-    "meta",
-    "meter",
-    "multicol",
-    "nav",
-    "nextid",
-    "nobr",
-    "noembed",
-    "noframes",
-    "noscript",
-    "object",
-    "ol",
-    "optgroup",
-    "option",
-    "output",
-    "p",
-    "param",
-    "picture",
-    "plaintext",
-    "pre",
-    "progress",
-    "q",
-    "rb",
-    "rbc",
-    "rp",
-    "rt",
-    "rtc",
-    "ruby",
-    "s",
-    "samp",
-    "script",
-    "search",
-    "section",
-    "select",
-    "shadow",
-    "slot",
-    "small",
-    "source",
-    "spacer",
-    "span",
-    "strike",
-    "strong",
-    "style",
-    "sub",
-    "summary",
-    "sup",
-    "svg",
-    "table",
-    "tbody",
-    "td",
-    "template",
-    "textarea",
-    "tfoot",
-    "th",
-    "thead",
-    "time",
-    "title",
-    "tr",
-    "track",
-    "tt",
-    "u",
-    "ul",
-    "var",
-    "video",
-    "wbr",
-    "xmp",
-];
+/// Data about a DOM element, looked up with [`dom_element`].
+///
+/// The table itself is generated by `cargo lintgen`, see `tasks/linter_codegen/src/dom_elements.rs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DomElement {
+    /// Reserved elements, like `meta`, `html`, `script` and `style`, are usually not rendered.
+    pub reserved: bool,
+    /// Void elements, like `br`, `img` and `input`, cannot have children.
+    pub void: bool,
+}
+
+impl DomElement {
+    /// Whether the element can have ARIA roles, states and properties.
+    pub fn supports_aria(self) -> bool {
+        !self.reserved
+    }
+}
+
+/// Returns the data for the DOM element `name`, or `None` for custom components.
+///
+/// # Example
+/// - `dom_element("meta")` => `Some(DomElement { reserved: true, void: true })`
+/// - `dom_element("Foo")` => `None`
+pub fn dom_element(name: &str) -> Option<DomElement> {
+    DOM_ELEMENTS.get(name).copied()
+}
+
+/// Returns whether `name` is a DOM element, as opposed to a custom component.
+pub fn is_dom_element(name: &str) -> bool {
+    DOM_ELEMENTS.contains_key(name)
+}
@@ -13,6 +13,7 @@ use std::{
     ptr::{self, NonNull},
     rc::Rc,
     string::ToString,
+    sync::Arc,
 };
 
 use oxc_allocator::{Allocator, AllocatorPool, ArenaVec, CloneIn, TakeIn};
@@ -25,9 +26,11 @@ use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
 use oxc_data_structures::box_macros::boxed_array;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_estree_tokens::{ESTreeTokenOptionsJS, update_tokens};
-use oxc_parser::Token;
-use oxc_semantic::{AstNode, Semantic};
-use oxc_span::Span;
+use oxc_parser::{Parser, Token};
+use oxc_semantic::{AstNode, Semantic, SemanticBuilder};
+use oxc_span::{GetSpan, SourceType, Span};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
 mod ast_util;
 mod config;
@@ -38,6 +41,7 @@ mod external_plugin_store;
 mod fixer;
 mod frameworks;
 mod globals;
+mod migration_report;
 mod module_graph_visitor;
 mod module_record;
 mod options;
@@ -45,6 +49,7 @@ mod rule;
 mod service;
 mod suppression;
 pub(crate) mod timing;
+mod trace;
 mod tsgolint;
 mod utils;
 
@@ -55,6 +60,7 @@ pub mod table;
 mod generated {
     #[cfg(debug_assertions)]
     mod assert_layouts;
+    pub mod dom_elements;
     mod rule_runner_impls;
     pub mod rules_enum;
 }
@@ -66,8 +72,8 @@ mod lint_runner;
 
 pub use crate::config::plugins::normalize_plugin_name;
 pub use crate::disable_directives::{
-    DirectivePrefix, DisableDirectives, DisableRuleComment, RuleCommentRule, RuleCommentType,
-    create_unused_directives_diagnostics,
+    DirectivePrefix, DisableDirectives, DisableRuleComment, DisabledRule, RuleCommentRule,
+    RuleCommentType, create_unused_directives_diagnostics,
 };
 pub use crate::{
     config::{
@@ -81,17 +87,23 @@ pub use crate::{
         JsFix, LintFileResult, LoadPluginResult, convert_and_merge_js_fixes,
     },
     external_plugin_store::{ExternalOptionsId, ExternalPluginStore, ExternalRuleId},
-    fixer::{Fix, FixKind, Fixer, Message, MessageRule, PossibleFixes},
+    fixer::{
+        AppliedFix, Fix, FixChangelogEntry, FixKind, Fixer, Message, MessageRule, PossibleFixes,
+    },
     frameworks::FrameworkFlags,
     lint_runner::{DirectivesStore, LintRunner, LintRunnerBuilder},
     loader::LINTABLE_EXTENSIONS,
+    migration_report::{FileMigrationReport, MigrationReport},
     module_record::ModuleRecord,
     options::LintOptions,
-    options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
-    rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
+    options::{AllowWarnDeny, FixFilter, InvalidFilterKind, LintFilter, LintFilterKind},
+    rule::{
+        RuleCategory, RuleFixMeta, RuleLanguage, RuleMeta, RuleRunFunctionsImplemented, RuleRunner,
+    },
     service::{LintService, LintServiceOptions, OsFileSystem, RuntimeFileSystem},
     suppression::{OxlintSuppressionFileAction, SuppressionManager},
     timing::{RuleTimingRecord, RuleTimingSource, RuleTimingStore},
+    trace::{RuleTrace, TraceEvent},
     tsgolint::TsGoLintState,
     utils::{read_to_arena_str, read_to_string},
 };
@@ -101,7 +113,7 @@ use crate::{
     external_linter::GlobalsAndEnvs,
     fixer::CompositeFix,
     loader::LINT_PARTIAL_LOADER_EXTENSIONS,
-    rules::RuleEnum,
+    rules::{RULES, RuleEnum},
     timing::{RuleTimingRecorder, RuleTimingStat},
     utils::iter_possible_jest_call_node,
 };
@@ -127,6 +139,79 @@ fn get_timing_stat<const TIMINGS: bool>(
     }
 }
 
+/// Parses `source_text` as the file at `path`, for the single rule runs of [`Linter`].
+fn parse_sub_host<'a>(
+    path: &Path,
+    source_text: &'a str,
+    allocator: &'a Allocator,
+) -> Result<ContextSubHost<'a>, String> {
+    let source_type = SourceType::from_path(path).map_err(|err| err.to_string())?;
+    let parser_ret = Parser::new(allocator, source_text, source_type).parse();
+    if let Some(error) = parser_ret.diagnostics.first() {
+        return Err(format!("Failed to parse {}: {error}", path.display()));
+    }
+    let semantic =
+        SemanticBuilder::new_linter().build(allocator.alloc(parser_ret.program)).semantic;
+    let module_record = Arc::new(ModuleRecord::new(path, &parser_ret.module_record, &semantic));
+    Ok(ContextSubHost::new(semantic, module_record, 0, ContextSubHostOptions::default()))
+}
+
+/// The span, original text and replacement text of the fix of `message`, or of its first fix if
+/// it has several, as applied by the [`Fixer`].
+fn preview_fix(message: &Message, source_text: &str) -> Option<(Span, String, String)> {
+    let fix = match &message.fixes {
+        PossibleFixes::None => return None,
+        PossibleFixes::Single(fix) => fix,
+        PossibleFixes::Multiple(fixes) => fixes.first()?,
+    };
+    let original = fix.span.source_text(source_text).to_string();
+    Some((fix.span, original, fix.content.to_string()))
+}
+
+/// Removes diagnostics from `start` on which are identical to an earlier one.
+///
+/// Config resolution runs each rule at most once per file (see [`ConfigStore::resolve`]), so this
+/// only guards against regressions. A rule may report several different problems at the same
+/// span, which are all kept. In debug builds, an identical diagnostic fails an assertion instead.
+fn dedup_diagnostics(diagnostics: &mut Vec<Message>, start: usize) {
+    fn key(message: &Message) -> (Option<(&str, &str)>, Span, &str) {
+        let rule = message.rule.as_ref().map(|rule| (&*rule.plugin_name, &*rule.rule_name));
+        (rule, message.span, &message.error.message)
+    }
+
+    let section = &diagnostics[start..];
+    if section.len() < 2 {
+        return;
+    }
+    let mut seen = FxHashSet::with_capacity_and_hasher(section.len(), FxBuildHasher);
+    if section.iter().all(|message| seen.insert(key(message))) {
+        return;
+    }
+
+    // Diagnostics with the same key may still differ, e.g. in their labels, help or fixes.
+    let mut seen = FxHashMap::<_, Vec<&Message>>::default();
+    let is_duplicate = section
+        .iter()
+        .map(|message| {
+            let earlier = seen.entry(key(message)).or_default();
+            let is_duplicate = earlier.contains(&message);
+            debug_assert!(
+                !is_duplicate,
+                "{} reported the same diagnostic twice at {:?}: {}",
+                message.rule.as_ref().map_or_else(String::new, MessageRule::short_canonical_name),
+                message.span,
+                message.error.message,
+            );
+            if !is_duplicate {
+                earlier.push(message);
+            }
+            is_duplicate
+        })
+        .collect::<Vec<_>>();
+    let mut is_duplicate = iter::repeat_n(false, start).chain(is_duplicate);
+    diagnostics.retain(|_| !is_duplicate.next().unwrap_or(false));
+}
+
 #[cfg(debug_assertions)]
 fn cmp_diagnostics_for_runtime_optimization_assertion(
     left: &Message,
@@ -286,6 +371,7 @@ pub struct Linter {
     config: ConfigStore,
     external_linter: Option<ExternalLinter>,
     workspace_uri: Option<Box<str>>,
+    fix_filter: FixFilter,
 }
 
 impl Linter {
@@ -294,7 +380,13 @@ impl Linter {
         config: ConfigStore,
         external_linter: Option<ExternalLinter>,
     ) -> Self {
-        Self { options, config, external_linter, workspace_uri: None }
+        Self {
+            options,
+            config,
+            external_linter,
+            workspace_uri: None,
+            fix_filter: FixFilter::default(),
+        }
     }
 
     #[must_use]
@@ -310,6 +402,21 @@ impl Linter {
         self
     }
 
+    /// Only apply the fixes of the rules allowed by `filter`. All diagnostics are still reported.
+    #[must_use]
+    pub fn with_fix_filter(mut self, filter: FixFilter) -> Self {
+        self.fix_filter = filter;
+        self
+    }
+
+    /// Re-parse fixed files, and discard all fixes of a file if they introduce syntax errors.
+    /// Enabled by default.
+    #[must_use]
+    pub fn with_verify_fixes(mut self, yes: bool) -> Self {
+        self.options.verify_fixes = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_report_unused_directives(mut self, report_config: Option<AllowWarnDeny>) -> Self {
         self.options.report_unused_directive = report_config;
@@ -320,6 +427,10 @@ impl Linter {
         &self.options
     }
 
+    pub(crate) fn fix_filter(&self) -> &FixFilter {
+        &self.fix_filter
+    }
+
     pub(crate) fn respect_eslint_disable_directives(&self) -> bool {
         self.config.respect_eslint_disable_directives()
     }
@@ -370,7 +481,6 @@ impl Linter {
         let mut ctx_host =
             Rc::new(ContextHost::new(path, context_sub_hosts, allocator, self.options, config));
 
-        #[cfg(debug_assertions)]
         let mut current_diagnostic_index = 0;
 
         let is_partial_loader_file = ctx_host
@@ -379,6 +489,9 @@ impl Linter {
 
         loop {
             let semantic = ctx_host.semantic();
+            let is_declaration_file = ctx_host.is_declaration_file();
+            let source_type = ctx_host.source_type();
+            let file_extension = ctx_host.file_extension();
             let rules = rules
                 .iter()
                 .filter(|(rule, _)| {
@@ -386,6 +499,14 @@ impl Linter {
                         return false;
                     }
 
+                    if is_declaration_file && !rule.works_on_d_ts() {
+                        return false;
+                    }
+
+                    if !rule.language().applies_to(*source_type, file_extension) {
+                        return false;
+                    }
+
                     // If only the `run` function is implemented, we can skip running the file entirely if the current
                     // file does not contain any of the relevant AST node types.
                     if rule.run_info() == RuleRunFunctionsImplemented::Run
@@ -459,6 +580,12 @@ impl Linter {
             // can mutably access `ctx_host` via `Rc::get_mut` without panicking due to multiple references.
             drop(rules);
 
+            // JS plugins may report several problems at one span, so only the diagnostics of
+            // native rules are deduplicated.
+            ctx_host.get_diagnostics(|diagnostics| {
+                dedup_diagnostics(diagnostics, current_diagnostic_index);
+            });
+
             self.run_external_rules(
                 &external_rules,
                 path,
@@ -481,10 +608,7 @@ impl Linter {
                 break;
             }
 
-            #[cfg(debug_assertions)]
-            {
-                current_diagnostic_index = ctx_host.diagnostic_count();
-            }
+            current_diagnostic_index = ctx_host.diagnostic_count();
         }
 
         let diagnostics = ctx_host.take_diagnostics();
@@ -510,6 +634,270 @@ impl Linter {
         result
     }
 
+    /// Runs only the rule named `rule_name`, e.g. `no-console` or `eslint/no-console`, on the
+    /// file at `path` with `source_text`, and records the nodes it visits, the messages it
+    /// traces with [`LintContext::trace`], and the diagnostics it reports.
+    ///
+    /// The rule is configured as it would be for linting `path`, or with its default
+    /// configuration if it is not enabled for `path`. This is meant for `oxlint --debug-rule`:
+    /// it doesn't use the optimizations of [`Linter::run`], and doesn't support files with
+    /// multiple script blocks, such as Vue files.
+    ///
+    /// # Errors
+    /// If the `trace` feature is disabled, if there is no rule named `rule_name`, or if
+    /// `source_text` can't be parsed.
+    pub fn trace_rule(
+        &self,
+        rule_name: &str,
+        path: &Path,
+        source_text: &str,
+    ) -> Result<RuleTrace, String> {
+        if !cfg!(feature = "trace") {
+            return Err("Tracing rules requires oxlint to be built with the `debug_rule` feature."
+                .to_string());
+        }
+        let allocator = Allocator::default();
+        let sub_host = parse_sub_host(path, source_text, &allocator)?;
+        let (rule_name, ctx_host) =
+            self.run_single_rule(rule_name, path, sub_host, &allocator, self.options, true)?;
+        Ok(RuleTrace { rule_name, events: ctx_host.take_trace_events() })
+    }
+
+    /// Runs only the rule named `rule_name` on the file at `path` with `source_text`, like
+    /// [`Linter::trace_rule`], and returns the fixes of its diagnostics without applying them,
+    /// for previews in editors.
+    ///
+    /// Each fix is returned as its span, the original text in that span, and the replacement
+    /// text, in the order the diagnostics were reported. All kinds of fixes are included,
+    /// including suggestions, regardless of the fix kind the linter is configured with. For
+    /// diagnostics with several possible fixes, the first one is returned, as applied by the
+    /// [`Fixer`]. Fixes may overlap, in which case the [`Fixer`] would only apply the first.
+    ///
+    /// # Errors
+    /// If there is no rule named `rule_name`, or if `source_text` can't be parsed.
+    pub fn preview_fixes(
+        &self,
+        rule_name: &str,
+        path: &Path,
+        source_text: &str,
+    ) -> Result<Vec<(Span, String, String)>, String> {
+        let allocator = Allocator::default();
+        let options = LintOptions { fix: FixKind::All, ..self.options };
+        let sub_host = parse_sub_host(path, source_text, &allocator)?;
+        let (_, ctx_host) =
+            self.run_single_rule(rule_name, path, sub_host, &allocator, options, false)?;
+        Ok(ctx_host
+            .take_diagnostics()
+            .iter()
+            .filter_map(|message| preview_fix(message, source_text))
+            .collect())
+    }
+
+    /// Runs only the rule named `rule_name` on the file at `path` with `source_text`, like
+    /// [`Linter::preview_fixes`], and applies the fixes of its diagnostics, e.g. to rewrite
+    /// `eslint-*` directives when migrating.
+    ///
+    /// Returns the fixed source text, and the previewed fixes as a changelog, each tagged with
+    /// whether it was applied. Unlike [`Linter::preview_fixes`], only the fix kinds the linter
+    /// is configured with are included.
+    ///
+    /// # Errors
+    /// If there is no rule named `rule_name`, or if `source_text` can't be parsed.
+    pub fn apply_fixes(
+        &self,
+        rule_name: &str,
+        path: &Path,
+        source_text: &str,
+    ) -> Result<(String, Vec<FixChangelogEntry>), String> {
+        let allocator = Allocator::default();
+        let sub_host = parse_sub_host(path, source_text, &allocator)?;
+        let (_, ctx_host) =
+            self.run_single_rule(rule_name, path, sub_host, &allocator, self.options, false)?;
+        let messages = ctx_host.take_diagnostics();
+        // Collected before `messages` are moved into the `Fixer`.
+        #[expect(clippy::needless_collect)]
+        let previews = messages
+            .iter()
+            .filter_map(|message| preview_fix(message, source_text))
+            .collect::<Vec<_>>();
+
+        let result = Fixer::new(source_text, messages, SourceType::from_path(path).ok()).fix();
+        let mut applied_spans = result.applied_fixes.iter().map(|fix| fix.span).collect::<Vec<_>>();
+        let changelog = previews
+            .into_iter()
+            .map(|(span, original, replacement)| {
+                let applied = applied_spans
+                    .iter()
+                    .position(|applied_span| *applied_span == span)
+                    .map(|index| applied_spans.swap_remove(index))
+                    .is_some();
+                FixChangelogEntry { path: path.to_path_buf(), span, original, replacement, applied }
+            })
+            .collect();
+        Ok((result.fixed_code.into_owned(), changelog))
+    }
+
+    /// Runs `oxc/no-eslint-disable-comments` on each of `files`, given as path and source text,
+    /// in parallel, and returns the report of the `eslint-*` directives it reports in them.
+    ///
+    /// The rule is configured as for [`Linter::preview_fixes`], so directives it skips, e.g. in
+    /// files governed by an ESLint config, are not in the report. It doesn't block on anything
+    /// but the linting itself, so async callers can run it on a blocking thread.
+    ///
+    /// # Errors
+    /// If a file can't be parsed.
+    pub fn migration_report(&self, files: &[(&Path, &str)]) -> Result<MigrationReport, String> {
+        files
+            .par_iter()
+            .map(|(path, source_text)| {
+                let allocator = Allocator::default();
+                let sub_host = parse_sub_host(path, source_text, &allocator)?;
+                let (_, ctx_host) = self.run_single_rule(
+                    "oxc/no-eslint-disable-comments",
+                    path,
+                    sub_host,
+                    &allocator,
+                    self.options,
+                    false,
+                )?;
+                let reported_spans = ctx_host
+                    .take_diagnostics()
+                    .iter()
+                    .map(|message| message.span)
+                    .collect::<Vec<_>>();
+                let comments = ctx_host.semantic().comments().iter().filter(|comment| {
+                    reported_spans.iter().any(|span| comment.span.contains_inclusive(*span))
+                });
+                let mut report = MigrationReport::default();
+                report.insert(
+                    path.to_path_buf(),
+                    FileMigrationReport::from_comments(comments, source_text),
+                );
+                Ok(report)
+            })
+            .try_reduce(MigrationReport::default, |mut report, other| {
+                report.merge(other);
+                Ok(report)
+            })
+    }
+
+    /// Runs only the rule named `rule_name` on a file the caller has already parsed, and returns
+    /// its diagnostics. This lets tools which parse files themselves, such as bundlers, run a
+    /// rule like `oxc/no-eslint-disable-comments` without parsing every file a second time.
+    ///
+    /// `context_sub_host` holds the semantic model and module record of the file at `path`, as
+    /// for [`Linter::run`]. The rule is configured as for [`Linter::trace_rule`].
+    ///
+    /// # Errors
+    /// If there is no rule named `rule_name`.
+    pub fn run_rule<'a>(
+        &self,
+        rule_name: &str,
+        path: &Path,
+        context_sub_host: ContextSubHost<'a>,
+        allocator: &'a Allocator,
+    ) -> Result<Vec<Message>, String> {
+        let (_, ctx_host) = self.run_single_rule(
+            rule_name,
+            path,
+            context_sub_host,
+            allocator,
+            self.options,
+            false,
+        )?;
+        Ok(ctx_host.take_diagnostics())
+    }
+
+    /// Runs only the rule named `rule_name` on a single file, without the optimizations of
+    /// [`Linter::run`]. Returns the full name of the rule and the context host holding its
+    /// diagnostics, and its trace events if `trace` is `true`.
+    fn run_single_rule<'a>(
+        &self,
+        rule_name: &str,
+        path: &Path,
+        sub_host: ContextSubHost<'a>,
+        allocator: &'a Allocator,
+        options: LintOptions,
+        trace: bool,
+    ) -> Result<(String, Rc<ContextHost<'a>>), String> {
+        let ResolvedLinterState { rules, config, .. } = self.config.resolve(path);
+        let is_named = |rule: &RuleEnum| {
+            rule_name == rule.name()
+                || rule_name.split_once('/').is_some_and(|(plugin, name)| {
+                    name == rule.name() && normalize_plugin_name(plugin) == rule.plugin_name()
+                })
+        };
+        let (rule, severity) = rules
+            .iter()
+            .find(|(rule, _)| is_named(rule))
+            .map(|(rule, severity)| (rule.clone(), *severity))
+            .or_else(|| {
+                RULES
+                    .iter()
+                    .find(|rule| is_named(rule))
+                    .map(|rule| (rule.clone(), AllowWarnDeny::Warn))
+            })
+            .ok_or_else(|| format!("Rule `{rule_name}` not found."))?;
+
+        let mut ctx_host = ContextHost::new(path, vec![sub_host], allocator, options, config);
+        if trace {
+            ctx_host = ctx_host.with_trace();
+        }
+        let ctx_host = Rc::new(ctx_host);
+
+        let rule_name = format!("{}/{}", rule.plugin_name(), rule.name());
+        if rule.is_tsgolint_rule() {
+            return Err(format!("`{rule_name}` is a type-aware rule, which can't be run alone."));
+        }
+        if !rule.language().applies_to(*ctx_host.source_type(), ctx_host.file_extension()) {
+            ctx_host.push_trace_event(|| {
+                TraceEvent::Note(format!(
+                    "{rule_name} only runs on {} files.",
+                    rule.language().as_str()
+                ))
+            });
+            return Ok((rule_name, ctx_host));
+        }
+        if !rule.should_run(&ctx_host) {
+            ctx_host.push_trace_event(|| {
+                TraceEvent::Note(format!("{rule_name} doesn't run on this file."))
+            });
+            return Ok((rule_name, ctx_host));
+        }
+
+        let ctx = Rc::clone(&ctx_host).spawn(&rule, severity);
+        let semantic = ctx_host.semantic();
+        let run_info = rule.run_info();
+        if run_info.is_run_once_implemented() {
+            ctx_host.push_trace_event(|| TraceEvent::RunOnce);
+            rule.run_once::<false>(&ctx, None);
+        }
+        if run_info.is_run_implemented() {
+            for node in semantic.nodes() {
+                let kind = node.kind().ty();
+                if rule.types_info().is_none_or(|types| types.has(kind)) {
+                    ctx_host.push_trace_event(|| TraceEvent::Visit { kind, span: node.span() });
+                    rule.run::<false>(node, &ctx, None);
+                }
+            }
+        }
+        if run_info.is_run_on_jest_node_implemented()
+            && ctx_host.plugins().has_test()
+            && ctx_host.frameworks().is_test()
+        {
+            for jest_node in iter_possible_jest_call_node(semantic) {
+                let node = jest_node.node;
+                ctx_host.push_trace_event(|| TraceEvent::Visit {
+                    kind: node.kind().ty(),
+                    span: node.span(),
+                });
+                rule.run_on_jest_node::<false>(&jest_node, &ctx, None);
+            }
+        }
+
+        Ok((rule_name, ctx_host))
+    }
+
     #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
     fn run_external_rules<'a>(
         &self,
@@ -954,3 +1342,258 @@ impl RawTransferMetadata {
         Self { data_offset, is_ts, is_jsx, has_bom, tokens_offset, tokens_len }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::BTreeSet,
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use rustc_hash::FxHashMap;
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::Severity;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{SourceType, Span};
+
+    use crate::{
+        ConfigStore, ConfigStoreBuilder, ContextSubHost, ContextSubHostOptions,
+        ExternalPluginStore, FileMigrationReport, FixKind, LintOptions, Linter, ModuleRecord,
+        Oxlintrc,
+    };
+
+    fn linter() -> Linter {
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::empty().build(&mut external_plugin_store).unwrap();
+        Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        )
+    }
+
+    #[test]
+    fn preview_fixes() {
+        let source_text = "\
+// oxlint-disable-next-line no-console
+// oxlint-disable-next-line no-debugger
+debugger; console.log(foo);
+// eslint-disable-next-line no-alert
+// eslint-disable-next-line no-console, no-alert
+console.log(alert(foo));
+// oxlint-disable-next-line no-console -- logging is fine here
+// oxlint-disable-next-line no-debugger
+debugger; console.log(foo);
+";
+        let previews = linter()
+            .preview_fixes("oxc/no-stacked-next-line-directives", Path::new("foo.js"), source_text)
+            .unwrap();
+        // The third stack has no fix, because its first directive has a description.
+        assert_eq!(
+            previews,
+            vec![
+                (
+                    Span::new(0, 78),
+                    "// oxlint-disable-next-line no-console\n// oxlint-disable-next-line no-debugger"
+                        .to_string(),
+                    "// oxlint-disable-next-line no-debugger, no-console".to_string(),
+                ),
+                (
+                    Span::new(107, 144),
+                    "// eslint-disable-next-line no-alert\n".to_string(),
+                    String::new(),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_fixes() {
+        let source_text = "\
+// eslint-disable-next-line no-console
+console.log(foo);
+/* eslint-disable no-debugger */
+debugger;
+";
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::empty().build(&mut external_plugin_store).unwrap();
+        let linter = Linter::new(
+            LintOptions { fix: FixKind::SafeFix, ..LintOptions::default() },
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        );
+        let path = Path::new("foo.js");
+        let (fixed_code, changelog) =
+            linter.apply_fixes("oxc/no-eslint-disable-comments", path, source_text).unwrap();
+        assert_eq!(
+            fixed_code,
+            "\
+// oxlint-disable-next-line no-console
+console.log(foo);
+/* oxlint-disable no-debugger */
+debugger;
+"
+        );
+
+        // The changelog has the previewed fixes, tagged as applied.
+        let previews =
+            linter.preview_fixes("oxc/no-eslint-disable-comments", path, source_text).unwrap();
+        assert_eq!(changelog.len(), previews.len());
+        for (entry, (span, original, replacement)) in changelog.iter().zip(previews) {
+            assert_eq!(entry.path, path);
+            assert_eq!(
+                (entry.span, &entry.original, &entry.replacement),
+                (span, &original, &replacement)
+            );
+            assert!(entry.applied);
+        }
+        let mut rewritten = source_text.to_string();
+        for entry in changelog.iter().rev() {
+            rewritten.replace_range(
+                entry.span.start as usize..entry.span.end as usize,
+                &entry.replacement,
+            );
+        }
+        assert_eq!(rewritten, fixed_code);
+    }
+
+    #[test]
+    fn overlapping_overrides_report_once_with_last_options() {
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let oxlintrc = serde_json::from_value::<Oxlintrc>(serde_json::json!({
+            "rules": { "no-console": "warn" },
+            "overrides": [
+                { "files": ["src/**"], "rules": { "no-console": ["warn", { "allow": ["info"] }] } },
+                { "files": ["*.js"], "rules": { "no-console": ["error", { "allow": ["log"] }] } },
+            ],
+        }))
+        .unwrap();
+        let config = ConfigStoreBuilder::from_oxlintrc(
+            true,
+            oxlintrc,
+            None,
+            &mut external_plugin_store,
+            None,
+        )
+        .unwrap()
+        .build(&mut external_plugin_store)
+        .unwrap();
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        );
+
+        let path = Path::new("src/app.js");
+        let source_text = "console.log(foo); console.info(foo);";
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(path).unwrap();
+        let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
+        let semantic =
+            SemanticBuilder::new_linter().build(allocator.alloc(parser_ret.program)).semantic;
+        let module_record = Arc::new(ModuleRecord::new(path, &parser_ret.module_record, &semantic));
+        let sub_host =
+            ContextSubHost::new(semantic, module_record, 0, ContextSubHostOptions::default());
+
+        let messages = linter.run(path, vec![sub_host], &allocator);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].error.severity, Severity::Error);
+        assert!(messages[0].span.source_text(source_text).starts_with("console.info"));
+    }
+
+    #[test]
+    fn run_rule_on_parsed_file() {
+        let path = Path::new("foo.ts");
+        let source_text = "\
+// eslint-disable-next-line no-console
+console.log(foo);
+/* oxlint-disable no-debugger */
+debugger; // eslint-disable-line no-debugger
+";
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(path).unwrap();
+        let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
+        let semantic =
+            SemanticBuilder::new_linter().build(allocator.alloc(parser_ret.program)).semantic;
+        let module_record = Arc::new(ModuleRecord::new(path, &parser_ret.module_record, &semantic));
+        let sub_host =
+            ContextSubHost::new(semantic, module_record, 0, ContextSubHostOptions::default());
+
+        let messages = linter()
+            .run_rule("oxc/no-eslint-disable-comments", path, sub_host, &allocator)
+            .unwrap();
+        let directives = messages
+            .iter()
+            .map(|message| message.span.source_text(source_text))
+            .collect::<Vec<_>>();
+        assert_eq!(directives, vec!["eslint-disable-next-line", "eslint-disable-line"]);
+    }
+
+    #[test]
+    fn preview_fixes_unknown_rule() {
+        assert_eq!(
+            linter().preview_fixes("no-such-rule", Path::new("foo.js"), "foo();"),
+            Err("Rule `no-such-rule` not found.".to_string())
+        );
+    }
+
+    #[test]
+    fn migration_report() {
+        // Files are linted on several threads with a shared `Linter`.
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Linter>();
+
+        let files = [
+            (Path::new("a.js"), "// eslint-disable-next-line no-console\nconsole.log(a);"),
+            (Path::new("b.js"), "/* eslint-disable no-alert, no-debugger */\nalert(b);"),
+            (Path::new("c.js"), "// oxlint-disable-next-line no-empty\nif (c) {}"),
+            (
+                Path::new("d.ts"),
+                "// eslint-disable-next-line no-console -- legacy
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+let d: any = console.log(d);",
+            ),
+            (Path::new("e.js"), "/* eslint-disable */\n// not a directive\nfoo();"),
+        ];
+        let report = linter().migration_report(&files).unwrap();
+
+        let file = |directives: usize, rules: &[&str]| FileMigrationReport {
+            directives,
+            rules: rules.iter().map(ToString::to_string).collect(),
+        };
+        assert_eq!(
+            report
+                .files()
+                .iter()
+                .map(|(path, file)| (path.clone(), file.clone()))
+                .collect::<Vec<_>>(),
+            [
+                (PathBuf::from("a.js"), file(1, &["no-console"])),
+                (PathBuf::from("b.js"), file(1, &["no-alert", "no-debugger"])),
+                (PathBuf::from("c.js"), file(0, &[])),
+                (
+                    PathBuf::from("d.ts"),
+                    file(2, &["@typescript-eslint/no-explicit-any", "no-console"]),
+                ),
+                (PathBuf::from("e.js"), file(1, &[])),
+            ]
+        );
+        assert_eq!(report.total_directives(), 5);
+        assert_eq!(
+            report.rules(),
+            &BTreeSet::from(
+                ["@typescript-eslint/no-explicit-any", "no-alert", "no-console", "no-debugger"]
+                    .map(String::from)
+            )
+        );
+    }
+
+    #[test]
+    fn migration_report_parse_error() {
+        let files = [(Path::new("a.js"), "foo();"), (Path::new("b.js"), "let a = 1 +;")];
+        assert!(linter().migration_report(&files).unwrap_err().starts_with("Failed to parse b.js"));
+    }
+}
@@ -11,7 +11,7 @@ use oxc_span::Span;
 
 use crate::{
     AllowWarnDeny, DisableDirectives, FixKind, LintService, LintServiceOptions, Linter, Message,
-    OsFileSystem, RuleTimingStore, TsGoLintState, suppression::DiffManager,
+    RuleTimingStore, TsGoLintState, suppression::DiffManager,
 };
 
 /// Unified runner that orchestrates both regular (oxc) and type-aware (tsgolint) linting
@@ -108,6 +108,25 @@ impl DirectivesStore {
         }
     }
 
+    /// Files with at least `threshold` disable directive comments, with their number of
+    /// comments, sorted by path.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub fn files_with_disable_directives(&self, threshold: usize) -> Vec<(PathBuf, usize)> {
+        let map = self
+            .map
+            .lock()
+            .expect("DirectivesStore mutex poisoned in files_with_disable_directives");
+        let mut files = map
+            .iter()
+            .map(|(path, directives)| (path.clone(), directives.disable_rule_comments().len()))
+            .filter(|(_, count)| *count > 0 && *count >= threshold)
+            .collect::<Vec<_>>();
+        files.sort_unstable();
+        files
+    }
+
     /// Clear all disable directives
     ///
     /// # Panics
@@ -212,7 +231,8 @@ impl LintRunnerBuilder {
                     state
                         .with_silent(self.silent)
                         .with_type_check(self.type_check)
-                        .with_timings(self.timings),
+                        .with_timings(self.timings)
+                        .with_fix_filter(self.regular_linter.fix_filter().clone()),
                 ),
                 Err(e) => return Err(e),
             }
@@ -241,17 +261,19 @@ impl LintRunner {
     }
 
     /// Run both regular and type-aware linting on files
+    ///
+    /// Files are read from, and fixes are written to, `fs`, which is usually [`OsFileSystem`](crate::OsFileSystem).
+    ///
     /// # Errors
     /// Returns an error if type-aware linting fails.
     pub fn lint_files<const TIMINGS: bool>(
         mut self,
         files: &[Arc<OsStr>],
+        fs: &(dyn crate::RuntimeFileSystem + Sync + Send),
         tx_error: DiagnosticSender,
         diff_manager: &Arc<DiffManager>,
         rule_timing_store: Option<&RuleTimingStore>,
     ) -> Result<Self, String> {
-        let fs: &(dyn crate::RuntimeFileSystem + Sync + Send) = &OsFileSystem;
-
         if self.type_check_only {
             self.lint_service.collect_parse_diagnostics(fs, files.to_owned(), &tx_error);
         } else {
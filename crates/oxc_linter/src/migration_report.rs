@@ -0,0 +1,156 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
+
+use oxc_ast::Comment;
+
+use crate::rules::oxc::no_eslint_disable_comments::find_eslint_comment_directive;
+
+/// The `eslint-*` directive comments left to migrate in one file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FileMigrationReport {
+    /// Number of `eslint-*` directive comments.
+    pub directives: usize,
+    /// Rules referenced by the directives.
+    pub rules: BTreeSet<String>,
+}
+
+impl FileMigrationReport {
+    /// Collects the `eslint-*` directives in `comments` of a file with `source_text`.
+    pub fn from_comments<'c>(
+        comments: impl IntoIterator<Item = &'c Comment>,
+        source_text: &str,
+    ) -> Self {
+        let mut report = Self::default();
+        for directive in comments
+            .into_iter()
+            .filter_map(|comment| find_eslint_comment_directive(comment, source_text))
+        {
+            report.directives += 1;
+            report.rules.extend(directive.rules.iter().map(|(name, _)| (*name).to_string()));
+        }
+        report
+    }
+}
+
+/// The `eslint-*` directive comments left to migrate, per file, with aggregates over all files.
+///
+/// Reports of separate runs, e.g. of incremental CI runs over the changed files only, can be
+/// combined with [`MigrationReport::merge`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    files: BTreeMap<PathBuf, FileMigrationReport>,
+    /// Rules referenced by the directives of all files.
+    rules: BTreeSet<String>,
+    /// Number of directives in all files.
+    total_directives: usize,
+}
+
+impl MigrationReport {
+    /// Adds the report of the file at `path`, replacing an earlier report of the same file.
+    pub fn insert(&mut self, path: PathBuf, file: FileMigrationReport) {
+        self.files.insert(path, file);
+        self.recompute_aggregates();
+    }
+
+    /// Combines `other` into this report. `other` is taken as the later run, so its report of
+    /// a file in both reports replaces the one in this report.
+    pub fn merge(&mut self, other: MigrationReport) {
+        self.files.extend(other.files);
+        self.recompute_aggregates();
+    }
+
+    /// Reports of the files, sorted by path.
+    pub fn files(&self) -> &BTreeMap<PathBuf, FileMigrationReport> {
+        &self.files
+    }
+
+    /// Rules referenced by the directives of all files.
+    pub fn rules(&self) -> &BTreeSet<String> {
+        &self.rules
+    }
+
+    /// Number of directives in all files.
+    pub fn total_directives(&self) -> usize {
+        self.total_directives
+    }
+
+    fn recompute_aggregates(&mut self) {
+        self.rules = self.files.values().flat_map(|file| file.rules.iter().cloned()).collect();
+        self.total_directives = self.files.values().map(|file| file.directives).sum();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::{FileMigrationReport, MigrationReport};
+
+    fn file_report(source_text: &str) -> FileMigrationReport {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        FileMigrationReport::from_comments(&ret.program.comments, source_text)
+    }
+
+    fn report(files: &[(&str, &str)]) -> MigrationReport {
+        let mut report = MigrationReport::default();
+        for (path, source_text) in files {
+            report.insert(PathBuf::from(path), file_report(source_text));
+        }
+        report
+    }
+
+    #[test]
+    fn file_report_counts_eslint_directives() {
+        let file = file_report(
+            "// eslint-disable-next-line no-console, no-debugger
+            console.log(foo);
+            /* eslint-disable no-alert */
+            // oxlint-disable-next-line no-empty
+            // just a comment",
+        );
+        assert_eq!(file.directives, 2);
+        assert_eq!(
+            file.rules.iter().collect::<Vec<_>>(),
+            ["no-alert", "no-console", "no-debugger"]
+        );
+    }
+
+    #[test]
+    fn merge_partial_reports() {
+        let mut merged = report(&[
+            ("a.js", "// eslint-disable-next-line no-console\nconsole.log(a);"),
+            ("b.js", "/* eslint-disable no-alert, no-debugger */"),
+        ]);
+        merged.merge(report(&[
+            // `b.js` was migrated since the first run.
+            ("b.js", "/* oxlint-disable no-alert, no-debugger */"),
+            ("c.js", "// eslint-disable-next-line no-console, no-empty\nif (c) {}"),
+        ]));
+
+        let expected = report(&[
+            ("a.js", "// eslint-disable-next-line no-console\nconsole.log(a);"),
+            ("b.js", "/* oxlint-disable no-alert, no-debugger */"),
+            ("c.js", "// eslint-disable-next-line no-console, no-empty\nif (c) {}"),
+        ]);
+        assert_eq!(merged, expected);
+        assert_eq!(merged.total_directives(), 2);
+        assert_eq!(merged.rules().iter().collect::<Vec<_>>(), ["no-console", "no-empty"]);
+        assert_eq!(merged.files()[&PathBuf::from("b.js")], FileMigrationReport::default());
+    }
+
+    #[test]
+    fn merge_into_empty_report() {
+        let other = report(&[("a.js", "// eslint-disable-next-line no-console\nconsole.log(a);")]);
+        let mut merged = MigrationReport::default();
+        merged.merge(other.clone());
+        assert_eq!(merged, other);
+        assert_eq!(merged.total_directives(), 1);
+    }
+}
@@ -0,0 +1,196 @@
+use std::borrow::Cow;
+
+use oxc_span::min_edit_distance;
+
+use crate::{RuleCategory, config::parse_rule_key, fixer::MessageRule, rules::RULES};
+
+use super::LintFilterKind;
+
+/// Maximum number of close matches listed for an unknown rule or category name.
+const MAX_CLOSE_MATCHES: usize = 3;
+
+/// Restricts which rules' fixes are applied, without changing what is reported.
+///
+/// Names are rule names with or without a plugin prefix (`no-else-return`,
+/// `unicorn/prefer-node-protocol`), or category names (`style`). A fix is applied if its rule
+/// matches any `only` name, or `only` is empty, and matches no `except` name. Fixes which don't
+/// come from a rule, like those removing unused disable directives, are only applied when `only`
+/// is empty.
+#[derive(Debug, Default, Clone)]
+pub struct FixFilter {
+    only: Vec<LintFilterKind>,
+    except: Vec<LintFilterKind>,
+}
+
+impl FixFilter {
+    /// # Errors
+    ///
+    /// If a name is empty, or is not a built-in rule or category. The error message lists close
+    /// matches. Rules of plugins which are not built-in, like JS plugins, are not checked.
+    pub fn new<S: AsRef<str>>(only: &[S], except: &[S]) -> Result<Self, String> {
+        let parse = |names: &[S]| {
+            names.iter().map(|name| parse_name(name.as_ref())).collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self { only: parse(only)?, except: parse(except)? })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.only.is_empty() && self.except.is_empty()
+    }
+
+    /// Whether the fixes of `rule` are applied. `None` is a fix which doesn't come from a rule.
+    pub fn allows(&self, rule: Option<&MessageRule>) -> bool {
+        let Some(rule) = rule else {
+            return self.only.is_empty();
+        };
+        (self.only.is_empty() || self.only.iter().any(|kind| matches(kind, rule)))
+            && !self.except.iter().any(|kind| matches(kind, rule))
+    }
+}
+
+fn matches(kind: &LintFilterKind, rule: &MessageRule) -> bool {
+    match kind {
+        LintFilterKind::All => true,
+        LintFilterKind::Generic(name) => rule.rule_name == *name,
+        LintFilterKind::Rule(plugin, name) => {
+            rule.plugin_name == *plugin && rule.rule_name == *name
+        }
+        LintFilterKind::Category(category) => RULES.iter().any(|r| {
+            r.category() == *category
+                && r.plugin_name() == rule.plugin_name
+                && r.name() == rule.rule_name
+        }),
+    }
+}
+
+fn parse_name(name: &str) -> Result<LintFilterKind, String> {
+    if name.is_empty() {
+        return Err("Expected a rule or category name, got an empty string.".to_string());
+    }
+    if name == "all" {
+        return Ok(LintFilterKind::All);
+    }
+    if let Ok(category) = RuleCategory::try_from(name) {
+        return Ok(LintFilterKind::Category(category));
+    }
+
+    if !name.contains('/') {
+        if RULES.iter().any(|rule| rule.name() == name) {
+            return Ok(LintFilterKind::Generic(Cow::Owned(name.to_string())));
+        }
+        let candidates = RULES
+            .iter()
+            .map(|rule| Cow::Borrowed(rule.name()))
+            .chain(CATEGORY_NAMES.iter().map(|name| Cow::Borrowed(*name)));
+        return Err(unknown_name_error(name, name, candidates));
+    }
+
+    let (plugin_name, rule_name) = parse_rule_key(name);
+    if !RULES.iter().any(|rule| rule.plugin_name() == plugin_name) {
+        // Not a built-in plugin, e.g. a JS plugin, whose rules are not known here.
+        return Ok(LintFilterKind::Rule(Cow::Owned(plugin_name), Cow::Owned(rule_name)));
+    }
+    if RULES.iter().any(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name) {
+        return Ok(LintFilterKind::Rule(Cow::Owned(plugin_name), Cow::Owned(rule_name)));
+    }
+    let candidates =
+        RULES.iter().map(|rule| Cow::Owned(format!("{}/{}", rule.plugin_name(), rule.name())));
+    Err(unknown_name_error(&format!("{plugin_name}/{rule_name}"), &rule_name, candidates))
+}
+
+const CATEGORY_NAMES: [&str; 7] =
+    ["correctness", "suspicious", "pedantic", "perf", "style", "restriction", "nursery"];
+
+/// An error for the unknown `name`, listing the closest `candidates`. Typos are measured relative
+/// to the length of the `rule_name` part of `name`.
+fn unknown_name_error<'a>(
+    name: &str,
+    rule_name: &str,
+    candidates: impl Iterator<Item = Cow<'a, str>>,
+) -> String {
+    let threshold = (rule_name.len() / 4).max(2);
+    let mut close_matches = candidates
+        .filter_map(|candidate| {
+            let distance = min_edit_distance(&candidate, name);
+            (distance <= threshold).then_some((distance, candidate))
+        })
+        .collect::<Vec<_>>();
+    close_matches.sort();
+    close_matches.dedup();
+
+    if close_matches.is_empty() {
+        return format!("Unknown rule or category `{name}`.");
+    }
+    let close_matches = close_matches
+        .into_iter()
+        .take(MAX_CLOSE_MATCHES)
+        .map(|(_, candidate)| format!("`{candidate}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("Unknown rule or category `{name}`. Did you mean {close_matches}?")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rule(plugin_name: &'static str, rule_name: &'static str) -> MessageRule {
+        MessageRule { plugin_name: Cow::Borrowed(plugin_name), rule_name: Cow::Borrowed(rule_name) }
+    }
+
+    #[test]
+    fn only() {
+        let filter = FixFilter::new(&["unicorn/prefer-node-protocol"], &[]).unwrap();
+        assert!(filter.allows(Some(&rule("unicorn", "prefer-node-protocol"))));
+        assert!(!filter.allows(Some(&rule("eslint", "no-else-return"))));
+        assert!(!filter.allows(None));
+
+        let filter = FixFilter::new(&["prefer-node-protocol", "no-else-return"], &[]).unwrap();
+        assert!(filter.allows(Some(&rule("unicorn", "prefer-node-protocol"))));
+        assert!(filter.allows(Some(&rule("eslint", "no-else-return"))));
+        assert!(!filter.allows(Some(&rule("eslint", "no-debugger"))));
+    }
+
+    #[test]
+    fn except() {
+        let filter = FixFilter::new(&[], &["no-else-return"]).unwrap();
+        assert!(!filter.allows(Some(&rule("eslint", "no-else-return"))));
+        assert!(filter.allows(Some(&rule("unicorn", "prefer-node-protocol"))));
+        assert!(filter.allows(None));
+
+        let filter =
+            FixFilter::new(&["restriction", "pedantic"], &["eslint/no-else-return"]).unwrap();
+        assert!(!filter.allows(Some(&rule("eslint", "no-else-return"))));
+        assert!(filter.allows(Some(&rule("unicorn", "prefer-node-protocol"))));
+        assert!(!filter.allows(Some(&rule("eslint", "no-debugger"))));
+    }
+
+    #[test]
+    fn aliases() {
+        let filter = FixFilter::new(&["@typescript-eslint/no-inferrable-types"], &[]).unwrap();
+        assert!(filter.allows(Some(&rule("typescript", "no-inferrable-types"))));
+    }
+
+    #[test]
+    fn unknown_names() {
+        assert_eq!(
+            FixFilter::new(&["no-else-retrun"], &[]).unwrap_err(),
+            "Unknown rule or category `no-else-retrun`. Did you mean `no-else-return`?"
+        );
+        assert_eq!(
+            FixFilter::new(&[], &["styel"]).unwrap_err(),
+            "Unknown rule or category `styel`. Did you mean `style`?"
+        );
+        assert_eq!(
+            FixFilter::new(&["unicorn/prefer-node-protocl"], &[]).unwrap_err(),
+            "Unknown rule or category `unicorn/prefer-node-protocl`. Did you mean `unicorn/prefer-node-protocol`?"
+        );
+        assert_eq!(
+            FixFilter::new(&["not-a-rule-at-all-xyz"], &[]).unwrap_err(),
+            "Unknown rule or category `not-a-rule-at-all-xyz`."
+        );
+        assert!(FixFilter::new(&[""], &[]).is_err());
+        // Rules of other plugins are not known.
+        assert!(FixFilter::new(&["my-plugin/my-rule"], &[]).is_ok());
+    }
+}
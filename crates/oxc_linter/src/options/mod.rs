@@ -2,15 +2,30 @@ use crate::{FrameworkFlags, fixer::FixKind};
 
 mod allow_warn_deny;
 mod filter;
+mod fix_filter;
 
 pub use allow_warn_deny::AllowWarnDeny;
 pub use filter::{InvalidFilterKind, LintFilter, LintFilterKind};
+pub use fix_filter::FixFilter;
 
 /// Subset of options used directly by the linter.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct LintOptions {
     pub fix: FixKind,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    /// Re-parse fixed files, and discard their fixes if they introduce syntax errors.
+    pub verify_fixes: bool,
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        Self {
+            fix: FixKind::default(),
+            framework_hints: FrameworkFlags::default(),
+            report_unused_directive: None,
+            verify_fixes: true,
+        }
+    }
 }
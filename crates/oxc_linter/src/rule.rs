@@ -1,6 +1,6 @@
 #[cfg(feature = "ruledocs")]
 use std::borrow::Cow;
-use std::{fmt, hash::Hash};
+use std::{ffi::OsStr, fmt, hash::Hash};
 
 use schemars::{
     JsonSchema, SchemaGenerator,
@@ -9,6 +9,7 @@ use schemars::{
 use serde::{Deserialize, Serialize};
 
 use oxc_semantic::AstTypesBitset;
+use oxc_span::SourceType;
 
 use crate::{
     AstNode, FixKind,
@@ -426,6 +427,55 @@ pub trait RuleMeta {
     ///
     /// Set via `short_description = "..."` in `declare_oxc_lint!`.
     const INFO: RuleInfo = RuleInfo { short_description: "" };
+
+    /// Whether this rule runs on TypeScript declaration files (`.d.ts`).
+    ///
+    /// Defaults to `true`. Rules which only produce noise on ambient declarations opt out via
+    /// `works_on_d_ts = false` in `declare_oxc_lint!`, and are skipped for those files.
+    const WORKS_ON_D_TS: bool = true;
+
+    /// The language of the files this rule applies to.
+    ///
+    /// Set via `language = typescript`, `language = jsx` or `language = vue` in
+    /// `declare_oxc_lint!` for rules which do nothing in files of other languages.
+    const LANGUAGE: RuleLanguage = RuleLanguage::Any;
+}
+
+/// The language of the files a rule applies to, see [`RuleMeta::LANGUAGE`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleLanguage {
+    /// All files.
+    #[default]
+    Any,
+    /// TypeScript files, including `<script lang="ts">` blocks in Vue files.
+    TypeScript,
+    /// Files which may contain JSX. JavaScript files are linted with JSX enabled.
+    Jsx,
+    /// Vue single-file components.
+    Vue,
+}
+
+impl RuleLanguage {
+    /// Whether a rule of this language applies to a file with `source_type` and
+    /// `file_extension`.
+    pub fn applies_to(self, source_type: SourceType, file_extension: Option<&OsStr>) -> bool {
+        match self {
+            Self::Any => true,
+            Self::TypeScript => source_type.is_typescript(),
+            Self::Jsx => source_type.is_jsx(),
+            Self::Vue => file_extension.is_some_and(|ext| ext == "vue"),
+        }
+    }
+
+    /// The display name of this language, used in diagnostics.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::TypeScript => "TypeScript",
+            Self::Jsx => "JSX",
+            Self::Vue => "Vue",
+        }
+    }
 }
 
 /// Additional information describing a lint rule.
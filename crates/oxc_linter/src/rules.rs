@@ -35,6 +35,7 @@ pub(crate) mod import {
     pub mod no_relative_parent_imports;
     pub mod no_self_import;
     pub mod no_unassigned_import;
+    pub mod no_useless_path_segments;
     pub mod no_webpack_loader_syntax;
     pub mod prefer_default_export;
     pub mod unambiguous;
@@ -200,6 +201,7 @@ pub(crate) mod eslint {
     pub mod no_warning_comments;
     pub mod no_with;
     pub mod object_shorthand;
+    pub mod one_var;
     pub mod operator_assignment;
     pub mod prefer_arrow_callback;
     pub mod prefer_const;
@@ -669,6 +671,7 @@ pub(crate) mod jsx_a11y {
 pub(crate) mod oxc {
     pub mod approx_constant;
     pub mod bad_array_method_on_arguments;
+    pub mod bad_bigint_arg;
     pub mod bad_bitwise_operator;
     pub mod bad_char_at_comparison;
     pub mod bad_comparison_sequence;
@@ -679,19 +682,36 @@ pub(crate) mod oxc {
     pub mod const_comparisons;
     pub mod double_comparisons;
     pub mod erasing_op;
+    pub mod max_rules_per_directive;
     pub mod misrefactored_assign_op;
     pub mod missing_throw;
     pub mod no_accumulating_spread;
     pub mod no_async_await;
     pub mod no_async_endpoint_handlers;
     pub mod no_barrel_file;
+    pub mod no_class_level_directives;
     pub mod no_const_enum;
+    pub mod no_deprecated_directive_rules;
+    pub mod no_disabled_correctness_rules;
+    pub mod no_disabled_explicit_any;
+    pub mod no_duplicate_type_constituents;
+    pub mod no_eslint_disable_comments;
+    pub mod no_full_width_directives;
+    pub mod no_irrelevant_directive_rules;
     pub mod no_map_spread;
     pub mod no_optional_chaining;
+    pub mod no_redundant_enable_comments;
+    pub mod no_redundant_line_directives;
     pub mod no_rest_spread_properties;
+    pub mod no_stacked_next_line_directives;
+    pub mod no_stale_directives;
+    pub mod no_stripped_directives;
     pub mod no_this_in_exported_function;
+    pub mod no_unsupported_directive_rules;
+    pub mod no_unterminated_disable_comments;
     pub mod number_arg_out_of_range;
     pub mod only_used_in_recursion;
+    pub mod require_directive_ticket;
     pub mod uninvoked_array_callback;
 }
 
@@ -739,6 +759,7 @@ pub(crate) mod jsdoc {
     pub mod require_returns;
     pub mod require_returns_description;
     pub mod require_returns_type;
+    pub mod require_throws;
     pub mod require_throws_description;
     pub mod require_throws_type;
     pub mod require_yields;
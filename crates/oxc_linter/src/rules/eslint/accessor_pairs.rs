@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use oxc_ast::{
     AstKind,
     ast::{
@@ -19,18 +21,22 @@ use crate::{
     rule::{DefaultRuleConfig, Rule},
 };
 
-fn setter_without_getter_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Setter is defined without a getter")
+fn setter_without_getter_diagnostic(name: Option<&str>, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Setter{} is defined without a getter", quoted_name(name)))
         .with_help("Define a getter for this property")
         .with_label(span)
 }
 
-fn getter_without_setter_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Getter is defined without a setter")
+fn getter_without_setter_diagnostic(name: Option<&str>, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Getter{} is defined without a setter", quoted_name(name)))
         .with_help("Define a setter for this property")
         .with_label(span)
 }
 
+fn quoted_name(name: Option<&str>) -> String {
+    name.filter(|name| !name.is_empty()).map_or_else(String::new, |name| format!(" `{name}`"))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct AccessorPairsConfig {
@@ -252,10 +258,11 @@ impl AccessorPairs {
             });
 
             if !has_pair {
+                let name = Some(computed_key_name(key1, ctx));
                 if *kind1 == PropertyKind::Set && self.set_without_get {
-                    ctx.diagnostic(setter_without_getter_diagnostic(*span1));
+                    ctx.diagnostic(setter_without_getter_diagnostic(name.as_deref(), *span1));
                 } else if *kind1 == PropertyKind::Get && self.get_without_set {
-                    ctx.diagnostic(getter_without_setter_diagnostic(*span1));
+                    ctx.diagnostic(getter_without_setter_diagnostic(name.as_deref(), *span1));
                 }
             }
         }
@@ -272,10 +279,11 @@ impl AccessorPairs {
             });
 
             if !has_pair {
+                let name = Some(computed_key_name(key1, ctx));
                 if *kind1 == MethodDefinitionKind::Set && self.set_without_get {
-                    ctx.diagnostic(setter_without_getter_diagnostic(*span1));
+                    ctx.diagnostic(setter_without_getter_diagnostic(name.as_deref(), *span1));
                 } else if *kind1 == MethodDefinitionKind::Get && self.get_without_set {
-                    ctx.diagnostic(getter_without_setter_diagnostic(*span1));
+                    ctx.diagnostic(getter_without_setter_diagnostic(name.as_deref(), *span1));
                 }
             }
         }
@@ -286,13 +294,16 @@ impl AccessorPairs {
         accessors: &FxHashMap<String, AccessorInfo>,
         ctx: &LintContext,
     ) {
-        for info in accessors.values() {
+        // Report in source order
+        let mut accessors = accessors.iter().collect::<Vec<_>>();
+        accessors.sort_unstable_by_key(|(_, info)| info.getter.or(info.setter));
+        for (name, info) in accessors {
             match (info.getter, info.setter) {
                 (None, Some(setter_span)) if self.set_without_get => {
-                    ctx.diagnostic(setter_without_getter_diagnostic(setter_span));
+                    ctx.diagnostic(setter_without_getter_diagnostic(Some(name), setter_span));
                 }
                 (Some(getter_span), None) if self.get_without_set => {
-                    ctx.diagnostic(getter_without_setter_diagnostic(getter_span));
+                    ctx.diagnostic(getter_without_setter_diagnostic(Some(name), getter_span));
                 }
                 _ => {}
             }
@@ -317,7 +328,11 @@ impl AccessorPairs {
                 if call.arguments.len() >= 3
                     && let Some(Argument::ObjectExpression(descriptor)) = call.arguments.get(2)
                 {
-                    self.check_property_descriptor(descriptor, ctx);
+                    let name = match call.arguments.get(1) {
+                        Some(Argument::StringLiteral(lit)) => Some(lit.value.as_str()),
+                        _ => None,
+                    };
+                    self.check_property_descriptor(descriptor, name, ctx);
                 }
             }
             DefinePropertyCallee::DefineProperties | DefinePropertyCallee::Create => {
@@ -328,7 +343,8 @@ impl AccessorPairs {
                         if let ObjectPropertyKind::ObjectProperty(prop) = prop
                             && let Expression::ObjectExpression(descriptor) = &prop.value
                         {
-                            self.check_property_descriptor(descriptor, ctx);
+                            let name = prop.key.static_name();
+                            self.check_property_descriptor(descriptor, name.as_deref(), ctx);
                         }
                     }
                 }
@@ -374,7 +390,13 @@ impl AccessorPairs {
         }
     }
 
-    fn check_property_descriptor(&self, descriptor: &ObjectExpression, ctx: &LintContext) {
+    /// `name` is the name of the property defined with `descriptor`, if known.
+    fn check_property_descriptor(
+        &self,
+        descriptor: &ObjectExpression,
+        name: Option<&str>,
+        ctx: &LintContext,
+    ) {
         let mut has_get = false;
         let mut has_set = false;
         let mut set_span = None;
@@ -405,7 +427,7 @@ impl AccessorPairs {
             && self.set_without_get
             && let Some(span) = set_span
         {
-            ctx.diagnostic(setter_without_getter_diagnostic(span));
+            ctx.diagnostic(setter_without_getter_diagnostic(name, span));
         }
     }
 
@@ -483,12 +505,13 @@ impl AccessorPairs {
                 continue;
             }
 
+            let name = Some(computed_key_name(key, ctx));
             match kind {
                 TSMethodSignatureKind::Set if self.set_without_get => {
-                    ctx.diagnostic(setter_without_getter_diagnostic(*span));
+                    ctx.diagnostic(setter_without_getter_diagnostic(name.as_deref(), *span));
                 }
                 TSMethodSignatureKind::Get if self.get_without_set => {
-                    ctx.diagnostic(getter_without_setter_diagnostic(*span));
+                    ctx.diagnostic(getter_without_setter_diagnostic(name.as_deref(), *span));
                 }
                 _ => {}
             }
@@ -496,6 +519,11 @@ impl AccessorPairs {
     }
 }
 
+/// Name of a key without a static name for diagnostics, e.g. `#foo` or `[foo]`.
+fn computed_key_name(key: &PropertyKey, ctx: &LintContext) -> String {
+    key.name().map_or_else(|| format!("[{}]", ctx.source_range(key.span())), Cow::into_owned)
+}
+
 enum DefinePropertyCallee {
     DefineProperty,
     DefineProperties,
@@ -158,6 +158,16 @@ declare_oxc_lint!(
     ///   return a
     /// });
     /// ```
+    ///
+    /// ### Known false positives
+    ///
+    /// This rule cannot know the type of the receiver, so any call of a method with one of
+    /// the checked names is treated as an array method, e.g. `myCollection.map(cb)` on a
+    /// user-defined class. This matches the behavior of the ESLint rule.
+    ///
+    /// The checked methods are `every`, `filter`, `find`, `findIndex`, `findLast`,
+    /// `findLastIndex`, `flatMap`, `forEach`, `map`, `reduce`, `reduceRight`, `some`, `sort`,
+    /// `toSorted`, as well as `Array.from` and `Array.fromAsync`.
     ArrayCallbackReturn,
     eslint,
     pedantic,
@@ -336,8 +346,8 @@ pub fn get_array_method_info<'a>(
                 // Array.from
                 if callee.is_specific_member_access("Array", "from") {
                     // Check that current node is parent's second argument
-                    if call.arguments.len() == 2
-                        && let Some(call_arg) = call.arguments[1].as_expression()
+                    if let Some(call_arg) =
+                        call.arguments.get(1).and_then(|arg| arg.as_expression())
                         && call_arg.span() == current_node.kind().span()
                     {
                         return Some((callee.span(), "from"));
@@ -347,8 +357,8 @@ pub fn get_array_method_info<'a>(
                 // Array.fromAsync
                 if callee.is_specific_member_access("Array", "fromAsync") {
                     // Check that current node is parent's second argument
-                    if call.arguments.len() == 2
-                        && let Some(call_arg) = call.arguments[1].as_expression()
+                    if let Some(call_arg) =
+                        call.arguments.get(1).and_then(|arg| arg.as_expression())
                         && call_arg.span() == current_node.kind().span()
                     {
                         return Some((callee.span(), "fromAsync"));
@@ -358,13 +368,13 @@ pub fn get_array_method_info<'a>(
                 // "methods",
                 let (array_method_span, array_method) = callee.static_property_info()?;
 
+                // Only the first argument is the callback. Extra arguments such as the
+                // initial value of `reduce` or `thisArg` of `map` don't matter.
                 if TARGET_METHODS.contains(&array_method)
-                    // Check that current node is parent's first argument
-                    && call.arguments.len() == 1
                     && let Some(call_arg) = call.arguments.first()
-                        && call_arg
-                            .as_expression()
-                            .is_some_and(|arg| arg.span() == current_node.kind().span())
+                    && call_arg
+                        .as_expression()
+                        .is_some_and(|arg| arg.span() == current_node.kind().span())
                 {
                     return Some((array_method_span, array_method));
                 }
@@ -624,9 +634,20 @@ fn test() {
 }",
             None,
         ),
+        ("foo.reduce(function(acc, x) { return acc + x; }, 0)", None),
+        ("foo.map(function() { return this.x; }, thisArg)", None),
+        ("foo.reduce(initial, function() {})", None),
+        ("foo.forEach(function(x) { return x; }, thisArg)", None),
     ];
 
     let fail = vec![
+        ("foo.reduce(function(acc, x) { acc.push(x); }, [])", None),
+        ("foo.reduceRight((acc, x) => { acc.push(x); }, [])", None),
+        ("foo.map(function() {}, thisArg)", None),
+        (
+            "foo.forEach(function(x) { return x; }, thisArg)",
+            Some(serde_json::json!([{"checkForEach": true}])),
+        ),
         ("Array.from(x, function() {})", None),
         ("Array.from(x, function foo() {})", None),
         // ("Int32Array.from(x, function() {})", None),
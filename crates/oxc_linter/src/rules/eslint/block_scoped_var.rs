@@ -1,23 +1,13 @@
-use crate::{AstNode, context::LintContext, rule::Rule};
-use oxc_ast::{
-    AstKind,
-    ast::{BindingPattern, VariableDeclarationKind},
-};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::Scoping;
-use oxc_span::{GetSpan, Span};
-use oxc_str::Ident;
-use oxc_syntax::{scope::ScopeId, symbol::SymbolId};
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{references_outside_block, var_declaring_block},
+};
 
-fn redeclaration_diagnostic(decl_span: Span, redeclare_span: Span, name: &str) -> OxcDiagnostic {
-    OxcDiagnostic::warn(format!("'{name}' is used outside of binding context."))
-        .with_help(format!("Variable '{name}' is used outside its declaration block. Declare it outside the block or use 'let'/'const'."))
-        .with_labels([
-            redeclare_span.label("it is redeclared here"),
-            decl_span.label(format!("'{name}' is first declared here")),
-        ])
-}
 fn use_outside_scope_diagnostic(decl_span: Span, used_span: Span, name: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("'{name}' is used outside of binding context."))
         .with_help(format!("Variable '{name}' is used outside its declaration block. Declare it outside the block or use 'let'/'const'."))
@@ -36,6 +26,10 @@ declare_oxc_lint!(
     /// Enforces that variables are both **declared** and **used** within the same block scope.
     /// This rule prevents accidental use of variables outside their intended block, mimicking C-style block scoping in JavaScript.
     ///
+    /// A `var` declared several times is scoped to the innermost block containing all of its
+    /// declarations, and a `var` declared in the head of a `for` loop is scoped to the loop.
+    /// Function declarations and parameters sharing the name of a `var` are not checked.
+    ///
     /// ### Why is this bad?
     ///
     /// JavaScript’s `var` declarations are hoisted to the top of their enclosing function, which can cause variables declared in a block (e.g., inside an `if` or `for`) to be accessible outside of it.
@@ -128,106 +122,26 @@ declare_oxc_lint!(
 );
 
 impl Rule for BlockScopedVar {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::VariableDeclaration(decl) = node.kind() else {
-            return;
-        };
-        if decl.kind != VariableDeclarationKind::Var {
-            return;
-        }
-        let node_scope_id = node.scope_id();
-
-        // Exit early for top-level `var` declarations.
-        //
-        // Top-level `var` bindings are not block-scoped, so this rule intentionally
-        // does not perform "used outside its declaration block" checks for them.
-        // This is a deliberate semantic choice (not just a performance optimization):
-        // only non–top-level `var` declarations are subject to block-scope validation.
-        if ctx.scoping().scope_flags(node_scope_id).is_top() {
-            return;
-        }
-
-        for item in &decl.declarations {
-            run_for_declaration(&item.id, node_scope_id, ctx);
+    fn run_once(&self, ctx: &LintContext) {
+        let scoping = ctx.scoping();
+        for symbol_id in scoping.symbol_ids() {
+            if !scoping.symbol_flags(symbol_id).is_function_scoped_declaration() {
+                continue;
+            }
+            let Some(block) = var_declaring_block(symbol_id, ctx) else {
+                continue;
+            };
+            for reference in references_outside_block(symbol_id, block, ctx) {
+                ctx.diagnostic(use_outside_scope_diagnostic(
+                    scoping.symbol_span(symbol_id),
+                    ctx.reference_span(reference),
+                    scoping.symbol_name(symbol_id),
+                ));
+            }
         }
     }
 }
 
-fn run_for_all_references(
-    (pattern, name, symbol): (&BindingPattern, Ident<'_>, &SymbolId),
-    declare_scope_id: ScopeId,
-    ctx: &LintContext,
-) {
-    ctx.scoping()
-        .get_resolved_references(*symbol)
-        .filter(|reference| {
-            let reference_scope_id = reference.scope_id();
-            check_if_has_reference_outside_scope(
-                declare_scope_id,
-                reference_scope_id,
-                ctx.scoping(),
-            )
-        })
-        .for_each(|reference| {
-            ctx.diagnostic(use_outside_scope_diagnostic(
-                pattern.span(),
-                ctx.reference_span(reference),
-                &name,
-            ));
-        });
-}
-
-fn run_for_all_redeclarations(
-    (pattern, name, symbol): (&BindingPattern, Ident<'_>, &SymbolId),
-    declare_scope_id: ScopeId,
-    ctx: &LintContext,
-) {
-    ctx.scoping()
-        .symbol_redeclarations(*symbol)
-        .iter()
-        .filter(|redeclaration| {
-            let redeclare_scope_id = ctx.nodes().get_node(redeclaration.declaration).scope_id();
-            check_if_has_reference_outside_scope(
-                declare_scope_id,
-                redeclare_scope_id,
-                ctx.scoping(),
-            )
-        })
-        .for_each(|redeclaration| {
-            ctx.diagnostic(redeclaration_diagnostic(pattern.span(), redeclaration.span, &name));
-        });
-}
-
-fn run_for_declaration(pattern: &BindingPattern, node_scope_id: ScopeId, ctx: &LintContext) {
-    // e.g. "var [a, b] = [1, 2]"
-    for ident in pattern.get_binding_identifiers() {
-        let name = ident.name;
-        let Some(symbol) = ctx.scoping().find_binding(node_scope_id, name) else {
-            continue;
-        };
-
-        let binding = (pattern, name, &symbol);
-
-        // e.g. "if (true) { var a = 4; } else { var a = 4; }"
-        // in this case we can't find the reference of 'a' by call `get_resolved_references`
-        // so I use `symbol_redeclarations` to find all the redeclarations
-        run_for_all_redeclarations(binding, node_scope_id, ctx);
-
-        // e.g. "var a = 4; console.log(a);"
-        run_for_all_references(binding, node_scope_id, ctx);
-    }
-}
-
-/// Returns true if the reference is outside the declaration scope
-fn check_if_has_reference_outside_scope(
-    declare_scope_id: ScopeId,
-    reference_scope_id: ScopeId,
-    scoping: &Scoping,
-) -> bool {
-    reference_scope_id != declare_scope_id
-        && !scoping.scope_is_descendant_of(reference_scope_id, declare_scope_id)
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -326,6 +240,31 @@ fn test() {
                 }
             }
         ",
+        r"
+            if (true) {
+                {
+                    var a = 4, b = 3;
+                }
+                var a = 4;
+            }
+        ",
+        "if (true) { var a = 1; } else { var a = 2; }",
+        "for (var i = 0;;) {} for(var i = 0;;) {}",
+        "if (foo) { var a = 1; } else if (bar) { var a = 2; } else { var a = 3; }",
+        r"
+            function doIfElse() {
+                if (true) {
+                    var build = true;
+                } else {
+                    var build = false;
+                }
+            }
+        ",
+        "function f() { if (a) { var x; } else { var x; x; } }",
+        "function f(a) { { var a; } a; }",
+        "function f() { { var g; } function g() {} g; }",
+        "function f() { for (var i = 0; i < 10; i++) { i; } }",
+        "function f() { for (var i in {}) {} for (var i in {}) {} i; }",
     ];
 
     let fail = vec![
@@ -350,14 +289,6 @@ fn test() {
             }
             console.log(a, b);
         ",
-        r"
-            if (true) {
-                {
-                    var a = 4, b = 3;
-                }
-                var a = 4;
-            }
-        ",
         "function f(){ x; { var x; } }",
         "function f(){ { var x; } x; }",
         "function f() { var a; { var b = 0; } a = b; }",
@@ -370,11 +301,8 @@ fn test() {
         "for (var a of []) {} a;",
         "{ var a = 0; } a;",
         "if (true) { var a; } a;",
-        "if (true) { var a = 1; } else { var a = 2; }",
-        "for (var i = 0;;) {} for(var i = 0;;) {}",
         "class C { static { if (bar) { var foo; } foo; } }",
         "{ var foo,  bar; } bar;",
-        "if (foo) { var a = 1; } else if (bar) { var a = 2; } else { var a = 3; }",
         r"
             if (true) {
                 var build = true;
@@ -398,15 +326,6 @@ fn test() {
                 console.log(build);
             }
         ",
-        r"
-            function doIfElse() {
-                if (true) {
-                    var build = true;
-                } else {
-                    var build = false;
-                }
-            }
-        ",
         r"
             function doTryCatch() {
                 try {
@@ -434,6 +353,8 @@ fn test() {
                 }
             }
         ",
+        "function f() { if (a) { if (b) { var x; } else { var x; } } x; }",
+        "function f() { if (a) { for (var i in {}) {} for (var i in {}) {} } i; }",
     ];
 
     Tester::new(BlockScopedVar::NAME, BlockScopedVar::PLUGIN, pass, fail)
@@ -65,6 +65,17 @@ declare_oxc_lint!(
     /// function combine(a: number, b: number = 2, c: number = 3) {}
     /// function combine(a: number, b?: number, c: number = 3) {}
     /// ```
+    ///
+    /// The same ordering is checked for function and constructor types, method and call
+    /// signatures, and abstract methods, where only optional parameters can appear:
+    /// ```ts
+    /// /* default-param-last: "error" */
+    ///
+    /// type Callback = (error?: Error, value: string) => void; // incorrect
+    /// interface Api {
+    ///   fetch(id: string, init?: RequestInit): Promise<Response>; // correct
+    /// }
+    /// ```
     DefaultParamLast,
     eslint,
     style,
@@ -79,6 +90,15 @@ impl Rule for DefaultParamLast {
                 check_params(&function.params.items, ctx);
             }
             AstKind::ArrowFunctionExpression(function) => check_params(&function.params.items, ctx),
+            AstKind::TSFunctionType(func_type) => check_params(&func_type.params.items, ctx),
+            AstKind::TSConstructorType(ctor_type) => check_params(&ctor_type.params.items, ctx),
+            AstKind::TSMethodSignature(signature) => check_params(&signature.params.items, ctx),
+            AstKind::TSCallSignatureDeclaration(signature) => {
+                check_params(&signature.params.items, ctx);
+            }
+            AstKind::TSConstructSignatureDeclaration(signature) => {
+                check_params(&signature.params.items, ctx);
+            }
             _ => {}
         }
     }
@@ -213,6 +233,20 @@ fn test() {
                 c = 0,
             ) {}
         }",
+        "type F = (a: number, b?: number) => void;",
+        "type F = (a?: number, ...rest: number[]) => void;",
+        "type F = new (a: number, b?: number) => Foo;",
+        "interface Foo { bar(a: number, b?: number): void; }",
+        "interface Foo { (a: number, b?: number): void; }",
+        "interface Foo { new (a: number, b?: number): Foo; }",
+        "const foo: { bar(a: string, b?: string): void } = baz;",
+        "abstract class Foo { abstract bar(a: number, b?: number): void; }",
+        "declare function foo(a: number, b?: number): void;",
+        "
+        class Foo {
+            bar(a: number, b?: number): void;
+            bar(a: number, b = 1) {}
+        }",
     ];
 
     let fail = vec![
@@ -232,9 +266,9 @@ fn test() {
         "function foo(a = 1, b: number, c = 2, d: number) {}",
         "function foo(a = 1, b: number, c = 2) {}",
         "function foo(a = 1, b: number, ...c) {}",
-        // "function foo(a?: number, b: number) {}",
-        // "function foo(a: number, b?: number, c: number) {}",
-        // "function foo(a = 1, b?: number, c: number) {}",
+        "function foo(a?: number, b: number) {}",
+        "function foo(a: number, b?: number, c: number) {}",
+        "function foo(a = 1, b?: number, c: number) {}",
         "function foo(a = 1, { b }) {}",
         "function foo({ a } = {}, b) {}",
         "function foo({ a, b } = { a: 1, b: 2 }, c) {}",
@@ -244,8 +278,8 @@ fn test() {
         "const foo = function (a = 1, b: number, c = 2) {};",
         "const foo = function (a = 1, b: number, ...c) {};",
         "const foo = function (a?: number, b: number) {};",
-        // "const foo = function (a: number, b?: number, c: number) {};",
-        // "const foo = function (a = 1, b?: number, c: number) {};",
+        "const foo = function (a: number, b?: number, c: number) {};",
+        "const foo = function (a = 1, b?: number, c: number) {};",
         "const foo = function (a = 1, { b }) {};",
         "const foo = function ({ a } = {}, b) {};",
         "const foo = function ({ a, b } = { a: 1, b: 2 }, c) {};",
@@ -254,8 +288,8 @@ fn test() {
         "const foo = (a = 1, b: number, c = 2, d: number) => {};",
         "const foo = (a = 1, b: number, c = 2) => {};",
         "const foo = (a = 1, b: number, ...c) => {};",
-        // "const foo = (a?: number, b: number) => {};",
-        // "const foo = (a: number, b?: number, c: number) => {};",
+        "const foo = (a?: number, b: number) => {};",
+        "const foo = (a: number, b?: number, c: number) => {};",
         "const foo = (a = 1, b?: number, c: number) => {};",
         "
         class Foo {
@@ -277,6 +311,21 @@ fn test() {
                 private b: number,
             ) {}
         }",
+        "type F = (a?: number, b: number) => void;",
+        "type F = (a?: number, b?: number, c: number) => void;",
+        "type F = (a = 1, b: number) => void;",
+        "type F = new (a?: number, b: number) => Foo;",
+        "interface Foo { bar(a?: number, b: number): void; }",
+        "interface Foo { (a?: number, b: number): void; }",
+        "interface Foo { new (a?: number, b: number): Foo; }",
+        "const foo: { bar(a?: string, b: string): void } = baz;",
+        "abstract class Foo { abstract bar(a?: number, b: number): void; }",
+        "declare function foo(a?: number, b: number): void;",
+        "
+        class Foo {
+            bar(a?: number, b: number): void;
+            bar(a?: number, b?: number) {}
+        }",
     ];
 
     Tester::new(DefaultParamLast::NAME, DefaultParamLast::PLUGIN, pass, fail).test_and_snapshot();
@@ -1,9 +1,20 @@
-use oxc_ast::{AstKind, ast::Statement};
+use oxc_ast::{
+    AstKind,
+    ast::{
+        BindingPattern, Expression, ForInStatement, ForStatementLeft, MemberExpression, Statement,
+    },
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use oxc_str::static_ident;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+};
 
 fn guard_for_in_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Require `for-in` loops to include an `if` statement")
@@ -59,52 +70,151 @@ declare_oxc_lint!(
     ///    }
     /// }
     /// ```
+    ///
+    /// Two suggestions are offered: wrapping the loop body in an `Object.hasOwn` check, or
+    /// starting it with `if (!Object.hasOwn(foo, key)) continue;`. They are not offered when the
+    /// object expression would have side effects if evaluated again, such as a function call.
     GuardForIn,
     eslint,
     style,
+    suggestion,
     version = "0.2.14",
     short_description = "Require for-in loops to include an if statement.",
 );
 
 impl Rule for GuardForIn {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        if let AstKind::ForInStatement(for_in_statement) = node.kind() {
-            match &for_in_statement.body {
-                Statement::EmptyStatement(_) | Statement::IfStatement(_) => return,
-                Statement::BlockStatement(block_body) if block_body.body.is_empty() => return,
-                Statement::BlockStatement(block_body)
-                    if block_body.body.len() == 1
-                        && matches!(block_body.body[0], Statement::IfStatement(_)) =>
-                {
-                    return;
-                }
-                Statement::BlockStatement(block_body) if !block_body.body.is_empty() => {
-                    let block_statement = &block_body.body[0];
-                    if let Statement::IfStatement(i) = block_statement {
-                        if let Statement::ContinueStatement(_) = &i.consequent {
-                            return;
-                        }
-                        if let Statement::BlockStatement(consequent_block) = &i.consequent
-                            && consequent_block.body.len() == 1
-                            && matches!(&consequent_block.body[0], Statement::ContinueStatement(_))
-                        {
-                            return;
-                        }
-                    }
+        let AstKind::ForInStatement(for_in_statement) = node.kind() else {
+            return;
+        };
+        if is_guarded(&for_in_statement.body) {
+            return;
+        }
+
+        let diagnostic = guard_for_in_diagnostic(Span::new(
+            for_in_statement.span.start,
+            for_in_statement.right.span().end + 1,
+        ));
+        // The suggestions refer to the global `Object`.
+        if ctx.scoping().find_binding(node.scope_id(), static_ident!("Object")).is_some() {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        let Some((object, key)) = guard_operands(for_in_statement, ctx) else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+
+        let fixer = RuleFixer::new(FixKind::Suggestion, ctx);
+        ctx.diagnostic_with_suggestions(
+            diagnostic,
+            [
+                wrap_body_fix(fixer, &for_in_statement.body, object, key),
+                continue_guard_fix(fixer, &for_in_statement.body, object, key),
+            ],
+        );
+    }
+}
+
+/// Whether `body` starts with an `if` statement guarding it, in the forms ESLint accepts:
+/// a single `if` statement, or an `if` statement whose consequent is only `continue`.
+/// Empty bodies need no guard.
+fn is_guarded(body: &Statement) -> bool {
+    match body {
+        Statement::EmptyStatement(_) | Statement::IfStatement(_) => true,
+        Statement::BlockStatement(block) => match block.body.as_slice() {
+            [] | [Statement::IfStatement(_)] => true,
+            [Statement::IfStatement(if_statement), ..] => match &if_statement.consequent {
+                Statement::ContinueStatement(_) => true,
+                Statement::BlockStatement(consequent) => {
+                    matches!(consequent.body.as_slice(), [Statement::ContinueStatement(_)])
                 }
-                _ => {}
+                _ => false,
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// The source text of the object and key of `for_in_statement`, if both can be repeated in a
+/// guard. The key must be an identifier, and the object must not have side effects.
+fn guard_operands<'a>(
+    for_in_statement: &ForInStatement<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(&'a str, &'a str)> {
+    let key_span = match &for_in_statement.left {
+        ForStatementLeft::VariableDeclaration(declaration) => {
+            match declaration.declarations.as_slice() {
+                [declarator] => match &declarator.id {
+                    BindingPattern::BindingIdentifier(ident) => ident.span,
+                    _ => return None,
+                },
+                _ => return None,
             }
-            ctx.diagnostic(guard_for_in_diagnostic(Span::new(
-                for_in_statement.span.start,
-                for_in_statement.right.span().end + 1,
-            )));
         }
+        ForStatementLeft::AssignmentTargetIdentifier(ident) => ident.span,
+        _ => return None,
+    };
+    if !is_repeatable(&for_in_statement.right) {
+        return None;
     }
+    Some((ctx.source_range(for_in_statement.right.span()), ctx.source_range(key_span)))
+}
+
+/// Whether evaluating `expr` again has no side effects: identifiers, `this`, and member
+/// accesses on them with identifier or literal keys.
+fn is_repeatable(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::Identifier(_) | Expression::ThisExpression(_) => true,
+        expr => match expr.as_member_expression() {
+            Some(MemberExpression::StaticMemberExpression(member)) => is_repeatable(&member.object),
+            Some(MemberExpression::ComputedMemberExpression(member)) => {
+                is_repeatable(&member.object)
+                    && (member.expression.is_literal()
+                        || matches!(member.expression, Expression::Identifier(_)))
+            }
+            Some(MemberExpression::PrivateFieldExpression(member)) => is_repeatable(&member.object),
+            None => false,
+        },
+    }
+}
+
+/// `for (key in obj) if (Object.hasOwn(obj, key)) { ... }`
+fn wrap_body_fix(fixer: RuleFixer, body: &Statement, object: &str, key: &str) -> RuleFix {
+    let guard = format!("if (Object.hasOwn({object}, {key}))");
+    let fix = if let Statement::BlockStatement(block) = body {
+        fixer.insert_text_before(block.as_ref(), format!("{guard} "))
+    } else {
+        fixer.replace(body.span(), format!("{guard} {{ {} }}", fixer.source_range(body.span())))
+    };
+    fix.with_message(format!("Wrap the loop body in `{guard}`"))
+}
+
+/// `for (key in obj) { if (!Object.hasOwn(obj, key)) continue; ... }`
+fn continue_guard_fix(fixer: RuleFixer, body: &Statement, object: &str, key: &str) -> RuleFix {
+    let guard = format!("if (!Object.hasOwn({object}, {key})) continue;");
+    let fix = match body {
+        Statement::BlockStatement(block) if let Some(first) = block.body.first() => {
+            let first_start = first.span().start;
+            let before_first = fixer.source_range(Span::new(block.span.start, first_start));
+            // Put the guard on its own line if the first statement is on its own line.
+            let separator = match before_first.rfind('\n') {
+                Some(newline) => format!("\n{}", &before_first[newline + 1..]),
+                None => " ".to_string(),
+            };
+            fixer.insert_text_before(first, format!("{guard}{separator}"))
+        }
+        _ => {
+            fixer.replace(body.span(), format!("{{ {guard} {} }}", fixer.source_range(body.span())))
+        }
+    };
+    fix.with_message(format!("Skip inherited properties with `{guard}`"))
 }
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{ExpectFixTestCase, Tester};
 
     let pass = vec![
         "for (var x in o);",
@@ -122,7 +232,59 @@ fn test() {
         "for (var x in o) { if (x) f(); g(); }",
         "for (var x in o) { foo() }",
         "for (var x in o) foo();",
+        "for (const key in obj) { if (!Object.hasOwn(obj, key)) { f(); } g(); }",
+        "for (key in getObject()) { f(key); }",
+        "for (const [a, b] in obj) { f(a); }",
+        "const Object = {}; for (const key in obj) { f(key); }",
+    ];
+
+    let fix: Vec<ExpectFixTestCase> = vec![
+        (
+            "for (const key in obj) { f(key); }",
+            (
+                "for (const key in obj) if (Object.hasOwn(obj, key)) { f(key); }",
+                "for (const key in obj) { if (!Object.hasOwn(obj, key)) continue; f(key); }",
+            ),
+        )
+            .into(),
+        (
+            "for (var x in o) foo();",
+            (
+                "for (var x in o) if (Object.hasOwn(o, x)) { foo(); }",
+                "for (var x in o) { if (!Object.hasOwn(o, x)) continue; foo(); }",
+            ),
+        )
+            .into(),
+        (
+            "for (key in this.options) {\n    if (key) { f(); }\n    g(key);\n}",
+            (
+                "for (key in this.options) if (Object.hasOwn(this.options, key)) {\n    if (key) { f(); }\n    g(key);\n}",
+                "for (key in this.options) {\n    if (!Object.hasOwn(this.options, key)) continue;\n    if (key) { f(); }\n    g(key);\n}",
+            ),
+        )
+            .into(),
+        (
+            "for (const k in (a.b[c])) g(k);",
+            (
+                "for (const k in (a.b[c])) if (Object.hasOwn((a.b[c]), k)) { g(k); }",
+                "for (const k in (a.b[c])) { if (!Object.hasOwn((a.b[c]), k)) continue; g(k); }",
+            ),
+        )
+            .into(),
+        // The object would be evaluated again.
+        ("for (key in getObject()) { f(key); }", "for (key in getObject()) { f(key); }", None)
+            .into(),
+        ("for (key in a[b()]) f(key);", "for (key in a[b()]) f(key);", None).into(),
+        ("for (const [a, b] in obj) { f(a); }", "for (const [a, b] in obj) { f(a); }", None).into(),
+        (
+            "const Object = {}; for (const key in obj) { f(key); }",
+            "const Object = {}; for (const key in obj) { f(key); }",
+            None,
+        )
+            .into(),
     ];
 
-    Tester::new(GuardForIn::NAME, GuardForIn::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(GuardForIn::NAME, GuardForIn::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
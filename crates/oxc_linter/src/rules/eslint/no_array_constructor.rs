@@ -8,7 +8,12 @@ use oxc_semantic::IsGlobalReference;
 use oxc_span::{GetSpan, Span};
 use oxc_str::static_ident;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    ast_util::{is_start_of_expression_statement, needs_preceding_semicolon},
+    context::LintContext,
+    rule::Rule,
+};
 
 fn no_array_constructor_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Avoid calls to the `Array` constructor")
@@ -56,25 +61,17 @@ declare_oxc_lint!(
 
 impl Rule for NoArrayConstructor {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let (span, callee, arguments, type_parameters, optional) = match node.kind() {
-            AstKind::CallExpression(call_expr) => (
-                call_expr.span,
-                &call_expr.callee,
-                &call_expr.arguments,
-                &call_expr.type_arguments,
-                call_expr.optional,
-            ),
-            AstKind::NewExpression(new_expr) => (
-                new_expr.span,
-                &new_expr.callee,
-                &new_expr.arguments,
-                &new_expr.type_arguments,
-                false,
-            ),
+        let (span, callee, arguments, type_parameters) = match node.kind() {
+            AstKind::CallExpression(call_expr) => {
+                (call_expr.span, &call_expr.callee, &call_expr.arguments, &call_expr.type_arguments)
+            }
+            AstKind::NewExpression(new_expr) => {
+                (new_expr.span, &new_expr.callee, &new_expr.arguments, &new_expr.type_arguments)
+            }
             _ => return,
         };
 
-        let Expression::Identifier(ident) = &callee else {
+        let Expression::Identifier(ident) = callee.without_parentheses() else {
             return;
         };
 
@@ -85,18 +82,26 @@ impl Rule for NoArrayConstructor {
         if ident.is_global_reference_name(static_ident!("Array"), ctx.scoping())
             && (arg_len != 1 || last_arg_is_spread)
             && type_parameters.is_none()
-            && !optional
         {
             ctx.diagnostic_with_fix(no_array_constructor_diagnostic(span), |fixer| {
                 if arg_len <= 2 && last_arg_is_spread {
                     return fixer.noop();
                 }
-                let replacement = if arg_len == 0 {
-                    ""
+                // Keep everything between the parentheses, including comments.
+                let elements = ctx.find_next_token_within(callee.span().end, span.end, "(").map_or(
+                    "",
+                    |offset| {
+                        ctx.source_range(Span::new(callee.span().end + offset + 1, span.end - 1))
+                    },
+                );
+                let semicolon = if is_start_of_expression_statement(node, ctx.nodes())
+                    && needs_preceding_semicolon(ctx.semantic(), node)
+                {
+                    ";"
                 } else {
-                    ctx.source_range(Span::new(arguments[0].span().start, span.end - 1))
+                    ""
                 };
-                fixer.replace(span, format!("[{replacement}]"))
+                fixer.replace(span, format!("{semicolon}[{elements}]"))
             });
         }
     }
@@ -148,22 +153,20 @@ fn test() {
         "new Array",
         "new Array(x, y)",
         "new Array(0, 1, 2)",
-        // TODO: Catch optional chaining cases:
-        // "const array = Array?.();",
-        // TODO: Fix this case:
-        // "
-        // const array = (Array)(
-        //     /* foo */ a,
-        //     b = c() // bar
-        // );
-        // ",
+        "const array = Array?.();",
+        "
+        const array = (Array)(
+            /* foo */ a,
+            b = c() // bar
+        );
+        ",
         "const array = Array(...args);",
         "const array = Array(...foo, ...bar);",
         "const array = new Array(...args);",
         "const array = Array(5, ...args);",
         "const array = Array(5, 6, ...args);",
-        // "a = new (Array);",
-        // "a = new (Array) && (foo);",
+        "a = new (Array);",
+        "a = new (Array) && (foo);",
         "/*a*/Array()",
         "/*a*/Array()/*b*/",
         "Array/*a*/()",
@@ -178,22 +181,22 @@ fn test() {
         "new Array(/*a*/ /*b*/)",
         "new Array(/*a*/ x /*b*/, /*c*/ y /*d*/)",
         "new/*a*/Array(/*b*/ x /*c*/, /*d*/ y /*e*/)/*f*/;/*g*/",
-        // "new (Array /* a */);",
-        // "(/* a */ Array)(1, 2, 3);",
-        // "(Array /* a */)(1, 2, 3);",
-        // "(Array) /* a */ (1, 2, 3);",
-        // "(/* a */(Array))();",
-        // "Array?.(0, 1, 2).forEach(doSomething);",
+        "new (Array /* a */);",
+        "(/* a */ Array)(1, 2, 3);",
+        "(Array /* a */)(1, 2, 3);",
+        "(Array) /* a */ (1, 2, 3);",
+        "(/* a */(Array))();",
+        "Array?.(0, 1, 2).forEach(doSomething);",
         "new Array();",
         "Array();",
         "new Array(x, y);",
         "Array(x, y);",
         "new Array(0, 1, 2);",
         "Array(0, 1, 2);",
-        // "Array?.(0, 1, 2);",
-        // "Array?.(x, y);",
-        // "Array /*a*/ ?.();",
-        // "Array?./*a*/();",
+        "Array?.(0, 1, 2);",
+        "Array?.(x, y);",
+        "Array /*a*/ ?.();",
+        "Array?./*a*/();",
         r#"
                         (function () {
                             Fn
@@ -217,82 +220,86 @@ fn test() {
         ("new Array", "[]"),
         ("new Array(x, y)", "[x, y]"),
         ("new Array(0, 1, 2)", "[0, 1, 2]"),
-        // TODO: Catch this case and fix it:
-        // (
-        //     "
-        //                         const array = (Array)(
-        //                             /* foo */ a,
-        //                             b = c() // bar
-        //                         );
-        //                         ",
-        //     "
-        //                         const array = [
-        //                             /* foo */ a,
-        //                             b = c() // bar
-        //                         ];
-        //                         ",
-        // ),
+        ("const array = Array?.();", "const array = [];"),
+        (
+            "
+                                const array = (Array)(
+                                    /* foo */ a,
+                                    b = c() // bar
+                                );
+                                ",
+            "
+                                const array = [
+                                    /* foo */ a,
+                                    b = c() // bar
+                                ];
+                                ",
+        ),
         ("const array = Array(5, 6, ...args);", "const array = [5, 6, ...args];"),
-        // TODO: Catch this case:
-        // ("a = new (Array);", "a = [];"),
-        // ("a = new (Array) && (foo);", "a = [] && (foo);"),
+        ("a = new (Array);", "a = [];"),
+        ("a = new (Array) && (foo);", "a = [] && (foo);"),
         ("/*a*/Array()", "/*a*/[]"),
         ("/*a*/Array()/*b*/", "/*a*/[]/*b*/"),
-        // TODO: Preserve comments around callee:
-        // ("Array(/*a*/ /*b*/)", "[/*a*/ /*b*/]"),
-        // ("Array(/*a*/ x /*b*/, /*c*/ y /*d*/)", "[/*a*/ x /*b*/, /*c*/ y /*d*/]"),
-        // (
-        //     "/*a*/Array(/*b*/ x /*c*/, /*d*/ y /*e*/)/*f*/;/*g*/",
-        //     "/*a*/[/*b*/ x /*c*/, /*d*/ y /*e*/]/*f*/;/*g*/",
-        // ),
+        ("Array(/*a*/ /*b*/)", "[/*a*/ /*b*/]"),
+        ("Array(/*a*/ x /*b*/, /*c*/ y /*d*/)", "[/*a*/ x /*b*/, /*c*/ y /*d*/]"),
+        (
+            "/*a*/Array(/*b*/ x /*c*/, /*d*/ y /*e*/)/*f*/;/*g*/",
+            "/*a*/[/*b*/ x /*c*/, /*d*/ y /*e*/]/*f*/;/*g*/",
+        ),
         ("/*a*/new Array", "/*a*/[]"),
         ("/*a*/new Array/*b*/", "/*a*/[]/*b*/"),
-        // TODO: Preserve comments:
-        // ("new Array(/*a*/ /*b*/)", "[/*a*/ /*b*/]"),
-        // ("new Array(/*a*/ x /*b*/, /*c*/ y /*d*/)", "[/*a*/ x /*b*/, /*c*/ y /*d*/]"),
+        ("new Array(/*a*/ /*b*/)", "[/*a*/ /*b*/]"),
+        ("new Array(/*a*/ x /*b*/, /*c*/ y /*d*/)", "[/*a*/ x /*b*/, /*c*/ y /*d*/]"),
+        ("(/* a */ Array)(1, 2, 3);", "[1, 2, 3];"),
+        ("(Array) /* a */ (1, 2, 3);", "[1, 2, 3];"),
+        ("Array?.(0, 1, 2).forEach(doSomething);", "[0, 1, 2].forEach(doSomething);"),
+        ("Array?.(x, y);", "[x, y];"),
+        ("foo()\nArray(x, y)", "foo()\n;[x, y]"),
+        ("foo();\nArray(x, y)", "foo();\n[x, y]"),
+        ("if (a) Array()", "if (a) []"),
+        ("const foo = () => Array(x, y)", "const foo = () => [x, y]"),
         ("new Array();", "[];"),
         ("Array();", "[];"),
         ("new Array(x, y);", "[x, y];"),
         ("Array(x, y);", "[x, y];"),
         ("new Array(0, 1, 2);", "[0, 1, 2];"),
         ("Array(0, 1, 2);", "[0, 1, 2];"),
-        // TODO: These currently produce invalid syntax, need to fix the fixer.
-        // (
-        //     r#"
-        //                 (function () {
-        //                     Fn
-        //                     Array() // ";" required
-        //                 }) as Fn
-        //                 Array() // ";" not required
-        //                 "#,
-        //     r#"
-        //                 (function () {
-        //                     Fn
-        //                     ;[] // ";" required
-        //                 }) as Fn
-        //                 [] // ";" not required
-        //                 "#,
-        // ),
-        // (
-        //     r#"
-        //                 ({
-        //                     foo() {
-        //                         Object
-        //                         Array() // ";" required
-        //                     }
-        //                 }) as Object
-        //                 Array() // ";" not required
-        //                 "#,
-        //     r#"
-        //                 ({
-        //                     foo() {
-        //                         Object
-        //                         ;[] // ";" required
-        //                     }
-        //                 }) as Object
-        //                 [] // ";" not required
-        //                 "#,
-        // ),
+        (
+            r#"
+                        (function () {
+                            Fn
+                            Array() // ";" required
+                        }) as Fn
+                        Array() // ";" not required
+                        "#,
+            r#"
+                        (function () {
+                            Fn
+                            ;[] // ";" required
+                        }) as Fn
+                        [] // ";" not required
+                        "#,
+        ),
+        (
+            r#"
+                        ({
+                            foo() {
+                                Object
+                                Array() // ";" required
+                            }
+                        }) as Object
+                        Array() // ";" not required
+                        "#,
+            r#"
+                        ({
+                            foo() {
+                                Object
+                                ;[] // ";" required
+                            }
+                        }) as Object
+                        [] // ";" not required
+                        "#,
+        ),
     ];
 
     Tester::new(NoArrayConstructor::NAME, NoArrayConstructor::PLUGIN, pass, fail)
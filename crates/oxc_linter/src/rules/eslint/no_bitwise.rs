@@ -15,7 +15,7 @@ use crate::{
 };
 
 fn no_bitwise_diagnostic(operator: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn(format!("Unexpected use of `{operator:?}`."))
+    OxcDiagnostic::warn(format!("Unexpected use of `{operator}`."))
         .with_help("bitwise operators are not allowed, maybe you mistyped `&&` or `||`?")
         .with_label(span)
 }
@@ -197,6 +197,12 @@ fn test() {
         ("a <<= b", None),
         ("a >>= b", None),
         ("a >>>= b", None),
+        ("a|0", Some(json!([ { "int32Hint": false }]))),
+        ("a|1", Some(json!([ { "int32Hint": true }]))),
+        ("0|a", Some(json!([ { "int32Hint": true }]))),
+        ("a&0", Some(json!([ { "int32Hint": true }]))),
+        ("a |= 0", Some(json!([ { "int32Hint": true }]))),
+        ("a & b", Some(json!([ { "allow": ["|", "~"] }]))),
     ];
 
     Tester::new(NoBitwise::NAME, NoBitwise::PLUGIN, pass, fail).test_and_snapshot();
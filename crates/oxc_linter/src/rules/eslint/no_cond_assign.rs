@@ -138,9 +138,9 @@ impl NoCondAssign {
     }
 
     fn check_expression(&self, ctx: &LintContext<'_>, expr: &Expression<'_>) {
-        let mut expr = expr;
+        // With `always`, every assignment in a condition is reported from the assignment itself.
         if self.0 == NoCondAssignConfig::Always {
-            expr = expr.get_inner_expression();
+            return;
         }
         if let Expression::AssignmentExpression(expr) = expr {
             Self::emit_diagnostic(ctx, expr);
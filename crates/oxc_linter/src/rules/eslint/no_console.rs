@@ -107,24 +107,28 @@ impl Rule for NoConsole {
             return;
         };
 
-        if ident.name != "console"
-            || !ctx.scoping().root_unresolved_references().contains_key("console")
-        {
+        if ident.name != "console" {
+            return;
+        }
+        if !ctx.scoping().root_unresolved_references().contains_key("console") {
+            ctx.trace(|| "`console` is declared in this file, so it isn't the global `console`");
             return;
         }
 
         let (mem_span, prop_name) = match node.kind() {
             AstKind::StaticMemberExpression(member_expr) => member_expr.static_property_info(),
             AstKind::ComputedMemberExpression(member_expr) => {
-                match member_expr.static_property_info() {
-                    Some(info) => info,
-                    None => return,
-                }
+                let Some(info) = member_expr.static_property_info() else {
+                    ctx.trace(|| "the accessed property of `console` isn't known statically");
+                    return;
+                };
+                info
             }
             _ => unreachable!(),
         };
 
         if self.allow.iter().any(|allowed_name| allowed_name == prop_name) {
+            ctx.trace(|| format!("`console.{prop_name}` is allowed by the `allow` option"));
             return;
         }
 
@@ -39,7 +39,7 @@ fn no_duplicate_exports_diagnostic(
         ])
 }
 
-#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct NoDuplicateImports {
     /// When `true` this rule will also look at exports to see if there is both a re-export of a
@@ -77,6 +77,10 @@ pub struct NoDuplicateImports {
     /// considered separate from imports with value specifiers, so they can be imported from the
     /// same module on separate import statements.
     ///
+    /// Unlike ESLint, where this option defaults to `false`, it defaults to `true` here, since
+    /// keeping `import type` statements apart from value imports is common in TypeScript code.
+    /// Set it to `false` to report them as duplicates, as ESLint does.
+    ///
     /// Examples of **correct** code when `allowSeparateTypeImports` is set to `true`:
     /// ```js
     /// import { foo } from "module";
@@ -90,11 +94,21 @@ pub struct NoDuplicateImports {
     allow_separate_type_imports: bool,
 }
 
+impl Default for NoDuplicateImports {
+    fn default() -> Self {
+        Self { include_exports: false, allow_separate_type_imports: true }
+    }
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
     /// Disallow duplicate module imports.
     ///
+    /// Module specifiers are compared as written, without resolving them, so `./foo` and
+    /// `./foo.js` are treated as different modules. See `import/no-duplicates` for a rule which
+    /// resolves them.
+    ///
     /// ### Why is this bad?
     ///
     /// Using a single import statement per module will make the code clearer because you can see
@@ -706,6 +720,17 @@ fn test() {
             export * from "os";"#,
             Some(serde_json::json!([{ "includeExports": true }])),
         ),
+        // `allowSeparateTypeImports` defaults to `true`, unlike in ESLint.
+        (
+            r#"import { foo } from "module";
+            import type { Bar } from "module";"#,
+            None,
+        ),
+        (
+            r#"import { type Foo } from "module";
+            export type { Bar } from "module";"#,
+            Some(serde_json::json!([{ "includeExports": true }])),
+        ),
         (
             r#"import { foo, type Bar } from "module";"#,
             Some(serde_json::json!([{ "allowSeparateTypeImports": true }])),
@@ -824,7 +849,12 @@ fn test() {
         (
             r#"import { type Merge } from "lodash-es";
             import type { Find } from "lodash-es";"#,
-            None,
+            Some(serde_json::json!([{ "allowSeparateTypeImports": false }])),
+        ),
+        (
+            r#"import { foo } from "module";
+            import type { Bar } from "module";"#,
+            Some(serde_json::json!([{ "allowSeparateTypeImports": false }])),
         ),
         (
             r#"import type { Merge } from "lodash-es";
@@ -846,7 +876,9 @@ fn test() {
         (
             r#"import { type Foo } from "module";
             export type { Bar } from "module";"#,
-            Some(serde_json::json!([{ "includeExports": true }])),
+            Some(
+                serde_json::json!([{ "allowSeparateTypeImports": false, "includeExports": true }]),
+            ),
         ),
         (
             r#"export { os } from "os";
@@ -3,7 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, context::LintContext, rule::Rule, utils::is_label_of_nearest_breakable};
 
 fn no_extra_label_diagnostic(label: &LabelIdentifier) -> OxcDiagnostic {
     let label_name = &label.name;
@@ -109,45 +109,27 @@ impl Rule for NoExtraLabel {
 }
 
 fn report_label_if_extra(label: &LabelIdentifier, node: &AstNode, ctx: &LintContext) {
-    let nodes = ctx.nodes();
-    for ancestor_id in nodes.ancestor_ids(node.id()) {
-        if !is_breakable_statement(nodes.kind(ancestor_id)) {
-            continue;
-        }
-        let AstKind::LabeledStatement(labeled_stmt) = nodes.parent_kind(ancestor_id) else {
-            return; // no need to check outer loops/switches
-        };
-        if labeled_stmt.label.name != label.name {
-            return;
-        }
-
-        let keyword_len: u32 = match node.kind() {
-            AstKind::BreakStatement(_) => 5,
-            AstKind::ContinueStatement(_) => 8,
-            _ => unreachable!(),
-        };
-
-        let keyword_end = node.span().start + keyword_len;
-        let delete_span = Span::new(keyword_end, label.span.end);
-
-        let diagnostic = no_extra_label_diagnostic(label);
-        if ctx.comments().iter().any(|comment| delete_span.contains_inclusive(comment.span)) {
-            // No autofix to avoid deleting comments between keyword and label
-            // e.g. `break /* comment */ label;`
-            ctx.diagnostic(diagnostic);
-        } else {
-            // e.g. `break label;` -> `break;`
-            ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(delete_span));
-        }
+    if !is_label_of_nearest_breakable(label, node.id(), ctx) {
         return;
     }
-}
 
-fn is_breakable_statement(kind: AstKind) -> bool {
-    match kind {
-        kind if kind.is_iteration_statement() => true,
-        AstKind::SwitchStatement(_) => true,
-        _ => false,
+    let keyword_len: u32 = match node.kind() {
+        AstKind::BreakStatement(_) => 5,
+        AstKind::ContinueStatement(_) => 8,
+        _ => unreachable!(),
+    };
+
+    let keyword_end = node.span().start + keyword_len;
+    let delete_span = Span::new(keyword_end, label.span.end);
+
+    let diagnostic = no_extra_label_diagnostic(label);
+    if ctx.comments().iter().any(|comment| delete_span.contains_inclusive(comment.span)) {
+        // No autofix to avoid deleting comments between keyword and label
+        // e.g. `break /* comment */ label;`
+        ctx.diagnostic(diagnostic);
+    } else {
+        // e.g. `break label;` -> `break;`
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(delete_span));
     }
 }
 
@@ -171,6 +153,7 @@ fn test() {
         "A: do { switch (b) { case 0: break A; break; } } while (a);",
         "A: for (a in obj) { while (b) { break A; } }",
         "A: for (a of ary) { switch (b) { case 0: break A; } }", // { "ecmaVersion": 6 }
+        "A: B: while (a) { break A; }",
     ];
 
     let fail = vec![
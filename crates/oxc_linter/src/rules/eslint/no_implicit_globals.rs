@@ -260,6 +260,11 @@ fn assignment_span(reference: &Reference, ctx: &LintContext) -> Span {
             AstKind::AssignmentExpression(_)
             | AstKind::ForInStatement(_)
             | AstKind::ForOfStatement(_) => return parent.kind().span(),
+            // A destructuring assignment may leak several variables, so report each one at the
+            // variable itself.
+            AstKind::ArrayAssignmentTarget(_) | AstKind::ObjectAssignmentTarget(_) => {
+                return reference_node.kind().span();
+            }
             _ if parent.id() == node.id() => return reference_node.kind().span(),
             _ => node = parent,
         }
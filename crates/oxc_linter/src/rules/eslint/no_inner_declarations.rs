@@ -8,8 +8,8 @@ use serde::{Deserialize, Serialize};
 use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_inner_declarations_diagnostic(decl_type: &str, body: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Variable or `function` declarations are not allowed in nested blocks")
-        .with_help(format!("Move {decl_type} declaration to {body} root"))
+    OxcDiagnostic::warn(format!("Move {decl_type} declaration to {body} root"))
+        .with_help("Variable or `function` declarations are not allowed in nested blocks")
         .with_label(span)
 }
 
@@ -28,8 +28,7 @@ enum NoInnerDeclarationsConfig {
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 struct NoInnerDeclarationsOptions {
     /// Controls whether function declarations in nested blocks are allowed in strict mode (ES6+ behavior).
-    #[schemars(with = "BlockScopedFunctions")]
-    block_scoped_functions: Option<BlockScopedFunctions>,
+    block_scoped_functions: BlockScopedFunctions,
     /// Controls whether declarations directly inside TypeScript namespace or module bodies are allowed.
     #[schemars(with = "Namespaces")]
     namespaces: Option<Namespaces>,
@@ -86,6 +85,16 @@ declare_oxc_lint!(
     ///   // your code here
     /// }
     /// ```
+    ///
+    /// With the default `{ "blockScopedFunctions": "allow" }`, function declarations in nested
+    /// blocks are allowed in strict mode code (modules, classes and code under a `"use strict"`
+    /// directive), where they are scoped to the block:
+    /// ```javascript
+    /// "use strict";
+    /// if (test) {
+    ///   function doSomethingElse () { }
+    /// }
+    /// ```
     NoInnerDeclarations,
     eslint,
     pedantic,
@@ -106,19 +115,14 @@ impl Rule for NoInnerDeclarations {
 
         // Options follow the mode string, matching ESLint's positional schema
         // `[("functions" | "both"), { … }]`.
-        let block_scoped_functions = if value.is_array() && !value.is_null() {
-            value
-                .get(1)
-                .and_then(|v| v.get("blockScopedFunctions"))
-                .and_then(serde_json::Value::as_str)
-                .map(|value| match value {
-                    "disallow" => BlockScopedFunctions::Disallow,
-                    _ => BlockScopedFunctions::Allow,
-                })
-                .or(Some(BlockScopedFunctions::Allow))
-        } else {
-            None
-        };
+        let block_scoped_functions = value
+            .get(1)
+            .and_then(|v| v.get("blockScopedFunctions"))
+            .and_then(serde_json::Value::as_str)
+            .map_or_else(BlockScopedFunctions::default, |value| match value {
+                "disallow" => BlockScopedFunctions::Disallow,
+                _ => BlockScopedFunctions::Allow,
+            });
 
         let namespaces =
             value.get(1).and_then(|v| v.get("namespaces")).and_then(serde_json::Value::as_str).map(
@@ -145,9 +149,9 @@ impl Rule for NoInnerDeclarations {
                     return;
                 }
 
-                if self.0 == NoInnerDeclarationsConfig::Functions
-                    && self.1.block_scoped_functions == Some(BlockScopedFunctions::Allow)
-                {
+                // Function declarations are block scoped in strict mode code, so they don't
+                // leak out of the block.
+                if self.1.block_scoped_functions == BlockScopedFunctions::Allow {
                     // Modules are always strict mode.
                     // This check is redundant, because in modules, the scope will have strict mode flag set,
                     // but checking source type is cheaper than scope flags lookup, so do the quick check first.
@@ -223,7 +227,11 @@ fn check_rule<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>, allow_namespaces: b
             ("variable", span)
         }
         AstKind::Function(func) => {
-            let span = Span::sized(func.span.start, 8); // 8 for "function".len()
+            // Skip over `async` to the `function` keyword.
+            let start = ctx
+                .find_next_token_from(func.span.start, "function")
+                .map_or(func.span.start, |offset| func.span.start + offset);
+            let span = Span::sized(start, 8); // 8 for "function".len()
             ("function", span)
         }
         _ => unreachable!(),
@@ -284,10 +292,6 @@ fn test() {
              if (test) { function doSomething() { } } }",
             Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
         ), // { "ecmaVersion": 6 },
-        (
-            "function foo() { { function bar() { } } }",
-            Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
-        ), // { "ecmaVersion": 2022, "sourceType": "module" },
         (
             "class C { method() { if(test) { function somethingElse() { } } } }",
             Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
@@ -296,6 +300,14 @@ fn test() {
             "const C = class { method() { if(test) { function somethingElse() { } } } }",
             Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
         ), // { "ecmaVersion": 2022 }
+        // ESLint reports this with `ecmaVersion: 5`, where functions are never block scoped
+        (
+            "'use strict'
+             if (test) { function doSomething() { } }",
+            Some(serde_json::json!(["both", { "blockScopedFunctions": "allow" }])),
+        ),
+        ("'use strict'; switch (a) { case 1: function f() {} }", None),
+        ("'use strict'; foo: { function f() {} }", None),
         // `namespaces: "allow"` exempts declarations directly in a TS namespace/module body
         (
             "namespace N { function foo() {} }",
@@ -365,11 +377,6 @@ fn test() {
              if (test) { function doSomething() { } }",
             Some(serde_json::json!(["both", { "blockScopedFunctions": "disallow" }])),
         ), // { "ecmaVersion": 2022 },
-        (
-            "'use strict'
-             if (test) { function doSomething() { } }",
-            Some(serde_json::json!(["both", { "blockScopedFunctions": "allow" }])),
-        ), // { "ecmaVersion": 5 },
         (
             "function foo() {'use strict'
              { function bar() { } } }",
@@ -399,9 +406,47 @@ fn test() {
             "namespace N { if (x) { function foo() {} } }",
             Some(serde_json::json!(["both", { "namespaces": "allow" }])),
         ),
+        ("foo: function f() {}", None),
+        ("foo: var x;", Some(serde_json::json!(["both"]))),
+        ("function g() { foo: function f() {} }", None),
+        ("switch (a) { case 1: function f() {} }", None),
+        ("switch (a) { case 1: var x; }", Some(serde_json::json!(["both"]))),
+        ("function g() { switch (a) { default: var x; } }", Some(serde_json::json!(["both"]))),
+        ("if (test) { async function f() {} }", None),
+    ];
+
+    // Sloppy mode scripts, where function declarations are not block scoped.
+    Tester::new(NoInnerDeclarations::NAME, NoInnerDeclarations::PLUGIN, pass, fail)
+        .change_rule_path_extension("cts")
+        .test_and_snapshot();
+
+    // Modules are strict mode code, so `blockScopedFunctions` applies to all of them.
+    let pass = vec![
+        ("if (test) { function doSomething() { } }", None),
+        ("if (test) { function doSomething() { } }", Some(serde_json::json!(["both"]))),
+        (
+            "function foo() { { function bar() { } } }",
+            Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
+        ), // { "ecmaVersion": 2022, "sourceType": "module" },
+        ("switch (a) { case 1: function f() {} }", None),
+        ("foo: { async function f() {} }", None),
+    ];
+
+    let fail = vec![
+        (
+            "if (test) { function doSomething() { } }",
+            Some(serde_json::json!(["functions", { "blockScopedFunctions": "disallow" }])),
+        ),
+        (
+            "switch (a) { case 1: function f() {} }",
+            Some(serde_json::json!(["both", { "blockScopedFunctions": "disallow" }])),
+        ),
+        ("if (test) { var foo; }", Some(serde_json::json!(["both"]))),
+        ("switch (a) { case 1: var x; }", Some(serde_json::json!(["both"]))),
     ];
 
     Tester::new(NoInnerDeclarations::NAME, NoInnerDeclarations::PLUGIN, pass, fail)
         .change_rule_path_extension("mts")
+        .with_snapshot_suffix("module")
         .test_and_snapshot();
 }
@@ -13,6 +13,7 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::{DefaultRuleConfig, Rule},
+    utils::find_labeled_statement,
 };
 
 fn no_labels_diagnostic(message: &'static str, label_span: Span) -> OxcDiagnostic {
@@ -176,14 +177,8 @@ impl NoLabels {
         stmt_node_id: NodeId,
         ctx: &LintContext<'a>,
     ) -> bool {
-        for ancestor_kind in ctx.nodes().ancestor_kinds(stmt_node_id) {
-            if let AstKind::LabeledStatement(labeled_stmt) = ancestor_kind
-                && label.name == labeled_stmt.label.name
-            {
-                return self.is_allowed(&labeled_stmt.body);
-            }
-        }
-        false
+        find_labeled_statement(label, stmt_node_id, ctx)
+            .is_some_and(|labeled_stmt| self.is_allowed(&labeled_stmt.body))
     }
 }
 
@@ -206,6 +201,10 @@ fn test() {
             Some(serde_json::json!([{ "allowLoop": true }])),
         ),
         ("A: switch (a) { case 0: break A; }", Some(serde_json::json!([{ "allowSwitch": true }]))),
+        (
+            "A: while (a) { B: switch (b) { case 0: break A; default: continue A; } }",
+            Some(serde_json::json!([{ "allowLoop": true, "allowSwitch": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -251,6 +250,8 @@ fn test() {
             "A: for (var a in obj) { for (;;) { switch (a) { case 0: break A; } } }",
             Some(serde_json::json!([{ "allowSwitch": true }])),
         ),
+        ("A: while (a) { B: { break A; } }", Some(serde_json::json!([{ "allowLoop": true }]))),
+        ("A: { B: while (a) { break A; } }", Some(serde_json::json!([{ "allowLoop": true }]))),
     ];
 
     Tester::new(NoLabels::NAME, NoLabels::PLUGIN, pass, fail).test_and_snapshot();
@@ -3,7 +3,12 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::{NativeConstructorKind, native_constructor_kind},
+    rule::Rule,
+};
 
 fn no_new_native_nonconstructor_diagnostic(fn_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("`{fn_name}` cannot be called as a constructor."))
@@ -60,15 +65,11 @@ impl Rule for NoNewNativeNonconstructor {
         let Expression::Identifier(ident) = &expr.callee else {
             return;
         };
-        if matches!(ident.name.as_str(), "Symbol" | "BigInt")
-            && ctx.scoping().root_unresolved_references().contains_key(&ident.name)
+        let name = ident.name.as_str();
+        if native_constructor_kind(name) == Some(NativeConstructorKind::NonConstructor)
+            && ctx.is_reference_to_global_variable(ident)
         {
-            let start = expr.span.start;
-            let end = start + 3;
-            ctx.diagnostic(no_new_native_nonconstructor_diagnostic(
-                ident.name.as_str(),
-                Span::new(start, end),
-            ));
+            ctx.diagnostic(no_new_native_nonconstructor_diagnostic(name, ident.span));
         }
     }
 }
@@ -88,6 +89,9 @@ fn test() {
         "function BigInt() {} new BigInt();",
         "new foo(BigInt);",
         "new foo(bar, BigInt);",
+        "function foo() { var Symbol = class {}; return new Symbol(); }",
+        "import { BigInt } from './big-int'; new BigInt(1);",
+        "new Number(1);",
     ];
 
     let fail = vec![
@@ -95,6 +99,7 @@ fn test() {
         "function bar() { return function Symbol() {}; } var baz = new Symbol('baz');",
         "var foo = new BigInt(9007199254740991);",
         "function bar() { return function BigInt() {}; } var baz = new BigInt(9007199254740991);",
+        "function foo() { var Symbol = class {}; } new Symbol();",
     ];
 
     Tester::new(NoNewNativeNonconstructor::NAME, NoNewNativeNonconstructor::PLUGIN, pass, fail)
@@ -10,19 +10,16 @@ use crate::{
     AstNode,
     context::LintContext,
     fixer::{RuleFix, RuleFixer},
+    globals::{NativeConstructorKind, native_constructor_kind},
     rule::Rule,
 };
 
-fn no_new_wrappers_diagnostic(builtin_name: &str, new_span: Span) -> OxcDiagnostic {
+fn no_new_wrappers_diagnostic(builtin_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("Do not use `{builtin_name}` as a constructor"))
-        .with_help("Remove the `new` operator.")
-        .with_label(new_span)
-}
-
-fn not_a_constructor_diagnostic(builtin_name: &str, new_span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn(format!("`{builtin_name}` is not a constructor"))
-        .with_help("Remove the `new` operator.")
-        .with_label(new_span)
+        .with_help(format!(
+            "Remove the `new` operator to convert the value to a primitive with `{builtin_name}`."
+        ))
+        .with_label(span)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -41,6 +38,9 @@ declare_oxc_lint!(
     /// objects. Every object is truthy, that means an instance of `Boolean`
     /// always resolves to `true` even when its actual value is `false`.
     ///
+    /// `new Symbol` and `new BigInt` throw instead, and are reported by
+    /// `no-new-native-nonconstructor`.
+    ///
     /// https://eslint.org/docs/latest/rules/no-new-wrappers
     ///
     /// ### Examples
@@ -50,7 +50,6 @@ declare_oxc_lint!(
     /// var stringObject = new String('Hello world');
     /// var numberObject = new Number(33);
     /// var booleanObject = new Boolean(false);
-    /// var symbolObject = new Symbol('foo'); // symbol is not a constructor
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -59,12 +58,15 @@ declare_oxc_lint!(
     /// var stringObject2 = String(value);
     /// var numberObject = Number(value);
     /// var booleanObject = Boolean(value);
-    /// var symbolObject = Symbol('foo');
     /// ```
+    ///
+    /// The suggested fix removes the `new` operator, turning the wrapper object into a
+    /// primitive. This changes the type of the value, for example `typeof` and strict equality
+    /// comparisons behave differently, so the suggestion is marked as dangerous.
     NoNewWrappers,
     eslint,
     pedantic,
-    fix,
+    dangerous_suggestion,
     version = "0.2.10",
     short_description = "Disallow `new` operators with the `String`, `Number`, and `Boolean` objects.",
 );
@@ -77,28 +79,16 @@ impl Rule for NoNewWrappers {
         let Expression::Identifier(ident) = &expr.callee else {
             return;
         };
-        if !ctx.is_reference_to_global_variable(ident) {
-            return;
-        }
         let name = ident.name.as_str();
-        let span = if expr.span.size() > 24 {
-            Span::new(expr.span.start, ident.span.end)
-        } else {
-            expr.span
-        };
-        match name {
-            "String" | "Number" | "Boolean" => {
-                ctx.diagnostic_with_fix(no_new_wrappers_diagnostic(name, span), |fixer| {
-                    remove_new_operator(fixer, expr, name)
-                });
-            }
-            "Symbol" => {
-                ctx.diagnostic_with_fix(not_a_constructor_diagnostic(name, span), |fixer| {
-                    remove_new_operator(fixer, expr, name)
-                });
-            }
-            _ => {}
+        if native_constructor_kind(name) != Some(NativeConstructorKind::PrimitiveWrapper)
+            || !ctx.is_reference_to_global_variable(ident)
+        {
+            return;
         }
+        ctx.diagnostic_with_dangerous_suggestion(
+            no_new_wrappers_diagnostic(name, ident.span),
+            |fixer| remove_new_operator(fixer, expr, name),
+        );
     }
 }
 
@@ -129,6 +119,8 @@ fn test() {
     let pass = vec![
         "var a = new Object();",
         "var a = String('test'), b = String.fromCharCode(32);",
+        "var a = new Symbol('foo');",
+        "var a = new BigInt(1);",
         "function test(Number) { return new Number; }",
         r#"
             import String from "./string";
@@ -174,7 +166,6 @@ fn test() {
         ("var a = new String(foo);", "var a = String(foo);"),
         ("var a = new Boolean(foo);", "var a = Boolean(foo);"),
         ("var a = new Boolean(!!x);", "var a = Boolean(!!x);"),
-        ("var a = new Symbol('foo');", "var a = Symbol('foo');"),
         // literals dont need to be wrapped
         ("var a = new Boolean(false);", "var a = false;"),
         ("var a = new Boolean(true);", "var a = true;"),
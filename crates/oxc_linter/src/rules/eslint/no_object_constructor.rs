@@ -3,7 +3,12 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    ast_util::{is_start_of_expression_statement, needs_preceding_semicolon},
+    context::LintContext,
+    rule::Rule,
+};
 
 fn no_object_constructor_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Disallow calls to the `Object` constructor without an argument")
@@ -41,7 +46,7 @@ declare_oxc_lint!(
     NoObjectConstructor,
     eslint,
     pedantic,
-    pending,
+    fix,
     version = "0.13.2",
     short_description = "Disallow calls to the Object constructor without an argument.",
 );
@@ -67,7 +72,20 @@ impl Rule for NoObjectConstructor {
             && arguments.is_empty()
             && type_parameters.is_none()
         {
-            ctx.diagnostic(no_object_constructor_diagnostic(span));
+            ctx.diagnostic_with_fix(no_object_constructor_diagnostic(span), |fixer| {
+                if ctx.has_comments_between(span) {
+                    return fixer.noop();
+                }
+                // `{}` at the start of a statement would be parsed as a block.
+                let replacement = if !is_start_of_expression_statement(node, ctx.nodes()) {
+                    "{}"
+                } else if needs_preceding_semicolon(ctx.semantic(), node) {
+                    ";({})"
+                } else {
+                    "({})"
+                };
+                fixer.replace(span, replacement)
+            });
         }
     }
 }
@@ -262,6 +280,66 @@ fn test() {
         ),
     ];
 
+    let fix = vec![
+        ("new Object", "({})"),
+        ("Object()", "({})"),
+        ("const obj = new Object();", "const obj = {};"),
+        ("const fn = () => Object();", "const fn = () => ({});"),
+        ("const fn = () => Object().foo;", "const fn = () => ({}).foo;"),
+        ("const fn = () => (Object());", "const fn = () => ({});"),
+        ("Object() instanceof Object;", "({}) instanceof Object;"),
+        ("const obj = Object?.();", "const obj = {};"),
+        ("(new Object() instanceof Object);", "({} instanceof Object);"),
+        ("foo(new Object())", "foo({})"),
+        ("new Object(/* empty */)", "new Object(/* empty */)"),
+        // Semicolon required before `({})` to compensate for ASI
+        ("foo()\nObject()", "foo()\n;({})"),
+        ("var yield = bar.yield\nObject()", "var yield = bar.yield\n;({})"),
+        ("var foo = { bar: baz }\nObject()", "var foo = { bar: baz }\n;({})"),
+        ("<foo />\nObject()", "<foo />\n;({})"),
+        ("<foo></foo>\nObject()", "<foo></foo>\n;({})"),
+        // No semicolon required before `({})` because ASI does not occur
+        ("{}\nObject()", "{}\n({})"),
+        ("function foo() {}\nObject()", "function foo() {}\n({})"),
+        ("class Foo {}\nObject()", "class Foo {}\n({})"),
+        ("foo: Object();", "foo: ({});"),
+        ("foo();Object();", "foo();({});"),
+        ("{ Object(); }", "{ ({}); }"),
+        ("if (a) Object();", "if (a) ({});"),
+        ("if (a); else Object();", "if (a); else ({});"),
+        ("while (a) Object();", "while (a) ({});"),
+        ("do Object(); while (a);", "do ({}); while (a);"),
+        ("for (const prop in obj) Object();", "for (const prop in obj) ({});"),
+        ("with (obj) Object();", "with (obj) ({});"),
+        // No semicolon required before `({})` because ASI still occurs
+        ("const foo = () => {}\nObject()", "const foo = () => {}\n({})"),
+        ("a++\nObject()", "a++\n({})"),
+        ("a--\nObject()", "a--\n({})"),
+        (
+            "function foo() {\n    return\n    Object();\n}",
+            "function foo() {\n    return\n    ({});\n}",
+        ),
+        (
+            "function * foo() {\n    yield\n    Object();\n}",
+            "function * foo() {\n    yield\n    ({});\n}",
+        ),
+        ("do {} while (a) Object()", "do {} while (a) ({})"),
+        ("debugger\nObject()", "debugger\n({})"),
+        ("for (;;) {\n    break\n    Object()\n}", "for (;;) {\n    break\n    ({})\n}"),
+        ("for (;;) {\n    continue\n    Object()\n}", "for (;;) {\n    continue\n    ({})\n}"),
+        ("foo: break foo\nObject()", "foo: break foo\n({})"),
+        ("foo: while (true) continue foo\nObject()", "foo: while (true) continue foo\n({})"),
+        ("const foo = bar\nexport { foo }\nObject()", "const foo = bar\nexport { foo }\n({})"),
+        ("export { foo } from 'bar'\nObject()", "export { foo } from 'bar'\n({})"),
+        ("export * as foo from 'bar'\nObject()", "export * as foo from 'bar'\n({})"),
+        ("import foo from 'bar'\nObject()", "import foo from 'bar'\n({})"),
+        (
+            "var yield = 5;\nyield: while (foo) {\n    if (bar)\n        break yield\n    new Object();\n}",
+            "var yield = 5;\nyield: while (foo) {\n    if (bar)\n        break yield\n    ({});\n}",
+        ),
+    ];
+
     Tester::new(NoObjectConstructor::NAME, NoObjectConstructor::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
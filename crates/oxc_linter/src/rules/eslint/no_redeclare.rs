@@ -3,6 +3,7 @@ use javascript_globals::GLOBALS_BUILTIN;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use oxc_syntax::symbol::SymbolFlags;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -82,9 +83,12 @@ impl Rule for NoRedeclare {
         for symbol_id in ctx.scoping().symbol_ids() {
             let name = ctx.scoping().symbol_name(symbol_id);
             let decl_span = ctx.scoping().symbol_span(symbol_id);
-            let is_builtin = builtin_globals.is_some_and(|builtin_globals| {
-                builtin_globals.contains_key(name) || ctx.globals().is_enabled(name)
-            });
+            // Types such as `interface Array<T> {}` augment builtin globals instead of
+            // redeclaring them.
+            let is_builtin = ctx.scoping().symbol_flags(symbol_id).is_value()
+                && builtin_globals.is_some_and(|builtin_globals| {
+                    builtin_globals.contains_key(name) || ctx.globals().is_enabled(name)
+                });
 
             if is_builtin {
                 ctx.diagnostic(no_redeclare_as_builtin_in_diagnostic(name, decl_span));
@@ -98,6 +102,10 @@ impl Rule for NoRedeclare {
                         }
                         return false;
                     }
+                    // Interfaces and namespaces merge with other declarations of the same name.
+                    if rd.flags.intersects(SymbolFlags::Interface | SymbolFlags::Namespace) {
+                        return false;
+                    }
                     if rd.flags.is_function() {
                         let node = ctx.nodes().get_node(rd.declaration);
                         if let Some(func) = node.kind().as_function() {
@@ -128,8 +136,10 @@ impl Rule for NoRedeclare {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        // ES modules run in their own scope, and don't conflict with existing globals
-        !ctx.source_type().is_module()
+        // ES modules run in their own scope, and don't conflict with existing globals.
+        // Declaration files are parsed as modules, but without imports or exports they declare
+        // globals, and duplicated declarations in them are always redundant.
+        !ctx.source_type().is_module() || ctx.is_declaration_file()
     }
 }
 
@@ -196,8 +206,32 @@ fn test() {
         ("type foo = 1; export function foo(): void; export function foo() { }", None),
     ];
 
+    let declaration_file_pass = vec![
+        ("declare function foo(): void; declare function foo(a: string): void;", None),
+        (
+            "export declare function foo(): void; export declare function foo(a: string): void;",
+            None,
+        ),
+        ("interface Foo { a: string } interface Foo { b: string }", None),
+        ("declare function foo(): void; declare namespace foo { const bar: string; }", None),
+        ("declare class Foo {} interface Foo { a: string }", None),
+        ("declare global { interface Window { a: string } interface Window { b: string } }", None),
+        ("declare var foo: string; declare module 'foo' { var foo: string; }", None),
+        ("declare var Foo: FooConstructor; interface Foo {}", None),
+        ("declare global { interface Array<T> { last(): T | undefined } }", None),
+        ("interface Promise<T> { finally(): Promise<T> }", None),
+    ];
+
+    let declaration_file_fail = vec![
+        ("declare var foo: string; declare var foo: string;", None),
+        ("declare global { var foo: string; var foo: string; }", None),
+        ("declare module 'foo' { var foo: string; var foo: string; }", None),
+        ("declare var Object: ObjectConstructor;", None),
+    ];
+
     Tester::new(NoRedeclare::NAME, NoRedeclare::PLUGIN, pass, fail)
         .change_rule_path_extension(".cts")
+        .with_declaration_file_cases(declaration_file_pass, declaration_file_fail)
         .test_and_snapshot();
 
     let fail = vec![("var foo;", None, Some(serde_json::json!({ "globals": { "foo": false }})))];
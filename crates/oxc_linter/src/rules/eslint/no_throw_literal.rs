@@ -98,10 +98,12 @@ impl Rule for NoThrowLiteral {
             Expression::Identifier(id) if SPECIAL_IDENTIFIERS.contains(&id.name.as_str()) => {
                 ctx.diagnostic(no_throw_literal_diagnostic(expr.span(), true));
             }
-            expr if !could_be_error(ctx, expr) => {
+            expr if !could_be_error(ctx.semantic(), expr) => {
                 ctx.diagnostic(no_throw_literal_diagnostic(expr.span(), false));
             }
-            _ => {}
+            expr => ctx.trace(|| {
+                format!("`{}` could be an Error object", expr.span().source_text(ctx.source_text()))
+            }),
         }
     }
 }
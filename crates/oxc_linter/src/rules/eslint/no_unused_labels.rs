@@ -1,9 +1,8 @@
-use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::unused_labeled_statements};
 
 fn no_unused_labels_diagnostic(label_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("'{label_name}:' is defined but never used.")).with_label(span)
@@ -53,11 +52,7 @@ declare_oxc_lint!(
 
 impl Rule for NoUnusedLabels {
     fn run_once(&self, ctx: &LintContext) {
-        for id in ctx.unused_labels() {
-            let node = ctx.nodes().get_node(*id);
-            let AstKind::LabeledStatement(stmt) = node.kind() else {
-                continue;
-            };
+        for stmt in unused_labeled_statements(ctx) {
             ctx.diagnostic_with_fix(
                 no_unused_labels_diagnostic(stmt.label.name.as_str(), stmt.label.span),
                 |fixer| fixer.replace_with(stmt, &stmt.body),
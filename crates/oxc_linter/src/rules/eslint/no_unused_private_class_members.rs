@@ -1,10 +1,14 @@
 use itertools::Itertools;
-use oxc_ast::{AstKind, ast::AssignmentOperator};
+use oxc_ast::{
+    AstKind,
+    ast::{AssignmentOperator, ClassElement},
+};
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_index::IndexVec;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{AstNode, NodeId, Semantic};
 use oxc_span::{GetSpan, Span};
-use oxc_syntax::class::ElementKind;
+use oxc_syntax::class::{ClassId, ElementId, ElementKind};
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -88,32 +92,16 @@ declare_oxc_lint!(
     NoUnusedPrivateClassMembers,
     eslint,
     correctness,
+    suggestion,
     version = "0.1.1",
     short_description = "Disallow unused private class members.",
 );
 
 impl Rule for NoUnusedPrivateClassMembers {
     fn run_once(&self, ctx: &LintContext) {
-        ctx.classes().iter_enumerated().for_each(|(class_id, _)| {
-            for (element_id, element) in ctx.classes().elements[class_id].iter_enumerated() {
-                if !element.kind.intersects(ElementKind::Property | ElementKind::Method) {
-                    continue;
-                }
-
-                if element.is_private
-                    && !ctx.classes().iter_private_identifiers(class_id).any(|ident| {
-                        // If the element is a property, it must be read.
-                        (!element.kind.is_property() || is_read(ident.id, ctx.semantic()))
-                            && ident.element_ids.contains(&element_id)
-                    })
-                {
-                    ctx.diagnostic(no_unused_private_class_members_diagnostic(
-                        &element.name,
-                        element.span,
-                    ));
-                }
-            }
-        });
+        for class_id in ctx.classes().declarations.indices() {
+            Self::run_on_class(class_id, ctx);
+        }
     }
 
     fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
@@ -121,6 +109,99 @@ impl Rule for NoUnusedPrivateClassMembers {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy)]
+struct ElementUsage {
+    is_referenced: bool,
+    is_used: bool,
+}
+
+impl NoUnusedPrivateClassMembers {
+    /// Private names are scoped to the class that declares them, and semantic already resolves
+    /// each `#name` reference to the elements of that class. So a single pass over the class's
+    /// private identifier references is enough, and nested classes which declare the same
+    /// private name never see each other's references.
+    fn run_on_class(class_id: ClassId, ctx: &LintContext) {
+        let classes = ctx.classes();
+        let elements = &classes.elements[class_id];
+        if !elements.iter().any(|element| element.is_private) {
+            return;
+        }
+
+        let mut usages: IndexVec<ElementId, ElementUsage> =
+            IndexVec::from_vec(vec![ElementUsage::default(); elements.len()]);
+        for ident in classes.iter_private_identifiers(class_id) {
+            let mut is_read_ident = None;
+            for &element_id in &ident.element_ids {
+                let usage = &mut usages[element_id];
+                usage.is_referenced = true;
+                // If the element is a property, it must be read.
+                usage.is_used = usage.is_used
+                    || !elements[element_id].kind.is_property()
+                    || *is_read_ident.get_or_insert_with(|| is_read(ident.id, ctx.semantic()));
+            }
+        }
+
+        for (element_id, element) in elements.iter_enumerated() {
+            if !element.is_private
+                || !element.kind.intersects(ElementKind::Property | ElementKind::Method)
+                || usages[element_id].is_used
+            {
+                continue;
+            }
+
+            let diagnostic =
+                no_unused_private_class_members_diagnostic(&element.name, element.span);
+            // Only offer to remove members which are never referenced, otherwise the
+            // remaining writes would refer to a member that no longer exists.
+            match find_class_element_span(class_id, element.span, ctx) {
+                Some(span) if !usages[element_id].is_referenced => {
+                    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                        fixer.delete_range(expand_to_whole_lines(span, ctx.source_text()))
+                    });
+                }
+                _ => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+/// Find the span of the class element whose key is at `key_span`.
+fn find_class_element_span(class_id: ClassId, key_span: Span, ctx: &LintContext) -> Option<Span> {
+    let AstKind::Class(class) = ctx.nodes().kind(ctx.classes().get_node_id(class_id)) else {
+        return None;
+    };
+    class.body.body.iter().find_map(|element| {
+        let key = match element {
+            ClassElement::PropertyDefinition(prop) => &prop.key,
+            ClassElement::MethodDefinition(method) => &method.key,
+            ClassElement::AccessorProperty(prop) => &prop.key,
+            _ => return None,
+        };
+        (key.span() == key_span).then(|| element.span())
+    })
+}
+
+/// When `span` is the only thing on its line(s), expand it to cover the leading indentation and
+/// the trailing line break, so that removing it doesn't leave a blank line behind.
+fn expand_to_whole_lines(span: Span, source_text: &str) -> Span {
+    let before = &source_text[..span.start as usize];
+    let indent = before.len() - before.trim_end_matches([' ', '\t']).len();
+    let after = &source_text[span.end as usize..];
+    let trailing = after.len() - after.trim_start_matches([' ', '\t', ';']).len();
+    let line_start = before.len() - indent;
+    let rest = &after[trailing..];
+    if (line_start == 0 || before[..line_start].ends_with('\n'))
+        && let Some(line_break) = ["\r\n", "\n"].into_iter().find(|br| rest.starts_with(br))
+    {
+        #[expect(clippy::cast_possible_truncation)]
+        return Span::new(
+            span.start - indent as u32,
+            span.end + (trailing + line_break.len()) as u32,
+        );
+    }
+    span
+}
+
 fn is_read(current_node_id: NodeId, semantic: &Semantic) -> bool {
     for (curr, parent) in semantic
         .nodes()
@@ -293,6 +374,8 @@ fn test() {
         r"
             class Foo { #privateMember = {}; a() { return { ...this.#privateMember }; } }
         ",
+        r"class Outer { #a = 1; m() { class Inner { #a = 2; n() { return this.#a; } } return this.#a; } }",
+        r"class Foo { get #x() { return 1; } set #x(v) {} m() { this.#x = 1; } }",
         r"
             class Test {
                 #prop = undefined
@@ -666,8 +749,36 @@ fn test() {
         r"class Foo { #x; #y; method(a, b, c) { a ? (b ? this.#x : c) : this.#y; } }",
         r"class Foo { #x; method() { a && (b ? this.#x : c); } }",
         r"class Foo { #a; #b; #c; method() { this.#a ? this.#b : this.#c; } }",
+        r"class Outer { #a = 1; m() { class Inner { #a = 2; n() { return this.#a; } } } }",
+        r"class Outer { #a = 1; m() { class Inner { #a = 2; } return this.#a; } }",
+    ];
+
+    let fix = vec![
+        ("class Foo { #unused = 5; }", "class Foo {  }"),
+        (
+            "class Foo {
+                #unused = 5;
+                method() {}
+            }",
+            "class Foo {
+                method() {}
+            }",
+        ),
+        (
+            "class Foo {
+                #unusedMethod() {}
+            }",
+            "class Foo {
+            }",
+        ),
+        // Writes still reference the member, so it is not removed.
+        (
+            "class Foo { #a = 5; m() { this.#a = 1; } }",
+            "class Foo { #a = 5; m() { this.#a = 1; } }",
+        ),
     ];
 
     Tester::new(NoUnusedPrivateClassMembers::NAME, NoUnusedPrivateClassMembers::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
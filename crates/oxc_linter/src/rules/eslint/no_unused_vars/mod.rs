@@ -206,6 +206,8 @@ declare_oxc_lint!(
     config = NoUnusedVarsConfig,
     version = "0.7.0",
     short_description = "Disallows variable declarations, imports, or type declarations that are not used in code.",
+    // Declarations have side effects: they get merged together.
+    works_on_d_ts = false,
 );
 
 impl Deref for NoUnusedVars {
@@ -237,14 +239,9 @@ impl Rule for NoUnusedVars {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        // ignore .d.ts and vue/svelte/astro files.
-        // 1. declarations have side effects (they get merged together)
-        // 2. vue/svelte/astro scripts declare variables that get used in the template, which
-        //    we can't detect
-        !ctx.source_type().is_typescript_definition()
-            && !ctx
-                .file_extension()
-                .is_some_and(|ext| ext == "vue" || ext == "svelte" || ext == "astro")
+        // ignore vue/svelte/astro files, whose scripts declare variables that get used in the
+        // template, which we can't detect
+        !ctx.file_extension().is_some_and(|ext| ext == "vue" || ext == "svelte" || ext == "astro")
     }
 }
 
@@ -257,11 +254,22 @@ impl NoUnusedVars {
     ) {
         let is_ignored = self.is_ignored(symbol);
 
-        if is_ignored.is_some() && !self.report_used_ignore_pattern {
+        if let Some(reason) = *is_ignored
+            && !self.report_used_ignore_pattern
+        {
+            ctx.trace(|| format!("`{}` is ignored: {reason:?}", symbol.name()));
             return;
         }
 
-        let is_used = symbol.is_exported(exported_names) || symbol.has_usages(self);
+        let is_used = if symbol.is_exported(exported_names) {
+            ctx.trace(|| format!("`{}` is exported", symbol.name()));
+            true
+        } else if symbol.has_usages(self) {
+            ctx.trace(|| format!("`{}` is used", symbol.name()));
+            true
+        } else {
+            false
+        };
 
         match (is_used, *is_ignored) {
             // used, ignored because variable name matches one of several
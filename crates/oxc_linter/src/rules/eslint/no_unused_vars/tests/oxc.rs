@@ -1701,7 +1701,18 @@ import Layout from '../layouts/Layout.astro';
         ),
     ];
 
+    // Declarations get merged together, so nothing in a declaration file is unused.
+    let declaration_file_pass = vec![
+        "declare function identity<T, U>(value: T): T;",
+        "type Unused<T> = string;",
+        "interface Options { debug: boolean }",
+        "declare namespace NodeJS { interface ProcessEnv { NODE_ENV: string } }",
+        "declare global { interface Window { __APP__: unknown } }",
+        "import type { Foo } from './foo'; export {};",
+    ];
+
     Tester::new(NoUnusedVars::NAME, NoUnusedVars::PLUGIN, pass, vec![])
+        .with_declaration_file_cases(declaration_file_pass, vec![])
         .intentionally_allow_no_fix_tests()
         .test();
 }
@@ -1,5 +1,7 @@
 use itertools::Itertools;
 use oxc_allocator::{Allocator, BitSet, CloneIn};
+use schemars::JsonSchema;
+use serde::Deserialize;
 use smallvec::SmallVec;
 
 use oxc_ast::{
@@ -20,16 +22,44 @@ use oxc_semantic::{NodeId, Reference, ScopeId, SymbolId};
 use oxc_span::GetSpan;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn no_useless_assignment_diagnostic(span: Span, overwrite_span: Option<Span>) -> OxcDiagnostic {
+    let diagnostic =
+        OxcDiagnostic::warn("This assigned value is not used in subsequent statements.")
+            .with_help("Consider removing or reusing the assigned value.");
+    match overwrite_span {
+        Some(overwrite_span) => diagnostic.with_labels([
+            span.label("This value is never read"),
+            overwrite_span.label("It is overwritten here"),
+        ]),
+        None => diagnostic.with_label(span),
+    }
+}
 
-fn no_useless_assignment_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("This assigned value is not used in subsequent statements.")
-        .with_help("Consider removing or reusing the assigned value.")
-        .with_label(span)
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUselessAssignment(Box<NoUselessAssignmentConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUselessAssignmentConfig {
+    /// When `true`, declarations initialized with a literal value (e.g. `let x = 0;`)
+    /// are not reported, even if the value is overwritten before being read.
+    ///
+    /// These are often intentional defaults.
+    ignore_literal_initializers: bool,
 }
 
-#[derive(Debug, Default, Clone)]
-pub struct NoUselessAssignment;
+impl std::ops::Deref for NoUselessAssignment {
+    type Target = NoUselessAssignmentConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -96,9 +126,19 @@ declare_oxc_lint!(
     ///   }
     /// }
     /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "ignoreLiteralInitializers": true }`:
+    /// ```js
+    /// function fn() {
+    ///   let v = 0;
+    ///   v = compute();
+    ///   doSomething(v);
+    /// }
+    /// ```
     NoUselessAssignment,
     eslint,
     nursery,
+    config = NoUselessAssignmentConfig,
     version = "1.59.0",
     short_description = "Disallow variable assignments when the value is not used.",
 );
@@ -131,6 +171,10 @@ struct TrackedSymbol {
 }
 
 impl Rule for NoUselessAssignment {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let allocator = Allocator::default();
         let graph = ctx.cfg().graph();
@@ -168,7 +212,9 @@ impl Rule for NoUselessAssignment {
             });
 
             // Collect ops for this symbol (formerly Pass 2)
-            if var_decl.init.is_some() {
+            if let Some(init) = &var_decl.init
+                && !(self.ignore_literal_initializers && init.is_literal())
+            {
                 Self::push_op(
                     ctx,
                     graph,
@@ -372,6 +418,9 @@ impl Rule for NoUselessAssignment {
                     }
 
                     let mut is_in_try_block = None;
+                    // Writes seen later in this block with no read in between, so that a dead
+                    // store can point at the assignment that overwrites it.
+                    let mut overwritten_by: SmallVec<[(usize, NodeId); 4]> = SmallVec::new();
 
                     // Walk back from the end of the block to the start
                     for op in cfg_ops[current_block_id].iter().rev() {
@@ -404,12 +453,22 @@ impl Rule for NoUselessAssignment {
                                         } else {
                                             ctx.nodes().get_node(op.node).span()
                                         };
-                                    ctx.diagnostic(no_useless_assignment_diagnostic(span));
+                                    let overwrite_span = overwritten_by
+                                        .iter()
+                                        .find(|(idx, _)| *idx == compact_idx)
+                                        .map(|(_, node)| ctx.nodes().get_node(*node).span());
+                                    ctx.diagnostic(no_useless_assignment_diagnostic(
+                                        span,
+                                        overwrite_span,
+                                    ));
                                 }
                                 scratch_live.unset_bit(compact_idx);
+                                overwritten_by.retain(|(idx, _)| *idx != compact_idx);
+                                overwritten_by.push((compact_idx, op.node));
                             }
                             Operation::Read => {
                                 scratch_live.set_bit(compact_idx);
+                                overwritten_by.retain(|(idx, _)| *idx != compact_idx);
                             }
                         }
                     }
@@ -1579,3 +1638,30 @@ function useResource(unsafe: (resource: { readonly release: () => void }) => voi
     Tester::new(NoUselessAssignment::NAME, NoUselessAssignment::PLUGIN, pass, fail)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_ignore_literal_initializers() {
+    use crate::tester::Tester;
+
+    let options = Some(serde_json::json!([{ "ignoreLiteralInitializers": true }]));
+
+    let pass = vec![
+        ("let x = 0; x = compute(); console.log(x);", options.clone()),
+        (
+            "let x = 'default'; if (a) { x = 'a'; } else { x = 'b'; } console.log(x);",
+            options.clone(),
+        ),
+        ("let x = null; x = compute(); console.log(x);", options.clone()),
+    ];
+
+    let fail = vec![
+        ("let x = 0; x = compute(); console.log(x);", None),
+        ("let x = compute(); x = other(); console.log(x);", options.clone()),
+        ("let x = `${a}`; x = other(); console.log(x);", options.clone()),
+        ("let x = 0; x = 1; x = compute(); console.log(x);", options),
+    ];
+
+    Tester::new(NoUselessAssignment::NAME, NoUselessAssignment::PLUGIN, pass, fail)
+        .with_snapshot_suffix("ignore_literal_initializers")
+        .test_and_snapshot();
+}
@@ -4,9 +4,14 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule, utils::has_ambient_typescript_ancestor};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{has_ambient_typescript_ancestor, references_outside_block, var_declaring_block},
+};
 
 fn no_var_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected var, use let or const instead.")
@@ -52,6 +57,9 @@ declare_oxc_lint!(
     conditional_fix,
     version = "0.1.1",
     short_description = "Enforce using `let` or `const` over `var`.",
+    // `var` declarations in declaration files are ambient: unlike `let` and `const`, they
+    // describe properties of `globalThis`.
+    works_on_d_ts = false,
 );
 
 impl Rule for NoVar {
@@ -69,13 +77,14 @@ impl Rule for NoVar {
             let var_start = dec.span.start + var_offset;
             let var_keyword_span = Span::sized(var_start, 3);
             ctx.diagnostic_with_fix(no_var_diagnostic(var_keyword_span), |fixer| {
-                let parent_span = ctx.nodes().parent_kind(node.id()).span();
                 if dec.declarations.iter().any(|decl| {
                     decl.id.get_binding_identifiers().iter().any(|ident| {
-                        ctx.symbol_references(ident.symbol_id()).any(|id| {
-                            !parent_span
-                                .contains_inclusive(ctx.nodes().get_node(id.node_id()).span())
-                        })
+                        let symbol_id = ident.symbol_id();
+                        // Declarations in other blocks would be scoped to this one.
+                        var_declaring_block(symbol_id, ctx) != Some(node.scope_id())
+                            || references_outside_block(symbol_id, node.scope_id(), ctx)
+                                .next()
+                                .is_some()
                     })
                 }) {
                     return fixer.noop();
@@ -222,5 +231,15 @@ fn test() {
         ("declare var foo: 2;", "declare let foo: 2;"),
     ];
 
-    Tester::new(NoVar::NAME, NoVar::PLUGIN, pass, fail).expect_fix(fix).test_and_snapshot();
+    let declaration_file_pass = vec![
+        ("declare var foo: 2;", None),
+        ("declare var process: { env: Record<string, string | undefined> };", None),
+        ("export declare var version: string;", None),
+        ("declare global { var __DEV__: boolean; }", None),
+    ];
+
+    Tester::new(NoVar::NAME, NoVar::PLUGIN, pass, fail)
+        .with_declaration_file_cases(declaration_file_pass, vec![])
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
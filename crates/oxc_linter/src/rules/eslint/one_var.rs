@@ -0,0 +1,802 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        Expression, Statement, VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::NodeId;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+};
+
+fn combine_diagnostic(span: Span, kind: VariableDeclarationKind, which: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Combine this with the previous `{}` statement{which}.",
+        kind.as_str()
+    ))
+    .with_help("Declare these variables in a single statement.")
+    .with_label(span)
+}
+
+fn split_diagnostic(span: Span, kind: VariableDeclarationKind, which: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Split {which}`{}` declarations into multiple statements.",
+        kind.as_str()
+    ))
+    .with_help("Declare each variable in its own statement.")
+    .with_label(span)
+}
+
+fn split_requires_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Split requires to be separated into a single block.")
+        .with_help("Declare variables initialized with `require` in their own statement.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum Mode {
+    /// Requires one declaration statement per scope.
+    Always,
+    /// Requires one declaration statement per variable.
+    Never,
+    /// Requires consecutive declaration statements to be combined.
+    Consecutive,
+}
+
+/// Modes of one kind of declarations, e.g. `let`.
+#[derive(Debug, Default, Clone, Copy)]
+struct KindModes {
+    initialized: Option<Mode>,
+    uninitialized: Option<Mode>,
+}
+
+impl KindModes {
+    fn new(mode: Option<Mode>) -> Self {
+        Self { initialized: mode, uninitialized: mode }
+    }
+
+    fn is(self, initialized: Mode, uninitialized: Mode) -> bool {
+        self.initialized == Some(initialized) && self.uninitialized == Some(uninitialized)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OneVarConfig {
+    var: KindModes,
+    r#let: KindModes,
+    r#const: KindModes,
+    using: KindModes,
+    await_using: KindModes,
+    separate_requires: bool,
+}
+
+impl Default for OneVarConfig {
+    fn default() -> Self {
+        Self::from_mode(Mode::Always)
+    }
+}
+
+impl OneVarConfig {
+    fn from_mode(mode: Mode) -> Self {
+        let modes = KindModes::new(Some(mode));
+        Self {
+            var: modes,
+            r#let: modes,
+            r#const: modes,
+            using: modes,
+            await_using: modes,
+            separate_requires: false,
+        }
+    }
+
+    fn modes(&self, kind: VariableDeclarationKind) -> KindModes {
+        match kind {
+            VariableDeclarationKind::Var => self.var,
+            VariableDeclarationKind::Let => self.r#let,
+            VariableDeclarationKind::Const => self.r#const,
+            VariableDeclarationKind::Using => self.using,
+            VariableDeclarationKind::AwaitUsing => self.await_using,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OneVar(Box<OneVarConfig>);
+
+impl std::ops::Deref for OneVar {
+    type Target = OneVarConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Modes per kind of declarations. Kinds without a mode are not checked.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct OneVarKindsOptions {
+    var: Option<Mode>,
+    r#let: Option<Mode>,
+    r#const: Option<Mode>,
+    using: Option<Mode>,
+    await_using: Option<Mode>,
+    /// Whether declarations initialized with `require()` must be in a statement of their own,
+    /// with the `always` mode.
+    separate_requires: bool,
+    /// Mode for declarations with an initializer, for all kinds.
+    initialized: Option<Mode>,
+    /// Mode for declarations without an initializer, for all kinds.
+    uninitialized: Option<Mode>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+#[expect(unused)] // Only for schemars docs, not used in actual config parsing
+enum OneVarOptions {
+    /// Mode for all declarations.
+    Mode(Mode),
+    Kinds(OneVarKindsOptions),
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces variables to be declared either together or separately per function (for `var`)
+    /// or block (for `let`, `const` and `using`) scope.
+    ///
+    /// The mode can be set for all declarations (`"always"`, the default, `"never"` or
+    /// `"consecutive"`), per kind (`{ "var": "never", "const": "always" }`), or for
+    /// declarations with and without an initializer (`{ "initialized": "never",
+    /// "uninitialized": "consecutive" }`). With `separateRequires`, declarations initialized
+    /// with `require()` must be in a statement of their own in the `always` mode.
+    ///
+    /// Statements are split by the fixer, except in positions where splitting is not allowed,
+    /// such as `for` initializers. They are combined only with the directly preceding statement,
+    /// and not when comments or a different `declare` modifier are in between.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Mixing both styles in a codebase makes declarations harder to scan and diffs noisier.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```js
+    /// function foo() {
+    ///     var bar;
+    ///     var baz;
+    ///     let qux;
+    ///     let norf;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```js
+    /// function foo() {
+    ///     var bar,
+    ///         baz;
+    ///     let qux,
+    ///         norf;
+    /// }
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with the `"never"` option:
+    /// ```js
+    /// function foo() {
+    ///     var bar, baz;
+    ///     const qux = true, foobar = false;
+    /// }
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with the
+    /// `{ "initialized": "never", "uninitialized": "consecutive" }` option:
+    /// ```js
+    /// var a, b;
+    /// var c;
+    /// var d = 1, e = 2;
+    /// ```
+    OneVar,
+    eslint,
+    style,
+    fix,
+    config = OneVarOptions,
+    version = "next",
+    short_description = "Enforce variables to be declared either together or separately in functions.",
+);
+
+/// Whether the declarations of a kind in a scope have been seen, for the `always` mode.
+#[derive(Debug, Default, Clone, Copy)]
+struct ScopeState {
+    initialized: bool,
+    uninitialized: bool,
+    required: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct DeclarationCounts {
+    initialized: usize,
+    uninitialized: usize,
+}
+
+impl DeclarationCounts {
+    fn new(declarations: &[VariableDeclarator]) -> Self {
+        let initialized = declarations.iter().filter(|decl| decl.init.is_some()).count();
+        Self { initialized, uninitialized: declarations.len() - initialized }
+    }
+}
+
+impl Rule for OneVar {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        let config = match value.get(0) {
+            None => OneVarConfig::default(),
+            Some(value) if value.is_string() => {
+                OneVarConfig::from_mode(serde_json::from_value(value.clone())?)
+            }
+            Some(value) => {
+                let options = serde_json::from_value::<OneVarKindsOptions>(value.clone())?;
+                let modes = |mode| KindModes {
+                    initialized: options.initialized.or(mode),
+                    uninitialized: options.uninitialized.or(mode),
+                };
+                OneVarConfig {
+                    var: modes(options.var),
+                    r#let: modes(options.r#let),
+                    r#const: modes(options.r#const),
+                    using: modes(options.using),
+                    await_using: modes(options.await_using),
+                    separate_requires: options.separate_requires,
+                }
+            }
+        };
+        Ok(Self(Box::new(config)))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        // Nodes are in source order, so declarations are checked in the order ESLint visits them.
+        let mut scopes = FxHashMap::default();
+        for node in ctx.nodes() {
+            if let AstKind::VariableDeclaration(decl) = node.kind() {
+                self.check_declaration(node, decl, &mut scopes, ctx);
+            }
+        }
+    }
+}
+
+impl OneVar {
+    fn check_declaration<'a>(
+        &self,
+        node: &AstNode<'a>,
+        decl: &VariableDeclaration<'a>,
+        scopes: &mut FxHashMap<(NodeId, u8), ScopeState>,
+        ctx: &LintContext<'a>,
+    ) {
+        let modes = self.modes(decl.kind);
+        let counts = DeclarationCounts::new(&decl.declarations);
+        let parent = ctx.nodes().parent_node(node.id());
+
+        if modes.initialized == Some(Mode::Always)
+            && self.separate_requires
+            && has_mixed_requires(decl.declarations.iter())
+        {
+            ctx.diagnostic(split_requires_diagnostic(decl.span));
+        }
+
+        // consecutive
+        if let Some(Statement::VariableDeclaration(prev_decl)) = previous_statement(decl, parent)
+            && prev_decl.kind == decl.kind
+            && !has_mixed_requires(decl.declarations.iter().chain(&prev_decl.declarations))
+        {
+            let prev_counts = DeclarationCounts::new(&prev_decl.declarations);
+            let which = if modes.is(Mode::Consecutive, Mode::Consecutive) {
+                Some("")
+            } else if modes.initialized == Some(Mode::Consecutive)
+                && counts.initialized > 0
+                && prev_counts.initialized > 0
+            {
+                Some(" with initialized variables")
+            } else if modes.uninitialized == Some(Mode::Consecutive)
+                && counts.uninitialized > 0
+                && prev_counts.uninitialized > 0
+            {
+                Some(" with uninitialized variables")
+            } else {
+                None
+            };
+            if let Some(which) = which {
+                report_combine(decl, parent, which, ctx);
+            }
+        }
+
+        // always
+        if !self.has_only_one_statement(node, decl, counts, scopes, ctx) {
+            if modes.is(Mode::Always, Mode::Always) {
+                report_combine(decl, parent, "", ctx);
+            } else {
+                if modes.initialized == Some(Mode::Always) && counts.initialized > 0 {
+                    report_combine(decl, parent, " with initialized variables", ctx);
+                }
+                if modes.uninitialized == Some(Mode::Always) && counts.uninitialized > 0 {
+                    // `for (var a in b)` can't be combined
+                    if is_for_in_of_left(decl, parent) && counts.uninitialized == 1 {
+                        return;
+                    }
+                    report_combine(decl, parent, " with uninitialized variables", ctx);
+                }
+            }
+        }
+
+        // never
+        if matches!(parent.kind(), AstKind::ForStatement(for_stmt)
+            if for_stmt.init.as_ref().is_some_and(|init| init.span() == decl.span))
+        {
+            return;
+        }
+        if decl.declarations.len() > 1 {
+            let which = if modes.is(Mode::Never, Mode::Never) {
+                Some("")
+            } else if modes.initialized == Some(Mode::Never) && counts.initialized > 0 {
+                Some("initialized ")
+            } else if modes.uninitialized == Some(Mode::Never) && counts.uninitialized > 0 {
+                Some("uninitialized ")
+            } else {
+                None
+            };
+            if let Some(which) = which {
+                ctx.diagnostic_with_fix(split_diagnostic(decl.span, decl.kind, which), |fixer| {
+                    split_declarations(decl, parent, ctx, fixer)
+                });
+            }
+        }
+    }
+
+    /// Records the declarations of `decl` in its scope, and returns `false` if they should have
+    /// been declared in an earlier statement of the scope with the `always` mode.
+    fn has_only_one_statement(
+        &self,
+        node: &AstNode,
+        decl: &VariableDeclaration,
+        counts: DeclarationCounts,
+        scopes: &mut FxHashMap<(NodeId, u8), ScopeState>,
+        ctx: &LintContext,
+    ) -> bool {
+        let modes = self.modes(decl.kind);
+        let scope = scopes.entry((scope_id(node, decl.kind, ctx), decl.kind as u8)).or_default();
+        let has_requires = decl.declarations.iter().any(is_require);
+
+        if modes.is(Mode::Always, Mode::Always)
+            && (scope.uninitialized || scope.initialized)
+            && !has_requires
+        {
+            return false;
+        }
+        if counts.uninitialized > 0
+            && modes.uninitialized == Some(Mode::Always)
+            && scope.uninitialized
+        {
+            return false;
+        }
+        if counts.initialized > 0
+            && modes.initialized == Some(Mode::Always)
+            && scope.initialized
+            && !has_requires
+        {
+            return false;
+        }
+        if scope.required && has_requires {
+            return false;
+        }
+
+        for declarator in &decl.declarations {
+            if declarator.init.is_none() {
+                if modes.uninitialized == Some(Mode::Always) {
+                    scope.uninitialized = true;
+                }
+            } else if modes.initialized == Some(Mode::Always) {
+                if self.separate_requires && is_require(declarator) {
+                    scope.required = true;
+                } else {
+                    scope.initialized = true;
+                }
+            }
+        }
+        true
+    }
+}
+
+fn report_combine(decl: &VariableDeclaration, parent: &AstNode, which: &str, ctx: &LintContext) {
+    ctx.diagnostic_with_fix(combine_diagnostic(decl.span, decl.kind, which), |fixer| {
+        join_declarations(decl, parent, ctx, fixer)
+    });
+}
+
+/// The node whose scope declarations of `kind` belong to: the function for `var`, and the
+/// block for others.
+fn scope_id(node: &AstNode, kind: VariableDeclarationKind, ctx: &LintContext) -> NodeId {
+    ctx.nodes()
+        .ancestors(node.id())
+        .find(|ancestor| match ancestor.kind() {
+            AstKind::Program(_)
+            | AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::StaticBlock(_) => true,
+            AstKind::BlockStatement(_)
+            | AstKind::FunctionBody(_)
+            | AstKind::ForStatement(_)
+            | AstKind::ForInStatement(_)
+            | AstKind::ForOfStatement(_)
+            | AstKind::SwitchStatement(_) => !kind.is_var(),
+            _ => false,
+        })
+        .map_or(NodeId::ROOT, AstNode::id)
+}
+
+fn is_require(declarator: &VariableDeclarator) -> bool {
+    matches!(&declarator.init, Some(Expression::CallExpression(call))
+        if call.callee.is_specific_id("require"))
+}
+
+fn has_mixed_requires<'a, 'b: 'a>(
+    mut declarations: impl Iterator<Item = &'a VariableDeclarator<'b>> + Clone,
+) -> bool {
+    declarations.clone().any(is_require) && !declarations.all(is_require)
+}
+
+fn is_for_in_of_left(decl: &VariableDeclaration, parent: &AstNode) -> bool {
+    match parent.kind() {
+        AstKind::ForInStatement(for_in) => for_in.left.span() == decl.span,
+        AstKind::ForOfStatement(for_of) => for_of.left.span() == decl.span,
+        _ => false,
+    }
+}
+
+/// The statements of the statement list `node` is in, if any.
+fn statement_list<'a, 'b>(node: &'b AstNode<'a>) -> Option<&'b [Statement<'a>]> {
+    match node.kind() {
+        AstKind::Program(program) => Some(&program.body),
+        AstKind::BlockStatement(block) => Some(&block.body),
+        AstKind::FunctionBody(body) => Some(&body.statements),
+        AstKind::StaticBlock(block) => Some(&block.body),
+        AstKind::TSModuleBlock(block) => Some(&block.body),
+        _ => None,
+    }
+}
+
+fn previous_statement<'a, 'b>(
+    decl: &VariableDeclaration,
+    parent: &'b AstNode<'a>,
+) -> Option<&'b Statement<'a>> {
+    let statements = statement_list(parent)?;
+    let index = statements.iter().position(|stmt| stmt.span() == decl.span)?;
+    statements.get(index.checked_sub(1)?)
+}
+
+/// Merges `decl` into the previous statement, if it is a declaration of the same kind.
+fn join_declarations(
+    decl: &VariableDeclaration,
+    parent: &AstNode,
+    ctx: &LintContext,
+    fixer: RuleFixer<'_, '_>,
+) -> RuleFix {
+    let Some(Statement::VariableDeclaration(prev_decl)) = previous_statement(decl, parent) else {
+        return fixer.noop();
+    };
+    let (Some(prev_last), Some(first)) = (prev_decl.declarations.last(), decl.declarations.first())
+    else {
+        return fixer.noop();
+    };
+    let gap = Span::new(prev_last.span.end, first.span.start);
+    if prev_decl.kind != decl.kind
+        || prev_decl.declare != decl.declare
+        || ctx.has_comments_between(gap)
+    {
+        return fixer.noop();
+    }
+    // `;\n    var ` -> `,\n    `
+    let text = ctx.source_range(gap);
+    let after_semicolon = text.trim_start().strip_prefix(';').unwrap_or(text);
+    let indent = &after_semicolon[..after_semicolon.len() - after_semicolon.trim_start().len()];
+    fixer.replace(gap, format!(",{indent}"))
+}
+
+/// Splits `decl` into a statement per declarator, keeping comments after each comma with the
+/// following declarator.
+fn split_declarations(
+    decl: &VariableDeclaration,
+    parent: &AstNode,
+    ctx: &LintContext,
+    fixer: RuleFixer<'_, '_>,
+) -> RuleFix {
+    let is_export = matches!(parent.kind(), AstKind::ExportNamedDeclaration(_));
+    let statement_parent = if is_export { ctx.nodes().parent_node(parent.id()) } else { parent };
+    // e.g. `if (foo) var a, b;`
+    if statement_list(statement_parent).is_none()
+        && !matches!(statement_parent.kind(), AstKind::SwitchCase(_))
+    {
+        return fixer.noop();
+    }
+
+    let keyword = format!(
+        "{}{}{}",
+        if is_export { "export " } else { "" },
+        if decl.declare { "declare " } else { "" },
+        decl.kind.as_str()
+    );
+    let source_text = ctx.source_text();
+    let fixer = fixer.for_multifix();
+    let mut fix = fixer.new_fix_with_capacity(decl.declarations.len() - 1);
+    for (declarator, next) in decl.declarations.iter().zip(decl.declarations.iter().skip(1)) {
+        let Some(comma) = (declarator.span.end..next.span.start).find(|&pos| {
+            source_text.as_bytes()[pos as usize] == b',' && !ctx.is_inside_comment(pos)
+        }) else {
+            continue;
+        };
+        let comma_span = Span::sized(comma, 1);
+        let after_comma = Span::new(comma + 1, next.span.start);
+        let gap = ctx.source_range(after_comma);
+        let whitespace = &gap[..gap.len() - gap.trim_start().len()];
+        let is_comment_after = ctx.has_comments_between(after_comma);
+
+        if gap.is_empty() {
+            fix.push(fixer.replace(comma_span, format!("; {keyword} ")));
+        } else if whitespace.contains(['\n', '\r']) || is_comment_after {
+            fix.push(fixer.replace(Span::new(comma, next.span.start), format!(";{gap}{keyword} ")));
+        } else {
+            fix.push(fixer.replace(comma_span, format!("; {keyword}")));
+        }
+    }
+    fix.with_message("Split into multiple statements")
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function foo() { var bar = true; }", Some(json!(["always"]))),
+        (
+            "function foo() { var bar = true, baz = 1; if (qux) { bar = false; } }",
+            Some(json!(["always"])),
+        ),
+        ("var foo = function() { var bar = true; baz(); }", Some(json!(["always"]))),
+        ("function foo() { var bar = true, baz = false; }", Some(json!(["always"]))),
+        ("function foo() { var bar = true; var baz = false; }", Some(json!(["never"]))),
+        ("for (var i = 0, len = arr.length; i < len; i++) {}", Some(json!(["never"]))),
+        ("var bar = true; var baz = false;", Some(json!([{ "initialized": "never" }]))),
+        ("var bar = true, baz = false;", Some(json!([{ "initialized": "always" }]))),
+        ("var bar, baz;", Some(json!([{ "initialized": "never" }]))),
+        ("var bar; var baz;", Some(json!([{ "uninitialized": "never" }]))),
+        ("var bar, baz;", Some(json!([{ "uninitialized": "always" }]))),
+        ("var bar = true, baz = false;", Some(json!([{ "uninitialized": "never" }]))),
+        (
+            "var bar = true, baz = false, a, b;",
+            Some(json!([{ "uninitialized": "always", "initialized": "always" }])),
+        ),
+        (
+            "var bar = true; var baz = false; var a; var b;",
+            Some(json!([{ "uninitialized": "never", "initialized": "never" }])),
+        ),
+        (
+            "var bar, baz; var a = true; var b = false;",
+            Some(json!([{ "uninitialized": "always", "initialized": "never" }])),
+        ),
+        (
+            "var bar = true, baz = false; var a; var b;",
+            Some(json!([{ "uninitialized": "never", "initialized": "always" }])),
+        ),
+        (
+            "var bar; var baz; var a = true, b = false;",
+            Some(json!([{ "uninitialized": "never", "initialized": "always" }])),
+        ),
+        ("function foo() { var a = [1, 2, 3]; var [b, c, d] = a; }", Some(json!(["never"]))),
+        (
+            "function foo() { let a = 1; var c = true; let b = 2; }",
+            Some(json!([{ "var": "always" }])),
+        ),
+        (
+            "function foo() { const a = 1; var c = true; const b = 2; }",
+            Some(json!([{ "var": "always" }])),
+        ),
+        ("function foo() { let a = 1; let b = 2; }", Some(json!([{ "let": "never" }]))),
+        ("function foo() { const a = 1; const b = 2; }", Some(json!([{ "const": "never" }]))),
+        ("function foo() { let a = 1; const b = 2; }", Some(json!([{ "let": "always" }]))),
+        ("function foo() { const a = 1; let b = 2; }", Some(json!([{ "const": "always" }]))),
+        ("function foo() { let a = 1; let b = 2; }", Some(json!([{ "var": "always" }]))),
+        ("var a, b; var c; var d = 1, e = 2;", Some(json!([{ "let": "consecutive" }]))),
+        ("for (let x of foo) {}; for (let y of foo) {}", Some(json!(["always"]))),
+        (
+            "for (let x of foo) {}; for (let y of foo) {}",
+            Some(json!([{ "uninitialized": "always" }])),
+        ),
+        (
+            "for (let x in foo) {}; for (let y in foo) {}",
+            Some(json!([{ "uninitialized": "always" }])),
+        ),
+        (
+            "for (var x in foo) {}; for (var y in foo) {}",
+            Some(json!([{ "uninitialized": "always" }])),
+        ),
+        (
+            "var x; for (var y in foo) {}",
+            Some(json!([{ "initialized": "never", "uninitialized": "always" }])),
+        ),
+        (
+            "var x, y; for (y in foo) {}",
+            Some(json!([{ "initialized": "never", "uninitialized": "always" }])),
+        ),
+        (
+            "var x, y; for (var z in foo) {}",
+            Some(json!([{ "initialized": "never", "uninitialized": "always" }])),
+        ),
+        (
+            "var foo = require('foo'), bar = require('bar');",
+            Some(json!([{ "separateRequires": true, "var": "always" }])),
+        ),
+        (
+            "var bar = 'bar'; var foo = require('foo');",
+            Some(json!([{ "separateRequires": true, "var": "always" }])),
+        ),
+        (
+            "var foo = require('foo'); var bar = 'bar';",
+            Some(json!([{ "separateRequires": true, "var": "always" }])),
+        ),
+        ("var a = 0, b, c;", Some(json!(["consecutive"]))),
+        ("var a = 0, b = 1, c = 2;", Some(json!(["consecutive"]))),
+        ("var a = 0, b = 1; foo(); var c = 2;", Some(json!(["consecutive"]))),
+        ("let a = 0, b; const c = 1;", Some(json!(["consecutive"]))),
+        ("var a = 0; var b, c;", Some(json!([{ "initialized": "consecutive" }]))),
+        ("var a = 0; var b; var c = 1;", Some(json!([{ "initialized": "consecutive" }]))),
+        ("var a; var b = 0; var c;", Some(json!([{ "uninitialized": "consecutive" }]))),
+        ("var a, b; var c = 0;", Some(json!([{ "uninitialized": "consecutive" }]))),
+        (
+            "var a = 0; var b, c; var d = 1;",
+            Some(json!([{ "initialized": "never", "uninitialized": "consecutive" }])),
+        ),
+        (
+            "var a, b; var c = 0, d = 1;",
+            Some(json!([{ "uninitialized": "consecutive", "initialized": "always" }])),
+        ),
+        ("var a = require('a'); var b = 'b';", Some(json!(["consecutive"]))),
+        (
+            "const foo = require('foo'); const bar = 'bar';",
+            Some(json!([{ "const": "consecutive" }])),
+        ),
+        ("function foo() { var a = 0; { var b = 1; } }", Some(json!([{ "var": "consecutive" }]))),
+        ("var a = 0; { let b = 1; }", Some(json!(["always"]))),
+        ("class C { static { var a; } static { var b; } }", Some(json!(["always"]))),
+        ("class C { static { let a; } static { let b; } }", Some(json!(["always"]))),
+        ("var a; class C { static { var b; } }", Some(json!(["always"]))),
+        ("export let a, b;", Some(json!(["always"]))),
+        ("export let a, b = 1;", Some(json!(["consecutive"]))),
+        (
+            "switch (a) { case 0: var b = 1; break; case 1: var c = 2; }",
+            Some(json!(["consecutive"])),
+        ),
+        ("using a = foo(); await using b = bar();", Some(json!(["consecutive"]))),
+        ("var a = 1; var b = 2;", Some(json!([{ "let": "always" }]))),
+        ("var a = 1, b = 2;", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { var bar = true, baz = false; }", Some(json!(["never"]))),
+        ("function foo() { var bar = true; var baz = false; }", Some(json!(["always"]))),
+        ("function foo() { var bar = true; var baz = false; }", None),
+        ("var bar = true, baz = false;", Some(json!([{ "initialized": "never" }]))),
+        ("var bar, baz;", Some(json!([{ "uninitialized": "never" }]))),
+        (
+            "var bar = true; var baz = false; var a, b;",
+            Some(json!([{ "initialized": "always", "uninitialized": "never" }])),
+        ),
+        (
+            "var bar; var baz; var a = true, b = false;",
+            Some(json!([{ "uninitialized": "always", "initialized": "never" }])),
+        ),
+        ("let foo = true; let bar = false;", Some(json!([{ "let": "always" }]))),
+        ("const foo = true, bar = false;", Some(json!([{ "const": "never" }]))),
+        ("function foo() { let a = 1, b; if (bar) { a = 3; } }", Some(json!([{ "let": "never" }]))),
+        ("for (var x of foo) {}; var y;", Some(json!(["always"]))),
+        ("var x; for (var y of foo) {};", Some(json!(["always"]))),
+        ("var x; for (var y in foo) {};", Some(json!(["always"]))),
+        (
+            "var foo = require('foo'), bar = 'bar';",
+            Some(json!([{ "separateRequires": true, "var": "always" }])),
+        ),
+        ("var a = 0; var b = 1;", Some(json!(["consecutive"]))),
+        ("var a = 0; var b = 1; var c;", Some(json!(["consecutive"]))),
+        ("var a = 0; var b = 1; var c;", Some(json!([{ "initialized": "consecutive" }]))),
+        ("var a; var b; var c = 0;", Some(json!([{ "uninitialized": "consecutive" }]))),
+        (
+            "var a, b; var c;",
+            Some(json!([{ "initialized": "never", "uninitialized": "consecutive" }])),
+        ),
+        (
+            "var a = 1, b = 2;",
+            Some(json!([{ "initialized": "never", "uninitialized": "consecutive" }])),
+        ),
+        ("for (var i = 0, j = 1; i < 10; i++) {} var a = 1, b = 2;", Some(json!(["never"]))),
+        ("if (foo) var a, b;", Some(json!(["never"]))),
+        ("export var a = 1, b = 2;", Some(json!(["never"]))),
+        ("export const a = 1; export const b = 2;", Some(json!(["always"]))),
+        ("var a = 1; // comment\nvar b = 2;", Some(json!(["consecutive"]))),
+        ("declare var a: number; var b = 1;", Some(json!(["consecutive"]))),
+        ("class C { static { var a; var b; } }", Some(json!(["always"]))),
+        ("using a = foo(), b = bar();", Some(json!(["never"]))),
+        ("switch (a) { case 0: var b = 1, c = 2; }", Some(json!(["never"]))),
+    ];
+
+    let fix = vec![
+        ("var a, b;", "var a; var b;", Some(json!(["never"]))),
+        ("var a,b;", "var a; var b;", Some(json!(["never"]))),
+        ("let a = 1, b = 2, c = 3;", "let a = 1; let b = 2; let c = 3;", Some(json!(["never"]))),
+        ("var a,\n    b;", "var a;\n    var b;", Some(json!(["never"]))),
+        ("var a, // a\n    b; // b", "var a; // a\n    var b; // b", Some(json!(["never"]))),
+        ("var a, /* a */ b;", "var a; /* a */ var b;", Some(json!(["never"]))),
+        (
+            "export const a = 1, b = 2;",
+            "export const a = 1; export const b = 2;",
+            Some(json!(["never"])),
+        ),
+        (
+            "declare const a: number, b: string;",
+            "declare const a: number; declare const b: string;",
+            Some(json!(["never"])),
+        ),
+        (
+            "switch (a) { case 0: var b = 1, c = 2; }",
+            "switch (a) { case 0: var b = 1; var c = 2; }",
+            Some(json!(["never"])),
+        ),
+        // Splitting isn't allowed here
+        ("if (foo) var a, b;", "if (foo) var a, b;", Some(json!(["never"]))),
+        (
+            "var a = 1, b = 2, c;",
+            "var a = 1; var b = 2; var c;",
+            Some(json!([{ "initialized": "never", "uninitialized": "consecutive" }])),
+        ),
+        ("var a = 0; var b = 1;", "var a = 0, b = 1;", Some(json!(["consecutive"]))),
+        ("var a = 0\nvar b = 1", "var a = 0,\nb = 1", Some(json!(["consecutive"]))),
+        ("var a = 0;\n  var b = 1;", "var a = 0,\n  b = 1;", Some(json!(["consecutive"]))),
+        ("let a; let b;", "let a, b;", Some(json!([{ "uninitialized": "consecutive" }]))),
+        (
+            "function foo() { var bar = true; var baz = false; }",
+            "function foo() { var bar = true, baz = false; }",
+            None,
+        ),
+        (
+            "declare var a: number; declare var b: number;",
+            "declare var a: number, b: number;",
+            Some(json!(["consecutive"])),
+        ),
+        // Comments and different modifiers block merging
+        (
+            "var a = 1; // comment\nvar b = 2;",
+            "var a = 1; // comment\nvar b = 2;",
+            Some(json!(["consecutive"])),
+        ),
+        (
+            "declare var a: number; var b = 1;",
+            "declare var a: number; var b = 1;",
+            Some(json!(["consecutive"])),
+        ),
+        // Only the directly preceding statement can be merged
+        ("var a = 1; foo(); var b = 2;", "var a = 1; foo(); var b = 2;", None),
+        (
+            "export const a = 1; export const b = 2;",
+            "export const a = 1; export const b = 2;",
+            None,
+        ),
+    ];
+
+    Tester::new(OneVar::NAME, OneVar::PLUGIN, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -53,6 +53,11 @@ declare_oxc_lint!(
     /// It encourages the use of shorthand assignment operators like `+=`, `-=`, `*=`, `/=`, etc.
     /// to make the code more concise and readable.
     ///
+    /// Assignments to a commutative operator's right operand, like `x = y * x`, are reported
+    /// without a fix. Fixes are only offered when the target is an identifier or a member chain
+    /// without calls or computed keys which could have side effects, since the target is
+    /// evaluated once instead of twice, or twice instead of once.
+    ///
     /// ### Why is this bad?
     ///
     /// JavaScript provides shorthand operators that combine variable assignment and simple
@@ -256,27 +261,32 @@ operator_assignment_diagnostic(mode, expr.span, expr.operator.as_str(), true),
     }
 }
 
+/// Whether `target` can be written twice, or once instead of twice, without changing behavior:
+/// an identifier, or a member chain on an identifier or `this` without calls, whose computed keys
+/// are identifiers or literals.
 fn can_be_fixed(target: &AssignmentTarget) -> bool {
     let Some(simple_assignment_target) = target.as_simple_assignment_target() else { return false };
 
     if matches!(simple_assignment_target, SimpleAssignmentTarget::AssignmentTargetIdentifier(_)) {
         return true;
     }
-    let Some(expr) = simple_assignment_target.as_member_expression() else {
-        return false;
-    };
-    match expr {
+    simple_assignment_target.as_member_expression().is_some_and(is_side_effect_free_member)
+}
+
+fn is_side_effect_free_member(expr: &MemberExpression) -> bool {
+    let is_side_effect_free_key = match expr {
         MemberExpression::ComputedMemberExpression(computed_expr) => {
-            matches!(
-                computed_expr.object,
-                Expression::Identifier(_) | Expression::ThisExpression(_)
-            ) && computed_expr.expression.is_literal()
+            computed_expr.expression.is_literal()
+                || matches!(computed_expr.expression, Expression::Identifier(_))
         }
-        MemberExpression::StaticMemberExpression(static_expr) => {
-            matches!(static_expr.object, Expression::Identifier(_) | Expression::ThisExpression(_))
+        MemberExpression::StaticMemberExpression(_)
+        | MemberExpression::PrivateFieldExpression(_) => true,
+    };
+    is_side_effect_free_key
+        && match expr.object() {
+            Expression::Identifier(_) | Expression::ThisExpression(_) => true,
+            object => object.as_member_expression().is_some_and(is_side_effect_free_member),
         }
-        MemberExpression::PrivateFieldExpression(_) => false,
-    }
 }
 
 #[expect(clippy::cast_possible_truncation)]
@@ -528,6 +538,29 @@ fn test() {
         ("this.foo = this.foo + bar", "this.foo += bar", None),
         ("this.foo += bar", "this.foo = this.foo + bar", Some(serde_json::json!(["never"]))),
         ("foo[5] = foo[5] / baz", "foo[5] /= baz", None),
+        ("foo[bar] = foo[bar] + baz", "foo[bar] += baz", None),
+        ("foo.bar.baz = foo.bar.baz + qux", "foo.bar.baz += qux", None),
+        ("this.foo[0].bar = this.foo[0].bar * 2", "this.foo[0].bar *= 2", None),
+        (
+            "class C { #x; f() { this.#x = this.#x - 1; } }",
+            "class C { #x; f() { this.#x -= 1; } }",
+            None,
+        ),
+        (
+            "x.y[z['a']][0].b = x.y[z['a']][0].b * 2",
+            "x.y[z['a']][0].b = x.y[z['a']][0].b * 2",
+            None,
+        ),
+        ("x = y * x", "x = y * x", None),
+        ("foo[bar] >>>= baz", "foo[bar] = foo[bar] >>> baz", Some(serde_json::json!(["never"]))),
+        (
+            "foo.bar.baz += qux",
+            "foo.bar.baz = foo.bar.baz + qux",
+            Some(serde_json::json!(["never"])),
+        ),
+        ("foo[bar()] += baz", "foo[bar()] += baz", Some(serde_json::json!(["never"]))),
+        ("foo().bar += baz", "foo().bar += baz", Some(serde_json::json!(["never"]))),
+        ("(obj?.a).b += y", "(obj?.a).b += y", Some(serde_json::json!(["never"]))),
         ("this[5] = this[5] / foo", "this[5] /= foo", None),
         (
             "/*1*/x/*2*/./*3*/y/*4*/= x.y +/*5*/z/*6*/./*7*/w/*8*/;",
@@ -2,7 +2,11 @@ use oxc_ast::{AstKind, ast::Expression, match_member_expression};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
-use oxc_syntax::precedence::{GetPrecedence, Precedence};
+use oxc_syntax::{
+    identifier::is_identifier_part,
+    operator::BinaryOperator,
+    precedence::{GetPrecedence, Precedence},
+};
 
 use crate::{
     AstNode, ast_util::is_method_call, context::LintContext, globals::GLOBAL_OBJECT_NAMES,
@@ -97,22 +101,37 @@ impl Rule for PreferExponentiationOperator {
                 && let Some(base) = call_expr.arguments[0].as_expression()
                 && let Some(exponent) = call_expr.arguments[1].as_expression()
             {
-                let base_text = if does_base_need_parens(base) {
-                    format!("({})", base.span().source_text(ctx.source_text()))
-                } else {
-                    base.span().source_text(ctx.source_text()).to_string()
-                };
-                let exponent_text =
-                    get_exponent_text(fixer.source_range(exponent.span()), exponent);
-
+                let source_text = ctx.source_text();
+                let base_needs_parens = does_base_need_parens(base);
+                let exponent_needs_parens = does_exponent_need_parens(exponent);
+                let base_text = parenthesize_if(
+                    base.span().source_text(source_text).to_string(),
+                    base_needs_parens,
+                );
+                let exponent_text = parenthesize_if(
+                    fixer.source_range(exponent.span()).to_string(),
+                    exponent_needs_parens,
+                );
                 let replacement = format!("{base_text} ** {exponent_text}");
 
                 // Check if we need to wrap the entire expression in parentheses based on parent context
-                let replacement = if needs_parens_for_parent(node, ctx) {
-                    format!("({replacement})")
-                } else {
-                    replacement
+                if needs_parens_for_parent(node, ctx) {
+                    return fixer.replace(call_expr.span, format!("({replacement})"));
+                }
+
+                // Keep the replacement from merging with adjacent tokens,
+                // e.g. `a+Math.pow(++b, c)` -> `a+ ++b ** c` and `Math.pow(a, b)in c` -> `a ** b in c`.
+                let char_before = source_text[..call_expr.span.start as usize].chars().next_back();
+                let prefix = match (char_before, replacement.chars().next()) {
+                    (Some(before), Some(first)) if !can_be_adjacent(before, first) => " ",
+                    _ => "",
                 };
+                let char_after = source_text[call_expr.span.end as usize..].chars().next();
+                let suffix = match (replacement.chars().next_back(), char_after) {
+                    (Some(last), Some(after)) if !can_be_adjacent(last, after) => " ",
+                    _ => "",
+                };
+                let replacement = format!("{prefix}{replacement}{suffix}");
 
                 fixer.replace(call_expr.span, replacement)
             } else {
@@ -148,33 +167,49 @@ fn does_exponent_need_parens(expr: &Expression) -> bool {
     false
 }
 
-/// Gets the text for the exponent, adding parentheses if needed.
-fn get_exponent_text(source_text: &str, expr: &Expression) -> String {
-    if does_exponent_need_parens(expr) {
-        format!("({source_text})")
-    } else {
-        source_text.to_string()
-    }
+fn parenthesize_if(text: String, should_parenthesize: bool) -> String {
+    if should_parenthesize { format!("({text})") } else { text }
+}
+
+/// Whether the character `left` can be immediately followed by the character `right`
+/// without changing how the tokens they are part of are read.
+fn can_be_adjacent(left: char, right: char) -> bool {
+    let is_word_char = |c: char| is_identifier_part(c) || c.is_ascii_digit();
+    !(is_word_char(left) && is_word_char(right)
+        || matches!((left, right), ('+', '+') | ('-', '-') | ('/', '/' | '*')))
 }
 
+/// Whether `a ** b`, replacing the `Math.pow(a, b)` call in `node`, needs to be parenthesized
+/// in the context of its parent.
 fn needs_parens_for_parent(node: &AstNode, ctx: &LintContext) -> bool {
-    let parent = ctx.nodes().parent_node(node.id());
+    let span = node.span();
+    let mut parent = ctx.nodes().parent_node(node.id());
+    if matches!(parent.kind(), AstKind::ChainExpression(_)) {
+        parent = ctx.nodes().parent_node(parent.id());
+    }
 
     match parent.kind() {
         AstKind::BinaryExpression(bin_expr) => {
-            if bin_expr.operator == oxc_ast::ast::BinaryOperator::Exponential {
-                let AstKind::CallExpression(call_expr) = node.kind() else {
-                    return true;
-                };
-                bin_expr.right.span() != call_expr.span
+            if bin_expr.operator == BinaryOperator::Exponential {
+                // `**` is right-associative.
+                bin_expr.right.span() != span
             } else {
                 bin_expr.precedence() >= Precedence::Exponentiation
             }
         }
+        AstKind::CallExpression(call_expr) => call_expr.callee.span() == span,
+        AstKind::NewExpression(new_expr) => new_expr.callee.span() == span,
+        AstKind::StaticMemberExpression(member_expr) => member_expr.object.span() == span,
+        AstKind::ComputedMemberExpression(member_expr) => member_expr.object.span() == span,
+        AstKind::PrivateFieldExpression(member_expr) => member_expr.object.span() == span,
+        AstKind::TaggedTemplateExpression(tagged) => tagged.tag.span() == span,
+        AstKind::Class(class) => class.super_class.as_ref().is_some_and(|c| c.span() == span),
         AstKind::UnaryExpression(_)
         | AstKind::AwaitExpression(_)
         | AstKind::TSAsExpression(_)
-        | AstKind::TSSatisfiesExpression(_) => true,
+        | AstKind::TSSatisfiesExpression(_)
+        | AstKind::TSTypeAssertion(_)
+        | AstKind::TSNonNullExpression(_) => true,
         _ => false,
     }
 }
@@ -261,6 +296,35 @@ fn test() {
         ("Math.pow(a.b, c.d)", "a.b ** c.d"),
         // Call expressions don't need parens
         ("Math.pow(f(), g())", "f() ** g()"),
+        // Negative literals
+        ("Math.pow(-1, 2)", "(-1) ** 2"),
+        ("Math.pow(2, -1)", "2 ** -1"),
+        ("Math.pow(a, -b)", "a ** -b"),
+        ("-Math.pow(a, b)", "-(a ** b)"),
+        // Tokens next to the call must not merge with the replacement
+        ("a+Math.pow(++b, c)", "a+ ++b ** c"),
+        ("a-Math.pow(--b, c)", "a- --b ** c"),
+        ("a+Math.pow(b, c)", "a+b ** c"),
+        ("Math.pow(a, b)in c", "a ** b in c"),
+        ("Math.pow(a, b)instanceof C", "a ** b instanceof C"),
+        ("Math.pow(a, (b))in c", "a ** (b)in c"),
+        ("a in Math.pow(b, c)", "a in b ** c"),
+        // Template literal interpolations
+        ("`${Math.pow(a, b)}`", "`${a ** b}`"),
+        ("tag`${Math.pow(a, b)}`", "tag`${a ** b}`"),
+        ("Math.pow(a, b)`x`", "(a ** b)`x`"),
+        // Parent contexts
+        ("Math.pow(a, b).toString()", "(a ** b).toString()"),
+        ("(Math.pow(a, b)).toString()", "(a ** b).toString()"),
+        ("Math.pow(a, b)()", "(a ** b)()"),
+        ("Math.pow(a, b)!", "(a ** b)!"),
+        ("class C extends Math.pow(a, b) {}", "class C extends (a ** b) {}"),
+        ("foo(Math.pow(a, b))", "foo(a ** b)"),
+        ("foo[Math.pow(a, b)]", "foo[a ** b]"),
+        ("[Math.pow(a, b)]", "[a ** b]"),
+        ("a ? Math.pow(b, c) : d", "a ? b ** c : d"),
+        ("a * Math.pow(b, c)", "a * b ** c"),
+        ("a ** Math.pow(b, c)", "a ** b ** c"),
     ];
 
     Tester::new(
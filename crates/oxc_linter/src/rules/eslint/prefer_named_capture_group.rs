@@ -95,8 +95,16 @@ fn check_pattern(pattern: &Pattern<'_>, ctx: &LintContext<'_>, span_override: Op
     collector.visit_pattern(pattern);
 
     let count = collector.unnamed_spans.len();
+    if let Some(span) = span_override {
+        // The groups of a pattern built from several strings can't be located in the source, so
+        // the whole pattern is reported once.
+        if count > 0 {
+            ctx.diagnostic(prefer_named_capture_group_diagnostic(span, count));
+        }
+        return;
+    }
     for span in collector.unnamed_spans {
-        ctx.diagnostic(prefer_named_capture_group_diagnostic(span_override.unwrap_or(span), count));
+        ctx.diagnostic(prefer_named_capture_group_diagnostic(span, count));
     }
 }
 
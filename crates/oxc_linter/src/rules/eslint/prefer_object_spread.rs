@@ -2,7 +2,9 @@ use std::cmp::max;
 
 use oxc_allocator::ArenaBox;
 use oxc_ast::AstKind;
-use oxc_ast::ast::{Expression, ObjectExpression, ObjectPropertyKind, PropertyKind};
+use oxc_ast::ast::{
+    Expression, IdentifierReference, ObjectExpression, ObjectPropertyKind, PropertyKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -89,19 +91,21 @@ impl Rule for PreferObjectSpread {
             return;
         };
 
-        let unresolved_references = ctx.scoping().root_unresolved_references();
+        // `Object` (or `globalThis`) must resolve to the global, not to a local binding that
+        // happens to share the name in any enclosing scope.
+        let is_global_reference = |ident: &IdentifierReference| {
+            ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+        };
 
         match callee.object().get_inner_expression() {
             Expression::Identifier(ident) => {
-                if ident.name != "Object" || !unresolved_references.contains_key(&ident.name) {
+                if ident.name != "Object" || !is_global_reference(ident) {
                     return;
                 }
             }
             Expression::StaticMemberExpression(member_expr) => {
                 if let Expression::Identifier(ident) = member_expr.object.get_inner_expression() {
-                    if ident.name != "globalThis"
-                        || !unresolved_references.contains_key(&ident.name)
-                    {
+                    if ident.name != "globalThis" || !is_global_reference(ident) {
                         return;
                     }
                 } else {
@@ -438,6 +442,19 @@ fn test() {
         "Object.assign({}, { set a(val) {} })",
         "Object.assign({}, { foo: 'bar', get a() {} }, {})",
         "Object.assign({ foo }, bar, {}, { baz: 'quux', set a(val) {}, quuux }, {})",
+        "
+        function foo(Object) {
+            return Object.assign({}, bar);
+        }
+        Object.keys(baz);
+        ",
+        "
+        Object.keys(baz);
+        {
+            const Object = createObjectHelper();
+            Object.assign({}, bar);
+        }
+        ",
     ];
 
     let fail = vec![
@@ -601,9 +618,19 @@ fn test() {
         "Object.assign({ get a() {}, set b(val) {} })",
         "const obj = Object.assign<{}, Record<string, string[]>>({}, getObject());", // {                "parser": require("../../fixtures/parsers/typescript-parsers/object-assign-with-generic/object-assign-with-generic-1")            },
         "Object.assign<{}, A>({}, foo);", // {                "parser": require("../../fixtures/parsers/typescript-parsers/object-assign-with-generic/object-assign-with-generic-2")            }
+        "async function foo() { return Object.assign({}, await bar, a ? b : c) }",
+        "
+        function foo(Object) { Object.keys(bar); }
+        Object.assign({}, baz);
+        ",
     ];
 
     let fix = vec![
+        (
+            "async function foo() { return Object.assign({}, await bar, a ? b : c) }",
+            "async function foo() { return { ...await bar, ...(a ? b : c)} }",
+            None,
+        ),
         ("Object.assign({}, foo)", "({ ...foo})", None),
         ("Object.assign  ({}, foo)", "({ ...foo})", None),
         ("Object.assign({}, { foo: 'bar' })", "({ foo: 'bar'})", None),
@@ -134,7 +134,9 @@ fn check_reject_call(call_expr: &CallExpression, ctx: &LintContext, allow_empty_
     }
 
     if call_expr.arguments.is_empty()
-        || call_expr.arguments[0].as_expression().is_some_and(|e| !could_be_error(ctx, e))
+        || call_expr.arguments[0]
+            .as_expression()
+            .is_some_and(|e| !could_be_error(ctx.semantic(), e))
         || is_undefined(&call_expr.arguments[0])
     {
         ctx.diagnostic(prefer_promise_reject_errors_diagnostic(call_expr.span));
@@ -1,12 +1,16 @@
+use std::borrow::Cow;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use oxc_allocator::Allocator;
 use oxc_ast::{
     AstKind,
-    ast::{Argument, Expression},
+    ast::{Argument, BinaryOperator, Expression},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{LiteralParser, Options};
 use oxc_span::Span;
 
 use crate::{
@@ -109,6 +113,7 @@ declare_oxc_lint!(
     /// new RegExp("\\d\\d\\.\\d\\d\\.\\d\\d\\d\\d");
     /// RegExp(`^\\d\\.$`);
     /// new RegExp(String.raw`^\d\.$`);
+    /// new RegExp("^\\d" + "\\.$");
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -124,10 +129,16 @@ declare_oxc_lint!(
     /// RegExp(`${prefix}abc`);
     /// new RegExp(String.raw`^\d\. ${suffix}`);
     /// ```
+    ///
+    /// The fix converts the call into the equivalent regular expression literal, escaping `/`.
+    /// It is skipped when the pattern contains a line break, when the pattern and flags don't form
+    /// a valid regular expression literal, and when a `/` at that position could be parsed as
+    /// division or the start of a comment, e.g. after `)` or on a line following an expression
+    /// without a semicolon.
     PreferRegexLiterals,
     eslint,
     style,
-    pending,
+    fix,
     config = PreferRegexLiterals,
     version = "1.64.0",
     short_description = "Disallow use of the RegExp constructor in favor of regular expression literals.",
@@ -156,13 +167,28 @@ impl Rule for PreferRegexLiterals {
         if self.0.disallow_redundant_wrapping
             && let Some(has_flags_argument) = is_unnecessarily_wrapped_regex_literal(arguments, ctx)
         {
-            if has_flags_argument {
-                ctx.diagnostic(unexpected_redundant_regexp_with_flags_diagnostic(span));
+            let diagnostic = if has_flags_argument {
+                unexpected_redundant_regexp_with_flags_diagnostic(span)
             } else {
-                ctx.diagnostic(unexpected_redundant_regexp_diagnostic(span));
-            }
+                unexpected_redundant_regexp_diagnostic(span)
+            };
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                match unwrapped_regex_literal(arguments, ctx) {
+                    Some(literal) if can_replace_with_regex_literal(span, ctx) => {
+                        fixer.replace(span, literal)
+                    }
+                    _ => fixer.noop(),
+                }
+            });
         } else if has_only_static_string_arguments(arguments, ctx) {
-            ctx.diagnostic(unexpected_regexp_diagnostic(span));
+            ctx.diagnostic_with_fix(unexpected_regexp_diagnostic(span), |fixer| {
+                match regex_literal_from_arguments(arguments, ctx) {
+                    Some(literal) if can_replace_with_regex_literal(span, ctx) => {
+                        fixer.replace(span, literal)
+                    }
+                    _ => fixer.noop(),
+                }
+            });
         }
     }
 }
@@ -195,18 +221,140 @@ fn is_regex_literal_argument(argument: &Argument) -> bool {
 }
 
 fn is_static_string_argument(argument: &Argument, ctx: &LintContext) -> bool {
-    let Some(expr) = argument.as_expression().map(Expression::get_inner_expression) else {
-        return false;
+    argument.as_expression().is_some_and(|expr| static_string_value(expr, ctx).is_some())
+}
+
+/// Returns the value of a string literal, a template literal without substitutions, a
+/// `String.raw` tagged template without substitutions, or a `+` concatenation of these.
+fn static_string_value<'a>(expr: &Expression<'a>, ctx: &LintContext) -> Option<Cow<'a, str>> {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(lit) => Some(Cow::Borrowed(lit.value.as_str())),
+        Expression::TemplateLiteral(template) if template.is_no_substitution_template() => {
+            template.quasis[0].value.cooked.map(|cooked| Cow::Borrowed(cooked.as_str()))
+        }
+        Expression::TaggedTemplateExpression(tagged)
+            if tagged.quasi.is_no_substitution_template()
+                && is_string_raw_member_expression(&tagged.tag, ctx.scoping()) =>
+        {
+            Some(Cow::Borrowed(tagged.quasi.quasis[0].value.raw.as_str()))
+        }
+        Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+            let left = static_string_value(&binary.left, ctx)?;
+            let right = static_string_value(&binary.right, ctx)?;
+            Some(Cow::Owned(left.into_owned() + &right))
+        }
+        _ => None,
+    }
+}
+
+/// `new RegExp("a/b", "g")` -> `/a\/b/g`
+///
+/// Returns `None` if the pattern can't be written as a literal.
+fn regex_literal_from_arguments(arguments: &[Argument], ctx: &LintContext) -> Option<String> {
+    let pattern = static_string_value(arguments.first()?.as_expression()?, ctx)?;
+    let flags = match arguments.get(1) {
+        Some(flags) => static_string_value(flags.as_expression()?, ctx)?,
+        None => Cow::Borrowed(""),
     };
 
-    match expr {
-        Expression::StringLiteral(_) => true,
-        Expression::TemplateLiteral(template) => template.is_no_substitution_template(),
-        Expression::TaggedTemplateExpression(tagged) => {
-            tagged.quasi.is_no_substitution_template()
-                && is_string_raw_member_expression(&tagged.tag, ctx.scoping())
+    // Line breaks can't appear in a regular expression literal. Tabs, vertical tabs and form
+    // feeds are written as escapes. Other control characters are not fixed, to keep the
+    // fixed pattern readable.
+    let mut source = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                source.push(c);
+                match chars.next() {
+                    Some(next) if next.is_control() => return None,
+                    next => source.extend(next),
+                }
+            }
+            '/' => source.push_str("\\/"),
+            '\t' => source.push_str("\\t"),
+            '\u{b}' => source.push_str("\\v"),
+            '\u{c}' => source.push_str("\\f"),
+            '\u{2028}' | '\u{2029}' => return None,
+            c if c.is_control() => return None,
+            _ => source.push(c),
+        }
+    }
+    if source.is_empty() {
+        source.push_str("(?:)");
+    }
+
+    validated_regex_literal(&source, &flags)
+}
+
+/// `new RegExp(/a/)` -> `/a/`, `new RegExp(/a/i, "g")` -> `/a/g`
+///
+/// The flags argument replaces the flags of the literal, as it does at runtime.
+fn unwrapped_regex_literal(arguments: &[Argument], ctx: &LintContext) -> Option<String> {
+    let Expression::RegExpLiteral(literal) =
+        arguments.first()?.as_expression()?.get_inner_expression()
+    else {
+        return None;
+    };
+    match arguments.get(1) {
+        Some(flags) => {
+            let flags = static_string_value(flags.as_expression()?, ctx)?;
+            validated_regex_literal(literal.regex.pattern.text.as_str(), &flags)
+        }
+        None => Some(literal.span.source_text(ctx.source_text()).to_string()),
+    }
+}
+
+fn validated_regex_literal(source: &str, flags: &str) -> Option<String> {
+    let allocator = Allocator::default();
+    LiteralParser::new(&allocator, source, Some(flags), Options::default()).parse().ok()?;
+    Some(format!("/{source}/{flags}"))
+}
+
+/// Whether the code at `span` can be replaced with a regex literal without changing how the
+/// surrounding code is parsed.
+///
+/// A `/` right after an expression would be parsed as division, e.g. in `foo()\nRegExp("a")`
+/// without a semicolon, and `//` or `/*` would start a comment. Identifier characters right
+/// after the literal would be parsed as flags. Comments inside the call would be lost.
+fn can_replace_with_regex_literal(span: Span, ctx: &LintContext) -> bool {
+    const KEYWORDS: [&str; 14] = [
+        "await",
+        "case",
+        "delete",
+        "do",
+        "else",
+        "in",
+        "instanceof",
+        "new",
+        "of",
+        "return",
+        "throw",
+        "typeof",
+        "void",
+        "yield",
+    ];
+    fn is_identifier_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_' || c == '$'
+    }
+
+    if ctx.semantic().has_comments_between(span) {
+        return false;
+    }
+
+    let source_text = ctx.source_text();
+    if source_text[span.end as usize..].starts_with(is_identifier_char) {
+        return false;
+    }
+
+    let before = source_text[..span.start as usize].trim_end();
+    match before.chars().last() {
+        None => true,
+        Some(c) if is_identifier_char(c) => {
+            let word = before.rsplit(|c| !is_identifier_char(c)).next().unwrap_or_default();
+            KEYWORDS.contains(&word)
         }
-        _ => false,
+        Some(c) => !matches!(c, '/' | ')' | ']' | '}' | '\'' | '"' | '`'),
     }
 }
 
@@ -218,8 +366,6 @@ fn test() {
         ("/abc/", None),
         ("/abc/g", None),
         ("new RegExp(pattern)", None),
-        (r"new RegExp('\\p{Emoji_Presentation}\\P{Script_Extensions=Latin}' + '', `ug`)", None),
-        (r"new RegExp('\\cA' + '')", None),
         ("RegExp(pattern, 'g')", None),
         ("new RegExp(f('a'))", None),
         ("RegExp(prefix + 'a')", None),
@@ -235,15 +381,11 @@ fn test() {
         ("new RegExp(`a${''}c`);", None),
         ("new RegExp(String.raw`a${b}c`);", None),
         ("new RegExp(String.raw`a${''}c`);", None),
-        ("new RegExp('a' + 'b')", None),
         ("RegExp(1)", None),
-        (r"new RegExp('(\\p{Emoji_Presentation})\\1' + '', `ug`)", None),
-        (r"RegExp(String.raw`\78\126` + '\\5934', '' + `g` + '')", None),
         (
             r"func(new RegExp(String.raw`a${''}c\d`, 'u'),new RegExp(String.raw`a${''}c\d`, 'u'))",
             None,
         ),
-        (r#"new RegExp('\\[' + "b\\]")"#, None),
         (
             "new RegExp(/a/, flags);",
             Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
@@ -764,8 +906,64 @@ fn test() {
         ("new RegExp('(?i:foo)bar')", None), // { "ecmaVersion": 2025 },
         ("new RegExp('(?i:foo)bar')", None), // { "ecmaVersion": 2024 },
         ("var regex = new RegExp('foo', 'u');", None), // { "ecmaVersion": 2015, }
+        // Concatenations of static strings
+        (r"new RegExp('\\p{Emoji_Presentation}\\P{Script_Extensions=Latin}' + '', `ug`)", None),
+        (r"new RegExp('\\cA' + '')", None),
+        ("new RegExp('a' + 'b')", None),
+        (r"new RegExp('(\\p{Emoji_Presentation})\\1' + '', `ug`)", None),
+        (r"RegExp(String.raw`\78\126` + '\\5934', '' + `g` + '')", None),
+        (r#"new RegExp('\\[' + "b\\]")"#, None),
+    ];
+
+    let fix = vec![
+        ("new RegExp('abc');", "/abc/;", None),
+        ("RegExp('abc', 'g');", "/abc/g;", None),
+        (r#"new RegExp("\\d\\.$");"#, r"/\d\.$/;", None),
+        ("new RegExp('a/b');", r"/a\/b/;", None),
+        (r"new RegExp('a\\/b');", r"/a\/b/;", None),
+        ("const re = new RegExp('[/]');", r"const re = /[\/]/;", None),
+        ("new RegExp('');", "/(?:)/;", None),
+        ("new RegExp(String.raw`\\d+`, 'u');", r"/\d+/u;", None),
+        ("new RegExp(`abc`, `i`);", "/abc/i;", None),
+        ("new RegExp('a' + 'b', 'g');", "/ab/g;", None),
+        (r"new RegExp('\t\v\f');", r"/\t\v\f/;", None),
+        (r"new RegExp('\0');", r"new RegExp('\0');", None),
+        ("function f() { return RegExp('a'); }", "function f() { return /a/; }", None),
+        ("foo(RegExp('a'), RegExp('b'));", "foo(/a/, /b/);", None),
+        // Line breaks can't appear in a regular expression literal.
+        (r#"new RegExp("\\\n")"#, r#"new RegExp("\\\n")"#, None),
+        // Not a valid regular expression literal.
+        ("new RegExp('(');", "new RegExp('(');", None),
+        ("new RegExp('a', 'gg');", "new RegExp('a', 'gg');", None),
+        // A `/` here would be parsed as division or a comment.
+        ("foo()\nRegExp('a').test(x)", "foo()\nRegExp('a').test(x)", None),
+        ("a /RegExp('b')", "a /RegExp('b')", None),
+        // Identifier characters after the literal would be parsed as flags.
+        ("RegExp('a')instanceof RegExp", "RegExp('a')instanceof RegExp", None),
+        // The comment would be lost.
+        (
+            "new RegExp('mysafereg' /* comment explaining its safety */)",
+            "new RegExp('mysafereg' /* comment explaining its safety */)",
+            None,
+        ),
+        (
+            "new RegExp(/a/);",
+            "/a/;",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+        (
+            "new RegExp(/a/g, 'u');",
+            "/a/u;",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+        (
+            "new RegExp(/[[A--B]]/v, 'u');",
+            "new RegExp(/[[A--B]]/v, 'u');",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
     ];
 
     Tester::new(PreferRegexLiterals::NAME, PreferRegexLiterals::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
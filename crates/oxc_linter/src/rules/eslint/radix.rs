@@ -8,7 +8,11 @@ use oxc_span::{GetSpan, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
 
 fn missing_parameters(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Missing parameters.")
@@ -22,25 +26,30 @@ fn missing_radix(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-fn invalid_radix(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Invalid radix parameter, must be an integer between 2 and 36.")
-        .with_help("The radix parameter should be an integer between 2 and 36, or a variable that is not `undefined`.")
+fn invalid_radix(radix: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Invalid radix parameter `{radix}`, must be an integer between 2 and 36."
+    ))
+    .with_help("The radix parameter should be an integer between 2 and 36, or a variable that is not `undefined`.")
+    .with_label(span)
+}
+
+fn redundant_radix(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Redundant radix parameter.")
+        .with_help("Remove the radix parameter `10`, which is the default for decimal numbers.")
         .with_label(span)
 }
 
-// `RadixType` has no effect, it is only here for backward compatibility.
-// Without it, the linter will report unknown rule configuration error.
-#[derive(Debug, Default, Clone, JsonSchema, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+#[derive(Debug, Default, Clone, Deserialize)]
 pub struct Radix(RadixType);
 
-#[derive(Debug, Default, Clone, JsonSchema, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 enum RadixType {
     /// Always require the radix parameter when using `parseInt()`.
     #[default]
     Always,
-    /// Only require the radix parameter when necessary.
+    /// Only require the radix parameter when it is not `10`, and disallow a redundant `10`.
     AsNeeded,
 }
 
@@ -63,38 +72,47 @@ declare_oxc_lint!(
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseInt#radix)
     /// for more information on how `parseInt()` handles certain edge-cases.
     ///
-    /// ### Configuration
-    ///
-    /// Note that passing an option to this rule has no effect on its behavior.
-    /// In v1.49.0, the config option for this rule was removed and made a no-op.
-    /// This matches the behavior change made in ESLint v10, and the rule now
-    /// always enforces that a radix parameter is provided to `parseInt()`.
-    ///
-    /// If you receive new violations due to this change, you may either opt
-    /// to disable this rule, or add the radix parameter to all usages of
-    /// `parseInt()` in your codebase.
+    /// Only calls to the global `parseInt` and `Number.parseInt` are checked, so calls to a
+    /// local binding named `parseInt` are ignored.
     ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
     /// ```javascript
     /// let num = parseInt("071");      // 57
+    /// let other = parseInt("10", 1);  // invalid radix
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
     /// let num = parseInt("071", 10);  // 71
+    /// let other = parseInt("10", radix);
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with the `"as-needed"` option:
+    /// ```javascript
+    /// let num = parseInt("071", 10);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the `"as-needed"` option:
+    /// ```javascript
+    /// let num = parseInt("071");
+    /// let hex = parseInt("ff", 16);
     /// ```
     Radix,
     eslint,
     pedantic,
-    conditional_fix_dangerous,
+    conditional_suggestion,
     config = RadixType,
     version = "0.3.3",
     short_description = "Enforce the consistent use of the radix argument when using `parseInt()`, which specifies what base to use for parsing the number.",
 );
 
 impl Rule for Radix {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -102,7 +120,7 @@ impl Rule for Radix {
 
         match call_expr.callee.without_parentheses() {
             Expression::Identifier(ident) if Self::is_global_parse_int_ident(ident, ctx) => {
-                Self::check_arguments(call_expr, ctx);
+                self.check_arguments(call_expr, ctx);
             }
             Expression::StaticMemberExpression(member_expr)
                 if member_expr.property.name == "parseInt" =>
@@ -110,7 +128,7 @@ impl Rule for Radix {
                 if let Expression::Identifier(ident) = member_expr.object.without_parentheses()
                     && Self::is_global_number_ident(ident, ctx)
                 {
-                    Self::check_arguments(call_expr, ctx);
+                    self.check_arguments(call_expr, ctx);
                 }
             }
             Expression::ChainExpression(chain_expr) => {
@@ -119,7 +137,7 @@ impl Rule for Radix {
                     && member_expr.static_property_name() == Some("parseInt")
                     && Self::is_global_number_ident(ident, ctx)
                 {
-                    Self::check_arguments(call_expr, ctx);
+                    self.check_arguments(call_expr, ctx);
                 }
             }
             _ => {}
@@ -136,12 +154,18 @@ impl Radix {
         ident.name == "parseInt" && ctx.is_reference_to_global_variable(ident)
     }
 
-    fn check_arguments(call_expr: &CallExpression, ctx: &LintContext) {
+    fn check_arguments(&self, call_expr: &CallExpression, ctx: &LintContext) {
         match call_expr.arguments.len() {
             0 => ctx.diagnostic(missing_parameters(call_expr.span)),
+            1 if self.0 == RadixType::AsNeeded => {}
             1 => {
-                ctx.diagnostic_with_dangerous_fix(missing_radix(call_expr.span), |fixer| {
-                    let first_arg = &call_expr.arguments[0];
+                let first_arg = &call_expr.arguments[0];
+                // A spread argument may already provide the radix.
+                if first_arg.is_spread() {
+                    ctx.diagnostic(missing_radix(call_expr.span));
+                    return;
+                }
+                ctx.diagnostic_with_suggestion(missing_radix(call_expr.span), |fixer| {
                     let end = call_expr.span.end;
                     let has_trailing_comma =
                         ctx.find_next_token_within(first_arg.span().end, end, ",").is_some();
@@ -152,13 +176,22 @@ impl Radix {
             _ => {
                 let radix_arg = &call_expr.arguments[1];
                 if !is_valid_radix(radix_arg) {
-                    ctx.diagnostic(invalid_radix(radix_arg.span()));
+                    ctx.diagnostic(invalid_radix(
+                        ctx.source_range(radix_arg.span()),
+                        radix_arg.span(),
+                    ));
+                } else if self.0 == RadixType::AsNeeded && is_default_radix(radix_arg) {
+                    ctx.diagnostic(redundant_radix(radix_arg.span()));
                 }
             }
         }
     }
 }
 
+fn is_default_radix(node: &Argument) -> bool {
+    matches!(node, Argument::NumericLiteral(lit) if (lit.value - 10.0).abs() < f64::EPSILON)
+}
+
 fn is_valid_radix(node: &Argument) -> bool {
     let Some(expr) = node.as_expression() else {
         return false;
@@ -199,11 +232,17 @@ fn test() {
         // ("/* globals parseInt:off */ parseInt(foo);", None, None),
         ("Number.parseInt(foo);", None, Some(serde_json::json!({"globals": {"Number": "off"} }))),
         (r#"parseInt("10", 10);"#, Some(serde_json::json!(["always"])), None),
-        (r#"parseInt("10", 10);"#, Some(serde_json::json!(["as-needed"])), None),
         (r#"parseInt("10", 8);"#, Some(serde_json::json!(["always"])), None),
         (r#"parseInt("10", 8);"#, Some(serde_json::json!(["as-needed"])), None),
         (r#"parseInt("10", foo);"#, Some(serde_json::json!(["always"])), None),
         (r#"parseInt("10", foo);"#, Some(serde_json::json!(["as-needed"])), None),
+        (r#"parseInt("10");"#, Some(serde_json::json!(["as-needed"])), None),
+        (r#"Number.parseInt("10");"#, Some(serde_json::json!(["as-needed"])), None),
+        (r#"parseInt("10", 16);"#, Some(serde_json::json!(["as-needed"])), None),
+        // `parseInt` is shadowed by a local binding.
+        (r#"function f(parseInt) { parseInt("10"); }"#, None, None),
+        (r#"import { parseInt } from "./parse"; parseInt("10");"#, None, None),
+        (r#"const Number = { parseInt() {} }; Number.parseInt("10");"#, None, None),
     ];
 
     let fail = vec![
@@ -234,11 +273,13 @@ fn test() {
         ("parseInt();", Some(serde_json::json!(["always"])), None),
         ("parseInt();", Some(serde_json::json!(["as-needed"])), None),
         (r#"parseInt("10");"#, Some(serde_json::json!(["always"])), None),
-        (r#"parseInt("10");"#, Some(serde_json::json!(["as-needed"])), None),
+        (r#"parseInt("10", 10);"#, Some(serde_json::json!(["as-needed"])), None),
+        (r#"Number.parseInt("10", 10.0);"#, Some(serde_json::json!(["as-needed"])), None),
         (r#"parseInt("10", 1);"#, Some(serde_json::json!(["always"])), None),
         (r#"parseInt("10", 1);"#, Some(serde_json::json!(["as-needed"])), None),
         ("Number.parseInt();", Some(serde_json::json!(["always"])), None),
         ("Number.parseInt();", Some(serde_json::json!(["as-needed"])), None),
+        ("parseInt(...args);", None, None),
     ];
 
     let fix = vec![
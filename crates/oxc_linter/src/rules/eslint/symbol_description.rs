@@ -1,6 +1,9 @@
-use oxc_ast::AstKind;
+use std::borrow::Cow;
+
+use oxc_ast::{AstKind, ast::CallExpression};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
 use oxc_span::Span;
 
 use crate::{AstNode, context::LintContext, rule::Rule};
@@ -49,9 +52,13 @@ declare_oxc_lint!(
     /// ```javascript
     /// var foo = Symbol("some description");
     /// ```
+    ///
+    /// When the symbol initializes a variable or a property, the suggested fix uses its name as
+    /// the description, e.g. `const fooKey = Symbol()` becomes `const fooKey = Symbol("fooKey")`.
     SymbolDescription,
     eslint,
     pedantic,
+    suggestion,
     version = "0.4.0",
     short_description = "Require symbol descriptions.",
 );
@@ -66,15 +73,50 @@ impl Rule for SymbolDescription {
             return;
         };
 
-        if ident.name == "Symbol"
-            && call_expr.arguments.is_empty()
-            && ctx.scoping().root_unresolved_references().contains_key(&ident.name)
+        if ident.name != "Symbol"
+            || !call_expr.arguments.is_empty()
+            || !ident.is_global_reference(ctx.scoping())
         {
-            ctx.diagnostic(symbol_description_diagnostic(call_expr.span));
+            return;
+        }
+
+        let diagnostic = symbol_description_diagnostic(call_expr.span);
+        match initialized_name(node, ctx) {
+            Some(name) if !name.contains(['"', '\\']) => {
+                ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                    fixer
+                        .insert_text_before_range(
+                            closing_paren_span(call_expr),
+                            format!("\"{name}\""),
+                        )
+                        .with_message(format!("Use \"{name}\" as the description"))
+                });
+            }
+            _ => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// The name of the variable or property initialized with the `Symbol()` call in `node`.
+fn initialized_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Cow<'a, str>> {
+    let parent = ctx.nodes().ancestor_kinds(node.id()).find(|kind| {
+        !matches!(kind, AstKind::ChainExpression(_) | AstKind::ParenthesizedExpression(_))
+    })?;
+    match parent {
+        AstKind::VariableDeclarator(decl) => {
+            decl.id.get_identifier_name().map(|name| Cow::Borrowed(name.as_str()))
         }
+        AstKind::ObjectProperty(prop) if !prop.shorthand && !prop.method => prop.key.static_name(),
+        AstKind::PropertyDefinition(prop) => prop.key.name(),
+        _ => None,
     }
 }
 
+/// The empty span before the closing parenthesis of `call_expr`.
+fn closing_paren_span(call_expr: &CallExpression) -> Span {
+    Span::empty(call_expr.span.end - 1)
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -88,7 +130,35 @@ fn test() {
         "function bar(Symbol) { Symbol(); }",
     ];
 
-    let fail = vec!["Symbol();", "Symbol(); Symbol = function () {};"];
+    let fail = vec![
+        "Symbol();",
+        "Symbol(); Symbol = function () {};",
+        "const fooKey = Symbol();",
+        "const foo = { bar: Symbol() };",
+        "class Foo { static bar = Symbol(); }",
+        "foo(Symbol());",
+    ];
+
+    let fix = vec![
+        ("const fooKey = Symbol();", r#"const fooKey = Symbol("fooKey");"#),
+        ("let foo = Symbol( );", r#"let foo = Symbol( "foo");"#),
+        ("const foo = Symbol?.();", r#"const foo = Symbol?.("foo");"#),
+        ("const foo = (Symbol());", r#"const foo = (Symbol("foo"));"#),
+        ("const foo = { bar: Symbol() };", r#"const foo = { bar: Symbol("bar") };"#),
+        (
+            "const foo = { 'bar-baz': Symbol() };",
+            r#"const foo = { 'bar-baz': Symbol("bar-baz") };"#,
+        ),
+        ("class Foo { static bar = Symbol(); }", r#"class Foo { static bar = Symbol("bar"); }"#),
+        ("class Foo { #bar = Symbol(); }", r#"class Foo { #bar = Symbol("bar"); }"#),
+        // No name to use as the description.
+        ("Symbol();", "Symbol();"),
+        ("const { foo } = Symbol();", "const { foo } = Symbol();"),
+        ("const foo = { [bar]: Symbol() };", "const foo = { [bar]: Symbol() };"),
+        (r#"const foo = { 'say "hi"': Symbol() };"#, r#"const foo = { 'say "hi"': Symbol() };"#),
+    ];
 
-    Tester::new(SymbolDescription::NAME, SymbolDescription::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(SymbolDescription::NAME, SymbolDescription::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
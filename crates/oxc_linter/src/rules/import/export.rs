@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use oxc_ast::ast::Statement;
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -20,6 +21,15 @@ fn no_named_export(module_name: &str, span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+fn export_assignment_with_other_exports(span: Span, other_exports: Vec<Span>) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`export =` cannot be used in a module with other exports.")
+        .with_help("Move the other exports into a namespace merged with the `export =` target, or use only ES module exports.")
+        .with_labels(
+            std::iter::once(span.label("`export =` used here"))
+                .chain(other_exports.into_iter().map(LabeledSpan::underline)),
+        )
+}
+
 /// <https://github.com/import-js/eslint-plugin-import/blob/v2.29.1/docs/rules/export.md>
 #[derive(Debug, Default, Clone)]
 pub struct Export;
@@ -29,6 +39,8 @@ declare_oxc_lint!(
     ///
     /// Reports funny business with exports, like repeated exports of names or defaults.
     ///
+    /// In TypeScript, also reports `export =` assignments in modules with other exports.
+    ///
     /// ### Why is this bad?
     ///
     /// Having multiple exports of the same name can lead to ambiguity and confusion
@@ -63,6 +75,7 @@ impl Rule for Export {
         let named_export = &module_record.exported_bindings;
 
         diagnose_duplicate_named_exports(ctx, module_record);
+        diagnose_export_assignment(ctx);
 
         let mut all_export_names = FxHashMap::default();
         let mut visited = FxHashSet::default();
@@ -152,6 +165,38 @@ fn diagnose_duplicate_named_exports(ctx: &LintContext<'_>, module_record: &Modul
     }
 }
 
+/// TypeScript does not allow `export =` alongside other top-level exports.
+/// `export as namespace` declarations and empty `export {}` statements are allowed.
+fn diagnose_export_assignment(ctx: &LintContext<'_>) {
+    let body = &ctx.nodes().program().body;
+    let Some(export_assignment) = body.iter().find_map(|stmt| match stmt {
+        Statement::TSExportAssignment(export_assignment) => Some(export_assignment),
+        _ => None,
+    }) else {
+        return;
+    };
+
+    let other_exports = body
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Statement::ExportNamedDeclaration(decl)
+                if decl.declaration.is_some()
+                    || decl.source.is_some()
+                    || !decl.specifiers.is_empty() =>
+            {
+                Some(decl.span)
+            }
+            Statement::ExportDefaultDeclaration(decl) => Some(decl.span),
+            Statement::ExportAllDeclaration(decl) => Some(decl.span),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if !other_exports.is_empty() {
+        ctx.diagnostic(export_assignment_with_other_exports(export_assignment.span, other_exports));
+    }
+}
+
 fn export_name(export_entry: &ExportEntry) -> Option<(&str, Span)> {
     match &export_entry.export_name {
         ExportExportName::Name(name) => Some((name.name(), name.span)),
@@ -430,4 +475,27 @@ fn test() {
             .change_rule_path("export-star-4/index.js")
             .test();
     }
+
+    {
+        let pass = vec![
+            "declare function foo(): void; export = foo;",
+            "declare namespace Foo { export const bar: string; } export = Foo; export as namespace Foo;",
+            "declare class Foo {} declare namespace Foo { export interface Options {} } export = Foo;",
+            "declare module 'foo' { export const bar: string; } declare const baz: string; export = baz;",
+            "declare const foo: string; export = foo; export {};",
+            "export declare const foo: string; export as namespace Foo;",
+        ];
+        let fail = vec![
+            "declare function foo(): void; export = foo; export declare const bar: string;",
+            "export interface Options {} declare function foo(options: Options): void; export = foo;",
+            "declare const foo: string; export = foo; export default foo;",
+            "declare const foo: string; export = foo; export * from './bar';",
+            "declare const foo: string; declare const bar: string; export = foo; export { bar };",
+        ];
+        Tester::new(Export::NAME, Export::PLUGIN, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("index.d.ts")
+            .with_snapshot_suffix("declaration_file")
+            .test_and_snapshot();
+    }
 }
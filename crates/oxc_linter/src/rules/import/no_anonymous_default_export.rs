@@ -1,7 +1,4 @@
-use oxc_ast::{
-    AstKind,
-    ast::{ExportDefaultDeclarationKind, Expression},
-};
+use oxc_ast::ast::{ExportDefaultDeclarationKind, Expression, Statement};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -10,7 +7,6 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{
-    AstNode,
     context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
@@ -128,16 +124,35 @@ impl Rule for NoAnonymousDefaultExport {
         serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
     }
 
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::ExportDefaultDeclaration(export_decl) = node.kind() else {
+    fn run_once(&self, ctx: &LintContext) {
+        // Default exports of anonymous values have no local name.
+        let Some(entry) = ctx
+            .module_record()
+            .local_export_entries
+            .iter()
+            .find(|entry| entry.export_name.is_default() && entry.local_name.is_null())
+        else {
+            return;
+        };
+        let Some(export_decl) = ctx.nodes().program().body.iter().find_map(|stmt| match stmt {
+            Statement::ExportDefaultDeclaration(export_decl)
+                if export_decl.span == entry.statement_span =>
+            {
+                Some(export_decl)
+            }
+            _ => None,
+        }) else {
             return;
         };
+        let export_span = ctx
+            .find_next_token_within(export_decl.span.start, export_decl.span.end, "export")
+            .map_or(export_decl.span, |offset| Span::sized(export_decl.span.start + offset, 6));
         match &export_decl.declaration {
             ExportDefaultDeclarationKind::FunctionDeclaration(func_decl)
                 if !self.allow_anonymous_function && func_decl.id.is_none() =>
             {
                 ctx.diagnostic(no_anonymous_default_export_diagnostic(
-                    export_decl.span,
+                    export_span,
                     "Unexpected default export of anonymous function",
                 ));
             }
@@ -145,7 +160,7 @@ impl Rule for NoAnonymousDefaultExport {
                 if !self.allow_anonymous_class && class_decl.id.is_none() =>
             {
                 ctx.diagnostic(no_anonymous_default_export_diagnostic(
-                    export_decl.span,
+                    export_span,
                     "Unexpected default export of anonymous class",
                 ));
             }
@@ -153,31 +168,31 @@ impl Rule for NoAnonymousDefaultExport {
                 if !self.allow_arrow_function =>
             {
                 ctx.diagnostic(no_anonymous_default_export_diagnostic(
-                    export_decl.span,
+                    export_span,
                     "Assign arrow function to a variable before exporting as module default",
                 ));
             }
             ExportDefaultDeclarationKind::ObjectExpression(_) if !self.allow_object => {
                 ctx.diagnostic(no_anonymous_default_export_diagnostic(
-                    export_decl.span,
+                    export_span,
                     "Assign object to a variable before exporting as module default",
                 ));
             }
             ExportDefaultDeclarationKind::CallExpression(_) if !self.allow_call_expression => {
                 ctx.diagnostic(no_anonymous_default_export_diagnostic(
-                    export_decl.span,
+                    export_span,
                     "Assign call result to a variable before exporting as module default",
                 ));
             }
             ExportDefaultDeclarationKind::NewExpression(_) if !self.allow_new => {
                 ctx.diagnostic(no_anonymous_default_export_diagnostic(
-                    export_decl.span,
+                    export_span,
                     "Assign instance to a variable before exporting as module default",
                 ));
             }
             ExportDefaultDeclarationKind::ArrayExpression(_) if !self.allow_array => {
                 ctx.diagnostic(no_anonymous_default_export_diagnostic(
-                    export_decl.span,
+                    export_span,
                     "Assign array to a variable before exporting as module default",
                 ));
             }
@@ -187,7 +202,7 @@ impl Rule for NoAnonymousDefaultExport {
                     && (expr.is_literal() || matches!(expr, Expression::TemplateLiteral(_)))
                 {
                     ctx.diagnostic(no_anonymous_default_export_diagnostic(
-                        export_decl.span,
+                        export_span,
                         "Assign literal to a variable before exporting as module default",
                     ));
                 }
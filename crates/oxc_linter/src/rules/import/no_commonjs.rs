@@ -162,6 +162,7 @@ impl Rule for NoCommonjs {
                             "export",
                             property_name,
                         ));
+                        return;
                     }
 
                     match parent_node.kind() {
@@ -1,21 +1,28 @@
+use rustc_hash::FxHashSet;
+
 use oxc_ast::{
     AstKind,
-    ast::{
-        Declaration, Expression, ModuleExportName, VariableDeclaration, VariableDeclarationKind,
-    },
+    ast::{VariableDeclaration, VariableDeclarationKind},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::ReferenceId;
+use oxc_semantic::Reference;
 use oxc_span::Span;
 
 use crate::{context::LintContext, rule::Rule};
 
-fn no_mutable_exports_diagnostic(span: Span, kind: VariableDeclarationKind) -> OxcDiagnostic {
+fn no_mutable_exports_diagnostic(
+    export_span: Span,
+    kind: VariableDeclarationKind,
+    declaration_span: Option<Span>,
+) -> OxcDiagnostic {
     let kind_str = if kind == VariableDeclarationKind::Var { "var" } else { "let" };
     OxcDiagnostic::warn(format!("Exporting mutable '{kind_str}' binding, use 'const' instead."))
         .with_help(format!("Replace '{kind_str}' with 'const' to export an immutable binding."))
-        .with_label(span)
+        .with_labels(
+            std::iter::once(export_span.into())
+                .chain(declaration_span.map(|span| span.label("declared here"))),
+        )
 }
 
 // <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/docs/rules/no-mutable-exports.md>
@@ -27,6 +34,11 @@ declare_oxc_lint!(
     ///
     /// Forbids the use of mutable exports with var or let.
     ///
+    /// Exports are found in the module record, so `export { count }` and `export default count`
+    /// are reported when `count` is declared with `let` or `var` elsewhere in the module. A
+    /// suggestion to use `const` instead is offered for `let` declarations whose bindings are
+    /// all initialized and never reassigned.
+    ///
     /// ### Why is this bad?
     ///
     /// In general, we should always export constants
@@ -55,77 +67,86 @@ declare_oxc_lint!(
     NoMutableExports,
     import,
     style,
+    suggestion,
     version = "0.15.13",
     short_description = "Forbids the use of mutable exports with var or let.",
 );
 
 impl Rule for NoMutableExports {
-    fn run<'a>(&self, node: &oxc_semantic::AstNode<'a>, ctx: &LintContext<'a>) {
-        match node.kind() {
-            AstKind::ExportNamedDeclaration(export_name_decl) => {
-                // e.g. "export let a = 4;"
-                if let Some(declaration) = &export_name_decl.declaration {
-                    let Declaration::VariableDeclaration(decl) = declaration else {
-                        return;
-                    };
-                    if matches!(
-                        decl.kind,
-                        VariableDeclarationKind::Var | VariableDeclarationKind::Let
-                    ) {
-                        ctx.diagnostic(no_mutable_exports_diagnostic(decl.span, decl.kind));
-                    }
-                } else if export_name_decl.source.is_none() {
-                    // e.g. "let a = 3; export { a }"
-                    for specifier in &export_name_decl.specifiers {
-                        if let ModuleExportName::IdentifierReference(ident) = &specifier.local {
-                            let Some(declaration) =
-                                get_reference_declaration(ident.reference_id(), ctx)
-                            else {
-                                continue;
-                            };
-                            ctx.diagnostic(no_mutable_exports_diagnostic(
-                                declaration.span,
-                                declaration.kind,
-                            ));
-                        }
-                    }
-                }
+    fn run_once(&self, ctx: &LintContext) {
+        let module_record = ctx.module_record();
+        let mut reported = FxHashSet::default();
+        for entry in &module_record.local_export_entries {
+            if entry.is_type {
+                continue;
+            }
+            // e.g. "export let a = 4;", "let a = 3; export { a }", "let a = 4; export default a"
+            let Some(local_name) = entry.local_name.name() else {
+                continue;
+            };
+            let Some(declaration) = get_declaration(local_name, ctx) else {
+                continue;
+            };
+            // `export let a = 1, b = 2` has an entry for each binding.
+            if !reported.insert((entry.statement_span, declaration.span)) {
+                continue;
             }
-            AstKind::ExportDefaultDeclaration(export_default_decl) => {
-                // e.g. "let a = 4; export default a"
-                let Some(Expression::Identifier(ident)) =
-                    export_default_decl.declaration.as_expression()
-                else {
-                    return;
-                };
-                let Some(declaration) = get_reference_declaration(ident.reference_id(), ctx) else {
-                    return;
-                };
-                ctx.diagnostic(no_mutable_exports_diagnostic(declaration.span, declaration.kind));
+
+            let export_span = keyword_span(entry.statement_span, "export", ctx);
+            let kind_span = keyword_span(declaration.span, declaration.kind.as_str(), ctx);
+            let declaration_span =
+                (!entry.statement_span.contains_inclusive(declaration.span)).then_some(kind_span);
+            let diagnostic =
+                no_mutable_exports_diagnostic(export_span, declaration.kind, declaration_span);
+            if is_never_reassigned_let(declaration, ctx) {
+                ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                    fixer.replace(kind_span, "const").with_message("Replace 'let' with 'const'")
+                });
+            } else {
+                ctx.diagnostic(diagnostic);
             }
-            _ => {}
         }
     }
 }
 
 // find "let a = 2;" in "let a = 2; export default a"
 // find "let foo = 1" in "let foo = 1; export { foo }"
-fn get_reference_declaration<'a>(
-    reference_id: ReferenceId,
-    ctx: &'a LintContext,
-) -> Option<&'a VariableDeclaration<'a>> {
-    let reference = ctx.scoping().get_reference(reference_id);
-    let symbol_id = reference.symbol_id()?;
-    let reference_node = ctx.symbol_declaration(symbol_id);
-    if matches!(reference_node.kind(), AstKind::VariableDeclarator(_)) {
-        // we need return reference_node's parent node
-        if let AstKind::VariableDeclaration(decl) = ctx.nodes().parent_kind(reference_node.id())
-            && matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Var)
+fn get_declaration<'a>(name: &str, ctx: &'a LintContext) -> Option<&'a VariableDeclaration<'a>> {
+    let symbol_id = ctx.scoping().get_root_binding(name.into())?;
+    let declarator = ctx.symbol_declaration(symbol_id);
+    if !matches!(declarator.kind(), AstKind::VariableDeclarator(_)) {
+        return None;
+    }
+    match ctx.nodes().parent_kind(declarator.id()) {
+        AstKind::VariableDeclaration(decl)
+            if matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Var) =>
         {
-            return Some(decl);
+            Some(decl)
         }
+        _ => None,
     }
-    None
+}
+
+/// Span of the first `keyword` token in `span`.
+fn keyword_span(span: Span, keyword: &str, ctx: &LintContext) -> Span {
+    #[expect(clippy::cast_possible_truncation)]
+    ctx.find_next_token_within(span.start, span.end, keyword)
+        .map_or(span, |offset| Span::sized(span.start + offset, keyword.len() as u32))
+}
+
+/// Whether `declaration` is a `let` declaration which can be turned into a `const` one: all of
+/// its bindings are initialized and never reassigned.
+fn is_never_reassigned_let(declaration: &VariableDeclaration, ctx: &LintContext) -> bool {
+    declaration.kind == VariableDeclarationKind::Let
+        && !declaration.declare
+        && declaration.declarations.iter().all(|declarator| {
+            declarator.init.is_some()
+                && declarator
+                    .id
+                    .get_binding_identifiers()
+                    .iter()
+                    .all(|ident| !ctx.symbol_references(ident.symbol_id()).any(Reference::is_write))
+        })
 }
 
 #[test]
@@ -232,7 +253,25 @@ fn test() {
                 a
             }
         ",
+        "export let a = 1, b = 2;",
+        "let count = 0; export { count }; export function increment() { count++; }",
+        "let foo; export { foo }",
+    ];
+
+    let fix = vec![
+        ("export let count = 1", "export const count = 1"),
+        ("let foo = 2; export { foo }", "const foo = 2; export { foo }"),
+        ("let foo = 3; export default foo", "const foo = 3; export default foo"),
+        ("export let a = 1, b = 2;", "export const a = 1, b = 2;"),
+        ("export var count = 1", "export var count = 1"),
+        (
+            "let count = 0; export { count }; export function increment() { count++; }",
+            "let count = 0; export { count }; export function increment() { count++; }",
+        ),
+        ("let foo; export { foo }", "let foo; export { foo }"),
     ];
 
-    Tester::new(NoMutableExports::NAME, NoMutableExports::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(NoMutableExports::NAME, NoMutableExports::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
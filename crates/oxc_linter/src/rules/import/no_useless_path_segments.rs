@@ -0,0 +1,425 @@
+use std::{
+    ffi::OsString,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression, StringLiteral},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Span, VALID_EXTENSIONS};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn no_useless_path_segments_diagnostic(span: Span, path: &str, proposed: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Useless path segments for \"{path}\", should be \"{proposed}\""))
+        .with_help(format!("Import \"{proposed}\" instead."))
+        .with_label(span)
+}
+
+// <https://github.com/import-js/eslint-plugin-import/blob/v2.32.0/docs/rules/no-useless-path-segments.md>
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUselessPathSegments {
+    /// If set to `true`, paths of CommonJS-style `require` calls are checked too:
+    ///
+    /// ```js
+    /// var foo = require('./../foo'); // reported
+    /// ```
+    commonjs: bool,
+    /// If set to `true`, paths ending in `/index` or `/index.<ext>` are reported when the
+    /// directory alone resolves to the same file:
+    ///
+    /// ```js
+    /// import foo from './foo/index'; // reported, should be './foo'
+    /// import bar from './bar/index.js'; // reported, should be './bar/' if `./bar.js` exists
+    /// ```
+    no_useless_index: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports relative import paths which can be written with fewer segments, such as
+    /// `./../foo` instead of `../foo`, `./foo//bar` instead of `./foo/bar`, or `./foo/` instead
+    /// of `./foo`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Useless segments make paths harder to read and to search for, and lead to the same module
+    /// being imported with different paths across a codebase.
+    ///
+    /// Removing a trailing slash, or an `index` segment with the `noUselessIndex` option, can
+    /// change which file a path resolves to, e.g. to `./foo.js` instead of `./foo/index.js`.
+    /// These are only fixed when the import plugin could resolve the original path, and the
+    /// simplified path resolves to the same file. `require` calls are never resolved, so these
+    /// simplifications are only reported for them.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import foo from './../foo';
+    /// import bar from './bar/../baz';
+    /// import baz from './baz//qux';
+    /// import qux from './qux/';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import foo from '../foo';
+    /// import bar from './baz';
+    /// import baz from './baz/qux';
+    /// import qux from './qux';
+    /// import quux from '.';
+    /// ```
+    ///
+    /// Examples of **incorrect** code for the `{ "noUselessIndex": true }` option:
+    /// ```js
+    /// import foo from './foo/index';
+    /// import bar from './bar/index.js';
+    /// import baz from './index.js';
+    /// ```
+    ///
+    /// Examples of **correct** code for the `{ "noUselessIndex": true }` option:
+    /// ```js
+    /// import foo from './foo';
+    /// import bar from './bar';
+    /// import baz from '.';
+    /// ```
+    NoUselessPathSegments,
+    import,
+    style,
+    fix,
+    config = NoUselessPathSegments,
+    version = "next",
+    short_description = "Forbid unnecessary path segments in import and require statements.",
+);
+
+impl Rule for NoUselessPathSegments {
+    fn from_configuration(value: Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ImportDeclaration(import_decl) => self.check(&import_decl.source, ctx),
+            AstKind::ExportNamedDeclaration(export_decl) => {
+                if let Some(source) = &export_decl.source {
+                    self.check(source, ctx);
+                }
+            }
+            AstKind::ExportAllDeclaration(export_decl) => self.check(&export_decl.source, ctx),
+            AstKind::ImportExpression(import_expr) => {
+                if let Expression::StringLiteral(source) = &import_expr.source {
+                    self.check(source, ctx);
+                }
+            }
+            AstKind::CallExpression(call_expr) if self.commonjs => {
+                if let Expression::Identifier(ident) = &call_expr.callee
+                    && ident.name == "require"
+                    && call_expr.arguments.len() == 1
+                    && let Argument::StringLiteral(source) = &call_expr.arguments[0]
+                {
+                    self.check(source, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoUselessPathSegments {
+    fn check(&self, source: &StringLiteral, ctx: &LintContext) {
+        let path = source.value.as_str();
+        let Some(mut specifier) = RelativeSpecifier::parse(path) else {
+            return;
+        };
+        let Some(dir) = ctx.file_path().parent() else {
+            return;
+        };
+        // Only set if the import plugin resolved `path`.
+        let resolved = ctx
+            .module_record()
+            .get_loaded_module(path)
+            .map(|module| module.resolved_absolute_path.clone());
+        // Whether `specifier` was verified to resolve to the same file as `path`.
+        let mut verified = true;
+
+        if self.no_useless_index
+            && let Some(parent) = specifier.without_index()
+        {
+            if let Some(resolved) = &resolved {
+                let target = parent.to_path(dir);
+                if resolves_to_index(&target, resolved) {
+                    specifier = RelativeSpecifier {
+                        trailing_slash: !parent.names.is_empty() && has_file_sibling(&target),
+                        ..parent
+                    };
+                }
+            } else {
+                specifier = parent;
+                verified = false;
+            }
+        }
+
+        if specifier.trailing_slash && !specifier.names.is_empty() {
+            if resolved.is_none() {
+                specifier.trailing_slash = false;
+                verified = false;
+            } else if !has_file_sibling(&specifier.to_path(dir)) {
+                // `./foo/` only resolves to a directory, `./foo` resolves to `./foo.js` first.
+                specifier.trailing_slash = false;
+            }
+        }
+
+        let proposed = specifier.to_string();
+        if proposed == path {
+            return;
+        }
+        let diagnostic = no_useless_path_segments_diagnostic(source.span, path, &proposed);
+        let content_span = source.span.shrink(1);
+        // Paths with escape sequences can't be replaced with their value.
+        if verified && content_span.source_text(ctx.source_text()) == path {
+            ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(content_span, proposed));
+        } else {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+}
+
+/// A relative path, normalized to leading `..` segments followed by names, e.g. `./a/../../b/`
+/// is `../b/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RelativeSpecifier<'a> {
+    parents: usize,
+    names: Vec<&'a str>,
+    trailing_slash: bool,
+}
+
+impl<'a> RelativeSpecifier<'a> {
+    fn parse(path: &'a str) -> Option<Self> {
+        if !(matches!(path, "." | "..") || path.starts_with("./") || path.starts_with("../")) {
+            return None;
+        }
+        let mut specifier = Self { parents: 0, names: vec![], trailing_slash: path.ends_with('/') };
+        for segment in path.split('/') {
+            match segment {
+                "" | "." => {}
+                ".." => {
+                    if specifier.names.pop().is_none() {
+                        specifier.parents += 1;
+                    }
+                }
+                name => specifier.names.push(name),
+            }
+        }
+        Some(specifier)
+    }
+
+    /// The directory of a path ending in `/index` or `/index.<ext>`.
+    fn without_index(&self) -> Option<Self> {
+        let (last, names) = self.names.split_last()?;
+        let is_index = last.strip_prefix("index").is_some_and(|ext| {
+            ext.is_empty()
+                || ext.strip_prefix('.').is_some_and(|ext| VALID_EXTENSIONS.contains(&ext))
+        });
+        is_index.then(|| Self {
+            parents: self.parents,
+            names: names.to_vec(),
+            trailing_slash: false,
+        })
+    }
+
+    fn to_path(&self, dir: &Path) -> PathBuf {
+        let mut path = dir.ancestors().nth(self.parents).unwrap_or(dir).to_path_buf();
+        path.extend(&self.names);
+        path
+    }
+}
+
+impl fmt::Display for RelativeSpecifier<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.parents == 0 {
+            f.write_str(".")?;
+        } else {
+            f.write_str(&vec![".."; self.parents].join("/"))?;
+        }
+        for name in &self.names {
+            write!(f, "/{name}")?;
+        }
+        if self.trailing_slash {
+            f.write_str("/")?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether importing the directory `dir` resolves to `resolved`, which is what an `index` path in
+/// `dir` resolved to.
+fn resolves_to_index(dir: &Path, resolved: &Path) -> bool {
+    // `main` or `module` in `package.json` take precedence over `index` files.
+    !dir.join("package.json").is_file()
+        && VALID_EXTENSIONS
+            .iter()
+            .map(|ext| dir.join(format!("index.{ext}")))
+            .find(|index| index.is_file())
+            .is_some_and(|index| index == resolved)
+}
+
+/// Whether `path` without a trailing slash could resolve to a file instead of the directory.
+fn has_file_sibling(path: &Path) -> bool {
+    path.is_file()
+        || VALID_EXTENSIONS.iter().any(|ext| {
+            let mut file = OsString::from(path.as_os_str());
+            file.push(".");
+            file.push(ext);
+            Path::new(&file).is_file()
+        })
+}
+
+#[test]
+fn test_relative_specifier() {
+    let cases = [
+        ("./foo", "./foo"),
+        ("./../foo", "../foo"),
+        ("./foo//bar", "./foo/bar"),
+        ("./foo/./bar/", "./foo/bar/"),
+        ("./foo/../bar", "./bar"),
+        ("../foo/../../bar", "../../bar"),
+        (".", "."),
+        ("..", ".."),
+        ("./", "./"),
+        ("../", "../"),
+        ("./.", "."),
+        ("./..", ".."),
+        ("./foo/..", "."),
+        ("./foo/../", "./"),
+        ("./foo/../..", ".."),
+        ("../../", "../../"),
+        ("./.hidden/..foo", "./.hidden/..foo"),
+    ];
+    for (path, expected) in cases {
+        assert_eq!(RelativeSpecifier::parse(path).unwrap().to_string(), expected, "{path}");
+    }
+
+    for path in ["foo", "/foo", ".foo", "..foo", "@scope/foo", "node:fs"] {
+        assert_eq!(RelativeSpecifier::parse(path), None, "{path}");
+    }
+
+    let without_index =
+        |path| RelativeSpecifier::parse(path).unwrap().without_index().map(|s| s.to_string());
+    assert_eq!(without_index("./foo/index"), Some("./foo".to_string()));
+    assert_eq!(without_index("./foo/index.ts"), Some("./foo".to_string()));
+    assert_eq!(without_index("./index.js"), Some(".".to_string()));
+    assert_eq!(without_index("../index"), Some("..".to_string()));
+    assert_eq!(without_index("./foo/index.css"), None);
+    assert_eq!(without_index("./foo/indexes"), None);
+    assert_eq!(without_index("."), None);
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let commonjs = Some(json!([{ "commonjs": true }]));
+    let no_useless_index = Some(json!([{ "noUselessIndex": true }]));
+    let all = Some(json!([{ "commonjs": true, "noUselessIndex": true }]));
+
+    let pass = vec![
+        (r#"import "./malformed.js""#, None),
+        (r#"import "./test-module""#, None),
+        (r#"import "./deep/a.js""#, None),
+        (r#"import "../import/malformed.js""#, None),
+        (r#"import ".""#, None),
+        (r#"import "..""#, None),
+        (r#"import "./""#, None),
+        (r#"import "../""#, None),
+        (r#"import "../..""#, None),
+        (r#"import fs from "fs""#, None),
+        (r#"import fs from "fs/promises""#, None),
+        (r#"import foo from "@scope/foo/./bar""#, None),
+        // `./bar.js` exists, so `./bar` isn't `./bar/index.js`.
+        (r#"import "./bar/""#, None),
+        (r#"import "./importType/index""#, None),
+        (r#"require("./../malformed.js")"#, None),
+        (r#"require("./../malformed.js", foo)"#, commonjs.clone()),
+        (r#"import "./malformed.js""#, no_useless_index.clone()),
+        (r#"import "./bar/""#, no_useless_index.clone()),
+        // `package.json` in the directory of `./index.js` takes precedence.
+        (r#"import "./index.js""#, no_useless_index.clone()),
+        (r#"import "./index""#, no_useless_index.clone()),
+        (r#"import "./importType/index.css""#, no_useless_index.clone()),
+    ];
+
+    let fail = vec![
+        (r#"import "./../malformed.js""#, None),
+        (r#"import "./deep//a.js""#, None),
+        (r#"import "./deep/./a.js""#, None),
+        (r#"import "./deep/../malformed.js""#, None),
+        (r#"import ".//deep/a.js""#, None),
+        (r#"import "./deep/..""#, None),
+        (r#"import "./deep/../..""#, None),
+        (r#"import "./..""#, None),
+        (r#"import "./.""#, None),
+        (r#"import "./deep/../""#, None),
+        (r#"import "./test-module/""#, None),
+        (r#"import "./importType/""#, None),
+        (r#"export * from "./../malformed.js""#, None),
+        (r#"export { a } from "./deep//a.js""#, None),
+        (r#"import("./deep/./a.js")"#, None),
+        (r#"require("./../malformed.js")"#, commonjs.clone()),
+        (r#"require("./test-module/")"#, commonjs.clone()),
+        (r#"import "./importType/index""#, no_useless_index.clone()),
+        (r#"import "./importType/index.js""#, no_useless_index.clone()),
+        (r#"import "./test-module/index.js""#, no_useless_index.clone()),
+        (r#"import "./deep/../importType/index.js""#, no_useless_index.clone()),
+        // `./bar.js` exists, so the trailing slash is needed.
+        (r#"import "./bar/index.js""#, no_useless_index.clone()),
+        (r#"import "./bar/index""#, no_useless_index.clone()),
+        (r#"require("./importType/index.js")"#, all.clone()),
+        (r#"import "./deep/.\u002fa.js""#, None),
+    ];
+
+    let fix = vec![
+        (r#"import "./../malformed.js""#, r#"import "../malformed.js""#, None),
+        (r"import './../malformed.js'", r"import '../malformed.js'", None),
+        (r#"import "./deep//a.js""#, r#"import "./deep/a.js""#, None),
+        (r#"import "./deep/./a.js""#, r#"import "./deep/a.js""#, None),
+        (r"import './deep/../malformed.js'", r"import './malformed.js'", None),
+        (r#"import "./deep/..""#, r#"import ".""#, None),
+        (r#"import "./deep/../..""#, r#"import "..""#, None),
+        (r#"import "./deep/../""#, r#"import "./""#, None),
+        (r#"import "./test-module/""#, r#"import "./test-module""#, None),
+        (r"export * from './../malformed.js'", r"export * from '../malformed.js'", None),
+        (r#"import("./deep/./a.js")"#, r#"import("./deep/a.js")"#, None),
+        (r"require('./../malformed.js')", r"require('../malformed.js')", commonjs.clone()),
+        (r#"import "./importType/index""#, r#"import "./importType""#, no_useless_index.clone()),
+        (r"import './importType/index.js'", r"import './importType'", no_useless_index.clone()),
+        (r#"import "./bar/index.js""#, r#"import "./bar/""#, no_useless_index.clone()),
+        (r#"import "./deep/../importType/index.js""#, r#"import "./importType""#, no_useless_index),
+        // Not verified without the import plugin resolving `require` calls.
+        (r#"require("./test-module/")"#, r#"require("./test-module/")"#, commonjs),
+        (r#"require("./importType/index.js")"#, r#"require("./importType/index.js")"#, all),
+        // Escape sequences can't be kept.
+        (r#"import "./deep/.\u002fa.js""#, r#"import "./deep/.\u002fa.js""#, None),
+    ];
+
+    Tester::new(NoUselessPathSegments::NAME, NoUselessPathSegments::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .with_import_plugin(true)
+        .change_rule_path("no-useless-path-segments.js")
+        .test_and_snapshot();
+}
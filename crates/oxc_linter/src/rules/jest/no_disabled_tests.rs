@@ -10,7 +10,14 @@ use crate::{
 #[derive(Debug, Default, Clone)]
 pub struct NoDisabledTests;
 
-declare_oxc_lint!(NoDisabledTests, jest, correctness, docs = DOCUMENTATION, version = "0.0.7",);
+declare_oxc_lint!(
+    NoDisabledTests,
+    jest,
+    correctness,
+    suggestion,
+    docs = DOCUMENTATION,
+    version = "0.0.7",
+);
 
 impl Rule for NoDisabledTests {
     fn run_on_jest_node<'a, 'c>(
@@ -24,7 +31,7 @@ impl Rule for NoDisabledTests {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{fixer::FixKind, tester::Tester};
 
     let pass = vec![
         ("describe('foo', function () {})", None),
@@ -94,7 +101,54 @@ fn test() {
         ("import { test } from '@jest/globals';test('something');", None),
     ];
 
+    let fix = vec![
+        (
+            "describe.skip('foo', function () {})",
+            "describe('foo', function () {})",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "it['skip']('foo', function () {})",
+            "it('foo', function () {})",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "describe[`skip`]('foo', function () {})",
+            "describe('foo', function () {})",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "test.skip.each([])('foo', function () {})",
+            "test.each([])('foo', function () {})",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "it.concurrent.skip.each``('foo', function () {})",
+            "it.concurrent.each``('foo', function () {})",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "xdescribe('foo', function () {})",
+            "describe('foo', function () {})",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "xit.each([])('foo', function () {})",
+            "it.each([])('foo', function () {})",
+            None,
+            FixKind::Suggestion,
+        ),
+        ("xtest('foo', function () {})", "test('foo', function () {})", None, FixKind::Suggestion),
+    ];
+
     Tester::new(NoDisabledTests::NAME, NoDisabledTests::PLUGIN, pass, fail)
         .with_jest_plugin(true)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -89,6 +89,8 @@ fn test() {
         ),
         ("aVariable.mockImplementation(() => Promise.all([1, 2, 3]));", None),
         ("aVariable.mockReturnValue(Promise.all([1, 2, 3]));", None),
+        ("aVariable.mockImplementation(() => { return Promise.resolve(42); foo(); })", None),
+        ("aVariable.mockImplementation(function* () { return Promise.resolve(42); })", None),
     ];
 
     let fail = vec![
@@ -151,6 +153,8 @@ fn test() {
             "jest.spyOn(fs, \"readFile\").mockReturnValue(Promise.reject(new Error(\"oh noes!\")))",
             None,
         ),
+        ("aVariable.mockImplementation(function () { return Promise.resolve(42); })", None),
+        ("aVariable.mockReturnValue(Promise.resolve(...values))", None),
     ];
 
     let fix = vec![
@@ -210,12 +214,21 @@ fn test() {
             "aVariable.mockRejectedValueOnce(42)",
             None,
         ),
-        // Todo: Fixed
-        // (
-        //     "aVariable.mockReturnValue(Promise.resolve({ target: 'world', message: 'hello' }))",
-        //     "aVariable.mockResolvedValue({ target: 'world', message: 'hello' })",
-        //     None,
-        // ),
+        (
+            "aVariable.mockReturnValue(Promise.resolve({ target: 'world', message: 'hello' }))",
+            "aVariable.mockResolvedValue({ target: 'world', message: 'hello' })",
+            None,
+        ),
+        (
+            "aVariable.mockImplementation(() => Promise.resolve({\n    target: 'world',\n}))",
+            "aVariable.mockResolvedValue({\n    target: 'world',\n})",
+            None,
+        ),
+        (
+            "aVariable.mockImplementation(function () { return Promise.resolve(42); })",
+            "aVariable.mockResolvedValue(42)",
+            None,
+        ),
         (
             "
                 aVariable
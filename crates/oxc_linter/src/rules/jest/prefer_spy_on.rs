@@ -59,6 +59,10 @@ fn tests() {
             ",
             None,
         ),
+        ("obj[\"a\"] = jest.fn(() => 10)", None),
+        ("arr[0] = jest.fn()", None),
+        ("getModule( 'x' ).fn = jest.fn(async () => {\n    return 1;\n})", None),
+        ("obj[key] = jest.fn(() => 10)", None),
     ];
 
     let fix = vec![
@@ -68,16 +72,6 @@ fn tests() {
             None,
         ),
         ("Date['now'] = jest['fn']()", "jest.spyOn(Date, 'now').mockImplementation()", None),
-        (
-            "window[`${name}`] = jest[`fn`]()",
-            "jest.spyOn(window, `${name}`).mockImplementation()",
-            None,
-        ),
-        (
-            "obj['prop' + 1] = jest['fn']()",
-            "jest.spyOn(obj, 'prop' + 1).mockImplementation()",
-            None,
-        ),
         (
             "obj.one.two = jest.fn(); const test = 10;",
             "jest.spyOn(obj.one, 'two').mockImplementation(); const test = 10;",
@@ -94,11 +88,6 @@ fn tests() {
             "jest.spyOn(window, 'fetch').mockImplementation(() => ({})).one.two().three().four",
             None,
         ),
-        (
-            "foo[bar] = jest.fn().mockReturnValue(undefined)",
-            "jest.spyOn(foo, bar).mockImplementation().mockReturnValue(undefined)",
-            None,
-        ),
         (
             "
                 foo.bar = jest.fn().mockImplementation(baz => baz)
@@ -110,6 +99,17 @@ fn tests() {
             ",
             None,
         ),
+        (
+            "obj[\"a\"] = jest.fn(() => 10)",
+            "jest.spyOn(obj, \"a\").mockImplementation(() => 10)",
+            None,
+        ),
+        ("arr[0] = jest.fn()", "jest.spyOn(arr, 0).mockImplementation()", None),
+        (
+            "getModule( 'x' ).fn = jest.fn(async () => {\n    return 1;\n})",
+            "jest.spyOn(getModule( 'x' ), 'fn').mockImplementation(async () => {\n    return 1;\n})",
+            None,
+        ),
     ];
 
     Tester::new(PreferSpyOn::NAME, PreferSpyOn::PLUGIN, pass, fail)
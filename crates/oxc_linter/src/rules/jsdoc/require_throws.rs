@@ -0,0 +1,513 @@
+use std::ops::Deref;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::JSDoc;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        default_function_contexts, get_function_nearest_jsdoc_node, is_function_in_contexts,
+        is_missing_special_tag, should_ignore_as_avoid, should_ignore_as_custom_skip,
+        should_ignore_as_internal, should_ignore_as_private,
+    },
+};
+
+fn missing_throws_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Missing JSDoc `@throws` declaration for function which throws.")
+        .with_help("Add a `@throws` tag to the JSDoc comment.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct RequireThrows(Box<RequireThrowsConfig>);
+
+impl Deref for RequireThrows {
+    type Target = RequireThrowsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct RequireThrowsConfig {
+    /// Functions with these tags will be exempted from the lint rule.
+    exempted_by: Vec<String>,
+    /// Kinds of functions to check: `ArrowFunctionExpression`, `FunctionDeclaration` and
+    /// `FunctionExpression` (which includes methods).
+    contexts: Vec<String>,
+}
+
+impl Default for RequireThrowsConfig {
+    fn default() -> Self {
+        Self { exempted_by: vec!["inheritdoc".to_string()], contexts: default_function_contexts() }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires that throw statements are documented with a `@throws` tag.
+    ///
+    /// Only functions with a JSDoc comment are checked, see `jsdoc/require-jsdoc` for functions
+    /// without one. Throw statements inside nested functions, and inside `try` blocks with a
+    /// `catch` clause, are not thrown by the function itself and are ignored. Async functions are
+    /// also ignored, since they reject instead of throwing.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Callers of a function need to know which errors it may throw to handle them.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /** Parses the config. */
+    /// function parse (text) {
+    ///   if (!text) {
+    ///     throw new Error('Empty config');
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * Parses the config.
+    ///  * @throws {Error} If the config is empty.
+    ///  */
+    /// function parse (text) {
+    ///   if (!text) {
+    ///     throw new Error('Empty config');
+    ///   }
+    /// }
+    ///
+    /// /** Parses the config, or returns `undefined`. */
+    /// function tryParse (text) {
+    ///   try {
+    ///     throw new Error('Empty config');
+    ///   } catch {
+    ///     return undefined;
+    ///   }
+    /// }
+    /// ```
+    RequireThrows,
+    jsdoc,
+    pedantic,
+    config = RequireThrowsConfig,
+    version = "next",
+    short_description = "Requires that throw statements are documented with `@throws`.",
+);
+
+impl Rule for RequireThrows {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        // Step 1. Collect functions which throw.
+        // Like `require-returns`, find the function of each `throw` bottom-up, instead of
+        // searching the body of each function.
+        let mut throwing_functions = FxHashSet::default();
+        'visit_node: for node in ctx.nodes() {
+            if !matches!(node.kind(), AstKind::ThrowStatement(_)) {
+                continue;
+            }
+            let mut current_node = node;
+            while !matches!(current_node.kind(), AstKind::Program(_)) {
+                let parent_node = ctx.nodes().parent_node(current_node.id());
+                match parent_node.kind() {
+                    AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                        throwing_functions.insert(parent_node.id());
+                        continue 'visit_node;
+                    }
+                    // Caught, unless the `catch` clause throws again, which is a `throw` itself
+                    AstKind::TryStatement(try_stmt)
+                        if try_stmt.handler.is_some()
+                            && current_node.span() == try_stmt.block.span =>
+                    {
+                        continue 'visit_node;
+                    }
+                    AstKind::StaticBlock(_) => continue 'visit_node,
+                    _ => current_node = parent_node,
+                }
+            }
+        }
+
+        // Step 2. Check collected functions, in source order
+        let settings = &ctx.settings().jsdoc;
+        for node in ctx.nodes() {
+            if !throwing_functions.contains(&node.id())
+                || !is_function_in_contexts(node.kind(), &self.contexts)
+            {
+                continue;
+            }
+            let is_async = match node.kind() {
+                AstKind::Function(func) => func.r#async,
+                AstKind::ArrowFunctionExpression(arrow_func) => arrow_func.r#async,
+                _ => false,
+            };
+            if is_async {
+                continue;
+            }
+
+            // If no JSDoc is found, skip
+            let Some(jsdocs) = get_function_nearest_jsdoc_node(node, ctx)
+                .and_then(|node| ctx.jsdoc().get_all_by_node(ctx.nodes(), node))
+            else {
+                continue;
+            };
+            // If JSDoc is found but safely ignored, skip
+            if jsdocs.iter().any(|jsdoc| {
+                should_ignore_as_custom_skip(jsdoc)
+                    || should_ignore_as_avoid(jsdoc, settings, &self.exempted_by)
+                    || should_ignore_as_private(jsdoc, settings)
+                    || should_ignore_as_internal(jsdoc, settings)
+            }) {
+                continue;
+            }
+
+            let resolved_throws_tag_name = settings.resolve_tag_name("throws");
+            if is_missing_special_tag(jsdocs.iter().flat_map(JSDoc::tags), resolved_throws_tag_name)
+                && let Some(jsdoc) = jsdocs.last()
+            {
+                ctx.diagnostic(missing_throws_diagnostic(jsdoc.span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+                /**
+                 * @throws {Error} If `foo` is falsy.
+                 */
+                function quux (foo) {
+                  if (!foo) {
+                    throw new Error('err');
+                  }
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  try {
+                    throw new Error('err');
+                  } catch (e) {
+                    console.error(e);
+                  }
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  try {
+                    try {
+                      throw new Error('err');
+                    } finally {
+                      cleanup();
+                    }
+                  } catch {}
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  function inner () {
+                    throw new Error('err');
+                  }
+                  const arrow = () => { throw new Error('err'); };
+                  foo(function () { throw new Error('err'); });
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  class Foo {
+                    static {
+                      throw new Error('err');
+                    }
+                  }
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                function quux () {
+                  throw new Error('err');
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                async function quux () {
+                  throw new Error('err');
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 * @inheritdoc
+                 */
+                function quux () {
+                  throw new Error('err');
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 * @mytype
+                 */
+                function quux () {
+                  throw new Error('err');
+                }
+            ",
+            Some(serde_json::json!([{ "exemptedBy": ["mytype"] }])),
+            None,
+        ),
+        (
+            "
+                /**
+                 * @private
+                 */
+                function quux () {
+                  throw new Error('err');
+                }
+            ",
+            None,
+            Some(serde_json::json!({ "settings": { "jsdoc": { "ignorePrivate": true } } })),
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                const quux = () => {
+                  throw new Error('err');
+                };
+            ",
+            Some(serde_json::json!([{ "contexts": ["FunctionDeclaration"] }])),
+            None,
+        ),
+        (
+            "
+                /**
+                 * @exception {Error}
+                 */
+                function quux () {
+                  throw new Error('err');
+                }
+            ",
+            None,
+            Some(serde_json::json!({ "settings": {
+                "jsdoc": { "tagNamePreference": { "throws": "exception" } },
+            } })),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  throw new Error('err');
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 * Foo.
+                 */
+                const quux = function (foo) {
+                  if (!foo) {
+                    throw new Error('err');
+                  }
+                };
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                export const quux = () => {
+                  throw new Error('err');
+                };
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                class Foo {
+                  /**
+                   *
+                   */
+                  bar () {
+                    throw new Error('err');
+                  }
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  try {
+                    foo();
+                  } catch (e) {
+                    throw e;
+                  }
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  try {
+                    foo();
+                  } finally {
+                    throw new Error('err');
+                  }
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  try {
+                    throw new Error('err');
+                  } finally {
+                    cleanup();
+                  }
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  function inner () {}
+                  throw new Error('err');
+                }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+                /**
+                 * @inheritdoc
+                 */
+                function quux () {
+                  throw new Error('err');
+                }
+            ",
+            Some(serde_json::json!([{ "exemptedBy": [] }])),
+            None,
+        ),
+        (
+            "
+                /**
+                 *
+                 */
+                function quux () {
+                  throw new Error('err');
+                }
+            ",
+            Some(serde_json::json!([{ "contexts": ["FunctionDeclaration"] }])),
+            None,
+        ),
+        (
+            "
+                /**
+                 * @throws {Error}
+                 */
+                function quux () {
+                  throw new Error('err');
+                }
+            ",
+            None,
+            Some(serde_json::json!({ "settings": {
+                "jsdoc": { "tagNamePreference": { "throws": "exception" } },
+            } })),
+        ),
+    ];
+
+    Tester::new(RequireThrows::NAME, RequireThrows::PLUGIN, pass, fail).test_and_snapshot();
+}
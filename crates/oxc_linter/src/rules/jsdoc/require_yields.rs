@@ -1,10 +1,15 @@
 use std::ops::Deref;
 
-use oxc_ast::AstKind;
+use oxc_ast::{
+    AstKind,
+    ast::{ArrowFunctionExpression, Function, YieldExpression},
+};
+use oxc_ast_visit::VisitJs;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{JSDoc, JSDocTag};
 use oxc_span::Span;
+use oxc_syntax::scope::ScopeFlags;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -13,9 +18,9 @@ use crate::{
     context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::{
-        get_function_nearest_jsdoc_node, is_duplicated_special_tag, is_missing_special_tag,
-        should_ignore_as_avoid, should_ignore_as_custom_skip, should_ignore_as_internal,
-        should_ignore_as_private,
+        default_function_contexts, get_function_nearest_jsdoc_node, is_duplicated_special_tag,
+        is_function_in_contexts, is_missing_special_tag, should_ignore_as_avoid,
+        should_ignore_as_custom_skip, should_ignore_as_internal, should_ignore_as_private,
     },
 };
 
@@ -53,6 +58,10 @@ impl Deref for RequireYields {
 pub struct RequireYieldsConfig {
     /// Functions with these tags will be exempted from the lint rule.
     exempted_by: Vec<String>,
+    /// Kinds of functions to check: `FunctionDeclaration` and `FunctionExpression` (which
+    /// includes methods). `ArrowFunctionExpression` is accepted, but arrow functions can't be
+    /// generators.
+    contexts: Vec<String>,
     /// When `true`, all generator functions must have a `@yields` tag, even if they don't yield a value or have an empty body.
     force_require_yields: bool,
     /// When `true`, require `@yields` when a `@generator` tag is present.
@@ -63,6 +72,7 @@ impl Default for RequireYieldsConfig {
     fn default() -> Self {
         Self {
             exempted_by: vec!["inheritdoc".to_string()],
+            contexts: default_function_contexts(),
             force_require_yields: false,
             with_generator_tag: false,
         }
@@ -126,7 +136,7 @@ impl Rule for RequireYields {
         // If generator function does not have JSDoc, it will be skipped.
         match node.kind() {
             AstKind::Function(func)
-                if func.generator && (func.is_expression() || func.is_declaration()) =>
+                if func.generator && is_function_in_contexts(node.kind(), &self.contexts) =>
             {
                 // If no JSDoc is found, skip
                 let Some(jsdocs) = get_function_nearest_jsdoc_node(node, ctx)
@@ -220,6 +230,9 @@ impl Rule for RequireYields {
                 let Some((generator_func, generator_func_node)) = generator_func_node else {
                     return;
                 };
+                if !is_function_in_contexts(generator_func_node.kind(), &self.contexts) {
+                    return;
+                }
 
                 // If no JSDoc is found, skip
                 let Some(jsdocs) = get_function_nearest_jsdoc_node(generator_func_node, ctx)
@@ -245,7 +258,16 @@ impl Rule for RequireYields {
                 let jsdoc_tags = jsdocs.iter().flat_map(JSDoc::tags).collect::<Vec<_>>();
                 let resolved_yields_tag_name = settings.resolve_tag_name("yields");
 
-                if is_missing_special_tag(jsdoc_tags.iter().copied(), resolved_yields_tag_name) {
+                if !is_missing_special_tag(jsdoc_tags.iter().copied(), resolved_yields_tag_name) {
+                    return;
+                }
+
+                // Report the generator function only once, from its first `yield` with a value.
+                let mut finder = FirstYieldWithValueFinder::default();
+                if let Some(body) = &generator_func.body {
+                    finder.visit_function_body(body);
+                }
+                if finder.span == Some(yield_expr.span) {
                     ctx.diagnostic(missing_yields(generator_func.span));
                 }
             }
@@ -254,6 +276,24 @@ impl Rule for RequireYields {
     }
 }
 
+/// Finds the first `yield` with a value in a function, ignoring nested functions.
+#[derive(Default)]
+struct FirstYieldWithValueFinder {
+    span: Option<Span>,
+}
+
+impl<'a> VisitJs<'a> for FirstYieldWithValueFinder {
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.span.is_none() && expr.argument.is_some() {
+            self.span = Some(expr.span);
+        }
+    }
+
+    fn visit_function(&mut self, _function: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+}
+
 fn is_missing_yields_tag_with_generator_tag(
     jsdoc_tags: &Vec<&JSDocTag>,
     resolved_yields_tag_name: &str,
@@ -283,6 +323,18 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        (
+            "
+                              /**
+                               *
+                               */
+                              const quux = function * () {
+                                yield foo;
+                              }
+                          ",
+            Some(serde_json::json!([{ "contexts": ["FunctionDeclaration"] }])),
+            None,
+        ),
         (
             "
                               /**
@@ -1437,6 +1489,18 @@ fn test() {
             Some(serde_json::json!([{ "withGeneratorTag": true, }])),
             None,
         ),
+        (
+            "
+                            /**
+                             *
+                             */
+                            const quux = function * () {
+                              yield foo;
+                            }
+                        ",
+            Some(serde_json::json!([{ "contexts": ["FunctionExpression"] }])),
+            None,
+        ),
     ];
 
     Tester::new(RequireYields::NAME, RequireYields::PLUGIN, pass, fail).test_and_snapshot();
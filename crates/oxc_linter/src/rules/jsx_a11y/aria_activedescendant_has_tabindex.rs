@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::Rule,
     utils::{get_element_type, has_jsx_prop_ignore_case, is_interactive_element, parse_jsx_value},
 };
@@ -81,7 +81,7 @@ impl Rule for AriaActivedescendantHasTabindex {
 
         let element_type = get_element_type(ctx, jsx_opening_el);
 
-        if !HTML_TAG.contains(element_type.as_ref()) {
+        if !is_dom_element(element_type.as_ref()) {
             return;
         }
 
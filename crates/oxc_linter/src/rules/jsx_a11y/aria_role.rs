@@ -11,7 +11,7 @@ use serde::Deserialize;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::{HTML_TAG, VALID_ARIA_ROLES},
+    globals::{VALID_ARIA_ROLES, is_dom_element},
     rule::{DefaultRuleConfig, Rule},
     utils::{get_element_type, get_prop_value, has_jsx_prop},
 };
@@ -114,7 +114,7 @@ impl Rule for AriaRole {
         {
             let element_type = get_element_type(ctx, &jsx_el.opening_element);
 
-            if self.ignore_non_dom && !HTML_TAG.contains(element_type.as_ref()) {
+            if self.ignore_non_dom && !is_dom_element(element_type.as_ref()) {
                 return;
             }
 
@@ -6,7 +6,7 @@ use oxc_span::Span;
 
 use crate::{
     AstNode, LintContext,
-    globals::{RESERVED_HTML_TAG, is_valid_aria_property},
+    globals::{dom_element, is_valid_aria_property},
     rule::Rule,
     utils::{get_element_type, get_jsx_attribute_name},
 };
@@ -56,7 +56,7 @@ impl Rule for AriaUnsupportedElements {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
             let el_type = get_element_type(ctx, jsx_el);
-            if RESERVED_HTML_TAG.contains(&el_type.as_ref()) {
+            if dom_element(&el_type).is_some_and(|el| !el.supports_aria()) {
                 for attr in &jsx_el.attributes {
                     let attr = match attr {
                         JSXAttributeItem::Attribute(attr) => attr,
@@ -80,300 +80,315 @@ impl Rule for AriaUnsupportedElements {
 fn test() {
     use crate::tester::Tester;
 
+    fn components_settings() -> serde_json::Value {
+        serde_json::json!({
+            "settings": { "jsx-a11y": {
+                "components": {
+                    "Meta": "meta",
+                    "Link": "a",
+                }
+            } }
+        })
+    }
+
     let pass = vec![
-        (r"<a role />", None),
-        (r"<abbr role />", None),
-        (r"<acronym role />", None),
-        (r"<address role />", None),
-        (r"<applet role />", None),
-        (r"<area role />", None),
-        (r"<article role />", None),
-        (r"<aside role />", None),
-        (r"<audio role />", None),
-        (r"<b role />", None),
-        (r"<base  />", None),
-        (r"<bdi role />", None),
-        (r"<bdo role />", None),
-        (r"<big role />", None),
-        (r"<blink role />", None),
-        (r"<blockquote role />", None),
-        (r"<body role />", None),
-        (r"<br role />", None),
-        (r"<button role />", None),
-        (r"<canvas role />", None),
-        (r"<caption role />", None),
-        (r"<center role />", None),
-        (r"<cite role />", None),
-        (r"<code role />", None),
-        (r"<col  />", None),
-        (r"<colgroup  />", None),
-        (r"<content role />", None),
-        (r"<data role />", None),
-        (r"<datalist role />", None),
-        (r"<dd role />", None),
-        (r"<del role />", None),
-        (r"<details role />", None),
-        (r"<dfn role />", None),
-        (r"<dialog role />", None),
-        (r"<dir role />", None),
-        (r"<div role />", None),
-        (r"<dl role />", None),
-        (r"<dt role />", None),
-        (r"<em role />", None),
-        (r"<embed role />", None),
-        (r"<fieldset role />", None),
-        (r"<figcaption role />", None),
-        (r"<figure role />", None),
-        (r"<font role />", None),
-        (r"<footer role />", None),
-        (r"<form role />", None),
-        (r"<frame role />", None),
-        (r"<frameset role />", None),
-        (r"<h1 role />", None),
-        (r"<h2 role />", None),
-        (r"<h3 role />", None),
-        (r"<h4 role />", None),
-        (r"<h5 role />", None),
-        (r"<h6 role />", None),
-        (r"<head  />", None),
-        (r"<header role />", None),
-        (r"<hgroup role />", None),
-        (r"<hr role />", None),
-        (r"<html  />", None),
-        (r"<i role />", None),
-        (r"<iframe role />", None),
-        (r"<img role />", None),
-        (r"<input role />", None),
-        (r"<ins role />", None),
-        (r"<kbd role />", None),
-        (r"<keygen role />", None),
-        (r"<label role />", None),
-        (r"<legend role />", None),
-        (r"<li role />", None),
-        (r"<link  />", None),
-        (r"<main role />", None),
-        (r"<map role />", None),
-        (r"<mark role />", None),
-        (r"<marquee role />", None),
-        (r"<menu role />", None),
-        (r"<menuitem role />", None),
-        (r"<meta  />", None),
-        (r"<meter role />", None),
-        (r"<nav role />", None),
-        (r"<noembed  />", None),
-        (r"<noscript  />", None),
-        (r"<object role />", None),
-        (r"<ol role />", None),
-        (r"<optgroup role />", None),
-        (r"<option role />", None),
-        (r"<output role />", None),
-        (r"<p role />", None),
-        (r"<param  />", None),
-        (r"<picture  />", None),
-        (r"<pre role />", None),
-        (r"<progress role />", None),
-        (r"<q role />", None),
-        (r"<rp role />", None),
-        (r"<rt role />", None),
-        (r"<rtc role />", None),
-        (r"<ruby role />", None),
-        (r"<s role />", None),
-        (r"<samp role />", None),
-        (r"<script  />", None),
-        (r"<section role />", None),
-        (r"<select role />", None),
-        (r"<small role />", None),
-        (r"<source  />", None),
-        (r"<spacer role />", None),
-        (r"<span role />", None),
-        (r"<strike role />", None),
-        (r"<strong role />", None),
-        (r"<style  />", None),
-        (r"<sub role />", None),
-        (r"<summary role />", None),
-        (r"<sup role />", None),
-        (r"<table role />", None),
-        (r"<tbody role />", None),
-        (r"<td role />", None),
-        (r"<textarea role />", None),
-        (r"<tfoot role />", None),
-        (r"<th role />", None),
-        (r"<thead role />", None),
-        (r"<time role />", None),
-        (r"<title  />", None),
-        (r"<tr role />", None),
-        (r"<track  />", None),
-        (r"<tt role />", None),
-        (r"<u role />", None),
-        (r"<ul role />", None),
-        (r"<var role />", None),
-        (r"<video role />", None),
-        (r"<wbr role />", None),
-        (r"<xmp role />", None),
-        (r"<a aria-hidden />", None),
-        (r"<abbr aria-hidden />", None),
-        (r"<acronym aria-hidden />", None),
-        (r"<address aria-hidden />", None),
-        (r"<applet aria-hidden />", None),
-        (r"<area aria-hidden />", None),
-        (r"<article aria-hidden />", None),
-        (r"<aside aria-hidden />", None),
-        (r"<audio aria-hidden />", None),
-        (r"<b aria-hidden />", None),
-        (r"<base  />", None),
-        (r"<bdi aria-hidden />", None),
-        (r"<bdo aria-hidden />", None),
-        (r"<big aria-hidden />", None),
-        (r"<blink aria-hidden />", None),
-        (r"<blockquote aria-hidden />", None),
-        (r"<body aria-hidden />", None),
-        (r"<br aria-hidden />", None),
-        (r"<button aria-hidden />", None),
-        (r"<canvas aria-hidden />", None),
-        (r"<caption aria-hidden />", None),
-        (r"<center aria-hidden />", None),
-        (r"<cite aria-hidden />", None),
-        (r"<code aria-hidden />", None),
-        (r"<col  />", None),
-        (r"<colgroup  />", None),
-        (r"<content aria-hidden />", None),
-        (r"<data aria-hidden />", None),
-        (r"<datalist aria-hidden />", None),
-        (r"<dd aria-hidden />", None),
-        (r"<del aria-hidden />", None),
-        (r"<details aria-hidden />", None),
-        (r"<dfn aria-hidden />", None),
-        (r"<dialog aria-hidden />", None),
-        (r"<dir aria-hidden />", None),
-        (r"<div aria-hidden />", None),
-        (r"<dl aria-hidden />", None),
-        (r"<dt aria-hidden />", None),
-        (r"<em aria-hidden />", None),
-        (r"<embed aria-hidden />", None),
-        (r"<fieldset aria-hidden />", None),
-        (r"<figcaption aria-hidden />", None),
-        (r"<figure aria-hidden />", None),
-        (r"<font aria-hidden />", None),
-        (r"<footer aria-hidden />", None),
-        (r"<form aria-hidden />", None),
-        (r"<frame aria-hidden />", None),
-        (r"<frameset aria-hidden />", None),
-        (r"<h1 aria-hidden />", None),
-        (r"<h2 aria-hidden />", None),
-        (r"<h3 aria-hidden />", None),
-        (r"<h4 aria-hidden />", None),
-        (r"<h5 aria-hidden />", None),
-        (r"<h6 aria-hidden />", None),
-        (r"<head  />", None),
-        (r"<header aria-hidden />", None),
-        (r"<hgroup aria-hidden />", None),
-        (r"<hr aria-hidden />", None),
-        (r"<html  />", None),
-        (r"<i aria-hidden />", None),
-        (r"<iframe aria-hidden />", None),
-        (r"<img aria-hidden />", None),
-        (r"<input aria-hidden />", None),
-        (r"<ins aria-hidden />", None),
-        (r"<kbd aria-hidden />", None),
-        (r"<keygen aria-hidden />", None),
-        (r"<label aria-hidden />", None),
-        (r"<legend aria-hidden />", None),
-        (r"<li aria-hidden />", None),
-        (r"<link  />", None),
-        (r"<main aria-hidden />", None),
-        (r"<map aria-hidden />", None),
-        (r"<mark aria-hidden />", None),
-        (r"<marquee aria-hidden />", None),
-        (r"<menu aria-hidden />", None),
-        (r"<menuitem aria-hidden />", None),
-        (r"<meta  />", None),
-        (r"<meter aria-hidden />", None),
-        (r"<nav aria-hidden />", None),
-        (r"<noembed  />", None),
-        (r"<noscript  />", None),
-        (r"<object aria-hidden />", None),
-        (r"<ol aria-hidden />", None),
-        (r"<optgroup aria-hidden />", None),
-        (r"<option aria-hidden />", None),
-        (r"<output aria-hidden />", None),
-        (r"<p aria-hidden />", None),
-        (r"<param  />", None),
-        (r"<picture  />", None),
-        (r"<pre aria-hidden />", None),
-        (r"<progress aria-hidden />", None),
-        (r"<q aria-hidden />", None),
-        (r"<rp aria-hidden />", None),
-        (r"<rt aria-hidden />", None),
-        (r"<rtc aria-hidden />", None),
-        (r"<ruby aria-hidden />", None),
-        (r"<s aria-hidden />", None),
-        (r"<samp aria-hidden />", None),
-        (r"<script  />", None),
-        (r"<section aria-hidden />", None),
-        (r"<select aria-hidden />", None),
-        (r"<small aria-hidden />", None),
-        (r"<source  />", None),
-        (r"<spacer aria-hidden />", None),
-        (r"<span aria-hidden />", None),
-        (r"<strike aria-hidden />", None),
-        (r"<strong aria-hidden />", None),
-        (r"<style  />", None),
-        (r"<sub aria-hidden />", None),
-        (r"<summary aria-hidden />", None),
-        (r"<sup aria-hidden />", None),
-        (r"<table aria-hidden />", None),
-        (r"<tbody aria-hidden />", None),
-        (r"<td aria-hidden />", None),
-        (r"<textarea aria-hidden />", None),
-        (r"<tfoot aria-hidden />", None),
-        (r"<th aria-hidden />", None),
-        (r"<thead aria-hidden />", None),
-        (r"<time aria-hidden />", None),
-        (r"<title  />", None),
-        (r"<tr aria-hidden />", None),
-        (r"<track  />", None),
-        (r"<tt aria-hidden />", None),
-        (r"<u aria-hidden />", None),
-        (r"<ul aria-hidden />", None),
-        (r"<var aria-hidden />", None),
-        (r"<video aria-hidden />", None),
-        (r"<wbr aria-hidden />", None),
-        (r"<xmp aria-hidden />", None),
+        (r"<a role />", None, None),
+        (r"<abbr role />", None, None),
+        (r"<acronym role />", None, None),
+        (r"<address role />", None, None),
+        (r"<applet role />", None, None),
+        (r"<area role />", None, None),
+        (r"<article role />", None, None),
+        (r"<aside role />", None, None),
+        (r"<audio role />", None, None),
+        (r"<b role />", None, None),
+        (r"<base  />", None, None),
+        (r"<bdi role />", None, None),
+        (r"<bdo role />", None, None),
+        (r"<big role />", None, None),
+        (r"<blink role />", None, None),
+        (r"<blockquote role />", None, None),
+        (r"<body role />", None, None),
+        (r"<br role />", None, None),
+        (r"<button role />", None, None),
+        (r"<canvas role />", None, None),
+        (r"<caption role />", None, None),
+        (r"<center role />", None, None),
+        (r"<cite role />", None, None),
+        (r"<code role />", None, None),
+        (r"<col  />", None, None),
+        (r"<colgroup  />", None, None),
+        (r"<content role />", None, None),
+        (r"<data role />", None, None),
+        (r"<datalist role />", None, None),
+        (r"<dd role />", None, None),
+        (r"<del role />", None, None),
+        (r"<details role />", None, None),
+        (r"<dfn role />", None, None),
+        (r"<dialog role />", None, None),
+        (r"<dir role />", None, None),
+        (r"<div role />", None, None),
+        (r"<dl role />", None, None),
+        (r"<dt role />", None, None),
+        (r"<em role />", None, None),
+        (r"<embed role />", None, None),
+        (r"<fieldset role />", None, None),
+        (r"<figcaption role />", None, None),
+        (r"<figure role />", None, None),
+        (r"<font role />", None, None),
+        (r"<footer role />", None, None),
+        (r"<form role />", None, None),
+        (r"<frame role />", None, None),
+        (r"<frameset role />", None, None),
+        (r"<h1 role />", None, None),
+        (r"<h2 role />", None, None),
+        (r"<h3 role />", None, None),
+        (r"<h4 role />", None, None),
+        (r"<h5 role />", None, None),
+        (r"<h6 role />", None, None),
+        (r"<head  />", None, None),
+        (r"<header role />", None, None),
+        (r"<hgroup role />", None, None),
+        (r"<hr role />", None, None),
+        (r"<html  />", None, None),
+        (r"<i role />", None, None),
+        (r"<iframe role />", None, None),
+        (r"<img role />", None, None),
+        (r"<input role />", None, None),
+        (r"<ins role />", None, None),
+        (r"<kbd role />", None, None),
+        (r"<keygen role />", None, None),
+        (r"<label role />", None, None),
+        (r"<legend role />", None, None),
+        (r"<li role />", None, None),
+        (r"<link  />", None, None),
+        (r"<main role />", None, None),
+        (r"<map role />", None, None),
+        (r"<mark role />", None, None),
+        (r"<marquee role />", None, None),
+        (r"<menu role />", None, None),
+        (r"<menuitem role />", None, None),
+        (r"<meta  />", None, None),
+        (r"<meter role />", None, None),
+        (r"<nav role />", None, None),
+        (r"<noembed  />", None, None),
+        (r"<noscript  />", None, None),
+        (r"<object role />", None, None),
+        (r"<ol role />", None, None),
+        (r"<optgroup role />", None, None),
+        (r"<option role />", None, None),
+        (r"<output role />", None, None),
+        (r"<p role />", None, None),
+        (r"<param  />", None, None),
+        (r"<picture  />", None, None),
+        (r"<pre role />", None, None),
+        (r"<progress role />", None, None),
+        (r"<q role />", None, None),
+        (r"<rp role />", None, None),
+        (r"<rt role />", None, None),
+        (r"<rtc role />", None, None),
+        (r"<ruby role />", None, None),
+        (r"<s role />", None, None),
+        (r"<samp role />", None, None),
+        (r"<script  />", None, None),
+        (r"<section role />", None, None),
+        (r"<select role />", None, None),
+        (r"<small role />", None, None),
+        (r"<source  />", None, None),
+        (r"<spacer role />", None, None),
+        (r"<span role />", None, None),
+        (r"<strike role />", None, None),
+        (r"<strong role />", None, None),
+        (r"<style  />", None, None),
+        (r"<sub role />", None, None),
+        (r"<summary role />", None, None),
+        (r"<sup role />", None, None),
+        (r"<table role />", None, None),
+        (r"<tbody role />", None, None),
+        (r"<td role />", None, None),
+        (r"<textarea role />", None, None),
+        (r"<tfoot role />", None, None),
+        (r"<th role />", None, None),
+        (r"<thead role />", None, None),
+        (r"<time role />", None, None),
+        (r"<title  />", None, None),
+        (r"<tr role />", None, None),
+        (r"<track  />", None, None),
+        (r"<tt role />", None, None),
+        (r"<u role />", None, None),
+        (r"<ul role />", None, None),
+        (r"<var role />", None, None),
+        (r"<video role />", None, None),
+        (r"<wbr role />", None, None),
+        (r"<xmp role />", None, None),
+        (r"<a aria-hidden />", None, None),
+        (r"<abbr aria-hidden />", None, None),
+        (r"<acronym aria-hidden />", None, None),
+        (r"<address aria-hidden />", None, None),
+        (r"<applet aria-hidden />", None, None),
+        (r"<area aria-hidden />", None, None),
+        (r"<article aria-hidden />", None, None),
+        (r"<aside aria-hidden />", None, None),
+        (r"<audio aria-hidden />", None, None),
+        (r"<b aria-hidden />", None, None),
+        (r"<base  />", None, None),
+        (r"<bdi aria-hidden />", None, None),
+        (r"<bdo aria-hidden />", None, None),
+        (r"<big aria-hidden />", None, None),
+        (r"<blink aria-hidden />", None, None),
+        (r"<blockquote aria-hidden />", None, None),
+        (r"<body aria-hidden />", None, None),
+        (r"<br aria-hidden />", None, None),
+        (r"<button aria-hidden />", None, None),
+        (r"<canvas aria-hidden />", None, None),
+        (r"<caption aria-hidden />", None, None),
+        (r"<center aria-hidden />", None, None),
+        (r"<cite aria-hidden />", None, None),
+        (r"<code aria-hidden />", None, None),
+        (r"<col  />", None, None),
+        (r"<colgroup  />", None, None),
+        (r"<content aria-hidden />", None, None),
+        (r"<data aria-hidden />", None, None),
+        (r"<datalist aria-hidden />", None, None),
+        (r"<dd aria-hidden />", None, None),
+        (r"<del aria-hidden />", None, None),
+        (r"<details aria-hidden />", None, None),
+        (r"<dfn aria-hidden />", None, None),
+        (r"<dialog aria-hidden />", None, None),
+        (r"<dir aria-hidden />", None, None),
+        (r"<div aria-hidden />", None, None),
+        (r"<dl aria-hidden />", None, None),
+        (r"<dt aria-hidden />", None, None),
+        (r"<em aria-hidden />", None, None),
+        (r"<embed aria-hidden />", None, None),
+        (r"<fieldset aria-hidden />", None, None),
+        (r"<figcaption aria-hidden />", None, None),
+        (r"<figure aria-hidden />", None, None),
+        (r"<font aria-hidden />", None, None),
+        (r"<footer aria-hidden />", None, None),
+        (r"<form aria-hidden />", None, None),
+        (r"<frame aria-hidden />", None, None),
+        (r"<frameset aria-hidden />", None, None),
+        (r"<h1 aria-hidden />", None, None),
+        (r"<h2 aria-hidden />", None, None),
+        (r"<h3 aria-hidden />", None, None),
+        (r"<h4 aria-hidden />", None, None),
+        (r"<h5 aria-hidden />", None, None),
+        (r"<h6 aria-hidden />", None, None),
+        (r"<head  />", None, None),
+        (r"<header aria-hidden />", None, None),
+        (r"<hgroup aria-hidden />", None, None),
+        (r"<hr aria-hidden />", None, None),
+        (r"<html  />", None, None),
+        (r"<i aria-hidden />", None, None),
+        (r"<iframe aria-hidden />", None, None),
+        (r"<img aria-hidden />", None, None),
+        (r"<input aria-hidden />", None, None),
+        (r"<ins aria-hidden />", None, None),
+        (r"<kbd aria-hidden />", None, None),
+        (r"<keygen aria-hidden />", None, None),
+        (r"<label aria-hidden />", None, None),
+        (r"<legend aria-hidden />", None, None),
+        (r"<li aria-hidden />", None, None),
+        (r"<link  />", None, None),
+        (r"<main aria-hidden />", None, None),
+        (r"<map aria-hidden />", None, None),
+        (r"<mark aria-hidden />", None, None),
+        (r"<marquee aria-hidden />", None, None),
+        (r"<menu aria-hidden />", None, None),
+        (r"<menuitem aria-hidden />", None, None),
+        (r"<meta  />", None, None),
+        (r"<meter aria-hidden />", None, None),
+        (r"<nav aria-hidden />", None, None),
+        (r"<noembed  />", None, None),
+        (r"<noscript  />", None, None),
+        (r"<object aria-hidden />", None, None),
+        (r"<ol aria-hidden />", None, None),
+        (r"<optgroup aria-hidden />", None, None),
+        (r"<option aria-hidden />", None, None),
+        (r"<output aria-hidden />", None, None),
+        (r"<p aria-hidden />", None, None),
+        (r"<param  />", None, None),
+        (r"<picture  />", None, None),
+        (r"<pre aria-hidden />", None, None),
+        (r"<progress aria-hidden />", None, None),
+        (r"<q aria-hidden />", None, None),
+        (r"<rp aria-hidden />", None, None),
+        (r"<rt aria-hidden />", None, None),
+        (r"<rtc aria-hidden />", None, None),
+        (r"<ruby aria-hidden />", None, None),
+        (r"<s aria-hidden />", None, None),
+        (r"<samp aria-hidden />", None, None),
+        (r"<script  />", None, None),
+        (r"<section aria-hidden />", None, None),
+        (r"<select aria-hidden />", None, None),
+        (r"<small aria-hidden />", None, None),
+        (r"<source  />", None, None),
+        (r"<spacer aria-hidden />", None, None),
+        (r"<span aria-hidden />", None, None),
+        (r"<strike aria-hidden />", None, None),
+        (r"<strong aria-hidden />", None, None),
+        (r"<style  />", None, None),
+        (r"<sub aria-hidden />", None, None),
+        (r"<summary aria-hidden />", None, None),
+        (r"<sup aria-hidden />", None, None),
+        (r"<table aria-hidden />", None, None),
+        (r"<tbody aria-hidden />", None, None),
+        (r"<td aria-hidden />", None, None),
+        (r"<textarea aria-hidden />", None, None),
+        (r"<tfoot aria-hidden />", None, None),
+        (r"<th aria-hidden />", None, None),
+        (r"<thead aria-hidden />", None, None),
+        (r"<time aria-hidden />", None, None),
+        (r"<title  />", None, None),
+        (r"<tr aria-hidden />", None, None),
+        (r"<track  />", None, None),
+        (r"<tt aria-hidden />", None, None),
+        (r"<u aria-hidden />", None, None),
+        (r"<ul aria-hidden />", None, None),
+        (r"<var aria-hidden />", None, None),
+        (r"<video aria-hidden />", None, None),
+        (r"<wbr aria-hidden />", None, None),
+        (r"<xmp aria-hidden />", None, None),
+        (r"<Meta aria-hidden />", None, None),
+        (r"<Link role />", None, Some(components_settings())),
     ];
 
     let fail = vec![
-        (r"<base role {...props} />", None),
-        (r"<col role {...props} />", None),
-        (r"<colgroup role {...props} />", None),
-        (r"<head role {...props} />", None),
-        (r"<html role {...props} />", None),
-        (r"<link role {...props} />", None),
-        (r"<meta role {...props} />", None),
-        (r"<noembed role {...props} />", None),
-        (r"<noscript role {...props} />", None),
-        (r"<param role {...props} />", None),
-        (r"<picture role {...props} />", None),
-        (r"<script role {...props} />", None),
-        (r"<source role {...props} />", None),
-        (r"<style role {...props} />", None),
-        (r"<title role {...props} />", None),
-        (r"<track role {...props} />", None),
-        (r#"<base aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<col aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<colgroup aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<head aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<html aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<link aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<meta aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<noembed aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<noscript aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<param aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<picture aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<script aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<source aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<style aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<title aria-hidden aria-role="none" {...props} />"#, None),
-        (r#"<track aria-hidden aria-role="none" {...props} />"#, None),
+        (r"<base role {...props} />", None, None),
+        (r"<col role {...props} />", None, None),
+        (r"<colgroup role {...props} />", None, None),
+        (r"<head role {...props} />", None, None),
+        (r"<html role {...props} />", None, None),
+        (r"<link role {...props} />", None, None),
+        (r"<meta role {...props} />", None, None),
+        (r"<noembed role {...props} />", None, None),
+        (r"<noscript role {...props} />", None, None),
+        (r"<param role {...props} />", None, None),
+        (r"<picture role {...props} />", None, None),
+        (r"<script role {...props} />", None, None),
+        (r"<source role {...props} />", None, None),
+        (r"<style role {...props} />", None, None),
+        (r"<title role {...props} />", None, None),
+        (r"<track role {...props} />", None, None),
+        (r#"<base aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<col aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<colgroup aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<head aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<html aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<link aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<meta aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<noembed aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<noscript aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<param aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<picture aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<script aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<source aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<style aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<title aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r#"<track aria-hidden aria-role="none" {...props} />"#, None, None),
+        (r"<Meta aria-hidden />", None, Some(components_settings())),
+        (r"<Meta role />", None, Some(components_settings())),
     ];
 
     let fix = vec![
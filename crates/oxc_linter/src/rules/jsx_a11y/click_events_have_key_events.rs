@@ -6,7 +6,7 @@ use oxc_span::Span;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::Rule,
     utils::{
         get_element_type, has_jsx_prop, is_hidden_from_screen_reader, is_interactive_element,
@@ -64,7 +64,7 @@ impl Rule for ClickEventsHaveKeyEvents {
         // Check only native DOM elements or custom component via settings
         let element_type = get_element_type(ctx, jsx_opening_el);
 
-        if !HTML_TAG.contains(element_type.as_ref()) {
+        if !is_dom_element(element_type.as_ref()) {
             return;
         }
 
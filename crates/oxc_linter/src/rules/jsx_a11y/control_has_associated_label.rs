@@ -15,7 +15,7 @@ use oxc_str::CompactStr;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::{DefaultRuleConfig, Rule},
     utils::{
         get_element_type, get_jsx_attribute_name, get_string_literal_prop_value, has_jsx_prop,
@@ -175,7 +175,7 @@ impl Rule for ControlHasAssociatedLabel {
             return;
         }
 
-        let is_dom_element = HTML_TAG.contains(element_type.as_ref());
+        let is_dom_element = is_dom_element(element_type.as_ref());
         let is_interactive_el = is_interactive_element(&element_type, &element.opening_element);
         let is_interactive_role_el = role.is_some_and(is_interactive_role);
         let is_control_component =
@@ -9,7 +9,7 @@ use serde::Deserialize;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::{DefaultRuleConfig, Rule},
     utils::{get_element_type, get_prop_value, has_jsx_prop},
 };
@@ -90,7 +90,7 @@ impl Rule for MouseEventsHaveKeyEvents {
 
         let el_type = get_element_type(ctx, jsx_opening_el);
 
-        if !HTML_TAG.contains(el_type.as_ref()) {
+        if !is_dom_element(el_type.as_ref()) {
             return;
         }
 
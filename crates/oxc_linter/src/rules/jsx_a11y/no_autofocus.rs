@@ -11,11 +11,9 @@ use serde::Deserialize;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::{DefaultRuleConfig, Rule},
-    utils::{
-        get_element_type, get_string_literal_prop_value, has_jsx_prop, has_jsx_prop_ignore_case,
-    },
+    utils::{get_element_type, get_string_literal_prop_value, has_jsx_prop_ignore_case},
 };
 
 fn no_autofocus_diagnostic(span: Span) -> OxcDiagnostic {
@@ -37,6 +35,8 @@ declare_oxc_lint!(
     ///
     /// Enforce that `autoFocus` prop is not used on elements.
     ///
+    /// The prop is matched case-insensitively, so the HTML spelling `autofocus` is reported too.
+    ///
     /// ### Why is this bad?
     ///
     /// Autofocusing elements can cause usability issues for sighted and
@@ -59,6 +59,7 @@ declare_oxc_lint!(
     /// <div autoFocus="true" />
     /// <div autoFocus="false" />
     /// <div autoFocus={undefined} />
+    /// <input autofocus />
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -71,7 +72,7 @@ declare_oxc_lint!(
     NoAutofocus,
     jsx_a11y,
     correctness,
-    suggestion,
+    fix,
     config = NoAutofocus,
     version = "0.0.19",
     short_description = "Enforce that `autoFocus` prop is not used on elements.",
@@ -86,7 +87,7 @@ impl Rule for NoAutofocus {
         let AstKind::JSXElement(jsx_el) = node.kind() else {
             return;
         };
-        let Some(autofocus) = has_jsx_prop(&jsx_el.opening_element, "autoFocus") else {
+        let Some(autofocus) = has_jsx_prop_ignore_case(&jsx_el.opening_element, "autoFocus") else {
             return;
         };
 
@@ -108,15 +109,15 @@ impl Rule for NoAutofocus {
         if self.ignore_non_dom {
             let element_type = get_element_type(ctx, &jsx_el.opening_element);
 
-            if HTML_TAG.contains(element_type.as_ref()) {
-                ctx.diagnostic_with_suggestion(no_autofocus_diagnostic(attr.span), |fixer| {
+            if is_dom_element(element_type.as_ref()) {
+                ctx.diagnostic_with_fix(no_autofocus_diagnostic(attr.span), |fixer| {
                     fixer.delete(&attr.span)
                 });
             }
             return;
         }
 
-        ctx.diagnostic_with_suggestion(no_autofocus_diagnostic(attr.span), |fixer| {
+        ctx.diagnostic_with_fix(no_autofocus_diagnostic(attr.span), |fixer| {
             fixer.delete(&attr.span)
         });
     }
@@ -186,8 +187,7 @@ fn test() {
 
     let pass = vec![
         ("<div />;", None, None),
-        ("<div autofocus />;", None, None),
-        (r#"<input autofocus="true" />;"#, None, None),
+        ("<div autofocus={false} />;", None, None),
         ("<Foo bar />", None, None),
         ("<Button />", None, None),
         ("<Foo />", Some(ignore_non_dom_schema()), None),
@@ -200,7 +200,7 @@ fn test() {
         ("<div autoFocus={(`false`)} />", None, None),
         (r#"<div autoFocus="false" />"#, None, None),
         ("<Foo autoFocus />", Some(ignore_non_dom_schema()), None),
-        ("<div><div autofocus /></div>", Some(ignore_non_dom_schema()), None),
+        ("<div><Foo autofocus /></div>", Some(ignore_non_dom_schema()), None),
         ("<Button />", None, Some(components_settings())),
         ("<Button />", Some(ignore_non_dom_schema()), Some(components_settings())),
         ("<dialog><div autoFocus /></dialog>", None, None),
@@ -219,6 +219,9 @@ fn test() {
 
     let fail = vec![
         ("<div autoFocus />", None, None),
+        ("<div autofocus />;", None, None),
+        (r#"<input autofocus="true" />;"#, None, None),
+        ("<div><div autofocus /></div>", Some(ignore_non_dom_schema()), None),
         ("<div autoFocus={true} />", None, None),
         // the value of ignoreNonDOM should not impact these failing, as div is a dom element.
         ("<div autoFocus={true} />", Some(ignore_non_dom_schema()), None),
@@ -244,6 +247,7 @@ fn test() {
         ("<div autoFocus='true' />", "<div  />"),
         ("<Button autoFocus='true' />", "<Button  />"),
         ("<input autoFocus />", "<input  />"),
+        ("<input autofocus />", "<input  />"),
         ("<div autoFocus>foo</div>", "<div >foo</div>"),
         ("<div autoFocus id='lol'>foo</div>", "<div  id='lol'>foo</div>"),
     ];
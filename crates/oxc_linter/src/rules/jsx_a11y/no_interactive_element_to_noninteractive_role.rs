@@ -11,7 +11,7 @@ use oxc_str::CompactStr;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::{DefaultRuleConfig, Rule},
     utils::{
         get_element_type, has_jsx_prop_ignore_case, is_interactive_element, is_non_interactive_role,
@@ -93,7 +93,7 @@ impl Rule for NoInteractiveElementToNoninteractiveRole {
         let element_type = get_element_type(ctx, jsx_el);
 
         // Only check known HTML tags
-        if !HTML_TAG.contains(element_type.as_ref()) {
+        if !is_dom_element(element_type.as_ref()) {
             return;
         }
 
@@ -16,7 +16,7 @@ use serde::Deserialize;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::{DefaultRuleConfig, Rule},
     utils::{
         get_element_type, get_prop_value, get_string_literal_prop_value, has_jsx_prop,
@@ -155,7 +155,7 @@ impl Rule for NoNoninteractiveElementInteractions {
         };
 
         let element_type = get_element_type(ctx, jsx_el);
-        if !HTML_TAG.contains(element_type.as_ref()) {
+        if !is_dom_element(element_type.as_ref()) {
             return;
         }
 
@@ -13,7 +13,7 @@ use serde::Deserialize;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::{DefaultRuleConfig, Rule},
     utils::{
         get_element_type, has_jsx_prop_ignore_case, is_interactive_role, is_non_interactive_element,
@@ -179,7 +179,7 @@ impl Rule for NoNoninteractiveElementToInteractiveRole {
         let element_type = get_element_type(ctx, jsx_el);
 
         // Skip custom/unknown elements — only check known HTML tags.
-        if !HTML_TAG.contains(element_type.as_ref()) {
+        if !is_dom_element(element_type.as_ref()) {
             return;
         }
 
@@ -222,7 +222,7 @@ fn test() {
 
     // Default config uses recommended allowed roles.
     let pass = vec![
-        // Custom components (not in HTML_TAG — skipped)
+        // Custom components (not DOM elements — skipped)
         (r"<TestComponent onClick={doFoo} />", None, None),
         (r"<Button onClick={doFoo} />", None, None),
         // Interactive elements with interactive roles (allowed)
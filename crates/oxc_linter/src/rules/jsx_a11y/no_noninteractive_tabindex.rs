@@ -12,7 +12,7 @@ use serde::Deserialize;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::{DefaultRuleConfig, Rule},
     utils::{
         get_element_type, has_jsx_prop_ignore_case, is_interactive_element, is_interactive_role,
@@ -130,7 +130,7 @@ impl Rule for NoNoninteractiveTabindex {
             return;
         }
 
-        if !HTML_TAG.contains(component.as_ref()) {
+        if !is_dom_element(component.as_ref()) {
             return;
         }
 
@@ -14,7 +14,7 @@ use oxc_str::CompactStr;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::{DefaultRuleConfig, Rule},
     utils::{
         get_element_type, get_prop_value, has_jsx_prop, has_jsx_prop_ignore_case, is_abstract_role,
@@ -117,7 +117,7 @@ impl Rule for NoStaticElementInteractions {
         let element_type = get_element_type(ctx, jsx_el);
 
         // Do not test custom JSX elements.
-        if !HTML_TAG.contains(element_type.as_ref()) {
+        if !is_dom_element(element_type.as_ref()) {
             return;
         }
 
@@ -6,7 +6,7 @@ use oxc_span::Span;
 use crate::{
     AstNode,
     context::LintContext,
-    globals::HTML_TAG,
+    globals::is_dom_element,
     rule::Rule,
     utils::{get_element_type, has_jsx_prop_ignore_case},
 };
@@ -69,7 +69,7 @@ impl Rule for Scope {
             return;
         }
 
-        if !HTML_TAG.contains(element_type.as_ref()) {
+        if !is_dom_element(element_type.as_ref()) {
             return;
         }
 
@@ -9,7 +9,7 @@ use oxc_semantic::AstNode;
 use oxc_span::Span;
 
 use crate::{
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::Rule,
     utils::{NEXT_POLYFILLED_FEATURES, find_url_query_value, get_next_script_import_local_name},
 };
@@ -64,15 +64,12 @@ declare_oxc_lint!(
     NoUnwantedPolyfillio,
     nextjs,
     correctness,
+    language = jsx,
     version = "0.2.7",
     short_description = "Prevent use of unsafe polyfill.io domains and duplicate polyfills.",
 );
 
 impl Rule for NoUnwantedPolyfillio {
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
-
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
             return;
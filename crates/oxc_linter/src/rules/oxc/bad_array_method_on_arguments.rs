@@ -1,9 +1,22 @@
-use oxc_ast::{AstKind, MemberExpressionKind};
+use std::ops::Deref;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_ast::{
+    AstKind, MemberExpressionKind,
+    ast::{Argument, IdentifierReference},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    fixer::RuleFixer,
+    rule::{DefaultRuleConfig, Rule},
+};
 
 fn bad_array_method_on_arguments_diagnostic(method_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Bad array method on `arguments`.")
@@ -13,14 +26,65 @@ fn bad_array_method_on_arguments_diagnostic(method_name: &str, span: Span) -> Ox
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
-pub struct BadArrayMethodOnArguments;
+fn arguments_in_json_stringify_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`arguments` passed to `JSON.stringify`.")
+        .with_help(
+            "The `arguments` object is serialized as an object with numeric keys, not as an array. Convert it to an array first, e.g. `Array.from(arguments)`.",
+        )
+        .with_label(span)
+}
+
+fn arguments_in_arrow_outside_function_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`arguments` used in an arrow function outside of any function.")
+        .with_help(
+            "Arrow functions don't have their own `arguments` object, and there is no enclosing function to take it from. Use rest parameters instead.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct BadArrayMethodOnArgumentsConfig {
+    /// Report array methods called on `arguments`, e.g. `arguments.map(fn)`.
+    check_array_methods: bool,
+    /// Report `arguments` passed to `JSON.stringify`, which serializes it as an object rather
+    /// than an array.
+    check_json_stringify: bool,
+    /// Report `arguments` used in arrow functions which are not nested in a regular function,
+    /// e.g. at the top level of a module.
+    check_arrow_functions: bool,
+}
+
+impl Default for BadArrayMethodOnArgumentsConfig {
+    fn default() -> Self {
+        Self { check_array_methods: true, check_json_stringify: true, check_arrow_functions: true }
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct BadArrayMethodOnArguments(BadArrayMethodOnArgumentsConfig);
+
+impl Deref for BadArrayMethodOnArguments {
+    type Target = BadArrayMethodOnArgumentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
     ///
     /// This rule applies when an array method is called on the arguments object itself.
     ///
+    /// It also reports other common misuses of the `arguments` object:
+    /// - passing it to `JSON.stringify`, which serializes it as `{"0": ...}` rather than an array
+    /// - using it in an arrow function which is not nested in a regular function, where there is
+    ///   no `arguments` object to refer to
+    ///
+    /// Each check can be turned off with the options below. References to a variable or
+    /// parameter named `arguments` are ignored.
+    ///
     /// ### Why is this bad?
     ///
     /// The [arguments object](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/arguments)
@@ -58,40 +122,105 @@ declare_oxc_lint!(
     BadArrayMethodOnArguments,
     oxc,
     correctness,
+    suggestion,
+    config = BadArrayMethodOnArgumentsConfig,
     version = "0.0.3",
     short_description = "This rule applies when an array method is called on the arguments object itself.",
 );
 
 impl Rule for BadArrayMethodOnArguments {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let member_expr = match node.kind() {
-            AstKind::ComputedMemberExpression(member_expr) => {
-                MemberExpressionKind::Computed(member_expr)
-            }
-            AstKind::StaticMemberExpression(member_expr) => {
-                MemberExpressionKind::Static(member_expr)
-            }
-            _ => return,
+        let AstKind::IdentifierReference(ident) = node.kind() else {
+            return;
         };
-        if !member_expr.object().is_specific_id("arguments") {
+        if !is_implicit_arguments(ident, ctx) {
             return;
         }
 
-        let AstKind::CallExpression(_) = ctx.nodes().parent_kind(node.id()) else {
-            return;
-        };
-        let Some(name) = member_expr.static_property_name() else {
-            return;
-        };
-        if ARRAY_METHODS.binary_search(&name.as_str()).is_ok() {
-            ctx.diagnostic(bad_array_method_on_arguments_diagnostic(
-                name.as_str(),
-                member_expr.span(),
-            ));
+        let parent = ctx.nodes().parent_node(node.id());
+        match parent.kind() {
+            AstKind::ComputedMemberExpression(member_expr) if self.check_array_methods => {
+                check_array_method(MemberExpressionKind::Computed(member_expr), parent, ident, ctx);
+            }
+            AstKind::StaticMemberExpression(member_expr) if self.check_array_methods => {
+                check_array_method(MemberExpressionKind::Static(member_expr), parent, ident, ctx);
+            }
+            AstKind::CallExpression(call_expr)
+                if self.check_json_stringify
+                    && call_expr.callee.is_specific_member_access("JSON", "stringify")
+                    && matches!(
+                        call_expr.arguments.first(),
+                        Some(Argument::Identifier(arg)) if arg.span == ident.span
+                    ) =>
+            {
+                ctx.diagnostic(arguments_in_json_stringify_diagnostic(ident.span));
+            }
+            _ => {}
+        }
+
+        if self.check_arrow_functions && is_in_arrow_outside_function(node, ctx) {
+            ctx.diagnostic(arguments_in_arrow_outside_function_diagnostic(ident.span));
         }
     }
 }
 
+/// Whether `ident` refers to the implicit `arguments` object, rather than to a variable or
+/// parameter named `arguments`.
+fn is_implicit_arguments(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    ident.name == "arguments"
+        && ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+}
+
+fn check_array_method<'a>(
+    member_expr: MemberExpressionKind<'a>,
+    member_node: &AstNode<'a>,
+    arguments: &IdentifierReference<'a>,
+    ctx: &LintContext<'a>,
+) {
+    if member_expr.object().span() != arguments.span {
+        return;
+    }
+    let AstKind::CallExpression(_) = ctx.nodes().parent_kind(member_node.id()) else {
+        return;
+    };
+    let Some(name) = member_expr.static_property_name() else {
+        return;
+    };
+    if ARRAY_METHODS.binary_search(&name.as_str()).is_err() {
+        return;
+    }
+
+    let fixer = RuleFixer::new(FixKind::Suggestion, ctx);
+    let span = arguments.span;
+    ctx.diagnostic_with_suggestions(
+        bad_array_method_on_arguments_diagnostic(name.as_str(), member_expr.span()),
+        [
+            fixer
+                .replace(span, "Array.from(arguments)")
+                .with_message("Convert `arguments` with `Array.from`"),
+            fixer.replace(span, "[...arguments]").with_message("Spread `arguments` into an array"),
+        ],
+    );
+}
+
+/// Whether `node` is inside an arrow function, and no regular function encloses that arrow
+/// function to provide the `arguments` object.
+fn is_in_arrow_outside_function(node: &AstNode, ctx: &LintContext) -> bool {
+    let mut in_arrow = false;
+    for ancestor in ctx.nodes().ancestors(node.id()) {
+        match ancestor.kind() {
+            AstKind::ArrowFunctionExpression(_) => in_arrow = true,
+            AstKind::Function(_) => return false,
+            _ => {}
+        }
+    }
+    in_arrow
+}
+
 /// <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array#instance_methods>
 #[rustfmt::skip]
 const ARRAY_METHODS: [&str; 38] = [
@@ -120,70 +249,125 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        "function fn() {}",
-        "function fn(...args) {return args.reduce((prev, cur) => prev + cur, 0)}",
-        "function fn() {arguments.foo}",
-        "function fn() {arguments.map}",
-        "function fn() {arguments[method] }",
-        "function fn() {let method='map'; arguments[method](() => {}) }",
-        "function fn() {arguments['map']}",
-        "function fn() {arguments[`map`]}",
-        "function fn() {arg['map'](() => {})}",
-        "function fn() {foo.arguments.map}",
-        "function fn() {arguments[`map${''}`]((prev, cur) => prev + cur, 0)}",
-        "function fn() {arguments[`${''}map`]((prev, cur) => prev + cur, 0)}",
-        "function fn() {arguments[`${'map'}`]((prev, cur) => prev + cur, 0)}",
-        "function fn() {arguments.toLocaleString(() => {})}",
-        "function fn() {arguments.toString(() => {})}",
-        "function fn() { Array.prototype.slice.call(arguments) }",
+        ("function fn() {}", None),
+        ("function fn(...args) {return args.reduce((prev, cur) => prev + cur, 0)}", None),
+        ("function fn() {arguments.foo}", None),
+        ("function fn() {arguments.map}", None),
+        ("function fn() {arguments[method] }", None),
+        ("function fn() {let method='map'; arguments[method](() => {}) }", None),
+        ("function fn() {arguments['map']}", None),
+        ("function fn() {arguments[`map`]}", None),
+        ("function fn() {arg['map'](() => {})}", None),
+        ("function fn() {foo.arguments.map}", None),
+        ("function fn() {arguments[`map${''}`]((prev, cur) => prev + cur, 0)}", None),
+        ("function fn() {arguments[`${''}map`]((prev, cur) => prev + cur, 0)}", None),
+        ("function fn() {arguments[`${'map'}`]((prev, cur) => prev + cur, 0)}", None),
+        ("function fn() {arguments.toLocaleString(() => {})}", None),
+        ("function fn() {arguments.toString(() => {})}", None),
+        ("function fn() { Array.prototype.slice.call(arguments) }", None),
+        ("function fn(arguments) { arguments.map(() => {}) }", None),
+        ("function fn() { JSON.stringify(Array.from(arguments)) }", None),
+        ("function fn() { JSON.stringify({ args: [...arguments] }) }", None),
+        ("function fn() { return () => arguments[0] }", None),
+        ("function fn() { return () => () => arguments.length }", None),
+        ("const fn = function () { return () => arguments }", None),
+        ("class A { method() { return () => arguments } }", None),
+        ("const fn = () => args.map(f)", None),
+        (
+            "function fn() { arguments.map(f) }",
+            Some(serde_json::json!([{ "checkArrayMethods": false }])),
+        ),
+        (
+            "function fn() { JSON.stringify(arguments) }",
+            Some(serde_json::json!([{ "checkJsonStringify": false }])),
+        ),
+        (
+            "const fn = () => arguments[0]",
+            Some(serde_json::json!([{ "checkArrowFunctions": false }])),
+        ),
     ];
 
     let fail = vec![
-        "function fn() {arguments['map'](() => {})}",
-        "function fn() {arguments[`map`](() => {})}",
-        "function fn() {arguments.at(0)}",
-        "function fn() {arguments.concat([])}",
-        "function fn() {arguments.copyWithin(0)}",
-        "function fn() {arguments.entries()}",
-        "function fn() {arguments.every(() => {})}",
-        "function fn() {arguments.fill(() => {})}",
-        "function fn() {arguments.filter(() => {})}",
-        "function fn() {arguments.find(() => {})}",
-        "function fn() {arguments.findIndex(() => {})}",
-        "function fn() {arguments.findLast(() => {})}",
-        "function fn() {arguments.findLastIndex(() => {})}",
-        "function fn() {arguments.flat(() => {})}",
-        "function fn() {arguments.flatMap(() => {})}",
-        "function fn() {arguments.forEach(() => {})}",
-        "function fn() {arguments.groupBy(() => {})}",
-        "function fn() {arguments.includes(() => {})}",
-        "function fn() {arguments.indexOf(() => {})}",
-        "function fn() {arguments.join()}",
-        "function fn() {arguments.keys()}",
-        "function fn() {arguments.lastIndexOf('')}",
-        "function fn() {arguments.map(() => {})}",
-        "function fn() {arguments.pop()}",
-        "function fn() {arguments.push('')}",
-        "function fn() {arguments.reduce(() => {})}",
-        "function fn() {arguments.reduceRight(() => {})}",
-        "function fn() {arguments.reverse()}",
-        "function fn() {arguments.shift()}",
-        "function fn() {arguments.slice()}",
-        "function fn() {arguments.some(() => {})}",
-        "function fn() {arguments.sort(() => {})}",
-        "function fn() {arguments.splice(() => {})}",
-        "function fn() {arguments.toReversed(() => {})}",
-        "function fn() {arguments.toSorted(() => {})}",
-        "function fn() {arguments.toSpliced(0)}",
-        "function fn() {arguments.unshift()}",
-        "function fn() {arguments.values()}",
-        "function fn() {arguments['@@iterator'](() => {})}",
-        "const arr = [1, 2, 3, 4, 5];
+        ("function fn() {arguments['map'](() => {})}", None),
+        ("function fn() {arguments[`map`](() => {})}", None),
+        ("function fn() {arguments.at(0)}", None),
+        ("function fn() {arguments.concat([])}", None),
+        ("function fn() {arguments.copyWithin(0)}", None),
+        ("function fn() {arguments.entries()}", None),
+        ("function fn() {arguments.every(() => {})}", None),
+        ("function fn() {arguments.fill(() => {})}", None),
+        ("function fn() {arguments.filter(() => {})}", None),
+        ("function fn() {arguments.find(() => {})}", None),
+        ("function fn() {arguments.findIndex(() => {})}", None),
+        ("function fn() {arguments.findLast(() => {})}", None),
+        ("function fn() {arguments.findLastIndex(() => {})}", None),
+        ("function fn() {arguments.flat(() => {})}", None),
+        ("function fn() {arguments.flatMap(() => {})}", None),
+        ("function fn() {arguments.forEach(() => {})}", None),
+        ("function fn() {arguments.groupBy(() => {})}", None),
+        ("function fn() {arguments.includes(() => {})}", None),
+        ("function fn() {arguments.indexOf(() => {})}", None),
+        ("function fn() {arguments.join()}", None),
+        ("function fn() {arguments.keys()}", None),
+        ("function fn() {arguments.lastIndexOf('')}", None),
+        ("function fn() {arguments.map(() => {})}", None),
+        ("function fn() {arguments.pop()}", None),
+        ("function fn() {arguments.push('')}", None),
+        ("function fn() {arguments.reduce(() => {})}", None),
+        ("function fn() {arguments.reduceRight(() => {})}", None),
+        ("function fn() {arguments.reverse()}", None),
+        ("function fn() {arguments.shift()}", None),
+        ("function fn() {arguments.slice()}", None),
+        ("function fn() {arguments.some(() => {})}", None),
+        ("function fn() {arguments.sort(() => {})}", None),
+        ("function fn() {arguments.splice(() => {})}", None),
+        ("function fn() {arguments.toReversed(() => {})}", None),
+        ("function fn() {arguments.toSorted(() => {})}", None),
+        ("function fn() {arguments.toSpliced(0)}", None),
+        ("function fn() {arguments.unshift()}", None),
+        ("function fn() {arguments.values()}", None),
+        ("function fn() {arguments['@@iterator'](() => {})}", None),
+        (
+            "const arr = [1, 2, 3, 4, 5];
          function fn() { arguments.with(2, 6) }
          fn(arr)",
+            None,
+        ),
+        ("function fn() { return JSON.stringify(arguments) }", None),
+        ("function fn() { return JSON.stringify(arguments, null, 2) }", None),
+        ("const fn = () => arguments[0]", None),
+        ("const fn = () => arguments.map(f)", None),
+        ("export default () => JSON.stringify(arguments)", None),
+        ("class A { method = () => arguments }", None),
+        (
+            "const fn = () => arguments.map(f)",
+            Some(serde_json::json!([{ "checkArrowFunctions": false }])),
+        ),
+        (
+            "const fn = () => JSON.stringify(arguments)",
+            Some(serde_json::json!([{ "checkArrayMethods": false, "checkArrowFunctions": false }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "function fn() { return arguments.map(f) }",
+            (
+                "function fn() { return Array.from(arguments).map(f) }",
+                "function fn() { return [...arguments].map(f) }",
+            ),
+        ),
+        (
+            "function fn() { arguments['forEach'](f) }",
+            (
+                "function fn() { Array.from(arguments)['forEach'](f) }",
+                "function fn() { [...arguments]['forEach'](f) }",
+            ),
+        ),
     ];
 
     Tester::new(BadArrayMethodOnArguments::NAME, BadArrayMethodOnArguments::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
 
@@ -0,0 +1,186 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn non_integer_number_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`BigInt` throws a `RangeError` for numbers which are not integers.")
+        .with_help("Pass an integer, or round the number first, e.g. with `Math.trunc`.")
+        .with_label(span)
+}
+
+fn non_integer_string_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`BigInt` throws a `SyntaxError` for strings which are not integers.")
+        .with_help("Pass a string of decimal digits with an optional sign, or of hexadecimal, octal or binary digits with a `0x`, `0o` or `0b` prefix.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct BadBigintArg;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports calls to the global `BigInt` function with a number or string literal which it
+    /// can't convert.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `BigInt` throws a `RangeError` for numbers which are not integers, and a `SyntaxError` for
+    /// strings which don't contain an integer, so these calls always throw at runtime.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const a = BigInt(1.5);
+    /// const b = BigInt("not a number");
+    /// const c = BigInt("1.0");
+    /// const d = BigInt("-0x10");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const a = BigInt(1);
+    /// const b = BigInt("123");
+    /// const c = BigInt(" -42 ");
+    /// const d = BigInt("0x10");
+    /// const e = BigInt(Math.trunc(1.5));
+    /// ```
+    BadBigintArg,
+    oxc,
+    correctness,
+    version = "next",
+    short_description = "Disallows `BigInt` calls with literals which always throw.",
+);
+
+impl Rule for BadBigintArg {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let Expression::Identifier(ident) = call_expr.callee.without_parentheses() else {
+            return;
+        };
+        if ident.name != "BigInt" || !ident.is_global_reference(ctx.scoping()) {
+            return;
+        }
+        let Some(argument) = call_expr.arguments.first().and_then(Argument::as_expression) else {
+            return;
+        };
+
+        match argument.without_parentheses() {
+            Expression::StringLiteral(lit) if !is_string_integer(&lit.value) => {
+                ctx.diagnostic(non_integer_string_diagnostic(argument.span()));
+            }
+            Expression::TemplateLiteral(lit)
+                if lit.single_quasi().is_some_and(|value| !is_string_integer(&value)) =>
+            {
+                ctx.diagnostic(non_integer_string_diagnostic(argument.span()));
+            }
+            expr => {
+                if numeric_value(expr).is_some_and(|value| value.fract() != 0.0) {
+                    ctx.diagnostic(non_integer_number_diagnostic(argument.span()));
+                }
+            }
+        }
+    }
+}
+
+/// The value of a number literal, optionally negated or preceded by `+`.
+fn numeric_value(expr: &Expression) -> Option<f64> {
+    match expr.without_parentheses() {
+        Expression::NumericLiteral(lit) => Some(lit.value),
+        Expression::UnaryExpression(unary) => match unary.operator {
+            UnaryOperator::UnaryNegation => numeric_value(&unary.argument).map(|value| -value),
+            UnaryOperator::UnaryPlus => numeric_value(&unary.argument),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `value` is a string which `BigInt` converts, following `StringIntegerLiteral` in the
+/// spec: an optionally signed decimal integer, or an unsigned integer with a radix prefix,
+/// surrounded by optional whitespace. An empty string converts to `0n`.
+fn is_string_integer(value: &str) -> bool {
+    let value = value.trim_matches(|c: char| c.is_whitespace() || c == '\u{feff}');
+    if value.is_empty() {
+        return true;
+    }
+    let bytes = value.as_bytes();
+    if bytes.len() > 2 && bytes[0] == b'0' {
+        let digits = &bytes[2..];
+        match bytes[1] {
+            b'x' | b'X' => return digits.iter().all(u8::is_ascii_hexdigit),
+            b'o' | b'O' => return digits.iter().all(|b| matches!(b, b'0'..=b'7')),
+            b'b' | b'B' => return digits.iter().all(|b| matches!(b, b'0' | b'1')),
+            _ => {}
+        }
+    }
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "BigInt(1)",
+        "BigInt(-1)",
+        "BigInt(1.0)",
+        "BigInt(1e21)",
+        "BigInt(0x10)",
+        "BigInt(foo)",
+        "BigInt(1.5 + foo)",
+        r#"BigInt("123")"#,
+        r#"BigInt("-123")"#,
+        r#"BigInt("+123")"#,
+        r#"BigInt(" 123\n")"#,
+        r#"BigInt("")"#,
+        r#"BigInt("  ")"#,
+        r#"BigInt("0x1f")"#,
+        r#"BigInt("0XAbC")"#,
+        r#"BigInt("0o17")"#,
+        r#"BigInt("0b101")"#,
+        "BigInt(`123`)",
+        "BigInt(`${foo}.5`)",
+        "BigInt(123n)",
+        "BigInt()",
+        r#"foo.BigInt("bar")"#,
+        "new BigInt(1.5)",
+        "function foo(BigInt) { BigInt(1.5); }",
+        r#"const BigInt = (value) => value; BigInt("bar");"#,
+    ];
+
+    let fail = vec![
+        "BigInt(1.5)",
+        "BigInt(-0.5)",
+        "BigInt((1.5))",
+        "BigInt(.5)",
+        "BigInt(1e400)",
+        "BigInt(1e-7)",
+        r#"BigInt("not a number")"#,
+        r#"BigInt("1.0")"#,
+        r#"BigInt("1e3")"#,
+        r#"BigInt("1_000")"#,
+        r#"BigInt("123n")"#,
+        r#"BigInt("-0x10")"#,
+        r#"BigInt("0x")"#,
+        r#"BigInt("0b102")"#,
+        r#"BigInt("0o8")"#,
+        r#"BigInt("-")"#,
+        "BigInt(`1.5`)",
+        r#"(BigInt)("foo")"#,
+        r#"globalThis.foo = BigInt("foo")"#,
+    ];
+
+    Tester::new(BadBigintArg::NAME, BadBigintArg::PLUGIN, pass, fail).test_and_snapshot();
+}
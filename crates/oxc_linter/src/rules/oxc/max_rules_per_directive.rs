@@ -0,0 +1,138 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    DirectivePrefix,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirective, CommentDirectiveKind, find_comment_directive,
+    },
+};
+
+fn max_rules_per_directive_diagnostic(
+    directive: &CommentDirective,
+    count: usize,
+    max: usize,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{}{}` comment suppresses too many rules ({count}). Maximum allowed is {max}.",
+        directive.prefix.prefix(),
+        directive.kind.as_str()
+    ))
+    .with_help("Refactor the code to satisfy some of these rules, or split the comment to scope each rule to the code it applies to.")
+    .with_label(span)
+}
+
+const DEFAULT_MAX_RULES: usize = 5;
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MaxRulesPerDirective {
+    /// The maximum number of rules a directive comment may list.
+    max: usize,
+}
+
+impl Default for MaxRulesPerDirective {
+    fn default() -> Self {
+        Self { max: DEFAULT_MAX_RULES }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a maximum number of rules listed in a single `oxlint-*` or `eslint-*` directive
+    /// comment, 5 by default. `oxlint-enable` comments are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Code which needs many rules to be disabled at once is usually code which should be
+    /// refactored, and a long rule list makes it hard to tell which rule applies to which part
+    /// of the code.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the `{ "max": 2 }` option:
+    /// ```js
+    /// // oxlint-disable-next-line no-console, no-debugger, no-alert
+    /// console.log(alert(foo)); debugger;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the `{ "max": 2 }` option:
+    /// ```js
+    /// // oxlint-disable-next-line no-console, no-alert
+    /// console.log(alert(foo));
+    /// // oxlint-disable-next-line no-debugger
+    /// debugger;
+    /// ```
+    MaxRulesPerDirective,
+    oxc,
+    pedantic,
+    config = MaxRulesPerDirective,
+    version = "next",
+    short_description = "Enforce a maximum number of rules in a directive comment.",
+);
+
+impl Rule for MaxRulesPerDirective {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        for comment in ctx.comments() {
+            let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, source_text, prefix))
+            else {
+                continue;
+            };
+            if directive.kind == CommentDirectiveKind::Enable || directive.rules.len() <= self.max {
+                continue;
+            }
+            let (Some((_, first)), Some((_, last))) =
+                (directive.rules.first(), directive.rules.last())
+            else {
+                continue;
+            };
+            ctx.diagnostic(max_rules_per_directive_diagnostic(
+                &directive,
+                directive.rules.len(),
+                self.max,
+                Span::new(first.start, last.end),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("// oxlint-disable-next-line no-console\nconsole.log(foo);", None),
+        ("/* oxlint-disable */", None),
+        ("/* eslint-disable a, b, c, d, e */", None),
+        ("// oxlint-disable-next-line a, b\nfoo();", Some(json!([{ "max": 2 }]))),
+        ("foo(); // eslint-disable-line a, b -- c, d, e", Some(json!([{ "max": 2 }]))),
+        ("/* oxlint-enable a, b, c */", Some(json!([{ "max": 2 }]))),
+        ("// a, b, c, d, e, f", None),
+    ];
+
+    let fail = vec![
+        ("/* eslint-disable a, b, c, d, e, f */", None),
+        ("// oxlint-disable-next-line a, b, c\nfoo();", Some(json!([{ "max": 2 }]))),
+        ("foo(); // eslint-disable-line a, b, c -- reason", Some(json!([{ "max": 2 }]))),
+        ("/* oxlint-disable\n  a,\n  b,\n  c\n*/", Some(json!([{ "max": 2 }]))),
+        ("// oxlint-disable-next-line no-console\nconsole.log(foo);", Some(json!([{ "max": 0 }]))),
+    ];
+
+    Tester::new(MaxRulesPerDirective::NAME, MaxRulesPerDirective::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -83,9 +83,8 @@ impl Rule for MisrefactoredAssignOp {
                     |fixer| fixer.replace(span, suggestion),
                 );
             }
-
             // lhs op= l commutative_op r
-            if is_commutative_operator(binary_expr.operator)
+            else if is_commutative_operator(binary_expr.operator)
                 && assignment_target_eq_expr(&assignment_expr.left, &binary_expr.right, ctx)
             {
                 let suggestion = format!(
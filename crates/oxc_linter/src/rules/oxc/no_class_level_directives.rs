@@ -0,0 +1,285 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Class, Declaration, ExportDefaultDeclarationKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::GetSpan;
+
+use crate::{
+    AstNode, DirectivePrefix,
+    context::LintContext,
+    rule::Rule,
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirective, CommentDirectiveKind, find_comment_directive, node_after_span,
+    },
+};
+
+fn no_class_level_directives_diagnostic(
+    directive: &CommentDirective,
+    class: &Class,
+    enable: Option<&CommentDirective>,
+) -> OxcDiagnostic {
+    let class_name =
+        class.id.as_ref().map_or_else(|| "class".into(), |id| format!("`{}`", id.name));
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "`{}{}` comment disables rules for the whole {class_name}.",
+        directive.prefix.prefix(),
+        directive.kind.as_str()
+    ))
+    .with_help(
+        "Disable the rules only in the members reporting them, e.g. with a `disable-next-line` comment above each member.",
+    )
+    .with_label(directive.comment_span.primary_label("covers the whole class"))
+    .and_label(
+        class.id.as_ref().map_or(class.span, GetSpan::span).label(format!(
+            "{class_name} has {} members",
+            class.body.body.len()
+        )),
+    );
+    match enable {
+        Some(enable) => diagnostic.and_label(enable.comment_span.label("enabled again here")),
+        None => diagnostic,
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoClassLevelDirectives;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `oxlint-disable` comments (and their `eslint-disable` equivalents) placed
+    /// directly above a class declaration, which disable rules for the whole class:
+    ///
+    /// - an `oxlint-disable` comment whose rules are enabled again only after the end of the
+    ///   class, and
+    /// - an `oxlint-disable-next-line` comment above a class written on a single line.
+    ///
+    /// Only classes with at least two members are reported, since scoping the directive to a
+    /// single member wouldn't make it much narrower. `oxlint-disable` comments which are never
+    /// enabled again apply to the rest of the file, not only to the class, and are not reported.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Diagnostics usually come from one or two members of a class. A directive covering the
+    /// whole class also suppresses them in every other member, including members added later.
+    /// Disabling the rules only in the members reporting them keeps the rest of the class
+    /// checked.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// /* oxlint-disable no-console */
+    /// class Logger {
+    ///   log(message: string) {
+    ///     console.log(message);
+    ///   }
+    ///   format(message: string) {
+    ///     return `[log] ${message}`;
+    ///   }
+    /// }
+    /// /* oxlint-enable no-console */
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// class Logger {
+    ///   log(message: string) {
+    ///     // oxlint-disable-next-line no-console
+    ///     console.log(message);
+    ///   }
+    ///   format(message: string) {
+    ///     return `[log] ${message}`;
+    ///   }
+    /// }
+    /// ```
+    NoClassLevelDirectives,
+    oxc,
+    restriction,
+    version = "next",
+    short_description = "Disallow directive comments disabling rules for a whole class.",
+);
+
+impl Rule for NoClassLevelDirectives {
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let directives = ctx
+            .comments()
+            .iter()
+            .filter_map(|comment| {
+                [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                    .into_iter()
+                    .find_map(|prefix| find_comment_directive(comment, source_text, prefix))
+            })
+            .collect::<Vec<_>>();
+
+        for directive in &directives {
+            if !matches!(
+                directive.kind,
+                CommentDirectiveKind::Disable | CommentDirectiveKind::DisableNextLine
+            ) {
+                continue;
+            }
+            let Some(class) =
+                node_after_span(ctx.semantic(), directive.comment_span).and_then(class_declaration)
+            else {
+                continue;
+            };
+            if class.body.body.len() < 2 {
+                continue;
+            }
+
+            let class_source = class.span.source_text(source_text);
+            let enable = if directive.kind == CommentDirectiveKind::Disable {
+                // The first `enable` comment ending any of the disabled rules.
+                let Some(enable) = directives.iter().find(|enable| {
+                    enable.kind == CommentDirectiveKind::Enable
+                        && enable.prefix == directive.prefix
+                        && enable.comment_span.start > directive.comment_span.end
+                        && (enable.rules.is_empty()
+                            || directive.rules.is_empty()
+                            || directive.rules.iter().any(|(name, _)| {
+                                enable.references_rule(
+                                    name.rsplit_once('/').map_or(name, |(_, rule)| rule),
+                                )
+                            }))
+                }) else {
+                    continue;
+                };
+                if enable.comment_span.start < class.span.end {
+                    continue;
+                }
+                Some(enable)
+            } else if class_source.contains('\n') {
+                // `disable-next-line` only covers the first line of the class.
+                continue;
+            } else {
+                None
+            };
+
+            ctx.diagnostic(no_class_level_directives_diagnostic(directive, class, enable));
+        }
+    }
+}
+
+/// The class declared by `node`, including exported classes.
+fn class_declaration<'a, 'c>(node: &'c AstNode<'a>) -> Option<&'c Class<'a>> {
+    match node.kind() {
+        AstKind::Class(class) => Some(class),
+        AstKind::ExportNamedDeclaration(export) => match &export.declaration {
+            Some(Declaration::ClassDeclaration(class)) => Some(class),
+            _ => None,
+        },
+        AstKind::ExportDefaultDeclaration(export) => match &export.declaration {
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        // Directives scoped to members.
+        "class Logger {
+          log(message: string) {
+            // oxlint-disable-next-line no-console
+            console.log(message);
+          }
+          format(message: string) {
+            return message;
+          }
+        }",
+        // `disable-next-line` only covers the first line of a multi-line class.
+        "// eslint-disable-next-line @typescript-eslint/no-extraneous-class
+        class Foo {
+          a = 1;
+          b = 2;
+        }",
+        // Never enabled again, so it applies to the rest of the file.
+        "/* oxlint-disable no-console */
+        class Logger {
+          log() { console.log(1); }
+          warn() { console.warn(1); }
+        }",
+        // Enabled again inside the class.
+        "/* oxlint-disable no-console */
+        class Logger {
+          log() { console.log(1); }
+          /* oxlint-enable no-console */
+          warn() { console.warn(1); }
+        }",
+        // Only one member.
+        "/* oxlint-disable no-console */
+        class Logger {
+          log(message: string) { console.log(message); }
+        }
+        /* oxlint-enable no-console */",
+        "// oxlint-disable-next-line no-console
+        class Logger { log() { console.log(1); } }",
+        // Not directly above the class.
+        "/* oxlint-disable no-console */
+        const level = 1;
+        class Logger {
+          log() { console.log(level); }
+          warn() { console.warn(level); }
+        }
+        /* oxlint-enable no-console */",
+        // Enabling other rules doesn't end the directive before the class.
+        "/* oxlint-disable no-console */
+        foo();
+        /* oxlint-enable no-debugger */
+        class Logger {
+          log() { console.log(1); }
+          warn() { console.warn(1); }
+        }
+        /* oxlint-enable no-console */",
+        "/* oxlint-enable no-console */
+        class Logger {
+          log() { console.log(1); }
+          warn() { console.warn(1); }
+        }",
+        "// oxlint-disable-next-line no-console
+        const logger = { log() { console.log(1); }, warn() { console.warn(1); } };",
+    ];
+
+    let fail = vec![
+        "/* oxlint-disable no-console */
+        class Logger {
+          log(message: string) { console.log(message); }
+          format(message: string) { return message; }
+        }
+        /* oxlint-enable no-console */",
+        "/* eslint-disable @typescript-eslint/no-explicit-any */
+        export class Store {
+          private items: any[] = [];
+          add(item: any) { this.items.push(item); }
+        }
+        /* eslint-enable @typescript-eslint/no-explicit-any */",
+        "/* oxlint-disable no-console, no-debugger */
+        export default class {
+          log() { console.log(1); }
+          debug() { debugger; }
+        }
+        /* oxlint-enable no-debugger */",
+        "/* oxlint-disable no-console */
+        abstract class Base {
+          abstract run(): void;
+          stop() { console.log(1); }
+        }
+        /* oxlint-enable */",
+        "// oxlint-disable-next-line no-console
+        class Logger { log() { console.log(1); } warn() { console.warn(1); } }",
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any
+        export class Pair { first: any; second: any }",
+    ];
+
+    Tester::new(NoClassLevelDirectives::NAME, NoClassLevelDirectives::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .test_and_snapshot();
+}
@@ -0,0 +1,159 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    DirectivePrefix, context::LintContext, rule::Rule,
+    rules::oxc::no_eslint_disable_comments::find_comment_directive,
+};
+
+fn no_deprecated_directive_rules_diagnostic(
+    rule_name: &str,
+    replacement: &str,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Rule `{rule_name}` is deprecated."))
+        .with_help(format!("Use `{replacement}` instead."))
+        .with_label(span)
+}
+
+/// Deprecated rule names with the rule replacing them, without plugin prefixes.
+///
+/// The deprecated rules are still available, so directives using them keep working, but they
+/// will be removed eventually.
+const DEPRECATED_RULE_NAMES: [(&str, &str); 2] = [
+    // unicorn
+    ("no-instanceof-array", "no-instanceof-builtins"),
+    // typescript
+    ("no-var-requires", "no-require-imports"),
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecatedDirectiveRules;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows deprecated rule names in `oxlint-disable`, `oxlint-disable-next-line`,
+    /// `oxlint-disable-line` and `oxlint-enable` comments, and replaces them with the name of the
+    /// rule replacing them.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated rules are kept around so that existing configs and comments keep working, but
+    /// they will be removed in a future version. Once a project enables the replacement rule, a
+    /// directive using the old name no longer suppresses anything.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line unicorn/no-instanceof-array
+    /// if (value instanceof Array) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line unicorn/no-instanceof-builtins
+    /// if (value instanceof Array) {}
+    /// ```
+    NoDeprecatedDirectiveRules,
+    oxc,
+    style,
+    fix,
+    version = "next",
+    short_description = "Disallows deprecated rule names in `oxlint-*` directive comments.",
+);
+
+impl Rule for NoDeprecatedDirectiveRules {
+    fn run_once(&self, ctx: &LintContext) {
+        for comment in ctx.comments() {
+            let Some(directive) =
+                find_comment_directive(comment, ctx.source_text(), DirectivePrefix::Oxlint)
+            else {
+                continue;
+            };
+
+            for &(name, span) in &directive.rules {
+                let rule_name = name.rsplit_once('/').map_or(name, |(_, rule)| rule);
+                let Some(&(_, replacement)) =
+                    DEPRECATED_RULE_NAMES.iter().find(|(deprecated, _)| *deprecated == rule_name)
+                else {
+                    continue;
+                };
+
+                // Keep the plugin prefix, e.g. `unicorn/no-instanceof-array`.
+                let plugin_prefix = &name[..name.len() - rule_name.len()];
+                #[expect(clippy::cast_possible_truncation)]
+                let rule_name_span = Span::new(span.end - rule_name.len() as u32, span.end);
+                ctx.diagnostic_with_fix(
+                    no_deprecated_directive_rules_diagnostic(
+                        name,
+                        &format!("{plugin_prefix}{replacement}"),
+                        span,
+                    ),
+                    |fixer| fixer.replace(rule_name_span, replacement),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "// oxlint-disable-next-line unicorn/no-instanceof-builtins
+        if (value instanceof Array) {}",
+        "/* oxlint-disable typescript/no-require-imports */",
+        "/* oxlint-disable */",
+        "// eslint-disable-next-line unicorn/no-instanceof-array
+        if (value instanceof Array) {}",
+        "// no-instanceof-array",
+        "// oxlint-disable-next-line no-console -- no-var-requires is deprecated
+        console.log(foo);",
+    ];
+
+    let fail = vec![
+        "// oxlint-disable-next-line unicorn/no-instanceof-array
+        if (value instanceof Array) {}",
+        "// oxlint-disable-next-line no-instanceof-array
+        if (value instanceof Array) {}",
+        "/* oxlint-disable typescript/no-var-requires */",
+        "/* oxlint-disable @typescript-eslint/no-var-requires, no-console */",
+        "/* oxlint-enable no-var-requires */",
+        "foo(); // oxlint-disable-line unicorn/no-instanceof-array, typescript/no-var-requires",
+    ];
+
+    let fix = vec![
+        (
+            "// oxlint-disable-next-line unicorn/no-instanceof-array
+        if (value instanceof Array) {}",
+            "// oxlint-disable-next-line unicorn/no-instanceof-builtins
+        if (value instanceof Array) {}",
+        ),
+        (
+            "/* oxlint-disable @typescript-eslint/no-var-requires, no-console */",
+            "/* oxlint-disable @typescript-eslint/no-require-imports, no-console */",
+        ),
+        ("/* oxlint-enable no-var-requires */", "/* oxlint-enable no-require-imports */"),
+        (
+            "foo(); // oxlint-disable-line unicorn/no-instanceof-array, typescript/no-var-requires",
+            "foo(); // oxlint-disable-line unicorn/no-instanceof-builtins, typescript/no-require-imports",
+        ),
+    ];
+
+    Tester::new(NoDeprecatedDirectiveRules::NAME, NoDeprecatedDirectiveRules::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_deprecated_rule_names_exist() {
+    use crate::rules::RULES;
+
+    for name in DEPRECATED_RULE_NAMES.iter().flat_map(|&names| <[&str; 2]>::from(names)) {
+        assert!(RULES.iter().any(|rule| rule.name() == name), "unknown rule `{name}`");
+    }
+}
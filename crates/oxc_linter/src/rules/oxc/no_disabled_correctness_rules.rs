@@ -0,0 +1,216 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    DirectivePrefix,
+    config::parse_rule_key,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    rules::{
+        RULES,
+        oxc::no_eslint_disable_comments::{CommentDirectiveKind, find_comment_directive},
+    },
+};
+
+fn style_reason_diagnostic(rule_name: &str, span: Span, description_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{rule_name}` is a correctness rule, but is disabled for a style reason."
+    ))
+    .with_help("Correctness rules report code that is most likely wrong. Fix the reported code, or explain why it is correct here.")
+    .with_labels([span.label("correctness rule disabled here"), description_span.label("reason")])
+}
+
+fn correctness_rule_disabled_diagnostic(rule_name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{rule_name}` is a correctness rule and should not be disabled."))
+        .with_help(
+            "Correctness rules report code that is most likely wrong. Fix the reported code instead.",
+        )
+        .with_label(span)
+}
+
+/// Words in a directive description which describe a suppression as a matter of style.
+const STYLE_WORDS: [&str; 11] = [
+    "aesthetic",
+    "cosmetic",
+    "formatting",
+    "personal",
+    "preference",
+    "preferred",
+    "readability",
+    "style",
+    "styling",
+    "stylistic",
+    "taste",
+];
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoDisabledCorrectnessRules(Box<NoDisabledCorrectnessRulesConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoDisabledCorrectnessRulesConfig {
+    /// Report every directive which disables a `correctness` rule, not only those whose
+    /// description calls the suppression a style choice.
+    report_all: bool,
+}
+
+impl std::ops::Deref for NoDisabledCorrectnessRules {
+    type Target = NoDisabledCorrectnessRulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `oxlint-disable`, `oxlint-disable-next-line` and `oxlint-disable-line` comments
+    /// (and their `eslint-*` equivalents) which disable a rule in the `correctness` category,
+    /// with a description calling the suppression a matter of style, preference or readability.
+    ///
+    /// With `reportAll`, every directive disabling a `correctness` rule is reported. As with any
+    /// other rule, the severity of these reports is set in the config.
+    ///
+    /// Rules are looked up by their name in the directive, so directives for unknown rules and
+    /// rules from JS plugins are ignored.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Correctness rules report code that is outright wrong or useless. Unlike style rules, they
+    /// are not a matter of taste, so a style reason for disabling one usually means the
+    /// reported problem was misunderstood.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line no-unused-vars -- personal preference
+    /// const unused = 1;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line no-unused-vars -- used by the test harness via eval
+    /// const fixture = 1;
+    ///
+    /// // oxlint-disable-next-line curly -- stylistic preference
+    /// if (foo) bar();
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "reportAll": true }`:
+    /// ```js
+    /// // oxlint-disable-next-line no-unused-vars -- used by the test harness via eval
+    /// const fixture = 1;
+    /// ```
+    NoDisabledCorrectnessRules,
+    oxc,
+    suspicious,
+    config = NoDisabledCorrectnessRulesConfig,
+    version = "next",
+    short_description = "Disallows disabling `correctness` rules for a style reason.",
+);
+
+impl Rule for NoDisabledCorrectnessRules {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        for comment in ctx.comments() {
+            let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, ctx.source_text(), prefix))
+            else {
+                continue;
+            };
+            if directive.kind == CommentDirectiveKind::Enable {
+                continue;
+            }
+
+            let style_reason = directive
+                .description
+                .filter(|description| is_style_reason(description.source_text(ctx.source_text())));
+            if !self.report_all && style_reason.is_none() {
+                continue;
+            }
+
+            for &(name, span) in &directive.rules {
+                if !is_correctness_rule(name) {
+                    continue;
+                }
+                let diagnostic = match style_reason {
+                    Some(description) => style_reason_diagnostic(name, span, description),
+                    None => correctness_rule_disabled_diagnostic(name, span),
+                };
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+/// Whether `description` contains one of [`STYLE_WORDS`], ignoring case.
+fn is_style_reason(description: &str) -> bool {
+    description
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .any(|word| STYLE_WORDS.iter().any(|style_word| word.eq_ignore_ascii_case(style_word)))
+}
+
+/// Whether `name`, as written in a directive, refers to a built-in `correctness` rule.
+fn is_correctness_rule(name: &str) -> bool {
+    let (plugin_name, rule_name) = parse_rule_key(name);
+    RULES.iter().any(|rule| {
+        rule.plugin_name() == plugin_name
+            && rule.name() == rule_name
+            && rule.category() == RuleCategory::Correctness
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("// oxlint-disable-next-line no-debugger -- needed to debug the worker\ndebugger;", None),
+        ("// oxlint-disable-next-line no-debugger\ndebugger;", None),
+        ("// oxlint-disable-next-line curly -- stylistic preference\nif (foo) bar();", None),
+        ("/* oxlint-disable arrow-body-style -- personal taste */", None),
+        ("// oxlint-disable-next-line typescript/no-explicit-any -- style\nlet a: any;", None),
+        ("/* oxlint-enable no-debugger -- style */", None),
+        ("// oxlint-disable-next-line unknown-rule -- style\nfoo();", None),
+        ("// oxlint-disable-next-line no-debugger -- styled-components\ndebugger;", None),
+        ("// no-debugger is disabled for style reasons", None),
+        (
+            "// oxlint-disable-next-line curly -- needed for the generated code\nif (foo) bar();",
+            Some(serde_json::json!([{ "reportAll": true }])),
+        ),
+        ("/* oxlint-enable no-debugger */", Some(serde_json::json!([{ "reportAll": true }]))),
+    ];
+
+    let fail = vec![
+        ("// oxlint-disable-next-line no-debugger -- personal preference\ndebugger;", None),
+        ("debugger; // eslint-disable-line no-debugger -- Style", None),
+        ("/* oxlint-disable no-unused-vars, curly -- readability */", None),
+        ("/* eslint-disable @typescript-eslint/no-duplicate-enum-values -- cosmetic */", None),
+        ("// oxlint-disable-next-line eslint/no-debugger -- formatting\ndebugger;", None),
+        (
+            "// oxlint-disable-next-line no-debugger -- needed to debug the worker\ndebugger;",
+            Some(serde_json::json!([{ "reportAll": true }])),
+        ),
+        (
+            "/* oxlint-disable no-unused-vars, curly, typescript/no-duplicate-enum-values */",
+            Some(serde_json::json!([{ "reportAll": true }])),
+        ),
+        (
+            "// oxlint-disable-next-line no-debugger -- style\ndebugger;",
+            Some(serde_json::json!([{ "reportAll": true }])),
+        ),
+    ];
+
+    Tester::new(NoDisabledCorrectnessRules::NAME, NoDisabledCorrectnessRules::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,213 @@
+use oxc_ast::{AstKind, Comment};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode, DirectivePrefix,
+    context::LintContext,
+    rule::Rule,
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirective, CommentDirectiveKind, comment_line_span, find_comment_directive,
+    },
+};
+
+fn no_disabled_explicit_any_diagnostic(directive: &CommentDirective, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`no-explicit-any` is disabled to allow this `any`.")
+        .with_help(format!(
+            "Use `unknown` and narrow it, or assert the expected type, then remove the `{}-disable-next-line` comment.",
+            directive.prefix.prefix().trim_end_matches('-'),
+        ))
+        .with_labels([
+            span.label("`any` used here"),
+            directive.span.label("`no-explicit-any` disabled here"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDisabledExplicitAny;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `any` types on a line after an `eslint-disable-next-line` (or
+    /// `oxlint-disable-next-line`) comment which only disables
+    /// `typescript/no-explicit-any`.
+    ///
+    /// To stay conservative, directives disabling other rules as well are ignored.
+    ///
+    /// The suggestion replaces every `any` on the line with `unknown` and removes the directive.
+    /// This may introduce type errors, which need to be fixed by narrowing the type.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Disabling `no-explicit-any` for a line is usually a shortcut. `unknown` together with a
+    /// type guard, or an assertion to the expected type, keeps the code type checked without a
+    /// directive.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    /// const data: any = JSON.parse(text);
+    ///
+    /// // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    /// const config = loadConfig() as any;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const data: unknown = JSON.parse(text);
+    ///
+    /// const config = loadConfig() as Config;
+    /// ```
+    NoDisabledExplicitAny,
+    oxc,
+    restriction,
+    dangerous_suggestion,
+    language = typescript,
+    version = "next",
+    short_description = "Disallow disabling `no-explicit-any` for a line instead of using a narrower type.",
+);
+
+impl Rule for NoDisabledExplicitAny {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TSAnyKeyword(any) = node.kind() else {
+            return;
+        };
+        let Some((comment, directive)) = disable_next_line_directive(ctx, any.span) else {
+            return;
+        };
+
+        ctx.diagnostic_with_dangerous_suggestion(
+            no_disabled_explicit_any_diagnostic(&directive, any.span),
+            |fixer| {
+                // Replace every `any` the directive allows, so that the directive can be removed.
+                let line_span = next_line_span(ctx.source_text(), &comment);
+                let any_spans = ctx
+                    .nodes()
+                    .iter()
+                    .filter_map(|node| match node.kind() {
+                        AstKind::TSAnyKeyword(any) if line_span.contains_inclusive(any.span) => {
+                            Some(any.span)
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                let fixer = fixer.for_multifix();
+                let mut fix = fixer.new_fix_with_capacity(1 + any_spans.len());
+                fix.push(fixer.delete_range(comment_line_span(&comment, ctx.source_text())));
+                for span in any_spans {
+                    fix.push(fixer.replace(span, "unknown"));
+                }
+                fix.with_message(
+                    "Replace `any` with `unknown` and remove the directive (may introduce type errors)",
+                )
+            },
+        );
+    }
+}
+
+/// Finds the `*-disable-next-line` directive on the line before `span` which disables only
+/// `no-explicit-any`, with its comment.
+fn disable_next_line_directive<'a>(
+    ctx: &LintContext<'a>,
+    span: Span,
+) -> Option<(Comment, CommentDirective<'a>)> {
+    let source_text = ctx.source_text();
+    let line_start = source_text[..span.start as usize].rfind('\n')? + 1;
+
+    let comments = ctx.comments();
+    let index = comments.partition_point(|comment| comment.span.end as usize <= line_start);
+    let comment = comments[..index].last()?;
+
+    // The comment must end on the previous line, followed only by whitespace.
+    let rest_of_line = &source_text[comment.span.end as usize..line_start - 1];
+    if rest_of_line.contains('\n') || !rest_of_line.trim().is_empty() {
+        return None;
+    }
+
+    let directive = [DirectivePrefix::Eslint, DirectivePrefix::Oxlint]
+        .into_iter()
+        .find_map(|prefix| find_comment_directive(comment, source_text, prefix))?;
+    (directive.kind == CommentDirectiveKind::DisableNextLine
+        && directive.rules.len() == 1
+        && directive.references_rule("no-explicit-any"))
+    .then_some((*comment, directive))
+}
+
+/// Span of the line after `comment`, which its `*-disable-next-line` directive applies to.
+#[expect(clippy::cast_possible_truncation)]
+fn next_line_span(source_text: &str, comment: &Comment) -> Span {
+    let after = &source_text[comment.span.end as usize..];
+    let start =
+        after.find('\n').map_or(source_text.len(), |offset| comment.span.end as usize + offset + 1);
+    let end = source_text[start..].find('\n').map_or(source_text.len(), |offset| start + offset);
+    Span::new(start as u32, end as u32)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const data: unknown = JSON.parse(text);",
+        "const data: any = JSON.parse(text);",
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any
+        const data: unknown = JSON.parse(text);",
+        // Directives disabling other rules as well are ignored.
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any, no-console
+        console.log(data as any);",
+        "// eslint-disable-next-line no-console
+        console.log(data as any);",
+        "// eslint-disable-line @typescript-eslint/no-explicit-any
+        const data: any = JSON.parse(text);",
+        "/* eslint-disable @typescript-eslint/no-explicit-any */
+        const data: any = JSON.parse(text);",
+        // The directive only applies to the next line.
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any
+
+        const data: any = JSON.parse(text);",
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any-other
+        const data: any = JSON.parse(text);",
+    ];
+
+    let fail = vec![
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any
+        const data: any = JSON.parse(text);",
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any
+        const config = loadConfig() as any;",
+        "// oxlint-disable-next-line typescript/no-explicit-any
+        function foo(a: any, b: Array<any>) {}",
+        "/* eslint-disable-next-line no-explicit-any */
+        let value = <any>foo;",
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any -- legacy API
+        export type Handler = (event: any) => void;",
+    ];
+
+    let fix = vec![
+        (
+            "// eslint-disable-next-line @typescript-eslint/no-explicit-any
+        const data: any = JSON.parse(text);",
+            "        const data: unknown = JSON.parse(text);",
+        ),
+        (
+            "foo();
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+        const config = loadConfig() as any;",
+            "foo();
+        const config = loadConfig() as unknown;",
+        ),
+        (
+            "// oxlint-disable-next-line typescript/no-explicit-any
+        function foo(a: any, b: Array<any>) {}",
+            "        function foo(a: unknown, b: Array<unknown>) {}",
+        ),
+    ];
+
+    Tester::new(NoDisabledExplicitAny::NAME, NoDisabledExplicitAny::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,311 @@
+use oxc_ast::{
+    AstKind,
+    ast::{TSTupleElement, TSType},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{ContentEq, GetSpan, Span};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn no_duplicate_type_constituents_diagnostic(
+    kind: &str,
+    first_text: &str,
+    first_span: Span,
+    duplicate_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{kind} type constituent is duplicated with `{first_text}`."))
+        .with_help("Remove the duplicated constituent.")
+        .with_labels([
+            first_span.label(format!("`{first_text}` is first declared here")),
+            duplicate_span.primary_label("and is duplicated here"),
+        ])
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoDuplicateTypeConstituents(Box<NoDuplicateTypeConstituentsConfig>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoDuplicateTypeConstituentsConfig {
+    /// Whether to ignore duplicate types in intersection types.
+    /// When true, allows `type T = A & A`.
+    pub ignore_intersections: bool,
+    /// Whether to ignore duplicate types in union types.
+    /// When true, allows `type T = A | A`.
+    pub ignore_unions: bool,
+}
+
+impl std::ops::Deref for NoDuplicateTypeConstituents {
+    type Target = NoDuplicateTypeConstituentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// This rule disallows duplicate constituents of union or intersection types, without
+    /// type information.
+    ///
+    /// It is a fallback for
+    /// [`typescript/no-duplicate-type-constituents`](https://oxc.rs/docs/guide/usage/linter/rules/typescript/no-duplicate-type-constituents.html)
+    /// in runs without `--type-aware`. Constituents are compared by how they are written,
+    /// ignoring parentheses and formatting. Types which are equal but written differently,
+    /// such as `Array<string>` and `string[]`, or a type alias and the type it names, are not
+    /// reported; the type-aware rule reports those.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Duplicate constituents in union and intersection types serve no purpose and can make code harder to read. They are likely a mistake.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// type T1 = 'A' | 'A';
+    ///
+    /// type T2 = A | A | B;
+    ///
+    /// type T3 = { a: string } & { a: string };
+    ///
+    /// type T4 = [A, B] | (([A, B]));
+    ///
+    /// type T5 =
+    ///   | 'foo'
+    ///   | 'bar'
+    ///   | 'foo';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// type T1 = 'A' | 'B';
+    ///
+    /// type T2 = A | B | C;
+    ///
+    /// type T3 = { a: string } & { b: string };
+    ///
+    /// type T4 = [A, B] | [B, A];
+    ///
+    /// type T5 =
+    ///   | 'foo'
+    ///   | 'bar'
+    ///   | 'baz';
+    /// ```
+    ///
+    /// The fix removes the duplicated constituent together with the `|` or `&` before it. It is
+    /// not offered when comments would be removed.
+    NoDuplicateTypeConstituents,
+    oxc,
+    pedantic,
+    fix,
+    config = NoDuplicateTypeConstituentsConfig,
+    language = typescript,
+    version = "next",
+    short_description = "Disallow duplicate constituents of union or intersection types, without type information.",
+);
+
+impl Rule for NoDuplicateTypeConstituents {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn to_configuration(&self) -> Option<Result<serde_json::Value, serde_json::Error>> {
+        Some(serde_json::to_value(&*self.0))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (kind, types) = match node.kind() {
+            AstKind::TSUnionType(union) if !self.ignore_unions => ("Union", &union.types),
+            AstKind::TSIntersectionType(intersection) if !self.ignore_intersections => {
+                ("Intersection", &intersection.types)
+            }
+            _ => return,
+        };
+
+        for (index, ty) in types.iter().enumerate().skip(1) {
+            let Some(first) = types[..index].iter().find(|previous| is_same_type(previous, ty))
+            else {
+                continue;
+            };
+            let diagnostic = no_duplicate_type_constituents_diagnostic(
+                kind,
+                ctx.source_range(first.span()),
+                first.span(),
+                ty.span(),
+            );
+
+            // The duplicate, and the separator and whitespace between it and the previous
+            // constituent.
+            let delete_span = Span::new(types[index - 1].span().end, ty.span().end);
+            if ctx.has_comments_between(delete_span) {
+                ctx.diagnostic(diagnostic);
+            } else {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(delete_span));
+            }
+        }
+    }
+}
+
+/// Whether `a` and `b` are written the same way, ignoring parentheses and formatting.
+fn is_same_type(a: &TSType, b: &TSType) -> bool {
+    match (a.without_parenthesized(), b.without_parenthesized()) {
+        (TSType::TSTypeReference(a), TSType::TSTypeReference(b)) => {
+            a.type_name.content_eq(&b.type_name)
+                && match (&a.type_arguments, &b.type_arguments) {
+                    (Some(a), Some(b)) => are_same_types(&a.params, &b.params),
+                    (None, None) => true,
+                    _ => false,
+                }
+        }
+        (TSType::TSArrayType(a), TSType::TSArrayType(b)) => {
+            is_same_type(&a.element_type, &b.element_type)
+        }
+        (TSType::TSTupleType(a), TSType::TSTupleType(b)) => {
+            a.element_types.len() == b.element_types.len()
+                && a.element_types
+                    .iter()
+                    .zip(&b.element_types)
+                    .all(|(a, b)| is_same_tuple_element(a, b))
+        }
+        (TSType::TSNamedTupleMember(a), TSType::TSNamedTupleMember(b)) => {
+            a.label.name == b.label.name
+                && a.optional == b.optional
+                && is_same_tuple_element(&a.element_type, &b.element_type)
+        }
+        (TSType::TSUnionType(a), TSType::TSUnionType(b)) => are_same_types(&a.types, &b.types),
+        (TSType::TSIntersectionType(a), TSType::TSIntersectionType(b)) => {
+            are_same_types(&a.types, &b.types)
+        }
+        (TSType::TSTypeOperatorType(a), TSType::TSTypeOperatorType(b)) => {
+            a.operator == b.operator && is_same_type(&a.type_annotation, &b.type_annotation)
+        }
+        (TSType::TSIndexedAccessType(a), TSType::TSIndexedAccessType(b)) => {
+            is_same_type(&a.object_type, &b.object_type)
+                && is_same_type(&a.index_type, &b.index_type)
+        }
+        (a, b) => a.content_eq(b),
+    }
+}
+
+fn are_same_types(a: &[TSType], b: &[TSType]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| is_same_type(a, b))
+}
+
+fn is_same_tuple_element(a: &TSTupleElement, b: &TSTupleElement) -> bool {
+    match (a, b) {
+        (TSTupleElement::TSOptionalType(a), TSTupleElement::TSOptionalType(b)) => {
+            is_same_type(&a.type_annotation, &b.type_annotation)
+        }
+        (TSTupleElement::TSRestType(a), TSTupleElement::TSRestType(b)) => {
+            is_same_type(&a.type_annotation, &b.type_annotation)
+        }
+        _ => match (a.as_ts_type(), b.as_ts_type()) {
+            (Some(a), Some(b)) => is_same_type(a, b),
+            _ => false,
+        },
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("type T = 'A' | 'B';", None),
+        ("type T = 1 | 2;", None),
+        ("type T = A | B | C;", None),
+        ("type T = A & B;", None),
+        ("type T = { a: string } & { b: string };", None),
+        ("type T = [A, B] | [B, A];", None),
+        ("type T = [A, B] | [A, B, C];", None),
+        ("type T = [a: A] | [b: A];", None),
+        ("type T = [A?] | [A];", None),
+        ("type T = A[] | A[][];", None),
+        ("type T = Foo<A> | Foo<B>;", None),
+        ("type T = Foo<A> | Foo;", None),
+        ("type T = Foo.A | Bar.A;", None),
+        ("type T = keyof A | readonly A[];", None),
+        ("type T = A['a'] | A['b'];", None),
+        ("type T = (A | B) | (B | A);", None),
+        ("type T = `a${A}` | `b${A}`;", None),
+        // Equal types written differently are out of scope.
+        ("type T = Array<string> | string[];", None),
+        ("type T = 1 | 1n;", None),
+        ("type T = A | (A & B);", None),
+        ("type T = A & A;", Some(json!([{ "ignoreIntersections": true }]))),
+        ("type T = A | A;", Some(json!([{ "ignoreUnions": true }]))),
+    ];
+
+    let fail = vec![
+        ("type T = 'A' | 'A';", None),
+        ("type T = 'A' | \"A\";", None),
+        ("type T = 1 | 1.0 | 0x1;", None),
+        ("type T = A | A | B;", None),
+        ("type T = A & A;", None),
+        ("type T = A | (A);", None),
+        ("type T = ((A)) | A;", None),
+        ("type T = { a: string } & { a: string };", None),
+        ("type T = [A, B] | (([A, B]));", None),
+        ("type T = [a: A, b?: B] | [a: A, b?: B];", None),
+        ("type T = [A, ...B[]] | [A, ...(B)[]];", None),
+        ("type T = Foo<A, B[]> | Foo<A, (B)[]>;", None),
+        ("type T = Foo.Bar | Foo.Bar;", None),
+        ("type T = keyof A | keyof (A);", None),
+        ("type T = A['a'] | A[('a')];", None),
+        ("type T = (A | B) | (A | B);", None),
+        ("type T = null | undefined | null;", None),
+        ("type T = A | B | A | A;", None),
+        (
+            "type T =
+              | 'foo'
+              | 'bar'
+              | 'foo';",
+            None,
+        ),
+        ("function f(a: string | string) {}", None),
+        ("type T = A | /* comment */ A;", None),
+        ("type T = A | A;", Some(json!([{ "ignoreIntersections": true }]))),
+        ("type T = A & A;", Some(json!([{ "ignoreUnions": true }]))),
+    ];
+
+    let fix = vec![
+        ("type T = 'A' | 'A';", "type T = 'A';"),
+        ("type T = A & A;", "type T = A;"),
+        ("type T = A | A | B;", "type T = A | B;"),
+        ("type T = A | B | A;", "type T = A | B;"),
+        ("type T = A | (A);", "type T = A;"),
+        // Fixes for adjacent duplicates touch, so only one is applied per fix pass.
+        ("type T = A | B | A | A;", "type T = A | B | A;"),
+        ("type T = A | A | A;", "type T = A | A;"),
+        ("type T = A | A | B | A;", "type T = A | B;"),
+        ("type T = [A, B] | (([A, B]));", "type T = [A, B];"),
+        ("function f(a: string | string) {}", "function f(a: string) {}"),
+        (
+            "type T =
+              | 'foo'
+              | 'bar'
+              | 'foo';",
+            "type T =
+              | 'foo'
+              | 'bar';",
+        ),
+        ("type T = A | A /* comment */ | B;", "type T = A /* comment */ | B;"),
+        ("type T = A | /* comment */ A;", "type T = A | /* comment */ A;"),
+        ("type T = A |\n  // comment\n  A;", "type T = A |\n  // comment\n  A;"),
+    ];
+
+    Tester::new(NoDuplicateTypeConstituents::NAME, NoDuplicateTypeConstituents::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,912 @@
+use std::{borrow::Cow, ops::Range};
+
+use cow_utils::CowUtils;
+
+use crate::DirectivePrefix;
+
+use super::CommentDirectiveKind;
+
+/// Regions of a comment, as byte ranges into the raw comment text, delimiters included.
+///
+/// ```text
+/// // eslint-disable-next-line no-console, no-debugger -- reason
+/// ^^                                                              delimiter
+///   ^                                                             leading_whitespace
+///    ^^^^^^^^^^^^^^^^^^^^^^^^                                     directive.name
+///                             ^^^^^^^^^^^^^^^^^^^^^^^             directive.rule_list
+///                                                        ^^^^^^   directive.description
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentAnatomy {
+    /// The opening `//` or `/*`.
+    pub delimiter: Range<usize>,
+    /// Text between the opening delimiter and the directive, or the whitespace at the start of
+    /// the comment if there is no directive. For block comments, this includes lines and `*` or
+    /// `!` decorations before the directive.
+    pub leading_whitespace: Range<usize>,
+    /// The `eslint-*` or `oxlint-*` directive, if any.
+    pub directive: Option<DirectiveAnatomy>,
+    /// The closing `*/` of block comments.
+    pub closing_delimiter: Option<Range<usize>>,
+}
+
+/// Regions of the directive in a comment. See [`CommentAnatomy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectiveAnatomy {
+    pub prefix: DirectivePrefix,
+    pub kind: CommentDirectiveKind,
+    /// Full directive name, e.g. `eslint-disable-next-line`.
+    pub name: Range<usize>,
+    /// Rule list following the name, trimmed. Empty if no rules are listed.
+    pub rule_list: Range<usize>,
+    /// Description following a `--` (or ` - `) separator, trimmed.
+    /// `None` if there is no separator.
+    pub description: Option<Range<usize>>,
+}
+
+impl CommentAnatomy {
+    /// Borrows the text of each region from `raw`, the comment text this anatomy was parsed
+    /// from.
+    pub fn text<'a>(&self, raw: &'a str) -> CommentText<'a> {
+        let content_end =
+            self.closing_delimiter.as_ref().map_or(raw.len(), |closing| closing.start);
+        let trailing_start =
+            self.directive.as_ref().map_or(self.leading_whitespace.end, |directive| {
+                directive
+                    .description
+                    .as_ref()
+                    .map_or(directive.rule_list.end, |description| description.end)
+            });
+        CommentText {
+            delimiter: &raw[self.delimiter.clone()],
+            leading_whitespace: &raw[self.leading_whitespace.clone()],
+            directive: self.directive.as_ref().map(|directive| DirectiveText {
+                prefix: directive.prefix,
+                kind: directive.kind,
+                rule_list_padding: &raw[directive.name.end..directive.rule_list.start],
+                rule_list: Cow::Borrowed(&raw[directive.rule_list.clone()]),
+                separator: directive
+                    .description
+                    .as_ref()
+                    .map_or("", |description| &raw[directive.rule_list.end..description.start]),
+                description: directive
+                    .description
+                    .clone()
+                    .map(|description| Cow::Borrowed(&raw[description])),
+            }),
+            trailing: &raw[trailing_start..content_end],
+            closing_delimiter: self.closing_delimiter.clone().map(|closing| &raw[closing]),
+        }
+    }
+}
+
+/// The text of each region of a comment, see [`CommentAnatomy::text`].
+///
+/// Fixes modify regions and render the comment with [`render_comment`], rather than splicing
+/// the raw comment text. The text between regions is kept as well, so an unmodified comment
+/// renders to its original text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentText<'a> {
+    /// The opening `//` or `/*`.
+    pub delimiter: &'a str,
+    pub leading_whitespace: &'a str,
+    pub directive: Option<DirectiveText<'a>>,
+    /// Text after the directive, or after the leading whitespace if there is no directive, up
+    /// to the closing delimiter.
+    pub trailing: &'a str,
+    /// The closing `*/` of block comments. Set to `None` to render a line comment.
+    pub closing_delimiter: Option<&'a str>,
+}
+
+/// The text of the directive in a comment. See [`CommentText`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectiveText<'a> {
+    pub prefix: DirectivePrefix,
+    pub kind: CommentDirectiveKind,
+    /// Whitespace between the directive name and the rule list. A single space is rendered
+    /// instead if this is empty and rules are added.
+    pub rule_list_padding: &'a str,
+    /// Rule list following the name. Empty if no rules are listed.
+    pub rule_list: Cow<'a, str>,
+    /// Text between the rule list and the description, including the `--` separator.
+    /// ` -- ` is rendered instead if this is empty and a description is added.
+    pub separator: &'a str,
+    /// Description following the separator. `None` renders no separator.
+    pub description: Option<Cow<'a, str>>,
+}
+
+/// Renders `comment` back into comment text.
+///
+/// Regions can't end the comment early: line terminators in line comments are rendered as
+/// spaces, and `*/` in block comments is rendered as `* /`.
+pub fn render_comment(comment: &CommentText) -> String {
+    let mut content = String::from(comment.leading_whitespace);
+    if let Some(directive) = &comment.directive {
+        content.push_str(directive.prefix.prefix());
+        content.push_str(directive.kind.as_str());
+        if !directive.rule_list.is_empty() {
+            content.push_str(match directive.rule_list_padding {
+                "" => " ",
+                padding => padding,
+            });
+            content.push_str(&directive.rule_list);
+        }
+        if let Some(description) = &directive.description {
+            content.push_str(match directive.separator {
+                "" => " -- ",
+                separator => separator,
+            });
+            content.push_str(description);
+        }
+    }
+    content.push_str(comment.trailing);
+
+    let content = match comment.closing_delimiter {
+        None => content.cow_replace(|c| matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}'), " "),
+        Some(_) => content.cow_replace("*/", "* /"),
+    };
+    [comment.delimiter, &content, comment.closing_delimiter.unwrap_or_default()].concat()
+}
+
+/// Punctuation which ends a directive or its rule list, e.g. in `// eslint-disable;` or
+/// `// eslint-disable no-console.`. It is kept as text after the directive, not as a rule name.
+const TRAILING_PUNCTUATION: [char; 2] = [';', '.'];
+
+/// Splits `raw`, the full text of a line comment (`is_line`) or block comment, into its
+/// regions.
+///
+/// Line comments must start with the directive. Block comments are scanned line by line, so
+/// that directives inside multi-line banners such as `/*!\n * eslint-disable\n */` are found
+/// too. A directive on a line decorated with `*` or `!` ends with its line, so the rest of a
+/// banner, e.g. `@license` text, is not mistaken for its rule list or description.
+pub fn parse_comment_anatomy(raw: &str, is_line: bool) -> CommentAnatomy {
+    let delimiter = 0..raw.len().min(2);
+    let content_end = if is_line {
+        raw.len()
+    } else {
+        raw.strip_suffix("*/").map_or(raw.len(), str::len).max(delimiter.end)
+    };
+    let closing_delimiter = (!is_line).then_some(content_end..raw.len());
+    let content = &raw[delimiter.end..content_end];
+
+    let mut line_start = delimiter.end;
+    for line in content.split_inclusive('\n') {
+        if let Some((offset, prefix, kind, is_decorated)) = match_directive_line(line) {
+            let name_start = line_start + offset;
+            let name = name_start..name_start + prefix.prefix().len() + kind.as_str().len();
+            let directive_end = if is_decorated {
+                line_start + line.trim_end_matches(['\n', '\r']).len()
+            } else {
+                content_end
+            };
+            let (rule_list, description) =
+                if raw[name.end..directive_end].starts_with(TRAILING_PUNCTUATION) {
+                    // `eslint-disable; ...` ends at the punctuation, the rest is plain text.
+                    (name.end..name.end, None)
+                } else {
+                    let (rule_list, description) = split_rule_list(raw, name.end..directive_end);
+                    (trim_trailing_punctuation(raw, rule_list), description)
+                };
+            return CommentAnatomy {
+                leading_whitespace: delimiter.end..name.start,
+                delimiter,
+                directive: Some(DirectiveAnatomy { prefix, kind, name, rule_list, description }),
+                closing_delimiter,
+            };
+        }
+        if is_line {
+            break;
+        }
+        line_start += line.len();
+    }
+
+    let leading_whitespace_len = content.len() - content.trim_start().len();
+    CommentAnatomy {
+        leading_whitespace: delimiter.end..delimiter.end + leading_whitespace_len,
+        delimiter,
+        directive: None,
+        closing_delimiter,
+    }
+}
+
+/// Whether `raw`, the full text of a line comment (`is_line`) or block comment, contains a
+/// directive, i.e. whether [`parse_comment_anatomy`] finds one.
+///
+/// This is cheaper than parsing the comment, so prose comments can be skipped early.
+pub fn is_directive_comment(raw: &str, is_line: bool) -> bool {
+    // Both `eslint-` and `oxlint-` end with `lint-`.
+    if !raw.contains("lint-") {
+        return false;
+    }
+    let content = raw.get(2..).unwrap_or_default();
+    let content = if is_line { content } else { content.strip_suffix("*/").unwrap_or(content) };
+    // Line comments must start with the directive.
+    let lines = if is_line { 1 } else { usize::MAX };
+    content.split_inclusive('\n').take(lines).any(|line| match_directive_line(line).is_some())
+}
+
+/// Matches a directive at the start of `line`, ignoring leading whitespace and a single leading
+/// `*` or `!` used by block comment decorations.
+///
+/// Returns the byte offset of the directive within `line`, its prefix, its kind, and whether
+/// the line is decorated.
+fn match_directive_line(
+    line: &str,
+) -> Option<(usize, DirectivePrefix, CommentDirectiveKind, bool)> {
+    fn is_directive_end(rest: &str) -> bool {
+        rest.chars().next().is_none_or(|c| !c.is_ascii_alphanumeric() && c != '-' && c != '_')
+    }
+
+    let trimmed = line.trim_start();
+    let decoration = trimmed.strip_prefix(['*', '!']);
+    let is_decorated = decoration.is_some();
+    let trimmed = decoration.map_or(trimmed, str::trim_start);
+    let (prefix, rest) = [DirectivePrefix::Eslint, DirectivePrefix::Oxlint]
+        .into_iter()
+        .find_map(|prefix| Some((prefix, trimmed.strip_prefix(prefix.prefix())?)))?;
+    let kind = CommentDirectiveKind::ALL
+        .into_iter()
+        .find(|kind| rest.strip_prefix(kind.as_str()).is_some_and(is_directive_end))?;
+
+    Some((line.len() - trimmed.len(), prefix, kind, is_decorated))
+}
+
+/// Splits the text following a directive name into the rule list and the description.
+fn split_rule_list(raw: &str, rest: Range<usize>) -> (Range<usize>, Option<Range<usize>>) {
+    let text = &raw[rest.clone()];
+    let separator = text.match_indices('-').find(|(index, _)| {
+        let after = &text[index + 1..];
+        after.starts_with('-')
+            || (text[..*index].ends_with(char::is_whitespace)
+                && after.starts_with(char::is_whitespace))
+    });
+
+    let Some((index, _)) = separator else {
+        return (trim_range(raw, rest), None);
+    };
+    let separator_len = if text[index + 1..].starts_with('-') { 2 } else { 1 };
+    let rule_list = trim_range(raw, rest.start..rest.start + index);
+    let description = trim_range(raw, rest.start + index + separator_len..rest.end);
+    (rule_list, Some(description))
+}
+
+/// Shrinks the rule list `range` to exclude [`TRAILING_PUNCTUATION`] and the whitespace before it.
+fn trim_trailing_punctuation(raw: &str, range: Range<usize>) -> Range<usize> {
+    let text = raw[range.clone()].trim_end_matches(TRAILING_PUNCTUATION).trim_end();
+    range.start..range.start + text.len()
+}
+
+/// Shrinks `range` to exclude leading and trailing whitespace in `raw`. Ranges containing only
+/// whitespace become empty at their start.
+fn trim_range(raw: &str, range: Range<usize>) -> Range<usize> {
+    let text = &raw[range.clone()];
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return range.start..range.start;
+    }
+    let start = range.start + text.len() - text.trim_start().len();
+    start..start + trimmed.len()
+}
+
+/// Date of a `@since` marker in a directive description, e.g. `-- @since 2022-01`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SinceDate {
+    pub year: u32,
+    pub month: u32,
+    /// `None` for markers without a day, e.g. `@since 2022-01`.
+    pub day: Option<u32>,
+}
+
+impl SinceDate {
+    /// Number of whole months from `self` to `to`, negative if `to` is earlier.
+    ///
+    /// If either date has no day, only their months are compared.
+    pub fn months_until(self, to: Self) -> i64 {
+        let months = i64::from(to.year * 12 + to.month) - i64::from(self.year * 12 + self.month);
+        match (self.day, to.day) {
+            (Some(day), Some(to_day)) if to_day < day => months - 1,
+            _ => months,
+        }
+    }
+}
+
+/// Finds a `@since` marker followed by an ISO 8601 date, `YYYY-MM` or `YYYY-MM-DD`, in the
+/// description of a directive.
+///
+/// Returns the date and the range of the marker within `description`, from `@since` to the end
+/// of the date. Markers with an invalid date, e.g. `@since 2022-13`, are ignored.
+pub fn find_since_marker(description: &str) -> Option<(SinceDate, Range<usize>)> {
+    description.match_indices("@since").find_map(|(start, marker)| {
+        let after = &description[start + marker.len()..];
+        let date_text = after.trim_start();
+        if date_text.len() == after.len() {
+            return None;
+        }
+        let (date, date_len) = parse_iso_date(date_text)?;
+        let end = description.len() - date_text.len() + date_len;
+        Some((date, start..end))
+    })
+}
+
+/// Parses an ISO 8601 date, `YYYY-MM` or `YYYY-MM-DD`, at the start of `text`, which must not be
+/// followed by a digit, a letter or `-`.
+///
+/// Returns the date and the length of its text.
+fn parse_iso_date(text: &str) -> Option<(SinceDate, usize)> {
+    fn number(text: &str, range: Range<usize>) -> Option<u32> {
+        let digits = text.get(range)?;
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    }
+
+    let year = number(text, 0..4)?;
+    if text.as_bytes().get(4) != Some(&b'-') {
+        return None;
+    }
+    let month = number(text, 5..7).filter(|month| (1..=12).contains(month))?;
+    let (day, len) = match (text.as_bytes().get(7), number(text, 8..10)) {
+        (Some(b'-'), Some(day)) if (1..=31).contains(&day) => (Some(day), 10),
+        _ => (None, 7),
+    };
+    if text[len..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '-') {
+        return None;
+    }
+    Some((SinceDate { year, month, day }, len))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(raw: &str) -> CommentAnatomy {
+        parse_comment_anatomy(raw, raw.starts_with("//"))
+    }
+
+    /// Returns the text of each region of `raw`, in source order.
+    fn regions(raw: &str) -> Vec<(&'static str, &str)> {
+        let anatomy = parse(raw);
+        let mut regions = vec![
+            ("delimiter", &raw[anatomy.delimiter]),
+            ("leading_whitespace", &raw[anatomy.leading_whitespace]),
+        ];
+        if let Some(directive) = anatomy.directive {
+            regions.push(("name", &raw[directive.name]));
+            regions.push(("rule_list", &raw[directive.rule_list]));
+            if let Some(description) = directive.description {
+                regions.push(("description", &raw[description]));
+            }
+        }
+        if let Some(closing_delimiter) = anatomy.closing_delimiter {
+            regions.push(("closing_delimiter", &raw[closing_delimiter]));
+        }
+        regions
+    }
+
+    #[test]
+    fn line_comment() {
+        assert_eq!(
+            regions("// eslint-disable-next-line no-console, no-debugger -- reason"),
+            [
+                ("delimiter", "//"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable-next-line"),
+                ("rule_list", "no-console, no-debugger"),
+                ("description", "reason"),
+            ]
+        );
+        assert_eq!(
+            regions("//oxlint-disable-line"),
+            [
+                ("delimiter", "//"),
+                ("leading_whitespace", ""),
+                ("name", "oxlint-disable-line"),
+                ("rule_list", ""),
+            ]
+        );
+        assert_eq!(
+            regions("//  eslint-enable   no-console  "),
+            [
+                ("delimiter", "//"),
+                ("leading_whitespace", "  "),
+                ("name", "eslint-enable"),
+                ("rule_list", "no-console"),
+            ]
+        );
+    }
+
+    #[test]
+    fn block_comment() {
+        assert_eq!(
+            regions("/* eslint-disable no-console */"),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable"),
+                ("rule_list", "no-console"),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+        assert_eq!(
+            regions("/*eslint-disable*/"),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", ""),
+                ("name", "eslint-disable"),
+                ("rule_list", ""),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+        assert_eq!(
+            regions("/* oxlint-disable-next-line no-console -- legacy code */"),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", " "),
+                ("name", "oxlint-disable-next-line"),
+                ("rule_list", "no-console"),
+                ("description", "legacy code"),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_line_block_comment() {
+        assert_eq!(
+            regions("/*!\n * Copyright\n * eslint-disable no-console\n */"),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", "!\n * Copyright\n * "),
+                ("name", "eslint-disable"),
+                ("rule_list", "no-console"),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+        // The rest of a license banner is not part of the directive.
+        assert_eq!(
+            regions(
+                "/*!\n * @license MIT\n * eslint-disable no-console -- legacy\n * @preserve\n */"
+            ),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", "!\n * @license MIT\n * "),
+                ("name", "eslint-disable"),
+                ("rule_list", "no-console"),
+                ("description", "legacy"),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+        assert_eq!(
+            regions("/*! eslint-disable\n * @license MIT\n */"),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", "! "),
+                ("name", "eslint-disable"),
+                ("rule_list", ""),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+        assert_eq!(
+            regions("/*\n  eslint-disable no-console,\n    no-debugger\n*/"),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", "\n  "),
+                ("name", "eslint-disable"),
+                ("rule_list", "no-console,\n    no-debugger"),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+    }
+
+    #[test]
+    fn mixed_line_endings() {
+        // The directive is on a `\r\n`-terminated line, after a `\n`-terminated one.
+        let raw =
+            "/*!\n * @license MIT\r\n * eslint-disable no-console -- legacy\r\n * @preserve\n */";
+        assert_eq!(
+            regions(raw),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", "!\n * @license MIT\r\n * "),
+                ("name", "eslint-disable"),
+                ("rule_list", "no-console"),
+                ("description", "legacy"),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+        let directive = parse(raw).directive.unwrap();
+        assert_eq!(directive.name, 24..38);
+        assert_eq!(directive.rule_list, 39..49);
+        assert_eq!(directive.description, Some(53..59));
+
+        assert_eq!(
+            regions("/*\r\n  eslint-disable no-console,\n    no-debugger\r\n*/"),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", "\r\n  "),
+                ("name", "eslint-disable"),
+                ("rule_list", "no-console,\n    no-debugger"),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+    }
+
+    #[test]
+    fn description() {
+        assert_eq!(
+            regions("// eslint-disable-line no-console - single dash"),
+            [
+                ("delimiter", "//"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable-line"),
+                ("rule_list", "no-console"),
+                ("description", "single dash"),
+            ]
+        );
+        assert_eq!(
+            regions("// eslint-disable-line -- no rules"),
+            [
+                ("delimiter", "//"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable-line"),
+                ("rule_list", ""),
+                ("description", "no rules"),
+            ]
+        );
+        assert_eq!(
+            regions("/* eslint-disable no-console -- */"),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable"),
+                ("rule_list", "no-console"),
+                ("description", ""),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+        // Dashes inside rule names are not separators.
+        assert_eq!(
+            regions("// eslint-disable-next-line @typescript-eslint/no-explicit-any"),
+            [
+                ("delimiter", "//"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable-next-line"),
+                ("rule_list", "@typescript-eslint/no-explicit-any"),
+            ]
+        );
+    }
+
+    #[test]
+    fn trailing_punctuation() {
+        assert_eq!(
+            regions("// eslint-disable;"),
+            [
+                ("delimiter", "//"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable"),
+                ("rule_list", ""),
+            ]
+        );
+        assert_eq!(
+            regions("/* eslint-disable. Generated file */"),
+            [
+                ("delimiter", "/*"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable"),
+                ("rule_list", ""),
+                ("closing_delimiter", "*/"),
+            ]
+        );
+        assert_eq!(
+            regions("// eslint-disable-line no-console, no-debugger ."),
+            [
+                ("delimiter", "//"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable-line"),
+                ("rule_list", "no-console, no-debugger"),
+            ]
+        );
+        assert_eq!(
+            regions("// eslint-disable-line no-console; -- reason"),
+            [
+                ("delimiter", "//"),
+                ("leading_whitespace", " "),
+                ("name", "eslint-disable-line"),
+                ("rule_list", "no-console"),
+                ("description", "reason"),
+            ]
+        );
+    }
+
+    /// Comments from the tests above, which must render to their original text.
+    const ROUND_TRIP: [&str; 27] = [
+        "// eslint-disable-next-line no-console, no-debugger -- reason",
+        "//oxlint-disable-line",
+        "//  eslint-enable   no-console  ",
+        "/* eslint-disable no-console */",
+        "/*eslint-disable*/",
+        "/* oxlint-disable-next-line no-console -- legacy code */",
+        "/*!\n * Copyright\n * eslint-disable no-console\n */",
+        "/*!\n * @license MIT\n * eslint-disable no-console -- legacy\n * @preserve\n */",
+        "/*! eslint-disable\n * @license MIT\n */",
+        "/*\n  eslint-disable no-console,\n    no-debugger\n*/",
+        "// eslint-disable-line no-console - single dash",
+        "// eslint-disable-line -- no rules",
+        "/* eslint-disable no-console -- */",
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any",
+        "// oxlint-disable-next-line",
+        "/* eslint-disable-line */",
+        "// just a comment",
+        "/* eslint no-console: off */",
+        "// eslint-disablefoo",
+        "// foo eslint-disable",
+        "/**/",
+        "// eslint-disable  ",
+        "/* eslint-disable no-console--reason*/",
+        "// eslint-disable;",
+        "/* eslint-disable. Generated file */",
+        "// eslint-disable-line no-console, no-debugger .",
+        "// eslint-disable-line no-console; -- reason",
+    ];
+
+    fn render(raw: &str, edit: impl FnOnce(&mut CommentText)) -> String {
+        let anatomy = parse(raw);
+        let mut text = anatomy.text(raw);
+        edit(&mut text);
+        render_comment(&text)
+    }
+
+    fn edit_directive(raw: &str, edit: impl FnOnce(&mut DirectiveText)) -> String {
+        render(raw, |text| edit(text.directive.as_mut().unwrap()))
+    }
+
+    #[test]
+    fn render_round_trip() {
+        for raw in ROUND_TRIP {
+            assert_eq!(render(raw, |_| {}), raw);
+        }
+    }
+
+    #[test]
+    fn render_prefix() {
+        for (raw, expected) in [
+            (
+                "// eslint-disable-next-line no-console -- reason",
+                "// oxlint-disable-next-line no-console -- reason",
+            ),
+            ("/*eslint-disable*/", "/*oxlint-disable*/"),
+            (
+                "/*!\n * Copyright\n * eslint-disable no-console\n */",
+                "/*!\n * Copyright\n * oxlint-disable no-console\n */",
+            ),
+            ("// eslint-disable;", "// oxlint-disable;"),
+            ("// eslint-disable-line no-console.", "// oxlint-disable-line no-console."),
+        ] {
+            assert_eq!(
+                edit_directive(raw, |directive| directive.prefix = DirectivePrefix::Oxlint),
+                expected
+            );
+        }
+        assert_eq!(
+            edit_directive("// eslint-disable-line no-console", |directive| {
+                directive.kind = CommentDirectiveKind::DisableNextLine;
+            }),
+            "// eslint-disable-next-line no-console"
+        );
+    }
+
+    #[test]
+    fn render_rule_list() {
+        let set_rules = |directive: &mut DirectiveText| directive.rule_list = "no-debugger".into();
+        assert_eq!(
+            edit_directive("//  eslint-enable   no-console  ", set_rules),
+            "//  eslint-enable   no-debugger  "
+        );
+        assert_eq!(
+            edit_directive("/*eslint-disable*/", set_rules),
+            "/*eslint-disable no-debugger*/"
+        );
+        assert_eq!(
+            edit_directive("// eslint-disable-line -- no rules", set_rules),
+            "// eslint-disable-line no-debugger -- no rules"
+        );
+
+        let remove_rules = |directive: &mut DirectiveText| directive.rule_list = "".into();
+        assert_eq!(
+            edit_directive("/* eslint-disable no-console */", remove_rules),
+            "/* eslint-disable */"
+        );
+        assert_eq!(
+            edit_directive("// eslint-disable-line no-console -- reason", remove_rules),
+            "// eslint-disable-line -- reason"
+        );
+    }
+
+    #[test]
+    fn render_description() {
+        let set_description =
+            |directive: &mut DirectiveText| directive.description = Some("new reason".into());
+        assert_eq!(
+            edit_directive("// eslint-disable-line no-console", set_description),
+            "// eslint-disable-line no-console -- new reason"
+        );
+        assert_eq!(
+            edit_directive("/* eslint-disable no-console */", set_description),
+            "/* eslint-disable no-console -- new reason */"
+        );
+        assert_eq!(
+            edit_directive("// eslint-disable-line no-console - old reason", set_description),
+            "// eslint-disable-line no-console - new reason"
+        );
+        assert_eq!(
+            edit_directive("// eslint-disable-line", set_description),
+            "// eslint-disable-line -- new reason"
+        );
+
+        assert_eq!(
+            edit_directive("/* eslint-disable no-console -- reason */", |directive| {
+                directive.description = None;
+            }),
+            "/* eslint-disable no-console */"
+        );
+    }
+
+    #[test]
+    fn render_line_comment() {
+        assert_eq!(
+            render("/* eslint-disable-next-line no-console -- reason */", |text| {
+                text.delimiter = "//";
+                text.trailing = "";
+                text.closing_delimiter = None;
+            }),
+            "// eslint-disable-next-line no-console -- reason"
+        );
+    }
+
+    #[test]
+    fn render_escapes_comment_end() {
+        assert_eq!(
+            edit_directive("// eslint-disable-line no-console", |directive| {
+                directive.description = Some("first line\nsecond line\r\nthird line".into());
+            }),
+            "// eslint-disable-line no-console -- first line second line  third line"
+        );
+        assert_eq!(
+            edit_directive("/* eslint-disable no-console */", |directive| {
+                directive.description = Some("see */ below".into());
+            }),
+            "/* eslint-disable no-console -- see * / below */"
+        );
+        assert_eq!(
+            edit_directive("/* eslint-disable no-console */", |directive| {
+                directive.rule_list = "no-console *".into();
+                directive.description = None;
+            }),
+            "/* eslint-disable no-console * */"
+        );
+        assert_eq!(
+            render("/*\n  eslint-disable no-console,\n    no-debugger\n*/", |text| {
+                text.delimiter = "//";
+                text.closing_delimiter = None;
+            }),
+            "//   eslint-disable no-console,     no-debugger "
+        );
+    }
+
+    #[test]
+    fn directive_prefix_and_kind() {
+        let directive = parse("// oxlint-disable-next-line").directive.unwrap();
+        assert_eq!(directive.prefix, DirectivePrefix::Oxlint);
+        assert_eq!(directive.kind, CommentDirectiveKind::DisableNextLine);
+
+        let directive = parse("/* eslint-disable-line */").directive.unwrap();
+        assert_eq!(directive.prefix, DirectivePrefix::Eslint);
+        assert_eq!(directive.kind, CommentDirectiveKind::DisableLine);
+
+        let directive = parse("/* eslint-disable */").directive.unwrap();
+        assert_eq!(directive.kind, CommentDirectiveKind::Disable);
+
+        let directive = parse("// eslint-enable").directive.unwrap();
+        assert_eq!(directive.kind, CommentDirectiveKind::Enable);
+    }
+
+    #[test]
+    fn no_directive() {
+        assert_eq!(
+            regions("// just a comment"),
+            [("delimiter", "//"), ("leading_whitespace", " ")]
+        );
+        assert_eq!(
+            regions("/* eslint no-console: off */"),
+            [("delimiter", "/*"), ("leading_whitespace", " "), ("closing_delimiter", "*/"),]
+        );
+        assert_eq!(regions("// eslint-disablefoo").len(), 2);
+        assert_eq!(regions("// eslint- disable").len(), 2);
+        // Line comments must start with the directive.
+        assert_eq!(regions("// see\n eslint-disable").len(), 2);
+        assert_eq!(regions("// foo eslint-disable").len(), 2);
+        assert_eq!(
+            regions("/**/"),
+            [("delimiter", "/*"), ("leading_whitespace", ""), ("closing_delimiter", "*/")]
+        );
+    }
+
+    #[test]
+    fn directive_comment() {
+        for raw in [
+            "// eslint-disable",
+            "// oxlint-disable-next-line no-console",
+            "//eslint-disable-line no-console -- reason",
+            "/* eslint-enable */",
+            "/*! oxlint-disable */",
+            "/**\n * Generated file.\n * eslint-disable\n */",
+            "/*\r\n  oxlint-disable no-console\r\n*/",
+            "// eslint-disable;",
+        ] {
+            assert!(is_directive_comment(raw, raw.starts_with("//")), "{raw}");
+            assert!(parse(raw).directive.is_some(), "{raw}");
+        }
+    }
+
+    #[test]
+    fn prose_comment() {
+        for raw in [
+            "// just a comment",
+            "// see the eslint docs",
+            "// we used eslint-disable here before",
+            "// eslint-config-prettier is used here",
+            "// eslint-disablefoo",
+            "// eslint- disable",
+            "// see\n eslint-disable",
+            "/* eslint no-console: off */",
+            "/* TODO: remove the eslint-disable below */",
+            "/**/",
+            "//",
+        ] {
+            assert!(!is_directive_comment(raw, raw.starts_with("//")), "{raw}");
+            assert!(parse(raw).directive.is_none(), "{raw}");
+        }
+    }
+
+    #[test]
+    fn since_marker() {
+        fn since(description: &str) -> Option<(SinceDate, &str)> {
+            find_since_marker(description).map(|(date, range)| (date, &description[range]))
+        }
+        assert_eq!(
+            since("@since 2022-01"),
+            Some((SinceDate { year: 2022, month: 1, day: None }, "@since 2022-01"))
+        );
+        assert_eq!(
+            since("legacy API, @since  2021-11-30."),
+            Some((SinceDate { year: 2021, month: 11, day: Some(30) }, "@since  2021-11-30"))
+        );
+        assert_eq!(
+            since("@since soon, @since 2020-02"),
+            Some((SinceDate { year: 2020, month: 2, day: None }, "@since 2020-02"))
+        );
+        for description in [
+            "reason",
+            "@since2022-01",
+            "@since 2022-13",
+            "@since 22-01",
+            "@since 2022-01x",
+            "@since 202201",
+            "@since 2021-11-3",
+        ] {
+            assert_eq!(since(description), None, "{description}");
+        }
+    }
+
+    #[test]
+    fn months_until() {
+        let date = |year, month, day| SinceDate { year, month, day };
+        let today = date(2024, 3, Some(15));
+        assert_eq!(date(2022, 1, None).months_until(today), 26);
+        assert_eq!(date(2024, 2, Some(15)).months_until(today), 1);
+        assert_eq!(date(2024, 2, Some(16)).months_until(today), 0);
+        assert_eq!(date(2024, 3, None).months_until(today), 0);
+        assert_eq!(date(2025, 1, None).months_until(today), -10);
+    }
+}
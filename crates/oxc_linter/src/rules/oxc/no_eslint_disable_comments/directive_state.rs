@@ -0,0 +1,84 @@
+use rustc_hash::FxHashMap;
+
+use oxc_span::Span;
+
+use crate::DirectivePrefix;
+
+use super::CommentDirective;
+
+/// Which rules are disabled at a point in the file, following `disable` and `enable` comments
+/// in source order.
+///
+/// Line directives (`disable-next-line` and `disable-line`) don't change the state of the
+/// following code, and are not passed to this state.
+#[derive(Debug, Default)]
+pub struct DirectiveState<'a> {
+    /// The `disable` comment without rules which is in effect, if any.
+    all_disabled: Option<ActiveDisable<'a>>,
+    /// Rules explicitly disabled or enabled since the last comment without rules. Disabled
+    /// rules map to their `disable` comment, enabled rules map to `None`.
+    rules: FxHashMap<&'a str, Option<ActiveDisable<'a>>>,
+}
+
+/// A `disable` comment, or a rule in one, which is in effect.
+#[derive(Debug, Clone, Copy)]
+pub struct ActiveDisable<'a> {
+    pub prefix: DirectivePrefix,
+    /// The disabled rule, or `None` for a comment without rules.
+    pub rule_name: Option<&'a str>,
+    /// Span of the rule name, or of the directive name for a comment without rules.
+    pub span: Span,
+    /// Outer span of the `disable` comment.
+    pub comment_span: Span,
+}
+
+impl<'a> DirectiveState<'a> {
+    pub fn is_disabled(&self, rule_name: &str) -> bool {
+        self.active_disable(rule_name).is_some()
+    }
+
+    /// The `disable` comment or rule in effect for `rule_name`, if it is disabled.
+    pub fn active_disable(&self, rule_name: &str) -> Option<ActiveDisable<'a>> {
+        self.rules.get(rule_name).copied().unwrap_or(self.all_disabled)
+    }
+
+    pub fn is_any_disabled(&self) -> bool {
+        self.all_disabled.is_some() || self.rules.values().any(Option::is_some)
+    }
+
+    /// The `disable` comments and disabled rules in effect, in source order.
+    pub fn active_disables(&self) -> Vec<ActiveDisable<'a>> {
+        let mut disables =
+            self.rules.values().flatten().chain(&self.all_disabled).copied().collect::<Vec<_>>();
+        disables.sort_unstable_by_key(|disable| disable.span);
+        disables
+    }
+
+    pub fn disable(&mut self, directive: &CommentDirective<'a>) {
+        let active_disable = |rule_name, span| ActiveDisable {
+            prefix: directive.prefix,
+            rule_name,
+            span,
+            comment_span: directive.comment_span,
+        };
+        if directive.rules.is_empty() {
+            self.all_disabled = Some(active_disable(None, directive.span));
+            self.rules.clear();
+        } else {
+            for &(rule_name, span) in &directive.rules {
+                self.rules.insert(rule_name, Some(active_disable(Some(rule_name), span)));
+            }
+        }
+    }
+
+    pub fn enable(&mut self, directive: &CommentDirective<'a>) {
+        if directive.rules.is_empty() {
+            self.all_disabled = None;
+            self.rules.clear();
+        } else {
+            for &(rule_name, _) in &directive.rules {
+                self.rules.insert(rule_name, None);
+            }
+        }
+    }
+}
@@ -0,0 +1,1761 @@
+pub mod comment_anatomy;
+pub mod directive_state;
+pub mod scoped_disable;
+
+use std::{
+    cmp::Reverse,
+    hash::{Hash, Hasher},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::{FxHashMap, FxHasher};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_ast::{AstKind, Comment};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::Semantic;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode, DirectivePrefix,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+use comment_anatomy::{CommentText, is_directive_comment, parse_comment_anatomy, render_comment};
+use scoped_disable::{ScopedDisable, scoped_disables};
+
+fn no_eslint_disable_comments_diagnostic(directive: &CommentDirective) -> OxcDiagnostic {
+    let name = directive.kind.as_str();
+    OxcDiagnostic::warn(format!("Unexpected `eslint-{name}` comment."))
+        .with_help(format!("Use `oxlint-{name}` instead."))
+        .with_label(directive.span)
+}
+
+fn file_level_disable_diagnostic(directive: &CommentDirective, file_path: &Path) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `eslint-disable` comment.")
+        .with_help(format!(
+            "Use `oxlint-disable` instead, or turn these rules off for this file in the oxlint config:\n{}",
+            overrides_snippet(file_path, &directive.rules)
+        ))
+        .with_label(directive.span)
+}
+
+fn fallthrough_in_switch_case_diagnostic(directive: &CommentDirective) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `eslint-disable-next-line` comment for `no-fallthrough`.")
+        .with_help(
+            "Use `oxlint-disable-next-line` instead, or end the previous case with a `// falls through` comment, which `no-fallthrough` accepts without a directive.",
+        )
+        .with_label(directive.span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoEslintDisableComments(Box<NoEslintDisableCommentsConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoEslintDisableCommentsConfig {
+    /// Rewrite block comment directives into line comments, e.g.
+    /// `/* eslint-disable-next-line no-console */` becomes
+    /// `// oxlint-disable-next-line no-console`.
+    ///
+    /// Only block comments that fit on a single line and are the last thing on their line are
+    /// rewritten. Block comments with a rule list wrapped over multiple lines are left as block
+    /// comments.
+    prefer_line_comments: bool,
+    /// Group directives by comment kind, so that directives in line comments are reported and
+    /// fixed before those in block comments, or the other way around. Within a group,
+    /// directives keep their source order.
+    ///
+    /// Directives in the second group are reported without a fix as long as the file has
+    /// directives in the first group, so that `--fix` rewrites one comment kind per run, e.g.
+    /// with `"lineCommentsFirst"`, the first run only fixes line comments and the next one the
+    /// block comments.
+    fix_group_order: FixGroupOrder,
+    /// Append a note naming the original directive to the description of fixed directives,
+    /// e.g. `// eslint-disable-line no-console -- legacy` becomes
+    /// `// oxlint-disable-line no-console -- legacy (was eslint-disable-line)`. A description
+    /// is added to directives without one.
+    ///
+    /// Directives whose description already contains the note don't get a second one.
+    note_original_directive: bool,
+    /// Tag fixed directives with a short id derived from a fingerprint of the fixed comment,
+    /// appended to the description as ` [#abcd]`, e.g.
+    /// `// eslint-disable-line no-console` becomes
+    /// `// oxlint-disable-line no-console -- [#11b6]`.
+    ///
+    /// Identical directives get the same id in every file and run, so tooling can track the
+    /// migrated directives later on.
+    migration_id: bool,
+    /// Fix file-level `eslint-disable` comments by removing them and wrapping each top-level
+    /// statement, such as a function declaration, in which the listed rules report diagnostics
+    /// in an `oxlint-disable`/`oxlint-enable` pair, instead of rewriting them to
+    /// `oxlint-disable`.
+    ///
+    /// This is only done for comments listing rules whose diagnostics can be located without
+    /// running them, currently `no-console` and `no-debugger`, and which are not ended by an
+    /// `enable` comment. Other comments are fixed as usual.
+    scope_file_level_disables: bool,
+    /// Whether to keep or strip the rule list of file-level `eslint-disable` comments when
+    /// rewriting them, e.g. with `"strip"`, `/* eslint-disable no-console */` becomes
+    /// `/* oxlint-disable */`, disabling all rules for the rest of the file.
+    ///
+    /// Only comments not ended by an `enable` comment for any of the listed rules are stripped.
+    file_level_rule_list: FileLevelRuleList,
+    /// Only report the first directive, for quick checks such as pre-commit hooks which only
+    /// need to know whether any `eslint-*` directive is left. With `fixGroupOrder`, this is the
+    /// first directive in that order.
+    fail_fast: bool,
+    /// Report directives suppressing the most rules first, so that the broadest suppressions
+    /// can be triaged before narrower ones. Directives without a rule list suppress all rules
+    /// and are reported before any other. Directives listing the same number of rules keep
+    /// their `fixGroupOrder` order.
+    ///
+    /// With `failFast`, the reported directive is the one suppressing the most rules.
+    sort_by_rule_count: bool,
+    /// Only report directives when fixes are applied, e.g. with `--fix`, so that they are
+    /// migrated on `--fix` runs without adding diagnostics to other runs.
+    fix_only: bool,
+    /// Custom diagnostic messages for directives referencing specific rules, keyed by rule
+    /// name, e.g. to point to the security review process when a security rule is suppressed:
+    ///
+    /// ```json
+    /// { "ruleMessages": { "no-eval": "Suppressing `no-eval` requires a security review." } }
+    /// ```
+    ///
+    /// Keys without a plugin prefix also match rules with one, e.g. `no-explicit-any` matches
+    /// `@typescript-eslint/no-explicit-any`. If a directive references several mapped rules,
+    /// the message of the first one in its rule list is used.
+    rule_messages: FxHashMap<String, String>,
+    /// Only report directives on these 1-based, inclusive line ranges. Set with
+    /// [`NoEslintDisableComments::with_changed_lines`].
+    #[serde(skip)]
+    #[schemars(skip)]
+    changed_lines: Option<Vec<RangeInclusive<u32>>>,
+    /// Skip files in these directories, which still have an ESLint config. Set with
+    /// [`NoEslintDisableComments::with_eslint_config_dirs`].
+    #[serde(skip)]
+    #[schemars(skip)]
+    eslint_config_dirs: Option<Vec<PathBuf>>,
+}
+
+/// Order in which directives in line and block comments are reported and fixed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum FixGroupOrder {
+    /// Source order.
+    #[default]
+    Source,
+    /// Line comments before block comments.
+    LineCommentsFirst,
+    /// Block comments before line comments.
+    BlockCommentsFirst,
+}
+
+impl FixGroupOrder {
+    /// Whether directives in `comment` are in the group fixed first. With `Source`, all
+    /// directives are.
+    fn is_first_group(self, comment: &Comment) -> bool {
+        match self {
+            Self::Source => true,
+            Self::LineCommentsFirst => comment.is_line(),
+            Self::BlockCommentsFirst => comment.is_block(),
+        }
+    }
+}
+
+/// What to do with the rule list of file-level `eslint-disable` comments.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum FileLevelRuleList {
+    /// Keep the rule list.
+    #[default]
+    Keep,
+    /// Remove the rule list, disabling all rules.
+    Strip,
+}
+
+impl NoEslintDisableComments {
+    /// Only report directives on the given 1-based, inclusive line ranges, e.g. the lines added
+    /// or modified in a `git diff`.
+    ///
+    /// This lets pre-commit hooks flag new `eslint-*` directives without reporting every
+    /// existing one in the files touched by a commit.
+    #[must_use]
+    pub fn with_changed_lines(
+        mut self,
+        changed_lines: impl IntoIterator<Item = RangeInclusive<u32>>,
+    ) -> Self {
+        self.0.changed_lines = Some(changed_lines.into_iter().collect());
+        self
+    }
+
+    /// Skip files governed by an ESLint config, i.e. files in one of the given directories
+    /// containing an `.eslintrc`, or in one of their subdirectories, since ESLint applies a config
+    /// to the whole directory tree below it.
+    ///
+    /// Directives in those files are still used by ESLint, so only files already free of ESLint
+    /// config are safe to migrate. Directories are compared to file paths component-wise,
+    /// without resolving them, so pass them in the same form as the linted paths.
+    #[must_use]
+    pub fn with_eslint_config_dirs(mut self, dirs: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.0.eslint_config_dirs = Some(dirs.into_iter().collect());
+        self
+    }
+}
+
+impl std::ops::Deref for NoEslintDisableComments {
+    type Target = NoEslintDisableCommentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows `eslint-*` directive comments (`eslint-disable`, `eslint-disable-next-line`,
+    /// `eslint-disable-line` and `eslint-enable`), and rewrites them to their `oxlint-*`
+    /// equivalents.
+    ///
+    /// For file-level `eslint-disable` comments listing rules, the diagnostic also includes an
+    /// `overrides` entry for the oxlint config which turns those rules off for the file, for
+    /// projects which prefer to keep suppressions out of the source.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Oxlint honors `eslint-*` directives so that existing code keeps working while migrating
+    /// from ESLint. Once the migration is done, leftover `eslint-*` directives are misleading:
+    /// they suggest ESLint is still in use, and they keep suppressing diagnostics if ESLint is
+    /// ever run again on the same code. Using `oxlint-*` directives makes it clear which tool a
+    /// suppression belongs to.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /* eslint-disable no-console */
+    /// console.log(message);
+    ///
+    /// // eslint-disable-next-line no-debugger
+    /// debugger;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// /* oxlint-disable no-console */
+    /// console.log(message);
+    ///
+    /// // oxlint-disable-next-line no-debugger
+    /// debugger;
+    /// ```
+    NoEslintDisableComments,
+    oxc,
+    restriction,
+    fix,
+    config = NoEslintDisableCommentsConfig,
+    version = "next",
+    short_description = "Disallows `eslint-*` directive comments in favor of `oxlint-*` directives.",
+);
+
+impl Rule for NoEslintDisableComments {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if self
+            .eslint_config_dirs
+            .as_ref()
+            .is_some_and(|dirs| is_governed_by_eslint_config(ctx.file_path(), dirs))
+        {
+            return;
+        }
+        if self.fix_only && !ctx.can_apply_fix(FixKind::SafeFix) {
+            return;
+        }
+        let changed_spans =
+            self.changed_lines.as_ref().map(|lines| changed_line_spans(ctx.source_text(), lines));
+        let enable_directives: Vec<_> = ctx
+            .comments()
+            .iter()
+            .filter_map(|comment| {
+                [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                    .into_iter()
+                    .find_map(|prefix| find_comment_directive(comment, ctx.source_text(), prefix))
+            })
+            .filter(|directive| directive.kind == CommentDirectiveKind::Enable)
+            .collect();
+
+        // Every directive gets exactly one diagnostic.
+        let max_directives = if self.fail_fast { 1 } else { usize::MAX };
+        let mut directives =
+            directives_in_fix_order(ctx.comments(), ctx.source_text(), self.fix_group_order);
+        if self.sort_by_rule_count {
+            sort_by_rule_count(&mut directives);
+        }
+        let directives = directives
+            .into_iter()
+            .filter(|(_, directive)| {
+                changed_spans.as_ref().is_none_or(|spans| is_in_spans(spans, directive.span))
+            })
+            .take(max_directives)
+            .collect::<Vec<_>>();
+        // Only the first group is fixed while it has directives, so that each fix run rewrites
+        // one comment kind only.
+        let has_first_group =
+            directives.iter().any(|(comment, _)| self.fix_group_order.is_first_group(comment));
+        for (comment, directive) in directives {
+            let defer_fix = has_first_group && !self.fix_group_order.is_first_group(comment);
+            let diagnostic = if directive.is_fallthrough_in_switch_case(ctx) {
+                self.with_rule_message(
+                    fallthrough_in_switch_case_diagnostic(&directive),
+                    &directive,
+                )
+            } else if directive.kind == CommentDirectiveKind::Disable
+                && !directive.rules.is_empty()
+                && directive.rules.iter().all(|(name, _)| is_plausible_rule_name(name))
+                && !directive.is_ended_by_enable(&enable_directives)
+            {
+                let diagnostic = self.with_rule_message(
+                    file_level_disable_diagnostic(&directive, ctx.file_path()),
+                    &directive,
+                );
+                if self.scope_file_level_disables
+                    && !defer_fix
+                    && let Some(scoped) = scoped_disables(&directive, &enable_directives, ctx)
+                {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        let fixer = fixer.for_multifix();
+                        let mut fix = fixer.new_fix_with_capacity(1 + scoped.len() * 2);
+                        fix.push(fixer.delete_range(comment_line_span(comment, ctx.source_text())));
+                        for scoped in &scoped {
+                            let (disable, enable) =
+                                scoped_disable_comments(scoped, &directive, ctx.source_text());
+                            fix.push(fixer.insert_text_before_range(disable.0, disable.1));
+                            fix.push(fixer.insert_text_after_range(enable.0, enable.1));
+                        }
+                        fix.with_message("Disable the rules only around the code they report")
+                    });
+                    continue;
+                }
+                diagnostic
+            } else {
+                self.with_rule_message(
+                    no_eslint_disable_comments_diagnostic(&directive),
+                    &directive,
+                )
+            };
+            if defer_fix {
+                ctx.diagnostic(diagnostic);
+                continue;
+            }
+
+            let strip_rule_list = self.file_level_rule_list == FileLevelRuleList::Strip
+                && directive.kind == CommentDirectiveKind::Disable
+                && !directive.is_ended_by_enable(&enable_directives);
+            if !strip_rule_list
+                && !self.note_original_directive
+                && !self.prefer_line_comments
+                && !self.migration_id
+            {
+                // Only the prefix changes, so replace just the `eslint` keyword instead of
+                // rendering the whole comment.
+                #[expect(clippy::cast_possible_truncation)]
+                let keyword = Span::sized(directive.span.start, "eslint".len() as u32);
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(keyword, "oxlint"));
+                continue;
+            }
+
+            let raw = comment.span.source_text(ctx.source_text());
+            let mut text = parse_comment_anatomy(raw, comment.is_line()).text(raw);
+            if let Some(directive_text) = &mut text.directive {
+                directive_text.prefix = DirectivePrefix::Oxlint;
+                if strip_rule_list {
+                    directive_text.rule_list = "".into();
+                }
+            }
+            if self.note_original_directive {
+                add_original_directive_note(&mut text, &directive, ctx.source_text());
+            }
+            if self.prefer_line_comments {
+                convert_to_line_comment(&mut text, comment, ctx.source_text());
+            }
+            if self.migration_id {
+                let id = migration_id(&render_comment(&text));
+                add_description_note(&mut text, &format!("[#{id}]"));
+            }
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                fixer.replace(comment.span, render_comment(&text))
+            });
+        }
+    }
+}
+
+impl NoEslintDisableCommentsConfig {
+    /// Replaces the message of `diagnostic` with the `ruleMessages` entry of the first rule
+    /// referenced by `directive` which has one.
+    fn with_rule_message(
+        &self,
+        mut diagnostic: OxcDiagnostic,
+        directive: &CommentDirective,
+    ) -> OxcDiagnostic {
+        let message = directive.rules.iter().find_map(|(name, _)| {
+            self.rule_messages
+                .get(*name)
+                .or_else(|| self.rule_messages.get(name.rsplit_once('/')?.1))
+        });
+        if let Some(message) = message {
+            diagnostic.message = message.clone().into();
+        }
+        diagnostic
+    }
+}
+
+fn is_governed_by_eslint_config(file_path: &Path, eslint_config_dirs: &[PathBuf]) -> bool {
+    eslint_config_dirs.iter().any(|dir| file_path.starts_with(dir))
+}
+
+/// Finds the `eslint-*` directives in `comments`, ordered by `fix_group_order`.
+fn directives_in_fix_order<'a, 'c>(
+    comments: &'c [Comment],
+    source_text: &'a str,
+    fix_group_order: FixGroupOrder,
+) -> Vec<(&'c Comment, CommentDirective<'a>)> {
+    let mut directives = comments
+        .iter()
+        .filter_map(|comment| Some((comment, find_eslint_comment_directive(comment, source_text)?)))
+        .collect::<Vec<_>>();
+    // `sort_by_key` is stable, so directives keep their source order within a group.
+    match fix_group_order {
+        FixGroupOrder::Source => {}
+        FixGroupOrder::LineCommentsFirst => {
+            directives.sort_by_key(|(comment, _)| comment.is_block());
+        }
+        FixGroupOrder::BlockCommentsFirst => {
+            directives.sort_by_key(|(comment, _)| comment.is_line());
+        }
+    }
+    directives
+}
+
+/// Orders `directives` by the number of rules they suppress, descending. Directives without a
+/// rule list suppress all rules and come first.
+fn sort_by_rule_count(directives: &mut [(&Comment, CommentDirective)]) {
+    // `sort_by_key` is stable, so directives suppressing as many rules keep their order.
+    directives.sort_by_key(|(_, directive)| {
+        Reverse(if directive.rules.is_empty() { usize::MAX } else { directive.rules.len() })
+    });
+}
+
+/// Appends a `(was eslint-*)` note naming `directive` to the description of `comment`, or adds
+/// a description with the note if it has none.
+///
+/// Does nothing if the description already contains the note.
+fn add_original_directive_note(
+    comment: &mut CommentText,
+    directive: &CommentDirective,
+    source_text: &str,
+) {
+    add_description_note(comment, &format!("(was {})", directive.span.source_text(source_text)));
+}
+
+/// Four hex digits identifying a fixed directive, derived from a fingerprint of the fixed
+/// `comment` text.
+///
+/// [`FxHasher`] has no random seed, so identical comments get the same id in every run.
+fn migration_id(comment: &str) -> String {
+    let mut hasher = FxHasher::default();
+    comment.hash(&mut hasher);
+    format!("{:04x}", hasher.finish() >> 48)
+}
+
+/// Appends `note` to the description of `comment`, or adds a description with the note if it
+/// has none.
+///
+/// Does nothing if the description already contains the note.
+fn add_description_note(comment: &mut CommentText, note: &str) {
+    let Some(directive_text) = &mut comment.directive else {
+        return;
+    };
+    match &mut directive_text.description {
+        Some(description) if description.contains(note) => return,
+        Some(description) => {
+            let description = description.to_mut();
+            description.push(' ');
+            description.push_str(note);
+        }
+        None => directive_text.description = Some(note.to_string().into()),
+    }
+    // Keep the note apart from the closing `*/`.
+    if comment.closing_delimiter.is_some() && comment.trailing.is_empty() {
+        comment.trailing = " ";
+    }
+}
+
+/// Span of `comment`, extended to its whole line if nothing else is on the line.
+#[expect(clippy::cast_possible_truncation)]
+pub fn comment_line_span(comment: &Comment, source_text: &str) -> Span {
+    let before = &source_text[..comment.span.start as usize];
+    let line_start = before.rfind('\n').map_or(0, |offset| offset + 1);
+    let after = &source_text[comment.span.end as usize..];
+    let line_end =
+        after.find('\n').map_or(source_text.len(), |offset| comment.span.end as usize + offset + 1);
+    if before[line_start..].trim().is_empty()
+        && source_text[comment.span.end as usize..line_end].trim().is_empty()
+    {
+        Span::new(line_start as u32, line_end as u32)
+    } else {
+        comment.span
+    }
+}
+
+/// Renders the `oxlint-disable` line inserted before the lines of `scoped`, and the
+/// `oxlint-enable` line inserted after them, with the spans to insert them at.
+#[expect(clippy::cast_possible_truncation)]
+fn scoped_disable_comments(
+    scoped: &ScopedDisable,
+    directive: &CommentDirective,
+    source_text: &str,
+) -> ((Span, String), (Span, String)) {
+    let before = &source_text[..scoped.span.start as usize];
+    let line_start = before.rfind('\n').map_or(0, |offset| offset + 1);
+    let line = &before[line_start..];
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let line_end = source_text[scoped.span.end as usize..]
+        .find('\n')
+        .map_or(source_text.len(), |offset| scoped.span.end as usize + offset);
+
+    let rules = scoped.rules.join(", ");
+    let description = directive.description.map_or(String::new(), |description| {
+        format!(" -- {}", description.source_text(source_text))
+    });
+    (
+        (
+            Span::empty(line_start as u32),
+            format!("{indent}// oxlint-disable {rules}{description}\n"),
+        ),
+        (Span::empty(line_end as u32), format!("\n{indent}// oxlint-enable {rules}")),
+    )
+}
+
+/// Turns a single-line block comment into a line comment.
+///
+/// Line comments, block comments spanning multiple lines, and block comments followed by code
+/// on the same line, which a line comment would swallow, are left unchanged.
+fn convert_to_line_comment(text: &mut CommentText, comment: &Comment, source_text: &str) {
+    if comment.is_line() || comment.content_span().source_text(source_text).contains('\n') {
+        return;
+    }
+
+    let rest_of_line = &source_text[comment.span.end as usize..];
+    let rest_of_line = rest_of_line.split('\n').next().unwrap_or_default();
+    if !rest_of_line.trim().is_empty() {
+        return;
+    }
+
+    text.delimiter = "//";
+    text.trailing = text.trailing.trim_end();
+    text.closing_delimiter = None;
+}
+
+/// Renders an oxlint config `overrides` entry turning `rules` off for `file_path`.
+///
+/// `files` patterns are relative to the config file, which is unknown here, so absolute paths
+/// are matched by file name in any directory.
+fn overrides_snippet(file_path: &Path, rules: &[(&str, Span)]) -> String {
+    let file_path = match file_path.file_name() {
+        Some(file_name) if file_path.is_absolute() => Path::new("**").join(file_name),
+        _ => file_path.to_path_buf(),
+    };
+    let file_path = file_path.to_string_lossy().cow_replace('\\', "/").into_owned();
+
+    let rules: serde_json::Map<String, serde_json::Value> =
+        rules.iter().map(|(name, _)| ((*name).to_string(), "off".into())).collect();
+    let snippet = serde_json::json!({
+        "overrides": [{ "files": [file_path], "rules": rules }]
+    });
+    serde_json::to_string_pretty(&snippet).unwrap_or_default()
+}
+
+/// Converts 1-based, inclusive line ranges into the spans of `source_text` they cover. Ranges
+/// starting after the last line are skipped, and other ranges are clamped to the source.
+#[expect(clippy::cast_possible_truncation)]
+fn changed_line_spans(source_text: &str, lines: &[RangeInclusive<u32>]) -> Vec<Span> {
+    let line_starts: Vec<u32> = std::iter::once(0)
+        .chain(source_text.match_indices('\n').map(|(offset, _)| offset as u32 + 1))
+        .collect();
+
+    lines
+        .iter()
+        .filter(|lines| !lines.is_empty())
+        .filter_map(|lines| {
+            let start = *line_starts.get(lines.start().saturating_sub(1) as usize)?;
+            let end =
+                line_starts.get(*lines.end() as usize).copied().unwrap_or(source_text.len() as u32);
+            Some(Span::new(start, end))
+        })
+        .collect()
+}
+
+/// Whether `span` starts within one of `spans`.
+fn is_in_spans(spans: &[Span], span: Span) -> bool {
+    spans.iter().any(|changed| (changed.start..changed.end).contains(&span.start))
+}
+
+/// Whether `name` looks like a rule name, e.g. `no-console` or `@typescript-eslint/no-explicit-any`.
+///
+/// Block comments such as `/* eslint-disable\n * Generated file. Do not edit.\n */` are parsed
+/// with the rest of the comment as the rule list. We don't want to suggest a config for those.
+fn is_plausible_rule_name(name: &str) -> bool {
+    name.bytes().all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'/' | b'@'))
+}
+
+/// Kind of an `eslint-*` or `oxlint-*` directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentDirectiveKind {
+    Disable,
+    DisableNextLine,
+    DisableLine,
+    Enable,
+}
+
+impl CommentDirectiveKind {
+    /// All directive kinds, ordered so that longer names are matched before their prefixes.
+    const ALL: [Self; 4] = [Self::DisableNextLine, Self::DisableLine, Self::Disable, Self::Enable];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Disable => "disable",
+            Self::DisableNextLine => "disable-next-line",
+            Self::DisableLine => "disable-line",
+            Self::Enable => "enable",
+        }
+    }
+}
+
+/// An `eslint-*` or `oxlint-*` directive found in a comment.
+///
+/// ```text
+/// // eslint-disable-next-line no-console, no-debugger -- reason
+///    ^^^^^^^^^^^^^^^^^^^^^^^^                            span
+///                             ^^^^^^^^^^  ^^^^^^^^^^^    rules
+///                                                        ^^^^^^  description
+/// ```
+#[derive(Debug, Clone)]
+pub struct CommentDirective<'a> {
+    pub prefix: DirectivePrefix,
+    pub kind: CommentDirectiveKind,
+    /// Span of the full directive name, e.g. `eslint-disable-next-line`.
+    pub span: Span,
+    /// Outer span of the comment containing the directive.
+    pub comment_span: Span,
+    /// Rule names referenced by the directive, with their spans, in source order.
+    pub rules: Vec<(&'a str, Span)>,
+    /// Span of the text after the `--` separator, trimmed. `None` if there is no separator.
+    pub description: Option<Span>,
+}
+
+impl CommentDirective<'_> {
+    /// Whether the directive references `rule_name`, with or without a plugin prefix.
+    pub fn references_rule(&self, rule_name: &str) -> bool {
+        self.rules
+            .iter()
+            .any(|(name, _)| name.rsplit_once('/').map_or(*name, |(_, rule)| rule) == rule_name)
+    }
+
+    /// Whether a later `enable` directive in `enable_directives` re-enables any of the rules
+    /// disabled by this directive.
+    pub fn is_ended_by_enable(&self, enable_directives: &[CommentDirective]) -> bool {
+        enable_directives.iter().any(|enable| {
+            enable.comment_span.start > self.comment_span.end
+                && (enable.rules.is_empty()
+                    || self.rules.iter().any(|(name, _)| enable.references_rule(name)))
+        })
+    }
+
+    /// `// eslint-disable-next-line no-fallthrough` placed right above a `case` clause.
+    fn is_fallthrough_in_switch_case(&self, ctx: &LintContext) -> bool {
+        self.kind == CommentDirectiveKind::DisableNextLine
+            && self.references_rule("no-fallthrough")
+            && node_after_span(ctx.semantic(), self.comment_span)
+                .is_some_and(|node| matches!(node.kind(), AstKind::SwitchCase(_)))
+    }
+}
+
+/// Finds the `eslint-*` directive in `comment`, if any.
+pub fn find_eslint_comment_directive<'a>(
+    comment: &Comment,
+    source_text: &'a str,
+) -> Option<CommentDirective<'a>> {
+    find_comment_directive(comment, source_text, DirectivePrefix::Eslint)
+}
+
+/// Finds the directive starting with `prefix` in `comment`, if any.
+///
+/// See [`parse_comment_anatomy`] for where directives are found.
+#[expect(clippy::cast_possible_truncation)]
+pub fn find_comment_directive<'a>(
+    comment: &Comment,
+    source_text: &'a str,
+    prefix: DirectivePrefix,
+) -> Option<CommentDirective<'a>> {
+    let raw = comment_source_text(comment, source_text)?;
+    if !is_directive_comment(raw, comment.is_line()) {
+        return None;
+    }
+    let directive = parse_comment_anatomy(raw, comment.is_line()).directive?;
+    if directive.prefix != prefix {
+        return None;
+    }
+
+    let start = comment.span.start;
+    let span = Span::new(start + directive.name.start as u32, start + directive.name.end as u32);
+    let rule_list_start = start + directive.rule_list.start as u32;
+    Some(CommentDirective {
+        prefix,
+        kind: directive.kind,
+        span,
+        comment_span: comment.span,
+        rules: collect_rule_names(&raw[directive.rule_list])
+            .into_iter()
+            .map(|(name, name_span)| (name, name_span.move_right(rule_list_start)))
+            .collect(),
+        description: directive.description.map(|description| {
+            Span::new(start + description.start as u32, start + description.end as u32)
+        }),
+    })
+}
+
+/// The full text of `comment`, including its delimiters.
+///
+/// Returns `None` if the span of the comment is degenerate, i.e. inverted, out of bounds, or
+/// too short to hold the delimiters of the comment, which a malformed AST may produce. Slicing
+/// the source text with such a span would panic.
+fn comment_source_text<'a>(comment: &Comment, source_text: &'a str) -> Option<&'a str> {
+    let raw = source_text.get(comment.span.start as usize..comment.span.end as usize)?;
+    let is_well_formed = if comment.is_line() {
+        raw.starts_with("//")
+    } else {
+        raw.len() >= 4 && raw.starts_with("/*") && raw.ends_with("*/")
+    };
+    is_well_formed.then_some(raw)
+}
+
+/// Collects rule names from a directive's rule list.
+///
+/// Spans are relative to the start of `rule_list`.
+#[expect(clippy::cast_possible_truncation)]
+fn collect_rule_names(rule_list: &str) -> Vec<(&str, Span)> {
+    let mut rules = vec![];
+    let mut offset = 0;
+    for token in rule_list.split(|c: char| c == ',' || c.is_whitespace()) {
+        if !token.is_empty() && token != "*" {
+            rules.push((token, Span::sized(offset as u32, token.len() as u32)));
+        }
+        offset += token.len() + 1;
+    }
+    rules
+}
+
+/// Returns the outermost AST node starting on the line after `span`.
+///
+/// This is the node an `eslint-disable-next-line` directive in a comment with `span` applies to.
+/// Class members start at their first decorator or modifier, so a directive before
+/// `private foo() {}` or `@Input() protected bar` resolves to the member itself.
+pub fn node_after_span<'a, 'c>(semantic: &'c Semantic<'a>, span: Span) -> Option<&'c AstNode<'a>> {
+    let source_text = semantic.source_text();
+    let next_line_start = span.end as usize + source_text[span.end as usize..].find('\n')? + 1;
+    let next_line = &source_text[next_line_start..];
+    let code_start =
+        next_line_start + next_line.len() - next_line.trim_start_matches([' ', '\t']).len();
+
+    semantic.nodes().iter().find(|node| {
+        node.span().start as usize == code_start && !matches!(node.kind(), AstKind::Program(_))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "foo();",
+        "// oxlint-disable-next-line no-console
+        console.log(foo);",
+        "/* oxlint-disable no-console */",
+        "/* oxlint-enable no-console */",
+        "foo(); // oxlint-disable-line no-console",
+        "// just a comment mentioning eslint-disable",
+        "// eslint-disablefoo",
+        "// eslint-disable-lext-nine",
+        "// eslint-config-prettier is used here",
+        "/* eslint no-console: off */",
+        "const s = '// eslint-disable';",
+        // Code passed to `eval` or `Function` as a string is not scanned for comments
+        r#"eval("// eslint-disable");"#,
+        r#"eval("/* eslint-disable no-console */ console.log(foo);");"#,
+        r"eval('foo(); // eslint-disable-line no-alert');",
+        r#"new Function("/* eslint-disable */ return 1;");"#,
+        r#"Function("a", "// eslint-disable-next-line no-console\nconsole.log(a);");"#,
+        // Directive text in template literals, e.g. GraphQL queries, is not a comment
+        "const query = gql`
+          # eslint-disable
+          query { user { id } }
+        `;",
+        "const query = gql`
+          /* eslint-disable-next-line no-console */
+          query { user { id } }
+        `;",
+        "const query = graphql`
+          query { user { id } } // eslint-disable-line
+        `;",
+        "const s = `/* eslint-disable */ ${x}`;",
+        "const s = `${x} // eslint-disable-line`;",
+        "const s = `${`/* eslint-disable */`}`;",
+        // ESLint doesn't honor whitespace between the prefix and the directive kind
+        "// eslint- disable",
+        "/* eslint- disable-next-line no-console */",
+        "// eslint -disable-line",
+    ];
+
+    let fail = vec![
+        "/* eslint-disable */",
+        "// eslint-disable-next-line no-var
+        declare var foo: number;",
+        "// eslint-disable-next-line @typescript-eslint/no-unused-vars
+        export declare function foo(): void;",
+        "/* eslint-disable no-console */",
+        "/* eslint-enable no-console */",
+        // Enabled again, so the overrides snippet doesn't apply.
+        "/* eslint-disable no-console */
+        console.log(foo);
+        /* eslint-enable no-console */",
+        "// eslint-disable-next-line no-console
+        console.log(foo);",
+        "foo(); // eslint-disable-line no-console",
+        "foo(); /* eslint-disable-line */",
+        "/*
+          eslint-disable no-console,
+            no-debugger
+        */",
+        "/*!
+         * Copyright
+         * eslint-disable no-console
+         */",
+        "/*!
+         * @license MIT
+         * eslint-disable no-console
+         * Copyright (c) 2020 Example, Inc.
+         * @preserve
+         */",
+        "// eslint-disable-next-line @typescript-eslint/no-explicit-any -- legacy
+        let a: any;",
+        "switch (a) {
+            case 1:
+                foo();
+            // eslint-disable-next-line no-fallthrough
+            case 2:
+                bar();
+        }",
+        "switch (a) {
+            case 1:
+                foo();
+                // eslint-disable-next-line no-fallthrough
+                bar();
+        }",
+        "switch (a) {
+            case 1:
+                foo();
+            // eslint-disable-next-line no-console
+            case 2:
+                bar();
+        }",
+        // Mixed `\n` and `\r\n` line endings.
+        "/*!\n * @license MIT\r\n * eslint-disable no-console, no-debugger\r\n * @preserve\n */",
+        // Only the leading directive is reported, not the one in the description.
+        "// eslint-disable-next-line no-console -- because eslint-disable is bad
+        console.log(foo);",
+        "/* eslint-disable no-console --
+         * eslint-disable-line is bad too
+         */",
+        // Trailing punctuation ends the directive, and is not a rule name.
+        "// eslint-disable;",
+        "/* eslint-disable. Generated file */",
+        "// eslint-disable-next-line no-console.
+        console.log(foo);",
+        // Comments in template literal expressions are real comments.
+        "const s = `${/* eslint-disable-line no-console */ console.log(x)}`;",
+        "const s = `a ${ // eslint-disable-line no-console
+            console.log(x) } b`;",
+        "const s = `/* eslint-disable */ ${`${/* eslint-disable */ x}`}`;",
+    ];
+
+    let fix = vec![
+        ("/* eslint-disable */", "/* oxlint-disable */", None),
+        (
+            "const s = `${/* eslint-disable-line no-console */ console.log(x)}`;",
+            "const s = `${/* oxlint-disable-line no-console */ console.log(x)}`;",
+            None,
+        ),
+        (
+            "// eslint-disable-next-line no-var
+        declare var foo: number;",
+            "// oxlint-disable-next-line no-var
+        declare var foo: number;",
+            None,
+        ),
+        (
+            "// eslint-disable-next-line @typescript-eslint/no-unused-vars
+        export declare function foo(): void;",
+            "// oxlint-disable-next-line @typescript-eslint/no-unused-vars
+        export declare function foo(): void;",
+            None,
+        ),
+        ("// eslint-disable;", "// oxlint-disable;", None),
+        ("/* eslint-disable. Generated file */", "/* oxlint-disable. Generated file */", None),
+        (
+            "foo(); // eslint-disable-line no-console, no-debugger;",
+            "foo(); // oxlint-disable-line no-console, no-debugger;",
+            None,
+        ),
+        ("/* eslint-enable no-console */", "/* oxlint-enable no-console */", None),
+        (
+            "// eslint-disable-next-line no-console
+        console.log(foo);",
+            "// oxlint-disable-next-line no-console
+        console.log(foo);",
+            None,
+        ),
+        (
+            "foo(); // eslint-disable-line no-console",
+            "foo(); // oxlint-disable-line no-console",
+            None,
+        ),
+        (
+            "switch (a) {
+            case 1:
+                foo();
+            // eslint-disable-next-line no-fallthrough
+            case 2:
+                bar();
+        }",
+            "switch (a) {
+            case 1:
+                foo();
+            // oxlint-disable-next-line no-fallthrough
+            case 2:
+                bar();
+        }",
+            None,
+        ),
+        (
+            "/*!
+         * Copyright
+         * eslint-disable no-console
+         */",
+            "/*!
+         * Copyright
+         * oxlint-disable no-console
+         */",
+            None,
+        ),
+        // Only the directive in a license banner is changed.
+        (
+            "/*!
+         * @license MIT
+         * eslint-disable no-console
+         * Copyright (c) 2020 Example, Inc.
+         * @preserve
+         */",
+            "/*!
+         * @license MIT
+         * oxlint-disable no-console
+         * Copyright (c) 2020 Example, Inc.
+         * @preserve
+         */",
+            None,
+        ),
+        (
+            "/*!\n * @license MIT\r\n * eslint-disable no-console, no-debugger\r\n * @preserve\n */",
+            "/*!\n * @license MIT\r\n * oxlint-disable no-console, no-debugger\r\n * @preserve\n */",
+            None,
+        ),
+        // The description is kept verbatim.
+        (
+            "// eslint-disable-next-line no-console -- because eslint-disable is bad
+        console.log(foo);",
+            "// oxlint-disable-next-line no-console -- because eslint-disable is bad
+        console.log(foo);",
+            None,
+        ),
+        (
+            "/* eslint-disable no-console --
+         * eslint-disable-line is bad too
+         */",
+            "/* oxlint-disable no-console --
+         * eslint-disable-line is bad too
+         */",
+            None,
+        ),
+        (
+            "/* eslint-disable-next-line no-console */
+        console.log(foo);",
+            "// oxlint-disable-next-line no-console
+        console.log(foo);",
+            Some(serde_json::json!([{ "preferLineComments": true }])),
+        ),
+        (
+            "/* eslint-disable-next-line no-console -- because eslint-disable is bad */
+        console.log(foo);",
+            "// oxlint-disable-next-line no-console -- because eslint-disable is bad
+        console.log(foo);",
+            Some(serde_json::json!([{ "preferLineComments": true }])),
+        ),
+        (
+            "foo(); /* eslint-disable-line no-console */",
+            "foo(); // oxlint-disable-line no-console",
+            Some(serde_json::json!([{ "preferLineComments": true }])),
+        ),
+        (
+            "/*eslint-disable*/",
+            "//oxlint-disable",
+            Some(serde_json::json!([{ "preferLineComments": true }])),
+        ),
+        (
+            "// eslint-disable-next-line no-console
+        console.log(foo);",
+            "// oxlint-disable-next-line no-console
+        console.log(foo);",
+            Some(serde_json::json!([{ "preferLineComments": true }])),
+        ),
+        // Code after the comment would be swallowed by a line comment.
+        (
+            "foo(/* eslint-disable-line no-console */);",
+            "foo(/* oxlint-disable-line no-console */);",
+            Some(serde_json::json!([{ "preferLineComments": true }])),
+        ),
+        (
+            "/* eslint-disable-line no-console */ console.log(foo);",
+            "/* oxlint-disable-line no-console */ console.log(foo);",
+            Some(serde_json::json!([{ "preferLineComments": true }])),
+        ),
+        // Wrapped rule lists stay in a block comment.
+        (
+            "/* eslint-disable no-console,
+            no-debugger */",
+            "/* oxlint-disable no-console,
+            no-debugger */",
+            Some(serde_json::json!([{ "preferLineComments": true }])),
+        ),
+        (
+            "/* eslint-disable no-console */",
+            "/* oxlint-disable no-console */",
+            Some(serde_json::json!([{ "preferLineComments": false }])),
+        ),
+        (
+            "/* eslint-disable no-console */
+        foo(); // eslint-disable-line no-alert",
+            "/* oxlint-disable no-console */
+        foo(); // oxlint-disable-line no-alert",
+            None,
+        ),
+        // With `fixGroupOrder`, each fix run rewrites one comment kind: the first run fixes the
+        // first group, the next run the other one.
+        (
+            "/* eslint-disable no-console */
+        foo(); // eslint-disable-line no-alert",
+            "/* eslint-disable no-console */
+        foo(); // oxlint-disable-line no-alert",
+            Some(serde_json::json!([{ "fixGroupOrder": "lineCommentsFirst" }])),
+        ),
+        (
+            "/* eslint-disable no-console */
+        foo(); // oxlint-disable-line no-alert",
+            "/* oxlint-disable no-console */
+        foo(); // oxlint-disable-line no-alert",
+            Some(serde_json::json!([{ "fixGroupOrder": "lineCommentsFirst" }])),
+        ),
+        (
+            "/* eslint-disable no-console */
+        foo(); // eslint-disable-line no-alert",
+            "/* oxlint-disable no-console */
+        foo(); // eslint-disable-line no-alert",
+            Some(serde_json::json!([{ "fixGroupOrder": "blockCommentsFirst" }])),
+        ),
+        (
+            "/* oxlint-disable no-console */
+        foo(); // eslint-disable-line no-alert",
+            "/* oxlint-disable no-console */
+        foo(); // oxlint-disable-line no-alert",
+            Some(serde_json::json!([{ "fixGroupOrder": "blockCommentsFirst" }])),
+        ),
+        (
+            "foo(); // eslint-disable-line no-console -- legacy",
+            "foo(); // oxlint-disable-line no-console -- legacy (was eslint-disable-line)",
+            Some(serde_json::json!([{ "noteOriginalDirective": true }])),
+        ),
+        (
+            "// eslint-disable-next-line no-console
+        console.log(foo);",
+            "// oxlint-disable-next-line no-console -- (was eslint-disable-next-line)
+        console.log(foo);",
+            Some(serde_json::json!([{ "noteOriginalDirective": true }])),
+        ),
+        (
+            "/* eslint-disable */",
+            "/* oxlint-disable -- (was eslint-disable) */",
+            Some(serde_json::json!([{ "noteOriginalDirective": true }])),
+        ),
+        (
+            "/* eslint-disable no-console - generated */",
+            "/* oxlint-disable no-console - generated (was eslint-disable) */",
+            Some(serde_json::json!([{ "noteOriginalDirective": true }])),
+        ),
+        (
+            "/* eslint-disable no-console -- */",
+            "/* oxlint-disable no-console -- (was eslint-disable) */",
+            Some(serde_json::json!([{ "noteOriginalDirective": true }])),
+        ),
+        (
+            "/*!
+         * Copyright
+         * eslint-disable no-console
+         */",
+            "/*!
+         * Copyright
+         * oxlint-disable no-console -- (was eslint-disable)
+         */",
+            Some(serde_json::json!([{ "noteOriginalDirective": true }])),
+        ),
+        (
+            "/*!
+         * @license MIT
+         * eslint-disable no-console
+         * Copyright (c) 2020 Example, Inc.
+         */",
+            "/*!
+         * @license MIT
+         * oxlint-disable no-console -- (was eslint-disable)
+         * Copyright (c) 2020 Example, Inc.
+         */",
+            Some(serde_json::json!([{ "noteOriginalDirective": true }])),
+        ),
+        // The note is only appended once.
+        (
+            "// eslint-disable-line no-console -- legacy (was eslint-disable-line)",
+            "// oxlint-disable-line no-console -- legacy (was eslint-disable-line)",
+            Some(serde_json::json!([{ "noteOriginalDirective": true }])),
+        ),
+        (
+            "/* eslint-disable-next-line no-console -- legacy */
+        console.log(foo);",
+            "// oxlint-disable-next-line no-console -- legacy (was eslint-disable-next-line)
+        console.log(foo);",
+            Some(
+                serde_json::json!([{ "preferLineComments": true, "noteOriginalDirective": true }]),
+            ),
+        ),
+        (
+            "/* eslint-disable-line */",
+            "// oxlint-disable-line -- (was eslint-disable-line)",
+            Some(
+                serde_json::json!([{ "preferLineComments": true, "noteOriginalDirective": true }]),
+            ),
+        ),
+        (
+            "foo(); // eslint-disable-line no-console",
+            "foo(); // oxlint-disable-line no-console -- [#11b6]",
+            Some(serde_json::json!([{ "migrationId": true }])),
+        ),
+        // Identical directives get the same id.
+        (
+            "foo(); // eslint-disable-line no-console
+        bar(); // eslint-disable-line no-console",
+            "foo(); // oxlint-disable-line no-console -- [#11b6]
+        bar(); // oxlint-disable-line no-console -- [#11b6]",
+            Some(serde_json::json!([{ "migrationId": true }])),
+        ),
+        (
+            "foo(); // eslint-disable-line no-alert",
+            "foo(); // oxlint-disable-line no-alert -- [#eb13]",
+            Some(serde_json::json!([{ "migrationId": true }])),
+        ),
+        (
+            "/* eslint-disable no-console -- legacy */",
+            "/* oxlint-disable no-console -- legacy [#e319] */",
+            Some(serde_json::json!([{ "migrationId": true }])),
+        ),
+        (
+            "/* eslint-disable-next-line no-console */
+        console.log(foo);",
+            "// oxlint-disable-next-line no-console -- (was eslint-disable-next-line) [#d99c]
+        console.log(foo);",
+            Some(
+                serde_json::json!([{ "preferLineComments": true, "noteOriginalDirective": true, "migrationId": true }]),
+            ),
+        ),
+        (
+            "/* eslint-disable no-console */
+function foo() {
+  console.log(1);
+}
+function bar() {
+  return 1;
+}
+export const baz = () => console.warn(2);",
+            "// oxlint-disable no-console
+function foo() {
+  console.log(1);
+}
+// oxlint-enable no-console
+function bar() {
+  return 1;
+}
+// oxlint-disable no-console
+export const baz = () => console.warn(2);
+// oxlint-enable no-console",
+            Some(serde_json::json!([{ "scopeFileLevelDisables": true }])),
+        ),
+        (
+            "// eslint-disable no-console, no-debugger -- legacy
+function foo() { debugger; }
+function bar() { console.log(); }
+
+function baz() { console.log(); debugger; }",
+            "// oxlint-disable no-debugger -- legacy
+function foo() { debugger; }
+// oxlint-enable no-debugger
+// oxlint-disable no-console -- legacy
+function bar() { console.log(); }
+// oxlint-enable no-console
+
+// oxlint-disable no-console, no-debugger -- legacy
+function baz() { console.log(); debugger; }
+// oxlint-enable no-console, no-debugger",
+            Some(serde_json::json!([{ "scopeFileLevelDisables": true }])),
+        ),
+        (
+            "/* eslint-disable no-console */
+function foo() {}",
+            "function foo() {}",
+            Some(serde_json::json!([{ "scopeFileLevelDisables": true }])),
+        ),
+        (
+            "/* eslint-disable no-console */
+const console = logger;
+console.log(1);",
+            "const console = logger;
+console.log(1);",
+            Some(serde_json::json!([{ "scopeFileLevelDisables": true }])),
+        ),
+        (
+            "/* eslint-disable eslint/no-console */
+foo(); console.log(1); bar();
+console.log(2);",
+            "// oxlint-disable eslint/no-console
+foo(); console.log(1); bar();
+// oxlint-enable eslint/no-console
+// oxlint-disable eslint/no-console
+console.log(2);
+// oxlint-enable eslint/no-console",
+            Some(serde_json::json!([{ "scopeFileLevelDisables": true }])),
+        ),
+        // Not scoped
+        (
+            "/* eslint-disable no-console, no-alert */
+function foo() { console.log(); }",
+            "/* oxlint-disable no-console, no-alert */
+function foo() { console.log(); }",
+            Some(serde_json::json!([{ "scopeFileLevelDisables": true }])),
+        ),
+        (
+            "/* eslint-disable no-console */
+console.log();
+/* eslint-enable no-console */",
+            "/* oxlint-disable no-console */
+console.log();
+/* oxlint-enable no-console */",
+            Some(serde_json::json!([{ "scopeFileLevelDisables": true }])),
+        ),
+        (
+            "function foo() {
+  /* eslint-disable no-console */
+  console.log();
+}",
+            "function foo() {
+  /* oxlint-disable no-console */
+  console.log();
+}",
+            Some(serde_json::json!([{ "scopeFileLevelDisables": true }])),
+        ),
+        (
+            "/* eslint-disable no-console, no-debugger */
+console.log();",
+            "/* oxlint-disable no-console, no-debugger */
+console.log();",
+            Some(serde_json::json!([{ "fileLevelRuleList": "keep" }])),
+        ),
+        (
+            "/* eslint-disable no-console, no-debugger */
+console.log();",
+            "/* oxlint-disable */
+console.log();",
+            Some(serde_json::json!([{ "fileLevelRuleList": "strip" }])),
+        ),
+        (
+            "// eslint-disable no-console -- legacy
+console.log();",
+            "// oxlint-disable -- legacy
+console.log();",
+            Some(serde_json::json!([{ "fileLevelRuleList": "strip" }])),
+        ),
+        (
+            "/* eslint-disable no-console */
+console.log();
+/* eslint-enable no-console */",
+            "/* oxlint-disable no-console */
+console.log();
+/* oxlint-enable no-console */",
+            Some(serde_json::json!([{ "fileLevelRuleList": "strip" }])),
+        ),
+        (
+            "// eslint-disable-next-line no-console
+console.log();",
+            "// oxlint-disable-next-line no-console
+console.log();",
+            Some(serde_json::json!([{ "fileLevelRuleList": "strip" }])),
+        ),
+    ];
+
+    Tester::new(NoEslintDisableComments::NAME, NoEslintDisableComments::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_overrides_snippet() {
+    let rules = [
+        ("no-console", Span::new(0, 10)),
+        ("@typescript-eslint/no-explicit-any", Span::new(11, 45)),
+    ];
+    assert_eq!(
+        overrides_snippet(Path::new("src/legacy.ts"), &rules),
+        r#"{
+  "overrides": [
+    {
+      "files": [
+        "src/legacy.ts"
+      ],
+      "rules": {
+        "no-console": "off",
+        "@typescript-eslint/no-explicit-any": "off"
+      }
+    }
+  ]
+}"#
+    );
+    assert!(
+        overrides_snippet(&std::env::temp_dir().join("legacy.ts"), &rules[..1])
+            .contains("\"files\": [\n        \"**/legacy.ts\"\n      ]")
+    );
+}
+
+#[test]
+fn test_large_block_comment() {
+    use crate::tester::Tester;
+
+    let body = " * Generated file. Do not edit by hand, run `generate` instead.\n".repeat(10_000);
+    let source = format!("/* eslint-disable\n{body} */");
+    let fixed = format!("/* oxlint-disable\n{body} */");
+    // The directive is only found after scanning every line of the banner.
+    let banner = format!("/*!\n{body} * eslint-disable no-console\n */");
+    let fixed_banner = format!("/*!\n{body} * oxlint-disable no-console\n */");
+
+    let fail = vec![source.as_str(), banner.as_str()];
+    let fix = vec![
+        (source.as_str(), fixed.as_str(), None),
+        (banner.as_str(), fixed_banner.as_str(), None),
+    ];
+
+    Tester::new(NoEslintDisableComments::NAME, NoEslintDisableComments::PLUGIN, vec![], fail)
+        .expect_fix(fix)
+        .with_snapshot_suffix("large_block_comment")
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_degenerate_comment_spans() {
+    use oxc_ast::CommentKind;
+
+    let source =
+        "/* eslint-disable no-console */\nconsole.log(foo); // eslint-disable-line no-alert";
+    let comments = [
+        // Zero-length spans.
+        Comment::new(0, 0, CommentKind::MultiLineBlock),
+        Comment::new(49, 49, CommentKind::Line),
+        // Inverted spans.
+        Comment::new(31, 0, CommentKind::MultiLineBlock),
+        Comment::new(81, 50, CommentKind::Line),
+        // Spans too short for the delimiters of the comment.
+        Comment::new(0, 3, CommentKind::SingleLineBlock),
+        Comment::new(0, 1, CommentKind::Line),
+        // Out of bounds spans.
+        Comment::new(50, 1000, CommentKind::Line),
+        Comment::new(1000, 1010, CommentKind::MultiLineBlock),
+        // Spans not aligned with the comment.
+        Comment::new(3, 31, CommentKind::MultiLineBlock),
+        Comment::new(32, 80, CommentKind::Line),
+    ];
+
+    for comment in &comments {
+        assert!(find_eslint_comment_directive(comment, source).is_none(), "{comment:?}");
+    }
+    assert!(directives_in_fix_order(&comments, source, FixGroupOrder::Source).is_empty());
+
+    // Well-formed comments are still found.
+    let comments = [
+        Comment::new(0, 31, CommentKind::SingleLineBlock),
+        Comment::new(50, 81, CommentKind::Line),
+    ];
+    assert_eq!(directives_in_fix_order(&comments, source, FixGroupOrder::Source).len(), 2);
+}
+
+#[test]
+fn test_fail_fast() {
+    use crate::tester::Tester;
+
+    let source = "// eslint-disable-next-line no-console
+console.log(foo);
+/* eslint-disable no-debugger */
+debugger;
+alert(foo); // eslint-disable-line no-alert";
+    let fixed = "// oxlint-disable-next-line no-console
+console.log(foo);
+/* eslint-disable no-debugger */
+debugger;
+alert(foo); // eslint-disable-line no-alert";
+    let config = Some(serde_json::json!([{ "failFast": true }]));
+
+    let pass = vec![("// oxlint-disable-next-line no-console\nconsole.log(foo);", config.clone())];
+    let fail = vec![(source, config.clone())];
+    let fix = vec![(source, fixed, config)];
+
+    Tester::new(NoEslintDisableComments::NAME, NoEslintDisableComments::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .with_snapshot_suffix("fail_fast")
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_fix_only() {
+    use crate::tester::Tester;
+
+    let config = Some(serde_json::json!([{ "fixOnly": true }]));
+    let sources = [
+        "// eslint-disable-next-line no-console\nconsole.log(foo);",
+        "/* eslint-disable no-debugger */\ndebugger;",
+        "alert(foo); // eslint-disable-line no-alert",
+    ];
+
+    // Directives are not reported without `--fix`...
+    let pass = sources.iter().map(|source| (*source, config.clone())).collect::<Vec<_>>();
+    // ...and are still fixed with it.
+    let fix = vec![
+        (sources[0], "// oxlint-disable-next-line no-console\nconsole.log(foo);", config.clone()),
+        (sources[1], "/* oxlint-disable no-debugger */\ndebugger;", config.clone()),
+        (sources[2], "alert(foo); // oxlint-disable-line no-alert", config),
+    ];
+
+    Tester::new(NoEslintDisableComments::NAME, NoEslintDisableComments::PLUGIN, pass, vec![])
+        .expect_fix(fix)
+        .test();
+}
+
+#[test]
+fn test_rule_messages() {
+    use crate::tester::Tester;
+
+    let config = Some(serde_json::json!([{
+        "ruleMessages": {
+            "no-eval": "Suppressing `no-eval` requires a security review.",
+            "no-explicit-any": "Type this value instead of suppressing `no-explicit-any`.",
+        }
+    }]));
+
+    let fail = vec![
+        // Mapped rules.
+        ("// eslint-disable-next-line no-eval\neval(code);", config.clone()),
+        ("/* eslint-disable no-eval */\neval(code);", config.clone()),
+        ("let x: any; // eslint-disable-line @typescript-eslint/no-explicit-any", config.clone()),
+        // The first mapped rule in the rule list wins.
+        (
+            "// eslint-disable-next-line no-console, no-explicit-any, no-eval\nfoo();",
+            config.clone(),
+        ),
+        // Unmapped rules.
+        ("// eslint-disable-next-line no-console\nconsole.log(foo);", config.clone()),
+        ("// eslint-disable-next-line no-evaluate\nfoo();", config.clone()),
+        ("/* eslint-disable */", config),
+    ];
+
+    Tester::new(NoEslintDisableComments::NAME, NoEslintDisableComments::PLUGIN, vec![], fail)
+        .intentionally_allow_no_fix_tests()
+        .with_snapshot_suffix("rule_messages")
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_changed_lines() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source = "// eslint-disable-next-line no-console
+console.log(foo);
+/* eslint-disable no-debugger */
+debugger;
+/*
+ * Copyright
+ * eslint-disable no-alert
+ */
+alert(foo); // eslint-disable-line no-alert";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+
+    let directive_lines = |changed_lines: &[RangeInclusive<u32>]| {
+        let spans = changed_line_spans(source, changed_lines);
+        ret.program
+            .comments
+            .iter()
+            .filter_map(|comment| find_eslint_comment_directive(comment, source))
+            .filter(|directive| is_in_spans(&spans, directive.span))
+            .map(|directive| source[..directive.span.start as usize].lines().count())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(directive_lines(&[1..=9]), vec![1, 3, 7, 9]);
+    assert_eq!(directive_lines(&[]), Vec::<usize>::new());
+    assert_eq!(directive_lines(&[2..=2, 4..=6]), Vec::<usize>::new());
+    assert_eq!(directive_lines(&[3..=3]), vec![3]);
+    // Directives in a multi-line block comment are matched by their own line.
+    assert_eq!(directive_lines(&[7..=7]), vec![7]);
+    assert_eq!(directive_lines(&[5..=6, 8..=8]), Vec::<usize>::new());
+    assert_eq!(directive_lines(&[9..=100]), vec![9]);
+    assert_eq!(directive_lines(&[0..=1, 100..=200]), vec![1]);
+    #[expect(clippy::reversed_empty_ranges)]
+    let empty = 3..=1;
+    assert_eq!(directive_lines(&[empty]), Vec::<usize>::new());
+}
+
+#[test]
+fn test_with_changed_lines() {
+    use crate::{RuleEnum, tester::Tester};
+
+    // Lines 3 to 4 changed, e.g. in a `git diff`.
+    let pass = vec![
+        "// eslint-disable-next-line no-console
+console.log(foo);
+foo();
+bar();",
+        "/* eslint-disable no-debugger */
+debugger;
+foo();
+bar();
+alert(foo); // eslint-disable-line no-alert",
+    ];
+    let fail = vec![
+        "foo();
+bar();
+// eslint-disable-next-line no-console
+console.log(foo);",
+        "/* eslint-disable no-debugger */
+debugger;
+/*
+ * eslint-disable no-alert
+ */
+alert(foo);",
+    ];
+    // Only the directive on a changed line is fixed.
+    let fix = vec![(
+        "// eslint-disable-next-line no-alert
+alert(foo);
+// eslint-disable-next-line no-console
+console.log(foo);
+alert(foo); // eslint-disable-line no-alert",
+        "// eslint-disable-next-line no-alert
+alert(foo);
+// oxlint-disable-next-line no-console
+console.log(foo);
+alert(foo); // eslint-disable-line no-alert",
+    )];
+
+    Tester::new(NoEslintDisableComments::NAME, NoEslintDisableComments::PLUGIN, pass, fail)
+        .with_rule_setup(|rule| match rule {
+            RuleEnum::OxcNoEslintDisableComments(rule) => {
+                RuleEnum::OxcNoEslintDisableComments(rule.with_changed_lines([3..=4]))
+            }
+            rule => rule,
+        })
+        .expect_fix(fix)
+        .with_snapshot_suffix("changed_lines")
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_eslint_config_dirs() {
+    let dirs = [PathBuf::from("/repo/legacy"), PathBuf::from("/repo/packages/old")];
+    let is_governed = |file_path: &str| is_governed_by_eslint_config(Path::new(file_path), &dirs);
+
+    assert!(is_governed("/repo/legacy/index.js"));
+    assert!(is_governed("/repo/legacy/src/nested/foo.ts"));
+    assert!(is_governed("/repo/packages/old/lib/bar.js"));
+    assert!(!is_governed("/repo/index.js"));
+    assert!(!is_governed("/repo/src/legacy/index.js"));
+    assert!(!is_governed("/repo/packages/new/index.js"));
+    // Directories are matched by whole path components.
+    assert!(!is_governed("/repo/legacy-v2/index.js"));
+    assert!(!is_governed_by_eslint_config(Path::new("/repo/legacy/index.js"), &[]));
+}
+
+#[test]
+fn test_fix_group_order() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source = "/* eslint-disable no-console */
+// eslint-disable-next-line no-debugger
+debugger;
+/* eslint-enable no-console */
+foo(); // eslint-disable-line no-alert";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+
+    let fixed_lines = |fix_group_order| {
+        directives_in_fix_order(&ret.program.comments, source, fix_group_order)
+            .iter()
+            .map(|(_, directive)| source[..directive.span.start as usize].lines().count())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(fixed_lines(FixGroupOrder::Source), vec![1, 2, 4, 5]);
+    assert_eq!(fixed_lines(FixGroupOrder::LineCommentsFirst), vec![2, 5, 1, 4]);
+    assert_eq!(fixed_lines(FixGroupOrder::BlockCommentsFirst), vec![1, 4, 2, 5]);
+}
+
+#[test]
+fn test_sort_by_rule_count() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source = "// eslint-disable-next-line no-console
+console.log(foo);
+/* eslint-disable no-alert, no-debugger, no-console */
+// eslint-disable-next-line no-debugger, no-alert
+debugger;
+/* eslint-disable */
+foo(); /* eslint-disable-line no-alert */";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+
+    let sorted_lines = |fix_group_order| {
+        let mut directives =
+            directives_in_fix_order(&ret.program.comments, source, fix_group_order);
+        sort_by_rule_count(&mut directives);
+        directives
+            .iter()
+            .map(|(_, directive)| source[..directive.span.start as usize].lines().count())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(sorted_lines(FixGroupOrder::Source), vec![6, 3, 4, 1, 7]);
+    assert_eq!(sorted_lines(FixGroupOrder::LineCommentsFirst), vec![6, 3, 4, 1, 7]);
+    assert_eq!(sorted_lines(FixGroupOrder::BlockCommentsFirst), vec![6, 3, 4, 7, 1]);
+}
+
+#[test]
+fn test_node_after_span() {
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstType;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    let cases = [
+        ("private foo() {}", AstType::MethodDefinition),
+        ("protected bar = 1;", AstType::PropertyDefinition),
+        ("public static readonly baz: string;", AstType::PropertyDefinition),
+        ("private async *gen() {}", AstType::MethodDefinition),
+        ("protected abstract qux(): void;", AstType::MethodDefinition),
+        ("declare private value: number;", AstType::PropertyDefinition),
+        ("protected override render() {}", AstType::MethodDefinition),
+        ("private accessor count = 0;", AstType::AccessorProperty),
+        ("private get size() { return 0; }", AstType::MethodDefinition),
+        ("@Input() protected name: string;", AstType::PropertyDefinition),
+        ("@Output()\n    private changed = emitter();", AstType::PropertyDefinition),
+        ("[key: string]: any;", AstType::TSIndexSignature),
+        (
+            "constructor(\n    // eslint-disable-next-line\n    private readonly service: Service,\n  ) {}",
+            AstType::FormalParameter,
+        ),
+    ];
+
+    for (member, expected_kind) in cases {
+        let source =
+            format!("abstract class Foo {{\n  // eslint-disable-next-line\n  {member}\n}}");
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source, SourceType::ts()).parse();
+        assert!(ret.diagnostics.is_empty(), "{source}: {:?}", ret.diagnostics);
+        let semantic = SemanticBuilder::new_linter().build(&ret.program).semantic;
+
+        let comment = semantic.comments().last().unwrap();
+        let node = node_after_span(&semantic, comment.span).map(|node| node.kind().ty());
+        assert_eq!(node, Some(expected_kind), "{source}");
+    }
+}
+
+#[test]
+fn test_node_after_span_declare() {
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstType;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    let cases = [
+        ("declare const x: number;", AstType::VariableDeclaration),
+        ("declare let y: string, z: string;", AstType::VariableDeclaration),
+        ("declare var w;", AstType::VariableDeclaration),
+        ("declare function foo(): void;", AstType::Function),
+        ("declare class Foo {}", AstType::Class),
+        ("declare abstract class Bar {}", AstType::Class),
+        ("declare enum E { A }", AstType::TSEnumDeclaration),
+        ("declare const enum F { A }", AstType::TSEnumDeclaration),
+        ("declare namespace N {}", AstType::TSModuleDeclaration),
+        ("declare module 'foo' {}", AstType::TSModuleDeclaration),
+        ("declare global {}", AstType::TSGlobalDeclaration),
+        ("declare interface I {}", AstType::TSInterfaceDeclaration),
+        ("declare type T = string;", AstType::TSTypeAliasDeclaration),
+        ("export declare const x: number;", AstType::ExportNamedDeclaration),
+        ("export declare function foo(): void;", AstType::ExportNamedDeclaration),
+    ];
+
+    for (declaration, expected_kind) in cases {
+        let source = format!("// eslint-disable-next-line\n{declaration}");
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source, SourceType::ts()).parse();
+        assert!(ret.diagnostics.is_empty(), "{source}: {:?}", ret.diagnostics);
+        let semantic = SemanticBuilder::new_linter().build(&ret.program).semantic;
+
+        let comment = semantic.comments().last().unwrap();
+        let node = node_after_span(&semantic, comment.span).map(|node| node.kind().ty());
+        assert_eq!(node, Some(expected_kind), "{source}");
+    }
+}
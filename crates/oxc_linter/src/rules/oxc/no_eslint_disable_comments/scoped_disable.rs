@@ -0,0 +1,111 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_span::{GetSpan, Span};
+
+use crate::context::LintContext;
+
+use super::{CommentDirective, CommentDirectiveKind};
+
+/// Top-level statements sharing a line, in which `rules` report diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopedDisable<'a> {
+    pub span: Span,
+    pub rules: Vec<&'a str>,
+}
+
+/// Finds the top-level statements a file-level `disable` directive actually suppresses
+/// diagnostics in, so that it can be replaced by `oxlint-disable`/`oxlint-enable` pairs around
+/// them.
+///
+/// Returns `None` if the directive can't be scoped: it is inside a statement, it is ended by an
+/// `enable` directive, or it lists a rule for which [`rule_trigger_spans`] doesn't know where
+/// diagnostics are reported.
+pub fn scoped_disables<'a>(
+    directive: &CommentDirective<'a>,
+    enable_directives: &[CommentDirective],
+    ctx: &LintContext,
+) -> Option<Vec<ScopedDisable<'a>>> {
+    if directive.kind != CommentDirectiveKind::Disable || directive.rules.is_empty() {
+        return None;
+    }
+    let statements = &ctx.nodes().program().body;
+    if statements.iter().any(|stmt| stmt.span().contains_inclusive(directive.comment_span)) {
+        return None;
+    }
+    if directive.is_ended_by_enable(enable_directives) {
+        return None;
+    }
+
+    let mut triggers = vec![];
+    for &(rule_name, _) in &directive.rules {
+        for span in rule_trigger_spans(rule_name, ctx)? {
+            if span.start > directive.comment_span.end {
+                triggers.push((span, rule_name));
+            }
+        }
+    }
+
+    let mut scoped: Vec<ScopedDisable<'a>> = vec![];
+    for stmt in statements {
+        let stmt_span = stmt.span();
+        let mut rules: Vec<&str> = directive
+            .rules
+            .iter()
+            .map(|(name, _)| *name)
+            .filter(|name| {
+                triggers
+                    .iter()
+                    .any(|(span, rule)| rule == name && stmt_span.contains_inclusive(*span))
+            })
+            .collect();
+        if rules.is_empty() {
+            continue;
+        }
+        // Statements sharing a line share their comments.
+        if let Some(last) = scoped.last_mut()
+            && !ctx.source_range(Span::new(last.span.end, stmt_span.start)).contains('\n')
+        {
+            last.span = last.span.merge(stmt_span);
+            rules.retain(|name| !last.rules.contains(name));
+            last.rules.extend(rules);
+            continue;
+        }
+        scoped.push(ScopedDisable { span: stmt_span, rules });
+    }
+    Some(scoped)
+}
+
+/// Spans containing every diagnostic `rule_name` may report, for rules whose diagnostics can be
+/// found without running them. Options of the rule are ignored, so this may find more spans
+/// than the rule reports.
+///
+/// Returns `None` for other rules.
+fn rule_trigger_spans(rule_name: &str, ctx: &LintContext) -> Option<Vec<Span>> {
+    let rule_name = rule_name.rsplit_once('/').map_or(rule_name, |(_, rule)| rule);
+    let spans = match rule_name {
+        "no-console" => {
+            if !ctx.scoping().root_unresolved_references().contains_key("console") {
+                return Some(vec![]);
+            }
+            ctx.nodes()
+                .iter()
+                .filter_map(|node| {
+                    let member_expr = node.kind().as_member_expression_kind()?;
+                    match member_expr.object() {
+                        Expression::Identifier(ident) if ident.name == "console" => {
+                            Some(node.span())
+                        }
+                        _ => None,
+                    }
+                })
+                .collect()
+        }
+        "no-debugger" => ctx
+            .nodes()
+            .iter()
+            .filter(|node| matches!(node.kind(), AstKind::DebuggerStatement(_)))
+            .map(GetSpan::span)
+            .collect(),
+        _ => return None,
+    };
+    Some(spans)
+}
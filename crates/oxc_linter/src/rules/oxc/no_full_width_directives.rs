@@ -0,0 +1,153 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{DirectivePrefix, context::LintContext, rule::Rule};
+
+fn no_full_width_directives_diagnostic(directive: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Directive `{directive}` is written with full-width characters, so it is not recognized."
+    ))
+    .with_help(format!("Write it as `{directive}` with ASCII characters."))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFullWidthDirectives;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports comments starting with an `oxlint-*` or `eslint-*` directive, like
+    /// `oxlint-disable-next-line`, written with full-width characters (`ｏｘｌｉｎｔ－ｄｉｓａｂｌｅ`).
+    ///
+    /// Input methods for Chinese, Japanese and Korean often produce full-width forms of ASCII
+    /// letters and hyphens. The suggested fix replaces the directive name with its ASCII form,
+    /// the same as Unicode NFKC normalization does.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Only ASCII directive names are recognized. A directive written with full-width characters
+    /// looks right, but is an ordinary comment and suppresses nothing.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// // ｅｓｌｉｎｔ-disable-next-line no-console
+    /// console.log(foo);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// // eslint-disable-next-line no-console
+    /// console.log(foo);
+    /// ```
+    NoFullWidthDirectives,
+    oxc,
+    suspicious,
+    suggestion,
+    version = "next",
+    short_description = "Disallows directive comments written with full-width characters.",
+);
+
+impl Rule for NoFullWidthDirectives {
+    fn run_once(&self, ctx: &LintContext) {
+        for comment in ctx.comments() {
+            let content_span = comment.content_span();
+            let content = content_span.source_text(ctx.source_text());
+            let text = content.trim_start();
+            let name_len = text
+                .char_indices()
+                .find(|&(_, c)| !matches!(to_ascii(c), 'a'..='z' | 'A'..='Z' | '-'))
+                .map_or(text.len(), |(index, _)| index);
+            let name = &text[..name_len];
+            if name.is_ascii() {
+                continue;
+            }
+            let ascii_name = name.chars().map(to_ascii).collect::<String>();
+            if !is_directive_name(&ascii_name) {
+                continue;
+            }
+
+            #[expect(clippy::cast_possible_truncation)]
+            let start = content_span.start + (content.len() - text.len()) as u32;
+            #[expect(clippy::cast_possible_truncation)]
+            let span = Span::sized(start, name_len as u32);
+            ctx.diagnostic_with_suggestion(
+                no_full_width_directives_diagnostic(&ascii_name, span),
+                |fixer| {
+                    fixer
+                        .replace(span, ascii_name.clone())
+                        .with_message(format!("Replace with `{ascii_name}`"))
+                },
+            );
+        }
+    }
+}
+
+/// Maps a full-width form of a printable ASCII character (U+FF01 to U+FF5E) to the ASCII
+/// character, like NFKC normalization. Other characters are returned unchanged.
+fn to_ascii(c: char) -> char {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        _ => c,
+    }
+}
+
+fn is_directive_name(name: &str) -> bool {
+    [DirectivePrefix::Oxlint, DirectivePrefix::Eslint].into_iter().any(|prefix| {
+        name == prefix.enable_directive_name()
+            || name
+                .strip_prefix(prefix.disable_directive_name())
+                .is_some_and(|rest| matches!(rest, "" | "-line" | "-next-line"))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "// eslint-disable-next-line no-console\nconsole.log(foo);",
+        "/* oxlint-disable no-console */",
+        // Full-width rule names are not directive names.
+        "// eslint-disable-next-line ｎｏ-console\nconsole.log(foo);",
+        // Not a directive, even in ASCII.
+        "// ｅｓｌｉｎｔ is a linter",
+        "// ｅｓｌｉｎｔ-disabled",
+        "// some text ｅｓｌｉｎｔ-disable",
+        "// ｅｓｌｉｎｔ－ｆｏｏ",
+        "const foo = 'ｅｓｌｉｎｔ-disable';",
+        // Directive names are case-sensitive.
+        "/* ＥＳＬＩＮＴ-ENABLE */",
+        // Directives must start the comment.
+        "/*\n * ｅｓｌｉｎｔ-disable\n */",
+    ];
+
+    let fail = vec![
+        "// ｅｓｌｉｎｔ-disable-next-line no-console\nconsole.log(foo);",
+        "// ｅｓｌｉｎｔ－ｄｉｓａｂｌｅ－ｎｅｘｔ－ｌｉｎｅ no-console\nconsole.log(foo);",
+        "/* ｏｘｌｉｎｔ-disable no-console */",
+        "console.log(foo); // eslint－disable-line",
+        "//　ｏｘｌｉｎｔ-enable",
+    ];
+
+    let fix = vec![
+        (
+            "// ｅｓｌｉｎｔ-disable-next-line no-console\nconsole.log(foo);",
+            "// eslint-disable-next-line no-console\nconsole.log(foo);",
+        ),
+        (
+            "// ｅｓｌｉｎｔ－ｄｉｓａｂｌｅ－ｎｅｘｔ－ｌｉｎｅ no-console\nconsole.log(foo);",
+            "// eslint-disable-next-line no-console\nconsole.log(foo);",
+        ),
+        ("/* ｏｘｌｉｎｔ-disable no-console */", "/* oxlint-disable no-console */"),
+        ("console.log(foo); // eslint－disable-line", "console.log(foo); // eslint-disable-line"),
+        ("//　ｏｘｌｉｎｔ-enable", "//　oxlint-enable"),
+    ];
+
+    Tester::new(NoFullWidthDirectives::NAME, NoFullWidthDirectives::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
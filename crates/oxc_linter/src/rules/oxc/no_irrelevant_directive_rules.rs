@@ -0,0 +1,177 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    DirectivePrefix,
+    config::parse_rule_key,
+    context::LintContext,
+    rule::{Rule, RuleLanguage},
+    rules::{
+        RULES,
+        oxc::no_eslint_disable_comments::{CommentDirectiveKind, find_comment_directive},
+    },
+};
+
+fn no_irrelevant_directive_rules_diagnostic(
+    rule_name: &str,
+    language: RuleLanguage,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Rule `{rule_name}` only applies to {} files, it never reports anything in this file.",
+        language.as_str()
+    ))
+    .with_help("This directive suppresses nothing here. Remove the rule from it.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoIrrelevantDirectiveRules;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports rules in `eslint-disable`, `eslint-disable-next-line` and `eslint-disable-line`
+    /// comments (and their `oxlint-*` equivalents) which don't apply to the language of the file,
+    /// such as a TypeScript rule disabled in a `.js` file, or a React rule disabled in a `.ts`
+    /// file.
+    ///
+    /// Rules only run on TypeScript files, on files which may contain JSX (`.js`, `.jsx` and
+    /// `.tsx`), or on Vue files, are known from their metadata. Rules oxlint doesn't implement are
+    /// not reported, see `oxc/no-unsupported-directive-rules` for those.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A rule which doesn't apply to a file never reports anything in it, so disabling it
+    /// suppresses nothing. Such directives are usually left over from copying code between files,
+    /// or from converting a file to another language.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule in a `.js` file:
+    /// ```js
+    /// // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    /// let foo = bar();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule in a `.js` file:
+    /// ```js
+    /// // eslint-disable-next-line no-console
+    /// console.log(foo);
+    ///
+    /// // eslint-disable-next-line react/no-danger
+    /// <div dangerouslySetInnerHTML={html} />;
+    /// ```
+    NoIrrelevantDirectiveRules,
+    oxc,
+    suspicious,
+    version = "next",
+    short_description = "Disallows disable directives for rules which don't apply to the language of the file.",
+);
+
+impl Rule for NoIrrelevantDirectiveRules {
+    fn run_once(&self, ctx: &LintContext) {
+        let source_type = ctx.source_type();
+        let file_extension = ctx.file_extension();
+        for comment in ctx.comments() {
+            let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, ctx.source_text(), prefix))
+            else {
+                continue;
+            };
+            if directive.kind == CommentDirectiveKind::Enable {
+                continue;
+            }
+
+            for &(name, span) in &directive.rules {
+                let (plugin_name, rule_name) = parse_rule_key(name);
+                let Some(rule) = RULES
+                    .iter()
+                    .find(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+                else {
+                    continue;
+                };
+                let language = rule.language();
+                if !language.applies_to(*source_type, file_extension) {
+                    ctx.diagnostic(no_irrelevant_directive_rules_diagnostic(name, language, span));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("// eslint-disable-next-line no-console\nconsole.log(foo);", None, None, None),
+        (
+            "// eslint-disable-next-line @typescript-eslint/no-explicit-any\nlet foo: any;",
+            None,
+            None,
+            Some(PathBuf::from("foo.ts")),
+        ),
+        (
+            "// oxlint-disable-next-line typescript/no-explicit-any\nlet foo: any;",
+            None,
+            None,
+            Some(PathBuf::from("foo.tsx")),
+        ),
+        (
+            "// eslint-disable-next-line react/no-danger\n<div dangerouslySetInnerHTML={html} />;",
+            None,
+            None,
+            None,
+        ),
+        (
+            "// eslint-disable-next-line react/no-danger\n<div dangerouslySetInnerHTML={html} />;",
+            None,
+            None,
+            Some(PathBuf::from("foo.jsx")),
+        ),
+        ("/* eslint-disable */", None, None, None),
+        // Enabling a rule doesn't suppress anything.
+        ("/* eslint-enable typescript/no-explicit-any */", None, None, None),
+        // Unknown rules are reported by `oxc/no-unsupported-directive-rules`.
+        ("// eslint-disable-next-line typescript/no-such-rule\nfoo();", None, None, None),
+        ("// typescript/no-explicit-any is not a directive", None, None, None),
+    ];
+
+    let fail = vec![
+        (
+            "// eslint-disable-next-line @typescript-eslint/no-explicit-any\nlet foo = bar();",
+            None,
+            None,
+            None,
+        ),
+        (
+            "// oxlint-disable-next-line typescript/no-non-null-assertion\nfoo();",
+            None,
+            None,
+            Some(PathBuf::from("foo.mjs")),
+        ),
+        (
+            "/* eslint-disable no-console, @typescript-eslint/no-explicit-any, @typescript-eslint/ban-ts-comment */",
+            None,
+            None,
+            None,
+        ),
+        ("foo(); // eslint-disable-line typescript/no-explicit-any", None, None, None),
+        (
+            "// eslint-disable-next-line react/no-danger\nfoo();",
+            None,
+            None,
+            Some(PathBuf::from("foo.ts")),
+        ),
+        ("// eslint-disable-next-line vue/max-props\nfoo();", None, None, None),
+    ];
+
+    Tester::new(NoIrrelevantDirectiveRules::NAME, NoIrrelevantDirectiveRules::PLUGIN, pass, fail)
+        .change_rule_path_extension("js")
+        .test_and_snapshot();
+}
@@ -0,0 +1,175 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    DirectivePrefix,
+    context::LintContext,
+    rule::Rule,
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirective, CommentDirectiveKind, directive_state::DirectiveState,
+        find_comment_directive,
+    },
+};
+
+fn redundant_enable_rule_diagnostic(
+    directive: &CommentDirective,
+    rule_name: &str,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Redundant `{}` comment: `{rule_name}` is already enabled.",
+        directive.prefix.enable_directive_name()
+    ))
+    .with_help(format!("Remove `{rule_name}` from this comment."))
+    .with_label(span)
+}
+
+fn redundant_enable_all_diagnostic(directive: &CommentDirective) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Redundant `{}` comment: no rules are disabled.",
+        directive.prefix.enable_directive_name()
+    ))
+    .with_help("Remove this comment.")
+    .with_label(directive.span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRedundantEnableComments;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows `oxlint-enable` (and `eslint-enable`) comments which re-enable rules that are
+    /// not disabled at that point, for example a second `oxlint-enable no-console` without an
+    /// `oxlint-disable no-console` in between.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A redundant enable comment has no effect. It is usually left over after the matching
+    /// disable comment was removed or moved, and suggests that a region of code is suppressed
+    /// when it isn't.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /* oxlint-disable no-console */
+    /// console.log(message);
+    /// /* oxlint-enable no-console */
+    /// /* oxlint-enable no-console */
+    ///
+    /// /* oxlint-enable */
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// /* oxlint-disable no-console */
+    /// console.log(message);
+    /// /* oxlint-enable no-console */
+    ///
+    /// /* oxlint-disable */
+    /// debugger;
+    /// /* oxlint-enable */
+    /// ```
+    NoRedundantEnableComments,
+    oxc,
+    suspicious,
+    version = "next",
+    short_description = "Disallow enable comments for rules which are already enabled.",
+);
+
+impl Rule for NoRedundantEnableComments {
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let mut state = DirectiveState::default();
+
+        for comment in ctx.comments() {
+            let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, source_text, prefix))
+            else {
+                continue;
+            };
+
+            match directive.kind {
+                CommentDirectiveKind::Disable => state.disable(&directive),
+                CommentDirectiveKind::Enable => {
+                    if directive.rules.is_empty() {
+                        if !state.is_any_disabled() {
+                            ctx.diagnostic(redundant_enable_all_diagnostic(&directive));
+                        }
+                    } else {
+                        for &(rule_name, span) in &directive.rules {
+                            if !state.is_disabled(rule_name) {
+                                ctx.diagnostic(redundant_enable_rule_diagnostic(
+                                    &directive, rule_name, span,
+                                ));
+                            }
+                        }
+                    }
+                    state.enable(&directive);
+                }
+                // Line directives don't change the state of the following code.
+                CommentDirectiveKind::DisableNextLine | CommentDirectiveKind::DisableLine => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "/* oxlint-disable no-console */
+        console.log(foo);
+        /* oxlint-enable no-console */",
+        "/* oxlint-disable */
+        debugger;
+        /* oxlint-enable */",
+        "/* oxlint-disable no-console, no-debugger */
+        /* oxlint-enable no-console */
+        /* oxlint-enable no-debugger */",
+        "/* oxlint-disable */
+        /* oxlint-enable no-console */
+        /* oxlint-enable */",
+        "/* oxlint-disable no-console */
+        /* oxlint-enable no-console */
+        /* oxlint-disable no-console */
+        /* oxlint-enable no-console */",
+        "/* eslint-disable no-console */
+        /* oxlint-enable no-console */",
+        "/* oxlint-disable no-console */
+        /* oxlint-enable */",
+        "// oxlint-disable-next-line no-console
+        console.log(foo);",
+        "foo(); // oxlint-disable-line no-console",
+    ];
+
+    let fail = vec![
+        "/* oxlint-disable no-console */
+        console.log(foo);
+        /* oxlint-enable no-console */
+        /* oxlint-enable no-console */",
+        "/* oxlint-enable no-console */",
+        "/* oxlint-enable */",
+        "/* oxlint-disable */
+        /* oxlint-enable */
+        /* oxlint-enable */",
+        "/* oxlint-disable no-console */
+        /* oxlint-enable no-console, no-debugger */",
+        "/* oxlint-disable no-console, no-debugger */
+        /* oxlint-enable no-console */
+        /* oxlint-enable no-console */",
+        "/* eslint-disable no-console */
+        /* eslint-enable no-console */
+        /* eslint-enable no-console */",
+        "// oxlint-disable-next-line no-console
+        console.log(foo);
+        /* oxlint-enable no-console */",
+    ];
+
+    Tester::new(NoRedundantEnableComments::NAME, NoRedundantEnableComments::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,171 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    DirectivePrefix,
+    context::LintContext,
+    rule::Rule,
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirective, CommentDirectiveKind,
+        directive_state::{ActiveDisable, DirectiveState},
+        find_comment_directive,
+    },
+};
+
+fn redundant_line_rule_diagnostic(
+    directive: &CommentDirective,
+    rule_name: &str,
+    span: Span,
+    disable: &ActiveDisable,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Redundant `{}` comment: `{rule_name}` is already disabled.",
+        directive_name(directive)
+    ))
+    .with_help(format!("Remove `{rule_name}` from this comment."))
+    .with_labels([
+        span.primary_label("redundant"),
+        disable.span.label(format!("`{rule_name}` is disabled here")),
+    ])
+}
+
+fn directive_name(directive: &CommentDirective) -> String {
+    format!("{}{}", directive.prefix.prefix(), directive.kind.as_str())
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRedundantLineDirectives;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows `oxlint-disable-next-line` and `oxlint-disable-line` comments (and their
+    /// `eslint-*` equivalents) for rules which are already disabled by an `oxlint-disable`
+    /// comment in effect at that point.
+    ///
+    /// Comments where all rules are disabled, by an `oxlint-disable` comment without rules, are
+    /// not reported, since this rule is disabled there too.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The narrower directive has no effect while the wider one is in effect. It is usually left
+    /// over from before the wider directive was added, and keeps suppressing the rule on its
+    /// line after the wider directive is removed, which is easy to miss.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /* oxlint-disable no-console */
+    /// // oxlint-disable-next-line no-console
+    /// console.log(foo);
+    ///
+    /// /* oxlint-disable no-debugger */
+    /// debugger; // oxlint-disable-line no-debugger
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// /* oxlint-disable no-console */
+    /// console.log(foo);
+    /// /* oxlint-enable no-console */
+    /// // oxlint-disable-next-line no-console
+    /// console.log(bar);
+    /// ```
+    NoRedundantLineDirectives,
+    oxc,
+    suspicious,
+    version = "next",
+    short_description = "Disallow line directives for rules which are already disabled.",
+);
+
+impl Rule for NoRedundantLineDirectives {
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let mut state = DirectiveState::default();
+
+        for comment in ctx.comments() {
+            let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, source_text, prefix))
+            else {
+                continue;
+            };
+
+            match directive.kind {
+                CommentDirectiveKind::Disable => state.disable(&directive),
+                CommentDirectiveKind::Enable => state.enable(&directive),
+                CommentDirectiveKind::DisableNextLine | CommentDirectiveKind::DisableLine => {
+                    for &(rule_name, span) in &directive.rules {
+                        if let Some(disable) = state.active_disable(rule_name) {
+                            ctx.diagnostic(redundant_line_rule_diagnostic(
+                                &directive, rule_name, span, &disable,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "// oxlint-disable-next-line no-console
+        console.log(foo);",
+        "/* oxlint-disable no-debugger */
+        // oxlint-disable-next-line no-console
+        console.log(foo);",
+        "/* oxlint-disable no-console */
+        console.log(foo);
+        /* oxlint-enable no-console */
+        // oxlint-disable-next-line no-console
+        console.log(bar);",
+        "/* oxlint-disable */
+        /* oxlint-enable no-console */
+        // oxlint-disable-next-line no-console
+        console.log(foo);",
+        // Diagnostics of this rule are disabled too.
+        "/* oxlint-disable */
+        // oxlint-disable-next-line no-console
+        console.log(foo);",
+        "/* oxlint-disable no-console */
+        // oxlint-disable-next-line
+        debugger;",
+        "// oxlint-disable-next-line no-console
+        console.log(foo);
+        /* oxlint-disable no-console */",
+        "console.log(foo); // oxlint-disable-line no-console
+        /* oxlint-disable no-console */",
+    ];
+
+    let fail = vec![
+        "/* oxlint-disable no-console */
+        // oxlint-disable-next-line no-console
+        console.log(foo);",
+        "/* oxlint-disable no-console */
+        console.log(foo); // oxlint-disable-line no-console",
+        "/* oxlint-disable no-console */
+        // oxlint-disable-next-line no-debugger, no-console
+        console.log(foo);",
+        "/* oxlint-disable no-console, no-debugger */
+        debugger; // oxlint-disable-line no-debugger",
+        "/* eslint-disable no-console */
+        // oxlint-disable-next-line no-console
+        console.log(foo);",
+        "/* oxlint-disable no-console */
+        // eslint-disable-next-line no-console
+        console.log(foo);",
+        "/* oxlint-disable no-console, no-debugger */
+        /* oxlint-enable no-debugger */
+        // oxlint-disable-next-line no-console, no-debugger
+        console.log(foo);",
+    ];
+
+    Tester::new(NoRedundantLineDirectives::NAME, NoRedundantLineDirectives::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,322 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    DirectivePrefix,
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirective, CommentDirectiveKind, find_comment_directive,
+    },
+};
+
+fn no_stacked_next_line_directives_diagnostic(
+    directive_name: &str,
+    span: Span,
+    next_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{directive_name}` comment applies to the directive on the next line, not to the code below it."
+    ))
+    .with_help("Only the directive directly above the code suppresses diagnostics in it. Move the rules into that directive.")
+    .with_labels([
+        span.label("this suppresses nothing"),
+        next_span.label("it only applies to this line"),
+    ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoStackedNextLineDirectives;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `oxlint-disable-next-line` (and `eslint-disable-next-line`) comments which are
+    /// followed by another directive comment on the next line, instead of by code.
+    ///
+    /// In oxlint, as in ESLint, a `disable-next-line` comment applies to the line directly after
+    /// the comment and nothing else. When directives are stacked, each one applies to the line of
+    /// the directive below it, so only the last one, directly above the code, suppresses
+    /// diagnostics in the code:
+    ///
+    /// ```js
+    /// // oxlint-disable-next-line no-console   <- applies to the next line, a comment
+    /// // oxlint-disable-next-line no-debugger  <- applies to the code below
+    /// debugger; console.log(foo);
+    /// ```
+    ///
+    /// The suggested fix moves the rules of a stacked directive into the directive directly above
+    /// the code, when both use the same prefix and the stacked one has no description.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Stacked directives look like they all apply to the code below them, but only the last one
+    /// does. The diagnostics the others were meant to suppress are still reported, and these
+    /// directives are unused.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line no-console
+    /// // oxlint-disable-next-line no-debugger
+    /// debugger; console.log(foo);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line no-console, no-debugger
+    /// debugger; console.log(foo);
+    ///
+    /// // oxlint-disable-next-line no-console
+    /// console.log(foo); // oxlint-disable-line no-debugger
+    /// ```
+    NoStackedNextLineDirectives,
+    oxc,
+    suspicious,
+    suggestion,
+    version = "next",
+    short_description = "Disallows `disable-next-line` comments followed by another directive instead of code.",
+);
+
+impl Rule for NoStackedNextLineDirectives {
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let directives = ctx
+            .comments()
+            .iter()
+            .filter_map(|comment| {
+                [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                    .into_iter()
+                    .find_map(|prefix| find_comment_directive(comment, source_text, prefix))
+            })
+            .collect::<Vec<_>>();
+
+        // Index of the directive starting the line after each directive, if any.
+        let next_directives = directives
+            .iter()
+            .map(|directive| {
+                let next_line_start = next_line_start(source_text, directive.comment_span.end)?;
+                directives.iter().position(|next| next.comment_span.start == next_line_start)
+            })
+            .collect::<Vec<_>>();
+
+        for (index, directive) in directives.iter().enumerate() {
+            if directive.kind != CommentDirectiveKind::DisableNextLine {
+                continue;
+            }
+            let Some(mut target) = next_directives[index] else {
+                continue;
+            };
+            let next = &directives[target];
+            // The directive which applies to the code, at the end of the stack.
+            while directives[target].kind == CommentDirectiveKind::DisableNextLine
+                && let Some(next_target) = next_directives[target]
+            {
+                target = next_target;
+            }
+
+            let diagnostic = no_stacked_next_line_directives_diagnostic(
+                directive.span.source_text(source_text),
+                directive.comment_span,
+                next.comment_span,
+            );
+            ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                merge_into(fixer, source_text, directive, &directives[target])
+            });
+        }
+    }
+}
+
+/// Start of the first non-whitespace character on the line after `offset`.
+fn next_line_start(source_text: &str, offset: u32) -> Option<u32> {
+    let rest = &source_text[offset as usize..];
+    let line_end = rest.find('\n')?;
+    let next_line = &rest[line_end + 1..];
+    let indent = next_line.len() - next_line.trim_start_matches([' ', '\t', '\r']).len();
+    #[expect(clippy::cast_possible_truncation)]
+    Some(offset + (line_end + 1 + indent) as u32)
+}
+
+/// Removes the line of `directive` and adds its rules to `target`, the directive directly above
+/// the code.
+fn merge_into(
+    fixer: RuleFixer<'_, '_>,
+    source_text: &str,
+    directive: &CommentDirective,
+    target: &CommentDirective,
+) -> RuleFix {
+    if target.kind != CommentDirectiveKind::DisableNextLine
+        || target.prefix != directive.prefix
+        || directive.description.is_some()
+        // Merging a directive disabling all rules would disable all rules for the code.
+        || directive.rules.is_empty()
+    {
+        return fixer.noop();
+    }
+    let Some(line) = own_line_span(source_text, directive.comment_span) else {
+        return fixer.noop();
+    };
+
+    let fixer = fixer.for_multifix();
+    let mut fix = fixer.new_fix_with_capacity(2);
+    fix.push(fixer.delete_range(line));
+    // A directive disabling all rules already covers the rules of `directive`.
+    if let Some(&(_, last_rule_span)) = target.rules.last() {
+        let mut new_rules = String::new();
+        for (name, _) in &directive.rules {
+            if !target.rules.iter().any(|(target_name, _)| target_name == name) {
+                new_rules.push_str(", ");
+                new_rules.push_str(name);
+            }
+        }
+        if !new_rules.is_empty() {
+            fix.push(fixer.insert_text_after_range(last_rule_span, new_rules));
+        }
+    }
+    fix.with_message("Move the rules into the directive directly above the code")
+}
+
+/// The span of the whole line containing `span`, including the line break, if nothing else is on
+/// that line.
+fn own_line_span(source_text: &str, span: Span) -> Option<Span> {
+    let before = &source_text[..span.start as usize];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    if !before[line_start..].trim().is_empty() {
+        return None;
+    }
+    let after = &source_text[span.end as usize..];
+    let line_end = after.find('\n')?;
+    if !after[..line_end].trim().is_empty() {
+        return None;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    Some(Span::new(line_start as u32, span.end + line_end as u32 + 1))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "// oxlint-disable-next-line no-console, no-debugger
+        debugger; console.log(foo);",
+        "// oxlint-disable-next-line no-console
+        console.log(foo); // oxlint-disable-line no-debugger",
+        "// oxlint-disable-next-line no-console
+        console.log(foo);
+        // oxlint-disable-next-line no-debugger
+        debugger;",
+        // A blank line is not a directive.
+        "// oxlint-disable-next-line no-console
+
+        // oxlint-disable-next-line no-debugger
+        debugger;",
+        // Other comments are not directives.
+        "// oxlint-disable-next-line no-console
+        // some comment
+        console.log(foo);",
+        "/* oxlint-disable no-console */
+        /* oxlint-disable no-debugger */
+        debugger; console.log(foo);",
+        "// oxlint-disable-line no-console
+        // oxlint-disable-next-line no-debugger
+        debugger;",
+        "foo(); // oxlint-disable-next-line no-console
+        console.log(foo);",
+    ];
+
+    let fail = vec![
+        "// oxlint-disable-next-line no-console
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+        "// eslint-disable-next-line no-console
+        // eslint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+        // Only the last of three directives applies to the code.
+        "// oxlint-disable-next-line no-console
+        // oxlint-disable-next-line no-alert
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(alert(foo));",
+        "// eslint-disable-next-line no-console
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+        "/* oxlint-disable-next-line no-console */
+        /* oxlint-disable-next-line no-debugger */
+        debugger; console.log(foo);",
+        "// oxlint-disable-next-line no-console -- logging is fine here
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+        "// oxlint-disable-next-line
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+        "// oxlint-disable-next-line no-console
+        /* oxlint-disable no-debugger */
+        debugger; console.log(foo);",
+        "// oxlint-disable-next-line no-console
+        // oxlint-disable-line no-debugger
+        debugger; console.log(foo);",
+        "foo(); // oxlint-disable-next-line no-console
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+    ];
+
+    let fix = vec![
+        (
+            "// oxlint-disable-next-line no-console
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+            "        // oxlint-disable-next-line no-debugger, no-console
+        debugger; console.log(foo);",
+        ),
+        (
+            "// eslint-disable-next-line no-console, no-debugger
+        // eslint-disable-next-line no-debugger -- needed here
+        debugger; console.log(foo);",
+            "        // eslint-disable-next-line no-debugger, no-console -- needed here
+        debugger; console.log(foo);",
+        ),
+        (
+            "function foo() {
+            /* oxlint-disable-next-line no-console */
+            // oxlint-disable-next-line
+            debugger; console.log(foo);
+        }",
+            "function foo() {
+            // oxlint-disable-next-line
+            debugger; console.log(foo);
+        }",
+        ),
+        // Not fixed: different prefixes, a description, or a directive disabling all rules.
+        (
+            "// eslint-disable-next-line no-console
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+            "// eslint-disable-next-line no-console
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+        ),
+        (
+            "// oxlint-disable-next-line no-console -- logging is fine here
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+            "// oxlint-disable-next-line no-console -- logging is fine here
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+        ),
+        (
+            "// oxlint-disable-next-line
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+            "// oxlint-disable-next-line
+        // oxlint-disable-next-line no-debugger
+        debugger; console.log(foo);",
+        ),
+    ];
+
+    Tester::new(NoStackedNextLineDirectives::NAME, NoStackedNextLineDirectives::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
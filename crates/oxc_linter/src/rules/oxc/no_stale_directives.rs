@@ -0,0 +1,191 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    DirectivePrefix,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirective, CommentDirectiveKind,
+        comment_anatomy::{SinceDate, find_since_marker},
+        find_comment_directive,
+    },
+};
+
+fn no_stale_directives_diagnostic(
+    directive: &CommentDirective,
+    max_age_months: u32,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{}{}` comment is older than the maximum allowed age of {max_age_months} months.",
+        directive.prefix.prefix(),
+        directive.kind.as_str()
+    ))
+    .with_help("Fix the suppressed diagnostics and remove the comment, or update its `@since` date if it is still needed.")
+    .with_label(span)
+}
+
+const DEFAULT_MAX_AGE_MONTHS: u32 = 12;
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoStaleDirectives {
+    /// The maximum age, in whole months, of a directive comment's `@since` date.
+    max_age_months: u32,
+}
+
+impl Default for NoStaleDirectives {
+    fn default() -> Self {
+        Self { max_age_months: DEFAULT_MAX_AGE_MONTHS }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `oxlint-*` and `eslint-*` directive comments whose description contains a
+    /// `@since` marker with a date older than the configured maximum age, 12 months by default.
+    /// Dates are written in ISO 8601 format, either `YYYY-MM` or `YYYY-MM-DD`.
+    ///
+    /// Directives without a `@since` marker and `enable` comments are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Suppressions are often added as a temporary measure, and then forgotten. Dating them
+    /// makes it possible to find the ones which have been around for too long, and to either fix
+    /// the suppressed diagnostics or confirm that the suppression is still needed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line no-console -- @since 2022-01
+    /// console.log(message);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line no-console -- @since 9999-01
+    /// console.log(message);
+    ///
+    /// // oxlint-disable-next-line no-debugger -- no date
+    /// debugger;
+    /// ```
+    NoStaleDirectives,
+    oxc,
+    restriction,
+    config = NoStaleDirectives,
+    version = "next",
+    short_description = "Disallow directive comments older than a maximum age.",
+);
+
+impl Rule for NoStaleDirectives {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let today = today();
+        for comment in ctx.comments() {
+            let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, source_text, prefix))
+            else {
+                continue;
+            };
+            if directive.kind == CommentDirectiveKind::Enable {
+                continue;
+            }
+            let Some(description) = directive.description else {
+                continue;
+            };
+            let Some((date, range)) = find_since_marker(description.source_text(source_text))
+            else {
+                continue;
+            };
+            if date.months_until(today) <= i64::from(self.max_age_months) {
+                continue;
+            }
+            #[expect(clippy::cast_possible_truncation)]
+            let span = Span::new(
+                description.start + range.start as u32,
+                description.start + range.end as u32,
+            );
+            ctx.diagnostic(no_stale_directives_diagnostic(&directive, self.max_age_months, span));
+        }
+    }
+}
+
+/// Today's date in UTC.
+fn today() -> SinceDate {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    civil_from_days(seconds / 86_400)
+}
+
+/// Converts a number of days since 1970-01-01 to a date in the proleptic Gregorian calendar.
+#[expect(clippy::cast_possible_truncation)]
+fn civil_from_days(days: u64) -> SinceDate {
+    // Shift the epoch to 0000-03-01, so that leap days are at the end of a year.
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = era * 400 + year_of_era + u64::from(month <= 2);
+    SinceDate { year: year as u32, month: month as u32, day: Some(day as u32) }
+}
+
+#[test]
+fn test_civil_from_days() {
+    let date = |year, month, day| SinceDate { year, month, day: Some(day) };
+    assert_eq!(civil_from_days(0), date(1970, 1, 1));
+    assert_eq!(civil_from_days(59), date(1970, 3, 1));
+    assert_eq!(civil_from_days(11_016), date(2000, 2, 29));
+    assert_eq!(civil_from_days(19_722), date(2023, 12, 31));
+    assert_eq!(civil_from_days(19_723), date(2024, 1, 1));
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("// oxlint-disable-next-line no-console -- @since 9999-01\nconsole.log(foo);", None),
+        ("foo(); // eslint-disable-line no-console -- legacy, @since 9999-12-31", None),
+        ("// oxlint-disable-next-line no-console\nconsole.log(foo);", None),
+        ("// oxlint-disable-next-line no-console -- legacy\nconsole.log(foo);", None),
+        ("/* oxlint-enable no-console -- @since 2000-01 */", None),
+        ("// oxlint-disable-next-line no-console -- @since 2000-13\nconsole.log(foo);", None),
+        ("// oxlint-disable-next-line no-console @since 2000-01\nconsole.log(foo);", None),
+        ("// @since 2000-01", None),
+        (
+            "// oxlint-disable-next-line no-console -- @since 2000-01\nconsole.log(foo);",
+            Some(json!([{ "maxAgeMonths": 100_000 }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("// oxlint-disable-next-line no-console -- @since 2022-01\nconsole.log(foo);", None),
+        ("foo(); // eslint-disable-line no-console -- legacy, @since 2000-02-29", None),
+        ("/* eslint-disable no-console -- @since 2000-01 */", None),
+        ("/*\n  oxlint-disable no-console\n  -- @since 2000-01\n*/", None),
+        (
+            "// oxlint-disable-next-line no-console -- @since 2000-01\nconsole.log(foo);",
+            Some(json!([{ "maxAgeMonths": 0 }])),
+        ),
+    ];
+
+    Tester::new(NoStaleDirectives::NAME, NoStaleDirectives::PLUGIN, pass, fail).test_and_snapshot();
+}
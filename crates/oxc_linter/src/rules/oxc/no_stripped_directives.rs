@@ -0,0 +1,133 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    DirectivePrefix,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirective, CommentDirectiveKind, find_comment_directive,
+    },
+};
+
+fn no_stripped_directives_diagnostic(directive: &CommentDirective) -> OxcDiagnostic {
+    let help = match directive.kind {
+        CommentDirectiveKind::Disable => {
+            "Disable the rules for this file with an `overrides` entry in the configuration file instead."
+        }
+        CommentDirectiveKind::DisableNextLine | CommentDirectiveKind::DisableLine => {
+            "Fix the suppressed diagnostics, or disable the rules for this file with an `overrides` entry in the configuration file."
+        }
+        CommentDirectiveKind::Enable => {
+            "Remove this comment along with the `disable` comment it ends, and configure the rules in the configuration file instead."
+        }
+    };
+    OxcDiagnostic::warn(format!(
+        "`{}{}` comment will be stripped by the build.",
+        directive.prefix.prefix(),
+        directive.kind.as_str()
+    ))
+    .with_help(help)
+    .with_label(directive.span)
+}
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoStrippedDirectives {
+    /// Whether the build strips comments from the output. The rule reports nothing unless this
+    /// is set.
+    build_strips_comments: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `oxlint-*` and `eslint-*` directive comments when the build is configured to strip
+    /// comments, as set by the `buildStripsComments` option.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Directive comments don't survive a build that strips comments, so they have no effect
+    /// on the built output, for example when it is linted or published. Disabling rules in the
+    /// configuration file instead keeps the suppressions in place regardless of how the code
+    /// is built.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "buildStripsComments": true }`:
+    /// ```js
+    /// // oxlint-disable-next-line no-console
+    /// console.log(message);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "buildStripsComments": true }`:
+    /// ```js
+    /// // Logs the message.
+    /// console.log(message);
+    /// ```
+    NoStrippedDirectives,
+    oxc,
+    restriction,
+    config = NoStrippedDirectives,
+    version = "next",
+    short_description = "Disallow directive comments when the build strips comments.",
+);
+
+impl Rule for NoStrippedDirectives {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if !self.build_strips_comments {
+            return;
+        }
+        let source_text = ctx.source_text();
+        for comment in ctx.comments() {
+            if let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, source_text, prefix))
+            {
+                ctx.diagnostic(no_stripped_directives_diagnostic(&directive));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("// oxlint-disable-next-line no-console\nconsole.log(foo);", None),
+        ("/* eslint-disable no-debugger */\ndebugger;", None),
+        (
+            "// oxlint-disable-next-line no-console\nconsole.log(foo);",
+            Some(json!([{ "buildStripsComments": false }])),
+        ),
+        ("// Logs the message.\nconsole.log(foo);", Some(json!([{ "buildStripsComments": true }]))),
+        ("/* @__PURE__ */ foo();", Some(json!([{ "buildStripsComments": true }]))),
+    ];
+
+    let fail = vec![
+        (
+            "// oxlint-disable-next-line no-console\nconsole.log(foo);",
+            Some(json!([{ "buildStripsComments": true }])),
+        ),
+        (
+            "foo(); // eslint-disable-line no-console",
+            Some(json!([{ "buildStripsComments": true }])),
+        ),
+        (
+            "/* eslint-disable no-debugger */\ndebugger;\n/* eslint-enable no-debugger */",
+            Some(json!([{ "buildStripsComments": true }])),
+        ),
+        ("/* oxlint-disable */", Some(json!([{ "buildStripsComments": true }]))),
+    ];
+
+    Tester::new(NoStrippedDirectives::NAME, NoStrippedDirectives::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
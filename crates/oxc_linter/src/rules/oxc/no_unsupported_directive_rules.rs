@@ -0,0 +1,206 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    DirectivePrefix,
+    config::parse_rule_key,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    rules::{
+        RULES,
+        oxc::no_eslint_disable_comments::{CommentDirectiveKind, find_comment_directive},
+    },
+};
+
+fn no_unsupported_directive_rules_diagnostic(
+    rule_names: &[&str],
+    spans: impl IntoIterator<Item = Span>,
+) -> OxcDiagnostic {
+    let (count, noun) = match rule_names {
+        [_] => ("a rule", "it is"),
+        _ => ("rules", "they are"),
+    };
+    let rule_names =
+        rule_names.iter().map(|name| format!("`{name}`")).collect::<Vec<_>>().join(", ");
+    OxcDiagnostic::warn(format!(
+        "Disable directives in this file reference {count} oxlint doesn't support: {rule_names}."
+    ))
+    .with_help(format!(
+        "These directives don't suppress anything in oxlint. Replace them with directives for the equivalent oxlint rules, or add the rule names to the `allow` option once {noun} handled, e.g. by a JS plugin."
+    ))
+    .with_labels(spans.into_iter().map(|span| span.label("not supported by oxlint")))
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnsupportedDirectiveRules(Box<NoUnsupportedDirectiveRulesConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnsupportedDirectiveRulesConfig {
+    /// Rule names which are not reported, e.g. rules from JS plugins. A plugin name, such as
+    /// `my-plugin`, allows all rules of that plugin.
+    allow: Vec<String>,
+}
+
+impl std::ops::Deref for NoUnsupportedDirectiveRules {
+    type Target = NoUnsupportedDirectiveRulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `eslint-disable`, `eslint-disable-next-line` and `eslint-disable-line` comments
+    /// (and their `oxlint-*` equivalents) which reference rules oxlint doesn't implement. All
+    /// such rules in a file are reported together in a single diagnostic.
+    ///
+    /// This is meant as a gate while migrating from ESLint: set it to `"error"` to fail CI until
+    /// every directive has an oxlint equivalent, or is removed.
+    ///
+    /// Rule names are looked up like in the config, so `@typescript-eslint/` and other plugin
+    /// aliases are supported. Rules from JS plugins are unknown to this rule, and need to be
+    /// listed in the `allow` option.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A directive for a rule oxlint doesn't support suppresses nothing. If the code it was
+    /// meant for is reported by an equivalent oxlint rule, the directive needs to be updated to
+    /// that rule; otherwise it is dead weight.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// // eslint-disable-next-line some-plugin/some-rule
+    /// foo();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// // eslint-disable-next-line no-console
+    /// console.log(foo);
+    ///
+    /// // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    /// let bar: any;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "allow": ["some-plugin"] }`:
+    /// ```js
+    /// // eslint-disable-next-line some-plugin/some-rule
+    /// foo();
+    /// ```
+    NoUnsupportedDirectiveRules,
+    oxc,
+    restriction,
+    config = NoUnsupportedDirectiveRulesConfig,
+    version = "next",
+    short_description = "Disallows disable directives for rules oxlint doesn't support.",
+);
+
+impl Rule for NoUnsupportedDirectiveRules {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let mut rule_names: Vec<&str> = vec![];
+        let mut spans = vec![];
+        for comment in ctx.comments() {
+            let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, ctx.source_text(), prefix))
+            else {
+                continue;
+            };
+            if directive.kind == CommentDirectiveKind::Enable {
+                continue;
+            }
+
+            for &(name, span) in &directive.rules {
+                if is_supported_rule(name) || self.is_allowed(name) {
+                    continue;
+                }
+                if !rule_names.contains(&name) {
+                    rule_names.push(name);
+                }
+                spans.push(span);
+            }
+        }
+
+        if !rule_names.is_empty() {
+            ctx.diagnostic(no_unsupported_directive_rules_diagnostic(&rule_names, spans));
+        }
+    }
+}
+
+impl NoUnsupportedDirectiveRules {
+    fn is_allowed(&self, name: &str) -> bool {
+        self.allow.iter().any(|allowed| {
+            name == allowed
+                || name.strip_prefix(allowed.as_str()).is_some_and(|rest| rest.starts_with('/'))
+        })
+    }
+}
+
+/// Whether `name`, as written in a directive, refers to a built-in rule.
+fn is_supported_rule(name: &str) -> bool {
+    let (plugin_name, rule_name) = parse_rule_key(name);
+    RULES.iter().any(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("// eslint-disable-next-line no-console\nconsole.log(foo);", None),
+        ("// oxlint-disable-next-line eslint/no-debugger\ndebugger;", None),
+        ("// eslint-disable-next-line @typescript-eslint/no-explicit-any\nlet foo: any;", None),
+        ("/* eslint-disable react-hooks/rules-of-hooks, import-x/no-cycle */", None),
+        ("foo(); // eslint-disable-line jsx-a11y/alt-text", None),
+        ("/* eslint-disable */", None),
+        ("// eslint-disable-next-line\nfoo();", None),
+        // Enabling a rule doesn't suppress anything.
+        ("/* eslint-enable some-plugin/some-rule */", None),
+        ("// some-plugin/some-rule is not a directive", None),
+        (
+            "// eslint-disable-next-line some-plugin/some-rule\nfoo();",
+            Some(json!([{ "allow": ["some-plugin"] }])),
+        ),
+        (
+            "// eslint-disable-next-line some-plugin/some-rule\nfoo();",
+            Some(json!([{ "allow": ["some-plugin/some-rule"] }])),
+        ),
+        (
+            "// eslint-disable-next-line @scope/plugin/some-rule\nfoo();",
+            Some(json!([{ "allow": ["@scope/plugin"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("// eslint-disable-next-line some-plugin/some-rule\nfoo();", None),
+        ("// oxlint-disable-next-line no-such-rule\nfoo();", None),
+        ("/* eslint-disable no-console, some-plugin/some-rule */", None),
+        ("foo(); // eslint-disable-line typescript/no-such-rule", None),
+        (
+            "// eslint-disable-next-line some-plugin/some-rule\nfoo();\n// eslint-disable-next-line some-plugin/some-rule, other/rule\nbar();",
+            None,
+        ),
+        (
+            "// eslint-disable-next-line some-plugin/some-rule, some-plugin-extra/rule\nfoo();",
+            Some(json!([{ "allow": ["some-plugin"] }])),
+        ),
+    ];
+
+    Tester::new(NoUnsupportedDirectiveRules::NAME, NoUnsupportedDirectiveRules::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .test_and_snapshot();
+}
@@ -0,0 +1,302 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    DirectivePrefix,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirectiveKind,
+        directive_state::{ActiveDisable, DirectiveState},
+        find_comment_directive,
+    },
+};
+
+fn unterminated_disable_diagnostic(disable: &ActiveDisable, next_line_only: bool) -> OxcDiagnostic {
+    let disable_name = disable.prefix.disable_directive_name();
+    let enable_name = disable.prefix.enable_directive_name();
+    let message = match disable.rule_name {
+        Some(rule_name) => format!(
+            "`{disable_name}` comment for `{rule_name}` has no matching `{enable_name}` comment."
+        ),
+        None => format!("`{disable_name}` comment has no matching `{enable_name}` comment."),
+    };
+    let help = if next_line_only {
+        format!(
+            "Only one line of code follows this comment. Use `{disable_name}-next-line` to disable it for that line only."
+        )
+    } else {
+        format!(
+            "Add an `{enable_name}` comment after the code this is meant for, or use `{disable_name}-next-line` to scope it to a single line."
+        )
+    };
+    OxcDiagnostic::warn(message).with_help(help).with_label(disable.span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnterminatedDisableComments(Box<NoUnterminatedDisableCommentsConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnterminatedDisableCommentsConfig {
+    /// Allow `disable` comments without a matching `enable` comment before any code in the
+    /// file, which disable rules for the whole file.
+    allow_whole_file: bool,
+}
+
+impl std::ops::Deref for NoUnterminatedDisableComments {
+    type Target = NoUnterminatedDisableCommentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `oxlint-disable` (and `eslint-disable`) comments which are still in effect at the
+    /// end of the file, because no `oxlint-enable` comment re-enables the rules they disable.
+    ///
+    /// When only one line of code follows such a comment, the diagnostic suggests
+    /// `oxlint-disable-next-line` instead.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `disable` comment without a matching `enable` comment suppresses diagnostics for all of
+    /// the code after it. This is usually meant for a few lines only, and new code added further
+    /// down the file is silently suppressed too.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /* oxlint-disable no-console */
+    /// console.log(message);
+    ///
+    /// function foo() {
+    ///   // oxlint-disable no-debugger
+    ///   debugger;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// /* oxlint-disable no-console */
+    /// console.log(message);
+    /// /* oxlint-enable no-console */
+    ///
+    /// function foo() {
+    ///   // oxlint-disable-next-line no-debugger
+    ///   debugger;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "allowWholeFile": true }`:
+    /// ```js
+    /// /* oxlint-disable no-console */
+    /// console.log(message);
+    /// ```
+    NoUnterminatedDisableComments,
+    oxc,
+    pedantic,
+    config = NoUnterminatedDisableCommentsConfig,
+    version = "next",
+    short_description = "Disallow `disable` comments without a matching `enable` comment.",
+);
+
+impl Rule for NoUnterminatedDisableComments {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let mut state = DirectiveState::default();
+
+        for comment in ctx.comments() {
+            let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, source_text, prefix))
+            else {
+                continue;
+            };
+
+            match directive.kind {
+                CommentDirectiveKind::Disable => state.disable(&directive),
+                CommentDirectiveKind::Enable => state.enable(&directive),
+                // Line directives don't change the state of the following code.
+                CommentDirectiveKind::DisableNextLine | CommentDirectiveKind::DisableLine => {}
+            }
+        }
+
+        let code_start = first_code_start(ctx);
+        for disable in state.active_disables() {
+            if self.allow_whole_file
+                && code_start.is_none_or(|start| disable.comment_span.end <= start)
+            {
+                continue;
+            }
+            let next_line_only = is_followed_by_one_line(source_text, disable.comment_span);
+            ctx.diagnostic(unterminated_disable_diagnostic(&disable, next_line_only));
+        }
+    }
+}
+
+/// Start of the first directive or statement in the file, if any.
+fn first_code_start(ctx: &LintContext) -> Option<u32> {
+    let program = ctx.nodes().program();
+    let directive_start = program.directives.first().map(|directive| directive.span.start);
+    let statement_start = program.body.first().map(|statement| statement.span().start);
+    directive_start.into_iter().chain(statement_start).min()
+}
+
+/// Whether the comment with `comment_span` is on a line of its own, and followed by exactly one
+/// more non-empty line until the end of the file.
+fn is_followed_by_one_line(source_text: &str, comment_span: Span) -> bool {
+    let before = &source_text[..comment_span.start as usize];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    if !before[line_start..].trim().is_empty() {
+        return false;
+    }
+
+    let after = &source_text[comment_span.end as usize..];
+    let Some(rest) = after.trim_start_matches([' ', '\t']).strip_prefix('\n') else {
+        return false;
+    };
+    let rest = rest.trim();
+    !rest.is_empty() && !rest.contains('\n')
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "/* oxlint-disable no-console */
+            console.log(foo);
+            /* oxlint-enable no-console */",
+            None,
+        ),
+        (
+            "/* oxlint-disable */
+            debugger;
+            /* oxlint-enable */",
+            None,
+        ),
+        (
+            "/* oxlint-disable no-console, no-debugger */
+            /* oxlint-enable no-console */
+            /* oxlint-enable no-debugger */",
+            None,
+        ),
+        (
+            "/* oxlint-disable no-console, no-debugger */
+            debugger;
+            /* oxlint-enable */",
+            None,
+        ),
+        (
+            "/* eslint-disable no-console */
+            console.log(foo);
+            /* oxlint-enable no-console */",
+            None,
+        ),
+        (
+            "// oxlint-disable-next-line no-console
+            console.log(foo);",
+            None,
+        ),
+        ("foo(); // oxlint-disable-line no-console", None),
+        ("/* oxlint-enable */", None),
+        ("// disable comments are not directives\nfoo();", None),
+        (
+            "/* oxlint-disable no-console */
+            console.log(foo);",
+            Some(json!([{ "allowWholeFile": true }])),
+        ),
+        (
+            "#!/usr/bin/env node
+            /* eslint-disable */
+            'use strict';
+            foo();",
+            Some(json!([{ "allowWholeFile": true }])),
+        ),
+        ("/* oxlint-disable */", Some(json!([{ "allowWholeFile": true }]))),
+    ];
+
+    let fail = vec![
+        (
+            "/* oxlint-disable no-console */
+            console.log(foo);",
+            None,
+        ),
+        ("/* oxlint-disable */", None),
+        (
+            "foo();
+            /* oxlint-disable */
+            debugger;
+            bar();",
+            None,
+        ),
+        (
+            "function foo() {
+                // oxlint-disable no-debugger
+                debugger;
+            }",
+            None,
+        ),
+        (
+            "/* oxlint-disable no-console, no-debugger */
+            console.log(foo);
+            /* oxlint-enable no-console */
+            debugger;",
+            None,
+        ),
+        (
+            "/* oxlint-disable */
+            /* oxlint-enable no-console */
+            console.log(foo);",
+            None,
+        ),
+        (
+            "/* oxlint-disable no-console */
+            /* oxlint-enable no-console */
+            /* oxlint-disable no-console */
+            console.log(foo);",
+            None,
+        ),
+        (
+            "/* eslint-disable no-console */
+            console.log(foo);
+            /* eslint-enable no-debugger */",
+            None,
+        ),
+        (
+            "foo();
+            /* oxlint-disable no-console */
+            console.log(foo);",
+            Some(json!([{ "allowWholeFile": true }])),
+        ),
+        (
+            "'use strict';
+            /* oxlint-disable no-console */
+            console.log(foo);",
+            Some(json!([{ "allowWholeFile": true }])),
+        ),
+    ];
+
+    Tester::new(
+        NoUnterminatedDisableComments::NAME,
+        NoUnterminatedDisableComments::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
@@ -0,0 +1,165 @@
+use lazy_regex::Regex;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    DirectivePrefix,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    rules::oxc::no_eslint_disable_comments::{
+        CommentDirective, CommentDirectiveKind, find_comment_directive,
+    },
+    utils::deserialize_regex,
+};
+
+fn require_directive_ticket_diagnostic(
+    directive: &CommentDirective,
+    pattern: &Regex,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{}{}` comment does not reference a ticket.",
+        directive.prefix.prefix(),
+        directive.kind.as_str()
+    ))
+    .with_help(format!(
+        "Add a ticket reference matching `{pattern}` to the description, after `--`."
+    ))
+    .with_label(directive.description.unwrap_or(directive.span))
+}
+
+const DEFAULT_TICKET_PATTERN: &str = r"\b[A-Z][A-Z0-9]+-[0-9]+\b";
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireDirectiveTicket(Box<RequireDirectiveTicketConfig>);
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct RequireDirectiveTicketConfig {
+    /// Regex pattern a ticket reference must match, anywhere in the description of the
+    /// directive. Defaults to Jira-style keys such as `JIRA-123`.
+    #[serde(default = "default_ticket_pattern", deserialize_with = "deserialize_regex")]
+    #[schemars(with = "String", default = "default_ticket_pattern_str")]
+    ticket_pattern: Regex,
+}
+
+impl Default for RequireDirectiveTicketConfig {
+    fn default() -> Self {
+        Self { ticket_pattern: default_ticket_pattern() }
+    }
+}
+
+fn default_ticket_pattern_str() -> String {
+    DEFAULT_TICKET_PATTERN.to_string()
+}
+
+fn default_ticket_pattern() -> Regex {
+    Regex::new(DEFAULT_TICKET_PATTERN).unwrap()
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires the description of `oxlint-disable*` and `eslint-disable*` directive comments,
+    /// the text after `--`, to reference a ticket matching the `ticketPattern` option. By
+    /// default, tickets are Jira-style keys such as `JIRA-123`.
+    ///
+    /// `enable` comments are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Suppressions without a ticket tend to be forgotten. Referencing the ticket tracking the
+    /// suppressed diagnostics makes it possible to follow up on them, and to find the
+    /// suppressions to remove once the ticket is done.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line no-console
+    /// console.log(message);
+    ///
+    /// // oxlint-disable-next-line no-console -- temporary
+    /// console.log(message);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// // oxlint-disable-next-line no-console -- JIRA-123 remove once logging is set up
+    /// console.log(message);
+    /// ```
+    RequireDirectiveTicket,
+    oxc,
+    restriction,
+    config = RequireDirectiveTicketConfig,
+    version = "next",
+    short_description = "Require directive comments to reference a ticket.",
+);
+
+impl Rule for RequireDirectiveTicket {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<RequireDirectiveTicketConfig>>(value)
+            .map(DefaultRuleConfig::into_inner)
+            .map(|config| Self(Box::new(config)))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        for comment in ctx.comments() {
+            let Some(directive) = [DirectivePrefix::Oxlint, DirectivePrefix::Eslint]
+                .into_iter()
+                .find_map(|prefix| find_comment_directive(comment, source_text, prefix))
+            else {
+                continue;
+            };
+            if directive.kind == CommentDirectiveKind::Enable {
+                continue;
+            }
+            if directive.description.is_some_and(|description| {
+                self.0.ticket_pattern.is_match(description.source_text(source_text))
+            }) {
+                continue;
+            }
+            ctx.diagnostic(require_directive_ticket_diagnostic(&directive, &self.0.ticket_pattern));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("// oxlint-disable-next-line no-console -- JIRA-123\nconsole.log(foo);", None),
+        ("foo(); // eslint-disable-line no-console -- legacy, see OPS-42", None),
+        ("/* oxlint-disable no-console -- ABC2-7 remove after migration */", None),
+        ("/* oxlint-enable no-console */", None),
+        ("// TODO: JIRA-123", None),
+        (
+            "// oxlint-disable-next-line no-console -- #123\nconsole.log(foo);",
+            Some(json!([{ "ticketPattern": "#[0-9]+" }])),
+        ),
+        (
+            "// oxlint-disable-next-line no-console -- https://github.com/org/repo/issues/9\nconsole.log(foo);",
+            Some(json!([{ "ticketPattern": "github\\.com/[^/]+/[^/]+/issues/[0-9]+" }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("// oxlint-disable-next-line no-console\nconsole.log(foo);", None),
+        ("// oxlint-disable-next-line no-console -- temporary\nconsole.log(foo);", None),
+        ("foo(); // eslint-disable-line no-console -- see jira-123", None),
+        ("/* eslint-disable no-console -- JIRA-abc */", None),
+        ("// oxlint-disable-next-line no-console -- XJIRA-123X\nconsole.log(foo);", None),
+        ("// oxlint-disable-next-line no-console no-debugger JIRA-123\nconsole.log(foo);", None),
+        (
+            "// oxlint-disable-next-line no-console -- JIRA-123\nconsole.log(foo);",
+            Some(json!([{ "ticketPattern": "#[0-9]+" }])),
+        ),
+    ];
+
+    Tester::new(RequireDirectiveTicket::NAME, RequireDirectiveTicket::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
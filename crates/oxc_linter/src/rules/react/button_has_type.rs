@@ -1,6 +1,6 @@
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::{get_prop_value, has_jsx_prop_ignore_case, is_create_element_call},
 };
@@ -76,6 +76,7 @@ declare_oxc_lint!(
     react,
     restriction,
     config = ButtonHasType,
+    language = jsx,
     version = "0.1.1",
     short_description = "Enforces an explicit `type` attribute for all HTML `button` elements.",
 );
@@ -155,10 +156,6 @@ impl Rule for ButtonHasType {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 impl ButtonHasType {
@@ -690,19 +690,12 @@ impl Rule for ExhaustiveDeps {
             // lastly, we need co compare for any unnecessary deps
             // for example if `props.foo`, AND `props.foo.bar.baz` was declared in the deps array
             // `props.foo.bar.baz` is unnecessary (already covered by `props.foo`)
+            let mut unnecessary_dependencies = Vec::new();
             declared_dependencies.iter().array_combinations().for_each(|[a, b]| {
                 if a.contains(b) {
-                    ctx.diagnostic(unnecessary_dependency_diagnostic(
-                        hook_name,
-                        &a.to_string(),
-                        dependencies_node.span,
-                    ));
+                    unnecessary_dependencies.push(a);
                 } else if b.contains(a) {
-                    ctx.diagnostic(unnecessary_dependency_diagnostic(
-                        hook_name,
-                        &b.to_string(),
-                        dependencies_node.span,
-                    ));
+                    unnecessary_dependencies.push(b);
                 }
             });
 
@@ -711,11 +704,19 @@ impl Rule for ExhaustiveDeps {
                     continue;
                 }
 
-                ctx.diagnostic(unnecessary_dependency_diagnostic(
-                    hook_name,
-                    &dep.to_string(),
-                    dependencies_node.span,
-                ));
+                unnecessary_dependencies.push(dep);
+            }
+
+            // A dependency can be both covered by another one and unused.
+            let mut reported = FxHashSet::default();
+            for dep in unnecessary_dependencies {
+                if reported.insert(dep) {
+                    ctx.diagnostic(unnecessary_dependency_diagnostic(
+                        hook_name,
+                        &dep.to_string(),
+                        dependencies_node.span,
+                    ));
+                }
             }
         }
 
@@ -11,7 +11,7 @@ use serde::Deserialize;
 use crate::utils::{get_jsx_element_name, is_react_component_name};
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -279,6 +279,7 @@ declare_oxc_lint!(
     react,
     restriction,
     config = ForbidComponentPropsConfig,
+    language = jsx,
     version = "1.62.0",
     short_description = "Disallow specific props on components.",
 );
@@ -326,10 +327,6 @@ impl Rule for ForbidComponentProps {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 /// Returns the rightmost identifier of a JSX element name
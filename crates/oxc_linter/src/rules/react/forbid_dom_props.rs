@@ -162,6 +162,7 @@ declare_oxc_lint!(
     react,
     restriction,
     config = ForbidDomPropsConfig,
+    language = jsx,
     version = "1.24.0",
     short_description = "Disallow certain props on DOM Nodes.",
 );
@@ -220,8 +221,8 @@ impl Rule for ForbidDomProps {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx() && !self.forbid.is_empty()
+    fn should_run(&self, _ctx: &ContextHost) -> bool {
+        !self.forbid.is_empty()
     }
 }
 
@@ -124,6 +124,7 @@ declare_oxc_lint!(
     react,
     restriction,
     config = ForbidElementsConfig,
+    language = jsx,
     version = "0.16.11",
     short_description = "Allows you to configure a list of forbidden elements and to specify their desired replacements.",
 );
@@ -176,8 +177,8 @@ impl Rule for ForbidElements {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx() && !self.forbid.is_empty()
+    fn should_run(&self, _ctx: &ContextHost) -> bool {
+        !self.forbid.is_empty()
     }
 }
 
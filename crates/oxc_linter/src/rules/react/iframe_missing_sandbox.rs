@@ -171,11 +171,12 @@ impl Rule for IframeMissingSandbox {
 }
 
 fn validate_sandbox_value(literal: &StringLiteral, ctx: &LintContext) {
-    let attrs = literal.value.split(' ');
+    let attrs = literal.value.split(' ').map(str::trim).collect::<Vec<_>>();
     let mut has_allow_same_origin = false;
     let mut has_allow_scripts = false;
-    for trimmed_atr in attrs.into_iter().map(str::trim) {
-        if !is_allowed_value(trimmed_atr) {
+    for (i, &trimmed_atr) in attrs.iter().enumerate() {
+        // Each invalid value is reported once, even if it is repeated.
+        if !is_allowed_value(trimmed_atr) && !attrs[..i].contains(&trimmed_atr) {
             ctx.diagnostic(invalid_sandbox_prop(literal.span, trimmed_atr));
         }
         if trimmed_atr == "allow-scripts" {
@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{Rule, TupleRuleConfig},
     utils::get_prop_value,
 };
@@ -109,6 +109,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = JsxBooleanValueConfig,
+    language = jsx,
     version = "0.7.0",
     short_description = "Enforce a consistent boolean attribute style in your code.",
 );
@@ -164,10 +165,6 @@ impl Rule for JsxBooleanValue {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 impl JsxBooleanValue {
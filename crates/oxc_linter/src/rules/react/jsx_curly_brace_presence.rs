@@ -1,6 +1,6 @@
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 use lazy_regex::{Lazy, Regex, lazy_regex};
@@ -334,6 +334,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = JsxCurlyBracePresenceConfig,
+    language = jsx,
     version = "0.7.0",
     short_description = "Disallow unnecessary JSX expressions when literals alone are sufficient.",
 );
@@ -363,10 +364,6 @@ impl Rule for JsxCurlyBracePresence {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 impl JsxCurlyBracePresence {
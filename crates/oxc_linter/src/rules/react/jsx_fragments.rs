@@ -103,6 +103,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = FragmentMode,
+    language = jsx,
     version = "1.12.0",
     short_description = "Enforces the shorthand or standard form for React Fragments.",
 );
@@ -177,10 +178,6 @@ impl Rule for JsxFragments {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -20,7 +20,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     AstNode,
     ast_util::is_node_within_call_argument,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::default_true,
 };
@@ -109,6 +109,7 @@ declare_oxc_lint!(
     react,
     correctness,
     config = JsxKey,
+    language = jsx,
     version = "0.0.14",
     short_description = "Enforce `key` prop for elements in an array.",
 );
@@ -139,10 +140,6 @@ impl Rule for JsxKey {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 pub fn is_to_array(call: &CallExpression<'_>) -> bool {
@@ -13,7 +13,7 @@ use rustc_hash::FxHashSet;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -86,6 +86,7 @@ declare_oxc_lint!(
     react,
     style,
     config = JsxMaxDepthConfig,
+    language = jsx,
     version = "1.36.0",
     short_description = "Enforces a maximum depth for nested JSX elements and fragments.",
 );
@@ -116,10 +117,6 @@ impl Rule for JsxMaxDepth {
             ctx.diagnostic(jsx_max_depth_diagnostic(total_depth, self.max, node.span()));
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 fn calculate_variable_jsx_depth(
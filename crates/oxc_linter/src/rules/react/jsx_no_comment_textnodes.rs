@@ -3,11 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn jsx_no_comment_textnodes_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Comments inside children section of tag should be placed inside braces")
@@ -51,6 +47,7 @@ declare_oxc_lint!(
     JsxNoCommentTextnodes,
     react,
     suspicious,
+    language = jsx,
     version = "0.0.14",
     short_description = "This rule prevents comment strings (e.g. beginning with `//` or `/*`) from being accidentally injected as a text node in JSX statements.",
 );
@@ -65,10 +62,6 @@ impl Rule for JsxNoCommentTextnodes {
             ctx.diagnostic(jsx_no_comment_textnodes_diagnostic(jsx_text.span));
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 /// Returns true if the given text contains a comment pattern such as `//` or `/*`.
@@ -9,11 +9,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn jsx_no_constructed_context_values(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("The Context `value` prop should not be constructed.")
@@ -76,6 +72,7 @@ declare_oxc_lint!(
     JsxNoConstructedContextValues,
     react,
     perf,
+    language = jsx,
     version = "1.48.0",
     short_description = "Disallows JSX context provider values that cause needless re-renders.",
 );
@@ -117,10 +114,6 @@ impl Rule for JsxNoConstructedContextValues {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 fn is_context_provider(jsx_opening_elem: &JSXOpeningElement, ctx: &LintContext<'_>) -> bool {
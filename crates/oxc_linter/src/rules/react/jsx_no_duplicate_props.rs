@@ -8,11 +8,7 @@ use oxc_span::Span;
 use oxc_str::Str;
 use rustc_hash::FxHashMap;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn jsx_no_duplicate_props_diagnostic(prop_name: &str, span1: Span, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -57,6 +53,7 @@ declare_oxc_lint!(
     JsxNoDuplicateProps,
     react,
     correctness,
+    language = jsx,
     version = "0.0.14",
     short_description = "This rule prevents duplicate props in JSX elements.",
 );
@@ -87,10 +84,6 @@ impl Rule for JsxNoDuplicateProps {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -7,9 +7,9 @@ use serde::Deserialize;
 use oxc_ast::{
     AstKind,
     ast::{
-        BindingPattern, Expression, IdentifierReference, JSXAttribute, JSXAttributeItem,
-        JSXAttributeName, JSXAttributeValue, JSXChild, JSXElement, JSXElementName, JSXExpression,
-        JSXFragment, JSXMemberExpression, JSXMemberExpressionObject, ModuleExportName, PropertyKey,
+        BindingPattern, Expression, IdentifierReference, JSXAttributeItem, JSXAttributeName,
+        JSXAttributeValue, JSXChild, JSXElement, JSXElementName, JSXExpression, JSXFragment,
+        JSXMemberExpression, JSXMemberExpressionObject, ModuleExportName, PropertyKey,
     },
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -290,21 +290,20 @@ impl JsxNoLiterals {
     fn inspect_jsx_expression(
         expr: &Expression,
         options: &JsxNoLiteralsOptions,
-        attr: &JSXAttribute,
         ctx: &LintContext,
     ) {
         match &expr {
             Expression::StringLiteral(literal) => {
                 if !Self::is_allowed_string(literal.value.as_str(), options) {
-                    ctx.diagnostic(literal_attribute_diagnostic(attr.span));
+                    ctx.diagnostic(literal_attribute_diagnostic(literal.span));
                 }
             }
-            Expression::TemplateLiteral(_) => {
-                ctx.diagnostic(literal_attribute_diagnostic(attr.span));
+            Expression::TemplateLiteral(literal) => {
+                ctx.diagnostic(literal_attribute_diagnostic(literal.span));
             }
             Expression::BinaryExpression(expression) => {
-                Self::inspect_jsx_expression(&expression.left, options, attr, ctx);
-                Self::inspect_jsx_expression(&expression.right, options, attr, ctx);
+                Self::inspect_jsx_expression(&expression.left, options, ctx);
+                Self::inspect_jsx_expression(&expression.right, options, ctx);
             }
             _ => {}
         }
@@ -361,7 +360,7 @@ impl JsxNoLiterals {
                         && !options.ignore_props
                         && let Some(expr) = container.expression.as_expression()
                     {
-                        Self::inspect_jsx_expression(expr, options, attr, ctx);
+                        Self::inspect_jsx_expression(expr, options, ctx);
                     }
                 }
                 _ => {}
@@ -17,7 +17,7 @@ use oxc_str::CompactStr;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{MixedTupleRuleConfig, Rule},
 };
 
@@ -153,6 +153,7 @@ declare_oxc_lint!(
     suspicious,
     pending,
     config = JsxNoScriptUrlRuleConfig,
+    language = jsx,
     version = "0.13.2",
     short_description = "Disallow usage of `javascript:` URLs.",
 );
@@ -228,10 +229,6 @@ impl Rule for JsxNoScriptUrl {
             components: components.into_map(),
         })))
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -16,7 +16,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::is_same_expression,
 };
@@ -146,6 +146,7 @@ declare_oxc_lint!(
     pedantic,
     pending,
     config = JsxNoTargetBlank,
+    language = jsx,
     version = "0.2.5",
     short_description = "This rule aims to prevent user-generated link hrefs and form actions from creating security vulnerabilities.",
 );
@@ -247,10 +248,6 @@ impl Rule for JsxNoTargetBlank {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 fn check_is_external_link(link: &str) -> bool {
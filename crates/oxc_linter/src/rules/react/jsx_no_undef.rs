@@ -6,11 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn jsx_no_undef_diagnostic(ident_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("'{ident_name}' is not defined.")).with_label(span)
@@ -41,6 +37,7 @@ declare_oxc_lint!(
     JsxNoUndef,
     react,
     correctness,
+    language = jsx,
     version = "0.1.1",
     short_description = "Disallow undeclared variables in JSX.",
 );
@@ -85,10 +82,6 @@ impl Rule for JsxNoUndef {
             ctx.diagnostic(jsx_no_undef_diagnostic(name, ident.span));
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -1,6 +1,6 @@
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     fixer::{RuleFix, RuleFixer},
     rule::{DefaultRuleConfig, Rule},
     utils::is_jsx_fragment,
@@ -65,6 +65,7 @@ declare_oxc_lint!(
     pedantic,
     suggestion,
     config = JsxNoUselessFragment,
+    language = jsx,
     version = "0.0.14",
     short_description = "Disallow unnecessary fragments.",
 );
@@ -88,10 +89,6 @@ impl Rule for JsxNoUselessFragment {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 impl JsxNoUselessFragment {
@@ -8,7 +8,7 @@ use rustc_hash::FxHashMap;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     fixer::{Fix, RuleFix},
     rule::Rule,
     utils::is_same_member_expression,
@@ -63,6 +63,7 @@ declare_oxc_lint!(
     react,
     correctness,
     fix,
+    language = jsx,
     version = "0.7.2",
     short_description = "Enforces that any unique expression is only spread once.",
 );
@@ -138,10 +139,6 @@ impl Rule for JsxPropsNoSpreadMulti {
             );
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
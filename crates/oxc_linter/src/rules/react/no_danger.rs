@@ -1,16 +1,21 @@
+use fast_glob::glob_match;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
 use oxc_ast::{
     AstKind,
-    ast::{Argument, JSXAttributeItem, ObjectPropertyKind},
+    ast::{Argument, Expression, JSXAttributeItem, ObjectPropertyKind},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use oxc_str::CompactStr;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-    utils::{has_jsx_prop, is_create_element_call},
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{get_jsx_element_name, has_jsx_prop, is_create_element_call},
 };
 
 fn no_danger_diagnostic(span: Span) -> OxcDiagnostic {
@@ -19,14 +24,35 @@ fn no_danger_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
-pub struct NoDanger;
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoDanger(Box<NoDangerConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoDangerConfig {
+    /// Custom components to check in addition to DOM elements, for example wrapper components
+    /// which forward `dangerouslySetInnerHTML` to a DOM element.
+    ///
+    /// Glob patterns are supported, e.g. `["*Html"]`, and `["*"]` checks all components.
+    custom_component_names: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoDanger {
+    type Target = NoDangerConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
     ///
     /// This rule prevents the use of `dangerouslySetInnerHTML` prop.
     ///
+    /// Only DOM elements are checked, unless custom components are listed in
+    /// `customComponentNames`.
+    ///
     /// ### Why is this bad?
     ///
     /// `dangerouslySetInnerHTML` is a way to inject HTML into your React
@@ -51,14 +77,24 @@ declare_oxc_lint!(
     NoDanger,
     react,
     restriction,
+    config = NoDangerConfig,
+    language = jsx,
     version = "0.0.14",
     short_description = "This rule prevents the use of `dangerouslySetInnerHTML` prop.",
 );
 
 impl Rule for NoDanger {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
+                if !self.is_checked_element(&get_jsx_element_name(&jsx_elem.opening_element.name)) {
+                    return;
+                }
+
                 if let Some(JSXAttributeItem::Attribute(prop)) =
                     has_jsx_prop(&jsx_elem.opening_element, "dangerouslySetInnerHTML")
                 {
@@ -70,6 +106,18 @@ impl Rule for NoDanger {
                     return;
                 }
 
+                let element_name = match call_expr.arguments.first() {
+                    Some(Argument::StringLiteral(lit)) => lit.value.as_str(),
+                    Some(arg) => match arg.as_expression().map(Expression::get_inner_expression) {
+                        Some(Expression::Identifier(ident)) => ident.name.as_str(),
+                        _ => return,
+                    },
+                    None => return,
+                };
+                if !self.is_checked_element(element_name) {
+                    return;
+                }
+
                 let Some(props) = call_expr.arguments.get(1) else {
                     return;
                 };
@@ -90,9 +138,14 @@ impl Rule for NoDanger {
             _ => {}
         }
     }
+}
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+impl NoDanger {
+    /// DOM elements, like `div`, are always checked. Custom components only when they match
+    /// `customComponentNames`.
+    fn is_checked_element(&self, name: &str) -> bool {
+        (name.starts_with(char::is_lowercase) && !name.contains('.'))
+            || self.custom_component_names.iter().any(|pattern| glob_match(pattern.as_str(), name))
     }
 }
 
@@ -101,16 +154,49 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        "<App />;",
-        "<div className=\"bar\"></div>;",
-        "React.createElement(\"div\", { className: \"bar\" });",
+        ("<App />;", None),
+        ("<div className=\"bar\"></div>;", None),
+        ("React.createElement(\"div\", { className: \"bar\" });", None),
+        ("<App dangerouslySetInnerHTML={{ __html: \"\" }} />;", None),
+        ("React.createElement(App, { dangerouslySetInnerHTML: { __html: \"\" } });", None),
+        (
+            "<App dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["Title"] }])),
+        ),
+        (
+            "<Title.Html dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["Title"] }])),
+        ),
     ];
 
     let fail = vec![
-        "<div dangerouslySetInnerHTML={{ __html: \"\" }}></div>;",
-        "<button dangerouslySetInnerHTML={{ __html: \"baz\" }}>Foo</button>;",
-        "React.createElement(\"div\", { dangerouslySetInnerHTML: { __html: \"\" } });",
-        "React.createElement(\"button\", { dangerouslySetInnerHTML: { __html: \"baz\" } }, \"Foo\");",
+        ("<div dangerouslySetInnerHTML={{ __html: \"\" }}></div>;", None),
+        ("<button dangerouslySetInnerHTML={{ __html: \"baz\" }}>Foo</button>;", None),
+        ("React.createElement(\"div\", { dangerouslySetInnerHTML: { __html: \"\" } });", None),
+        (
+            "React.createElement(\"button\", { dangerouslySetInnerHTML: { __html: \"baz\" } }, \"Foo\");",
+            None,
+        ),
+        (
+            "<App dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["*"] }])),
+        ),
+        (
+            "<Title dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["Title"] }])),
+        ),
+        (
+            "<RawHtml dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["*Html"] }])),
+        ),
+        (
+            "<Title.Html dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["Title.*"] }])),
+        ),
+        (
+            "React.createElement(Title, { dangerouslySetInnerHTML: { __html: \"\" } });",
+            Some(serde_json::json!([{ "customComponentNames": ["Title"] }])),
+        ),
     ];
 
     Tester::new(NoDanger::NAME, NoDanger::PLUGIN, pass, fail).test_and_snapshot();
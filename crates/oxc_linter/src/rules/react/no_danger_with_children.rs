@@ -1,19 +1,29 @@
 use oxc_allocator::ArenaVec;
 use oxc_ast::{
     AstKind,
-    ast::{Argument, Expression, JSXAttributeItem, JSXAttributeName, JSXChild, ObjectPropertyKind},
+    ast::{
+        Argument, Expression, JSXAttributeItem, JSXAttributeName, JSXElement, ObjectPropertyKind,
+    },
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_str::Ident;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, context::LintContext, rule::Rule, utils::first_jsx_child_span};
 
-fn no_danger_with_children_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Only set one of `children` or `props.dangerouslySetInnerHTML`")
-            .with_help("`dangerouslySetInnerHTML` is not compatible with also passing children and React will throw a warning at runtime.")
-            .with_label(span)
+fn no_danger_with_children_diagnostic(danger_span: Span, children_span: Span) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn("Only set one of `children` or `props.dangerouslySetInnerHTML`")
+        .with_help("`dangerouslySetInnerHTML` is not compatible with also passing children and React will throw an error at runtime.");
+    if danger_span == children_span {
+        return diagnostic.with_label(
+            danger_span.label("`dangerouslySetInnerHTML` and `children` are set here"),
+        );
+    }
+    diagnostic.with_labels([
+        danger_span.label("`dangerouslySetInnerHTML` is set here"),
+        children_span.label("Children are passed here"),
+    ])
 }
 
 #[derive(Debug, Default, Clone)]
@@ -24,15 +34,20 @@ declare_oxc_lint!(
     ///
     /// Disallows DOM elements from using both `children` and `dangerouslySetInnerHTML` properties.
     ///
+    /// Children are either nested between the tags, ignoring whitespace-only text and
+    /// `{/* comments */}`, or passed as a `children` prop. Props spread from an inline object
+    /// literal, or from a variable initialized with an object literal, are checked too.
+    ///
     /// ### Why is this bad?
     ///
-    /// React will throw a warning if this rule is ignored and both `children` and `dangerouslySetInnerHTML` are used.
+    /// React will throw an error if this rule is ignored and both `children` and `dangerouslySetInnerHTML` are used.
     ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
     /// ```jsx
     /// <div dangerouslySetInnerHTML={{ __html: "HTML" }}>Children</div>
+    /// <div dangerouslySetInnerHTML={{ __html: "HTML" }} {...{ children: "Children" }} />
     /// React.createElement(
     ///     "div",
     ///     { dangerouslySetInnerHTML: { __html: "HTML" } },
@@ -44,6 +59,7 @@ declare_oxc_lint!(
     /// ```jsx
     /// <div>Children</div>
     /// <div dangerouslySetInnerHTML={{ __html: "HTML" }} />
+    /// <div dangerouslySetInnerHTML={{ __html: "HTML" }}>{/* comment */}</div>
     /// ```
     NoDangerWithChildren,
     react,
@@ -56,16 +72,16 @@ impl Rule for NoDangerWithChildren {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx) => {
-                // Either children are passed in as a prop like `children={}` or they are nested between the tags.
-                let has_children = has_jsx_prop(ctx, node, "children")
-                    || (!jsx.children.is_empty() && !is_line_break(&jsx.children[0]));
-                if !has_children {
+                // Either children are nested between the tags or they are passed in as a prop like `children={}`.
+                let Some(children_span) =
+                    first_jsx_child_span(jsx).or_else(|| find_jsx_prop(ctx, node, jsx, "children"))
+                else {
                     return;
-                }
+                };
 
-                let has_danger_prop = has_jsx_prop(ctx, node, "dangerouslySetInnerHTML");
-                if has_danger_prop {
-                    ctx.diagnostic(no_danger_with_children_diagnostic(jsx.span));
+                if let Some(danger_span) = find_jsx_prop(ctx, node, jsx, "dangerouslySetInnerHTML")
+                {
+                    ctx.diagnostic(no_danger_with_children_diagnostic(danger_span, children_span));
                 }
             }
             AstKind::CallExpression(call_expr) => {
@@ -87,41 +103,27 @@ impl Rule for NoDangerWithChildren {
                     return;
                 };
 
-                // If there are three arguments, then it is a JSX element with children.
-                // If it's just two arguments, it only has children if the props object has a children property.
-                let has_children = if call_expr.arguments.len() == 2 {
-                    match props {
-                        Expression::ObjectExpression(obj_expr) => {
-                            is_object_with_prop_name(&obj_expr.properties, "children")
-                        }
-                        Expression::Identifier(ident) => {
-                            does_object_var_have_prop_name(ctx, node, ident.name, "children")
-                        }
-                        _ => false,
+                let find_prop = |prop_name| match props {
+                    Expression::ObjectExpression(obj_expr) => {
+                        find_object_prop(&obj_expr.properties, prop_name)
                     }
-                } else {
-                    true
+                    Expression::Identifier(ident) => {
+                        does_object_var_have_prop_name(ctx, node, ident.name, prop_name)
+                            .then_some(ident.span)
+                    }
+                    _ => None,
                 };
 
-                if !has_children {
+                // If there are three arguments, then it is a JSX element with children.
+                // If it's just two arguments, it only has children if the props object has a children property.
+                let Some(children_span) =
+                    call_expr.arguments.get(2).map(GetSpan::span).or_else(|| find_prop("children"))
+                else {
                     return;
-                }
-
-                let has_danger_prop = match props {
-                    Expression::ObjectExpression(obj_expr) => {
-                        is_object_with_prop_name(&obj_expr.properties, "dangerouslySetInnerHTML")
-                    }
-                    Expression::Identifier(ident) => does_object_var_have_prop_name(
-                        ctx,
-                        node,
-                        ident.name,
-                        "dangerouslySetInnerHTML",
-                    ),
-                    _ => false,
                 };
 
-                if has_danger_prop && has_children {
-                    ctx.diagnostic(no_danger_with_children_diagnostic(call_expr.span));
+                if let Some(danger_span) = find_prop("dangerouslySetInnerHTML") {
+                    ctx.diagnostic(no_danger_with_children_diagnostic(danger_span, children_span));
                 }
             }
             _ => (),
@@ -168,6 +170,17 @@ fn test() {
         const props = {...props, scratch: {mode: 'edit'}};
         const component = shallow(<TaskEditableTitle {...props} />);
         ",
+        r#"<Hello dangerouslySetInnerHTML={{ __html: "HTML" }}> </Hello>"#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "HTML" }}>{/* comment */}</div>"#,
+        r#"
+        <div dangerouslySetInnerHTML={{ __html: "HTML" }}>
+            {/* comment */}
+        </div>
+        "#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "HTML" }} {...{ className: "foo" }} />"#,
+        r#"<div {...{ dangerouslySetInnerHTML: { __html: "HTML" } }} />"#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "HTML" }} {...getProps()} />"#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "HTML" }} {...{ [children]: "Children" }} />"#,
     ];
 
     let fail = vec![
@@ -191,7 +204,6 @@ fn test() {
         </Hello>
         "#,
         r#"<Hello dangerouslySetInnerHTML={{ __html: "HTML" }} children="Children" />"#,
-        r#"<Hello dangerouslySetInnerHTML={{ __html: "HTML" }}> </Hello>"#,
         r#"
         React.createElement(
             "div",
@@ -238,44 +250,42 @@ fn test() {
         const props = { ...otherProps, dangerouslySetInnerHTML: { __html: "HTML" } };
         React.createElement("div", props);
         "#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "HTML" }} {...{ children: "Children" }} />"#,
+        r#"<div {...{ dangerouslySetInnerHTML: { __html: "HTML" } }}>Children</div>"#,
+        r#"<div {...{ dangerouslySetInnerHTML: { __html: "HTML" }, "children": "Children" }} />"#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "HTML" }}>{/* comment */}{children}</div>"#,
     ];
 
     Tester::new(NoDangerWithChildren::NAME, NoDangerWithChildren::PLUGIN, pass, fail)
         .test_and_snapshot();
 }
 
-fn is_whitespace(s: &str) -> bool {
-    s.chars().all(char::is_whitespace)
-}
-
-fn is_line_break(child: &JSXChild) -> bool {
-    let JSXChild::Text(text) = child else {
-        return false;
-    };
-    let is_multi_line = text.value.contains('\n');
-    is_multi_line && is_whitespace(text.value.as_str())
-}
-
-/// Given a JSX element, find the JSXAttributeItem with the given name.
-/// If there are spread props, it will search within those as well.
-fn has_jsx_prop(ctx: &LintContext, node: &AstNode, prop_name: &'static str) -> bool {
-    let AstKind::JSXElement(jsx) = node.kind() else {
-        return false;
-    };
-
-    jsx.opening_element.attributes.iter().any(|attr| match attr {
+/// Given a JSX element, find the span of the prop with the given name.
+/// Spread props are searched as well, when spreading an object literal or a variable
+/// initialized with one.
+fn find_jsx_prop(
+    ctx: &LintContext,
+    node: &AstNode,
+    jsx: &JSXElement,
+    prop_name: &'static str,
+) -> Option<Span> {
+    jsx.opening_element.attributes.iter().find_map(|attr| match attr {
         JSXAttributeItem::Attribute(attr) => {
             let JSXAttributeName::Identifier(ident) = &attr.name else {
-                return false;
-            };
-            ident.name == prop_name
-        }
-        JSXAttributeItem::SpreadAttribute(attr) => {
-            let Some(ident) = attr.argument.get_identifier_reference() else {
-                return false;
+                return None;
             };
-            does_object_var_have_prop_name(ctx, node, ident.name, prop_name)
+            (ident.name == prop_name).then_some(attr.span)
         }
+        JSXAttributeItem::SpreadAttribute(attr) => match attr.argument.get_inner_expression() {
+            Expression::ObjectExpression(obj_expr) => {
+                find_object_prop(&obj_expr.properties, prop_name)
+            }
+            Expression::Identifier(ident) => {
+                does_object_var_have_prop_name(ctx, node, ident.name, prop_name)
+                    .then_some(attr.span)
+            }
+            _ => None,
+        },
     })
 }
 
@@ -335,15 +345,15 @@ fn find_var_in_scope<'c>(
         .map(|symbol_id| ctx.semantic().symbol_declaration(symbol_id))
 }
 
-/// Returns whether a given object has a property with the given name.
-fn is_object_with_prop_name(
+/// Returns the span of the property with the given name in an object literal.
+fn find_object_prop(
     obj_props: &ArenaVec<'_, ObjectPropertyKind<'_>>,
     prop_name: &str,
-) -> bool {
-    obj_props.iter().any(|prop| {
+) -> Option<Span> {
+    obj_props.iter().find_map(|prop| {
         let ObjectPropertyKind::ObjectProperty(obj_prop) = prop else {
-            return false;
+            return None;
         };
-        obj_prop.key.static_name().is_some_and(|key| key == prop_name)
+        obj_prop.key.static_name().is_some_and(|key| key == prop_name).then_some(obj_prop.span)
     })
 }
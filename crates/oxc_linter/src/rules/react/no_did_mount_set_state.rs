@@ -8,7 +8,6 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::{DefaultRuleConfig, Rule},
-    rules::ContextHost,
     utils::{AllowedOrDisallowInFunc, function_count_before_lifecycle_component},
 };
 
@@ -69,6 +68,7 @@ declare_oxc_lint!(
     react,
     correctness,
     config = AllowedOrDisallowInFunc,
+    language = jsx,
     version = "1.36.0",
     short_description = "Disallow usage of `setState` in `componentDidMount`.",
 );
@@ -103,10 +103,6 @@ impl Rule for NoDidMountSetState {
 
         ctx.diagnostic(no_did_mount_set_state_diagnostic(call_expr.callee.span()));
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -6,7 +6,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     AstNode,
     ast_util::get_outer_member_expression,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::Rule,
     utils::{is_es5_component, is_es6_component, is_state_member_expression},
 };
@@ -91,6 +91,7 @@ declare_oxc_lint!(
     NoDirectMutationState,
     react,
     correctness,
+    language = jsx,
     version = "0.2.0",
     short_description = "Disallow direct mutation of `this.state`.",
 );
@@ -129,10 +130,6 @@ impl Rule for NoDirectMutationState {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 fn should_ignore_component<'a, 'b>(node: &'b AstNode<'a>, ctx: &'b LintContext<'a>) -> bool {
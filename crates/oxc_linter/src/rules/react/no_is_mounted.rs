@@ -3,11 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_is_mounted_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Do not use `isMounted`.")
@@ -47,6 +43,7 @@ declare_oxc_lint!(
     NoIsMounted,
     react,
     correctness,
+    language = jsx,
     version = "0.0.19",
     short_description = "This rule prevents using `isMounted` in class components.",
 );
@@ -73,10 +70,6 @@ impl Rule for NoIsMounted {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -17,7 +17,6 @@ use serde::{Deserialize, Serialize};
 use crate::{
     context::LintContext,
     rule::{DefaultRuleConfig, Rule},
-    rules::ContextHost,
     utils::{
         expression_contains_jsx, function_body_contains_jsx, function_contains_jsx, is_hoc_call,
         is_react_component_name,
@@ -82,6 +81,7 @@ declare_oxc_lint!(
     restriction,
     none,
     config = NoMultiComp,
+    language = jsx,
     version = "1.43.0",
     short_description = "Prevents multiple React components from being defined in the same file.",
 );
@@ -110,10 +110,6 @@ impl Rule for NoMultiComp {
             ctx.diagnostic(no_multi_comp_diagnostic(&component.name, component.span));
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 /// Visitor that finds React components while tracking nesting depth.
@@ -6,7 +6,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     AstNode,
     ast_util::outermost_paren_parent,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::Rule,
     utils::{is_hoc_call, is_react_component_name},
 };
@@ -73,6 +73,7 @@ declare_oxc_lint!(
     NoObjectTypeAsDefaultProp,
     react,
     perf,
+    language = jsx,
     version = "1.66.0",
     short_description = "Disallows using object, array, function, class, regex, JSX, or `new`-constructed values as default values for destructured React component props.",
 );
@@ -90,10 +91,6 @@ impl Rule for NoObjectTypeAsDefaultProp {
 
         ctx.diagnostic(no_object_type_as_default_prop_diagnostic(kind, right.span()));
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -3,11 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_render_return_value_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Do not depend on the return value from `ReactDOM.render`.")
@@ -49,6 +45,7 @@ declare_oxc_lint!(
     NoRenderReturnValue,
     react,
     correctness,
+    language = jsx,
     version = "0.0.15",
     short_description = "This rule will warn you if you try to use the `ReactDOM.render()` return value.",
 );
@@ -89,10 +86,6 @@ impl Rule for NoRenderReturnValue {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -3,12 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-    utils::get_parent_component,
-};
+use crate::{AstNode, context::LintContext, rule::Rule, utils::get_parent_component};
 
 fn no_set_state_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Do not use `setState`.").with_label(span)
@@ -52,6 +47,7 @@ declare_oxc_lint!(
     NoSetState,
     react,
     style,
+    language = jsx,
     version = "0.5.2",
     short_description = "Disallow the usage of `this.setState` in React components.",
 );
@@ -75,10 +71,6 @@ impl Rule for NoSetState {
 
         ctx.diagnostic(no_set_state_diagnostic(call_expr.callee.span()));
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -13,7 +13,7 @@ use serde::Deserialize;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::get_parent_component,
 };
@@ -86,6 +86,7 @@ declare_oxc_lint!(
     react,
     correctness,
     config = NoStringRefs,
+    language = jsx,
     version = "0.0.15",
     short_description = "This rule prevents using the deprecated behavior of string literals in ref attributes.",
 );
@@ -144,10 +145,6 @@ impl Rule for NoStringRefs {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -65,6 +65,7 @@ declare_oxc_lint!(
     NoThisInSfc,
     react,
     correctness,
+    language = jsx,
     version = "1.37.0",
     short_description = "Prevents using `this` in stateless functional components.",
 );
@@ -97,10 +98,6 @@ impl Rule for NoThisInSfc {
 
         ctx.diagnostic(no_this_in_sfc_diagnostic(this_expr.span));
     }
-
-    fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 fn get_parent_function<'a, 'b>(
@@ -3,11 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 static ESCAPED_DOUBLE_QUOTE: &str = "&quot; or &ldquo; or &#34; or &rdquo;";
 static ESCAPED_SINGLE_QUOTE: &str = "&apos; or &lsquo; or &#39; or &rsquo;";
@@ -49,6 +45,7 @@ declare_oxc_lint!(
     react,
     pedantic,
     pending,
+    language = jsx,
     version = "0.0.15",
     short_description = "This rule prevents characters that you may have meant as JSX escape characters from being accidentally injected as a text node in JSX statements.",
 );
@@ -69,10 +66,6 @@ impl Rule for NoUnescapedEntities {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
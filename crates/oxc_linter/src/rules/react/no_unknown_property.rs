@@ -16,7 +16,7 @@ use serde::Deserialize;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     globals::is_valid_aria_property,
     rule::{DefaultRuleConfig, Rule},
     utils::get_jsx_attribute_name,
@@ -95,6 +95,7 @@ declare_oxc_lint!(
     restriction,
     suggestion,
     config = NoUnknownPropertyConfig,
+    language = jsx,
     version = "0.2.0",
     short_description = "Disallow usage of unknown DOM properties.",
 );
@@ -577,10 +578,6 @@ impl Rule for NoUnknownProperty {
                     );
             });
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
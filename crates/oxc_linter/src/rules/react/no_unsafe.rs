@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     AstNode,
     config::ReactVersion,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::{get_parent_component, is_es5_component},
 };
@@ -87,6 +87,7 @@ declare_oxc_lint!(
     react,
     correctness,
     config = NoUnsafeConfig,
+    language = jsx,
     version = "1.35.0",
     short_description = "This rule identifies and restricts the use of unsafe React lifecycle methods.",
 );
@@ -124,10 +125,6 @@ impl Rule for NoUnsafe {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 /// Check if a method name is an unsafe lifecycle method
@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::{
         expression_contains_jsx, function_body_contains_jsx, function_contains_jsx,
@@ -112,6 +112,7 @@ declare_oxc_lint!(
     suspicious,
     none,
     config = NoUnstableNestedComponentsConfig,
+    language = jsx,
     version = "1.66.0",
     short_description = "Disallows defining React components inside other components.",
 );
@@ -148,10 +149,6 @@ impl Rule for NoUnstableNestedComponents {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -9,7 +9,6 @@ use crate::{
     config::ReactVersion,
     context::LintContext,
     rule::{DefaultRuleConfig, Rule},
-    rules::ContextHost,
     utils::{AllowedOrDisallowInFunc, is_es5_component, is_es6_component},
 };
 
@@ -63,6 +62,7 @@ declare_oxc_lint!(
     react,
     correctness,
     config = AllowedOrDisallowInFunc,
+    language = jsx,
     version = "1.37.0",
     short_description = "Disallows using `setState` in the `componentWillUpdate` lifecycle method.",
 );
@@ -152,10 +152,6 @@ impl Rule for NoWillUpdateSetState {
 
         ctx.diagnostic(no_will_update_set_state_diagnostic(call_expr.callee.span()));
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -6,7 +6,7 @@ use serde::Deserialize;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::{AlwaysNever, is_es5_component, is_es6_component},
 };
@@ -51,6 +51,7 @@ declare_oxc_lint!(
     react,
     style,
     config = AlwaysNever,
+    language = jsx,
     version = "0.5.0",
     short_description = "Enforce consistent React class style, preferring ES2015 classes over `createReactClass`.",
 );
@@ -77,10 +78,6 @@ impl Rule for PreferEs6Class {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -11,7 +11,7 @@ use oxc_span::Span;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::{expression_contains_jsx, function_contains_jsx, is_es6_component},
 };
@@ -87,6 +87,7 @@ declare_oxc_lint!(
     react,
     restriction,
     config = PreferFunctionComponent,
+    language = jsx,
     version = "1.59.0",
     short_description = "Enforces that React components are written as function components instead of class components.",
 );
@@ -120,10 +121,6 @@ impl Rule for PreferFunctionComponent {
         let span = class.id.as_ref().map_or(class.span, |id| id.span);
         ctx.diagnostic(prefer_function_component_diagnostic(span));
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 /// Returns `true` if the class has a `componentDidCatch` method or a
@@ -4,11 +4,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_str::static_ident;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn react_in_jsx_scope_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("`React` must be in scope when using JSX.")
@@ -55,6 +51,7 @@ declare_oxc_lint!(
     ReactInJsxScope,
     react,
     suspicious,
+    language = jsx,
     version = "0.0.20",
     short_description = "Enforce that React is in scope when using JSX.",
 );
@@ -75,10 +72,6 @@ impl Rule for ReactInJsxScope {
             ctx.diagnostic(react_in_jsx_scope_diagnostic(node_span));
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -9,7 +9,7 @@ use oxc_span::{GetSpan, Span};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::Rule,
     utils::{is_es5_component, is_es6_component},
 };
@@ -70,6 +70,7 @@ declare_oxc_lint!(
     RequireRenderReturn,
     react,
     nursery,
+    language = jsx,
     version = "0.2.0",
     short_description = "Require render methods in ES5 and ES2015 React components to return a value.",
 );
@@ -104,10 +105,6 @@ impl Rule for RequireRenderReturn {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -788,10 +788,9 @@ fn check_use_effect_event_usage(
                 return;
             }
             if let Some(ident) = decl.id.get_binding_identifier() {
-                let additional_effect_hooks = additional_effect_hooks(ctx);
                 report_invalid_use_effect_event_references(
                     ident.symbol_id(),
-                    additional_effect_hooks.as_ref(),
+                    ctx.settings().react_hooks.additional_effect_hooks.as_ref(),
                     ctx,
                 );
             }
@@ -802,12 +801,6 @@ fn check_use_effect_event_usage(
     }
 }
 
-fn additional_effect_hooks(ctx: &LintContext<'_>) -> Option<Regex> {
-    let pattern =
-        ctx.settings().json.as_ref()?.get("react-hooks")?.get("additionalEffectHooks")?.as_str()?;
-    Regex::new(pattern).ok()
-}
-
 fn report_invalid_use_effect_event_references(
     symbol_id: SymbolId,
     additional_effect_hooks: Option<&Regex>,
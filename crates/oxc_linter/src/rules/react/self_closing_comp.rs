@@ -10,9 +10,10 @@ use serde::Deserialize;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
-    globals::HTML_TAG,
+    context::LintContext,
+    globals::is_dom_element,
     rule::{DefaultRuleConfig, Rule},
+    utils::has_jsx_prop,
 };
 
 fn self_closing_comp_diagnostic(span: Span) -> OxcDiagnostic {
@@ -49,7 +50,9 @@ declare_oxc_lint!(
     /// It also follows common React and JSX conventions for empty elements.
     ///
     /// A self-closing component which contains whitespace is allowed except
-    /// when it also contains a newline.
+    /// when it also contains a newline. Elements with a `dangerouslySetInnerHTML`
+    /// prop are ignored, since their content is set by the prop, and fragments
+    /// can't be self-closing.
     ///
     /// ### Examples
     ///
@@ -73,6 +76,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = SelfClosingComp,
+    language = jsx,
     version = "0.9.3",
     short_description = "Detects components without children which can be self-closed to avoid unnecessary extra closing tags.",
 );
@@ -111,6 +115,10 @@ impl Rule for SelfClosingComp {
             return;
         };
 
+        if has_jsx_prop(&jsx_el.opening_element, "dangerouslySetInnerHTML").is_some() {
+            return;
+        }
+
         let is_comp = matches!(
             jsx_el.opening_element.name,
             JSXElementName::MemberExpression(_) | JSXElementName::NamespacedName(_)
@@ -118,7 +126,7 @@ impl Rule for SelfClosingComp {
 
         let mut is_dom_comp = false;
         if !is_comp && let Some(tag_name) = jsx_el.opening_element.name.get_identifier_name() {
-            is_dom_comp = HTML_TAG.contains(tag_name.as_str());
+            is_dom_comp = is_dom_element(tag_name.as_str());
         }
 
         if self.html && is_dom_comp || self.component && !is_dom_comp {
@@ -130,10 +138,6 @@ impl Rule for SelfClosingComp {
             });
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -247,6 +251,12 @@ fn test() {
 			      "#,
             Some(serde_json::json!([{ "html": true }])),
         ),
+        ("var HelloJohn = <></>;", None),
+        (
+            "var HelloJohn = <div dangerouslySetInnerHTML={{ __html: html }}></div>;",
+            Some(serde_json::json!([{ "html": true }])),
+        ),
+        ("var HelloJohn = <Hello dangerouslySetInnerHTML={{ __html: html }}></Hello>;", None),
     ];
 
     let fail = vec![
@@ -288,6 +298,13 @@ fn test() {
 			</div>;"#,
             Some(serde_json::json!([{ "html": true }])),
         ),
+        (
+            r#"var HelloJohn = <Hello
+			  name="John"
+			  {...props}
+			></Hello>;"#,
+            None,
+        ),
     ];
 
     let fix = vec![
@@ -356,6 +373,17 @@ fn test() {
             r#"var contentContainer = <div className="content" />;"#,
             Some(serde_json::json!([{ "html": true }])),
         ),
+        (
+            r#"var HelloJohn = <Hello
+			  name="John"
+			  {...props}
+			></Hello>;"#,
+            r#"var HelloJohn = <Hello
+			  name="John"
+			  {...props}
+			 />;"#,
+            None,
+        ),
     ];
 
     Tester::new(SelfClosingComp::NAME, SelfClosingComp::PLUGIN, pass, fail)
@@ -15,7 +15,7 @@ use serde::Deserialize;
 use crate::{
     AstNode,
     ast_util::get_declaration_of_variable,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::is_create_element_call,
 };
@@ -84,6 +84,7 @@ declare_oxc_lint!(
     react,
     suspicious,
     config = StylePropObjectConfig,
+    language = jsx,
     version = "0.11.0",
     short_description = "Require that the value of the prop `style` be an object or a variable that is an object.",
 );
@@ -221,10 +222,6 @@ impl Rule for StylePropObject {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -1,24 +1,21 @@
 use oxc_ast::{
     AstKind,
-    ast::{
-        Argument, JSXAttributeItem, JSXAttributeName, JSXElementName, ObjectPropertyKind,
-        PropertyKey,
-    },
+    ast::{Argument, JSXAttributeItem, JSXAttributeName, JSXElementName, ObjectPropertyKind},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
+    globals::dom_element,
     rule::Rule,
-    utils::is_create_element_call,
+    utils::{first_jsx_child_span, is_create_element_call},
 };
 
 fn void_dom_elements_no_children_diagnostic(tag: &str, span: Span) -> OxcDiagnostic {
-    // TODO: use imperative phrasing
-    OxcDiagnostic::warn(format!("Void DOM element <{tag:?} /> cannot receive children."))
+    OxcDiagnostic::warn(format!("Void DOM element `<{tag} />` cannot receive children."))
         .with_help("Remove this element's children or use a non-void element.")
         .with_label(span)
 }
@@ -31,6 +28,9 @@ declare_oxc_lint!(
     ///
     /// Disallow void DOM elements (e.g. `<img />`, `<br />`) from receiving children.
     ///
+    /// Children are either nested between the tags, ignoring whitespace-only text, or passed
+    /// with the `children` or `dangerouslySetInnerHTML` props.
+    ///
     /// ### Why is this bad?
     ///
     /// There are some HTML elements that are only self-closing (e.g. img, br, hr). These are collectively known as void DOM elements.
@@ -60,17 +60,17 @@ declare_oxc_lint!(
     VoidDomElementsNoChildren,
     react,
     correctness,
+    language = jsx,
     version = "0.2.11",
     short_description = "Disallow void DOM elements (e.g. `<img />`, `<br />`) from receiving children.",
 );
 
-const VOID_DOM_ELEMENTS: [&str; 16] = [
-    "area", "base", "br", "col", "embed", "hr", "img", "input", "keygen", "link", "menuitem",
-    "meta", "param", "source", "track", "wbr",
-];
+fn is_void_dom_element(name: &str) -> bool {
+    dom_element(name).is_some_and(|el| el.void)
+}
 
-pub fn is_void_dom_element(element_name: &str) -> bool {
-    VOID_DOM_ELEMENTS.contains(&element_name)
+fn is_children_prop(name: &str) -> bool {
+    name == "children" || name == "dangerouslySetInnerHTML"
 }
 
 impl Rule for VoidDomElementsNoChildren {
@@ -86,21 +86,25 @@ impl Rule for VoidDomElementsNoChildren {
                     return;
                 }
 
-                let has_children_attribute_or_danger =
-                    jsx_opening_el.attributes.iter().any(|attribute| match attribute {
-                        JSXAttributeItem::Attribute(attr) => {
-                            let JSXAttributeName::Identifier(iden) = &attr.name else {
-                                return false;
-                            };
-                            iden.name == "children" || iden.name == "dangerouslySetInnerHTML"
-                        }
-                        JSXAttributeItem::SpreadAttribute(_) => false,
-                    });
+                for attribute in &jsx_opening_el.attributes {
+                    let JSXAttributeItem::Attribute(attr) = attribute else {
+                        continue;
+                    };
+                    let JSXAttributeName::Identifier(iden) = &attr.name else {
+                        continue;
+                    };
+                    if is_children_prop(&iden.name) {
+                        ctx.diagnostic(void_dom_elements_no_children_diagnostic(
+                            &identifier.name,
+                            attr.span,
+                        ));
+                    }
+                }
 
-                if !jsx_el.children.is_empty() || has_children_attribute_or_danger {
+                if let Some(span) = first_jsx_child_span(jsx_el) {
                     ctx.diagnostic(void_dom_elements_no_children_diagnostic(
                         &identifier.name,
-                        identifier.span,
+                        span,
                     ));
                 }
             }
@@ -109,10 +113,6 @@ impl Rule for VoidDomElementsNoChildren {
                     return;
                 }
 
-                if call_expr.arguments.is_empty() {
-                    return;
-                }
-
                 let Some(Argument::StringLiteral(element_name)) = call_expr.arguments.first()
                 else {
                     return;
@@ -122,39 +122,30 @@ impl Rule for VoidDomElementsNoChildren {
                     return;
                 }
 
-                if call_expr.arguments.len() < 2 {
-                    return;
+                if let Some(Argument::ObjectExpression(obj_expr)) = call_expr.arguments.get(1) {
+                    for property in &obj_expr.properties {
+                        let ObjectPropertyKind::ObjectProperty(prop) = property else {
+                            continue;
+                        };
+                        if prop.key.static_name().is_some_and(|name| is_children_prop(&name)) {
+                            ctx.diagnostic(void_dom_elements_no_children_diagnostic(
+                                &element_name.value,
+                                prop.span,
+                            ));
+                        }
+                    }
                 }
 
-                let Some(Argument::ObjectExpression(obj_expr)) = call_expr.arguments.get(1) else {
-                    return;
-                };
-
-                let has_children_prop_or_danger =
-                    obj_expr.properties.iter().any(|property| match property {
-                        ObjectPropertyKind::ObjectProperty(prop) => match &prop.key {
-                            PropertyKey::StaticIdentifier(iden) => {
-                                iden.name == "children" || iden.name == "dangerouslySetInnerHTML"
-                            }
-                            _ => false,
-                        },
-                        ObjectPropertyKind::SpreadProperty(_) => false,
-                    });
-
-                if call_expr.arguments.get(2).is_some() || has_children_prop_or_danger {
+                if let Some(child) = call_expr.arguments.get(2) {
                     ctx.diagnostic(void_dom_elements_no_children_diagnostic(
                         &element_name.value,
-                        element_name.span,
+                        child.span(),
                     ));
                 }
             }
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
-    }
 }
 
 #[test]
@@ -170,6 +161,16 @@ fn test() {
         (r"React.createElement('div', { dangerouslySetInnerHTML: { __html: 'Foo' } });", None),
         (r"React.createElement('img');", None),
         (r"React.createElement();", None),
+        (r"<br></br>;", None),
+        (r"<br> </br>;", None),
+        (
+            r"<img>
+            </img>;",
+            None,
+        ),
+        (r"<Foo>Children</Foo>;", None),
+        (r"<foo.br>Children</foo.br>;", None),
+        (r"React.createElement(tag, {}, 'Foo');", None),
         (
             r"
                 const props = {};
@@ -213,6 +214,12 @@ fn test() {
         (r"<img {...props} children='Foo' />;", None),
         (r"<br dangerouslySetInnerHTML={{ __html: 'Foo' }} />;", None),
         (r"React.createElement('br', {}, 'Foo');", None),
+        (r"React.createElement('br', undefined, 'Foo');", None),
+        (r"React.createElement('img', { 'children': 'Foo' });", None),
+        (r"<br> Foo </br>;", None),
+        (r"<img>{foo}</img>;", None),
+        (r"<input children='Foo' dangerouslySetInnerHTML={{ __html: 'Foo' }} />;", None),
+        (r"<hr children='Foo'>Bar</hr>;", None),
         (r"React.createElement('br', { children: 'Foo' });", None),
         (r"React.createElement('br', { dangerouslySetInnerHTML: { __html: 'Foo' } });", None),
         (
@@ -1 +1,2 @@
 pub mod no_negated_condition;
+pub mod swap_branches;
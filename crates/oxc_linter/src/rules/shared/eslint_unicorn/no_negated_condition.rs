@@ -17,6 +17,10 @@ use crate::{
     fixer::{RuleFix, RuleFixer},
 };
 
+use super::swap_branches::{
+    Branch, BranchWrap, conditional_expression_branches, if_statement_branches, swap_branches,
+};
+
 fn no_negated_condition_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected negated condition.")
         .with_help("Remove the negation operator and switch the consequent and alternate branches.")
@@ -57,11 +61,11 @@ a ? doSomethingB() : doSomethingC()
 ";
 
 pub fn run_on_if_statement<'a>(if_stmt: &IfStatement<'a>, ctx: &LintContext<'a>) {
-    let Some(alternate) = &if_stmt.alternate else {
-        return;
-    };
-
-    if matches!(alternate, Statement::IfStatement(_)) {
+    if if_stmt
+        .alternate
+        .as_ref()
+        .is_none_or(|alternate| matches!(alternate, Statement::IfStatement(_)))
+    {
         return;
     }
 
@@ -71,7 +75,7 @@ pub fn run_on_if_statement<'a>(if_stmt: &IfStatement<'a>, ctx: &LintContext<'a>)
     }
 
     ctx.diagnostic_with_fix(no_negated_condition_diagnostic(test.span()), |fixer| {
-        fix_if_statement(fixer, if_stmt, alternate, test, ctx)
+        fix_if_statement(fixer, if_stmt, test, ctx)
     });
 }
 
@@ -106,16 +110,20 @@ fn is_negated_expression(expr: &Expression) -> bool {
 fn fix_if_statement<'a>(
     fixer: RuleFixer<'_, 'a>,
     if_stmt: &IfStatement<'a>,
-    alternate: &Statement<'a>,
     negated_test: &Expression<'a>,
     ctx: &LintContext<'a>,
 ) -> RuleFix {
+    let Some((consequent, alternate)) =
+        if_statement_branches(if_stmt, ctx.comments(), ctx.source_text())
+    else {
+        return fixer.noop();
+    };
     let fixer = fixer.for_multifix();
     // invert test (1–2) + swap branches (2) — capacity 4 is enough
     let mut fixes = fixer.new_fix_with_capacity(4);
 
     push_invert_test_fixes(&mut fixes, &fixer, negated_test, ctx);
-    push_swap_statement_branches(&mut fixes, &fixer, &if_stmt.consequent, alternate, ctx);
+    push_swap_branches(&mut fixes, &fixer, consequent, alternate, ctx);
 
     fixes.with_message(
         "Remove the negation operator and switch the consequent and alternate branches.",
@@ -129,6 +137,16 @@ fn fix_conditional_expression<'a>(
     negated_test: &Expression<'a>,
     ctx: &LintContext<'a>,
 ) -> RuleFix {
+    let Some((mut consequent, alternate)) =
+        conditional_expression_branches(conditional_expr, ctx.comments(), ctx.source_text())
+    else {
+        return fixer.noop();
+    };
+    if is_for_statement_init(node, conditional_expr, ctx)
+        && contains_unparenthesized_in_expression(&conditional_expr.consequent)
+    {
+        consequent.wrap = BranchWrap::Parentheses;
+    }
     let fixer = fixer.for_multifix();
     let mut fixes = fixer.new_fix_with_capacity(10);
 
@@ -192,15 +210,7 @@ fn fix_conditional_expression<'a>(
     }
 
     push_invert_test_fixes(&mut fixes, &fixer, negated_test, ctx);
-    push_swap_expression_branches(
-        &mut fixes,
-        &fixer,
-        node,
-        conditional_expr,
-        &conditional_expr.consequent,
-        &conditional_expr.alternate,
-        ctx,
-    );
+    push_swap_branches(&mut fixes, &fixer, consequent, alternate, ctx);
 
     fixes.with_message(
         "Remove the negation operator and switch the consequent and alternate branches.",
@@ -237,65 +247,16 @@ fn push_invert_test_fixes<'a>(
     }
 }
 
-fn push_swap_statement_branches<'a>(
+fn push_swap_branches<'a>(
     fixes: &mut RuleFix,
     fixer: &RuleFixer<'_, 'a>,
-    consequent: &Statement<'a>,
-    alternate: &Statement<'a>,
+    consequent: Branch,
+    alternate: Branch,
     ctx: &LintContext<'a>,
 ) {
-    let cons_span = consequent.span();
-    let alt_span = alternate.span();
-    let cons_src = ctx.source_range(cons_span);
-    let alt_src = ctx.source_range(alt_span);
-
-    // Identical branches: only the test needs changing.
-    if cons_src == alt_src
-        && matches!(consequent, Statement::BlockStatement(_))
-            == matches!(alternate, Statement::BlockStatement(_))
-    {
-        return;
+    for (span, text) in swap_branches(ctx.source_text(), consequent, alternate) {
+        fixes.push(fixer.replace(span, text));
     }
-
-    let cons_is_block = matches!(consequent, Statement::BlockStatement(_));
-    let alt_is_block = matches!(alternate, Statement::BlockStatement(_));
-
-    // Non-block branches must be wrapped after the swap so ASI / dangling `else` stay valid.
-    let new_cons = if alt_is_block { alt_src.to_string() } else { format!("{{{alt_src}}}") };
-    let new_alt = if cons_is_block { cons_src.to_string() } else { format!("{{{cons_src}}}") };
-
-    fixes.push(fixer.replace(cons_span, new_cons));
-    fixes.push(fixer.replace(alt_span, new_alt));
-}
-
-fn push_swap_expression_branches<'a>(
-    fixes: &mut RuleFix,
-    fixer: &RuleFixer<'_, 'a>,
-    node: &AstNode<'a>,
-    conditional_expr: &ConditionalExpression<'a>,
-    consequent: &Expression<'a>,
-    alternate: &Expression<'a>,
-    ctx: &LintContext<'a>,
-) {
-    let cons_span = consequent.span();
-    let alt_span = alternate.span();
-    let cons_src = ctx.source_range(cons_span);
-    let alt_src = ctx.source_range(alt_span);
-
-    if cons_src == alt_src {
-        return;
-    }
-
-    // Must own the replacement strings because both edits reference the other branch's text.
-    fixes.push(fixer.replace(cons_span, alt_src.to_string()));
-    let new_alt = if is_for_statement_init(node, conditional_expr, ctx)
-        && contains_unparenthesized_in_expression(consequent)
-    {
-        format!("({cons_src})")
-    } else {
-        cons_src.to_string()
-    };
-    fixes.push(fixer.replace(alt_span, new_alt));
 }
 
 fn is_restricted_statement_or_yield_argument(node: &AstNode, ctx: &LintContext) -> bool {
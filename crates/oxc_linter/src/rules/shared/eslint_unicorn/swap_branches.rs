@@ -0,0 +1,217 @@
+use oxc_ast::{
+    Comment,
+    ast::{ConditionalExpression, IfStatement, Statement},
+    comments_range, has_comments_between, is_inside_comment,
+};
+use oxc_span::{GetSpan, Span};
+
+/// How the text of a branch is wrapped when it is moved to the other branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BranchWrap {
+    None,
+    /// A non-block statement is wrapped in a block, so that ASI and dangling `else`s keep
+    /// their meaning.
+    Block,
+    Parentheses,
+}
+
+/// A branch of an `if` statement or of a conditional expression, with the comments that are
+/// moved along with it when the branches are swapped.
+///
+/// Comments between the consequent and the `else` or `:` belong to the consequent, and comments
+/// between the `else` or `:` and the alternate belong to the alternate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Branch {
+    /// The branch itself.
+    pub span: Span,
+    /// The branch and its comments, which is replaced by the other branch.
+    pub region: Span,
+    pub wrap: BranchWrap,
+    /// Whether the region ends with a line comment, which must be followed by a line break
+    /// when moved.
+    pub ends_with_line_comment: bool,
+}
+
+/// The branches of an `if` statement with an `else` branch.
+///
+/// Returns `None` if the `if` statement has no `else` branch, if the `else` branch is another
+/// `if` statement, since `else if` chains can't be swapped without rewriting the chain, or if
+/// there are comments between the condition and the consequent.
+pub fn if_statement_branches(
+    if_stmt: &IfStatement,
+    comments: &[Comment],
+    source_text: &str,
+) -> Option<(Branch, Branch)> {
+    let alternate = if_stmt.alternate.as_ref()?;
+    if matches!(alternate, Statement::IfStatement(_)) {
+        return None;
+    }
+    let wrap = |stmt: &Statement| {
+        if matches!(stmt, Statement::BlockStatement(_)) {
+            BranchWrap::None
+        } else {
+            BranchWrap::Block
+        }
+    };
+    find_branches(
+        if_stmt.test.span(),
+        (if_stmt.consequent.span(), wrap(&if_stmt.consequent)),
+        (alternate.span(), wrap(alternate)),
+        "else",
+        comments,
+        source_text,
+    )
+}
+
+/// The branches of a conditional expression.
+///
+/// Returns `None` if there are comments between the condition and the consequent.
+pub fn conditional_expression_branches(
+    conditional_expr: &ConditionalExpression,
+    comments: &[Comment],
+    source_text: &str,
+) -> Option<(Branch, Branch)> {
+    find_branches(
+        conditional_expr.test.span(),
+        (conditional_expr.consequent.span(), BranchWrap::None),
+        (conditional_expr.alternate.span(), BranchWrap::None),
+        ":",
+        comments,
+        source_text,
+    )
+}
+
+fn find_branches(
+    test: Span,
+    (consequent, consequent_wrap): (Span, BranchWrap),
+    (alternate, alternate_wrap): (Span, BranchWrap),
+    separator: &str,
+    comments: &[Comment],
+    source_text: &str,
+) -> Option<(Branch, Branch)> {
+    if has_comments_between(comments, Span::new(test.end, consequent.start)) {
+        return None;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    let separator_start = source_text[consequent.end as usize..alternate.start as usize]
+        .match_indices(separator)
+        .map(|(offset, _)| consequent.end + offset as u32)
+        .find(|&start| !is_inside_comment(comments, start))?;
+    #[expect(clippy::cast_possible_truncation)]
+    let separator_end = separator_start + separator.len() as u32;
+
+    let trailing_comment =
+        comments_range(comments, consequent.end..separator_start).next_back().copied();
+    let leading_comment = comments_range(comments, separator_end..alternate.start).next().copied();
+    let consequent = Branch {
+        span: consequent,
+        region: Span::new(
+            consequent.start,
+            trailing_comment.map_or(consequent.end, |c| c.span.end),
+        ),
+        wrap: consequent_wrap,
+        ends_with_line_comment: trailing_comment.is_some_and(Comment::is_line),
+    };
+    let alternate = Branch {
+        span: alternate,
+        region: Span::new(leading_comment.map_or(alternate.start, |c| c.span.start), alternate.end),
+        wrap: alternate_wrap,
+        ends_with_line_comment: false,
+    };
+    Some((consequent, alternate))
+}
+
+/// Edits swapping the text of two branches, along with their comments.
+///
+/// Returns no edits if the branches are identical.
+pub fn swap_branches(
+    source_text: &str,
+    consequent: Branch,
+    alternate: Branch,
+) -> Vec<(Span, String)> {
+    let new_consequent = moved_text(source_text, alternate);
+    let new_alternate = moved_text(source_text, consequent);
+    if new_consequent == consequent.region.source_text(source_text)
+        && new_alternate == alternate.region.source_text(source_text)
+    {
+        return vec![];
+    }
+    vec![(consequent.region, new_consequent), (alternate.region, new_alternate)]
+}
+
+fn moved_text(source_text: &str, branch: Branch) -> String {
+    let before = Span::new(branch.region.start, branch.span.start).source_text(source_text);
+    let text = branch.span.source_text(source_text);
+    let after = Span::new(branch.span.end, branch.region.end).source_text(source_text);
+    let line_break = if branch.ends_with_line_comment { "\n" } else { "" };
+    match branch.wrap {
+        BranchWrap::None => format!("{before}{text}{after}{line_break}"),
+        BranchWrap::Block => format!("{before}{{{text}}}{after}{line_break}"),
+        BranchWrap::Parentheses => format!("{before}({text}){after}{line_break}"),
+    }
+}
+
+#[test]
+fn test_swap_branches() {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Expression;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn swap(source: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        let comments = &ret.program.comments;
+        let (consequent, alternate) = match &ret.program.body[0] {
+            Statement::IfStatement(if_stmt) => if_statement_branches(if_stmt, comments, source),
+            Statement::ExpressionStatement(expr_stmt) => match &expr_stmt.expression {
+                Expression::ConditionalExpression(conditional_expr) => {
+                    conditional_expression_branches(conditional_expr, comments, source)
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }?;
+        let mut fixed = source.to_string();
+        let mut edits = swap_branches(source, consequent, alternate);
+        edits.sort_by_key(|(span, _)| std::cmp::Reverse(span.start));
+        for (span, text) in edits {
+            fixed.replace_range(span.start as usize..span.end as usize, &text);
+        }
+        Some(fixed)
+    }
+
+    assert_eq!(swap("if (a) { b } else { c }").as_deref(), Some("if (a) { c } else { b }"));
+    assert_eq!(swap("if (a) b(); else c();").as_deref(), Some("if (a) {c();} else {b();}"));
+    assert_eq!(swap("if (a) { b } else { b }").as_deref(), Some("if (a) { b } else { b }"));
+    assert_eq!(swap("a ? b : c").as_deref(), Some("a ? c : b"));
+
+    // `else if` chains are not swapped.
+    assert_eq!(swap("if (a) { b } else if (c) { d }"), None);
+    assert_eq!(swap("if (a) { b } else if (c) { d } else { e }"), None);
+    assert_eq!(
+        swap("if (a) { b } else { if (c) { d } else { e } }").as_deref(),
+        Some("if (a) { if (c) { d } else { e } } else { b }")
+    );
+
+    // Comments move with their branch.
+    assert_eq!(
+        swap("if (a) { b } /* b */ else /* c */ { c }").as_deref(),
+        Some("if (a) /* c */ { c } else { b } /* b */")
+    );
+    assert_eq!(
+        swap("if (a) { b } // b\nelse // c\n{ c }").as_deref(),
+        Some("if (a) // c\n{ c }\nelse { b } // b\n")
+    );
+    assert_eq!(swap("a ? b /* b */ : /* c */ c").as_deref(), Some("a ? /* c */ c : b /* b */"));
+    assert_eq!(
+        swap("if (a) { b } else { c } // not c").as_deref(),
+        Some("if (a) { c } else { b } // not c")
+    );
+
+    // Comments between the condition and the branches prevent swapping.
+    assert_eq!(swap("if (a) /* b */ { b } else { c }"), None);
+    assert_eq!(swap("if (a /* a */) { b } else { c }"), None);
+    assert_eq!(swap("a /* a */ ? b : c"), None);
+    assert_eq!(swap("a ? /* b */ b : c"), None);
+}
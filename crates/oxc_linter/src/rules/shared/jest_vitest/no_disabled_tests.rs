@@ -14,6 +14,9 @@ pub const DOCUMENTATION: &str = r"### What it does
 
 This rule raises a warning about disabled tests.
 
+Removing `.skip`, or the `x` prefix from `xit`, `xtest` and `xdescribe`, is offered as a
+suggestion, since re-enabling a test changes what runs.
+
 ### Why is this bad?
 
 Jest has a feature that allows you to temporarily mark tests as disabled. This
@@ -117,18 +120,26 @@ pub fn run_on_jest_node<'a, 'c>(
         } else {
             Message::DisabledTestWithX.details()
         };
-        ctx.diagnostic(no_disabled_tests_diagnostic(error, help, call_expr.callee.span()));
+        // Re-enabling a test changes what runs, so this is only a suggestion.
+        ctx.diagnostic_with_suggestion(
+            no_disabled_tests_diagnostic(error, help, call_expr.callee.span()),
+            |fixer| fixer.delete_range(Span::sized(call_expr.callee.span().start, 1)),
+        );
         return;
     }
 
     // `it.skip('foo', function () {})'`
     // `describe.skip('foo', function () {})'`
-    if members.iter().any(|member| member.is_name_equal("skip")) {
+    // `it.skip.concurrent.each(table)('foo', function () {})'`
+    if let Some(skip_node) = members.iter().find(|member| member.is_name_equal("skip")) {
         let (error, help) = if matches!(kind, JestGeneralFnKind::Describe) {
             Message::DisabledSuiteWithSkip.details()
         } else {
             Message::DisabledTestWithSkip.details()
         };
-        ctx.diagnostic(no_disabled_tests_diagnostic(error, help, call_expr.callee.span()));
+        ctx.diagnostic_with_suggestion(
+            no_disabled_tests_diagnostic(error, help, call_expr.callee.span()),
+            |fixer| fixer.delete_range(skip_node.removal_span()),
+        );
     }
 }
@@ -5,8 +5,8 @@ use oxc_span::Span;
 use crate::{
     context::LintContext,
     utils::{
-        JestFnKind, JestGeneralFnKind, MemberExpressionElement, ParsedGeneralJestFnCall,
-        PossibleJestNode, parse_general_jest_fn_call,
+        JestFnKind, JestGeneralFnKind, ParsedGeneralJestFnCall, PossibleJestNode,
+        parse_general_jest_fn_call,
     },
 };
 
@@ -21,6 +21,9 @@ pub const DOCUMENTATION: &str = r"### What it does
 This rule reminds you to remove `.only` from your tests by raising a warning
 whenever you are using the exclusivity feature.
 
+The fix removes `.only` from the call chain, or the `f` prefix from `fit` and
+`fdescribe`.
+
 ### Why is this bad?
 
 Jest has a feature that allows you to focus tests by appending `.only` or
@@ -44,6 +47,8 @@ fit('foo', () => {});
 fit.each`
 table
 `();
+it.only.concurrent.each(table)('foo', () => {});
+bench.only('foo', () => {});
 ```
 ";
 
@@ -56,12 +61,17 @@ pub fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<
         return;
     };
     let ParsedGeneralJestFnCall { kind, members, name, .. } = jest_fn_call;
-    if !matches!(kind, JestFnKind::General(JestGeneralFnKind::Describe | JestGeneralFnKind::Test)) {
+    if !matches!(
+        kind,
+        JestFnKind::General(
+            JestGeneralFnKind::Describe | JestGeneralFnKind::Test | JestGeneralFnKind::Bench
+        )
+    ) {
         return;
     }
 
     if name.starts_with('f') {
-        ctx.diagnostic_with_suggestion(
+        ctx.diagnostic_with_fix(
             no_focused_tests_diagnostic(Span::sized(
                 call_expr.span.start,
                 u32::try_from(name.len()).unwrap_or(1),
@@ -72,14 +82,11 @@ pub fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<
         return;
     }
 
+    // `it.only.concurrent.each(table)(name, fn)`
     let only_node = members.iter().find(|member| member.is_name_equal("only"));
     if let Some(only_node) = only_node {
-        ctx.diagnostic_with_suggestion(no_focused_tests_diagnostic(only_node.span), |fixer| {
-            let mut span = only_node.span.expand_left(1);
-            if !matches!(only_node.element, MemberExpressionElement::IdentName(_)) {
-                span = span.expand_right(1);
-            }
-            fixer.delete_range(span)
+        ctx.diagnostic_with_fix(no_focused_tests_diagnostic(only_node.span), |fixer| {
+            fixer.delete_range(only_node.removal_span())
         });
     }
 }
@@ -51,7 +51,8 @@ pub fn run<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) {
         AstKind::CallExpression(call_expr) => {
             check_call_expression(call_expr, node, ctx);
         }
-        AstKind::Function(fn_decl) => {
+        // Function expressions passed to tests are checked with the test call.
+        AstKind::Function(fn_decl) if fn_decl.is_declaration() => {
             let Some(func_body) = &fn_decl.body else {
                 return;
             };
@@ -1,12 +1,12 @@
 use oxc_ast::{
     AstKind,
-    ast::{Argument, CallExpression, Expression, Statement},
+    ast::{Argument, Expression, Statement},
 };
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_str::Str;
 
-use crate::{context::LintContext, fixer::RuleFixer, utils::get_node_name};
+use crate::{context::LintContext, utils::get_node_name};
 
 fn use_mock_shorthand(preferred_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Prefer mock resolved/rejected shorthands for promises.")
@@ -83,7 +83,7 @@ pub fn run<'a>(node: &oxc_semantic::AstNode<'a>, ctx: &LintContext<'a>) {
                 if !arrow_func.params.is_empty() {
                     return;
                 }
-                let Some(stmt) = arrow_func.body.statements.first() else {
+                let [stmt] = arrow_func.body.statements.as_slice() else {
                     return;
                 };
 
@@ -97,13 +97,14 @@ pub fn run<'a>(node: &oxc_semantic::AstNode<'a>, ctx: &LintContext<'a>) {
                 }
             }
             Expression::FunctionExpression(func_expr) => {
-                if !func_expr.params.is_empty() {
+                // Generators return an iterator, not the returned value.
+                if !func_expr.params.is_empty() || func_expr.generator {
                     return;
                 }
                 let Some(func_body) = &func_expr.body else {
                     return;
                 };
-                let Some(stmt) = func_body.statements.first() else {
+                let [stmt] = func_body.statements.as_slice() else {
                     return;
                 };
                 let Statement::ReturnStatement(return_stmt) = stmt else {
@@ -141,36 +142,21 @@ fn report<'a>(
         if arg_name.ends_with("reject") { mock_promise_reject } else { mock_promise_resolve };
     let fix_span = arg_span.unwrap_or(call_expr.span);
 
-    if call_expr.arguments.len() <= 1 {
+    let argument = match call_expr.arguments.as_slice() {
+        [] => Some("undefined"),
+        [argument] => argument.as_expression().map(|expr| ctx.source_range(expr.span())),
+        _ => None,
+    };
+
+    if let Some(argument) = argument {
         ctx.diagnostic_with_fix(
             use_mock_shorthand(Str::from(prefer_name).as_str(), property_span),
             |fixer| {
-                let content = fix(fixer, prefer_name, call_expr);
                 let span = Span::new(property_span.start, fix_span.end);
-                fixer.replace(span, content)
+                fixer.replace(span, format!("{prefer_name}({argument}"))
             },
         );
     } else {
         ctx.diagnostic(use_mock_shorthand(Str::from(prefer_name).as_str(), property_span));
     }
 }
-
-fn fix<'a>(
-    fixer: RuleFixer<'_, 'a>,
-    prefer_name: &'a str,
-    call_expr: &CallExpression<'a>,
-) -> String {
-    let mut content = fixer.codegen();
-    content.print_str(prefer_name);
-    content.print_ascii_byte(b'(');
-    if call_expr.arguments.is_empty() {
-        content.print_str("undefined");
-    } else {
-        for argument in &call_expr.arguments {
-            if let Some(expr) = argument.as_expression() {
-                content.print_expression(expr);
-            }
-        }
-    }
-    content.into_source_text()
-}
@@ -5,9 +5,11 @@ use oxc_ast::{
         SimpleAssignmentTarget,
     },
 };
+use std::borrow::Cow;
+
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::AstNode;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{
     context::LintContext,
@@ -42,6 +44,9 @@ you're responsible for restoring the original implementation, which is easy to
 forget and can cause tests to interfere with each other. Using `jest.spyOn()`
 provides automatic cleanup capabilities and makes your tests more reliable.
 
+Assignments to a computed property with a non-literal key, such as
+`obj[key] = jest.fn()`, are reported without a suggested fix.
+
 ### Examples
 
 Examples of **incorrect** code for this rule:
@@ -112,73 +117,76 @@ fn check_and_fix<'a>(
         return;
     }
 
-    ctx.diagnostic_with_suggestion(
-        use_jest_spy_on(Span::new(call_expr.span.start, first_fn_member.span.end)),
-        |fixer| {
-            let (end, has_mock_implementation) = if jest_fn_call.members.len() > 1 {
-                let second = &jest_fn_call.members[1];
-                let has_mock_implementation = jest_fn_call
-                    .members
-                    .iter()
-                    .any(|modifier| modifier.is_name_equal("mockImplementation"));
-
-                (second.span.start - 1, has_mock_implementation)
-            } else {
-                (first_fn_member.span.end + (call_expr.span.end - first_fn_member.span.end), false)
-            };
-            let content = build_code(call_expr, left_assign, has_mock_implementation, fixer);
-            fixer.replace(Span::new(assign_expr.span.start, end), content)
-        },
-    );
-}
+    let diagnostic = use_jest_spy_on(Span::new(call_expr.span.start, first_fn_member.span.end));
+    let Some((object, property)) = spied_on_property(left_assign, ctx) else {
+        ctx.diagnostic(diagnostic);
+        return;
+    };
 
-fn build_code<'a>(
-    call_expr: &'a CallExpression<'a>,
-    left_assign: &MemberExpression,
-    has_mock_implementation: bool,
-    fixer: RuleFixer<'_, 'a>,
-) -> String {
-    let (framework_spy, arguments) = get_test_fn_call(call_expr);
+    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+        let (end, has_mock_implementation) = if jest_fn_call.members.len() > 1 {
+            let second = &jest_fn_call.members[1];
+            let has_mock_implementation = jest_fn_call
+                .members
+                .iter()
+                .any(|modifier| modifier.is_name_equal("mockImplementation"));
 
-    let mut formatter = fixer.codegen();
-    formatter.print_str(framework_spy);
+            (second.span.start - 1, has_mock_implementation)
+        } else {
+            (first_fn_member.span.end + (call_expr.span.end - first_fn_member.span.end), false)
+        };
+        let content = build_code(call_expr, object, &property, has_mock_implementation, fixer);
+        fixer.replace(Span::new(assign_expr.span.start, end), content)
+    });
+}
 
-    match left_assign {
+/// The source text of the object of `left_assign`, and the property name to pass to `spyOn`,
+/// quoted if it is not a literal in the source.
+///
+/// Returns `None` for properties which are not known without running the code, such as
+/// `obj[key]`, and for private fields.
+fn spied_on_property<'a>(
+    left_assign: &MemberExpression,
+    ctx: &LintContext<'a>,
+) -> Option<(&'a str, Cow<'a, str>)> {
+    let object = ctx.source_range(left_assign.object().span());
+    let property = match left_assign {
         MemberExpression::ComputedMemberExpression(cmp_mem_expr) => {
-            formatter.print_expression(&cmp_mem_expr.object);
-            formatter.print_ascii_byte(b',');
-            formatter.print_ascii_byte(b' ');
-            formatter.print_expression(&cmp_mem_expr.expression);
+            match &cmp_mem_expr.expression {
+                Expression::StringLiteral(_) | Expression::NumericLiteral(_) => {
+                    Cow::Borrowed(ctx.source_range(cmp_mem_expr.expression.span()))
+                }
+                Expression::TemplateLiteral(template) if template.expressions.is_empty() => {
+                    Cow::Borrowed(ctx.source_range(template.span))
+                }
+                _ => return None,
+            }
         }
         MemberExpression::StaticMemberExpression(static_mem_expr) => {
-            let name = &static_mem_expr.property.name;
-            formatter.print_expression(&static_mem_expr.object);
-            formatter.print_ascii_byte(b',');
-            formatter.print_ascii_byte(b' ');
-            formatter.print_str(format!("\'{name}\'").as_str());
+            Cow::Owned(format!("'{}'", static_mem_expr.property.name))
         }
-        MemberExpression::PrivateFieldExpression(_) => (),
-    }
-
-    formatter.print_ascii_byte(b')');
+        MemberExpression::PrivateFieldExpression(_) => return None,
+    };
+    Some((object, property))
+}
 
+fn build_code<'a>(
+    call_expr: &'a CallExpression<'a>,
+    object: &str,
+    property: &str,
+    has_mock_implementation: bool,
+    fixer: RuleFixer<'_, 'a>,
+) -> String {
+    let (framework_spy, argument) = get_test_fn_call(call_expr);
+    let spy = format!("{framework_spy}{object}, {property})");
     if has_mock_implementation {
-        return formatter.into_source_text();
+        return spy;
     }
-
-    formatter.print_str(".mockImplementation(");
-
-    if let Some(expr) = arguments {
-        formatter.print_expression(expr);
-    }
-
-    formatter.print_ascii_byte(b')');
-    formatter.into_source_text()
+    let implementation = argument.map_or("", |argument| fixer.source_range(argument.span()));
+    format!("{spy}.mockImplementation({implementation})")
 }
 
-fn get_test_fn_call<'a>(
-    call_expr: &'a CallExpression<'a>,
-) -> (&'a str, Option<&'a Expression<'a>>) {
+fn get_test_fn_call<'a>(call_expr: &'a CallExpression<'a>) -> (&'a str, Option<&'a Argument<'a>>) {
     let node_name = get_node_name(&call_expr.callee);
     let is_test_fn = node_name == "jest.fn" || node_name == "vi.fn";
 
@@ -187,7 +195,7 @@ fn get_test_fn_call<'a>(
             "vi.fn" => "vi.spyOn(",
             _ => "jest.spyOn(",
         };
-        return (framework_spy, call_expr.arguments.first().and_then(Argument::as_expression));
+        return (framework_spy, call_expr.arguments.first());
     }
 
     match &call_expr.callee {
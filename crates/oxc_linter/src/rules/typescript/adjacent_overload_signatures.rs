@@ -10,11 +10,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_str::CompactStr;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn adjacent_overload_signatures_diagnostic(
     fn_name: &str,
@@ -85,6 +81,7 @@ declare_oxc_lint!(
     AdjacentOverloadSignatures,
     typescript,
     style,
+    language = typescript,
     version = "0.0.7",
     short_description = "Require that function overload signatures be consecutive.",
 );
@@ -332,10 +329,6 @@ impl Rule for AdjacentOverloadSignatures {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
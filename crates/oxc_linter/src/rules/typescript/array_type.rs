@@ -10,7 +10,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -165,6 +165,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = ArrayTypeConfig,
+    language = typescript,
     version = "0.2.8",
     short_description = "Require consistently using either `T[]` or `Array<T>` for arrays.",
 );
@@ -239,10 +240,6 @@ impl Rule for ArrayType {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 impl ArrayType {
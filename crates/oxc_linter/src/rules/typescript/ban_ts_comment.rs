@@ -5,10 +5,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use schemars::JsonSchema;
 
-use crate::{
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{context::LintContext, rule::Rule};
 
 fn comment(ts_comment_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -182,6 +179,7 @@ declare_oxc_lint!(
     pedantic,
     conditional_fix,
     config = BanTsCommentConfig,
+    language = typescript,
     version = "0.0.8",
     short_description = "This rule lets you set which directive comments you want to allow in your codebase.",
 );
@@ -274,10 +272,6 @@ impl Rule for BanTsComment {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 impl BanTsComment {
@@ -1048,6 +1042,7 @@ if (false) {
     ];
 
     Tester::new(BanTsComment::NAME, BanTsComment::PLUGIN, pass, fail)
+        .with_skipped_file_cases("js", vec![("// @ts-ignore", None)])
         .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -4,11 +4,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn type_diagnostic(banned_type: &str, suggested_type: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -79,6 +75,7 @@ declare_oxc_lint!(
     typescript,
     pedantic,
     none,
+    language = typescript,
     version = "0.0.14",
     short_description = "Disallow certain types.",
 );
@@ -115,10 +112,6 @@ impl Rule for BanTypes {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
@@ -127,6 +127,7 @@ declare_oxc_lint!(
     style,
     conditional_suggestion,
     config = ClassLiteralPropertyStyleOption,
+    language = typescript,
     version = "1.47.0",
     short_description = "Enforces a consistent style for exposing literal values on classes.",
 );
@@ -78,6 +78,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = PreferGenericType,
+    language = typescript,
     version = "0.14.0",
     short_description = "Enforce specifying generic type arguments on type annotation or constructor name of a constructor call.",
 );
@@ -112,10 +113,6 @@ impl Rule for ConsistentGenericConstructors {
     fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
         serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
     }
-
-    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 impl ConsistentGenericConstructors {
@@ -14,7 +14,7 @@ use oxc_span::{GetSpan, Span};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -109,6 +109,7 @@ declare_oxc_lint!(
     style,
     conditional_fix,
     config = ConsistentIndexedObjectStyleConfig,
+    language = typescript,
     version = "0.4.2",
     short_description = "Choose between requiring either `Record` type or indexed signature types.",
 );
@@ -469,10 +470,6 @@ impl Rule for ConsistentIndexedObjectStyle {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn contains_convertible_index_signature(r#type: &TSType) -> bool {
@@ -13,7 +13,7 @@ use serde::Deserialize;
 use crate::{
     AstNode,
     ast_util::outermost_paren_parent,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     fixer::{RuleFix, RuleFixer},
     rule::{DefaultRuleConfig, Rule},
 };
@@ -258,6 +258,7 @@ declare_oxc_lint!(
     style,
     conditional_fix_suggestion,
     config = ConsistentTypeAssertionsConfig,
+    language = typescript,
     version = "1.44.0",
     short_description = "Enforce consistent usage of TypeScript type assertions.",
 );
@@ -358,10 +359,6 @@ impl Rule for ConsistentTypeAssertions {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn is_const(type_annotation: &TSType) -> bool {
@@ -14,7 +14,7 @@ use oxc_span::Span;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     fixer::RuleFixer,
     rule::{DefaultRuleConfig, Rule},
 };
@@ -88,6 +88,7 @@ declare_oxc_lint!(
     style,
     conditional_fix_dangerous,
     config = ConsistentTypeDefinitionsConfig,
+    language = typescript,
     version = "0.2.17",
     short_description = "Enforce type definitions to consistently use either `interface` or `type`.",
 );
@@ -238,10 +239,6 @@ impl Rule for ConsistentTypeDefinitions {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn is_within_declare_global_block(ctx: &LintContext, node_id: NodeId) -> bool {
@@ -188,6 +188,7 @@ declare_oxc_lint!(
     style,
     conditional_fix,
     config = ConsistentTypeImportsConfig,
+    language = typescript,
     version = "0.5.2",
     short_description = "Enforce consistent usage of type imports.",
 );
@@ -328,10 +329,7 @@ impl Rule for ConsistentTypeImports {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-            && !ctx
-                .file_extension()
-                .is_some_and(|ext| ext == "vue" || ext == "svelte" || ext == "astro")
+        !ctx.file_extension().is_some_and(|ext| ext == "vue" || ext == "svelte" || ext == "astro")
     }
 }
 
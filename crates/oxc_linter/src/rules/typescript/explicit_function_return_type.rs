@@ -18,7 +18,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     AstNode,
     ast_util::{iter_outer_expressions, outermost_paren_parent},
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -135,6 +135,7 @@ declare_oxc_lint!(
     typescript,
     restriction,
     config = ExplicitFunctionReturnTypeConfig,
+    language = typescript,
     version = "0.4.4",
     short_description = "This rule enforces that functions have an explicit return type annotation.",
 );
@@ -289,10 +290,6 @@ impl Rule for ExplicitFunctionReturnType {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 impl ExplicitFunctionReturnType {
@@ -16,7 +16,7 @@ use oxc_span::{GetSpan, Span};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -199,6 +199,7 @@ declare_oxc_lint!(
     restriction,
     conditional_fix_suggestion,
     config = ExplicitMemberAccessibilityConfig,
+    language = typescript,
     version = "1.61.0",
     short_description = "Require explicit accessibility modifiers on class properties and methods.",
 );
@@ -217,10 +218,6 @@ impl Rule for ExplicitMemberAccessibility {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 impl ExplicitMemberAccessibility {
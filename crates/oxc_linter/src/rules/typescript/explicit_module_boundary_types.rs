@@ -168,6 +168,7 @@ declare_oxc_lint!(
     typescript,
     restriction,
     config = ExplicitModuleBoundaryTypesConfig,
+    language = typescript,
     version = "1.9.0",
     short_description = "Require explicit return and argument types on exported functions' and classes' public class methods.",
 );
@@ -222,9 +223,6 @@ impl Rule for ExplicitModuleBoundaryTypes {
             _ => {}
         }
     }
-    fn should_run(&self, ctx: &crate::ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 impl ExplicitModuleBoundaryTypes {
@@ -11,7 +11,7 @@ use oxc_span::Span;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -147,6 +147,7 @@ declare_oxc_lint!(
     typescript,
     style,
     pending,
+    language = typescript,
     version = "1.68.0",
     short_description = "Enforce using a particular method signature syntax.",
     config = MethodSignatureStyleConfig
@@ -179,10 +180,6 @@ impl Rule for MethodSignatureStyle {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
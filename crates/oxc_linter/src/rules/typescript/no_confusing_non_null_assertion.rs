@@ -7,11 +7,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 #[derive(Debug, Default, Clone)]
 pub struct NoConfusingNonNullAssertion;
@@ -44,6 +40,7 @@ declare_oxc_lint!(
     typescript,
     suspicious,
     pending,
+    language = typescript,
     version = "0.6.1",
     short_description = "Disallow non-null assertion in locations that may be confusing.",
 );
@@ -130,10 +127,6 @@ impl Rule for NoConfusingNonNullAssertion {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
@@ -7,11 +7,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use rustc_hash::FxHashMap;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_duplicate_enum_values_diagnostic(
     first_init_span: Span,
@@ -86,6 +82,7 @@ declare_oxc_lint!(
     NoDuplicateEnumValues,
     typescript,
     correctness,
+    language = typescript,
     version = "0.0.8",
     short_description = "Disallow duplicate enum member values.",
 );
@@ -132,10 +129,6 @@ impl Rule for NoDuplicateEnumValues {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
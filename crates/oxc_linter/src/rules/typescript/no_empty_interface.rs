@@ -8,7 +8,7 @@ use serde_json::Value;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -68,6 +68,7 @@ declare_oxc_lint!(
     style,
     pending,
     config = NoEmptyInterface,
+    language = typescript,
     version = "0.0.6",
     short_description = "Disallow the declaration of empty interfaces.",
 );
@@ -88,10 +89,6 @@ impl Rule for NoEmptyInterface {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
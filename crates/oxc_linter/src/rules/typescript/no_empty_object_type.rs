@@ -163,6 +163,7 @@ declare_oxc_lint!(
     restriction,
     pending,
     config = NoEmptyObjectTypeConfig,
+    language = typescript,
     version = "0.12.0",
     short_description = "Disallow accidentally using the \"empty object\" type.",
 );
@@ -196,10 +197,6 @@ impl Rule for NoEmptyObjectType {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn check_interface_declaration(
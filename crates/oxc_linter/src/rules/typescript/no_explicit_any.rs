@@ -8,7 +8,7 @@ use serde_json::Value;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -74,6 +74,7 @@ declare_oxc_lint!(
     restriction,
     conditional_fix,
     config = NoExplicitAny,
+    language = typescript,
     version = "0.0.13",
     short_description = "Disallows explicit use of the `any` type.",
 );
@@ -99,10 +100,6 @@ impl Rule for NoExplicitAny {
             ctx.diagnostic(no_explicit_any_diagnostic(any.span));
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 impl NoExplicitAny {
@@ -6,12 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    ast_util::outermost_paren_parent,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, ast_util::outermost_paren_parent, context::LintContext, rule::Rule};
 
 fn no_extra_non_null_assertion_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("extra non-null assertion")
@@ -75,6 +70,7 @@ declare_oxc_lint!(
     typescript,
     correctness,
     fix,
+    language = typescript,
     version = "0.0.6",
     short_description = "Disallow extra non-null assertions.",
 );
@@ -131,10 +127,6 @@ impl Rule for NoExtraNonNullAssertion {
             });
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
@@ -6,12 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    fixer::Fix,
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, fixer::Fix, rule::Rule};
 
 fn no_import_type_side_effects_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("TypeScript will only remove the inline type specifiers which will leave behind a side effect import at runtime.")
@@ -75,6 +70,7 @@ declare_oxc_lint!(
     typescript,
     restriction,
     fix,
+    language = typescript,
     version = "0.5.0",
     short_description = "Enforce the use of top-level `import type` qualifier when an import only has specifiers with inline type qualifiers.",
 );
@@ -89,7 +85,8 @@ impl Rule for NoImportTypeSideEffects {
             return;
         }
 
-        let Some(specifiers) = &import_decl.specifiers else {
+        // `import {} from 'mod'` is already a side effect import
+        let Some(specifiers) = import_decl.specifiers.as_ref().filter(|s| !s.is_empty()) else {
             return;
         };
 
@@ -121,9 +118,16 @@ impl Rule for NoImportTypeSideEffects {
                 for specifier in type_specifiers {
                     // import { type    A } from 'foo.js'
                     //          ^^^^^^^^
+                    // Only whitespace after `type` is removed, so comments such as
+                    // `type /* comment */ A` are preserved.
+                    let after_type =
+                        Span::new(specifier.span.start + 4, specifier.imported.span().start);
+                    let whitespace = ctx.source_range(after_type);
+                    let whitespace_len = whitespace.len() - whitespace.trim_start().len();
+                    #[expect(clippy::cast_possible_truncation)]
                     fix.push(Fix::delete(Span::new(
                         specifier.span.start,
-                        specifier.imported.span().start,
+                        after_type.start + whitespace_len as u32,
                     )));
                 }
 
@@ -131,10 +135,6 @@ impl Rule for NoImportTypeSideEffects {
             },
         );
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
@@ -153,6 +153,7 @@ fn test() {
         "import T, { type U } from 'mod';",
         "import type * as T from 'mod';",
         "import 'mod';",
+        "import {} from 'mod';",
     ];
 
     let fail = vec![
@@ -170,9 +171,53 @@ fn test() {
             "import { type A as AA, type B as BB } from 'mod';",
             "import type { A as AA, B as BB } from 'mod';",
         ),
+        (
+            "import { type A, /* B */ type B } from 'mod';",
+            "import type { A, /* B */ B } from 'mod';",
+        ),
+        (
+            "import { type /* A */ A, type\n  B } from 'mod';",
+            "import type { /* A */ A, B } from 'mod';",
+        ),
     ];
 
     Tester::new(NoImportTypeSideEffects::NAME, NoImportTypeSideEffects::PLUGIN, pass, fail)
         .expect_fix(fix)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_stable_with_consistent_type_imports() {
+    use super::consistent_type_imports::ConsistentTypeImports;
+    use crate::tester::Tester;
+
+    let inline_style =
+        Some(serde_json::json!([{ "prefer": "type-imports", "fixStyle": "inline-type-imports" }]));
+
+    // `consistent-type-imports` with inline fixes produces inline type specifiers ...
+    Tester::new(
+        ConsistentTypeImports::NAME,
+        ConsistentTypeImports::PLUGIN,
+        vec![("import type { A, B } from 'mod'; let a: A; let b: B;", inline_style.clone())],
+        vec![("import { A, B } from 'mod'; let a: A; let b: B;", inline_style.clone())],
+    )
+    .expect_fix(vec![(
+        "import { A, B } from 'mod'; let a: A; let b: B;",
+        "import { type A, type B } from 'mod'; let a: A; let b: B;",
+        inline_style,
+    )])
+    .test();
+
+    // ... which this rule hoists to a top-level `import type`, which is accepted by both rules.
+    Tester::new(
+        NoImportTypeSideEffects::NAME,
+        NoImportTypeSideEffects::PLUGIN,
+        vec!["import type { A, B } from 'mod'; let a: A; let b: B;"],
+        vec!["import { type A, type B } from 'mod'; let a: A; let b: B;"],
+    )
+    .expect_fix(vec![(
+        "import { type A, type B } from 'mod'; let a: A; let b: B;",
+        "import type { A, B } from 'mod'; let a: A; let b: B;",
+    )])
+    .test();
+}
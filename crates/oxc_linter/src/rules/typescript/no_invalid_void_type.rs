@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -130,6 +130,7 @@ declare_oxc_lint!(
     restriction,
     none,
     config = NoInvalidVoidTypeConfig,
+    language = typescript,
     version = "1.47.0",
     short_description = "Disallow `void` type usage outside return types and configured generic contexts.",
 );
@@ -215,10 +216,6 @@ impl Rule for NoInvalidVoidType {
         let in_union = matches!(parent.kind(), AstKind::TSUnionType(_));
         ctx.diagnostic(self.not_return_for_options(keyword.span, in_union));
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 impl NoInvalidVoidType {
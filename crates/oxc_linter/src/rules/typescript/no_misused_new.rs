@@ -96,6 +96,7 @@ declare_oxc_lint!(
     NoMisusedNew,
     typescript,
     correctness,
+    language = typescript,
     version = "0.0.7",
     short_description = "Enforce valid definitions of `new` and `constructor` in TypeScript.",
 );
@@ -150,10 +151,6 @@ impl Rule for NoMisusedNew {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
@@ -114,6 +114,7 @@ declare_oxc_lint!(
     typescript,
     restriction,
     config = NoNamespace,
+    language = typescript,
     version = "0.0.8",
     short_description = "Disallow TypeScript namespaces.",
 );
@@ -152,10 +153,7 @@ impl Rule for NoNamespace {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        if self.allow_definition_files && ctx.source_type().is_typescript_definition() {
-            return false;
-        }
-        ctx.source_type().is_typescript()
+        !(self.allow_definition_files && ctx.is_declaration_file())
     }
 }
 
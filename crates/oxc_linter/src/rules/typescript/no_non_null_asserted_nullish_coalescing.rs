@@ -5,11 +5,7 @@ use oxc_semantic::SymbolId;
 use oxc_span::Span;
 use oxc_syntax::operator::LogicalOperator;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_non_null_asserted_nullish_coalescing_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("'Disallow non-null assertions in the left operand of a nullish coalescing operator")
@@ -67,6 +63,7 @@ declare_oxc_lint!(
     typescript,
     restriction,
     suggestion,
+    language = typescript,
     version = "0.5.0",
     short_description = "Disallow non-null assertions in the left operand of a nullish coalescing operator.",
 );
@@ -101,10 +98,6 @@ impl Rule for NoNonNullAssertedNullishCoalescing {
             },
         );
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn non_null_assertion_spans(expr: &oxc_ast::ast::TSNonNullExpression) -> Vec<Span> {
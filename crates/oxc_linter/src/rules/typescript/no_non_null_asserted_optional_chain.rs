@@ -6,11 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_non_null_asserted_optional_chain_diagnostic(
     chain_span: Span,
@@ -58,6 +54,7 @@ declare_oxc_lint!(
     typescript,
     correctness,
     suggestion,
+    language = typescript,
     version = "0.0.6",
     short_description = "Disallow non-null assertions after an optional chain expression.",
 );
@@ -118,10 +115,6 @@ impl Rule for NoNonNullAssertedOptionalChain {
             });
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn is_parent_member_or_call(node: &AstNode<'_>, ctx: &LintContext<'_>) -> bool {
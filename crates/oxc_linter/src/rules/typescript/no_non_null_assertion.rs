@@ -3,11 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 #[derive(Debug, Default, Clone)]
 pub struct NoNonNullAssertion;
@@ -40,6 +36,7 @@ declare_oxc_lint!(
     typescript,
     restriction,
     pending,
+    language = typescript,
     version = "0.5.0",
     short_description = "Disallow non-null assertions using the `!` postfix operator.",
 );
@@ -64,10 +61,6 @@ impl Rule for NoNonNullAssertion {
         let is_member_expression = ctx.nodes().parent_kind(node.id()).is_member_expression_kind();
         ctx.diagnostic(no_non_null_assertion_diagnostic(expr.span, is_member_expression));
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
@@ -252,6 +252,7 @@ declare_oxc_lint!(
     restriction,
     fix_suggestion,
     config = NoRestrictedTypesConfig,
+    language = typescript,
     version = "1.31.0",
     short_description = "Disallow certain types from being used.",
 );
@@ -350,8 +351,8 @@ impl Rule for NoRestrictedTypes {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript() && !self.0.types.is_empty()
+    fn should_run(&self, _ctx: &ContextHost) -> bool {
+        !self.0.types.is_empty()
     }
 }
 
@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -97,6 +97,7 @@ declare_oxc_lint!(
     typescript,
     correctness,
     config = NoThisAliasConfig,
+    language = typescript,
     version = "0.0.7",
     short_description = "Disallow aliasing of `this`.",
 );
@@ -164,10 +165,6 @@ impl Rule for NoThisAlias {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[inline]
@@ -250,5 +247,7 @@ fn test() {
         ("const self = this;", Some(serde_json::json!([{ "allowNames": vec!["bar"] }]))),
     ];
 
-    Tester::new(NoThisAlias::NAME, NoThisAlias::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(NoThisAlias::NAME, NoThisAlias::PLUGIN, pass, fail)
+        .with_skipped_file_cases("jsx", vec![("const self = this;", None)])
+        .test_and_snapshot();
 }
@@ -6,11 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{FileExtension, Span};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_unnecessary_type_constraint_diagnostic(
     generic_type: &str,
@@ -80,6 +76,7 @@ declare_oxc_lint!(
     typescript,
     suspicious,
     suggestion,
+    language = typescript,
     version = "0.0.6",
     short_description = "Disallow unnecessary constraints on generic types.",
 );
@@ -119,10 +116,6 @@ impl Rule for NoUnnecessaryTypeConstraint {
             );
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn should_add_trailing_comma(
@@ -4,11 +4,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::SymbolId;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_unsafe_declaration_merging_diagnostic(span: Span, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unsafe declaration merging between classes and interfaces.")
@@ -45,6 +41,7 @@ declare_oxc_lint!(
     NoUnsafeDeclarationMerging,
     typescript,
     correctness,
+    language = typescript,
     version = "0.0.11",
     short_description = "Disallow unsafe declaration merging.",
 );
@@ -72,10 +69,6 @@ impl Rule for NoUnsafeDeclarationMerging {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn check_and_diagnostic(
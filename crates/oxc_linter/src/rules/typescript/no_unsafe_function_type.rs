@@ -55,6 +55,7 @@ declare_oxc_lint!(
     NoUnsafeFunctionType,
     typescript,
     pedantic,
+    language = typescript,
     version = "0.11.1",
     short_description = "Disallow using the unsafe built-in Function type.",
 );
@@ -80,10 +81,6 @@ impl Rule for NoUnsafeFunctionType {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn handle_function_type<'a>(identifier: &'a IdentifierReference<'a>, ctx: &LintContext<'a>) {
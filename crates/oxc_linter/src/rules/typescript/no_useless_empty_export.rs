@@ -1,4 +1,7 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    AstKind,
+    ast::{ExportNamedDeclaration, Statement, TSModuleReference},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -11,6 +14,14 @@ fn no_useless_empty_export_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+fn empty_re_export_diagnostic(source: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Empty re-exports export nothing")
+        .with_help(format!(
+            "{source} is still loaded and run for its side effects. Use `import {source};` if that is intended, or remove this re-export."
+        ))
+        .with_label(span)
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct NoUselessEmptyExport;
 
@@ -37,17 +48,29 @@ declare_oxc_lint!(
     /// This rule reports an `export {}` that doesn't do anything in a file
     /// already using ES modules.
     ///
+    /// In declaration files (`.d.ts`), an `export {}` also stops the other
+    /// declarations of the file from being exported implicitly, so it is only
+    /// reported if every other top-level statement is an import or an export.
+    ///
+    /// An empty re-export with a source, such as `export {} from "mod"`, is
+    /// reported too, without a fix: it exports nothing, but still loads and
+    /// runs `mod`, which is clearer written as `import "mod"`.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
     /// ```ts
     /// export const value = 'Hello, world!';
     /// export {};
+    ///
+    /// export {} from 'polyfill';
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```ts
     /// export const value = 'Hello, world!';
+    ///
+    /// import 'polyfill';
     /// ```
     NoUselessEmptyExport,
     typescript,
@@ -63,13 +86,14 @@ impl Rule for NoUselessEmptyExport {
         if decl.declaration.is_some() || !decl.specifiers.is_empty() {
             return;
         }
-        let module_record = ctx.module_record();
-        if module_record.exported_bindings.is_empty()
-            && module_record.local_export_entries.is_empty()
-            && module_record.indirect_export_entries.is_empty()
-            && module_record.star_export_entries.is_empty()
-            && module_record.export_default.is_none()
+        // `export type {} from "mod"` is erased, so it has no side effects.
+        if let Some(source) = &decl.source
+            && !decl.export_kind.is_type()
         {
+            ctx.diagnostic(empty_re_export_diagnostic(ctx.source_range(source.span), decl.span));
+            return;
+        }
+        if !has_other_module_syntax(decl, ctx) {
             return;
         }
         ctx.diagnostic_with_fix(no_useless_empty_export_diagnostic(decl.span), |fixer| {
@@ -78,6 +102,46 @@ impl Rule for NoUselessEmptyExport {
     }
 }
 
+/// Whether the file is a module without `decl`, because of other imports or exports.
+fn has_other_module_syntax(decl: &ExportNamedDeclaration, ctx: &LintContext) -> bool {
+    let module_record = ctx.module_record();
+    let has_exports = !module_record.exported_bindings.is_empty()
+        || !module_record.local_export_entries.is_empty()
+        || !module_record.indirect_export_entries.is_empty()
+        || !module_record.star_export_entries.is_empty()
+        || module_record.export_default.is_some();
+    let body = &ctx.nodes().program().body;
+
+    if ctx.is_declaration_file() {
+        // Declarations in a declaration file with exports are exported implicitly, unless the
+        // file has an `export {}`.
+        return (has_exports
+            || body.iter().any(|stmt| matches!(stmt, Statement::TSExportAssignment(_))))
+            && body.iter().all(|stmt| {
+                stmt.is_module_declaration()
+                    || matches!(stmt, Statement::TSImportEqualsDeclaration(_))
+            });
+    }
+
+    has_exports
+        || !module_record.import_entries.is_empty()
+        || module_record
+            .requested_modules
+            .values()
+            .flatten()
+            .any(|module| module.statement_span != decl.span)
+        || body.iter().any(|stmt| match stmt {
+            Statement::TSExportAssignment(_) => true,
+            Statement::TSImportEqualsDeclaration(import_decl) => {
+                matches!(
+                    import_decl.module_reference,
+                    TSModuleReference::ExternalModuleReference(_)
+                )
+            }
+            _ => false,
+        })
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -98,6 +162,9 @@ fn test() {
             export = {};
         ",
         "export {};",
+        "export type {};",
+        "import('_'); export {};",
+        "export type {} from '_';",
     ];
 
     let fail = vec![
@@ -128,10 +195,63 @@ fn test() {
             export { _ };
             export {};
         ",
-        // "
-        // import _ = require('_');
-        // export {};
-        // ",
+        "
+            import _ = require('_');
+            export {};
+        ",
+        "
+            import {} from '_';
+            export {};
+        ",
+        "
+            import '_';
+            export {};
+        ",
+        "
+            export = {};
+            export {};
+        ",
+        "
+            export type {} from '_';
+            export {};
+        ",
+        // Empty re-exports with a source are reported even without other exports.
+        "export {} from '_';",
+        "
+            export const _ = {};
+            export {} from '_';
+        ",
+    ];
+
+    // `export {}` stops the other declarations of a declaration file from being exported.
+    let declaration_file_pass = vec![
+        "export {};",
+        "
+            declare const _: number;
+            export {};
+        ",
+        "
+            export declare const _: number;
+            declare const hidden: number;
+            export {};
+        ",
+        "
+            import type { T } from '_';
+            declare const hidden: T;
+            export {};
+        ",
+    ];
+
+    let declaration_file_fail = vec![
+        "
+            export declare const _: number;
+            export {};
+        ",
+        "
+            import type { T } from '_';
+            export declare const _: T;
+            export {};
+        ",
     ];
 
     let fix = vec![
@@ -141,10 +261,12 @@ fn test() {
         ("const _ = {};export default _;export {};", "const _ = {};export default _;"),
         ("export {};const _ = {};export default _;", "const _ = {};export default _;"),
         ("const _ = {};export { _ };export {};", "const _ = {};export { _ };"),
-        // ("import _ = require('_');export {};", "import _ = require('_');"),
+        ("import _ = require('_');export {};", "import _ = require('_');"),
+        ("import '_';export {};", "import '_';"),
     ];
 
     Tester::new(NoUselessEmptyExport::NAME, NoUselessEmptyExport::PLUGIN, pass, fail)
+        .with_declaration_file_cases(declaration_file_pass, declaration_file_fail)
         .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -3,12 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{
-    AstNode,
-    ast_util::is_global_require_call,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, ast_util::is_global_require_call, context::LintContext, rule::Rule};
 
 fn no_var_requires_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Require statement not part of import statement.")
@@ -40,6 +35,7 @@ declare_oxc_lint!(
     NoVarRequires,
     typescript,
     restriction,
+    language = typescript,
     version = "0.0.7",
     short_description = "Disallow `require` statements except in import statements.",
 );
@@ -71,10 +67,6 @@ impl Rule for NoVarRequires {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
@@ -118,5 +110,8 @@ fn test() {
         ",
     ];
 
-    Tester::new(NoVarRequires::NAME, NoVarRequires::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(NoVarRequires::NAME, NoVarRequires::PLUGIN, pass, fail)
+        .with_skipped_file_cases("js", vec!["var foo = require('foo');"])
+        .with_skipped_file_cases("cjs", vec!["const foo = require('foo');"])
+        .test_and_snapshot();
 }
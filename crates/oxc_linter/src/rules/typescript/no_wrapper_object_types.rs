@@ -57,6 +57,7 @@ declare_oxc_lint!(
     typescript,
     correctness,
     fix,
+    language = typescript,
     version = "0.8.0",
     short_description = "Disallow the use of wrapper object types.",
 );
@@ -105,10 +106,6 @@ impl Rule for NoWrapperObjectTypes {
             }
         }
     }
-
-    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
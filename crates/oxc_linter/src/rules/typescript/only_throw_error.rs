@@ -1,12 +1,29 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, IdentifierReference, TSType},
+};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    AstNode,
+    ast_util::could_be_error,
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::TypeOrValueSpecifier,
 };
 
+fn object_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected an error object to be thrown.").with_label(span)
+}
+
+fn undef_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not throw undefined.").with_label(span)
+}
+
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct OnlyThrowError(Box<OnlyThrowErrorConfig>);
 
@@ -15,12 +32,20 @@ pub struct OnlyThrowError(Box<OnlyThrowErrorConfig>);
 pub struct OnlyThrowErrorConfig {
     /// An array of type or value specifiers for additional types that are allowed to be thrown.
     /// Use this to allow throwing custom error types.
+    ///
+    /// This option has no effect, as the rule runs without type information.
     pub allow: Vec<TypeOrValueSpecifier>,
     /// Whether to allow rethrowing caught values that are not Error objects.
     pub allow_rethrowing: bool,
     /// Whether to allow throwing values typed as `any`.
+    ///
+    /// Without type information, this applies to untyped parameters and variables, and to
+    /// global identifiers.
     pub allow_throwing_any: bool,
     /// Whether to allow throwing values typed as `unknown`.
+    ///
+    /// Without type information, this applies to identifiers annotated as `unknown`, and to
+    /// caught errors when `allowRethrowing` is `false`.
     pub allow_throwing_unknown: bool,
 }
 
@@ -35,6 +60,14 @@ impl Default for OnlyThrowErrorConfig {
     }
 }
 
+impl std::ops::Deref for OnlyThrowError {
+    type Target = OnlyThrowErrorConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -44,6 +77,11 @@ declare_oxc_lint!(
     ///
     /// It's considered good practice to only throw Error objects (or subclasses of Error). This is because Error objects automatically capture a stack trace, which is useful for debugging. Additionally, some tools and environments expect thrown values to be Error objects.
     ///
+    /// This rule runs without type information. Values which are definitely not Error objects,
+    /// such as literals, objects, functions and classes, are always reported. Identifiers are
+    /// resolved to their declaration, and those which can't be shown to hold an Error object are
+    /// treated as `any` or `unknown`, see `allowThrowingAny` and `allowThrowingUnknown`.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -85,7 +123,7 @@ declare_oxc_lint!(
     /// const error = new Error('Error message');
     /// throw error;
     /// ```
-    OnlyThrowError(tsgolint),
+    OnlyThrowError,
     typescript,
     pedantic,
     config = OnlyThrowErrorConfig,
@@ -101,4 +139,172 @@ impl Rule for OnlyThrowError {
     fn to_configuration(&self) -> Option<Result<serde_json::Value, serde_json::Error>> {
         Some(serde_json::to_value(&*self.0))
     }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ThrowStatement(stmt) = node.kind() else {
+            return;
+        };
+
+        let argument = &stmt.argument;
+        if let Expression::Identifier(ident) = argument.get_inner_expression()
+            && ident.name == "undefined"
+            && ctx.is_reference_to_global_variable(ident)
+        {
+            ctx.diagnostic(undef_diagnostic(argument.span()));
+            return;
+        }
+
+        if !self.is_allowed(argument, ctx) {
+            ctx.diagnostic(object_diagnostic(argument.span()));
+        }
+    }
+}
+
+impl OnlyThrowError {
+    fn is_allowed(&self, expr: &Expression, ctx: &LintContext) -> bool {
+        // Unlike `eslint/no-throw-literal`, both branches have to be errors, as the thrown value
+        // has the type of either branch.
+        if let Expression::ConditionalExpression(expr) = expr.get_inner_expression() {
+            return self.is_allowed(&expr.consequent, ctx) && self.is_allowed(&expr.alternate, ctx);
+        }
+
+        if !could_be_error(ctx.semantic(), expr) {
+            return false;
+        }
+
+        let Expression::Identifier(ident) = expr.get_inner_expression() else {
+            return true;
+        };
+        let thrown_type = thrown_identifier_type(ident, ctx);
+        ctx.trace(|| {
+            let assumed = match thrown_type {
+                ThrownType::Error => "an Error object",
+                ThrownType::Any => "of type `any`",
+                ThrownType::Unknown => "of type `unknown`",
+                ThrownType::Caught => "a rethrown `catch` parameter",
+            };
+            format!("`{}` is assumed to be {assumed}", ident.name)
+        });
+        match thrown_type {
+            ThrownType::Error => true,
+            ThrownType::Any => self.allow_throwing_any,
+            ThrownType::Unknown => self.allow_throwing_unknown,
+            ThrownType::Caught => self.allow_rethrowing || self.allow_throwing_unknown,
+        }
+    }
+}
+
+/// Best guess at the type of a thrown identifier which could hold an Error object.
+enum ThrownType {
+    /// Assumed to be an Error object, e.g. `new Error()` or a type other than `any` and `unknown`.
+    Error,
+    Any,
+    Unknown,
+    /// The parameter of a `catch` clause.
+    Caught,
+}
+
+fn thrown_identifier_type(ident: &IdentifierReference, ctx: &LintContext) -> ThrownType {
+    let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+        return ThrownType::Any;
+    };
+
+    let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+    match declaration.kind() {
+        AstKind::VariableDeclarator(decl) => {
+            if let Some(annotation) = &decl.type_annotation {
+                return annotated_type(&annotation.type_annotation);
+            }
+            if decl.init.as_ref().is_some_and(|init| {
+                matches!(init.get_inner_expression(), Expression::NewExpression(_))
+            }) {
+                ThrownType::Error
+            } else {
+                ThrownType::Any
+            }
+        }
+        AstKind::FormalParameter(param) => param
+            .type_annotation
+            .as_ref()
+            .map_or(ThrownType::Any, |annotation| annotated_type(&annotation.type_annotation)),
+        AstKind::CatchParameter(param) => match &param.type_annotation {
+            Some(annotation) if matches!(annotation.type_annotation, TSType::TSAnyKeyword(_)) => {
+                ThrownType::Any
+            }
+            _ => ThrownType::Caught,
+        },
+        _ => ThrownType::Error,
+    }
+}
+
+fn annotated_type(ty: &TSType) -> ThrownType {
+    match ty {
+        TSType::TSAnyKeyword(_) => ThrownType::Any,
+        TSType::TSUnknownKeyword(_) => ThrownType::Unknown,
+        _ => ThrownType::Error,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let strict = json!([{ "allowThrowingAny": false, "allowThrowingUnknown": false }]);
+    let no_rethrow = json!([{ "allowRethrowing": false, "allowThrowingUnknown": false }]);
+
+    let pass = vec![
+        ("throw new Error();", None),
+        ("throw new TypeError('error');", None),
+        ("class CustomError extends Error {} throw new CustomError();", None),
+        ("const error = new Error(); throw error;", None),
+        ("throw foo();", None),
+        ("throw foo.bar;", None),
+        ("async function foo() { throw await bar; }", None),
+        ("throw foo ? new Error() : bar;", None),
+        ("throw foo ? new Error() : new TypeError();", None),
+        ("throw foo;", None),
+        ("function foo(e) { throw e; }", None),
+        ("function foo(e: any) { throw e; }", None),
+        ("function foo(e: unknown) { throw e; }", None),
+        ("try {} catch (e) { throw e; }", None),
+        ("const undefined = new Error(); throw undefined;", None),
+        ("const error = new Error(); throw error;", Some(strict.clone())),
+        ("function foo(e: TypeError) { throw e; }", Some(strict.clone())),
+        ("import { error } from 'foo'; throw error;", Some(strict.clone())),
+        ("let error: Error = foo(); throw error;", Some(strict.clone())),
+        ("try {} catch (e) { throw e; }", Some(strict.clone())),
+        ("throw foo();", Some(strict.clone())),
+        (
+            "try {} catch (e) { throw e; }",
+            Some(json!([{ "allowRethrowing": false, "allowThrowingUnknown": true }])),
+        ),
+        ("function foo(e) { throw e; }", Some(no_rethrow.clone())),
+    ];
+
+    let fail = vec![
+        ("throw 'error';", None),
+        ("throw 0;", None),
+        ("throw false;", None),
+        ("throw null;", None),
+        ("throw undefined;", None),
+        ("throw { message: 'error' };", None),
+        ("throw `${error}`;", None),
+        ("throw 'an ' + new Error();", None),
+        ("throw foo ? new Error() : 'error';", None),
+        ("throw foo ? 'error' : 0;", None),
+        ("const error = 'Something went wrong'; throw error;", None),
+        ("function foo() {} throw foo;", None),
+        ("function foo(e: string) { throw e; }", None),
+        ("throw foo;", Some(strict.clone())),
+        ("function foo(e) { throw e; }", Some(strict.clone())),
+        ("function foo(e: any) { throw e; }", Some(strict.clone())),
+        ("function foo(e: unknown) { throw e; }", Some(strict.clone())),
+        ("const error = foo(); throw error;", Some(strict)),
+        ("try {} catch (e) { throw e; }", Some(no_rethrow.clone())),
+        ("try {} catch (e: any) { throw e; }", Some(json!([{ "allowThrowingAny": false }]))),
+        ("function foo(e: unknown) { throw e; }", Some(no_rethrow)),
+    ];
+
+    Tester::new(OnlyThrowError::NAME, OnlyThrowError::PLUGIN, pass, fail).test_and_snapshot();
 }
@@ -17,7 +17,7 @@ use oxc_str::Str;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -124,6 +124,7 @@ declare_oxc_lint!(
     typescript,
     style,
     config = ParameterPropertiesConfig,
+    language = typescript,
     version = "1.48.0",
     short_description = "Requires or disallows parameter properties in class constructors.",
 );
@@ -147,10 +148,6 @@ impl Rule for ParameterProperties {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[derive(Default)]
@@ -6,11 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn prefer_as_const_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Expected a `const` assertion instead of a literal type annotation.")
@@ -57,6 +53,7 @@ declare_oxc_lint!(
     typescript,
     correctness,
     conditional_fix,
+    language = typescript,
     version = "0.0.8",
     short_description = "Enforce the use of `as const` over literal types.",
 );
@@ -104,10 +101,6 @@ impl Rule for PreferAsConst {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn literal_type_span_if_matches(
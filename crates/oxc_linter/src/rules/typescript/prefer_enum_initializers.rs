@@ -1,14 +1,10 @@
-use oxc_ast::{AstKind, ast::TSEnumMemberName};
+use oxc_ast::{AstKind, ast::Expression};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    fixer::RuleFixer,
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, fixer::RuleFixer, rule::Rule};
 
 fn prefer_enum_initializers_diagnostic(member_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -36,6 +32,10 @@ declare_oxc_lint!(
     ///
     /// In projects where the value of `enum` members are important, allowing implicit values for enums can cause bugs if enums are modified over time.
     ///
+    /// The suggested fix initializes the member to the value TypeScript currently gives it, so
+    /// behavior is preserved. This is only possible when the value of the previous member is a
+    /// numeric literal.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -58,6 +58,7 @@ declare_oxc_lint!(
     typescript,
     pedantic,
     suggestion,
+    language = typescript,
     version = "0.3.2",
     short_description = "Require each enum member value to be explicitly initialized.",
 );
@@ -68,38 +69,62 @@ impl Rule for PreferEnumInitializers {
             return;
         };
 
-        for (index, member) in enum_body.members.iter().enumerate() {
-            if member.initializer.is_none()
-                && let TSEnumMemberName::Identifier(i) = &member.id
-            {
-                let member_name = i.name.as_str();
-                let name_span = i.span;
-                let fixer = RuleFixer::new(FixKind::Suggestion, ctx);
-                ctx.diagnostic_with_suggestions(
-                    prefer_enum_initializers_diagnostic(member_name, member.span),
-                    [
-                        fixer
-                            .replace(name_span, format!("{member_name} = {index}"))
-                            .with_message(format!("Initialize to `{index}` (the enum index).")),
-                        fixer
-                            .replace(name_span, format!("{member_name} = {}", index + 1))
-                            .with_message(format!(
-                                "Initialize to `{}` (the enum index + 1).",
-                                index + 1
-                            )),
-                        fixer
-                            .replace(name_span, format!("{member_name} = '{member_name}'"))
-                            .with_message(format!(
-                                "Initialize to `'{member_name}'` (the enum member name)."
-                            )),
-                    ],
-                );
+        // The value TypeScript gives the next uninitialized member, if known.
+        let mut next_value = Some(0.0);
+        for member in &enum_body.members {
+            if let Some(initializer) = &member.initializer {
+                next_value = numeric_value(initializer).map(|value| value + 1.0);
+                continue;
             }
+            let value = next_value;
+            next_value = value.map(|value| value + 1.0);
+
+            let member_name = member.id.static_name();
+            let name_span = member.id.span();
+            let fixer = RuleFixer::new(FixKind::Suggestion, ctx);
+            let implicit_value = value.map(|value| {
+                let value = format_number(value);
+                fixer
+                    .insert_text_after_range(name_span, format!(" = {value}"))
+                    .with_message(format!("Initialize to `{value}`, the value it has now."))
+            });
+            let member_name_value = (!member_name.contains(['\'', '\\'])).then(|| {
+                fixer
+                    .insert_text_after_range(name_span, format!(" = '{member_name}'"))
+                    .with_message(format!(
+                        "Initialize to `'{member_name}'` (the enum member name)."
+                    ))
+            });
+            ctx.diagnostic_with_suggestions(
+                prefer_enum_initializers_diagnostic(&member_name, name_span),
+                implicit_value.into_iter().chain(member_name_value),
+            );
         }
     }
+}
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
+/// The value of a numeric literal initializer, optionally negated, e.g. `1` or `-1`.
+fn numeric_value(expr: &Expression) -> Option<f64> {
+    match expr.without_parentheses() {
+        Expression::NumericLiteral(lit) => Some(lit.value),
+        Expression::UnaryExpression(unary_expr) => {
+            let value = numeric_value(&unary_expr.argument)?;
+            match unary_expr.operator {
+                UnaryOperator::UnaryPlus => Some(value),
+                UnaryOperator::UnaryNegation => Some(-value),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 9_007_199_254_740_992.0 {
+        (value as i64).to_string()
+    } else {
+        value.to_string()
     }
 }
 
@@ -128,6 +153,12 @@ fn test() {
               Down = 'Down',
             }
                 ",
+        "
+            const enum Flags {
+              A = 1 << 0,
+              B = 1 << 1,
+            }
+                ",
     ];
 
     let fail = vec![
@@ -154,47 +185,80 @@ fn test() {
               Down = 'Down',
             }
                   ",
+        "
+            const enum Status {
+              Open = 1,
+              Close,
+            }
+                  ",
+        "
+            const enum Flags {
+              A = 1 << 0,
+              B,
+            }
+                  ",
+        "
+            enum Quoted {
+              'a b',
+            }
+                  ",
     ];
 
-    // Each test case provides 3 suggestions: index, index+1, and member name as string
-    // When multiple members are uninitialized, all fixes for the same suggestion type are applied
+    // Uninitialized members are suggested to be initialized to their implicit value, or to their
+    // name. When multiple members are uninitialized, all fixes for the same suggestion are applied.
     let fix: Vec<ExpectFixTestCase> = vec![
+        ("enum Direction { Up, }", ("enum Direction { Up = 0, }", "enum Direction { Up = 'Up', }"))
+            .into(),
         (
-            "enum Direction { Up, }",
+            "enum Direction { Up, Down, }",
             (
-                "enum Direction { Up = 0, }",
-                "enum Direction { Up = 1, }",
-                "enum Direction { Up = 'Up', }",
+                "enum Direction { Up = 0, Down = 1, }",
+                "enum Direction { Up = 'Up', Down = 'Down', }",
             ),
         )
             .into(),
+        // The value of `Down` is unknown, TypeScript requires an initializer after a string.
+        ("enum Direction { Up = 'Up', Down, }", "enum Direction { Up = 'Up', Down = 'Down', }")
+            .into(),
         (
-            "enum Direction { Up, Down, }",
+            "enum Direction { Up, Down = 'Down', }",
             (
-                "enum Direction { Up = 0, Down = 1, }",
-                "enum Direction { Up = 1, Down = 2, }",
+                "enum Direction { Up = 0, Down = 'Down', }",
                 "enum Direction { Up = 'Up', Down = 'Down', }",
             ),
         )
             .into(),
         (
-            "enum Direction { Up = 'Up', Down, }",
+            "const enum Status { Open = 1, Close, }",
             (
-                "enum Direction { Up = 'Up', Down = 1, }",
-                "enum Direction { Up = 'Up', Down = 2, }",
-                "enum Direction { Up = 'Up', Down = 'Down', }",
+                "const enum Status { Open = 1, Close = 2, }",
+                "const enum Status { Open = 1, Close = 'Close', }",
             ),
         )
             .into(),
         (
-            "enum Direction { Up, Down = 'Down', }",
+            "const enum Status { Open = -1, Closed, Pending }",
             (
-                "enum Direction { Up = 0, Down = 'Down', }",
-                "enum Direction { Up = 1, Down = 'Down', }",
-                "enum Direction { Up = 'Up', Down = 'Down', }",
+                "const enum Status { Open = -1, Closed = 0, Pending = 1 }",
+                "const enum Status { Open = -1, Closed = 'Closed', Pending = 'Pending' }",
+            ),
+        )
+            .into(),
+        (
+            "enum Status { Open = 0.5, Closed }",
+            (
+                "enum Status { Open = 0.5, Closed = 1.5 }",
+                "enum Status { Open = 0.5, Closed = 'Closed' }",
             ),
         )
             .into(),
+        ("const enum Flags { A = 1 << 0, B, }", "const enum Flags { A = 1 << 0, B = 'B', }").into(),
+        (
+            "enum Quoted { 'a b', }",
+            ("enum Quoted { 'a b' = 0, }", "enum Quoted { 'a b' = 'a b', }"),
+        )
+            .into(),
+        ("enum Quoted { \"it's\", }", "enum Quoted { \"it's\" = 0, }").into(),
     ];
 
     Tester::new(PreferEnumInitializers::NAME, PreferEnumInitializers::PLUGIN, pass, fail)
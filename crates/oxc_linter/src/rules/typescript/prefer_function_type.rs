@@ -6,12 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    fixer::Fix,
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, fixer::Fix, rule::Rule};
 
 fn prefer_function_type_diagnostic(suggestion: &str, span: Span) -> OxcDiagnostic {
     // FIXME: use imperative message phrasing
@@ -86,6 +81,7 @@ declare_oxc_lint!(
     typescript,
     style,
     conditional_fix,
+    language = typescript,
     version = "0.2.11",
     short_description = "Enforce using function types instead of interfaces with call signatures.",
 );
@@ -289,7 +285,14 @@ fn check_member(member: &TSSignature, node: &AstNode<'_>, ctx: &LintContext<'_>)
 impl Rule for PreferFunctionType {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::TSInterfaceDeclaration(decl) => {
+            // `export default interface` is reported with its export, without a fix, since
+            // `export default type` is not valid.
+            AstKind::TSInterfaceDeclaration(decl)
+                if !matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    AstKind::ExportDefaultDeclaration(_)
+                ) =>
+            {
                 let body = &decl.body.body;
 
                 if !has_one_super_type(decl) && body.len() == 1 {
@@ -370,10 +373,6 @@ impl Rule for PreferFunctionType {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
@@ -1,14 +1,17 @@
-use oxc_ast::{AstKind, ast::Expression};
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSEnumDeclaration},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -25,6 +28,17 @@ fn prefer_literal_enum_member_diagnostic(span: Span) -> OxcDiagnostic {
 pub struct PreferLiteralEnumMember {
     /// When set to `true`, allows bitwise expressions in enum member initializers.
     /// This includes bitwise NOT (`~`), AND (`&`), OR (`|`), XOR (`^`), and shift operators (`<<`, `>>`, `>>>`).
+    /// Operands may be literals or other members of the same enum, referenced as `A`, `Foo.A`
+    /// or `Foo['A']`.
+    ///
+    /// Examples of **correct** code with `{ "allowBitwiseExpressions": true }`:
+    /// ```ts
+    /// enum Foo {
+    ///   A = 1 << 0,
+    ///   B = 1 << 1,
+    ///   C = A | Foo.B,
+    /// }
+    /// ```
     allow_bitwise_expressions: bool,
 }
 
@@ -55,6 +69,7 @@ declare_oxc_lint!(
     typescript,
     restriction,
     config = PreferLiteralEnumMember,
+    language = typescript,
     version = "0.3.2",
     short_description = "Explicit enum values must only be literal values (string, number, boolean, etc.).",
 );
@@ -65,62 +80,88 @@ impl Rule for PreferLiteralEnumMember {
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::TSEnumMember(decl) = node.kind() else {
-            return;
-        };
-        let Some(initializer) = &decl.initializer else {
+        let AstKind::TSEnumDeclaration(decl) = node.kind() else {
             return;
         };
-        if initializer.is_literal() {
-            return;
-        }
 
-        if let Expression::TemplateLiteral(template) = initializer
-            && template.expressions.is_empty()
-        {
-            return;
+        for member in &decl.body.members {
+            let Some(initializer) = &member.initializer else {
+                continue;
+            };
+            if !self.is_allowed_initializer(decl, initializer, false) {
+                ctx.diagnostic(prefer_literal_enum_member_diagnostic(member.id.span()));
+            }
         }
+    }
+}
 
-        if let Expression::UnaryExpression(unary_expr) = initializer
-            && unary_expr.argument.is_literal()
-        {
-            if matches!(
-                unary_expr.operator,
-                UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation,
-            ) {
-                return;
-            }
+impl PreferLiteralEnumMember {
+    /// Whether `expr` is a literal, or with `allowBitwiseExpressions`, a bitwise expression of
+    /// literals and members of `decl`. Members can only be referenced in a bitwise expression
+    /// (`part_of_bitwise_expression`).
+    fn is_allowed_initializer(
+        &self,
+        decl: &TSEnumDeclaration,
+        expr: &Expression,
+        part_of_bitwise_expression: bool,
+    ) -> bool {
+        let expr = expr.without_parentheses();
+        if part_of_bitwise_expression && is_self_enum_member(decl, expr) {
+            return true;
+        }
 
-            if self.allow_bitwise_expressions
-                && matches!(unary_expr.operator, UnaryOperator::BitwiseNot)
-            {
-                return;
+        match expr {
+            Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+            Expression::UnaryExpression(unary_expr) => match unary_expr.operator {
+                UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation => self
+                    .is_allowed_initializer(decl, &unary_expr.argument, part_of_bitwise_expression),
+                UnaryOperator::BitwiseNot => {
+                    self.allow_bitwise_expressions
+                        && self.is_allowed_initializer(decl, &unary_expr.argument, true)
+                }
+                _ => false,
+            },
+            Expression::BinaryExpression(binary_expr) => {
+                self.allow_bitwise_expressions
+                    && matches!(
+                        binary_expr.operator,
+                        BinaryOperator::BitwiseOR
+                            | BinaryOperator::BitwiseAnd
+                            | BinaryOperator::BitwiseXOR
+                            | BinaryOperator::ShiftLeft
+                            | BinaryOperator::ShiftRight
+                            | BinaryOperator::ShiftRightZeroFill
+                    )
+                    && self.is_allowed_initializer(decl, &binary_expr.left, true)
+                    && self.is_allowed_initializer(decl, &binary_expr.right, true)
             }
+            _ => expr.is_literal(),
         }
+    }
+}
 
-        if self.allow_bitwise_expressions
-            && let Expression::BinaryExpression(binary_expr) = initializer
-            && matches!(
-                binary_expr.operator,
-                BinaryOperator::BitwiseOR
-                    | BinaryOperator::BitwiseAnd
-                    | BinaryOperator::BitwiseXOR
-                    | BinaryOperator::ShiftLeft
-                    | BinaryOperator::ShiftRight
-                    | BinaryOperator::ShiftRightZeroFill
-            )
-            && binary_expr.left.is_literal()
-            && binary_expr.right.is_literal()
-        {
-            return;
+/// Whether `expr` refers to a member of `decl` by its name, e.g. `A`, `Foo.A` or `Foo['A']`
+/// in `enum Foo { A }`. Names are resolved against the members of `decl` only.
+fn is_self_enum_member(decl: &TSEnumDeclaration, expr: &Expression) -> bool {
+    let name = if let Expression::Identifier(ident) = expr {
+        ident.name.as_str()
+    } else {
+        let Some(member_expr) = expr.as_member_expression() else {
+            return false;
+        };
+        if !is_enum_name(decl, member_expr.object()) {
+            return false;
         }
+        let Some(name) = member_expr.static_property_name() else {
+            return false;
+        };
+        name
+    };
+    decl.body.members.iter().any(|member| member.id.static_name() == name)
+}
 
-        ctx.diagnostic(prefer_literal_enum_member_diagnostic(decl.span));
-    }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
+fn is_enum_name(decl: &TSEnumDeclaration, expr: &Expression) -> bool {
+    matches!(expr.without_parentheses(), Expression::Identifier(ident) if ident.name == decl.id.name)
 }
 
 #[test]
@@ -222,81 +263,102 @@ fn test() {
                   ",
             Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
         ),
-        // TODO: Fix these to allow `|`.
-        // (
-        //     "
-        //     enum Foo {
-        //       A = 1 << 0,
-        //       B = 1 >> 0,
-        //       C = A | B,
-        //     }
-        //           ",
-        //     Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
-        // ),
-        // (
-        //     "
-        //     enum Foo {
-        //       A = 1 << 0,
-        //       B = 1 >> 0,
-        //       C = Foo.A | Foo.B,
-        //     }
-        //           ",
-        //     Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
-        // ),
-        // (
-        //     "
-        //     enum Foo {
-        //       A = 1 << 0,
-        //       B = 1 >> 0,
-        //       C = Foo['A'] | B,
-        //     }
-        //           ",
-        //     Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
-        // ),
-        // (
-        //     "
-        //     enum Foo {
-        //       A = 1 << 0,
-        //       B = 1 << 1,
-        //       C = 1 << 2,
-        //       D = A | B | C,
-        //     }
-        //           ",
-        //     Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
-        // ),
-        // (
-        //     "
-        //     enum Foo {
-        //       A = 1 << 0,
-        //       B = 1 << 1,
-        //       C = 1 << 2,
-        //       D = Foo.A | Foo.B | Foo.C,
-        //     }
-        //           ",
-        //     Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
-        // ),
-        // (
-        //     "
-        //     enum Foo {
-        //       A = 1 << 0,
-        //       B = 1 << 1,
-        //       C = 1 << 2,
-        //       D = Foo.A | (Foo.B & ~Foo.C),
-        //     }
-        //           ",
-        //     Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
-        // ),
-        // TODO: Fix
-        // (
-        //     "
-        //     enum Foo {
-        //       A = 1 << 0,
-        //       B = 1 << 1,
-        //       C = 1 << 2,
-        //       D = Foo.A | -Foo.B,
-        //     }",
-        //     Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
-        // ),
+        (
+            "
+            enum Foo {
+              A = 1 << 0,
+              B = 1 >> 0,
+              C = A | B,
+            }
+                  ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+            enum Foo {
+              A = 1 << 0,
+              B = 1 >> 0,
+              C = Foo.A | Foo.B,
+            }
+                  ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+            enum Foo {
+              A = 1 << 0,
+              B = 1 >> 0,
+              C = Foo['A'] | B,
+            }
+                  ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+            enum Foo {
+              A = 1 << 0,
+              B = 1 << 1,
+              C = 1 << 2,
+              D = A | B | C,
+            }
+                  ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+            enum Foo {
+              A = 1 << 0,
+              B = 1 << 1,
+              C = 1 << 2,
+              D = Foo.A | Foo.B | Foo.C,
+            }
+                  ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+            enum Foo {
+              A = 1 << 0,
+              B = 1 << 1,
+              C = 1 << 2,
+              D = Foo.A | (Foo.B & ~Foo.C),
+            }
+                  ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+            enum Foo {
+              A = 1 << 0,
+              B = 1 << 1,
+              C = 1 << 2,
+              D = Foo.A | -Foo.B,
+            }",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+            const enum Flags {
+              None = 0,
+              Read = 1 << 0,
+              Write = 1 << 1,
+              ReadWrite = Read | Write,
+              'Quoted Name' = 1 << 2,
+              All = ReadWrite | Flags['Quoted Name'],
+            }
+                  ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+            const enum Direction {
+              Up = 1,
+              Down = -1,
+              Left = 'left',
+            }
+                  ",
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -454,6 +516,37 @@ fn test() {
                   ",
             None,
         ),
+        (
+            "
+            const enum Flags {
+              Read = 1 << 0,
+              Write = 1 << 1,
+              ReadWrite = Read | Write,
+            }
+                  ",
+            None,
+        ),
+        (
+            "
+            const enum Flags {
+              Read = 1,
+              Write = Read * 2,
+              Execute = Flags.Read,
+            }
+                  ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+            const Read = 1;
+            const enum Flags {
+              Write = 1 << 1,
+              ReadWrite = Read | Write,
+              Other = Write | Mask.Read,
+            }
+                  ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
     ];
 
     Tester::new(PreferLiteralEnumMember::NAME, PreferLiteralEnumMember::PLUGIN, pass, fail)
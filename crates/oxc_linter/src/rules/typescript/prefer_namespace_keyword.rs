@@ -6,11 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn prefer_namespace_keyword_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Use `namespace` instead of `module` to declare custom TypeScript modules.")
@@ -62,6 +58,7 @@ declare_oxc_lint!(
     typescript,
     correctness,
     fix,
+    language = typescript,
     version = "0.7.0",
     short_description = "Require using `namespace` keyword over `module` keyword to declare custom TypeScript modules.",
 );
@@ -91,10 +88,6 @@ impl Rule for PreferNamespaceKeyword {
             fixer.replace(Span::sized(span_start, 6), "namespace")
         });
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[test]
@@ -4,10 +4,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{context::LintContext, rule::Rule};
 
 fn prefer_ts_expect_error_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Enforce using `@ts-expect-error` over `@ts-ignore`")
@@ -57,6 +54,7 @@ declare_oxc_lint!(
     typescript,
     pedantic,
     fix,
+    language = typescript,
     version = "0.2.11",
     short_description = "Enforce using @ts-expect-error over @ts-ignore.",
 );
@@ -91,10 +89,6 @@ impl Rule for PreferTsExpectError {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn get_last_comment_line(comment: Comment, raw: &str) -> &str {
@@ -7,7 +7,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::AlwaysNever,
 };
@@ -89,6 +89,7 @@ declare_oxc_lint!(
     typescript,
     correctness,
     config = TripleSlashReferenceConfig,
+    language = typescript,
     version = "0.2.0",
     short_description = "Disallow certain triple slash directives in favor of ES module import declarations.",
 );
@@ -151,10 +152,6 @@ impl Rule for TripleSlashReference {
             }
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 fn get_attr_key_and_value(raw: &str) -> Option<(String, String)> {
@@ -19,7 +19,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -73,6 +73,7 @@ declare_oxc_lint!(
     typescript,
     style,
     config = UnifiedSignaturesOptions,
+    language = typescript,
     version = "1.48.0",
     short_description = "Disallow overload signatures that can be unified into one.",
 );
@@ -106,10 +107,6 @@ impl Rule for UnifiedSignatures {
             _ => {}
         }
     }
-
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
-    }
 }
 
 #[derive(Clone, Copy)]
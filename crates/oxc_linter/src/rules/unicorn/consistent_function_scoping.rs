@@ -156,6 +156,7 @@ declare_oxc_lint!(
     pending,
     config = ConsistentFunctionScoping,
     version = "0.8.0",
+    works_on_d_ts = false,
     short_description = "Disallow functions that are declared in a scope which does not capture any variables from the outer scope.",
 );
 
@@ -291,11 +292,6 @@ impl Rule for ConsistentFunctionScoping {
             function_name,
         ));
     }
-
-    fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
-        // .d.ts files are never run, so there are no perf considerations for them.
-        !ctx.source_type().is_typescript_definition()
-    }
 }
 
 #[derive(Default)]
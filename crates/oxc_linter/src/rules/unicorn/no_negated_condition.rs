@@ -133,6 +133,11 @@ d();
 } else {
 e();
 }",
+        "if (!a) { b } // b
+else { c }",
+        r"if (!a) /* b */ { b } else { c }",
+        r"!a ? b /* b */ : /* c */ c",
+        r"!a /* a */ ? b : c",
     ];
 
     let fix = vec![
@@ -283,6 +288,16 @@ e();
 d();
 }",
         ),
+        (
+            "if (!a) { b } // b
+else { c }",
+            "if (a) { c }
+else { b } // b
+",
+        ),
+        (r"if (!a) /* b */ { b } else { c }", r"if (!a) /* b */ { b } else { c }"),
+        (r"!a ? b /* b */ : /* c */ c", r"a ? /* c */ c : b /* b */"),
+        (r"!a /* a */ ? b : c", r"!a /* a */ ? b : c"),
     ];
 
     Tester::new(NoNegatedCondition::NAME, NoNegatedCondition::PLUGIN, pass, fail)
@@ -117,6 +117,7 @@ fn test() {
         "const foo = i > 5 ? (i < 100 ? true : false) : (i < 100 ? true : false);",
         "const foo = i > 5 ? true : (i < 100 ? FOO(i > 50 ? false : true) : false);",
         "foo ? doBar() : doBaz();",
+        "const foo = (i > 5 ? i < 100 : i > 0) ? true : false;",
         "var foo = bar === baz ? qux : quxx;",
         "const pluginName = isAbsolute ?
                 pluginPath.slice(pluginPath.lastIndexOf('/') + 1) :
@@ -135,6 +136,7 @@ fn test() {
         "const foo = i > 5 ? true : i < 100 ? true : false;",
         "foo ? bar : baz === qux ? quxx : foobar;",
         "foo ? baz === qux ? quxx : foobar : bar;",
+        "foo = a ? b : c ? d : e;",
         "const foo = i > 5 ? i < 100 ? true : false : i < 100 ? true : false;",
         "const foo = i > 5 ? true : (i < 100 ? true : (i < 1000 ? true : false));",
         "const foo = a ?
@@ -165,6 +167,7 @@ fn test() {
         ),
         ("foo ? bar : baz === qux ? quxx : foobar;", "foo ? bar : (baz === qux ? quxx : foobar);"),
         ("foo ? baz === qux ? quxx : foobar : bar;", "foo ? (baz === qux ? quxx : foobar) : bar;"),
+        ("foo = a ? b : c ? d : e;", "foo = a ? b : (c ? d : e);"),
     ];
 
     Tester::new(NoNestedTernary::NAME, NoNestedTernary::PLUGIN, pass, fail)
@@ -75,7 +75,7 @@ fn is_useless_check<'a>(
     left: &'a Expression<'a>,
     right: &'a Expression<'a>,
     operator: LogicalOperator,
-) -> Option<OxcDiagnostic> {
+) -> Option<Span> {
     let every_condition = ConditionDTO {
         property_name: "every",
         binary_operators: vec![BinaryOperator::StrictEquality],
@@ -146,15 +146,7 @@ fn is_useless_check<'a>(
         _ => false,
     };
 
-    if l && r {
-        Some(if active_condition.property_name == "every" {
-            every(binary_expression_span?)
-        } else {
-            some(binary_expression_span?)
-        })
-    } else {
-        None
-    }
+    if l && r { binary_expression_span } else { None }
 }
 
 impl Rule for NoUselessLengthCheck {
@@ -163,11 +155,29 @@ impl Rule for NoUselessLengthCheck {
             if ![LogicalOperator::And, LogicalOperator::Or].contains(&log_expr.operator) {
                 return;
             }
+            // Nested expressions with the same operator are checked with the outermost one.
+            if let Some(AstKind::LogicalExpression(parent)) = ctx
+                .nodes()
+                .ancestor_kinds(node.id())
+                .find(|kind| !matches!(kind, AstKind::ParenthesizedExpression(_)))
+                && parent.operator == log_expr.operator
+            {
+                return;
+            }
             let mut flat_exprs = Vec::new();
             make_flat_logical_expression(log_expr, &mut flat_exprs);
+            // A length check between two calls is part of two windows, but reported once.
+            let mut last_reported = None;
             for window in flat_exprs.windows(2) {
-                if let Some(diag) = is_useless_check(window[0], window[1], log_expr.operator) {
-                    ctx.diagnostic(diag);
+                if let Some(span) = is_useless_check(window[0], window[1], log_expr.operator)
+                    && last_reported != Some(span)
+                {
+                    last_reported = Some(span);
+                    ctx.diagnostic(if log_expr.operator == LogicalOperator::Or {
+                        every(span)
+                    } else {
+                        some(span)
+                    });
                 }
             }
         }
@@ -120,7 +120,7 @@ impl Rule for PreferAt {
                 self.handle_computed_member(computed, node, ctx);
             }
             AstKind::CallExpression(call) if !is_assignment_target(node, ctx) => {
-                self.check_call_expression(call, node, ctx);
+                self.check_call_expression(call, ctx);
             }
             _ => {}
         }
@@ -228,12 +228,7 @@ impl PreferAt {
         false
     }
 
-    fn check_call_expression<'a>(
-        &self,
-        call_expr: &CallExpression<'a>,
-        node: &AstNode<'a>,
-        ctx: &LintContext<'a>,
-    ) {
+    fn check_call_expression<'a>(&self, call_expr: &CallExpression<'a>, ctx: &LintContext<'a>) {
         let Some(MemberExpression::StaticMemberExpression(static_member)) =
             call_expr.callee.get_member_expr()
         else {
@@ -250,15 +245,6 @@ impl PreferAt {
             }
             _ => {}
         }
-
-        // Check for array.slice(-N)[0]
-        let parent_kind = ctx.nodes().parent_kind(node.id());
-        if let AstKind::ComputedMemberExpression(computed) = parent_kind
-            && computed.expression.get_inner_expression().is_number_0()
-        {
-            let parent_id = ctx.nodes().parent_id(node.id());
-            Self::check_slice_index_access(call_expr, computed, parent_id, ctx);
-        }
     }
 
     fn check_char_at<'a>(
@@ -1,9 +1,14 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, ast::Expression};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    ast_util::{get_declaration_of_variable, is_global_new_expression},
+    context::LintContext,
+    rule::Rule,
+};
 
 fn prefer_blob_reading_methods_diagnostic(
     span: Span,
@@ -24,6 +29,9 @@ declare_oxc_lint!(
     ///
     /// Recommends using `Blob#text()` and `Blob#arrayBuffer()` over `FileReader#readAsText()` and `FileReader#readAsArrayBuffer()`.
     ///
+    /// Calls on objects which are known to be created by something other than the global
+    /// `FileReader`, such as a local class named `FileReader`, are ignored.
+    ///
     /// ### Why is this bad?
     ///
     /// `FileReader` predates promises, and the newer [`Blob#arrayBuffer()`](https://developer.mozilla.org/en-US/docs/Web/API/Blob/arrayBuffer) and [`Blob#text()`](https://developer.mozilla.org/en-US/docs/Web/API/Blob/text) methods are much cleaner and easier to use.
@@ -84,10 +92,38 @@ impl Rule for PreferBlobReadingMethods {
             _ => return,
         };
 
+        if is_file_reader(member_expr.object(), ctx) == Some(false) {
+            return;
+        }
+
         ctx.diagnostic(prefer_blob_reading_methods_diagnostic(span, replacement, current));
     }
 }
 
+/// Whether `expr` is created by `new FileReader()` with the global `FileReader`, or `None` when
+/// that's unknown, e.g. for a parameter.
+fn is_file_reader<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> Option<bool> {
+    match expr.get_inner_expression() {
+        Expression::NewExpression(new_expr) => {
+            Some(is_global_new_expression(new_expr, "FileReader", ctx))
+        }
+        Expression::Identifier(ident) => {
+            let AstKind::VariableDeclarator(declarator) =
+                get_declaration_of_variable(ident, ctx)?.kind()
+            else {
+                return None;
+            };
+            let Some(Expression::NewExpression(new_expr)) =
+                declarator.init.as_ref().map(Expression::get_inner_expression)
+            else {
+                return None;
+            };
+            Some(is_global_new_expression(new_expr, "FileReader", ctx))
+        }
+        _ => None,
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -100,9 +136,20 @@ fn test() {
         "fileReader.readAsDataURL(blob)",
         "fileReader.readAsBinaryString(blob)",
         r#"fileReader.readAsText(blob, "ascii")"#,
+        // Not the global `FileReader`
+        "class FileReader {} new FileReader().readAsText(blob)",
+        r#"import { FileReader } from "file-reader"; const reader = new FileReader(); reader.readAsArrayBuffer(blob)"#,
+        "function read(FileReader) { const reader = new FileReader(); reader.readAsText(blob) }",
+        "const reader = new TextReader(); reader.readAsText(blob)",
     ];
 
-    let fail = vec!["fileReader.readAsArrayBuffer(blob)", "fileReader.readAsText(blob)"];
+    let fail = vec![
+        "fileReader.readAsArrayBuffer(blob)",
+        "fileReader.readAsText(blob)",
+        "new FileReader().readAsText(blob)",
+        "const reader = new FileReader(); reader.readAsArrayBuffer(blob)",
+        "let reader = new (FileReader)(); reader.readAsText(blob)",
+    ];
 
     Tester::new(PreferBlobReadingMethods::NAME, PreferBlobReadingMethods::PLUGIN, pass, fail)
         .test_and_snapshot();
@@ -3,7 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, context::LintContext, rule::Rule, utils::is_value_not_usable};
 
 fn prefer_dom_node_append_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Prefer `Node#append()` over `Node#appendChild()` for DOM nodes.")
@@ -19,6 +19,9 @@ declare_oxc_lint!(
     ///
     /// Enforces the use of, for example, `document.body.append(div);` over `document.body.appendChild(div);` for DOM nodes.
     ///
+    /// The fix is only applied when the return value is not used, since `Node#append()` returns
+    /// `undefined` while `Node#appendChild()` returns the appended node.
+    ///
     /// ### Why is this bad?
     ///
     /// There are [some advantages of using `Node#append()`](https://developer.mozilla.org/en-US/docs/Web/API/ParentNode/append), like the ability to append multiple nodes and to append both [`DOMString`](https://developer.mozilla.org/en-US/docs/Web/API/DOMString) and DOM node objects.
@@ -37,7 +40,7 @@ declare_oxc_lint!(
     PreferDomNodeAppend,
     unicorn,
     pedantic,
-    fix,
+    conditional_fix,
     version = "0.0.18",
     short_description = "Enforces the use of, for example, `document.body.append(div);` over `document.body.appendChild(div);` for DOM nodes.",
 );
@@ -74,9 +77,12 @@ impl Rule for PreferDomNodeAppend {
             return;
         }
 
-        ctx.diagnostic_with_fix(prefer_dom_node_append_diagnostic(span), |fixer| {
-            fixer.replace(span, "append")
-        });
+        let diagnostic = prefer_dom_node_append_diagnostic(span);
+        if is_value_not_usable(node, ctx) {
+            ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, "append"));
+        } else {
+            ctx.diagnostic(diagnostic);
+        }
     }
 }
 
@@ -120,16 +126,21 @@ fn test() {
     ];
 
     let fix = vec![
+        ("node.appendChild(child);", "node.append(child);"),
+        ("document.body.appendChild(child);", "document.body.append(child);"),
         (
             "node.appendChild(child).appendChild(grandchild);",
-            "node.append(child).append(grandchild);",
+            "node.appendChild(child).append(grandchild);",
         ),
         ("node?.appendChild(child);", "node?.append(child);"),
+        // The return value is used
+        ("const foo = node.appendChild(child);", "const foo = node.appendChild(child);"),
         (
             "function foo() { return node.appendChild(child); }",
-            "function foo() { return node.append(child); }",
+            "function foo() { return node.appendChild(child); }",
         ),
-        ("const foo = [node.appendChild(child)]", "const foo = [node.append(child)]"),
+        ("const foo = [node.appendChild(child)]", "const foo = [node.appendChild(child)]"),
+        ("() => node?.appendChild(child)", "() => node?.appendChild(child)"),
     ];
 
     Tester::new(PreferDomNodeAppend::NAME, PreferDomNodeAppend::PLUGIN, pass, fail)
@@ -14,6 +14,7 @@ use crate::{
     context::LintContext,
     fixer::{RuleFix, RuleFixer},
     rule::Rule,
+    utils::is_value_not_usable,
 };
 
 fn set(span: Span) -> OxcDiagnostic {
@@ -228,22 +229,6 @@ fn dash_to_camel_case(s: &str) -> String {
     result
 }
 
-fn is_value_not_usable(node: &AstNode, ctx: &LintContext) -> bool {
-    let parent_node = ctx.nodes().parent_node(node.id());
-    let parent_kind = parent_node.kind();
-
-    if matches!(parent_kind, AstKind::ExpressionStatement(_)) {
-        return true;
-    }
-
-    if matches!(parent_kind, AstKind::ChainExpression(_)) {
-        let grandparent = ctx.nodes().parent_node(parent_node.id());
-        return matches!(grandparent.kind(), AstKind::ExpressionStatement(_));
-    }
-
-    false
-}
-
 fn call_uses_optional_chain(call_expr: &CallExpression) -> bool {
     call_expr.optional || expression_uses_optional_chain(&call_expr.callee)
 }
@@ -482,6 +467,10 @@ fn test() {
         (r"element.setAttribute('data-🦄', '🦄');", r#"element.dataset["🦄"] = '🦄';"#),
         (r"element.setAttribute('data-ゆ', 'ゆ');", r"element.dataset.ゆ = 'ゆ';"),
         (r"element.setAttribute('data-foo2', '🦄');", r"element.dataset.foo2 = '🦄';"),
+        (
+            r"() => element.setAttribute('data-foo', 'bar')",
+            r"() => element.setAttribute('data-foo', 'bar')",
+        ),
         (r"element.setAttribute('data-foo:bar', 'zaz');", r#"element.dataset["foo:bar"] = 'zaz';"#),
         (
             r#"element.setAttribute("data-foo:bar", "zaz");"#,
@@ -1,13 +1,14 @@
 use oxc_ast::{AstKind, ast::Expression};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{
     AstNode,
     ast_util::{call_expr_method_callee_info, is_method_call},
     context::LintContext,
     rule::Rule,
+    utils::{is_movable_expression, is_value_not_usable},
 };
 
 fn prefer_dom_node_remove_diagnostic(span: Span) -> OxcDiagnostic {
@@ -25,6 +26,9 @@ declare_oxc_lint!(
     ///
     /// Prefers the use of `child.remove()` over `parentNode.removeChild(child)`.
     ///
+    /// The fix is only applied when the return value is not used, and both the parent and the
+    /// child are identifiers or member accesses, which can be dropped or moved safely.
+    ///
     /// ### Why is this bad?
     ///
     /// The DOM function [`Node#remove()`](https://developer.mozilla.org/en-US/docs/Web/API/ChildNode/remove) is preferred
@@ -44,7 +48,7 @@ declare_oxc_lint!(
     PreferDomNodeRemove,
     unicorn,
     pedantic,
-    pending,
+    conditional_fix,
     version = "0.0.18",
     short_description = "Prefers the use of `child.remove()` over `parentNode.removeChild(child)`.",
 );
@@ -79,29 +83,41 @@ impl Rule for PreferDomNodeRemove {
         }
 
         // Check if the callee object (the thing `.removeChild()` is called on) is a non-DOM type
-        if let Some(member_expr) = call_expr.callee.get_member_expr() {
-            if member_expr.is_computed() {
-                return;
-            }
+        let Some(member_expr) = call_expr.callee.get_member_expr() else {
+            return;
+        };
+        if member_expr.is_computed() {
+            return;
+        }
 
-            let object = member_expr.object().without_parentheses();
-            if is_non_dom_node(object) {
-                return;
-            }
+        let parent = member_expr.object().without_parentheses();
+        if is_non_dom_node(parent) {
+            return;
         }
 
-        let Some(expr) = call_expr.arguments[0].as_expression() else {
+        let Some(child) = call_expr.arguments[0].as_expression() else {
             return;
         };
 
-        let expr = expr.without_parentheses();
-        if is_non_dom_node(expr) {
+        let child = child.without_parentheses();
+        if is_non_dom_node(child) {
             return;
         }
 
-        ctx.diagnostic(prefer_dom_node_remove_diagnostic(
-            call_expr_method_callee_info(call_expr).unwrap().0,
-        ));
+        let diagnostic =
+            prefer_dom_node_remove_diagnostic(call_expr_method_callee_info(call_expr).unwrap().0);
+        if !member_expr.optional()
+            && is_movable_expression(parent)
+            && is_movable_expression(child)
+            && is_value_not_usable(node, ctx)
+        {
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                let child_text = ctx.source_range(child.span());
+                fixer.replace(call_expr.span, format!("{child_text}.remove()"))
+            });
+        } else {
+            ctx.diagnostic(diagnostic);
+        }
     }
 }
 
@@ -260,6 +276,29 @@ fn test() {
         "a.removeChild!(k)",
     ];
 
+    let fix = vec![
+        ("parentNode.removeChild(foo)", "foo.remove()"),
+        ("parentNode.removeChild(this);", "this.remove();"),
+        ("parentNode.removeChild(some.node);", "some.node.remove();"),
+        ("foo.parentNode.removeChild((foo));", "foo.remove();"),
+        ("this.removeChild(child)", "child.remove()"),
+        // The return value is used
+        (
+            "const foo = parentNode.removeChild(child);",
+            "const foo = parentNode.removeChild(child);",
+        ),
+        ("if (parentNode.removeChild(foo)) {}", "if (parentNode.removeChild(foo)) {}"),
+        // Moving the child or dropping the parent is not safe
+        ("parentNode.removeChild(getChild())", "parentNode.removeChild(getChild())"),
+        ("parentNode.removeChild(a?.b)", "parentNode.removeChild(a?.b)"),
+        ("() => parentNode.removeChild(child)", "() => parentNode.removeChild(child)"),
+        ("foo().removeChild(child)", "foo().removeChild(child)"),
+        ("foo[doSomething()].removeChild(child)", "foo[doSomething()].removeChild(child)"),
+        ("parentNode?.removeChild(foo)", "parentNode?.removeChild(foo)"),
+        ("foo?.parentNode.removeChild(foo)", "foo?.parentNode.removeChild(foo)"),
+    ];
+
     Tester::new(PreferDomNodeRemove::NAME, PreferDomNodeRemove::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
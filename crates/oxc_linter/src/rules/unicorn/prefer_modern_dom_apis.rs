@@ -6,7 +6,9 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{AstNode, ast_util::is_method_call, context::LintContext, rule::Rule};
+use crate::{
+    AstNode, ast_util::is_method_call, context::LintContext, rule::Rule, utils::is_value_not_usable,
+};
 
 fn prefer_modern_dom_apis_diagnostic(
     good_method: &str,
@@ -38,16 +40,6 @@ fn get_replacement_for_position(position: &str) -> Option<&'static str> {
     }
 }
 
-fn is_value_not_usable(node: &AstNode, ctx: &LintContext) -> bool {
-    let parent_node = ctx.nodes().parent_node(node.id());
-    let grandparent_node = ctx.nodes().parent_node(parent_node.id());
-    matches!(
-        (parent_node.kind(), grandparent_node.kind()),
-        (AstKind::ExpressionStatement(_), _)
-            | (AstKind::ChainExpression(_), AstKind::ExpressionStatement(_))
-    )
-}
-
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -44,6 +44,7 @@ declare_oxc_lint!(
     style,
     fix,
     version = "0.12.0",
+    works_on_d_ts = false,
     short_description = "Prefers use of `String.raw` to avoid escaping `\\`.",
 );
 
@@ -192,10 +193,6 @@ impl Rule for PreferStringRaw {
             fixer.replace(string_literal.span, fix)
         });
     }
-
-    fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
-        !ctx.source_type().is_typescript_definition()
-    }
 }
 
 /// Returns true if the string ends with an odd number of backslashes.
@@ -303,15 +300,11 @@ fn test() {
         (r"const a = 'c:\\someöäü\\path';", r"const a = String.raw`c:\someöäü\path`;"),
     ];
 
+    let declaration_file_pass =
+        vec![r#"declare const POSIX_REGEX_SOURCE: { ascii: "\\x00-\\x7F"; };"#];
+
     Tester::new(PreferStringRaw::NAME, PreferStringRaw::PLUGIN, pass, fail)
+        .with_declaration_file_cases(declaration_file_pass, vec![])
         .expect_fix(fix)
         .test_and_snapshot();
-
-    let dts_pass = vec![r#"declare const POSIX_REGEX_SOURCE: { ascii: "\\x00-\\x7F"; };"#];
-    let dts_fail: Vec<&str> = vec![];
-
-    Tester::new(PreferStringRaw::NAME, PreferStringRaw::PLUGIN, dts_pass, dts_fail)
-        .change_rule_path("test.d.ts")
-        .intentionally_allow_no_fix_tests()
-        .test();
 }
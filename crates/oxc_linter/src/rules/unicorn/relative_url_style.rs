@@ -11,7 +11,7 @@ use url::Url;
 
 use crate::{
     AstNode,
-    ast_util::is_new_expression,
+    ast_util::{is_global_new_expression, is_new_expression},
     context::LintContext,
     rule::{DefaultRuleConfig, Rule},
 };
@@ -89,7 +89,9 @@ impl Rule for RelativeUrlStyle {
             return;
         };
 
-        if !is_new_expression(new_expr, &["URL"], Some(2), Some(2)) {
+        if !is_new_expression(new_expr, &["URL"], Some(2), Some(2))
+            || !is_global_new_expression(new_expr, "URL", ctx)
+        {
             return;
         }
 
@@ -236,6 +238,9 @@ fn test() {
         (r#"const base = new URL("./", import.meta.url)"#, None),
         (r"new URL", None),
         (r"new URL(0, base)", None),
+        // Shadowed `URL`
+        (r#"import { URL } from "url-polyfill"; new URL("./foo", base)"#, None),
+        (r#"function f(URL) { return new URL("./foo", base) }"#, None),
         // Not checking this case
         (r#"new globalThis.URL("./foo", base)"#, None),
         (r#"const foo = "./foo"; new URL(foo, base)"#, None),
@@ -260,6 +265,7 @@ fn test() {
         (r#"new URL("", "https://example.com/a/b.html")"#, Some(serde_json::json!(["always"]))),
         (r"/* 2 */ new URL", Some(serde_json::json!(["always"]))),
         (r"new URL(0, base2)", Some(serde_json::json!(["always"]))),
+        (r#"class URL {} new URL("foo", base)"#, Some(serde_json::json!(["always"]))),
         // Not checking this case
         (r#"new globalThis.URL("foo", base)"#, Some(serde_json::json!(["always"]))),
         (r"new URL(`${foo}`, base2)", Some(serde_json::json!(["always"]))),
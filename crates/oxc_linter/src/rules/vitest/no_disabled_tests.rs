@@ -10,7 +10,14 @@ use crate::{
 #[derive(Debug, Default, Clone)]
 pub struct NoDisabledTests;
 
-declare_oxc_lint!(NoDisabledTests, vitest, correctness, docs = DOCUMENTATION, version = "0.0.7",);
+declare_oxc_lint!(
+    NoDisabledTests,
+    vitest,
+    correctness,
+    suggestion,
+    docs = DOCUMENTATION,
+    version = "0.0.7",
+);
 
 impl Rule for NoDisabledTests {
     fn run_on_jest_node<'a, 'c>(
@@ -24,7 +31,9 @@ impl Rule for NoDisabledTests {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use cow_utils::CowUtils;
+
+    use crate::{fixer::FixKind, tester::Tester};
 
     let mut pass = vec![
         ("describe('foo', function () {})", None),
@@ -165,7 +174,71 @@ fn test() {
         .map(|x| (x, None)),
     );
 
+    // `.skip` combined with other modifiers, in either order. The suggestion must remove
+    // `.skip` and keep the rest of the chain.
+    let test_chains = [
+        "skip",
+        "skip.concurrent",
+        "concurrent.skip",
+        "skip.sequential",
+        "skip.fails",
+        "skip.each(table)",
+        "skip.each`table`",
+        "skip.for(table)",
+        "skip.concurrent.each(table)",
+        "concurrent.skip.each(table)",
+        "concurrent.skip.each`table`",
+    ];
+    let describe_chains = [
+        "skip",
+        "skip.each(table)",
+        "skip.concurrent",
+        "concurrent.skip",
+        "skip.shuffle",
+        "concurrent.skip.each(table)",
+    ];
+    let callees = ["it", "test"]
+        .into_iter()
+        .flat_map(|base| test_chains.map(|chain| format!("{base}.{chain}")))
+        .chain(describe_chains.map(|chain| format!("describe.{chain}")))
+        .chain(["bench.skip".to_string()]);
+    let matrix = callees
+        .map(|callee| {
+            let source = format!("{callee}('foo', () => {{}});");
+            let fixed = source.cow_replace(".skip", "").into_owned();
+            (source, fixed)
+        })
+        .collect::<Vec<_>>();
+
+    pass.extend([
+        ("const it = { skip: () => {} }; it.skip('foo', () => {});", None),
+        ("import { it } from './test-utils'; it.skip('foo', () => {});", None),
+        ("function run(test) { test.skip('foo', () => {}); }", None),
+    ]);
+    fail.extend(matrix.iter().map(|(source, _)| (source.as_str(), None)));
+
+    let mut fix = vec![
+        (
+            "describe['skip']('foo', function () {})",
+            "describe('foo', function () {})",
+            None,
+            FixKind::Suggestion,
+        ),
+        (
+            "import { describe as suite } from 'vitest'; suite.skip('foo', function () {})",
+            "import { describe as suite } from 'vitest'; suite('foo', function () {})",
+            None,
+            FixKind::Suggestion,
+        ),
+    ];
+    fix.extend(
+        matrix
+            .iter()
+            .map(|(source, fixed)| (source.as_str(), fixed.as_str(), None, FixKind::Suggestion)),
+    );
+
     Tester::new(NoDisabledTests::NAME, NoDisabledTests::PLUGIN, pass, fail)
         .with_vitest_plugin(true)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -14,7 +14,7 @@ declare_oxc_lint!(
     NoFocusedTests,
     vitest,
     correctness,
-    suggestion,
+    fix,
     docs = DOCUMENTATION,
     version = "0.0.8",
 );
@@ -31,7 +31,8 @@ impl Rule for NoFocusedTests {
 
 #[test]
 fn test() {
-    use crate::fixer::FixKind;
+    use cow_utils::CowUtils;
+
     use crate::tester::Tester;
 
     let mut pass = vec![
@@ -74,13 +75,8 @@ fn test() {
     ];
 
     let mut fix = vec![
-        ("describe.only('foo', () => {})", "describe('foo', () => {})", None, FixKind::Suggestion),
-        (
-            "describe['only']('foo', () => {})",
-            "describe('foo', () => {})",
-            None,
-            FixKind::Suggestion,
-        ),
+        ("describe.only('foo', () => {})", "describe('foo', () => {})", None),
+        ("describe['only']('foo', () => {})", "describe('foo', () => {})", None),
     ];
 
     let pass_vitest = vec![
@@ -106,42 +102,82 @@ fn test() {
     ];
 
     let fix_vitest = vec![
-        (r#"it.only("test", () => {});"#, r#"it("test", () => {});"#, None, FixKind::Suggestion),
-        (
-            r#"describe.only("test", () => {});"#,
-            r#"describe("test", () => {});"#,
-            None,
-            FixKind::Suggestion,
-        ),
-        (
-            r#"test.only("test", () => {});"#,
-            r#"test("test", () => {});"#,
-            None,
-            FixKind::Suggestion,
-        ),
-        (
-            r#"it.only.each([])("test", () => {});"#,
-            r#"it.each([])("test", () => {});"#,
-            None,
-            FixKind::Suggestion,
-        ),
-        (
-            r#"test.only.each``("test", () => {});"#,
-            r#"test.each``("test", () => {});"#,
-            None,
-            FixKind::Suggestion,
-        ),
-        (
-            r#"it.only.each``("test", () => {});"#,
-            r#"it.each``("test", () => {});"#,
-            None,
-            FixKind::Suggestion,
-        ),
+        (r#"it.only("test", () => {});"#, r#"it("test", () => {});"#, None),
+        (r#"describe.only("test", () => {});"#, r#"describe("test", () => {});"#, None),
+        (r#"test.only("test", () => {});"#, r#"test("test", () => {});"#, None),
+        (r#"it.only.each([])("test", () => {});"#, r#"it.each([])("test", () => {});"#, None),
+        (r#"test.only.each``("test", () => {});"#, r#"test.each``("test", () => {});"#, None),
+        (r#"it.only.each``("test", () => {});"#, r#"it.each``("test", () => {});"#, None),
+    ];
+
+    // `.only` combined with other modifiers, in either order. The fix must remove `.only` and
+    // keep the rest of the chain.
+    let test_chains = [
+        "only",
+        "only.concurrent",
+        "concurrent.only",
+        "only.sequential",
+        "sequential.only",
+        "only.fails",
+        "only.each(table)",
+        "only.each`table`",
+        "only.for(table)",
+        "only.concurrent.each(table)",
+        "concurrent.only.each(table)",
+        "only.concurrent.each`table`",
+        "concurrent.only.each`table`",
+        "only.concurrent.for(table)",
     ];
+    let describe_chains = [
+        "only",
+        "only.each(table)",
+        "only.each`table`",
+        "only.concurrent",
+        "concurrent.only",
+        "only.sequential",
+        "only.shuffle",
+        "only.concurrent.each(table)",
+        "concurrent.only.each(table)",
+    ];
+    let callees = ["it", "test"]
+        .into_iter()
+        .flat_map(|base| test_chains.map(|chain| format!("{base}.{chain}")))
+        .chain(describe_chains.map(|chain| format!("describe.{chain}")))
+        .chain(["bench.only".to_string()]);
+    let matrix = callees
+        .map(|callee| {
+            let source = format!("{callee}('foo', () => {{}});");
+            let fixed = source.cow_replace(".only", "").into_owned();
+            (source, fixed)
+        })
+        .collect::<Vec<_>>();
+
+    let pass_vitest_imports = vec![
+        ("const it = { only: () => {} }; it.only('foo', () => {});", None),
+        ("import { it } from './test-utils'; it.only('foo', () => {});", None),
+        ("import { describe } from 'mocha'; describe.only('foo', () => {});", None),
+        ("function run(test) { test.only('foo', () => {}); }", None),
+    ];
+
+    let fail_vitest_imports = vec![
+        ("import { it as vitestIt } from 'vitest'; vitestIt.only('foo', () => {});", None),
+        ("import { bench } from 'vitest'; bench.only('foo', () => {});", None),
+    ];
+
+    let fix_vitest_imports = vec![(
+        "import { it as vitestIt } from 'vitest'; vitestIt.only('foo', () => {});",
+        "import { it as vitestIt } from 'vitest'; vitestIt('foo', () => {});",
+        None,
+    )];
 
     pass.extend(pass_vitest);
+    pass.extend(pass_vitest_imports);
     fail.extend(fail_vitest);
+    fail.extend(fail_vitest_imports);
+    fail.extend(matrix.iter().map(|(source, _)| (source.as_str(), None)));
     fix.extend(fix_vitest);
+    fix.extend(fix_vitest_imports);
+    fix.extend(matrix.iter().map(|(source, fixed)| (source.as_str(), fixed.as_str(), None)));
 
     Tester::new(NoFocusedTests::NAME, NoFocusedTests::PLUGIN, pass, fail)
         .with_vitest_plugin(true)
@@ -210,12 +210,11 @@ fn test() {
             "aVariable.mockRejectedValueOnce(42)",
             None,
         ),
-        // Todo: Fixed
-        // (
-        //     "aVariable.mockReturnValue(Promise.resolve({ target: 'world', message: 'hello' }))",
-        //     "aVariable.mockResolvedValue({ target: 'world', message: 'hello' })",
-        //     None,
-        // ),
+        (
+            "aVariable.mockReturnValue(Promise.resolve({ target: 'world', message: 'hello' }))",
+            "aVariable.mockResolvedValue({ target: 'world', message: 'hello' })",
+            None,
+        ),
         (
             "
                 aVariable
@@ -266,12 +265,11 @@ fn test() {
             "aVariable.mockResolvedValueOnce(undefined)",
             None,
         ),
-        // Todo: Fixed
-        // (
-        //     "jest.spyOn(fs, \"readFile\").mockReturnValue(Promise.reject(new Error(\"oh noes!\")))",
-        //     "jest.spyOn(fs, \"readFile\").mockRejectedValue(new Error(\"oh noes!\"))",
-        //     None,
-        // ),
+        (
+            "jest.spyOn(fs, \"readFile\").mockReturnValue(Promise.reject(new Error(\"oh noes!\")))",
+            "jest.spyOn(fs, \"readFile\").mockRejectedValue(new Error(\"oh noes!\"))",
+            None,
+        ),
     ];
 
     let mut pass = vec![
@@ -393,12 +391,11 @@ fn test() {
             "aVariable.mockRejectedValueOnce(42)",
             None,
         ),
-        // Todo: Fixed
-        // (
-        //     "aVariable.mockReturnValue(Promise.resolve({ target: 'world', message: 'hello' }))",
-        //     "aVariable.mockResolvedValue({ target: 'world', message: 'hello' })",
-        //     None,
-        // ),
+        (
+            "aVariable.mockReturnValue(Promise.resolve({ target: 'world', message: 'hello' }))",
+            "aVariable.mockResolvedValue({ target: 'world', message: 'hello' })",
+            None,
+        ),
         (
             "aVariable.mockImplementation(() => Promise.reject(42)).mockImplementation(() => Promise.resolve(42)).mockReturnValue(Promise.reject(42))",
             "aVariable.mockRejectedValue(42).mockResolvedValue(42).mockRejectedValue(42)",
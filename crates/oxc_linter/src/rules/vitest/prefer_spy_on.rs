@@ -68,16 +68,6 @@ fn tests() {
             None,
         ),
         ("Date['now'] = jest['fn']()", "jest.spyOn(Date, 'now').mockImplementation()", None),
-        (
-            "window[`${name}`] = jest[`fn`]()",
-            "jest.spyOn(window, `${name}`).mockImplementation()",
-            None,
-        ),
-        (
-            "obj['prop' + 1] = jest['fn']()",
-            "jest.spyOn(obj, 'prop' + 1).mockImplementation()",
-            None,
-        ),
         (
             "obj.one.two = jest.fn(); const test = 10;",
             "jest.spyOn(obj.one, 'two').mockImplementation(); const test = 10;",
@@ -94,11 +84,6 @@ fn tests() {
             "jest.spyOn(window, 'fetch').mockImplementation(() => ({})).one.two().three().four",
             None,
         ),
-        (
-            "foo[bar] = jest.fn().mockReturnValue(undefined)",
-            "jest.spyOn(foo, bar).mockImplementation().mockReturnValue(undefined)",
-            None,
-        ),
         (
             "
                 foo.bar = jest.fn().mockImplementation(baz => baz)
@@ -144,6 +129,8 @@ fn tests() {
 			      ",
             None,
         ),
+        ("obj[`a`] = vi.fn(x => x)", None),
+        ("obj[key] = vi.fn(x => x)", None),
     ];
 
     let vitest_fix = vec![
@@ -153,12 +140,6 @@ fn tests() {
             None,
         ),
         ("Date['now'] = vi['fn']()", "vi.spyOn(Date, 'now').mockImplementation()", None),
-        (
-            "window[`${name}`] = vi[`fn`]()",
-            "vi.spyOn(window, `${name}`).mockImplementation()",
-            None,
-        ),
-        ("obj['prop' + 1] = vi['fn']()", "vi.spyOn(obj, 'prop' + 1).mockImplementation()", None),
         (
             "obj.one.two = vi.fn(); const test = 10;",
             "vi.spyOn(obj.one, 'two').mockImplementation(); const test = 10;",
@@ -175,11 +156,6 @@ fn tests() {
             "vi.spyOn(window, 'fetch').mockImplementation(() => ({})).one.two().three().four",
             None,
         ),
-        (
-            "foo[bar] = vi.fn().mockReturnValue(undefined)",
-            "vi.spyOn(foo, bar).mockImplementation().mockReturnValue(undefined)",
-            None,
-        ),
         (
             "
 			        foo.bar = vi.fn().mockImplementation(baz => baz)
@@ -191,6 +167,7 @@ fn tests() {
 			      ",
             None,
         ),
+        ("obj[`a`] = vi.fn(x => x)", "vi.spyOn(obj, `a`).mockImplementation(x => x)", None),
     ];
 
     pass.extend(vitest_pass);
@@ -80,6 +80,7 @@ declare_oxc_lint!(
     vue,
     restriction,
     config = MaxProps,
+    language = vue,
     version = "1.19.0",
     short_description = "Enforce a maximum number of props defined for a given Vue component.",
 );
@@ -86,6 +86,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = NextTickOption,
+    language = vue,
     version = "1.69.0",
     short_description = "Enforce Promise or callback style in `nextTick`.",
 );
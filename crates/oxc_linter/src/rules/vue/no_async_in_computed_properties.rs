@@ -115,6 +115,7 @@ declare_oxc_lint!(
     correctness,
     none,
     config = NoAsyncInComputedProperties,
+    language = vue,
     version = "1.71.0",
     short_description = "Disallow asynchronous actions in computed properties.",
 );
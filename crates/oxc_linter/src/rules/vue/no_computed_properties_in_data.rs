@@ -70,6 +70,7 @@ declare_oxc_lint!(
     NoComputedPropertiesInData,
     vue,
     correctness,
+    language = vue,
     version = "1.67.0",
     short_description = "Disallow accessing computed properties inside `data()`.",
 );
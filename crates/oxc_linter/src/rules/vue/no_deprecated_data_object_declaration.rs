@@ -53,6 +53,7 @@ declare_oxc_lint!(
     vue,
     correctness,
     pending, // fixer will be implemented later
+    language = vue,
     version = "1.62.0",
     short_description = "Disallow object declarations for `data` (in Vue.js 3.0.0+).",
 );
@@ -58,6 +58,7 @@ declare_oxc_lint!(
     NoDeprecatedDeleteSet,
     vue,
     correctness,
+    language = vue,
     version = "1.62.0",
     short_description = "Disallow using deprecated `$set` / `$delete` (in Vue.js 3.0.0+).",
 );
@@ -87,6 +87,7 @@ declare_oxc_lint!(
     correctness,
     pending,
     config = NoDeprecatedModelDefinition,
+    language = vue,
     version = "1.63.0",
     short_description = "Disallow deprecated `model` definition (in Vue.js 3.0.0+).",
 );
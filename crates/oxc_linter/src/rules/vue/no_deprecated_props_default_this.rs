@@ -65,6 +65,7 @@ declare_oxc_lint!(
     vue,
     correctness,
     pending,
+    language = vue,
     version = "1.67.0",
     short_description = "Disallow deprecated `this` access in props default function (in Vue.js 3.0.0+).",
 );
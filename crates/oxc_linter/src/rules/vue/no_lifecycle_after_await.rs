@@ -69,6 +69,7 @@ declare_oxc_lint!(
     NoLifecycleAfterAwait,
     vue,
     correctness,
+    language = vue,
     version = "1.39.0",
     short_description = "Disallow asynchronously registered lifecycle hooks.",
 );
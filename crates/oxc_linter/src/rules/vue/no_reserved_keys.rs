@@ -115,6 +115,7 @@ declare_oxc_lint!(
     vue,
     correctness,
     config = NoReservedKeys,
+    language = vue,
     version = "1.69.0",
     short_description = "Disallow overwriting reserved Vue instance keys (e.g. `$data`, `$emit`) or using `_`-prefixed keys inside `data` / `asyncData`.",
 );
@@ -94,6 +94,7 @@ declare_oxc_lint!(
     vue,
     correctness,
     config = NoReservedProps,
+    language = vue,
     version = "1.69.0",
     short_description = "Disallow reserved attribute names (e.g. `key`, `ref`) from being used as prop names.",
 );
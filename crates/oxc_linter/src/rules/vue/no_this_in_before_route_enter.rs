@@ -61,6 +61,7 @@ declare_oxc_lint!(
     NoThisInBeforeRouteEnter,
     vue,
     correctness,
+    language = vue,
     version = "1.37.0",
     short_description = "Disallow `this` usage in a `beforeRouteEnter` method.",
 );
@@ -68,6 +68,7 @@ declare_oxc_lint!(
     NoWatchAfterAwait,
     vue,
     correctness,
+    language = vue,
     version = "1.67.0",
     short_description = "Disallow asynchronously-registered `watch`.",
 );
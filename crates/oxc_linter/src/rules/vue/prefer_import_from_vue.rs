@@ -42,6 +42,7 @@ declare_oxc_lint!(
     correctness,
     fix,
     version = "1.20.0",
+    works_on_d_ts = false,
     short_description = "Enforce imports from `vue` instead of `@vue/*`.",
 );
 
@@ -82,10 +83,6 @@ impl Rule for PreferImportFromVue {
             }
         }
     }
-
-    fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
-        !ctx.source_type().is_typescript_definition()
-    }
 }
 
 #[test]
@@ -88,6 +88,7 @@ declare_oxc_lint!(
     RequireDirectExport,
     vue,
     style,
+    language = vue,
     version = "1.69.0",
     short_description = "This rule requires that the component object be directly exported.",
     config = RequireDirectExport,
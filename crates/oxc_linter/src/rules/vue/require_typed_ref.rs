@@ -44,6 +44,7 @@ declare_oxc_lint!(
     RequireTypedRef,
     vue,
     style,
+    language = typescript,
     version = "1.17.0",
     short_description = "Require `ref` and `shallowRef` functions to be strongly typed.",
 );
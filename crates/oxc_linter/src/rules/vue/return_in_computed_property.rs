@@ -77,6 +77,7 @@ declare_oxc_lint!(
     vue,
     correctness,
     config = ReturnInComputedProperty,
+    language = vue,
     version = "1.63.0",
     short_description = "Enforce that a `return` statement is present in every computed property.",
 );
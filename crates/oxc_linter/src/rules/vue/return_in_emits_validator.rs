@@ -71,6 +71,7 @@ declare_oxc_lint!(
     ReturnInEmitsValidator,
     vue,
     correctness,
+    language = vue,
     version = "1.67.0",
     short_description = "Enforce that a `return` statement is present in `emits` validators (in Vue.js 3.0.0+).",
 );
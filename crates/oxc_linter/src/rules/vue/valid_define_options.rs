@@ -70,6 +70,7 @@ declare_oxc_lint!(
     ValidDefineOptions,
     vue,
     correctness,
+    language = vue,
     version = "1.67.0",
     short_description = "Enforce valid use of the `defineOptions` compiler macro.",
 );
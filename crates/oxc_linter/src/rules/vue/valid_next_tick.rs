@@ -79,6 +79,7 @@ declare_oxc_lint!(
     vue,
     correctness,
     fix,
+    language = vue,
     version = "1.67.0",
     short_description = "Enforce valid `nextTick` function calls.",
 );
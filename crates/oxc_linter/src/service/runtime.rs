@@ -28,7 +28,7 @@ use oxc_span::{SourceType, VALID_EXTENSIONS};
 use oxc_str::CompactStr;
 
 use crate::{
-    Fixer, Linter, Message, PossibleFixes, RuleTimingStore,
+    AppliedFix, Fixer, Linter, Message, MessageRule, PossibleFixes, RuleTimingStore,
     context::{ContextSubHost, ContextSubHostOptions},
     disable_directives::DisableDirectives,
     loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
@@ -186,6 +186,22 @@ pub trait RuntimeFileSystem {
     /// # Errors
     /// When the program does not have write permission for the file system
     fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error>;
+
+    /// write the result of applying `applied_fixes` to a file
+    ///
+    /// Defaults to [`RuntimeFileSystem::write_file`]. Implementations which report fixes
+    /// instead of applying them (e.g. `--fix-dry-run`) can override this.
+    ///
+    /// # Errors
+    /// When the program does not have write permission for the file system
+    fn write_fixed_file(
+        &self,
+        path: &Path,
+        content: &str,
+        _applied_fixes: &[AppliedFix],
+    ) -> Result<(), std::io::Error> {
+        self.write_file(path, content)
+    }
 }
 
 pub struct OsFileSystem;
@@ -625,6 +641,7 @@ impl Runtime {
                         // This means we do not write multiple times to the same file if there are multiple sources
                         // in the same file (for example, multiple scripts in an `.astro` file).
                         let mut new_source_text = Cow::from(dep.source_text);
+                        let mut applied_fixes = vec![];
 
                         let path = Path::new(&module_to_lint.path);
 
@@ -695,7 +712,12 @@ impl Runtime {
                                     if st.is_javascript() { st.with_jsx(true) } else { st }
                                 }),
                             )
+                            .with_verify(me.linter.options().verify_fixes)
+                            .with_filter(me.linter.fix_filter())
                             .fix();
+                            if !fix_result.discarded_fixes.is_empty() {
+                                warn_discarded_fixes(path, &fix_result.discarded_fixes);
+                            }
                             if fix_result.fixed {
                                 // write to file, replacing only the changed part
                                 let start = 0;
@@ -703,6 +725,7 @@ impl Runtime {
                                 new_source_text
                                     .to_mut()
                                     .replace_range(start..end, &fix_result.fixed_code);
+                                applied_fixes.extend(fix_result.applied_fixes);
                             }
 
                             messages = fix_result.messages;
@@ -726,7 +749,8 @@ impl Runtime {
                         // If the new source text is owned, that means it was modified,
                         // so we write the new source text to the file.
                         if let Cow::Owned(new_source_text) = &new_source_text
-                            && let Err(error) = file_system.write_file(path, new_source_text)
+                            && let Err(error) =
+                                file_system.write_fixed_file(path, new_source_text, &applied_fixes)
                         {
                             tx_error
                                 .send(vec![Error::new(OxcDiagnostic::error(format!(
@@ -1179,3 +1203,27 @@ impl Runtime {
         Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic, tokens))
     }
 }
+
+/// Prints a note to stderr for a file whose fixes were discarded because they introduced syntax
+/// errors. This is a bug in the rules, not a problem of the file, so it isn't a diagnostic.
+#[expect(clippy::print_stderr)]
+fn warn_discarded_fixes(path: &Path, discarded_fixes: &[AppliedFix]) {
+    let mut rules = discarded_fixes
+        .iter()
+        .filter_map(|fix| fix.rule.as_ref().map(MessageRule::short_canonical_name))
+        .collect::<Vec<_>>();
+    rules.sort_unstable();
+    rules.dedup();
+    let rules = if rules.is_empty() {
+        String::new()
+    } else {
+        format!(
+            " from {}",
+            rules.iter().map(|rule| format!("`{rule}`")).collect::<Vec<_>>().join(", ")
+        )
+    };
+    eprintln!(
+        "note: fixes{rules} were not applied to {}, because they would introduce syntax errors. The problems are reported unfixed. Please report the fixes as a bug.",
+        path.display()
+    );
+}
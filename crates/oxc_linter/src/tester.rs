@@ -314,6 +314,8 @@ pub struct Tester {
     snapshot_suffix: Option<&'static str>,
     current_working_directory: Box<Path>,
     plugins: LintPlugins,
+    /// Applied to the rule after it is configured from the rule config of a test case.
+    rule_setup: Option<fn(RuleEnum) -> RuleEnum>,
 }
 
 impl Tester {
@@ -341,6 +343,7 @@ impl Tester {
             snapshot_suffix: None,
             current_working_directory,
             plugins: LintPlugins::default(),
+            rule_setup: None,
         }
     }
 
@@ -384,6 +387,13 @@ impl Tester {
         self
     }
 
+    /// Adjust the rule after it is configured from the rule config of each test case, e.g. to
+    /// set options which can only be set programmatically.
+    pub fn with_rule_setup(mut self, setup: fn(RuleEnum) -> RuleEnum) -> Self {
+        self.rule_setup = Some(setup);
+        self
+    }
+
     pub fn with_import_plugin(mut self, yes: bool) -> Self {
         self.plugins.set(LintPlugins::IMPORT, yes);
         self
@@ -399,6 +409,45 @@ impl Tester {
         self
     }
 
+    /// Add cases which are linted as a TypeScript declaration file, i.e. the rule path with a
+    /// `.d.ts` extension. Any path set on the cases themselves is replaced.
+    pub fn with_declaration_file_cases<T: Into<TestCase>>(
+        mut self,
+        expect_pass: Vec<T>,
+        expect_fail: Vec<T>,
+    ) -> Self {
+        let path = self.rule_path.with_extension("d.ts");
+        let declaration_file_case = |case: T| TestCase { path: Some(path.clone()), ..case.into() };
+        self.expect_pass.extend(expect_pass.into_iter().map(declaration_file_case));
+        self.expect_fail.extend(expect_fail.into_iter().map(declaration_file_case));
+        self
+    }
+
+    /// Add cases which are linted as a file with the extension `ext`, on which the rule does not
+    /// run, e.g. JavaScript files for a rule which only applies to TypeScript. Any path set on
+    /// the cases themselves is replaced.
+    ///
+    /// The cases are expected to pass, so they should contain code that the rule would report
+    /// if it ran.
+    ///
+    /// # Panics
+    ///
+    /// If the rule applies to files with the extension `ext`.
+    pub fn with_skipped_file_cases<T: Into<TestCase>>(mut self, ext: &str, cases: Vec<T>) -> Self {
+        let path = self.rule_path.with_extension(ext);
+        let source_type = SourceType::from_path(&path).unwrap();
+        let language = self.find_rule().language();
+        assert!(
+            !language.applies_to(source_type, path.extension()),
+            "Rule {}/{} applies to `.{ext}` files, but was expected to be skipped",
+            self.plugin_name,
+            self.rule_name
+        );
+        let skipped_file_case = |case: T| TestCase { path: Some(path.clone()), ..case.into() };
+        self.expect_pass.extend(cases.into_iter().map(skipped_file_case));
+        self
+    }
+
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
@@ -632,7 +681,11 @@ impl Tester {
                 config
             );
         }
-        let rule = self.find_rule().from_configuration(rule_config.unwrap_or_default()).unwrap();
+        let mut rule =
+            self.find_rule().from_configuration(rule_config.unwrap_or_default()).unwrap();
+        if let Some(setup) = self.rule_setup {
+            rule = setup(rule);
+        }
         let mut external_plugin_store = ExternalPluginStore::default();
         let linter = Linter::new(
             self.lint_options,
@@ -0,0 +1,107 @@
+//! Traces of a single rule running on a single file, for `oxlint --debug-rule`.
+//!
+//! A trace lists the AST nodes a rule was run on, the messages the rule recorded with
+//! [`LintContext::trace`](crate::LintContext::trace), and the diagnostics it reported, in the
+//! order they happened. It answers "why is this not flagged" without adding print statements to
+//! the rule.
+
+use std::fmt::Write;
+
+use oxc_ast::AstType;
+use oxc_span::Span;
+
+/// An event in a [`RuleTrace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// The rule's `run_once` was called.
+    RunOnce,
+    /// The rule's `run` was called on a node of this kind.
+    Visit { kind: AstType, span: Span },
+    /// A message recorded by the rule with [`LintContext::trace`](crate::LintContext::trace).
+    Note(String),
+    /// A diagnostic reported by the rule.
+    Diagnostic { message: String, span: Span },
+}
+
+/// The events of a rule running on a file, see [`Linter::trace_rule`](crate::Linter::trace_rule).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTrace {
+    /// Full name of the traced rule, e.g. `eslint/no-console`.
+    pub rule_name: String,
+    pub events: Vec<TraceEvent>,
+}
+
+impl RuleTrace {
+    /// Renders the trace as plain text, suited for pasting into bug reports. Spans are shown as
+    /// `line:column` positions in `source_text`, both 1-based.
+    pub fn render(&self, source_text: &str) -> String {
+        let position = |offset: u32| {
+            let before = &source_text[..(offset as usize).min(source_text.len())];
+            let line = before.matches('\n').count() + 1;
+            let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+            format!("{line}:{column}")
+        };
+
+        let mut output = String::new();
+        let mut visits = 0;
+        let mut diagnostics = 0;
+        for event in &self.events {
+            match event {
+                TraceEvent::RunOnce => output.push_str("run_once\n"),
+                TraceEvent::Visit { kind, span } => {
+                    visits += 1;
+                    let (start, end) = (span.start, span.end);
+                    writeln!(output, "visit {kind:?} at {} ({start}..{end})", position(start))
+                        .unwrap();
+                }
+                TraceEvent::Note(message) => writeln!(output, "  trace: {message}").unwrap(),
+                TraceEvent::Diagnostic { message, span } => {
+                    diagnostics += 1;
+                    writeln!(output, "  diagnostic at {}: {message}", position(span.start))
+                        .unwrap();
+                }
+            }
+        }
+        writeln!(
+            output,
+            "{} visited {visits} node{} and reported {diagnostics} diagnostic{}.",
+            self.rule_name,
+            if visits == 1 { "" } else { "s" },
+            if diagnostics == 1 { "" } else { "s" },
+        )
+        .unwrap();
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let source_text = "foo();\n  debugger;\n";
+        let trace = RuleTrace {
+            rule_name: "eslint/no-debugger".to_string(),
+            events: vec![
+                TraceEvent::RunOnce,
+                TraceEvent::Visit { kind: AstType::DebuggerStatement, span: Span::new(9, 18) },
+                TraceEvent::Note("not in a test file".to_string()),
+                TraceEvent::Diagnostic {
+                    message: "`debugger` statement is not allowed".to_string(),
+                    span: Span::new(9, 18),
+                },
+            ],
+        };
+
+        assert_eq!(
+            trace.render(source_text),
+            "run_once
+visit DebuggerStatement at 2:3 (9..18)
+  trace: not in a test file
+  diagnostic at 2:3: `debugger` statement is not allowed
+eslint/no-debugger visited 1 node and reported 1 diagnostic.
+"
+        );
+    }
+}
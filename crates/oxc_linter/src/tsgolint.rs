@@ -19,7 +19,7 @@ use oxc_span::{SourceType, Span};
 use super::{AllowWarnDeny, ConfigStore, DisableDirectives, ResolvedLinterState, read_to_string};
 
 use crate::{
-    CompositeFix, FixKind, Fixer, Message, MessageRule, PossibleFixes, RuleTimingRecord,
+    CompositeFix, FixFilter, FixKind, Fixer, Message, MessageRule, PossibleFixes, RuleTimingRecord,
     RuleTimingSource, RuleTimingStore, WEBSITE_BASE_RULES_URL, suppression::DiffManager,
 };
 
@@ -43,6 +43,8 @@ pub struct TsGoLintState {
     type_check: bool,
     /// If `true`, request that per-rule debug timings be returned from `tsgolint`.
     timings: bool,
+    /// Restricts which rules' fixes are applied.
+    fix_filter: FixFilter,
 }
 
 impl TsGoLintState {
@@ -59,6 +61,7 @@ impl TsGoLintState {
             fix_suggestions: fix_kind.contains(FixKind::Suggestion),
             type_check: false,
             timings: false,
+            fix_filter: FixFilter::default(),
         }
     }
 
@@ -82,6 +85,7 @@ impl TsGoLintState {
             fix_suggestions: fix_kind.contains(FixKind::Suggestion),
             type_check: false,
             timings: false,
+            fix_filter: FixFilter::default(),
         })
     }
 
@@ -113,6 +117,15 @@ impl TsGoLintState {
         self
     }
 
+    /// Only apply the fixes of the rules allowed by `filter`.
+    ///
+    /// Default is to apply all fixes.
+    #[must_use]
+    pub fn with_fix_filter(mut self, filter: FixFilter) -> Self {
+        self.fix_filter = filter;
+        self
+    }
+
     /// # Panics
     /// - when `stdin` of subprocess cannot be opened
     /// - when `stdout` of subprocess cannot be opened
@@ -153,6 +166,7 @@ impl TsGoLintState {
 
         let should_fix = self.fix || self.fix_suggestions;
         let cwd = self.cwd.clone();
+        let fix_filter = self.fix_filter.clone();
         let sender_for_fixes = error_sender.clone();
 
         let diff_manager_clone_to_ts_go = Arc::<DiffManager>::clone(diff_manager);
@@ -281,10 +295,16 @@ impl TsGoLintState {
                     let source_type = SourceType::from_path(&path)
                         .ok()
                         .map(|st| if st.is_javascript() { st.with_jsx(true) } else { st });
-                    let fix_result = Fixer::new(&source_text, messages, source_type).fix();
+                    let fix_result = Fixer::new(&source_text, messages, source_type)
+                        .with_filter(&fix_filter)
+                        .fix();
 
                     if fix_result.fixed
-                        && let Err(error) = file_system.write_file(&path, &fix_result.fixed_code)
+                        && let Err(error) = file_system.write_fixed_file(
+                            &path,
+                            &fix_result.fixed_code,
+                            &fix_result.applied_fixes,
+                        )
                     {
                         sender_for_fixes
                             .send(vec![Error::new(OxcDiagnostic::error(format!(
@@ -518,6 +518,16 @@ impl<'a> KnownMemberExpressionProperty<'a> {
         !self.is_name_equal(name)
     }
 
+    /// Span to delete to remove this member from its chain, including the preceding `.`, or
+    /// the surrounding `[]` for computed members.
+    pub fn removal_span(&self) -> Span {
+        let span = self.span.expand_left(1);
+        match self.element {
+            MemberExpressionElement::IdentName(_) => span,
+            MemberExpressionElement::Expression(_) => span.expand_right(1),
+        }
+    }
+
     pub fn is_name_in_modifiers(&self, modifiers: &[ModifierName]) -> bool {
         self.name().is_some_and(|name| {
             if let Some(modifier_name) = ModifierName::from(name.as_ref()) {
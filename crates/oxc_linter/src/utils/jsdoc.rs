@@ -69,20 +69,11 @@ pub fn get_function_nearest_jsdoc_node<'a, 'b>(
     semantic: &'b Semantic<'a>,
 ) -> Option<&'b AstNode<'a>> {
     let mut current_node = node;
-    let source_function_id = node.id();
     // Whether the node has attached JSDoc or not is determined by `JSDocBuilder`
     while semantic.jsdoc().get_all_by_node(semantic.nodes(), current_node).is_none() {
         // Tie-breaker, otherwise every loop will end at `Program` node!
         // Maybe more checks should be added
         match current_node.kind() {
-            // Do not leak an outer function's JSDoc into nested function expressions.
-            // Keep walking only for the source function node itself, because function
-            // JSDoc can be attached on wrapper nodes like `VariableDeclaration`.
-            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
-                if current_node.id() != source_function_id =>
-            {
-                return None;
-            }
             // Do not apply object-level docs to functions nested in object literals.
             // e.g. `const x = /** ... */ { method(arg) {} }`
             AstKind::ObjectExpression(_) |
@@ -110,11 +101,38 @@ pub fn get_function_nearest_jsdoc_node<'a, 'b>(
             },
             _ => current_node = semantic.nodes().parent_node(current_node.id()),
         }
+        // Do not leak an outer function's JSDoc into nested functions.
+        // Keep walking only for the source function node itself, because function
+        // JSDoc can be attached on wrapper nodes like `VariableDeclaration`.
+        if matches!(current_node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        {
+            return None;
+        }
     }
 
     Some(current_node)
 }
 
+/// Default `contexts` option of rules checking functions.
+pub fn default_function_contexts() -> Vec<String> {
+    ["ArrowFunctionExpression", "FunctionDeclaration", "FunctionExpression"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Whether the ESTree node type of a function node is listed in a `contexts` option.
+/// Methods are `FunctionExpression`s, as in ESTree.
+pub fn is_function_in_contexts(kind: AstKind, contexts: &[String]) -> bool {
+    let node_type = match kind {
+        AstKind::Function(func) if func.is_declaration() => "FunctionDeclaration",
+        AstKind::Function(func) if func.is_expression() => "FunctionExpression",
+        AstKind::ArrowFunctionExpression(_) => "ArrowFunctionExpression",
+        _ => return false,
+    };
+    contexts.iter().any(|context| context == node_type)
+}
+
 pub fn should_ignore_as_internal(jsdoc: &JSDoc, settings: &JSDocPluginSettings) -> bool {
     if settings.ignore_internal {
         let resolved_internal_tag_name = settings.resolve_tag_name("internal");
@@ -0,0 +1,62 @@
+use oxc_ast::{
+    AstKind,
+    ast::{LabelIdentifier, LabeledStatement},
+};
+use oxc_semantic::NodeId;
+
+use crate::context::LintContext;
+
+/// Whether an unlabeled `break` can target the statement, i.e. it is a loop or a `switch`.
+pub fn is_breakable_statement(kind: AstKind) -> bool {
+    kind.is_iteration_statement() || matches!(kind, AstKind::SwitchStatement(_))
+}
+
+/// Finds the labeled statement referenced by the `label` of a `break` or `continue` statement.
+///
+/// Labels on blocks and other non-loop statements are found too:
+/// ```js
+/// foo: while (a) {
+///   bar: {
+///     break foo; // refers to the loop
+///     break bar; // refers to the block
+///   }
+/// }
+/// ```
+pub fn find_labeled_statement<'a, 'c>(
+    label: &LabelIdentifier,
+    jump_stmt_id: NodeId,
+    ctx: &'c LintContext<'a>,
+) -> Option<&'c LabeledStatement<'a>> {
+    ctx.nodes().ancestor_kinds(jump_stmt_id).find_map(|kind| match kind {
+        AstKind::LabeledStatement(labeled_stmt) if labeled_stmt.label.name == label.name => {
+            Some(labeled_stmt)
+        }
+        _ => None,
+    })
+}
+
+/// Whether the `label` of a `break` or `continue` statement refers to the nearest enclosing loop
+/// or `switch`, which the statement targets without the label too.
+pub fn is_label_of_nearest_breakable(
+    label: &LabelIdentifier,
+    jump_stmt_id: NodeId,
+    ctx: &LintContext,
+) -> bool {
+    let nodes = ctx.nodes();
+    nodes.ancestor_ids(jump_stmt_id).find(|&id| is_breakable_statement(nodes.kind(id))).is_some_and(
+        |id| {
+            matches!(nodes.parent_kind(id), AstKind::LabeledStatement(labeled_stmt)
+                if labeled_stmt.label.name == label.name)
+        },
+    )
+}
+
+/// Labeled statements whose label is not referenced by any `break` or `continue` statement.
+pub fn unused_labeled_statements<'a, 'c>(
+    ctx: &'c LintContext<'a>,
+) -> impl Iterator<Item = &'c LabeledStatement<'a>> {
+    ctx.unused_labels().iter().filter_map(|&id| match ctx.nodes().kind(id) {
+        AstKind::LabeledStatement(labeled_stmt) => Some(labeled_stmt),
+        _ => None,
+    })
+}
@@ -20,6 +20,7 @@ mod control_flow;
 mod express;
 mod jest;
 mod jsdoc;
+mod label;
 mod nextjs;
 mod node;
 mod promise;
@@ -32,14 +33,15 @@ mod this_expression;
 mod typescript;
 mod unicorn;
 mod url;
+mod var_scope;
 mod vitest;
 mod vue;
 pub mod vue_casing;
 
 pub use self::{
-    comment::*, config::*, control_flow::*, express::*, jest::*, jsdoc::*, nextjs::*, node::*,
-    promise::*, react::*, react_perf::*, regex::*, schemars::*, static_value::*,
-    this_expression::*, typescript::*, unicorn::*, url::*, vitest::*, vue::*,
+    comment::*, config::*, control_flow::*, express::*, jest::*, jsdoc::*, label::*, nextjs::*,
+    node::*, promise::*, react::*, react_perf::*, regex::*, schemars::*, static_value::*,
+    this_expression::*, typescript::*, unicorn::*, url::*, var_scope::*, vitest::*, vue::*,
 };
 
 /// List of Eslint rules that have TypeScript equivalents.
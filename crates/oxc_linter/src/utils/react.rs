@@ -18,11 +18,12 @@ use oxc_cfg::{
 };
 use oxc_ecmascript::{ToBoolean, WithoutGlobalReferenceInformation};
 use oxc_semantic::{AstNode, SymbolId};
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::node::NodeId;
 use oxc_syntax::operator::UnaryOperator;
 use oxc_syntax::scope::ScopeFlags;
 
-use crate::globals::HTML_TAG;
+use crate::globals::is_dom_element;
 use crate::{LintContext, OxlintSettings};
 
 pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
@@ -72,6 +73,21 @@ pub fn has_jsx_prop_ignore_case<'a, 'b>(
     })
 }
 
+/// Returns the span of the first child nested between the tags, ignoring whitespace-only text
+/// and `{/* comments */}`, which React does not pass as children.
+pub fn first_jsx_child_span(jsx: &JSXElement) -> Option<Span> {
+    jsx.children
+        .iter()
+        .find(|child| match child {
+            JSXChild::Text(text) => !text.value.chars().all(char::is_whitespace),
+            JSXChild::ExpressionContainer(container) => {
+                !matches!(container.expression, JSXExpression::EmptyExpression(_))
+            }
+            _ => true,
+        })
+        .map(GetSpan::span)
+}
+
 pub fn get_prop_value<'a, 'b>(item: &'b JSXAttributeItem<'a>) -> Option<&'b JSXAttributeValue<'a>> {
     item.as_attribute().and_then(|item| item.value.as_ref())
 }
@@ -169,7 +185,7 @@ pub fn is_abstract_role<'a>(ctx: &LintContext<'a>, jsx_opening_el: &JSXOpeningEl
     // Do not test custom JSX components, we do not know what
     // low-level DOM element this maps to.
     let element_type = get_element_type(ctx, jsx_opening_el);
-    if !HTML_TAG.contains(element_type.as_ref()) {
+    if !is_dom_element(element_type.as_ref()) {
         return false;
     }
 
@@ -229,7 +245,7 @@ pub fn is_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningEle
 pub fn is_non_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
     // Do not test custom JSX components, we do not know what
     // low-level DOM element this maps to.
-    if !HTML_TAG.contains(element_type.as_ref()) {
+    if !is_dom_element(element_type) {
         return false;
     }
 
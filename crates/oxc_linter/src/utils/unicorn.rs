@@ -112,6 +112,38 @@ pub fn is_node_value_not_dom_node(expr: &Expression) -> bool {
     )
 }
 
+/// Whether the value of the expression `node` is discarded, i.e. it is used as a statement,
+/// optionally as an optional chain: `foo.bar();` or `foo?.bar();`.
+///
+/// ref: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/v56.0.0/rules/utils/is-value-not-usable.js
+pub fn is_value_not_usable(node: &AstNode, ctx: &LintContext) -> bool {
+    let mut parent_node = ctx.nodes().parent_node(node.id());
+    if matches!(parent_node.kind(), AstKind::ChainExpression(_)) {
+        parent_node = ctx.nodes().parent_node(parent_node.id());
+    }
+    if !matches!(parent_node.kind(), AstKind::ExpressionStatement(_)) {
+        return false;
+    }
+    // The body of `() => foo()` is an expression statement, but its value is returned.
+    let body_node = ctx.nodes().parent_node(parent_node.id());
+    !matches!(
+        (body_node.kind(), ctx.nodes().parent_kind(body_node.id())),
+        (AstKind::FunctionBody(_), AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+    )
+}
+
+/// Whether the expression can be moved or dropped by a fix without changing behavior, i.e. it
+/// is `this`, an identifier, or a non-optional chain of static member accesses on one of these.
+pub fn is_movable_expression(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::Identifier(_) | Expression::ThisExpression(_) => true,
+        Expression::StaticMemberExpression(member_expr) => {
+            !member_expr.optional && is_movable_expression(&member_expr.object)
+        }
+        _ => false,
+    }
+}
+
 pub fn is_empty_stmt(stmt: &Statement) -> bool {
     match stmt {
         Statement::BlockStatement(block_stmt) => {
@@ -0,0 +1,63 @@
+use oxc_ast::{AstKind, ast::VariableDeclarationKind};
+use oxc_semantic::Reference;
+use oxc_syntax::{scope::ScopeId, symbol::SymbolId};
+
+use crate::context::LintContext;
+
+/// The scope a `var` symbol would have if `var` declarations were block scoped: the innermost
+/// scope containing all of its declarations. Declarations in the head of a `for` loop are
+/// scoped to the loop.
+///
+/// ```js
+/// function f() {
+///   if (a) {
+///     var x = 1; // scoped to the `if` block
+///   }
+///   if (b) { var y = 1; } else { var y = 2; } // scoped to the function body
+///   for (var i = 0; i < 10; i++) {} // scoped to the `for` loop
+/// }
+/// ```
+///
+/// Returns `None` if the symbol is also declared by something other than a `var` declaration,
+/// such as a function declaration or a parameter.
+pub fn var_declaring_block(symbol_id: SymbolId, ctx: &LintContext) -> Option<ScopeId> {
+    let scoping = ctx.scoping();
+    let redeclarations = scoping.symbol_redeclarations(symbol_id);
+    let mut declarations = if redeclarations.is_empty() {
+        vec![scoping.symbol_declaration(symbol_id)]
+    } else {
+        redeclarations.iter().map(|redeclaration| redeclaration.declaration).collect()
+    }
+    .into_iter()
+    .map(|declaration| {
+        let node = ctx.nodes().get_node(declaration);
+        match node.kind() {
+            AstKind::VariableDeclarator(decl) if decl.kind == VariableDeclarationKind::Var => {
+                Some(node.scope_id())
+            }
+            _ => None,
+        }
+    });
+
+    let first = declarations.next()??;
+    declarations.try_fold(first, |block, scope_id| {
+        let scope_id = scope_id?;
+        // The lowest common ancestor of both scopes.
+        scoping
+            .scope_ancestors(scope_id)
+            .find(|&ancestor| ancestor == block || scoping.scope_is_descendant_of(block, ancestor))
+    })
+}
+
+/// References to a `var` symbol outside of its [`var_declaring_block`].
+pub fn references_outside_block<'c>(
+    symbol_id: SymbolId,
+    block: ScopeId,
+    ctx: &'c LintContext,
+) -> impl Iterator<Item = &'c Reference> + 'c {
+    let scoping = ctx.scoping();
+    scoping.get_resolved_references(symbol_id).filter(move |reference| {
+        let scope_id = reference.scope_id();
+        scope_id != block && !scoping.scope_is_descendant_of(scope_id, block)
+    })
+}
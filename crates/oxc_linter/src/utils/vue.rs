@@ -15,7 +15,7 @@ use crate::{
 };
 
 // These sets mirror eslint-plugin-vue's `vue/no-reserved-component-names`.
-// `globals::HTML_TAG` has a broader DOM/ARIA scope and different message
+// `globals::dom_element` has a broader DOM/ARIA scope and different message
 // grouping, so it is not a drop-in replacement for this Vue rule.
 pub const VUE_RESERVED_HTML_ELEMENTS: Set<&'static str> = phf_set! {
     "a", "abbr", "address", "area", "article", "aside", "audio", "b", "base", "bdi", "bdo",
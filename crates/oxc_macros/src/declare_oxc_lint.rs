@@ -35,6 +35,10 @@ pub struct LintRuleMeta {
     version: LitStr,
     /// A short, one-line summary of what the rule does.
     short_description: Option<LitStr>,
+    /// Whether the rule runs on TypeScript declaration files (`.d.ts`).
+    works_on_d_ts: Option<syn::LitBool>,
+    /// The language of the files the rule applies to.
+    language: Option<Ident>,
 }
 
 impl Parse for LintRuleMeta {
@@ -109,6 +113,8 @@ impl Parse for LintRuleMeta {
         let mut config: Option<Path> = None;
         let mut version: Option<LitStr> = None;
         let mut short_description: Option<LitStr> = None;
+        let mut works_on_d_ts: Option<syn::LitBool> = None;
+        let mut language: Option<Ident> = None;
 
         // remaining options are `key = value` pairs, with the exception of
         // fix kinds. Those can be short-handed to just the fix kind
@@ -159,6 +165,16 @@ impl Parse for LintRuleMeta {
                     input.parse::<Token!(=)>()?;
                     short_description.replace(input.parse()?);
                 }
+                // works_on_d_ts = false
+                "works_on_d_ts" => {
+                    input.parse::<Token!(=)>()?;
+                    works_on_d_ts.replace(input.parse()?);
+                }
+                // language = typescript
+                "language" => {
+                    input.parse::<Token!(=)>()?;
+                    language.replace(input.parse()?);
+                }
                 _ => {
                     if input.peek(Token!(=)) || fix.is_some() {
                         return Err(Error::new_spanned(key, "unexpected key in rule declaration"));
@@ -213,6 +229,8 @@ impl Parse for LintRuleMeta {
             config,
             version,
             short_description,
+            works_on_d_ts,
+            language,
         })
     }
 }
@@ -234,6 +252,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         config,
         version,
         short_description,
+        works_on_d_ts,
+        language,
     } = metadata;
 
     let canonical_name = rule_name_converter().convert(name.to_string());
@@ -321,6 +341,24 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         }
     });
 
+    let works_on_d_ts_const = works_on_d_ts.map(|works_on_d_ts| {
+        quote! {
+            const WORKS_ON_D_TS: bool = #works_on_d_ts;
+        }
+    });
+
+    let language_const = language.map(|language| {
+        let language = match language.to_string().as_str() {
+            "typescript" => quote! { TypeScript },
+            "jsx" => quote! { Jsx },
+            "vue" => quote! { Vue },
+            _ => panic!("invalid rule language"),
+        };
+        quote! {
+            const LANGUAGE: crate::rule::RuleLanguage = crate::rule::RuleLanguage::#language;
+        }
+    });
+
     let output = quote! {
         #import_statement
 
@@ -344,6 +382,10 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
             #version_const
 
             #info_const
+
+            #works_on_d_ts_const
+
+            #language_const
         }
     };
 
@@ -49,6 +49,12 @@ mod declare_oxc_lint;
 ///
 /// `pending` and `none` are special cases that do not follow this pattern.
 ///
+/// ## Declaration files
+///
+/// Rules run on TypeScript declaration files (`.d.ts`) by default. Rules which
+/// only make sense for code with runtime behavior can opt out with
+/// `works_on_d_ts = false`, and are then skipped for those files.
+///
 /// ## Integration markers
 /// You can optionally add an integration marker immediately after the rule's struct
 /// name in parentheses. Currently the only supported marker is `tsgolint`:
@@ -0,0 +1,123 @@
+use std::{borrow::Cow, path::Path, sync::Arc};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_benchmark::{
+    BenchmarkId, Criterion, black_box, criterion_group, criterion_main, eslint_directive_source,
+};
+use oxc_linter::{
+    ConfigStore, ConfigStoreBuilder, ContextSubHost, ContextSubHostOptions, ExternalPluginStore,
+    FixKind, Fixer, LintOptions, Linter, Message, ModuleRecord, Oxlintrc, PossibleFixes,
+};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, Span};
+
+/// Compare the two ways of fixing `eslint-*` directive comments to `oxlint-*`:
+///
+/// * `scoped`: the fixes of `oxc/no-eslint-disable-comments`, which replace only the `eslint`
+///   keyword of each directive with `oxlint`.
+/// * `cow_replace`: the same fixes, widened to replace the whole comment with its text after
+///   `cow_replace`.
+///
+/// Both apply the fixes with [`Fixer`] and produce the same fixed source text.
+fn bench_eslint_directive_fix(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("eslint_directive_fix");
+
+    let oxlintrc =
+        Oxlintrc::from_string(r#"{ "rules": { "oxc/no-eslint-disable-comments": "warn" } }"#)
+            .unwrap();
+    let mut external_plugin_store = ExternalPluginStore::default();
+    let lint_config =
+        ConfigStoreBuilder::from_oxlintrc(true, oxlintrc, None, &mut external_plugin_store, None)
+            .unwrap()
+            .build(&mut external_plugin_store)
+            .unwrap();
+    let linter = Linter::new(
+        LintOptions::default(),
+        ConfigStore::new(lint_config, FxHashMap::default(), external_plugin_store),
+        None,
+    )
+    .with_fix(FixKind::All);
+
+    for count in [10, 100, 1000] {
+        let source_text = eslint_directive_source(count);
+        let source_type = SourceType::mjs();
+
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, &source_text, source_type).parse();
+        let path = Path::new("");
+        let semantic = SemanticBuilder::new_linter().build(&parser_ret.program).semantic;
+        let module_record = Arc::new(ModuleRecord::new(path, &parser_ret.module_record, &semantic));
+        let messages = linter.run(
+            path,
+            vec![ContextSubHost::new(semantic, module_record, 0, ContextSubHostOptions::default())],
+            &allocator,
+        );
+        // Span of the comment fixed by each message.
+        let comment_spans = messages
+            .iter()
+            .map(|message| {
+                parser_ret
+                    .program
+                    .comments
+                    .iter()
+                    .find(|comment| comment.span.contains_inclusive(message.span))
+                    .unwrap()
+                    .span
+            })
+            .collect::<Vec<_>>();
+
+        let scoped = Fixer::new(&source_text, messages.clone(), Some(source_type)).fix();
+        let cow_replace = Fixer::new(
+            &source_text,
+            widen_fixes(messages.clone(), &comment_spans, &source_text),
+            Some(source_type),
+        )
+        .fix();
+        assert_eq!(scoped.fixed_code, cow_replace.fixed_code);
+        assert!(!scoped.fixed_code.contains("eslint-disable"));
+
+        group.bench_function(BenchmarkId::new("scoped", count), |b| {
+            b.iter_with_setup_wrapper(|runner| {
+                let messages = messages.clone();
+                runner
+                    .run(|| black_box(Fixer::new(&source_text, messages, Some(source_type)).fix()));
+            });
+        });
+
+        group.bench_function(BenchmarkId::new("cow_replace", count), |b| {
+            b.iter_with_setup_wrapper(|runner| {
+                let messages = messages.clone();
+                runner.run(|| {
+                    let messages = widen_fixes(messages, &comment_spans, &source_text);
+                    black_box(Fixer::new(&source_text, messages, Some(source_type)).fix())
+                });
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Replace the fix of each message with one replacing the whole comment at the corresponding
+/// span in `comment_spans` with its text after `cow_replace`.
+fn widen_fixes(
+    mut messages: Vec<Message>,
+    comment_spans: &[Span],
+    source_text: &str,
+) -> Vec<Message> {
+    for (message, comment_span) in messages.iter_mut().zip(comment_spans) {
+        if let PossibleFixes::Single(fix) = &mut message.fixes {
+            let comment_text = comment_span.source_text(source_text);
+            fix.content = Cow::Owned(comment_text.cow_replace("eslint-", "oxlint-").into_owned());
+            fix.span = *comment_span;
+        }
+    }
+    messages
+}
+
+criterion_group!(eslint_directive_fix, bench_eslint_directive_fix);
+criterion_main!(eslint_directive_fix);
@@ -0,0 +1,63 @@
+use std::{path::Path, sync::Arc};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_benchmark::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use oxc_linter::{
+    ConfigStore, ConfigStoreBuilder, ContextSubHost, ContextSubHostOptions, ExternalPluginStore,
+    FixKind, Fixer, LintOptions, Linter, ModuleRecord,
+};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_tasks_common::TestFiles;
+
+/// Measure the cost of verifying fixes, which re-parses the fixed source text and, if it has
+/// syntax errors, the original source text too.
+///
+/// The fixes are those of all rules, so `unverified` and `verified` apply the same fixes.
+fn bench_fix_verification(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("fix_verification");
+
+    for file in TestFiles::minimal().files() {
+        let source_text = &file.source_text;
+        let source_type = file.source_type;
+
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
+        let path = Path::new("");
+        let semantic = SemanticBuilder::new_linter().build(&parser_ret.program).semantic;
+        let module_record = Arc::new(ModuleRecord::new(path, &parser_ret.module_record, &semantic));
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let lint_config = ConfigStoreBuilder::all().build(&mut external_plugin_store).unwrap();
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(lint_config, FxHashMap::default(), external_plugin_store),
+            None,
+        )
+        .with_fix(FixKind::All);
+        let messages = linter.run(
+            path,
+            vec![ContextSubHost::new(semantic, module_record, 0, ContextSubHostOptions::default())],
+            &allocator,
+        );
+
+        for (name, verify) in [("unverified", false), ("verified", true)] {
+            let id = BenchmarkId::new(name, &file.file_name);
+            group.bench_function(id, |b| {
+                b.iter_with_setup_wrapper(|runner| {
+                    let messages = messages.clone();
+                    runner.run(|| {
+                        Fixer::new(source_text, messages, Some(source_type))
+                            .with_verify(verify)
+                            .fix()
+                    });
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(fix_verification, bench_fix_verification);
+criterion_main!(fix_verification);
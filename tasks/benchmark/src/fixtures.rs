@@ -0,0 +1,24 @@
+use std::fmt::Write;
+
+/// Directive comments cycled through by [`eslint_directive_source`].
+///
+/// Mix of line and block comments, with and without rule lists and descriptions,
+/// to resemble the directives found in real-world code.
+const DIRECTIVES: &[&str] = &[
+    "// eslint-disable-next-line no-console",
+    "/* eslint-disable no-alert, no-debugger */",
+    "// eslint-disable-next-line @typescript-eslint/no-explicit-any -- legacy API",
+    "/* eslint-enable no-alert, no-debugger */",
+    "/*\n * eslint-disable-next-line no-unused-vars, prefer-const\n */",
+    "// eslint-disable-next-line",
+];
+
+/// Generate JS source containing `count` ESLint directive comments, each followed by a
+/// statement, so that benchmarks of directive handling have many comments to work on.
+pub fn eslint_directive_source(count: usize) -> String {
+    let mut source_text = String::with_capacity(count * 64);
+    for (i, directive) in DIRECTIVES.iter().cycle().take(count).enumerate() {
+        writeln!(source_text, "{directive}\nconsole.log(value{i});").unwrap();
+    }
+    source_text
+}
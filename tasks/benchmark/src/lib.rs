@@ -2,6 +2,9 @@ use std::alloc::{GlobalAlloc, Layout, System};
 
 pub use criterion::*;
 
+mod fixtures;
+pub use fixtures::eslint_directive_source;
+
 #[global_allocator]
 static GLOBAL: NeverGrowInPlaceAllocator = NeverGrowInPlaceAllocator;
 
@@ -0,0 +1,184 @@
+use std::fmt::Write as _;
+
+struct DomElementData {
+    /// Reserved elements do not support ARIA roles, states and properties, usually because they
+    /// are not rendered.
+    ///
+    /// Reference: <https://github.com/A11yance/aria-query/blob/v5.3.2/src/domMap.js>
+    /// Reference: <https://www.w3.org/TR/html-aria/#rules-wd>
+    reserved: bool,
+    /// Void elements cannot have children.
+    ///
+    /// Reference: <https://html.spec.whatwg.org/multipage/syntax.html#void-elements>
+    void: bool,
+}
+
+/// DOM elements known to the linter.
+const DOM_ELEMENTS: &[(&str, DomElementData)] = &[
+    ("a", DomElementData { reserved: false, void: false }),
+    ("abbr", DomElementData { reserved: false, void: false }),
+    ("acronym", DomElementData { reserved: false, void: false }),
+    ("address", DomElementData { reserved: false, void: false }),
+    ("applet", DomElementData { reserved: false, void: false }),
+    ("area", DomElementData { reserved: false, void: true }),
+    ("article", DomElementData { reserved: false, void: false }),
+    ("aside", DomElementData { reserved: false, void: false }),
+    ("audio", DomElementData { reserved: false, void: false }),
+    ("b", DomElementData { reserved: false, void: false }),
+    ("base", DomElementData { reserved: true, void: true }),
+    ("basefont", DomElementData { reserved: false, void: false }),
+    ("bdi", DomElementData { reserved: false, void: false }),
+    ("bdo", DomElementData { reserved: false, void: false }),
+    ("bgsound", DomElementData { reserved: false, void: false }),
+    ("big", DomElementData { reserved: false, void: false }),
+    ("blink", DomElementData { reserved: false, void: false }),
+    ("blockquote", DomElementData { reserved: false, void: false }),
+    ("body", DomElementData { reserved: false, void: false }),
+    ("br", DomElementData { reserved: false, void: true }),
+    ("button", DomElementData { reserved: false, void: false }),
+    ("canvas", DomElementData { reserved: false, void: false }),
+    ("caption", DomElementData { reserved: false, void: false }),
+    ("center", DomElementData { reserved: false, void: false }),
+    ("cite", DomElementData { reserved: false, void: false }),
+    ("code", DomElementData { reserved: false, void: false }),
+    ("col", DomElementData { reserved: true, void: true }),
+    ("colgroup", DomElementData { reserved: true, void: false }),
+    ("command", DomElementData { reserved: false, void: false }),
+    ("content", DomElementData { reserved: false, void: false }),
+    ("data", DomElementData { reserved: false, void: false }),
+    ("datalist", DomElementData { reserved: false, void: false }),
+    ("dd", DomElementData { reserved: false, void: false }),
+    ("del", DomElementData { reserved: false, void: false }),
+    ("details", DomElementData { reserved: false, void: false }),
+    ("dfn", DomElementData { reserved: false, void: false }),
+    ("dialog", DomElementData { reserved: false, void: false }),
+    ("dir", DomElementData { reserved: false, void: false }),
+    ("div", DomElementData { reserved: false, void: false }),
+    ("dl", DomElementData { reserved: false, void: false }),
+    ("dt", DomElementData { reserved: false, void: false }),
+    ("element", DomElementData { reserved: false, void: false }),
+    ("em", DomElementData { reserved: false, void: false }),
+    ("embed", DomElementData { reserved: false, void: true }),
+    ("fieldset", DomElementData { reserved: false, void: false }),
+    ("figcaption", DomElementData { reserved: false, void: false }),
+    ("figure", DomElementData { reserved: false, void: false }),
+    ("font", DomElementData { reserved: false, void: false }),
+    ("footer", DomElementData { reserved: false, void: false }),
+    ("form", DomElementData { reserved: false, void: false }),
+    ("frame", DomElementData { reserved: false, void: false }),
+    ("frameset", DomElementData { reserved: false, void: false }),
+    ("h1", DomElementData { reserved: false, void: false }),
+    ("h2", DomElementData { reserved: false, void: false }),
+    ("h3", DomElementData { reserved: false, void: false }),
+    ("h4", DomElementData { reserved: false, void: false }),
+    ("h5", DomElementData { reserved: false, void: false }),
+    ("h6", DomElementData { reserved: false, void: false }),
+    ("head", DomElementData { reserved: true, void: false }),
+    ("header", DomElementData { reserved: false, void: false }),
+    ("hgroup", DomElementData { reserved: false, void: false }),
+    ("hr", DomElementData { reserved: false, void: true }),
+    ("html", DomElementData { reserved: true, void: false }),
+    ("i", DomElementData { reserved: false, void: false }),
+    ("iframe", DomElementData { reserved: false, void: false }),
+    ("image", DomElementData { reserved: false, void: false }),
+    ("img", DomElementData { reserved: false, void: true }),
+    ("input", DomElementData { reserved: false, void: true }),
+    ("ins", DomElementData { reserved: false, void: false }),
+    ("isindex", DomElementData { reserved: false, void: false }),
+    ("kbd", DomElementData { reserved: false, void: false }),
+    ("keygen", DomElementData { reserved: false, void: true }),
+    ("label", DomElementData { reserved: false, void: false }),
+    ("legend", DomElementData { reserved: false, void: false }),
+    ("li", DomElementData { reserved: false, void: false }),
+    ("link", DomElementData { reserved: true, void: true }),
+    ("listing", DomElementData { reserved: false, void: false }),
+    ("main", DomElementData { reserved: false, void: false }),
+    ("map", DomElementData { reserved: false, void: false }),
+    ("mark", DomElementData { reserved: false, void: false }),
+    ("marquee", DomElementData { reserved: false, void: false }),
+    ("math", DomElementData { reserved: false, void: false }),
+    ("menu", DomElementData { reserved: false, void: false }),
+    ("menuitem", DomElementData { reserved: false, void: true }),
+    ("meta", DomElementData { reserved: true, void: true }),
+    ("meter", DomElementData { reserved: false, void: false }),
+    ("multicol", DomElementData { reserved: false, void: false }),
+    ("nav", DomElementData { reserved: false, void: false }),
+    ("nextid", DomElementData { reserved: false, void: false }),
+    ("nobr", DomElementData { reserved: false, void: false }),
+    ("noembed", DomElementData { reserved: true, void: false }),
+    ("noframes", DomElementData { reserved: false, void: false }),
+    ("noscript", DomElementData { reserved: true, void: false }),
+    ("object", DomElementData { reserved: false, void: false }),
+    ("ol", DomElementData { reserved: false, void: false }),
+    ("optgroup", DomElementData { reserved: false, void: false }),
+    ("option", DomElementData { reserved: false, void: false }),
+    ("output", DomElementData { reserved: false, void: false }),
+    ("p", DomElementData { reserved: false, void: false }),
+    ("param", DomElementData { reserved: true, void: true }),
+    ("picture", DomElementData { reserved: true, void: false }),
+    ("plaintext", DomElementData { reserved: false, void: false }),
+    ("pre", DomElementData { reserved: false, void: false }),
+    ("progress", DomElementData { reserved: false, void: false }),
+    ("q", DomElementData { reserved: false, void: false }),
+    ("rb", DomElementData { reserved: false, void: false }),
+    ("rbc", DomElementData { reserved: false, void: false }),
+    ("rp", DomElementData { reserved: false, void: false }),
+    ("rt", DomElementData { reserved: false, void: false }),
+    ("rtc", DomElementData { reserved: false, void: false }),
+    ("ruby", DomElementData { reserved: false, void: false }),
+    ("s", DomElementData { reserved: false, void: false }),
+    ("samp", DomElementData { reserved: false, void: false }),
+    ("script", DomElementData { reserved: true, void: false }),
+    ("search", DomElementData { reserved: false, void: false }),
+    ("section", DomElementData { reserved: false, void: false }),
+    ("select", DomElementData { reserved: false, void: false }),
+    ("shadow", DomElementData { reserved: false, void: false }),
+    ("slot", DomElementData { reserved: false, void: false }),
+    ("small", DomElementData { reserved: false, void: false }),
+    ("source", DomElementData { reserved: true, void: true }),
+    ("spacer", DomElementData { reserved: false, void: false }),
+    ("span", DomElementData { reserved: false, void: false }),
+    ("strike", DomElementData { reserved: false, void: false }),
+    ("strong", DomElementData { reserved: false, void: false }),
+    ("style", DomElementData { reserved: true, void: false }),
+    ("sub", DomElementData { reserved: false, void: false }),
+    ("summary", DomElementData { reserved: false, void: false }),
+    ("sup", DomElementData { reserved: false, void: false }),
+    ("svg", DomElementData { reserved: false, void: false }),
+    ("table", DomElementData { reserved: false, void: false }),
+    ("tbody", DomElementData { reserved: false, void: false }),
+    ("td", DomElementData { reserved: false, void: false }),
+    ("template", DomElementData { reserved: false, void: false }),
+    ("textarea", DomElementData { reserved: false, void: false }),
+    ("tfoot", DomElementData { reserved: false, void: false }),
+    ("th", DomElementData { reserved: false, void: false }),
+    ("thead", DomElementData { reserved: false, void: false }),
+    ("time", DomElementData { reserved: false, void: false }),
+    ("title", DomElementData { reserved: true, void: false }),
+    ("tr", DomElementData { reserved: false, void: false }),
+    ("track", DomElementData { reserved: true, void: true }),
+    ("tt", DomElementData { reserved: false, void: false }),
+    ("u", DomElementData { reserved: false, void: false }),
+    ("ul", DomElementData { reserved: false, void: false }),
+    ("var", DomElementData { reserved: false, void: false }),
+    ("video", DomElementData { reserved: false, void: false }),
+    ("wbr", DomElementData { reserved: false, void: true }),
+    ("xmp", DomElementData { reserved: false, void: false }),
+];
+
+/// Generate the `DOM_ELEMENTS` table consumed through `oxc_linter::globals`.
+pub fn generate_dom_elements() -> String {
+    let mut out = String::new();
+    out.push_str(
+        "// Auto-generated code, DO NOT EDIT DIRECTLY!\n// To regenerate: `cargo lintgen`\n\n",
+    );
+    out.push_str("use crate::globals::DomElement;\n\n");
+    // `rustfmt` does not format macro bodies, so emit one entry per line.
+    out.push_str("pub static DOM_ELEMENTS: phf::Map<&'static str, DomElement> = phf::phf_map! {\n");
+    for (name, DomElementData { reserved, void }) in DOM_ELEMENTS {
+        writeln!(out, "    \"{name}\" => DomElement {{ reserved: {reserved}, void: {void} }},")
+            .unwrap();
+    }
+    out.push_str("};\n");
+    out
+}
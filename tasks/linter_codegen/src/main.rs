@@ -19,6 +19,7 @@ use std::{
 };
 use syn::File;
 
+mod dom_elements;
 mod early_diverge_detector;
 mod if_else_detector;
 mod let_else_detector;
@@ -32,7 +33,8 @@ mod utils;
 
 fn main() -> io::Result<()> {
     generate_rule_runner_impls()?;
-    generate_rules_enum_file()
+    generate_rules_enum_file()?;
+    generate_dom_elements_file()
 }
 
 /// # Errors
@@ -144,6 +146,24 @@ pub fn generate_rules_enum_file() -> io::Result<()> {
     Ok(())
 }
 
+/// Generate the `dom_elements.rs` file with the DOM element data shared by a11y rules.
+///
+/// # Errors
+/// Returns `io::Error` if file operations fail.
+pub fn generate_dom_elements_file() -> io::Result<()> {
+    let root = project_root::get_project_root()
+        .map_err(|e| std::io::Error::other(format!("could not find project root: {e}")))?;
+
+    let out = dom_elements::generate_dom_elements();
+    let formatted_out = rust_fmt(&out);
+
+    let target_path = root.join("crates/oxc_linter/src/generated/dom_elements.rs");
+    fs::write(&target_path, &formatted_out)?;
+    println!("Generated DOM elements into {}", target_path.display());
+
+    Ok(())
+}
+
 /// Detect the top-level node types used in a lint rule file by analyzing the Rust AST with `syn`.
 /// Returns `Some(bitset)` if at least one node type can be determined, otherwise `None`.
 fn detect_top_level_node_types(
@@ -111,7 +111,7 @@ fn generate_imports() -> TokenStream {
     quote! {
         use crate::{
             context::{ContextHost, LintContext},
-            rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta, RuleRunner, RuleRunFunctionsImplemented},
+            rule::{Rule, RuleCategory, RuleFixMeta, RuleLanguage, RuleMeta, RuleRunner, RuleRunFunctionsImplemented},
             timing::RuleTimingStat,
             utils::PossibleJestNode,
             AstNode
@@ -270,6 +270,22 @@ fn generate_rule_enum_impl(rule_entries: &[RuleEntry<'_>]) -> TokenStream {
         })
         .collect();
 
+    let works_on_d_ts_arms: Vec<TokenStream> = rule_entries
+        .iter()
+        .map(|rule| {
+            let enum_name = make_enum_ident(rule);
+            quote! { Self::#enum_name(_) => #enum_name::WORKS_ON_D_TS }
+        })
+        .collect();
+
+    let language_arms: Vec<TokenStream> = rule_entries
+        .iter()
+        .map(|rule| {
+            let enum_name = make_enum_ident(rule);
+            quote! { Self::#enum_name(_) => #enum_name::LANGUAGE }
+        })
+        .collect();
+
     let info_arms: Vec<TokenStream> = rule_entries
         .iter()
         .map(|rule| {
@@ -452,6 +468,20 @@ fn generate_rule_enum_impl(rule_entries: &[RuleEntry<'_>]) -> TokenStream {
                 }
             }
 
+            /// Whether this rule runs on TypeScript declaration files.
+            pub fn works_on_d_ts(&self) -> bool {
+                match self {
+                    #(#works_on_d_ts_arms),*
+                }
+            }
+
+            /// The language of the files this rule applies to.
+            pub fn language(&self) -> RuleLanguage {
+                match self {
+                    #(#language_arms),*
+                }
+            }
+
             /// Additional information about this rule.
             #[cfg(feature = "ruledocs")]
             pub fn info(&self) -> RuleInfo {